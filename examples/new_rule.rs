@@ -40,9 +40,9 @@ fn main() {
     );
 
     msg.finalize();
-    let mut buf = vec![0; 1024 * 8];
+    let mut buf = vec![0; msg.buffer_len()];
 
-    msg.serialize(&mut buf[..msg.buffer_len()]);
+    msg.serialize(&mut buf);
 
     println!(">>> {msg:?}");
 
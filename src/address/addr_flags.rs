@@ -14,8 +14,13 @@ const IFA_F_MCAUTOJOIN: u32 = 0x400;
 const IFA_F_STABLE_PRIVACY: u32 = 0x800;
 
 bitflags! {
+    /// Full 32 bits address flags used by [`super::AddressAttribute::Flags`]
+    /// (`IFA_FLAGS`). Kernel reports all flags in `IFA_FLAGS` and only the
+    /// first byte of them in `ifa_flags` of [`super::AddressHeader`], hence
+    /// `IFA_FLAGS` should be preferred when both are present.
     #[non_exhaustive]
     #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+    #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
     pub struct AddressFlags : u32 {
         const Secondary = IFA_F_SECONDARY;
         const Nodad = IFA_F_NODAD;
@@ -36,6 +41,7 @@ bitflags! {
 bitflags! {
     #[non_exhaustive]
     #[derive(Debug, Clone, Copy, Eq, PartialEq)]
+    #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
     /// [`AddressHeaderFlags`] is only used for [`super::AddressHeader`] and holding
     /// subset(first byte) of [`AddressFlags`].
     pub struct AddressHeaderFlags : u8 {
@@ -56,3 +62,17 @@ impl Default for AddressHeaderFlags {
         Self::empty()
     }
 }
+
+impl From<AddressHeaderFlags> for AddressFlags {
+    fn from(v: AddressHeaderFlags) -> Self {
+        Self::from_bits_retain(v.bits().into())
+    }
+}
+
+/// Truncating conversion: flags beyond first byte, for example
+/// [AddressFlags::Noprefixroute], are dropped.
+impl From<AddressFlags> for AddressHeaderFlags {
+    fn from(v: AddressFlags) -> Self {
+        Self::from_bits_retain(v.bits() as u8)
+    }
+}
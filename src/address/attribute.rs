@@ -8,10 +8,11 @@ use byteorder::{ByteOrder, NativeEndian};
 use netlink_packet_utils::{
     nla::{DefaultNla, Nla, NlaBuffer},
     parsers::{parse_string, parse_u32},
-    DecodeError, Emitable, Parseable,
+    DecodeError, Emitable, Parseable, ParseableParametrized,
 };
 
 use crate::address::{AddressFlags, CacheInfo, CacheInfoBuffer};
+use crate::AddressFamily;
 
 const IFA_ADDRESS: u16 = 1;
 const IFA_LOCAL: u16 = 2;
@@ -21,8 +22,8 @@ const IFA_ANYCAST: u16 = 5;
 const IFA_CACHEINFO: u16 = 6;
 const IFA_MULTICAST: u16 = 7;
 const IFA_FLAGS: u16 = 8;
+const IFA_RT_PRIORITY: u16 = 9;
 // TODO(Gris Ge)
-// const IFA_RT_PRIORITY: u16 = 9;
 // const IFA_TARGET_NETNSID: u16 = 10,
 // const IFA_PROTO: u16 = 11;
 
@@ -32,6 +33,7 @@ const IPV4_ADDR_LEN: usize = 4;
 const IPV6_ADDR_LEN: usize = 16;
 
 #[derive(Debug, PartialEq, Eq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[non_exhaustive]
 pub enum AddressAttribute {
     Address(IpAddr),
@@ -45,6 +47,9 @@ pub enum AddressAttribute {
     /// IPv6 only
     Multicast(Ipv6Addr),
     Flags(AddressFlags),
+    /// Metric of the prefix route kernel creates for this address.
+    RoutePriority(u32),
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_nla"))]
     Other(DefaultNla),
 }
 
@@ -62,7 +67,7 @@ impl Nla for AddressAttribute {
             }
             Self::Label(ref string) => string.as_bytes().len() + 1,
 
-            Self::Flags(_) => size_of::<u32>(),
+            Self::Flags(_) | Self::RoutePriority(_) => size_of::<u32>(),
 
             Self::CacheInfo(ref attr) => attr.buffer_len(),
 
@@ -87,6 +92,9 @@ impl Nla for AddressAttribute {
             Self::Flags(ref value) => {
                 NativeEndian::write_u32(buffer, value.bits())
             }
+            Self::RoutePriority(value) => {
+                NativeEndian::write_u32(buffer, value)
+            }
             Self::CacheInfo(ref attr) => attr.emit(buffer),
             Self::Other(ref attr) => attr.emit_value(buffer),
         }
@@ -102,6 +110,7 @@ impl Nla for AddressAttribute {
             Self::CacheInfo(_) => IFA_CACHEINFO,
             Self::Multicast(_) => IFA_MULTICAST,
             Self::Flags(_) => IFA_FLAGS,
+            Self::RoutePriority(_) => IFA_RT_PRIORITY,
             Self::Other(ref nla) => nla.kind(),
         }
     }
@@ -196,6 +205,9 @@ impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>>
             IFA_FLAGS => Self::Flags(AddressFlags::from_bits_retain(
                 parse_u32(payload).context("invalid IFA_FLAGS value")?,
             )),
+            IFA_RT_PRIORITY => Self::RoutePriority(
+                parse_u32(payload).context("invalid IFA_RT_PRIORITY value")?,
+            ),
             kind => Self::Other(
                 DefaultNla::parse(buf)
                     .context(format!("unknown NLA type {kind}"))?,
@@ -203,3 +215,35 @@ impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>>
         })
     }
 }
+
+// Unlike `Parseable` which guesses the address family from the length of
+// `IFA_ADDRESS` and `IFA_LOCAL` payload, this checks the payload length
+// against the address family of the message header.
+impl<'a, T: AsRef<[u8]> + ?Sized>
+    ParseableParametrized<NlaBuffer<&'a T>, AddressFamily>
+    for AddressAttribute
+{
+    fn parse_with_param(
+        buf: &NlaBuffer<&'a T>,
+        family: AddressFamily,
+    ) -> Result<Self, DecodeError> {
+        let payload = buf.value();
+        let name = match buf.kind() {
+            IFA_ADDRESS => "IFA_ADDRESS",
+            IFA_LOCAL => "IFA_LOCAL",
+            _ => return Self::parse(buf),
+        };
+        let expected_len = match family {
+            AddressFamily::Inet => IPV4_ADDR_LEN,
+            AddressFamily::Inet6 => IPV6_ADDR_LEN,
+            _ => return Self::parse(buf),
+        };
+        if payload.len() != expected_len {
+            return Err(DecodeError::from(format!(
+                "Invalid {name} for address family {family:?}, expecting \
+                {expected_len} bytes, got {payload:?}"
+            )));
+        }
+        Self::parse(buf)
+    }
+}
@@ -1,14 +1,18 @@
 // SPDX-License-Identifier: MIT
 
+use std::net::{IpAddr, Ipv4Addr};
+
 use anyhow::Context;
 use netlink_packet_utils::{
     nla::{NlaBuffer, NlasIterator},
-    traits::{Emitable, Parseable},
+    traits::{Emitable, Parseable, ParseableParametrized},
     DecodeError,
 };
 
 use crate::{
-    address::{AddressAttribute, AddressHeaderFlags, AddressScope},
+    address::{
+        AddressAttribute, AddressFlags, AddressHeaderFlags, AddressScope,
+    },
     AddressFamily,
 };
 
@@ -23,6 +27,8 @@ buffer!(AddressMessageBuffer(ADDRESS_HEADER_LEN) {
     payload: (slice, ADDRESS_HEADER_LEN..),
 });
 
+impl_try_from_slice!(AddressMessageBuffer);
+
 impl<'a, T: AsRef<[u8]> + ?Sized> AddressMessageBuffer<&'a T> {
     pub fn attributes(
         &self,
@@ -32,13 +38,117 @@ impl<'a, T: AsRef<[u8]> + ?Sized> AddressMessageBuffer<&'a T> {
 }
 
 #[derive(Debug, PartialEq, Eq, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[non_exhaustive]
 pub struct AddressMessage {
     pub header: AddressHeader,
     pub attributes: Vec<AddressAttribute>,
 }
 
+impl AddressMessage {
+    /// Metric of the prefix route of this address stored in
+    /// [AddressAttribute::RoutePriority].
+    pub fn route_priority(&self) -> Option<u32> {
+        self.attributes.iter().find_map(|attr| match attr {
+            AddressAttribute::RoutePriority(v) => Some(*v),
+            _ => None,
+        })
+    }
+}
+
+/// Builder of [AddressMessage] for the `ip address add` and
+/// `ip address del` requests.
+///
+/// ```
+/// use std::net::{IpAddr, Ipv4Addr};
+///
+/// use netlink_packet_route::address::AddressMessageBuilder;
+///
+/// // ip address add 192.0.2.1/24 broadcast 192.0.2.255 dev eth1
+/// let message = AddressMessageBuilder::new()
+///     .index(3)
+///     .address(IpAddr::V4(Ipv4Addr::new(192, 0, 2, 1)), 24)
+///     .broadcast(Ipv4Addr::new(192, 0, 2, 255))
+///     .build();
+/// ```
+#[derive(Debug, PartialEq, Eq, Clone, Default)]
+pub struct AddressMessageBuilder {
+    header: AddressHeader,
+    attributes: Vec<AddressAttribute>,
+    address: Option<IpAddr>,
+}
+
+impl AddressMessageBuilder {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set the index of the interface holding the address (`ifa_index`).
+    pub fn index(mut self, index: u32) -> Self {
+        self.header.index = index;
+        self
+    }
+
+    /// Set the header family and prefix length from the address, and append
+    /// it as `IFA_LOCAL`. Like `ip address add` does when no peer address is
+    /// specified, [Self::build()] also appends it as the last attribute
+    /// `IFA_ADDRESS`.
+    pub fn address(mut self, address: IpAddr, prefix_len: u8) -> Self {
+        self.header.family = match address {
+            IpAddr::V4(_) => AddressFamily::Inet,
+            IpAddr::V6(_) => AddressFamily::Inet6,
+        };
+        self.header.prefix_len = prefix_len;
+        self.attributes.push(AddressAttribute::Local(address));
+        self.address = Some(address);
+        self
+    }
+
+    /// Set the IPv4 broadcast address (`IFA_BROADCAST`).
+    pub fn broadcast(mut self, broadcast: Ipv4Addr) -> Self {
+        self.attributes.push(AddressAttribute::Broadcast(broadcast));
+        self
+    }
+
+    /// Set the IPv4 address label (`IFA_LABEL`), which kernel requires to
+    /// be the interface name or prefixed by the interface name and `:`.
+    pub fn label(mut self, label: String) -> Self {
+        self.attributes.push(AddressAttribute::Label(label));
+        self
+    }
+
+    /// Set the address scope in the header.
+    pub fn scope(mut self, scope: AddressScope) -> Self {
+        self.header.scope = scope;
+        self
+    }
+
+    /// Set the address flags. Like `ip address add`, they are stored in the
+    /// header flags when fitting in 8 bits, otherwise appended as
+    /// `IFA_FLAGS`.
+    pub fn flags(mut self, flags: AddressFlags) -> Self {
+        match u8::try_from(flags.bits()) {
+            Ok(bits) => {
+                self.header.flags = AddressHeaderFlags::from_bits_retain(bits)
+            }
+            Err(_) => self.attributes.push(AddressAttribute::Flags(flags)),
+        }
+        self
+    }
+
+    pub fn build(mut self) -> AddressMessage {
+        if let Some(address) = self.address {
+            self.attributes.push(AddressAttribute::Address(address));
+        }
+        AddressMessage {
+            header: self.header,
+            attributes: self.attributes,
+        }
+    }
+}
+
 #[derive(Debug, PartialEq, Eq, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct AddressHeader {
     pub family: AddressFamily,
     pub prefix_len: u8,
@@ -91,10 +201,12 @@ impl<'a, T: AsRef<[u8]> + 'a> Parseable<AddressMessageBuffer<&'a T>>
     for AddressMessage
 {
     fn parse(buf: &AddressMessageBuffer<&'a T>) -> Result<Self, DecodeError> {
+        let header = AddressHeader::parse(buf)
+            .context("failed to parse address message header")?;
+        let family = header.family;
         Ok(AddressMessage {
-            header: AddressHeader::parse(buf)
-                .context("failed to parse address message header")?,
-            attributes: Vec::<AddressAttribute>::parse(buf)
+            header,
+            attributes: parse_attributes(buf, family)
                 .context("failed to parse address message NLAs")?,
         })
     }
@@ -111,3 +223,16 @@ impl<'a, T: AsRef<[u8]> + 'a> Parseable<AddressMessageBuffer<&'a T>>
         Ok(attributes)
     }
 }
+
+// Unlike `Vec::<AddressAttribute>::parse()`, this checks the length of
+// `IFA_ADDRESS` and `IFA_LOCAL` against the address family of the header.
+fn parse_attributes<'a, T: AsRef<[u8]> + 'a>(
+    buf: &AddressMessageBuffer<&'a T>,
+    family: AddressFamily,
+) -> Result<Vec<AddressAttribute>, DecodeError> {
+    let mut attributes = vec![];
+    for nla_buf in buf.attributes() {
+        attributes.push(AddressAttribute::parse_with_param(&nla_buf?, family)?);
+    }
+    Ok(attributes)
+}
@@ -13,4 +13,6 @@ pub use self::addr_flags::{AddressFlags, AddressHeaderFlags};
 pub use self::addr_scope::AddressScope;
 pub use self::attribute::AddressAttribute;
 pub use self::cache_info::{CacheInfo, CacheInfoBuffer};
-pub use self::message::{AddressHeader, AddressMessage, AddressMessageBuffer};
+pub use self::message::{
+    AddressHeader, AddressMessage, AddressMessageBuffer, AddressMessageBuilder,
+};
@@ -0,0 +1,160 @@
+// SPDX-License-Identifier: MIT
+
+use std::net::{IpAddr, Ipv4Addr, Ipv6Addr};
+use std::str::FromStr;
+
+use netlink_packet_utils::{Emitable, Parseable};
+
+use crate::address::{
+    AddressAttribute, AddressFlags, AddressHeader, AddressHeaderFlags,
+    AddressMessage, AddressMessageBuffer, AddressMessageBuilder, AddressScope,
+};
+use crate::AddressFamily;
+
+// Captured RTM_NEWADDR request sent by iproute2 6.1 for
+//   ip address add 192.0.2.1/24 broadcast 192.0.2.255 dev veth9a \
+//      label veth9a:0
+// with veth9a holding interface index 11, netlink header removed.
+#[test]
+fn test_address_message_builder_ipv4() {
+    let raw = vec![
+        0x02, // AF_INET
+        0x18, // prefix length 24
+        0x00, // flags
+        0x00, // RT_SCOPE_UNIVERSE
+        0x0b, 0x00, 0x00, 0x00, // interface index 11
+        0x08, 0x00, // length 8
+        0x02, 0x00, // IFA_LOCAL
+        0xc0, 0x00, 0x02, 0x01, // 192.0.2.1
+        0x08, 0x00, // length 8
+        0x04, 0x00, // IFA_BROADCAST
+        0xc0, 0x00, 0x02, 0xff, // 192.0.2.255
+        0x0d, 0x00, // length 13
+        0x03, 0x00, // IFA_LABEL
+        0x76, 0x65, 0x74, 0x68, 0x39, 0x61, 0x3a, 0x30,
+        0x00, // 'veth9a:0\0'
+        0x00, 0x00, 0x00, // padding
+        0x08, 0x00, // length 8
+        0x01, 0x00, // IFA_ADDRESS
+        0xc0, 0x00, 0x02, 0x01, // 192.0.2.1
+    ];
+
+    let address = IpAddr::V4(Ipv4Addr::new(192, 0, 2, 1));
+    let message = AddressMessageBuilder::new()
+        .index(11)
+        .address(address, 24)
+        .broadcast(Ipv4Addr::new(192, 0, 2, 255))
+        .label("veth9a:0".to_string())
+        .build();
+
+    let expected = AddressMessage {
+        header: AddressHeader {
+            family: AddressFamily::Inet,
+            prefix_len: 24,
+            flags: AddressHeaderFlags::empty(),
+            scope: AddressScope::Universe,
+            index: 11,
+        },
+        attributes: vec![
+            AddressAttribute::Local(address),
+            AddressAttribute::Broadcast(Ipv4Addr::new(192, 0, 2, 255)),
+            AddressAttribute::Label("veth9a:0".to_string()),
+            AddressAttribute::Address(address),
+        ],
+    };
+    assert_eq!(message, expected);
+
+    let mut buf = vec![0; message.buffer_len()];
+    message.emit(&mut buf);
+    assert_eq!(buf, raw);
+
+    assert_eq!(
+        expected,
+        AddressMessage::parse(&AddressMessageBuffer::new(&raw)).unwrap()
+    );
+}
+
+// Captured RTM_NEWADDR request sent by iproute2 6.1 for
+//   ip -6 address add 2001:db8::1/64 dev veth9a scope link nodad
+// with veth9a holding interface index 11, netlink header removed.
+// The flags fit in the header, hence no IFA_FLAGS.
+#[test]
+fn test_address_message_builder_ipv6_flags() {
+    let raw = vec![
+        0x0a, // AF_INET6
+        0x40, // prefix length 64
+        0x02, // IFA_F_NODAD
+        0xfd, // RT_SCOPE_LINK
+        0x0b, 0x00, 0x00, 0x00, // interface index 11
+        0x14, 0x00, // length 20
+        0x02, 0x00, // IFA_LOCAL
+        0x20, 0x01, 0x0d, 0xb8, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x01, // 2001:db8::1
+        0x14, 0x00, // length 20
+        0x01, 0x00, // IFA_ADDRESS
+        0x20, 0x01, 0x0d, 0xb8, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x01, // 2001:db8::1
+    ];
+
+    let message = AddressMessageBuilder::new()
+        .index(11)
+        .address(Ipv6Addr::from_str("2001:db8::1").unwrap().into(), 64)
+        .scope(AddressScope::Link)
+        .flags(AddressFlags::Nodad)
+        .build();
+
+    assert_eq!(message.header.family, AddressFamily::Inet6);
+    assert_eq!(message.header.flags, AddressHeaderFlags::Nodad);
+
+    let mut buf = vec![0; message.buffer_len()];
+    message.emit(&mut buf);
+    assert_eq!(buf, raw);
+
+    assert_eq!(
+        message,
+        AddressMessage::parse(&AddressMessageBuffer::new(&raw)).unwrap()
+    );
+}
+
+// Captured RTM_NEWADDR request sent by iproute2 6.1 for
+//   ip -6 address add 2001:db8::1/64 dev veth9a noprefixroute
+// with veth9a holding interface index 11, netlink header removed.
+// IFA_F_NOPREFIXROUTE does not fit in the header, hence IFA_FLAGS.
+#[test]
+fn test_address_message_builder_ipv6_extended_flags() {
+    let raw = vec![
+        0x0a, // AF_INET6
+        0x40, // prefix length 64
+        0x00, // flags
+        0x00, // RT_SCOPE_UNIVERSE
+        0x0b, 0x00, 0x00, 0x00, // interface index 11
+        0x14, 0x00, // length 20
+        0x02, 0x00, // IFA_LOCAL
+        0x20, 0x01, 0x0d, 0xb8, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x01, // 2001:db8::1
+        0x08, 0x00, // length 8
+        0x08, 0x00, // IFA_FLAGS
+        0x00, 0x02, 0x00, 0x00, // IFA_F_NOPREFIXROUTE
+        0x14, 0x00, // length 20
+        0x01, 0x00, // IFA_ADDRESS
+        0x20, 0x01, 0x0d, 0xb8, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x01, // 2001:db8::1
+    ];
+
+    let message = AddressMessageBuilder::new()
+        .index(11)
+        .address(Ipv6Addr::from_str("2001:db8::1").unwrap().into(), 64)
+        .flags(AddressFlags::Noprefixroute)
+        .build();
+
+    assert_eq!(message.header.flags, AddressHeaderFlags::empty());
+
+    let mut buf = vec![0; message.buffer_len()];
+    message.emit(&mut buf);
+    assert_eq!(buf, raw);
+
+    assert_eq!(
+        message,
+        AddressMessage::parse(&AddressMessageBuffer::new(&raw)).unwrap()
+    );
+}
@@ -0,0 +1,126 @@
+// SPDX-License-Identifier: MIT
+
+use std::net::{IpAddr, Ipv4Addr, Ipv6Addr};
+use std::str::FromStr;
+
+use netlink_packet_utils::{
+    nla::NlaBuffer, Emitable, Parseable, ParseableParametrized,
+};
+
+use crate::address::{
+    AddressAttribute, AddressHeader, AddressHeaderFlags, AddressMessage,
+    AddressMessageBuffer, AddressScope,
+};
+use crate::AddressFamily;
+
+// Hand-crafted RTM_NEWADDR payload equivalent to:
+//      ip addr add 192.0.2.1/24 dev eth0
+// with eth0 holding interface index 2.
+#[test]
+fn test_address_family_inet() {
+    let raw = vec![
+        0x02, // AF_INET
+        0x18, // prefix length 24
+        0x00, // flags
+        0x00, // RT_SCOPE_UNIVERSE
+        0x02, 0x00, 0x00, 0x00, // interface index 2
+        0x08, 0x00, 0x01, 0x00, // length 8, IFA_ADDRESS
+        0xc0, 0x00, 0x02, 0x01, // 192.0.2.1
+        0x08, 0x00, 0x02, 0x00, // length 8, IFA_LOCAL
+        0xc0, 0x00, 0x02, 0x01, // 192.0.2.1
+    ];
+
+    let expected = AddressMessage {
+        header: AddressHeader {
+            family: AddressFamily::Inet,
+            prefix_len: 24,
+            flags: AddressHeaderFlags::empty(),
+            scope: AddressScope::Universe,
+            index: 2,
+        },
+        attributes: vec![
+            AddressAttribute::Address(IpAddr::V4(Ipv4Addr::new(192, 0, 2, 1))),
+            AddressAttribute::Local(IpAddr::V4(Ipv4Addr::new(192, 0, 2, 1))),
+        ],
+    };
+
+    assert_eq!(
+        expected,
+        AddressMessage::parse(&AddressMessageBuffer::new(&raw)).unwrap()
+    );
+    assert_eq!(
+        expected.attributes,
+        Vec::<AddressAttribute>::parse(&AddressMessageBuffer::new(&raw))
+            .unwrap()
+    );
+
+    let mut buf = vec![0; expected.buffer_len()];
+    expected.emit(&mut buf);
+    assert_eq!(buf, raw);
+}
+
+// Hand-crafted RTM_NEWADDR payload equivalent to:
+//      ip addr add 2001:db8::1/64 dev eth0
+// with eth0 holding interface index 2.
+#[test]
+fn test_address_family_inet6() {
+    let raw = vec![
+        0x0a, // AF_INET6
+        0x40, // prefix length 64
+        0x00, // flags
+        0x00, // RT_SCOPE_UNIVERSE
+        0x02, 0x00, 0x00, 0x00, // interface index 2
+        0x14, 0x00, 0x01, 0x00, // length 20, IFA_ADDRESS
+        0x20, 0x01, 0x0d, 0xb8, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x01, // 2001:db8::1
+    ];
+
+    let expected = AddressMessage {
+        header: AddressHeader {
+            family: AddressFamily::Inet6,
+            prefix_len: 64,
+            flags: AddressHeaderFlags::empty(),
+            scope: AddressScope::Universe,
+            index: 2,
+        },
+        attributes: vec![AddressAttribute::Address(IpAddr::V6(
+            Ipv6Addr::from_str("2001:db8::1").unwrap(),
+        ))],
+    };
+
+    assert_eq!(
+        expected,
+        AddressMessage::parse(&AddressMessageBuffer::new(&raw)).unwrap()
+    );
+
+    let mut buf = vec![0; expected.buffer_len()];
+    expected.emit(&mut buf);
+    assert_eq!(buf, raw);
+}
+
+// Hand-crafted malformed AF_INET6 RTM_NEWADDR payload holding 4 bytes
+// IFA_ADDRESS.
+#[test]
+fn test_address_family_mismatch() {
+    let raw = vec![
+        0x0a, // AF_INET6
+        0x40, // prefix length 64
+        0x00, // flags
+        0x00, // RT_SCOPE_UNIVERSE
+        0x02, 0x00, 0x00, 0x00, // interface index 2
+        0x08, 0x00, 0x01, 0x00, // length 8, IFA_ADDRESS
+        0xc0, 0x00, 0x02, 0x01, // 192.0.2.1
+    ];
+
+    assert!(AddressMessage::parse(&AddressMessageBuffer::new(&raw)).is_err());
+
+    let nla = NlaBuffer::new_checked(&raw[8..]).unwrap();
+    assert!(
+        AddressAttribute::parse_with_param(&nla, AddressFamily::Inet6).is_err()
+    );
+    // Without address family, the payload length is used to guess
+    assert_eq!(
+        AddressAttribute::parse(&nla).unwrap(),
+        AddressAttribute::Address(IpAddr::V4(Ipv4Addr::new(192, 0, 2, 1)))
+    );
+}
@@ -0,0 +1,28 @@
+// SPDX-License-Identifier: MIT
+
+use crate::address::{AddressFlags, AddressHeaderFlags};
+
+#[test]
+fn test_address_header_flags_promote() {
+    let header_flags =
+        AddressHeaderFlags::Permanent | AddressHeaderFlags::Secondary;
+    assert_eq!(
+        AddressFlags::from(header_flags),
+        AddressFlags::Permanent | AddressFlags::Secondary
+    );
+    assert_eq!(
+        AddressFlags::from(AddressHeaderFlags::empty()),
+        AddressFlags::empty()
+    );
+}
+
+#[test]
+fn test_address_flags_truncate() {
+    let flags = AddressFlags::Permanent
+        | AddressFlags::Noprefixroute
+        | AddressFlags::StablePrivacy;
+    assert_eq!(
+        AddressHeaderFlags::from(flags),
+        AddressHeaderFlags::Permanent
+    );
+}
@@ -55,3 +55,52 @@ fn test_ipv4_get_loopback_address() {
 
     assert_eq!(buf, raw);
 }
+
+// Hand-crafted RTM_NEWADDR request equivalent to
+//   ip address add 192.0.2.1/24 dev eth1 metric 100
+// with eth1 holding interface index 3.
+#[test]
+fn test_ipv4_add_address_with_metric() {
+    let raw = vec![
+        0x02, // AF_INET
+        0x18, // prefix length 24
+        0x00, // flags
+        0x00, // RT_SCOPE_UNIVERSE
+        0x03, 0x00, 0x00, 0x00, // interface index 3
+        0x08, 0x00, // length 8
+        0x02, 0x00, // IFA_LOCAL
+        0xc0, 0x00, 0x02, 0x01, // 192.0.2.1
+        0x08, 0x00, // length 8
+        0x01, 0x00, // IFA_ADDRESS
+        0xc0, 0x00, 0x02, 0x01, // 192.0.2.1
+        0x08, 0x00, // length 8
+        0x09, 0x00, // IFA_RT_PRIORITY
+        0x64, 0x00, 0x00, 0x00, // 100
+    ];
+
+    let expected = AddressMessage {
+        header: AddressHeader {
+            family: AddressFamily::Inet,
+            prefix_len: 24,
+            flags: AddressHeaderFlags::empty(),
+            scope: AddressScope::Universe,
+            index: 3,
+        },
+        attributes: vec![
+            AddressAttribute::Local(IpAddr::V4(Ipv4Addr::new(192, 0, 2, 1))),
+            AddressAttribute::Address(IpAddr::V4(Ipv4Addr::new(192, 0, 2, 1))),
+            AddressAttribute::RoutePriority(100),
+        ],
+    };
+
+    let parsed =
+        AddressMessage::parse(&AddressMessageBuffer::new(&raw)).unwrap();
+    assert_eq!(parsed, expected);
+    assert_eq!(parsed.route_priority(), Some(100));
+
+    let mut buf = vec![0; expected.buffer_len()];
+    expected.emit(&mut buf);
+    assert_eq!(buf, raw);
+
+    assert_eq!(AddressMessage::default().route_priority(), None);
+}
@@ -1,5 +1,11 @@
 // SPDX-License-Identifier: MIT
 
+#[cfg(test)]
+mod builder;
+#[cfg(test)]
+mod family;
+#[cfg(test)]
+mod flags;
 #[cfg(test)]
 mod ipv4;
 #[cfg(test)]
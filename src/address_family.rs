@@ -0,0 +1,25 @@
+// SPDX-License-Identifier: MIT
+
+// Methods shared by the per-platform definitions of `AddressFamily`.
+
+use crate::{
+    ip::{IPV4_ADDR_LEN, IPV6_ADDR_LEN},
+    AddressFamily,
+};
+
+impl AddressFamily {
+    /// Whether this is `AF_INET` or `AF_INET6`.
+    pub fn is_ip(&self) -> bool {
+        matches!(self, Self::Inet | Self::Inet6)
+    }
+
+    /// Length in bytes of an IP address of this family, `None` if this is
+    /// neither `AF_INET` nor `AF_INET6`.
+    pub fn address_len(&self) -> Option<usize> {
+        match self {
+            Self::Inet => Some(IPV4_ADDR_LEN),
+            Self::Inet6 => Some(IPV6_ADDR_LEN),
+            _ => None,
+        }
+    }
+}
@@ -1,6 +1,7 @@
 // SPDX-License-Identifier: MIT
 
 #[derive(Debug, PartialEq, Eq, Clone, Copy, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[non_exhaustive]
 // We are using not using #[repr(u8)] here as we have duplicate(e.g. AF_ROUTE vs
 // AF_NETLINK) here
@@ -39,3 +40,27 @@ impl From<AddressFamily> for u8 {
         }
     }
 }
+
+impl AddressFamily {
+    /// Name of this family as used by iproute2, e.g. `inet`, `inet6`,
+    /// `bridge`. Unknown family is named `other`.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Unspec => "unspec",
+            Self::Local => "local",
+            Self::Unix => "unix",
+            Self::Inet => "inet",
+            Self::Inet6 => "inet6",
+            Self::Other(_) => "other",
+        }
+    }
+}
+
+impl std::fmt::Display for AddressFamily {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Other(d) => write!(f, "other({d})"),
+            _ => write!(f, "{}", self.as_str()),
+        }
+    }
+}
@@ -1,6 +1,7 @@
 // SPDX-License-Identifier: MIT
 
 #[derive(Debug, PartialEq, Eq, Clone, Copy, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[non_exhaustive]
 pub enum AddressFamily {
     #[default]
@@ -115,3 +116,53 @@ impl From<AddressFamily> for u8 {
         }
     }
 }
+
+impl AddressFamily {
+    /// Name of this family as used by iproute2, e.g. `inet`, `inet6`,
+    /// `bridge`. Unknown family is named `other`.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Unspec => "unspec",
+            Self::Local => "local",
+            Self::Unix => "unix",
+            Self::Inet => "inet",
+            Self::Implink => "implink",
+            Self::Pup => "pup",
+            Self::Chaos => "chaos",
+            Self::Netbios => "netbios",
+            Self::Iso => "iso",
+            Self::Osi => "osi",
+            Self::Ecma => "ecma",
+            Self::Datakit => "datakit",
+            Self::Ccitt => "ccitt",
+            Self::Sna => "sna",
+            Self::Decnet => "dnet",
+            Self::Dli => "dli",
+            Self::Lat => "lat",
+            Self::Hylink => "hylink",
+            Self::Appletalk => "appletalk",
+            Self::Route => "route",
+            Self::Link => "link",
+            Self::Coip => "coip",
+            Self::Cnt => "cnt",
+            Self::Ipx => "ipx",
+            Self::Sip => "sip",
+            Self::Isdn => "isdn",
+            Self::E164 => "e164",
+            Self::Inet6 => "inet6",
+            Self::Natm => "natm",
+            Self::Atm => "atm",
+            Self::Netgraph => "netgraph",
+            Self::Other(_) => "other",
+        }
+    }
+}
+
+impl std::fmt::Display for AddressFamily {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Other(d) => write!(f, "other({d})"),
+            _ => write!(f, "{}", self.as_str()),
+        }
+    }
+}
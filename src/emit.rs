@@ -0,0 +1,96 @@
+// SPDX-License-Identifier: MIT
+
+use netlink_packet_utils::{nla::Nla, Emitable};
+
+/// Emit `msg` into `buf` and return the number of bytes written.
+///
+/// The `buf` is resized to [Emitable::buffer_len()] and zeroed before
+/// emitting, so a single `Vec` could be reused across sends without
+/// reallocation as long as its capacity is big enough. Zeroing is required
+/// as some emitters skip reserved fields and padding.
+///
+/// ```
+/// use netlink_packet_route::{emit_to_vec, link::LinkMessageBuilder};
+///
+/// let mut buf = Vec::with_capacity(4096);
+/// for index in 1..4 {
+///     let msg = LinkMessageBuilder::new().index(index).up().build();
+///     let len = emit_to_vec(&msg, &mut buf);
+///     assert_eq!(len, buf.len());
+///     // send(&buf) ...
+/// }
+/// ```
+pub fn emit_to_vec<T: Emitable + ?Sized>(msg: &T, buf: &mut Vec<u8>) -> usize {
+    let len = msg.buffer_len();
+    buf.clear();
+    buf.resize(len, 0);
+    msg.emit(buf.as_mut_slice());
+    len
+}
+
+/// Call [Nla::emit_value()] of `nla`, checking in debug builds that it fills
+/// exactly [Nla::value_len()] bytes.
+///
+/// Writing past `value_len()` already panics on slice bounds, but writing
+/// less silently leaves part of the attribute uninitialized. To catch that,
+/// the value is emitted into `buffer` pre-filled with `0x00` and into a
+/// scratch buffer pre-filled with `0xff`: any byte differing between the two
+/// was never written by `emit_value()`, hence reserved fields and padding
+/// must be written explicitly. Nested checked emits are only verified by the
+/// outermost call, so each value is emitted twice at most.
+pub(crate) fn emit_value_checked<T: Nla + ?Sized>(nla: &T, buffer: &mut [u8]) {
+    #[cfg(debug_assertions)]
+    if let Some(_guard) = debug_check::TopLevelGuard::enter() {
+        let len = nla.value_len();
+        debug_assert_eq!(
+            buffer.len(),
+            len,
+            "attribute {} emitted into buffer of wrong size",
+            nla.kind()
+        );
+        buffer.fill(0x00);
+        nla.emit_value(buffer);
+        let mut filled = vec![0xffu8; len];
+        nla.emit_value(&mut filled);
+        if let Some(pos) =
+            buffer.iter().zip(filled.iter()).position(|(a, b)| a != b)
+        {
+            panic!(
+                "attribute {} left byte {pos} of its value_len() {len} \
+                 unwritten",
+                nla.kind()
+            );
+        }
+        return;
+    }
+    nla.emit_value(buffer)
+}
+
+#[cfg(debug_assertions)]
+mod debug_check {
+    use std::cell::Cell;
+
+    thread_local! {
+        static CHECKING: Cell<bool> = const { Cell::new(false) };
+    }
+
+    // Marks the current thread as inside a checked emit until dropped, even
+    // when the emit panics.
+    pub(super) struct TopLevelGuard;
+
+    impl TopLevelGuard {
+        pub(super) fn enter() -> Option<Self> {
+            if CHECKING.with(|c| c.replace(true)) {
+                None
+            } else {
+                Some(Self)
+            }
+        }
+    }
+
+    impl Drop for TopLevelGuard {
+        fn drop(&mut self) {
+            CHECKING.with(|c| c.set(false));
+        }
+    }
+}
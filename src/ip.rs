@@ -104,6 +104,7 @@ const IPPROTO_RAW: i32 = 255;
 const IPPROTO_MPTCP: i32 = 262;
 
 #[derive(Debug, PartialEq, Eq, Clone, Copy, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[non_exhaustive]
 pub enum IpProtocol {
     Hopopts,
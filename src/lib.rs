@@ -1,21 +1,83 @@
 // SPDX-License-Identifier: MIT
 
+//! The `netlink-packet-route` crate is designed to abstract Netlink route
+//! protocol(`rtnetlink`) packet into Rust data types. The goal of this crate is
+//! saving netlink user from reading Kernel Netlink codes.
+//!
+//! This crate grouped Netlink route protocol into these modules:
+//!  * `link`: NIC interface, similar to to `ip link` command.
+//!  * `address`: IP address, similar to `ip address` command.
+//!  * `route`: Route, similar to `ip route` command.
+//!  * `rule`: Route rule, similar to `ip rule` command.
+//!  * `tc`: Traffic control, similar to `tc` command.
+//!  * `neighbour`: Neighbour, similar to `ip neighbour` command.
+//!  * `neighbour_table`: Neighbour table, similar to `ip ntable` command.
+//!  * `nexthop`: Nexthop object, similar to `ip nexthop` command.
+//!  * `nsid`: Namespace, similar to `ip netns` command.
+//!  * `tunnel`: VXLAN VNI filter, similar to `bridge vni` command.
+//!  * `mdb`: Bridge multicast group database, similar to `bridge mdb` command.
+//!  * `vlan`: Bridge per-VLAN options, similar to `bridge vlan` command.
+//!
+//! At the top level of this crate, we also provide:
+//!  * [AddressFamily]
+//!
+//! Attributes not supported by this crate yet are stored in the `Other`
+//! variant of each attribute enum holding
+//! `netlink_packet_utils::nla::DefaultNla`. The same variant could be used
+//! to emit arbitrary attribute with known kind and raw value:
+//!
+//! ```
+//! use netlink_packet_route::link::{LinkAttribute, LinkMessage};
+//! use netlink_packet_utils::{nla::DefaultNla, Emitable};
+//!
+//! let mut message = LinkMessage::default();
+//! message.header.index = 2;
+//! message
+//!     .attributes
+//!     .push(LinkAttribute::Other(DefaultNla::new(1000, vec![0x01, 0x02])));
+//!
+//! let mut buf = vec![0; message.buffer_len()];
+//! message.emit(&mut buf);
+//! assert_eq!(&buf[16..], &[0x06, 0x00, 0xe8, 0x03, 0x01, 0x02, 0x00, 0x00]);
+//! ```
+//!
+//! Normally, you should use [`rtnetlink`][rtnetlink_url] instead of using this
+//! crate directly.
+//!
+//! [rtnetlink_url]: https://docs.rs/rtnetlink
+
+#[macro_use]
+mod macros;
+
 pub mod address;
 pub mod link;
+pub mod mdb;
 pub mod neighbour;
 pub mod neighbour_table;
+pub mod net;
+pub mod nexthop;
 pub mod nsid;
 pub mod prefix;
 pub mod route;
 pub mod rule;
+pub mod stats;
 pub mod tc;
+pub mod tunnel;
+pub mod vlan;
 
+mod emit;
 mod message;
+mod strict;
 #[cfg(test)]
 mod tests;
 
 pub(crate) mod ip;
+#[cfg(feature = "serde")]
+mod serde_hex;
+#[cfg(feature = "serde")]
+mod serde_nla;
 
+mod address_family;
 #[cfg(any(target_os = "linux", target_os = "fuchsia"))]
 mod address_family_linux;
 #[cfg(any(target_os = "linux", target_os = "fuchsia"))]
@@ -39,30 +101,12 @@ mod address_family_fallback;
 )))]
 pub use self::address_family_fallback::AddressFamily;
 
+pub use self::emit::emit_to_vec;
 pub use self::ip::IpProtocol;
-pub use self::message::{RouteNetlinkMessage, RouteNetlinkMessageBuffer};
-
-/// The `netlink-packet-route` crate is designed to abstract Netlink route
-/// protocol(`rtnetlink`) packet into Rust data types. The goal of this crate is
-/// saving netlink user from reading Kernel Netlink codes.
-///
-/// This crate grouped Netlink route protocol into these modules:
-///  * `link`: NIC interface, similar to to `ip link` command.
-///  * `address`: IP address, similar to `ip address` command.
-///  * `route`: Route, similar to `ip route` command.
-///  * `rule`: Route rule, similar to `ip rule` command.
-///  * `tc`: Traffic control, similar to `tc` command.
-///  * `neighbour`: Neighbour, similar to `ip neighbour` command.
-///  * `neighbour_table`: Neighbour table, similar to `ip ntable` command.
-///  * `nsid`: Namespace, similar to `ip netns` command.
-///
-/// At the top level of this crate, we also provide:
-///  * [AddressFamily]
-///
-/// Normally, you should use [`rtnetlink`][rtnetlink_url] instead of using this
-/// crate directly.
-///
-/// [rtnetlink_url]: https://docs.rs/rtnetlink
+pub use self::message::{
+    AttributeLocation, RouteNetlinkMessage, RouteNetlinkMessageBuffer,
+};
+pub use self::strict::{is_strict_parsing, set_strict_parsing};
 
 #[macro_use]
 extern crate netlink_packet_utils;
@@ -18,12 +18,14 @@ const IFLA_BRIDGE_VLAN_TUNNEL_INFO: u16 = 3;
 // const IFLA_BRIDGE_MST: u16 = 6;
 
 #[derive(Clone, Eq, PartialEq, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[non_exhaustive]
 pub enum AfSpecBridge {
     Flags(BridgeFlag),
     Mode(BridgeMode),
     VlanInfo(BridgeVlanInfo),
     VlanTunnelInfo(Vec<BridgeVlanTunnelInfo>),
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_nla"))]
     Other(DefaultNla),
 }
 
@@ -102,6 +104,74 @@ impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>> for AfSpecBridge {
     }
 }
 
+impl AfSpecBridge {
+    /// Build [AfSpecBridge::VlanTunnelInfo] entries mapping VLAN IDs to
+    /// tunnel IDs(e.g. VxLAN VNI) in the compact form `bridge vlan add ...
+    /// tunnel_info` uses.
+    ///
+    /// Consecutive mappings where both VID and tunnel ID increase by one are
+    /// merged into a pair of entries flagged with
+    /// [BridgeVlanInfoFlags::RangeBegin] and [BridgeVlanInfoFlags::RangeEnd],
+    /// other mappings are emitted as single entry without flags.
+    ///
+    /// ```
+    /// use netlink_packet_route::link::AfSpecBridge;
+    ///
+    /// // VIDs 100-110 to VNIs 1000-1010 plus VID 200 to VNI 5000
+    /// let mappings = (100..=110).map(|vid| (vid, 900 + u32::from(vid)));
+    /// let entries =
+    ///     AfSpecBridge::vlan_tunnel_info_ranges(mappings.chain([(200, 5000)]));
+    /// assert_eq!(entries.len(), 3);
+    /// ```
+    pub fn vlan_tunnel_info_ranges(
+        mappings: impl IntoIterator<Item = (u16, u32)>,
+    ) -> Vec<Self> {
+        let mut ret = Vec::new();
+        let mut mappings = mappings.into_iter().peekable();
+        while let Some((first_vid, first_id)) = mappings.next() {
+            let (mut last_vid, mut last_id) = (first_vid, first_id);
+            while let Some(&(vid, id)) = mappings.peek() {
+                if Some(vid) == last_vid.checked_add(1)
+                    && Some(id) == last_id.checked_add(1)
+                {
+                    (last_vid, last_id) = (vid, id);
+                    mappings.next();
+                } else {
+                    break;
+                }
+            }
+            if first_vid == last_vid {
+                ret.push(Self::vlan_tunnel_info(
+                    first_vid,
+                    first_id,
+                    BridgeVlanInfoFlags::empty(),
+                ));
+            } else {
+                ret.push(Self::vlan_tunnel_info(
+                    first_vid,
+                    first_id,
+                    BridgeVlanInfoFlags::RangeBegin,
+                ));
+                ret.push(Self::vlan_tunnel_info(
+                    last_vid,
+                    last_id,
+                    BridgeVlanInfoFlags::RangeEnd,
+                ));
+            }
+        }
+        ret
+    }
+
+    // Use the same attribute order as kernel and iproute2
+    fn vlan_tunnel_info(vid: u16, id: u32, flags: BridgeVlanInfoFlags) -> Self {
+        Self::VlanTunnelInfo(vec![
+            BridgeVlanTunnelInfo::Id(id),
+            BridgeVlanTunnelInfo::Vid(vid),
+            BridgeVlanTunnelInfo::Flags(flags),
+        ])
+    }
+}
+
 #[cfg(any(target_os = "linux", target_os = "fuchsia"))]
 pub(crate) struct VecAfSpecBridge(pub(crate) Vec<AfSpecBridge>);
 
@@ -131,6 +201,7 @@ const BRIDGE_VLAN_INFO_ONLY_OPTS: u16 = 1 << 6;
 bitflags! {
     #[non_exhaustive]
     #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+    #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
     pub struct BridgeVlanInfoFlags: u16 {
         /// Operate on Bridge device as well
         const Controller = BRIDGE_VLAN_INFO_CONTROLLER;
@@ -155,6 +226,7 @@ impl BridgeVlanInfoFlags {
 }
 
 #[derive(Debug, PartialEq, Eq, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct BridgeVlanInfo {
     pub flags: BridgeVlanInfoFlags,
     pub vid: u16,
@@ -201,6 +273,7 @@ const BRIDGE_FLAGS_CONTROLLER: u16 = 1;
 const BRIDGE_FLAGS_SELF: u16 = 2;
 
 #[derive(Clone, Copy, Eq, PartialEq, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[non_exhaustive]
 pub enum BridgeFlag {
     /// Bridge command to/from controller
@@ -238,6 +311,7 @@ const BRIDGE_MODE_VEB: u16 = 0;
 const BRIDGE_MODE_VEPA: u16 = 1;
 
 #[derive(Clone, Copy, Eq, PartialEq, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[non_exhaustive]
 pub enum BridgeMode {
     /// Default loopback mode
@@ -276,11 +350,13 @@ const IFLA_BRIDGE_VLAN_TUNNEL_VID: u16 = 2;
 const IFLA_BRIDGE_VLAN_TUNNEL_FLAGS: u16 = 3;
 
 #[derive(Clone, Eq, PartialEq, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[non_exhaustive]
 pub enum BridgeVlanTunnelInfo {
     Id(u32),
     Vid(u16),
     Flags(BridgeVlanInfoFlags),
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_nla"))]
     Other(DefaultNla),
 }
 
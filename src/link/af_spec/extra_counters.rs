@@ -0,0 +1,36 @@
+// SPDX-License-Identifier: MIT
+
+use byteorder::{ByteOrder, NativeEndian};
+use netlink_packet_utils::DecodeError;
+
+const COUNTER_LEN: usize = 8;
+
+// Counters appended by newer kernel to `Inet6Stats` or `Icmp6Stats` which are
+// unknown to this crate.
+pub(crate) fn parse_extra_counters(
+    raw: &[u8],
+) -> Result<Vec<u64>, DecodeError> {
+    if !raw.len().is_multiple_of(COUNTER_LEN) {
+        return Err(format!(
+            "trailing counters length {} is not multiple of {COUNTER_LEN}",
+            raw.len()
+        )
+        .into());
+    }
+    Ok(raw
+        .chunks_exact(COUNTER_LEN)
+        .map(NativeEndian::read_u64)
+        .collect())
+}
+
+pub(crate) fn extra_counters_len(counters: &[u64]) -> usize {
+    counters.len() * COUNTER_LEN
+}
+
+pub(crate) fn emit_extra_counters(counters: &[u64], buffer: &mut [u8]) {
+    for (counter, chunk) in
+        counters.iter().zip(buffer.chunks_exact_mut(COUNTER_LEN))
+    {
+        NativeEndian::write_u64(chunk, *counter);
+    }
+}
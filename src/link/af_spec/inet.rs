@@ -16,9 +16,11 @@ const IPV4_DEVCONF_MAX: usize = __IPV4_DEVCONF_MAX - 1;
 const DEV_CONF_LEN: usize = IPV4_DEVCONF_MAX * 4;
 
 #[derive(Clone, Eq, PartialEq, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[non_exhaustive]
 pub enum AfSpecInet {
     DevConf(InetDevConf),
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_nla"))]
     Other(DefaultNla),
 }
 
@@ -124,6 +126,7 @@ buffer!(InetDevConfBuffer(DEV_CONF_LEN) {
 });
 
 #[derive(Clone, Copy, Eq, PartialEq, Debug, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[non_exhaustive]
 pub struct InetDevConf {
     pub forwarding: i32,
@@ -12,15 +12,16 @@ use netlink_packet_utils::{
 };
 
 use super::super::{
-    buffer_tool::expand_buffer_if_small, Icmp6Stats, Icmp6StatsBuffer,
-    Inet6CacheInfo, Inet6CacheInfoBuffer, Inet6DevConf, Inet6DevConfBuffer,
-    Inet6IfaceFlags, Inet6Stats, Inet6StatsBuffer,
+    buffer_tool::{expand_buffer_if_small, pad_buffer_if_small},
+    Icmp6Stats, Icmp6StatsBuffer, Inet6CacheInfo, Inet6CacheInfoBuffer,
+    Inet6DevConf, Inet6DevConfBuffer, Inet6IfaceFlags, Inet6Stats,
+    Inet6StatsBuffer,
 };
 use super::{
     inet6_devconf::LINK_INET6_DEV_CONF_LEN, inet6_icmp::ICMP6_STATS_LEN,
     inet6_stats::INET6_STATS_LEN,
 };
-use crate::ip::parse_ipv6_addr;
+use crate::{ip::parse_ipv6_addr, is_strict_parsing};
 
 const IFLA_INET6_FLAGS: u16 = 1;
 const IFLA_INET6_CONF: u16 = 2;
@@ -34,6 +35,7 @@ const IFLA_INET6_ADDR_GEN_MODE: u16 = 8;
 const IFLA_INET6_RA_MTU: u16 = 9;
 
 #[derive(Clone, Eq, PartialEq, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[non_exhaustive]
 pub enum AfSpecInet6 {
     //TODO(Gris Ge): Use Vec<enum> for `IFF_UP` and etc
@@ -45,6 +47,7 @@ pub enum AfSpecInet6 {
     Token(Ipv6Addr),
     AddrGenMode(u8),
     RaMtu(u32),
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_nla"))]
     Other(DefaultNla),
 }
 
@@ -140,14 +143,29 @@ impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>> for AfSpecInet6 {
                     payload
                 ))?,
             ),
+            IFLA_INET6_STATS if is_strict_parsing() => Stats(
+                Inet6Stats::parse(
+                    &Inet6StatsBuffer::new_strict(payload)
+                        .context("invalid IFLA_INET6_STATS value")?,
+                )
+                .context(format!(
+                    "invalid IFLA_INET6_STATS value {:?}",
+                    payload
+                ))?,
+            ),
+            IFLA_INET6_ICMP6STATS if is_strict_parsing() => Icmp6Stats(
+                super::super::Icmp6Stats::parse(
+                    &Icmp6StatsBuffer::new_strict(payload)
+                        .context("invalid IFLA_INET6_ICMP6STATS value")?,
+                )
+                .context(format!(
+                    "invalid IFLA_INET6_ICMP6STATS value {:?}",
+                    payload
+                ))?,
+            ),
             IFLA_INET6_STATS => Stats(
                 Inet6Stats::parse(&Inet6StatsBuffer::new(
-                    expand_buffer_if_small(
-                        payload,
-                        INET6_STATS_LEN,
-                        "IFLA_INET6_STATS",
-                    )
-                    .as_slice(),
+                    pad_buffer_if_small(payload, INET6_STATS_LEN).as_slice(),
                 ))
                 .context(format!(
                     "invalid IFLA_INET6_STATS value {:?}",
@@ -156,12 +174,7 @@ impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>> for AfSpecInet6 {
             ),
             IFLA_INET6_ICMP6STATS => Icmp6Stats(
                 super::super::Icmp6Stats::parse(&Icmp6StatsBuffer::new(
-                    expand_buffer_if_small(
-                        payload,
-                        ICMP6_STATS_LEN,
-                        "IFLA_INET6_ICMP6STATS",
-                    )
-                    .as_slice(),
+                    pad_buffer_if_small(payload, ICMP6_STATS_LEN).as_slice(),
                 ))
                 .context(format!(
                     "invalid IFLA_INET6_ICMP6STATS value {:?}",
@@ -6,6 +6,7 @@ use netlink_packet_utils::{
 };
 
 #[derive(Clone, Copy, Eq, PartialEq, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[non_exhaustive]
 pub struct Inet6CacheInfo {
     pub max_reasm_len: i32,
@@ -217,6 +217,7 @@ impl Emitable for Inet6DevConf {
 }
 
 #[derive(Clone, Copy, Eq, PartialEq, Debug, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[non_exhaustive]
 pub struct Inet6DevConf {
     pub forwarding: i32,
@@ -5,9 +5,20 @@ use netlink_packet_utils::{
     DecodeError,
 };
 
+use super::extra_counters::{
+    emit_extra_counters, extra_counters_len, parse_extra_counters,
+};
+
 pub(crate) const ICMP6_STATS_LEN: usize = 48;
 
-#[derive(Clone, Copy, Eq, PartialEq, Debug, Default)]
+/// ICMPv6 statistics of `IFLA_INET6_ICMP6STATS`.
+///
+/// When parsed from link message, counters appended by newer kernel are
+/// stored in `extra_counters` and shorter data is padded with zero. With
+/// [crate::set_strict_parsing()] enabled, data of any other size than the 6
+/// known counters is rejected.
+#[derive(Clone, Eq, PartialEq, Debug, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[non_exhaustive]
 pub struct Icmp6Stats {
     pub num: i64,
@@ -16,6 +27,9 @@ pub struct Icmp6Stats {
     pub out_msgs: i64,
     pub out_errors: i64,
     pub csum_errors: i64,
+    /// Counters appended by newer kernel which are unknown to this crate,
+    /// preserved for lossless emit.
+    pub extra_counters: Vec<u64>,
 }
 
 buffer!(Icmp6StatsBuffer(ICMP6_STATS_LEN) {
@@ -25,10 +39,15 @@ buffer!(Icmp6StatsBuffer(ICMP6_STATS_LEN) {
     out_msgs: (i64, 24..32),
     out_errors: (i64, 32..40),
     csum_errors: (i64, 40..48),
+    extra_counters: (slice, ICMP6_STATS_LEN..),
 });
 
-impl<T: AsRef<[u8]>> Parseable<Icmp6StatsBuffer<T>> for Icmp6Stats {
-    fn parse(buf: &Icmp6StatsBuffer<T>) -> Result<Self, DecodeError> {
+impl_new_strict!(Icmp6StatsBuffer, ICMP6_STATS_LEN);
+
+impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<Icmp6StatsBuffer<&'a T>>
+    for Icmp6Stats
+{
+    fn parse(buf: &Icmp6StatsBuffer<&'a T>) -> Result<Self, DecodeError> {
         Ok(Self {
             num: buf.num(),
             in_msgs: buf.in_msgs(),
@@ -36,13 +55,14 @@ impl<T: AsRef<[u8]>> Parseable<Icmp6StatsBuffer<T>> for Icmp6Stats {
             out_msgs: buf.out_msgs(),
             out_errors: buf.out_errors(),
             csum_errors: buf.csum_errors(),
+            extra_counters: parse_extra_counters(buf.extra_counters())?,
         })
     }
 }
 
 impl Emitable for Icmp6Stats {
     fn buffer_len(&self) -> usize {
-        ICMP6_STATS_LEN
+        ICMP6_STATS_LEN + extra_counters_len(&self.extra_counters)
     }
 
     fn emit(&self, buffer: &mut [u8]) {
@@ -53,5 +73,6 @@ impl Emitable for Icmp6Stats {
         buffer.set_out_msgs(self.out_msgs);
         buffer.set_out_errors(self.out_errors);
         buffer.set_csum_errors(self.csum_errors);
+        emit_extra_counters(&self.extra_counters, buffer.extra_counters_mut());
     }
 }
@@ -8,6 +8,7 @@ const IF_READY: u32 = 0x80000000;
 
 bitflags! {
     #[derive(Debug, PartialEq, Eq, Clone, Copy)]
+    #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
     #[non_exhaustive]
     pub struct Inet6IfaceFlags : u32 {
         const Otherconf = IF_RA_OTHERCONF;
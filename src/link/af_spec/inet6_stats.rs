@@ -5,6 +5,10 @@ use netlink_packet_utils::{
     DecodeError,
 };
 
+use super::extra_counters::{
+    emit_extra_counters, extra_counters_len, parse_extra_counters,
+};
+
 pub(crate) const INET6_STATS_LEN: usize = 288;
 
 buffer!(Inet6StatsBuffer(INET6_STATS_LEN) {
@@ -44,9 +48,19 @@ buffer!(Inet6StatsBuffer(INET6_STATS_LEN) {
     in_ect1_pkts: (i64, 264..272),
     in_ect0_pkts: (i64, 272..280),
     in_ce_pkts: (i64, 280..288),
+    extra_counters: (slice, INET6_STATS_LEN..),
 });
 
-#[derive(Clone, Copy, Eq, PartialEq, Debug, Default)]
+impl_new_strict!(Inet6StatsBuffer, INET6_STATS_LEN);
+
+/// IPv6 statistics of `IFLA_INET6_STATS`.
+///
+/// When parsed from link message, counters appended by newer kernel are
+/// stored in `extra_counters` and shorter data is padded with zero. With
+/// [crate::set_strict_parsing()] enabled, data of any other size than the 36
+/// known counters is rejected.
+#[derive(Clone, Eq, PartialEq, Debug, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[non_exhaustive]
 pub struct Inet6Stats {
     pub num: i64,
@@ -85,10 +99,15 @@ pub struct Inet6Stats {
     pub in_ect1_pkts: i64,
     pub in_ect0_pkts: i64,
     pub in_ce_pkts: i64,
+    /// Counters appended by newer kernel which are unknown to this crate,
+    /// preserved for lossless emit.
+    pub extra_counters: Vec<u64>,
 }
 
-impl<T: AsRef<[u8]>> Parseable<Inet6StatsBuffer<T>> for Inet6Stats {
-    fn parse(buf: &Inet6StatsBuffer<T>) -> Result<Self, DecodeError> {
+impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<Inet6StatsBuffer<&'a T>>
+    for Inet6Stats
+{
+    fn parse(buf: &Inet6StatsBuffer<&'a T>) -> Result<Self, DecodeError> {
         Ok(Self {
             num: buf.num(),
             in_pkts: buf.in_pkts(),
@@ -126,13 +145,14 @@ impl<T: AsRef<[u8]>> Parseable<Inet6StatsBuffer<T>> for Inet6Stats {
             in_ect1_pkts: buf.in_ect1_pkts(),
             in_ect0_pkts: buf.in_ect0_pkts(),
             in_ce_pkts: buf.in_ce_pkts(),
+            extra_counters: parse_extra_counters(buf.extra_counters())?,
         })
     }
 }
 
 impl Emitable for Inet6Stats {
     fn buffer_len(&self) -> usize {
-        INET6_STATS_LEN
+        INET6_STATS_LEN + extra_counters_len(&self.extra_counters)
     }
 
     fn emit(&self, buffer: &mut [u8]) {
@@ -173,5 +193,6 @@ impl Emitable for Inet6Stats {
         buffer.set_in_ect1_pkts(self.in_ect1_pkts);
         buffer.set_in_ect0_pkts(self.in_ect0_pkts);
         buffer.set_in_ce_pkts(self.in_ce_pkts);
+        emit_extra_counters(&self.extra_counters, buffer.extra_counters_mut());
     }
 }
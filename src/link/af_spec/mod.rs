@@ -1,6 +1,7 @@
 // SPDX-License-Identifier: MIT
 
 mod bridge;
+mod extra_counters;
 mod inet;
 mod inet6;
 mod inet6_cache;
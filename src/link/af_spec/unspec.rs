@@ -36,10 +36,12 @@ use crate::AddressFamily;
 //                 [DEVCONF_NDISC_EVICT_NOCARRIER] = 1]]]]]]
 
 #[derive(Clone, Eq, PartialEq, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[non_exhaustive]
 pub enum AfSpecUnspec {
     Inet(Vec<AfSpecInet>),
     Inet6(Vec<AfSpecInet6>),
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_nla"))]
     Other(DefaultNla),
 }
 
@@ -25,12 +25,12 @@ use super::{
     stats::LINK_STATS_LEN,
     stats64::LINK_STATS64_LEN,
     xdp::VecLinkXdp,
-    AfSpecBridge, AfSpecUnspec, LinkEvent, LinkExtentMask, LinkInfo,
-    LinkPhysId, LinkProtoInfoBridge, LinkProtoInfoInet6,
+    AfSpecBridge, AfSpecUnspec, LinkDevlinkPort, LinkEvent, LinkExtentMask,
+    LinkInfo, LinkPhysId, LinkProtoInfoBridge, LinkProtoInfoInet6,
     LinkProtocolDownReason, LinkVfInfo, LinkVfPort, LinkWirelessEvent, LinkXdp,
     Map, MapBuffer, Prop, State, Stats, Stats64, Stats64Buffer, StatsBuffer,
 };
-use crate::AddressFamily;
+use crate::{is_strict_parsing, AddressFamily};
 
 const IFLA_ADDRESS: u16 = 1;
 const IFLA_BROADCAST: u16 = 2;
@@ -93,14 +93,17 @@ const IFLA_PROTO_DOWN_REASON: u16 = 55;
 /* TODO:(Gris Ge)
 const IFLA_PARENT_DEV_NAME: u16 = 56;
 const IFLA_PARENT_DEV_BUS_NAME: u16 = 57;
-const IFLA_GRO_MAX_SIZE: u16 = 58;
 const IFLA_TSO_MAX_SIZE: u16 = 59;
 const IFLA_TSO_MAX_SEGS: u16 = 60;
 const IFLA_ALLMULTI: u16 = 61;
-const IFLA_DEVLINK_PORT: u16 = 62;
 */
+const IFLA_GRO_MAX_SIZE: u16 = 58;
+const IFLA_DEVLINK_PORT: u16 = 62;
+const IFLA_GSO_IPV4_MAX_SIZE: u16 = 63;
+const IFLA_GRO_IPV4_MAX_SIZE: u16 = 64;
 
 #[derive(Debug, PartialEq, Eq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[non_exhaustive]
 pub enum LinkAttribute {
     VfInfoList(Vec<LinkVfInfo>),
@@ -119,13 +122,19 @@ pub enum LinkAttribute {
     Wireless(LinkWirelessEvent),
     ProtoInfoBridge(Vec<LinkProtoInfoBridge>),
     ProtoInfoInet6(Vec<LinkProtoInfoInet6>),
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_nla"))]
     ProtoInfoUnknown(DefaultNla),
     PropList(Vec<Prop>),
     ProtoDownReason(Vec<LinkProtocolDownReason>),
+    /// Handle of the devlink port backing this interface.
+    DevlinkPort(Vec<LinkDevlinkPort>),
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_hex"))]
     Address(Vec<u8>),
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_hex"))]
     Broadcast(Vec<u8>),
     /// Permanent hardware address of the device. The provides the same
     /// information as the ethtool ioctl interface.
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_hex"))]
     PermAddress(Vec<u8>),
     IfName(String),
     Qdisc(String),
@@ -149,6 +158,12 @@ pub enum LinkAttribute {
     CarrierChanges(u32),
     GsoMaxSegs(u32),
     GsoMaxSize(u32),
+    /// Maximum size of GRO packet the device would aggregate.
+    GroMaxSize(u32),
+    /// Maximum size of IPv4 GSO packet the device would build.
+    GsoIpv4MaxSize(u32),
+    /// Maximum size of IPv4 GRO packet the device would aggregate.
+    GroIpv4MaxSize(u32),
     /// The minimum MTU for the device.
     MinMtu(u32),
     /// The maximum MTU for the device.
@@ -162,7 +177,11 @@ pub enum LinkAttribute {
     // message)
     AfSpecUnspec(Vec<AfSpecUnspec>),
     AfSpecBridge(Vec<AfSpecBridge>),
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_hex"))]
     AfSpecUnknown(Vec<u8>),
+    /// Attribute not supported by this crate yet, also usable for emitting
+    /// arbitrary attribute built by [DefaultNla::new()] with raw value.
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_nla"))]
     Other(DefaultNla),
 }
 
@@ -179,6 +198,7 @@ impl Nla for LinkAttribute {
             Self::ProtoInfoBridge(v) => v.as_slice().buffer_len(),
             Self::ProtoInfoInet6(v) => v.as_slice().buffer_len(),
             Self::ProtoDownReason(v) => v.as_slice().buffer_len(),
+            Self::DevlinkPort(v) => v.as_slice().buffer_len(),
 
             Self::Address(bytes)
             | Self::Broadcast(bytes)
@@ -209,6 +229,9 @@ impl Nla for LinkAttribute {
             | Self::CarrierChanges(_)
             | Self::GsoMaxSegs(_)
             | Self::GsoMaxSize(_)
+            | Self::GroMaxSize(_)
+            | Self::GsoIpv4MaxSize(_)
+            | Self::GroIpv4MaxSize(_)
             | Self::LinkNetNsId(_)
             | Self::MinMtu(_)
             | Self::CarrierUpCount(_)
@@ -242,6 +265,7 @@ impl Nla for LinkAttribute {
             Self::ProtoInfoBridge(v) => v.as_slice().emit(buffer),
             Self::ProtoInfoInet6(v) => v.as_slice().emit(buffer),
             Self::ProtoDownReason(v) => v.as_slice().emit(buffer),
+            Self::DevlinkPort(v) => v.as_slice().emit(buffer),
             Self::Address(bytes)
             | Self::Broadcast(bytes)
             | Self::PermAddress(bytes)
@@ -276,6 +300,9 @@ impl Nla for LinkAttribute {
             | Self::CarrierDownCount(value)
             | Self::GsoMaxSegs(value)
             | Self::GsoMaxSize(value)
+            | Self::GroMaxSize(value)
+            | Self::GsoIpv4MaxSize(value)
+            | Self::GroIpv4MaxSize(value)
             | Self::MinMtu(value)
             | Self::MaxMtu(value) => NativeEndian::write_u32(buffer, *value),
 
@@ -324,6 +351,7 @@ impl Nla for LinkAttribute {
             Self::NewIfIndex(_) => IFLA_NEW_IFINDEX,
             Self::PropList(_) => IFLA_PROP_LIST | NLA_F_NESTED,
             Self::ProtoDownReason(_) => IFLA_PROTO_DOWN_REASON,
+            Self::DevlinkPort(_) => IFLA_DEVLINK_PORT | NLA_F_NESTED,
             Self::Address(_) => IFLA_ADDRESS,
             Self::Broadcast(_) => IFLA_BROADCAST,
             Self::PermAddress(_) => IFLA_PERM_ADDRESS,
@@ -349,6 +377,9 @@ impl Nla for LinkAttribute {
             Self::CarrierChanges(_) => IFLA_CARRIER_CHANGES,
             Self::GsoMaxSegs(_) => IFLA_GSO_MAX_SEGS,
             Self::GsoMaxSize(_) => IFLA_GSO_MAX_SIZE,
+            Self::GroMaxSize(_) => IFLA_GRO_MAX_SIZE,
+            Self::GsoIpv4MaxSize(_) => IFLA_GSO_IPV4_MAX_SIZE,
+            Self::GroIpv4MaxSize(_) => IFLA_GRO_IPV4_MAX_SIZE,
             Self::MinMtu(_) => IFLA_MIN_MTU,
             Self::MaxMtu(_) => IFLA_MAX_MTU,
             Self::LinkNetNsId(_) => IFLA_LINK_NETNSID,
@@ -495,6 +526,18 @@ impl<'a, T: AsRef<[u8]> + ?Sized>
                 }
                 Self::ProtoDownReason(nlas)
             }
+            IFLA_DEVLINK_PORT => {
+                let mut nlas = vec![];
+                for nla in NlasIterator::new(payload) {
+                    let nla =
+                        &nla.context("invalid IFLA_DEVLINK_PORT value")?;
+                    nlas.push(
+                        LinkDevlinkPort::parse(nla)
+                            .context("invalid IFLA_DEVLINK_PORT value")?,
+                    );
+                }
+                Self::DevlinkPort(nlas)
+            }
             // HW address (we parse them as Vec for now, because for IP over
             // GRE, the HW address is an IP instead of a MAC for
             // example
@@ -579,6 +622,18 @@ impl<'a, T: AsRef<[u8]> + ?Sized>
                 parse_u32(payload)
                     .context("invalid IFLA_GSO_MAX_SIZE value")?,
             ),
+            IFLA_GRO_MAX_SIZE => Self::GroMaxSize(
+                parse_u32(payload)
+                    .context("invalid IFLA_GRO_MAX_SIZE value")?,
+            ),
+            IFLA_GSO_IPV4_MAX_SIZE => Self::GsoIpv4MaxSize(
+                parse_u32(payload)
+                    .context("invalid IFLA_GSO_IPV4_MAX_SIZE value")?,
+            ),
+            IFLA_GRO_IPV4_MAX_SIZE => Self::GroIpv4MaxSize(
+                parse_u32(payload)
+                    .context("invalid IFLA_GRO_IPV4_MAX_SIZE value")?,
+            ),
             IFLA_MIN_MTU => Self::MinMtu(
                 parse_u32(payload).context("invalid IFLA_MIN_MTU value")?,
             ),
@@ -616,6 +671,13 @@ impl<'a, T: AsRef<[u8]> + ?Sized>
                 ))
                 .context(format!("Invalid IFLA_STATS value {:?}", payload))?,
             ),
+            IFLA_STATS64 if is_strict_parsing() => Self::Stats64(
+                super::Stats64::parse(
+                    &Stats64Buffer::new_strict(payload)
+                        .context("invalid IFLA_STATS64 value")?,
+                )
+                .context(format!("Invalid IFLA_STATS64 value {:?}", payload))?,
+            ),
             IFLA_STATS64 => {
                 let payload = expand_buffer_if_small(
                     payload,
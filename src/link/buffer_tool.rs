@@ -23,3 +23,13 @@ pub(crate) fn expand_buffer_if_small(
     }
     payload
 }
+
+// Unlike `expand_buffer_if_small()`, no warning is emitted for data bigger
+// than expected as the caller is preserving the unknown trailing data.
+pub(crate) fn pad_buffer_if_small(got: &[u8], expected_size: usize) -> Vec<u8> {
+    let mut payload = got.to_vec();
+    if payload.len() < expected_size {
+        payload.resize(expected_size, 0);
+    }
+    payload
+}
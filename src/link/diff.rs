@@ -0,0 +1,65 @@
+// SPDX-License-Identifier: MIT
+
+use netlink_packet_utils::nla::Nla;
+
+use super::{LinkAttribute, LinkMessage};
+
+/// Difference of a single attribute between two [LinkMessage] of the same
+/// interface, as reported by [LinkMessage::diff()].
+#[derive(Debug, PartialEq, Eq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[non_exhaustive]
+#[allow(clippy::large_enum_variant)]
+pub enum AttrChange {
+    /// Attribute only found in the new message.
+    Added(LinkAttribute),
+    /// Attribute only found in the old message.
+    Removed(LinkAttribute),
+    /// Attribute of the same kind holding different value.
+    Changed {
+        old: LinkAttribute,
+        new: LinkAttribute,
+    },
+}
+
+impl LinkMessage {
+    /// Compare the attributes of `self`(old) against `other`(new).
+    ///
+    /// Attributes are paired by their netlink kind. When the same kind is
+    /// repeated, they are paired in the order they appear. The header of the
+    /// messages is not compared.
+    pub fn diff(&self, other: &LinkMessage) -> Vec<AttrChange> {
+        let mut ret = Vec::new();
+        let mut paired = vec![false; other.attributes.len()];
+        for old in &self.attributes {
+            let new = other
+                .attributes
+                .iter()
+                .enumerate()
+                .find(|(i, a)| !paired[*i] && a.kind() == old.kind())
+                .map(|(i, _)| i);
+            match new {
+                Some(i) => {
+                    paired[i] = true;
+                    let new = &other.attributes[i];
+                    if new != old {
+                        ret.push(AttrChange::Changed {
+                            old: old.clone(),
+                            new: new.clone(),
+                        });
+                    }
+                }
+                None => ret.push(AttrChange::Removed(old.clone())),
+            }
+        }
+        for (attr, _) in other
+            .attributes
+            .iter()
+            .zip(paired.iter())
+            .filter(|(_, paired)| !**paired)
+        {
+            ret.push(AttrChange::Added(attr.clone()));
+        }
+        ret
+    }
+}
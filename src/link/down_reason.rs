@@ -12,10 +12,12 @@ const IFLA_PROTO_DOWN_REASON_MASK: u16 = 1;
 const IFLA_PROTO_DOWN_REASON_VALUE: u16 = 2;
 
 #[derive(Debug, PartialEq, Eq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[non_exhaustive]
 pub enum LinkProtocolDownReason {
     Value(u32),
     Mask(u32),
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_nla"))]
     Other(DefaultNla),
 }
 
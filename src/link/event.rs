@@ -15,6 +15,7 @@ const IFLA_EVENT_IGMP_RESEND: u32 = 5;
 const IFLA_EVENT_BONDING_OPTIONS: u32 = 6;
 
 #[derive(Debug, PartialEq, Eq, Clone, Copy, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[non_exhaustive]
 pub enum LinkEvent {
     #[default]
@@ -10,9 +10,11 @@ const RTEXT_FILTER_CFM_STATUS: u32 = 1 << 6;
 const RTEXT_FILTER_MST: u32 = 1 << 7;
 
 #[derive(Debug, PartialEq, Eq, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub(crate) struct VecLinkExtentMask(pub(crate) Vec<LinkExtentMask>);
 
 #[derive(Debug, PartialEq, Eq, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[non_exhaustive]
 #[repr(u32)]
 pub enum LinkExtentMask {
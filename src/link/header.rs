@@ -22,6 +22,8 @@ buffer!(LinkMessageBuffer(LINK_HEADER_LEN) {
     payload: (slice, LINK_HEADER_LEN..),
 });
 
+impl_try_from_slice!(LinkMessageBuffer);
+
 impl<'a, T: AsRef<[u8]> + ?Sized> LinkMessageBuffer<&'a T> {
     pub fn attributes(
         &self,
@@ -50,6 +52,7 @@ impl<'a, T: AsRef<[u8]> + ?Sized> LinkMessageBuffer<&'a T> {
 ///
 /// `LinkHeader` exposes all these fields except for the "reserved" one.
 #[derive(Debug, PartialEq, Eq, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct LinkHeader {
     /// Address family: one of the `AF_*` constants.
     /// The [AddressFamily] has `From<u8>` and `From<AddressFamily> for u8`
@@ -66,10 +69,28 @@ pub struct LinkHeader {
     /// State of the link, described by a combinations of `IFF_*`
     /// constants.
     pub flags: LinkFlags,
-    /// Change mask for the `flags` field.
+    /// Change mask for the `flags` field, see [LinkHeader::set_flags()].
     pub change_mask: LinkFlags,
 }
 
+impl LinkHeader {
+    /// Set the bits of `mask` in [LinkHeader::flags] to the value held
+    /// by `flags` and add `mask` to [LinkHeader::change_mask], leaving
+    /// other bits untouched. Bits of `flags` outside of `mask` are ignored.
+    ///
+    /// In `RTM_NEWLINK` and `RTM_SETLINK` requests to existing interface,
+    /// kernel only changes the flags included in the change mask, except
+    /// for a zero change mask meaning all flags are replaced by the
+    /// header flags. Hence, a zero change mask with non-empty flags is
+    /// usually a bug which clears flags like [LinkFlags::Promisc]
+    /// unintentionally. The change mask is ignored in `RTM_GETLINK`
+    /// requests and kernel replies.
+    pub fn set_flags(&mut self, flags: LinkFlags, mask: LinkFlags) {
+        self.flags = (self.flags & !mask) | (flags & mask);
+        self.change_mask |= mask;
+    }
+}
+
 impl Emitable for LinkHeader {
     fn buffer_len(&self) -> usize {
         LINK_HEADER_LEN
@@ -78,6 +99,7 @@ impl Emitable for LinkHeader {
     fn emit(&self, buffer: &mut [u8]) {
         let mut packet = LinkMessageBuffer::new(buffer);
         packet.set_interface_family(u8::from(self.interface_family));
+        packet.set_reserved_1(0);
         packet.set_link_index(self.index);
         packet.set_change_mask(self.change_mask.bits());
         packet.set_link_layer_type(u16::from(self.link_layer_type));
@@ -26,6 +26,7 @@ const IFF_ECHO: u32 = 1 << 18;
 
 bitflags! {
     #[derive(Debug, PartialEq, Eq, Clone, Copy, Default)]
+    #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
     #[non_exhaustive]
     pub struct LinkFlags: u32 {
         const Up = IFF_UP;
@@ -73,6 +73,7 @@ const BOND_ARP_FILTER_ACTIVE: u32 = BOND_ARP_FILTER | BOND_ARP_VALIDATE_ACTIVE;
 const BOND_ARP_FILTER_BACKUP: u32 = BOND_ARP_FILTER | BOND_ARP_VALIDATE_BACKUP;
 
 #[derive(Debug, Clone, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[non_exhaustive]
 pub enum BondAdInfo {
     Aggregator(u16),
@@ -80,6 +81,7 @@ pub enum BondAdInfo {
     ActorKey(u16),
     PartnerKey(u16),
     PartnerMac([u8; 6]),
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_nla"))]
     Other(DefaultNla),
 }
 
@@ -151,6 +153,7 @@ impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>> for BondAdInfo {
 }
 
 #[derive(Debug, Clone, Copy, Eq, PartialEq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[non_exhaustive]
 pub enum BondMode {
     #[default]
@@ -212,6 +215,7 @@ impl std::fmt::Display for BondMode {
 }
 
 #[derive(Debug, Clone, Copy, Eq, PartialEq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum BondArpValidate {
     #[default]
     None,
@@ -339,6 +343,7 @@ impl Nla for BondIpAddrNla {
 }
 
 #[derive(Debug, PartialEq, Eq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[non_exhaustive]
 pub enum InfoBond {
     Mode(BondMode),
@@ -372,6 +377,7 @@ pub enum InfoBond {
     AdLacpActive(u8),
     MissedMax(u8),
     NsIp6Target(Vec<Ipv6Addr>),
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_nla"))]
     Other(DefaultNla),
 }
 
@@ -21,12 +21,13 @@ const IFLA_BOND_PORT_MII_STATUS: u16 = 2;
 const IFLA_BOND_PORT_LINK_FAILURE_COUNT: u16 = 3;
 const IFLA_BOND_PORT_PERM_HWADDR: u16 = 4;
 const IFLA_BOND_PORT_QUEUE_ID: u16 = 5;
-// const IFLA_BOND_PORT_AD_AGGREGATOR_ID: u16 = 6;
-// const IFLA_BOND_PORT_AD_ACTOR_OPER_PORT_STATE: u16 = 7;
-// const IFLA_BOND_PORT_AD_PARTNER_OPER_PORT_STATE: u16 = 8;
+const IFLA_BOND_PORT_AD_AGGREGATOR_ID: u16 = 6;
+const IFLA_BOND_PORT_AD_ACTOR_OPER_PORT_STATE: u16 = 7;
+const IFLA_BOND_PORT_AD_PARTNER_OPER_PORT_STATE: u16 = 8;
 const IFLA_BOND_PORT_PRIO: u16 = 9;
 
 #[derive(Debug, Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[non_exhaustive]
 pub enum BondPortState {
     Active,
@@ -57,6 +58,7 @@ impl From<BondPortState> for u8 {
 }
 
 #[derive(Debug, Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[non_exhaustive]
 pub enum MiiStatus {
     Up,
@@ -93,14 +95,23 @@ impl From<MiiStatus> for u8 {
 }
 
 #[derive(Debug, PartialEq, Eq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[non_exhaustive]
 pub enum InfoBondPort {
     LinkFailureCount(u32),
     MiiStatus(MiiStatus),
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_hex"))]
     PermHwaddr(Vec<u8>),
     Prio(i32),
     QueueId(u16),
     BondPortState(BondPortState),
+    /// ID of the 802.3ad aggregator this port belongs to
+    AdAggregatorId(u16),
+    /// 802.3ad actor operational port state
+    AdActorOperPortState(u8),
+    /// 802.3ad partner operational port state
+    AdPartnerOperPortState(u16),
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_nla"))]
     Other(DefaultNla),
 }
 
@@ -109,7 +120,9 @@ impl Nla for InfoBondPort {
     fn value_len(&self) -> usize {
         use self::InfoBondPort::*;
         match self {
-            QueueId(_)
+            QueueId(_) |
+            AdAggregatorId(_) |
+            AdPartnerOperPortState(_)
                 => 2,
             LinkFailureCount(_) |
             Prio(_)
@@ -118,6 +131,7 @@ impl Nla for InfoBondPort {
             => bytes.len(),
             MiiStatus(_) => 1,
             BondPortState(_) => 1,
+            AdActorOperPortState(_) => 1,
             Other(nla)
                 => nla.value_len(),
         }
@@ -127,7 +141,9 @@ impl Nla for InfoBondPort {
     fn emit_value(&self, buffer: &mut [u8]) {
         use self::InfoBondPort::*;
         match self {
-            QueueId(ref value)
+            QueueId(ref value) |
+            AdAggregatorId(ref value) |
+            AdPartnerOperPortState(ref value)
              => NativeEndian::write_u16(buffer, *value),
             PermHwaddr(ref bytes)
              => buffer.copy_from_slice(bytes.as_slice()),
@@ -137,6 +153,7 @@ impl Nla for InfoBondPort {
              => NativeEndian::write_u32(buffer, *value),
             MiiStatus(state) => buffer[0] = (*state).into(),
             BondPortState(state) => buffer[0] = (*state).into(),
+            AdActorOperPortState(value) => buffer[0] = *value,
             Other(nla)
              => nla.emit_value(buffer),
         }
@@ -152,6 +169,11 @@ impl Nla for InfoBondPort {
             Prio(_) => IFLA_BOND_PORT_PRIO,
             QueueId(_) => IFLA_BOND_PORT_QUEUE_ID,
             BondPortState(_) => IFLA_BOND_PORT_STATE,
+            AdAggregatorId(_) => IFLA_BOND_PORT_AD_AGGREGATOR_ID,
+            AdActorOperPortState(_) => IFLA_BOND_PORT_AD_ACTOR_OPER_PORT_STATE,
+            AdPartnerOperPortState(_) => {
+                IFLA_BOND_PORT_AD_PARTNER_OPER_PORT_STATE
+            }
             Other(nla) => nla.kind(),
         }
     }
@@ -186,6 +208,20 @@ impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>> for InfoBondPort {
                     .context("invalid IFLA_BOND_PORT_STATE value")?
                     .into(),
             ),
+            IFLA_BOND_PORT_AD_AGGREGATOR_ID => AdAggregatorId(
+                parse_u16(payload)
+                    .context("invalid IFLA_BOND_PORT_AD_AGGREGATOR_ID value")?,
+            ),
+            IFLA_BOND_PORT_AD_ACTOR_OPER_PORT_STATE => {
+                AdActorOperPortState(parse_u8(payload).context(
+                    "invalid IFLA_BOND_PORT_AD_ACTOR_OPER_PORT_STATE value",
+                )?)
+            }
+            IFLA_BOND_PORT_AD_PARTNER_OPER_PORT_STATE => {
+                AdPartnerOperPortState(parse_u16(payload).context(
+                    "invalid IFLA_BOND_PORT_AD_PARTNER_OPER_PORT_STATE value",
+                )?)
+            }
             kind => Other(
                 DefaultNla::parse(buf)
                     .context(format!("unknown NLA type {kind}"))?,
@@ -63,6 +63,7 @@ const IFLA_BR_MULTI_BOOLOPT: u16 = 46;
 const IFLA_BR_MCAST_QUERIER_STATE: u16 = 47;
 
 #[derive(Debug, PartialEq, Eq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[non_exhaustive]
 pub enum InfoBridge {
     GroupAddr([u8; 6]),
@@ -111,6 +112,7 @@ pub enum InfoBridge {
     VlanStatsPerHost(u8),
     MultiBoolOpt(u64),
     MulticastQuerierState(Vec<BridgeQuerierState>),
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_nla"))]
     Other(DefaultNla),
 }
 
@@ -519,6 +521,7 @@ impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>> for InfoBridge {
 const BRIDGE_ID_LEN: usize = 8;
 
 #[derive(Debug, PartialEq, Eq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct BridgeId {
     pub priority: u16,
     pub address: [u8; 6],
@@ -563,6 +566,7 @@ const BRIDGE_QUERIER_IPV6_PORT: u16 = 6;
 const BRIDGE_QUERIER_IPV6_OTHER_TIMER: u16 = 7;
 
 #[derive(Debug, Clone, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[non_exhaustive]
 pub enum BridgeQuerierState {
     Ipv4Address(Ipv4Addr),
@@ -571,6 +575,7 @@ pub enum BridgeQuerierState {
     Ipv6Address(Ipv6Addr),
     Ipv6Port(u32),
     Ipv6OtherTimer(u64),
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_nla"))]
     Other(DefaultNla),
 }
 
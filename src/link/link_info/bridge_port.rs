@@ -56,6 +56,7 @@ const IFLA_BRPORT_NEIGH_VLAN_SUPPRESS: u16 = 43;
 const IFLA_BRPORT_BACKUP_NHID: u16 = 44;
 
 #[derive(Debug, PartialEq, Eq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[non_exhaustive]
 pub enum InfoBridgePort {
     State(BridgePortState),
@@ -100,6 +101,7 @@ pub enum InfoBridgePort {
     MulticastMaxGroups(u32),
     NeighVlanSupress(bool),
     BackupNextHopId(u32),
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_nla"))]
     Other(DefaultNla),
 }
 
@@ -528,6 +530,7 @@ const BR_STATE_FORWARDING: u8 = 3;
 const BR_STATE_BLOCKING: u8 = 4;
 
 #[derive(Debug, Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[non_exhaustive]
 pub enum BridgePortState {
     Disabled,
@@ -570,6 +573,7 @@ const MDB_RTR_TYPE_PERM: u8 = 2;
 const MDB_RTR_TYPE_TEMP: u8 = 3;
 
 #[derive(Debug, Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[non_exhaustive]
 pub enum BridgePortMulticastRouter {
     Disabled,
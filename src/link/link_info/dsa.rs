@@ -0,0 +1,61 @@
+// SPDX-License-Identifier: MIT
+
+use anyhow::Context;
+use byteorder::{ByteOrder, NativeEndian};
+use netlink_packet_utils::{
+    nla::{DefaultNla, Nla, NlaBuffer},
+    parsers::parse_u32,
+    traits::Parseable,
+    DecodeError,
+};
+
+// Kernel also defines `IFLA_DSA_MASTER` as alias of `IFLA_DSA_CONDUIT`
+const IFLA_DSA_CONDUIT: u16 = 1;
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[non_exhaustive]
+pub enum InfoDsa {
+    /// Interface index of the conduit (formerly known as master) interface
+    /// the DSA user port is attached to.
+    Conduit(u32),
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_nla"))]
+    Other(DefaultNla),
+}
+
+impl Nla for InfoDsa {
+    fn value_len(&self) -> usize {
+        match self {
+            Self::Conduit(_) => 4,
+            Self::Other(nla) => nla.value_len(),
+        }
+    }
+
+    fn emit_value(&self, buffer: &mut [u8]) {
+        match self {
+            Self::Conduit(value) => NativeEndian::write_u32(buffer, *value),
+            Self::Other(nla) => nla.emit_value(buffer),
+        }
+    }
+
+    fn kind(&self) -> u16 {
+        match self {
+            Self::Conduit(_) => IFLA_DSA_CONDUIT,
+            Self::Other(nla) => nla.kind(),
+        }
+    }
+}
+
+impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>> for InfoDsa {
+    fn parse(buf: &NlaBuffer<&'a T>) -> Result<Self, DecodeError> {
+        let payload = buf.value();
+        Ok(match buf.kind() {
+            IFLA_DSA_CONDUIT => Self::Conduit(
+                parse_u32(payload).context("invalid IFLA_DSA_CONDUIT value")?,
+            ),
+            kind => Self::Other(DefaultNla::parse(buf).context(format!(
+                "unknown NLA type {kind} for IFLA_INFO_DATA(dsa)"
+            ))?),
+        })
+    }
+}
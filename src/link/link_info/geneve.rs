@@ -31,6 +31,7 @@ const GENEVE_DF_SET: u8 = 1;
 const GENEVE_DF_INHERIT: u8 = 2;
 
 #[derive(Debug, PartialEq, Eq, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[non_exhaustive]
 pub enum GeneveDf {
     Unset,
@@ -62,6 +63,7 @@ impl From<GeneveDf> for u8 {
 }
 
 #[derive(Debug, PartialEq, Eq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[non_exhaustive]
 pub enum InfoGeneve {
     Id(u32),
@@ -78,6 +80,7 @@ pub enum InfoGeneve {
     TtlInherit(bool),
     Df(GeneveDf),
     InnerProtoInherit,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_nla"))]
     Other(DefaultNla),
 }
 
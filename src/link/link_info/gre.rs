@@ -1,45 +1,85 @@
 // SPDX-License-Identifier: MIT
 
-use anyhow::Context;
-use netlink_packet_utils::{
-    nla::{DefaultNla, Nla, NlaBuffer},
-    DecodeError, Parseable,
-};
+use netlink_packet_utils::nla::DefaultNla;
 
-#[derive(Debug, PartialEq, Eq, Clone)]
-#[non_exhaustive]
-pub enum InfoGreTun {
-    Other(DefaultNla),
-}
+pub(super) const IFLA_GRE_COLLECT_METADATA: u16 = 18;
+pub(super) const IFLA_GRE_FWMARK: u16 = 20;
 
-impl Nla for InfoGreTun {
-    fn value_len(&self) -> usize {
-        match self {
-            Self::Other(nla) => nla.value_len(),
-        }
-    }
+// The gre, gretap, ip6gre and ip6gretap kinds share the `IFLA_GRE_*`
+// attributes, implement `Nla` and `Parseable` of their enums in one place.
+macro_rules! impl_gre_info {
+    ($name:ident, $kind:literal) => {
+        impl netlink_packet_utils::nla::Nla for $name {
+            fn value_len(&self) -> usize {
+                match self {
+                    Self::CollectMetadata => 0,
+                    Self::FwMark(_) => 4,
+                    Self::Other(nla) => nla.value_len(),
+                }
+            }
+
+            fn emit_value(&self, buffer: &mut [u8]) {
+                use byteorder::{ByteOrder, NativeEndian};
+                match self {
+                    Self::CollectMetadata => (),
+                    Self::FwMark(value) => {
+                        NativeEndian::write_u32(buffer, *value)
+                    }
+                    Self::Other(nla) => nla.emit_value(buffer),
+                }
+            }
 
-    fn emit_value(&self, buffer: &mut [u8]) {
-        match self {
-            Self::Other(nla) => nla.emit_value(buffer),
+            fn kind(&self) -> u16 {
+                use super::gre::{IFLA_GRE_COLLECT_METADATA, IFLA_GRE_FWMARK};
+                match self {
+                    Self::CollectMetadata => IFLA_GRE_COLLECT_METADATA,
+                    Self::FwMark(_) => IFLA_GRE_FWMARK,
+                    Self::Other(nla) => nla.kind(),
+                }
+            }
         }
-    }
 
-    fn kind(&self) -> u16 {
-        match self {
-            Self::Other(nla) => nla.kind(),
+        impl<'a, T: AsRef<[u8]> + ?Sized>
+            netlink_packet_utils::Parseable<
+                netlink_packet_utils::nla::NlaBuffer<&'a T>,
+            > for $name
+        {
+            fn parse(
+                buf: &netlink_packet_utils::nla::NlaBuffer<&'a T>,
+            ) -> Result<Self, netlink_packet_utils::DecodeError> {
+                use anyhow::Context;
+                use netlink_packet_utils::parsers::parse_u32;
+
+                use super::gre::{IFLA_GRE_COLLECT_METADATA, IFLA_GRE_FWMARK};
+
+                let payload = buf.value();
+                Ok(match buf.kind() {
+                    IFLA_GRE_COLLECT_METADATA => Self::CollectMetadata,
+                    IFLA_GRE_FWMARK => Self::FwMark(
+                        parse_u32(payload)
+                            .context("invalid IFLA_GRE_FWMARK value")?,
+                    ),
+                    kind => Self::Other(
+                        netlink_packet_utils::Parseable::parse(buf).context(
+                            format!("unknown NLA type {kind} for {}", $kind),
+                        )?,
+                    ),
+                })
+            }
         }
-    }
+    };
 }
 
-impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>> for InfoGreTun {
-    fn parse(buf: &NlaBuffer<&'a T>) -> Result<Self, DecodeError> {
-        #[allow(clippy::match_single_binding)]
-        Ok(match buf.kind() {
-            kind => Self::Other(
-                DefaultNla::parse(buf)
-                    .context(format!("unknown NLA type {kind} for gre"))?,
-            ),
-        })
-    }
+#[derive(Debug, PartialEq, Eq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[non_exhaustive]
+pub enum InfoGreTun {
+    /// Metadata(external) mode, tunnel parameters are provided per packet
+    /// by eBPF or `tc` tunnel key action
+    CollectMetadata,
+    FwMark(u32),
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_nla"))]
+    Other(DefaultNla),
 }
+
+impl_gre_info!(InfoGreTun, "gre");
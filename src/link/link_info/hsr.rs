@@ -20,6 +20,7 @@ const IFLA_HSR_VERSION: u16 = 6;
 const IFLA_HSR_PROTOCOL: u16 = 7;
 
 #[derive(Debug, PartialEq, Eq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[non_exhaustive]
 pub enum InfoHsr {
     Port1(u32),
@@ -29,6 +30,7 @@ pub enum InfoHsr {
     Version(u8),
     SeqNr(u16),
     Protocol(HsrProtocol),
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_nla"))]
     Other(DefaultNla),
 }
 
@@ -115,6 +117,7 @@ const HSR_PROTOCOL_HSR: u8 = 0;
 const HSR_PROTOCOL_PRP: u8 = 1;
 
 #[derive(Debug, PartialEq, Eq, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[non_exhaustive]
 #[repr(u8)]
 pub enum HsrProtocol {
@@ -8,15 +8,17 @@ use netlink_packet_utils::{
 };
 
 use super::super::{
-    InfoBond, InfoBridge, InfoGeneve, InfoGreTap, InfoGreTap6, InfoGreTun,
-    InfoGreTun6, InfoGtp, InfoHsr, InfoIpVlan, InfoIpVtap, InfoIpoib, InfoKind,
-    InfoMacSec, InfoMacVlan, InfoMacVtap, InfoSitTun, InfoTun, InfoVeth,
-    InfoVlan, InfoVrf, InfoVti, InfoVxlan, InfoXfrm,
+    InfoBond, InfoBridge, InfoDsa, InfoGeneve, InfoGreTap, InfoGreTap6,
+    InfoGreTun, InfoGreTun6, InfoGtp, InfoHsr, InfoIpVlan, InfoIpVtap,
+    InfoIpoib, InfoKind, InfoMacSec, InfoMacVlan, InfoMacVtap, InfoSitTun,
+    InfoTun, InfoVeth, InfoVlan, InfoVrf, InfoVti, InfoVxlan, InfoXfrm,
 };
+use super::vxlan::{emit_vxlan_nlas, vxlan_nlas_buffer_len};
 
 const IFLA_INFO_DATA: u16 = 2;
 
 #[derive(Debug, PartialEq, Eq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[non_exhaustive]
 pub enum InfoData {
     Bridge(Vec<InfoBridge>),
@@ -42,6 +44,8 @@ pub enum InfoData {
     MacSec(Vec<InfoMacSec>),
     Hsr(Vec<InfoHsr>),
     Geneve(Vec<InfoGeneve>),
+    Dsa(Vec<InfoDsa>),
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_hex"))]
     Other(Vec<u8>),
 }
 
@@ -58,7 +62,7 @@ impl Nla for InfoData {
             Self::MacVlan(nlas) => nlas.as_slice().buffer_len(),
             Self::MacVtap(nlas) => nlas.as_slice().buffer_len(),
             Self::Vrf(nlas) => nlas.as_slice().buffer_len(),
-            Self::Vxlan(nlas) => nlas.as_slice().buffer_len(),
+            Self::Vxlan(nlas) => vxlan_nlas_buffer_len(nlas),
             Self::Xfrm(nlas) => nlas.as_slice().buffer_len(),
             Self::MacSec(nlas) => nlas.as_slice().buffer_len(),
             Self::Hsr(nlas) => nlas.as_slice().buffer_len(),
@@ -71,6 +75,7 @@ impl Nla for InfoData {
             Self::Vti(nlas) => nlas.as_slice().buffer_len(),
             Self::Gtp(nlas) => nlas.as_slice().buffer_len(),
             Self::Geneve(nlas) => nlas.as_slice().buffer_len(),
+            Self::Dsa(nlas) => nlas.as_slice().buffer_len(),
             Self::Other(v) => v.len(),
         }
     }
@@ -87,7 +92,7 @@ impl Nla for InfoData {
             Self::MacVlan(nlas) => nlas.as_slice().emit(buffer),
             Self::MacVtap(nlas) => nlas.as_slice().emit(buffer),
             Self::Vrf(nlas) => nlas.as_slice().emit(buffer),
-            Self::Vxlan(nlas) => nlas.as_slice().emit(buffer),
+            Self::Vxlan(nlas) => emit_vxlan_nlas(nlas, buffer),
             Self::Xfrm(nlas) => nlas.as_slice().emit(buffer),
             Self::MacSec(nlas) => nlas.as_slice().emit(buffer),
             Self::Hsr(nlas) => nlas.as_slice().emit(buffer),
@@ -100,6 +105,7 @@ impl Nla for InfoData {
             Self::Vti(nlas) => nlas.as_slice().emit(buffer),
             Self::Gtp(nlas) => nlas.as_slice().emit(buffer),
             Self::Geneve(nlas) => nlas.as_slice().emit(buffer),
+            Self::Dsa(nlas) => nlas.as_slice().emit(buffer),
             Self::Other(v) => buffer.copy_from_slice(v.as_slice()),
         }
     }
@@ -320,6 +326,17 @@ impl InfoData {
                 }
                 InfoData::Ipoib(v)
             }
+            InfoKind::Dsa => {
+                let mut v = Vec::new();
+                for nla in NlasIterator::new(payload) {
+                    let nla = &nla.context(format!(
+                        "invalid IFLA_INFO_DATA for {kind} {payload:?}"
+                    ))?;
+                    let parsed = InfoDsa::parse(nla)?;
+                    v.push(parsed);
+                }
+                InfoData::Dsa(v)
+            }
             InfoKind::Xfrm => {
                 let mut v = Vec::new();
                 for nla in NlasIterator::new(payload) {
@@ -20,6 +20,7 @@ const IFLA_INFO_PORT_KIND: u16 = 4;
 const IFLA_INFO_PORT_DATA: u16 = 5;
 
 #[derive(Debug, PartialEq, Eq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[non_exhaustive]
 pub enum InfoPortKind {
     Bond,
@@ -93,11 +94,13 @@ impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>> for InfoPortKind {
 pub type InfoVrfPort = InfoVrf;
 
 #[derive(Debug, PartialEq, Eq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[non_exhaustive]
 pub enum InfoPortData {
     BondPort(Vec<InfoBondPort>),
     BridgePort(Vec<InfoBridgePort>),
     VrfPort(Vec<InfoVrfPort>),
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_hex"))]
     Other(Vec<u8>),
 }
 
@@ -7,6 +7,8 @@ use netlink_packet_utils::{
     DecodeError, Emitable, Parseable, ParseableParametrized,
 };
 
+use crate::emit::emit_value_checked;
+
 use super::super::{InfoData, InfoPortData, InfoPortKind, LinkXstats};
 
 const IFLA_INFO_KIND: u16 = 1;
@@ -43,8 +45,12 @@ const XFRM: &str = "xfrm";
 const MACSEC: &str = "macsec";
 const HSR: &str = "hsr";
 const GENEVE: &str = "geneve";
+const DSA: &str = "dsa";
+// FreeBSD name for WireGuard interface
+const WIREGUARD_FREEBSD: &str = "wg";
 
 #[derive(Debug, PartialEq, Eq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[non_exhaustive]
 pub enum LinkInfo {
     Xstats(LinkXstats),
@@ -52,6 +58,7 @@ pub enum LinkInfo {
     Data(InfoData),
     PortKind(InfoPortKind),
     PortData(InfoPortData),
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_nla"))]
     Other(DefaultNla),
 }
 
@@ -70,10 +77,10 @@ impl Nla for LinkInfo {
     fn emit_value(&self, buffer: &mut [u8]) {
         match self {
             Self::Xstats(v) => v.emit(buffer),
-            Self::Kind(nla) => nla.emit_value(buffer),
-            Self::Data(nla) => nla.emit_value(buffer),
-            Self::PortKind(nla) => nla.emit_value(buffer),
-            Self::PortData(nla) => nla.emit_value(buffer),
+            Self::Kind(nla) => emit_value_checked(nla, buffer),
+            Self::Data(nla) => emit_value_checked(nla, buffer),
+            Self::PortKind(nla) => emit_value_checked(nla, buffer),
+            Self::PortData(nla) => emit_value_checked(nla, buffer),
             Self::Other(nla) => nla.emit_value(buffer),
         }
     }
@@ -172,6 +179,7 @@ impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>> for VecLinkInfo {
 }
 
 #[derive(Debug, PartialEq, Eq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[non_exhaustive]
 pub enum InfoKind {
     Dummy,
@@ -197,114 +205,60 @@ pub enum InfoKind {
     Vrf,
     Gtp,
     Ipoib,
+    /// WireGuard interface. Kernel provides no `IFLA_INFO_DATA` for it:
+    /// keys, listen port, firewall mark and peers are only configured and
+    /// reported through the `wireguard` generic netlink family
+    /// (`WGDEVICE_A_*` and `WGPEER_A_*` attributes), which is out of scope
+    /// of this crate.
     Wireguard,
     Xfrm,
     MacSec,
     Hsr,
     Geneve,
+    /// DSA (Distributed Switch Architecture) user port of switch chip.
+    Dsa,
     Other(String),
 }
 
-impl std::fmt::Display for InfoKind {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        write!(
-            f,
-            "{}",
-            match self {
-                Self::Dummy => DUMMY,
-                Self::Ifb => IFB,
-                Self::Bridge => BRIDGE,
-                Self::Tun => TUN,
-                Self::Nlmon => NLMON,
-                Self::Vlan => VLAN,
-                Self::Veth => VETH,
-                Self::Vxlan => VXLAN,
-                Self::Bond => BOND,
-                Self::IpVlan => IPVLAN,
-                Self::IpVtap => IPVTAP,
-                Self::MacVlan => MACVLAN,
-                Self::MacVtap => MACVTAP,
-                Self::GreTap => GRETAP,
-                Self::GreTap6 => IP6GRETAP,
-                Self::IpTun => IPIP,
-                Self::SitTun => SIT,
-                Self::GreTun => GRE,
-                Self::GreTun6 => IP6GRE,
-                Self::Vti => VTI,
-                Self::Vrf => VRF,
-                Self::Gtp => GTP,
-                Self::Ipoib => IPOIB,
-                Self::Wireguard => WIREGUARD,
-                Self::Xfrm => XFRM,
-                Self::MacSec => MACSEC,
-                Self::Hsr => HSR,
-                Self::Geneve => GENEVE,
-                Self::Other(s) => s.as_str(),
-            }
-        )
-    }
-}
-
-impl Nla for InfoKind {
-    fn value_len(&self) -> usize {
-        let len = match self {
-            Self::Dummy => DUMMY.len(),
-            Self::Ifb => IFB.len(),
-            Self::Bridge => BRIDGE.len(),
-            Self::Tun => TUN.len(),
-            Self::Nlmon => NLMON.len(),
-            Self::Vlan => VLAN.len(),
-            Self::Veth => VETH.len(),
-            Self::Vxlan => VXLAN.len(),
-            Self::Bond => BOND.len(),
-            Self::IpVlan => IPVLAN.len(),
-            Self::IpVtap => IPVTAP.len(),
-            Self::MacVlan => MACVLAN.len(),
-            Self::MacVtap => MACVTAP.len(),
-            Self::GreTap => GRETAP.len(),
-            Self::GreTap6 => IP6GRETAP.len(),
-            Self::IpTun => IPIP.len(),
-            Self::SitTun => SIT.len(),
-            Self::GreTun => GRE.len(),
-            Self::GreTun6 => IP6GRE.len(),
-            Self::Vti => VTI.len(),
-            Self::Vrf => VRF.len(),
-            Self::Gtp => GTP.len(),
-            Self::Ipoib => IPOIB.len(),
-            Self::Wireguard => WIREGUARD.len(),
-            Self::Xfrm => XFRM.len(),
-            Self::MacSec => MACSEC.len(),
-            Self::Hsr => HSR.len(),
-            Self::Geneve => GENEVE.len(),
-            Self::Other(s) => s.len(),
-        };
-        len + 1
-    }
-
-    fn emit_value(&self, buffer: &mut [u8]) {
-        let kind = self.to_string();
-        let s = kind.as_str();
-        buffer[..s.len()].copy_from_slice(s.to_string().as_bytes());
-        buffer[s.len()] = 0;
-    }
-
-    fn kind(&self) -> u16 {
-        IFLA_INFO_KIND
+impl InfoKind {
+    /// The `IFLA_INFO_KIND` string used by kernel for this kind.
+    pub fn as_str(&self) -> &str {
+        match self {
+            Self::Dummy => DUMMY,
+            Self::Ifb => IFB,
+            Self::Bridge => BRIDGE,
+            Self::Tun => TUN,
+            Self::Nlmon => NLMON,
+            Self::Vlan => VLAN,
+            Self::Veth => VETH,
+            Self::Vxlan => VXLAN,
+            Self::Bond => BOND,
+            Self::IpVlan => IPVLAN,
+            Self::IpVtap => IPVTAP,
+            Self::MacVlan => MACVLAN,
+            Self::MacVtap => MACVTAP,
+            Self::GreTap => GRETAP,
+            Self::GreTap6 => IP6GRETAP,
+            Self::IpTun => IPIP,
+            Self::SitTun => SIT,
+            Self::GreTun => GRE,
+            Self::GreTun6 => IP6GRE,
+            Self::Vti => VTI,
+            Self::Vrf => VRF,
+            Self::Gtp => GTP,
+            Self::Ipoib => IPOIB,
+            Self::Wireguard => WIREGUARD,
+            Self::Xfrm => XFRM,
+            Self::MacSec => MACSEC,
+            Self::Hsr => HSR,
+            Self::Geneve => GENEVE,
+            Self::Dsa => DSA,
+            Self::Other(s) => s.as_str(),
+        }
     }
-}
 
-impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>> for InfoKind {
-    fn parse(buf: &NlaBuffer<&'a T>) -> Result<InfoKind, DecodeError> {
-        if buf.kind() != IFLA_INFO_KIND {
-            return Err(format!(
-                "failed to parse IFLA_INFO_KIND: NLA type is {}",
-                buf.kind()
-            )
-            .into());
-        }
-        let s = parse_string(buf.value())
-            .context("invalid IFLA_INFO_KIND value")?;
-        Ok(match s.as_str() {
+    fn from_kernel_str(s: &str) -> Self {
+        match s {
             DUMMY => Self::Dummy,
             IFB => Self::Ifb,
             BRIDGE => Self::Bridge,
@@ -333,7 +287,66 @@ impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>> for InfoKind {
             XFRM => Self::Xfrm,
             HSR => Self::Hsr,
             GENEVE => Self::Geneve,
-            _ => Self::Other(s),
-        })
+            DSA => Self::Dsa,
+            _ => Self::Other(s.to_string()),
+        }
+    }
+}
+
+impl std::fmt::Display for InfoKind {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl std::str::FromStr for InfoKind {
+    type Err = std::convert::Infallible;
+
+    /// Besides the kernel kind strings, also accepts `wg` used by FreeBSD
+    /// for WireGuard interfaces. Unknown kinds are stored in
+    /// [InfoKind::Other].
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(s.into())
+    }
+}
+
+// Also provides `TryFrom<&str>` via the blanket implementation.
+impl From<&str> for InfoKind {
+    fn from(s: &str) -> Self {
+        match s {
+            WIREGUARD_FREEBSD => Self::Wireguard,
+            _ => Self::from_kernel_str(s),
+        }
+    }
+}
+
+impl Nla for InfoKind {
+    fn value_len(&self) -> usize {
+        self.as_str().len() + 1
+    }
+
+    fn emit_value(&self, buffer: &mut [u8]) {
+        let s = self.as_str();
+        buffer[..s.len()].copy_from_slice(s.as_bytes());
+        buffer[s.len()] = 0;
+    }
+
+    fn kind(&self) -> u16 {
+        IFLA_INFO_KIND
+    }
+}
+
+impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>> for InfoKind {
+    fn parse(buf: &NlaBuffer<&'a T>) -> Result<InfoKind, DecodeError> {
+        if buf.kind() != IFLA_INFO_KIND {
+            return Err(format!(
+                "failed to parse IFLA_INFO_KIND: NLA type is {}",
+                buf.kind()
+            )
+            .into());
+        }
+        let s = parse_string(buf.value())
+            .context("invalid IFLA_INFO_KIND value")?;
+        Ok(Self::from_kernel_str(&s))
     }
 }
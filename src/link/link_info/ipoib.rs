@@ -14,11 +14,13 @@ const IFLA_IPOIB_MODE: u16 = 2;
 const IFLA_IPOIB_UMCAST: u16 = 3;
 
 #[derive(Debug, PartialEq, Eq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[non_exhaustive]
 pub enum InfoIpoib {
     Pkey(u16),
     Mode(u16),
     UmCast(u16),
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_nla"))]
     Other(DefaultNla),
 }
 
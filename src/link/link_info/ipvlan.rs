@@ -13,10 +13,12 @@ const IFLA_IPVLAN_MODE: u16 = 1;
 const IFLA_IPVLAN_FLAGS: u16 = 2;
 
 #[derive(Debug, PartialEq, Eq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[non_exhaustive]
 pub enum InfoIpVlan {
     Mode(IpVlanMode),
     Flags(IpVlanFlags),
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_nla"))]
     Other(DefaultNla),
 }
 
@@ -70,10 +72,12 @@ impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>> for InfoIpVlan {
 }
 
 #[derive(Debug, PartialEq, Eq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[non_exhaustive]
 pub enum InfoIpVtap {
     Mode(IpVtapMode),
     Flags(IpVtapFlags),
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_nla"))]
     Other(DefaultNla),
 }
 
@@ -131,6 +135,7 @@ const IPVLAN_MODE_L3: u16 = 1;
 const IPVLAN_MODE_L3S: u16 = 2;
 
 #[derive(Debug, PartialEq, Eq, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[non_exhaustive]
 pub enum IpVlanMode {
     L2,
@@ -172,6 +177,7 @@ const IPVLAN_F_VEPA: u16 = 0x02;
 bitflags! {
     #[non_exhaustive]
     #[derive(Debug, Clone, Copy, Eq, PartialEq)]
+    #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
     pub struct IpVlanFlags: u16 {
         const Private = IPVLAN_F_PRIVATE;
         const Vepa = IPVLAN_F_VEPA;
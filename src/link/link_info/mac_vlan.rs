@@ -20,6 +20,7 @@ const IFLA_MACVLAN_BC_QUEUE_LEN_USED: u16 = 8;
 const IFLA_MACVLAN_BC_CUTOFF: u16 = 9;
 
 #[derive(Debug, PartialEq, Eq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[non_exhaustive]
 pub enum InfoMacVlan {
     Mode(MacVlanMode),
@@ -32,6 +33,7 @@ pub enum InfoMacVlan {
     BcQueueLen(u32),
     BcQueueLenUsed(u32),
     BcCutoff(i32),
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_nla"))]
     Other(DefaultNla),
 }
 
@@ -144,7 +146,10 @@ impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>> for InfoMacVlan {
     }
 }
 
+/// The macvtap driver shares the `IFLA_MACVLAN_*` attributes with macvlan,
+/// kernel has no macvtap specific attribute.
 #[derive(Debug, PartialEq, Eq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[non_exhaustive]
 pub enum InfoMacVtap {
     Mode(MacVtapMode),
@@ -156,6 +161,7 @@ pub enum InfoMacVtap {
     BcQueueLen(u32),
     BcQueueLenUsed(u32),
     BcCutoff(i32),
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_nla"))]
     Other(DefaultNla),
 }
 
@@ -271,6 +277,7 @@ const MACVLAN_MODE_PASSTHRU: u32 = 8;
 const MACVLAN_MODE_SOURCE: u32 = 16;
 
 #[derive(Debug, PartialEq, Eq, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[non_exhaustive]
 pub enum MacVlanMode {
     Private,
@@ -281,6 +288,7 @@ pub enum MacVlanMode {
     Other(u32),
 }
 
+/// The macvtap shares the same `MACVLAN_MODE_*` values with macvlan.
 pub type MacVtapMode = MacVlanMode;
 
 impl From<u32> for MacVlanMode {
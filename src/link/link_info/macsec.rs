@@ -37,6 +37,7 @@ const MACSEC_CIPHER_ID_GCM_AES_XPN_256: u64 = 0x0080C20001000004;
 const MACSEC_DEFAULT_CIPHER_ID: u64 = 0x0080020001000001;
 
 #[derive(Debug, PartialEq, Eq, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[non_exhaustive]
 pub enum MacSecCipherId {
     #[deprecated]
@@ -77,6 +78,7 @@ impl From<MacSecCipherId> for u64 {
 }
 
 #[derive(Debug, PartialEq, Eq, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[non_exhaustive]
 pub enum MacSecValidate {
     Disabled,
@@ -108,6 +110,7 @@ impl From<MacSecValidate> for u8 {
 }
 
 #[derive(Debug, PartialEq, Eq, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[non_exhaustive]
 pub enum MacSecOffload {
     Off,
@@ -139,6 +142,7 @@ impl From<MacSecOffload> for u8 {
 }
 
 #[derive(Debug, PartialEq, Eq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[non_exhaustive]
 pub enum InfoMacSec {
     Sci(u64),
@@ -155,6 +159,7 @@ pub enum InfoMacSec {
     ReplayProtect(u8),
     Validation(MacSecValidate),
     Offload(MacSecOffload),
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_nla"))]
     Other(DefaultNla),
 }
 
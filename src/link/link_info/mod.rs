@@ -4,7 +4,9 @@ mod bond;
 mod bond_port;
 mod bridge;
 mod bridge_port;
+mod dsa;
 mod geneve;
+#[macro_use]
 mod gre;
 mod gre6;
 mod gre_tap;
@@ -36,6 +38,7 @@ pub use self::bridge::{
 pub use self::bridge_port::{
     BridgePortMulticastRouter, BridgePortState, InfoBridgePort,
 };
+pub use self::dsa::InfoDsa;
 pub use self::geneve::{GeneveDf, InfoGeneve};
 pub use self::gre::InfoGreTun;
 pub use self::gre6::InfoGreTun6;
@@ -60,7 +63,7 @@ pub use self::veth::InfoVeth;
 pub use self::vlan::{InfoVlan, VlanQosMapping};
 pub use self::vrf::InfoVrf;
 pub use self::vti::InfoVti;
-pub use self::vxlan::InfoVxlan;
+pub use self::vxlan::{InfoVxlan, VxlanDf};
 pub use self::xfrm::InfoXfrm;
 pub use self::xstats::LinkXstats;
 
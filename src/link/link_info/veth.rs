@@ -12,12 +12,14 @@ use super::super::{LinkMessage, LinkMessageBuffer};
 const VETH_INFO_PEER: u16 = 1;
 
 #[derive(Debug, PartialEq, Eq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[non_exhaustive]
 // This data is not for querying/dumping as in kernel 6.5.8,
 // because the `struct rtnl_link_ops veth_link_ops` does not have `fill_info`.
 // Only for create veth
 pub enum InfoVeth {
     Peer(LinkMessage),
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_nla"))]
     Other(DefaultNla),
 }
 
@@ -20,6 +20,7 @@ const IFLA_VLAN_PROTOCOL: u16 = 5;
 const IFLA_VLAN_QOS_MAPPING: u16 = 1;
 
 #[derive(Debug, PartialEq, Eq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[non_exhaustive]
 pub enum InfoVlan {
     Id(u16),
@@ -27,6 +28,7 @@ pub enum InfoVlan {
     EgressQos(Vec<VlanQosMapping>),
     IngressQos(Vec<VlanQosMapping>),
     Protocol(VlanProtocol),
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_nla"))]
     Other(DefaultNla),
 }
 
@@ -72,10 +74,12 @@ impl Nla for InfoVlan {
 }
 
 #[derive(Debug, PartialEq, Eq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[non_exhaustive]
 pub enum VlanQosMapping {
     /// Tuple (from, to)
     Mapping(u32, u32),
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_nla"))]
     Other(DefaultNla),
 }
 
@@ -7,7 +7,7 @@ use byteorder::{BigEndian, ByteOrder, NativeEndian};
 use netlink_packet_utils::{
     nla::{DefaultNla, Nla, NlaBuffer},
     parsers::{parse_u16_be, parse_u32, parse_u8},
-    traits::Parseable,
+    traits::{Emitable, Parseable},
     DecodeError,
 };
 
@@ -43,7 +43,44 @@ const IFLA_VXLAN_DF: u16 = 29;
 const IFLA_VXLAN_VNIFILTER: u16 = 30;
 const IFLA_VXLAN_LOCALBYPASS: u16 = 31;
 
+const VXLAN_DF_UNSET: u8 = 0;
+const VXLAN_DF_SET: u8 = 1;
+const VXLAN_DF_INHERIT: u8 = 2;
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[non_exhaustive]
+pub enum VxlanDf {
+    Unset,
+    Set,
+    Inherit,
+    Other(u8),
+}
+
+impl From<u8> for VxlanDf {
+    fn from(d: u8) -> Self {
+        match d {
+            VXLAN_DF_UNSET => Self::Unset,
+            VXLAN_DF_SET => Self::Set,
+            VXLAN_DF_INHERIT => Self::Inherit,
+            _ => Self::Other(d),
+        }
+    }
+}
+
+impl From<VxlanDf> for u8 {
+    fn from(d: VxlanDf) -> Self {
+        match d {
+            VxlanDf::Unset => VXLAN_DF_UNSET,
+            VxlanDf::Set => VXLAN_DF_SET,
+            VxlanDf::Inherit => VXLAN_DF_INHERIT,
+            VxlanDf::Other(value) => value,
+        }
+    }
+}
+
 #[derive(Debug, PartialEq, Eq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[non_exhaustive]
 pub enum InfoVxlan {
     Id(u32),
@@ -70,13 +107,23 @@ pub enum InfoVxlan {
     UDPZeroCsumRX(bool),
     RemCsumTX(bool),
     RemCsumRX(bool),
+    /// Flag attribute, always emitted as zero-length NLA, hence `false` is
+    /// not expressible on wire and the attribute should be omitted instead.
     Gbp(bool),
+    /// Flag attribute, always emitted as zero-length NLA, hence `false` is
+    /// not expressible on wire and the attribute should be omitted instead.
     Gpe(bool),
     RemCsumNoPartial(bool),
+    /// Kernel dumps this as u8 but only accepts zero-length flag NLA in
+    /// request, hence it is emitted as flag NLA. The `false` value is not
+    /// expressible on wire and is omitted when emitting [InfoData::Vxlan].
+    ///
+    /// [InfoData::Vxlan]: crate::link::InfoData::Vxlan
     TtlInherit(bool),
-    Df(u8),
+    Df(VxlanDf),
     Vnifilter(bool),
     Localbypass(bool),
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_nla"))]
     Other(DefaultNla),
 }
 
@@ -96,11 +143,13 @@ impl Nla for InfoVxlan {
             | Self::UDPZeroCsumRX(_)
             | Self::RemCsumTX(_)
             | Self::RemCsumRX(_)
-            | Self::TtlInherit(_)
             | Self::Df(_)
             | Self::Vnifilter(_)
             | Self::Localbypass(_) => 1,
-            Self::Gbp(_) | Self::Gpe(_) | Self::RemCsumNoPartial(_) => 0,
+            Self::Gbp(_)
+            | Self::Gpe(_)
+            | Self::RemCsumNoPartial(_)
+            | Self::TtlInherit(_) => 0,
             Self::Port(_) => 2,
             Self::Id(_)
             | Self::Label(_)
@@ -122,12 +171,12 @@ impl Nla for InfoVxlan {
             | Self::Link(value)
             | Self::Ageing(value)
             | Self::Limit(value) => NativeEndian::write_u32(buffer, *value),
-            Self::Gbp(_value)
-            | Self::Gpe(_value)
-            | Self::RemCsumNoPartial(_value) => (),
-            Self::Tos(value) | Self::Ttl(value) | Self::Df(value) => {
-                buffer[0] = *value
-            }
+            Self::Gbp(_)
+            | Self::Gpe(_)
+            | Self::RemCsumNoPartial(_)
+            | Self::TtlInherit(_) => (),
+            Self::Tos(value) | Self::Ttl(value) => buffer[0] = *value,
+            Self::Df(value) => buffer[0] = (*value).into(),
             Self::Vnifilter(value)
             | Self::Localbypass(value)
             | Self::Learning(value)
@@ -140,8 +189,7 @@ impl Nla for InfoVxlan {
             | Self::UDPZeroCsumTX(value)
             | Self::UDPZeroCsumRX(value)
             | Self::RemCsumTX(value)
-            | Self::RemCsumRX(value)
-            | Self::TtlInherit(value) => buffer[0] = *value as u8,
+            | Self::RemCsumRX(value) => buffer[0] = *value as u8,
             Self::Group(value) | Self::Local(value) => {
                 buffer.copy_from_slice(&value.octets())
             }
@@ -195,6 +243,28 @@ impl Nla for InfoVxlan {
     }
 }
 
+impl InfoVxlan {
+    fn is_omitted(&self) -> bool {
+        matches!(self, Self::TtlInherit(false))
+    }
+}
+
+pub(crate) fn vxlan_nlas_buffer_len(nlas: &[InfoVxlan]) -> usize {
+    nlas.iter()
+        .filter(|nla| !nla.is_omitted())
+        .map(|nla| nla.buffer_len())
+        .sum()
+}
+
+pub(crate) fn emit_vxlan_nlas(nlas: &[InfoVxlan], buffer: &mut [u8]) {
+    let mut offset = 0;
+    for nla in nlas.iter().filter(|nla| !nla.is_omitted()) {
+        let len = nla.buffer_len();
+        nla.emit(&mut buffer[offset..offset + len]);
+        offset += len;
+    }
+}
+
 impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>> for InfoVxlan {
     fn parse(buf: &NlaBuffer<&'a T>) -> Result<Self, DecodeError> {
         let payload = buf.value();
@@ -330,7 +400,7 @@ impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>> for InfoVxlan {
                     .context("invalid IFLA_VXLAN_REMCSUM_RX value")? > 0,
             ),
             IFLA_VXLAN_DF => {
-                Self::Df(parse_u8(payload).context("invalid IFLA_VXLAN_DF value")?)
+                Self::Df(parse_u8(payload).context("invalid IFLA_VXLAN_DF value")?.into())
             }
             IFLA_VXLAN_GBP => {
                 Self::Gbp(true)
@@ -339,10 +409,17 @@ impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>> for InfoVxlan {
                 Self::Gpe(true)
             }
             IFLA_VXLAN_REMCSUM_NOPARTIAL => Self::RemCsumNoPartial(true),
-            IFLA_VXLAN_TTL_INHERIT => Self::TtlInherit(
-                parse_u8(payload)
-                    .context("invalid IFLA_VXLAN_TTL_INHERIT value")? > 0,
-            ),
+            IFLA_VXLAN_TTL_INHERIT => {
+                if payload.is_empty() {
+                    Self::TtlInherit(true)
+                } else {
+                    Self::TtlInherit(
+                        parse_u8(payload)
+                            .context("invalid IFLA_VXLAN_TTL_INHERIT value")?
+                            > 0,
+                    )
+                }
+            }
             IFLA_VXLAN_VNIFILTER => Self::Vnifilter(
                 parse_u8(payload)
                     .context("invalid IFLA_VXLAN_VNIFILTER value")? > 0,
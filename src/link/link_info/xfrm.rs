@@ -13,10 +13,12 @@ const IFLA_XFRM_LINK: u16 = 1;
 const IFLA_XFRM_IF_ID: u16 = 2;
 
 #[derive(Debug, PartialEq, Eq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[non_exhaustive]
 pub enum InfoXfrm {
     Link(u32),
     IfId(u32),
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_nla"))]
     Other(DefaultNla),
 }
 
@@ -9,8 +9,10 @@ use crate::link::InfoKind;
 // This is filled by driver via `struct rtnl_link_ops.fill_xstats`
 // Currently(Linux kernel 6.5.8), only the `can` interface support so.
 #[derive(Debug, PartialEq, Eq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[non_exhaustive]
 pub enum LinkXstats {
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_hex"))]
     Other(Vec<u8>),
 }
 
@@ -69,6 +69,7 @@ const ARPHRD_VOID: u16 = 0xffff;
 const ARPHRD_NONE: u16 = 0xfffe;
 
 #[derive(Debug, PartialEq, Eq, Clone, Copy, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[non_exhaustive]
 #[repr(u16)]
 // Since this list seldom changes, we do not add `Other(u16)` for unknown data.
@@ -9,6 +9,7 @@ const IF_OPER_DORMANT: u8 = 5;
 const IF_OPER_UP: u8 = 6;
 
 #[derive(Debug, Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[non_exhaustive]
 pub enum State {
     /// Status can't be determined
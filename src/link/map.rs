@@ -17,6 +17,7 @@ buffer!(MapBuffer(LINK_MAP_LEN) {
 });
 
 #[derive(Debug, Clone, Copy, Eq, PartialEq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[non_exhaustive]
 pub struct Map {
     pub memory_start: u64,
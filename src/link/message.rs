@@ -1,21 +1,187 @@
 // SPDX-License-Identifier: MIT
 
+use std::os::unix::io::RawFd;
+
 use anyhow::Context;
 use netlink_packet_utils::{
     traits::{Emitable, Parseable, ParseableParametrized},
     DecodeError,
 };
 
-use crate::link::{LinkAttribute, LinkHeader, LinkMessageBuffer};
+use crate::link::{
+    InfoBond, InfoData, InfoKind, LinkAttribute, LinkDevlinkPort,
+    LinkExtentMask, LinkFlags, LinkHeader, LinkInfo, LinkMessageBuffer, Prop,
+};
 use crate::AddressFamily;
 
 #[derive(Debug, PartialEq, Eq, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[non_exhaustive]
 pub struct LinkMessage {
     pub header: LinkHeader,
     pub attributes: Vec<LinkAttribute>,
 }
 
+impl LinkMessage {
+    /// Interface name stored in the first [LinkAttribute::IfName].
+    pub fn name(&self) -> Option<&str> {
+        self.attributes.iter().find_map(|attr| match attr {
+            LinkAttribute::IfName(name) => Some(name.as_str()),
+            _ => None,
+        })
+    }
+
+    /// Alternative interface names stored in [Prop::AltIfName] of all
+    /// [LinkAttribute::PropList].
+    pub fn alt_names(&self) -> Vec<&str> {
+        self.attributes
+            .iter()
+            .filter_map(|attr| match attr {
+                LinkAttribute::PropList(props) => Some(props),
+                _ => None,
+            })
+            .flatten()
+            .filter_map(|prop| match prop {
+                Prop::AltIfName(name) => Some(name.as_str()),
+                _ => None,
+            })
+            .collect()
+    }
+
+    /// MTU stored in the first [LinkAttribute::Mtu].
+    pub fn mtu(&self) -> Option<u32> {
+        self.attributes.iter().find_map(|attr| match attr {
+            LinkAttribute::Mtu(mtu) => Some(*mtu),
+            _ => None,
+        })
+    }
+
+    /// Number of times the carrier went up, stored in the first
+    /// [LinkAttribute::CarrierUpCount].
+    pub fn carrier_up_count(&self) -> Option<u32> {
+        self.attributes.iter().find_map(|attr| match attr {
+            LinkAttribute::CarrierUpCount(v) => Some(*v),
+            _ => None,
+        })
+    }
+
+    /// Number of times the carrier went down, stored in the first
+    /// [LinkAttribute::CarrierDownCount].
+    pub fn carrier_down_count(&self) -> Option<u32> {
+        self.attributes.iter().find_map(|attr| match attr {
+            LinkAttribute::CarrierDownCount(v) => Some(*v),
+            _ => None,
+        })
+    }
+
+    /// Hardware address stored in the first [LinkAttribute::Address].
+    pub fn mac(&self) -> Option<&[u8]> {
+        self.attributes.iter().find_map(|attr| match attr {
+            LinkAttribute::Address(mac) => Some(mac.as_slice()),
+            _ => None,
+        })
+    }
+
+    /// Interface index in the target network namespace stored in
+    /// [LinkAttribute::NewIfIndex], only included by kernel in the
+    /// `RTM_DELLINK` notification of moving the interface out of current
+    /// network namespace.
+    pub fn new_ifindex(&self) -> Option<i32> {
+        self.attributes.iter().find_map(|attr| match attr {
+            LinkAttribute::NewIfIndex(v) => Some(*v),
+            _ => None,
+        })
+    }
+
+    /// ID of the target network namespace stored in
+    /// [LinkAttribute::NewNetnsId], only included by kernel in the
+    /// `RTM_DELLINK` notification of moving the interface out of current
+    /// network namespace.
+    pub fn new_netns_id(&self) -> Option<i32> {
+        self.attributes.iter().find_map(|attr| match attr {
+            LinkAttribute::NewNetnsId(v) => Some(*v),
+            _ => None,
+        })
+    }
+
+    /// Devlink port handle stored in the first
+    /// [LinkAttribute::DevlinkPort].
+    pub fn devlink_port(&self) -> Option<&[LinkDevlinkPort]> {
+        self.attributes.iter().find_map(|attr| match attr {
+            LinkAttribute::DevlinkPort(nlas) => Some(nlas.as_slice()),
+            _ => None,
+        })
+    }
+
+    /// Interface kind stored in the first [LinkInfo::Kind] of
+    /// [LinkAttribute::LinkInfo].
+    pub fn kind(&self) -> Option<&InfoKind> {
+        self.attributes
+            .iter()
+            .filter_map(|attr| match attr {
+                LinkAttribute::LinkInfo(infos) => Some(infos),
+                _ => None,
+            })
+            .flatten()
+            .find_map(|info| match info {
+                LinkInfo::Kind(kind) => Some(kind),
+                _ => None,
+            })
+    }
+
+    /// Whether [LinkFlags::Up] is set in header flags.
+    pub fn is_up(&self) -> bool {
+        self.header.flags.contains(LinkFlags::Up)
+    }
+
+    /// Set the interface administratively up in request: [LinkFlags::Up] is
+    /// set in both the header flags and the change mask.
+    pub fn up(&mut self) {
+        self.header.set_flags(LinkFlags::Up, LinkFlags::Up);
+    }
+
+    /// Set the interface administratively down in request: [LinkFlags::Up]
+    /// is removed from the header flags and set in the change mask.
+    pub fn down(&mut self) {
+        self.header.set_flags(LinkFlags::empty(), LinkFlags::Up);
+    }
+
+    /// Interface index of the currently active port of bond stored in
+    /// [InfoBond::ActivePort], only reported by kernel for bond modes with
+    /// a single active port, e.g. `active-backup`.
+    #[doc(alias = "active_slave")]
+    pub fn bond_active_port(&self) -> Option<u32> {
+        self.bond_infos().find_map(|info| match info {
+            InfoBond::ActivePort(index) => Some(*index),
+            _ => None,
+        })
+    }
+
+    /// Interface index of the primary port of bond stored in
+    /// [InfoBond::Primary].
+    pub fn bond_primary(&self) -> Option<u32> {
+        self.bond_infos().find_map(|info| match info {
+            InfoBond::Primary(index) => Some(*index),
+            _ => None,
+        })
+    }
+
+    fn bond_infos(&self) -> impl Iterator<Item = &InfoBond> {
+        self.attributes
+            .iter()
+            .filter_map(|attr| match attr {
+                LinkAttribute::LinkInfo(infos) => Some(infos),
+                _ => None,
+            })
+            .flatten()
+            .filter_map(|info| match info {
+                LinkInfo::Data(InfoData::Bond(bond_infos)) => Some(bond_infos),
+                _ => None,
+            })
+            .flatten()
+    }
+}
+
 impl Emitable for LinkMessage {
     fn buffer_len(&self) -> usize {
         self.header.buffer_len() + self.attributes.as_slice().buffer_len()
@@ -43,6 +209,20 @@ impl<'a, T: AsRef<[u8]> + 'a> Parseable<LinkMessageBuffer<&'a T>>
     }
 }
 
+impl<'a, T: AsRef<[u8]> + ?Sized> LinkMessageBuffer<&'a T> {
+    /// Parse the attributes one by one without collecting them into a `Vec`,
+    /// so callers only interested in some attributes could stop early.
+    /// Yields the same attributes as [LinkMessage::parse()] does.
+    pub fn parsed_attributes(
+        &self,
+    ) -> impl Iterator<Item = Result<LinkAttribute, DecodeError>> + 'a {
+        let family = AddressFamily::from(self.interface_family());
+        self.attributes().map(move |nla_buf| {
+            LinkAttribute::parse_with_param(&nla_buf?, family)
+        })
+    }
+}
+
 impl<'a, T: AsRef<[u8]> + 'a>
     ParseableParametrized<LinkMessageBuffer<&'a T>, AddressFamily>
     for Vec<LinkAttribute>
@@ -59,3 +239,167 @@ impl<'a, T: AsRef<[u8]> + 'a>
         Ok(attributes)
     }
 }
+
+/// Builder of [LinkMessage] for the common `ip link add` and `ip link set`
+/// requests.
+///
+/// The `IFLA_LINKINFO` attribute is assembled by [Self::build()] with
+/// `IFLA_INFO_KIND` placed before `IFLA_INFO_DATA` as required by kernel.
+///
+/// ```
+/// use netlink_packet_route::link::{
+///     InfoData, InfoKind, InfoVlan, LinkMessageBuilder,
+/// };
+///
+/// // ip link add link eth0 name eth0.101 type vlan id 101
+/// let message = LinkMessageBuilder::new()
+///     .name("eth0.101".to_string())
+///     .link(2)
+///     .kind(InfoKind::Vlan)
+///     .info_data(InfoData::Vlan(vec![InfoVlan::Id(101)]))
+///     .up()
+///     .build();
+/// ```
+#[derive(Debug, PartialEq, Eq, Clone, Default)]
+pub struct LinkMessageBuilder {
+    header: LinkHeader,
+    attributes: Vec<LinkAttribute>,
+    kind: Option<InfoKind>,
+    info_data: Option<InfoData>,
+    props: Vec<Prop>,
+}
+
+impl LinkMessageBuilder {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Create builder for `RTM_GETLINK` dump request with the specified
+    /// `IFLA_EXT_MASK` filter, for example [LinkExtentMask::Vf] to include
+    /// SR-IOV VF information or [LinkExtentMask::SkipStats] to omit the
+    /// statistics in kernel reply. No `IFLA_EXT_MASK` is included if
+    /// `ext_mask` is empty.
+    pub fn for_dump(ext_mask: Vec<LinkExtentMask>) -> Self {
+        let mut ret = Self::new();
+        if !ext_mask.is_empty() {
+            ret.attributes.push(LinkAttribute::ExtMask(ext_mask));
+        }
+        ret
+    }
+
+    /// Set the interface name (`IFLA_IFNAME`).
+    pub fn name(mut self, name: String) -> Self {
+        self.attributes.push(LinkAttribute::IfName(name));
+        self
+    }
+
+    /// Set the interface index in the header.
+    pub fn index(mut self, index: u32) -> Self {
+        self.header.index = index;
+        self
+    }
+
+    /// Set the interface MTU (`IFLA_MTU`).
+    pub fn mtu(mut self, mtu: u32) -> Self {
+        self.attributes.push(LinkAttribute::Mtu(mtu));
+        self
+    }
+
+    /// Set the index of the parent interface (`IFLA_LINK`), for example the
+    /// lower interface of VLAN or MAC VLAN.
+    pub fn link(mut self, index: u32) -> Self {
+        self.attributes.push(LinkAttribute::Link(index));
+        self
+    }
+
+    /// Move the interface to the network namespace referred by file
+    /// descriptor `fd` (`IFLA_NET_NS_FD`).
+    pub fn netns_fd(mut self, fd: RawFd) -> Self {
+        self.attributes.push(LinkAttribute::NetNsFd(fd));
+        self
+    }
+
+    /// Move the interface to the network namespace of process `pid`
+    /// (`IFLA_NET_NS_PID`).
+    pub fn netns_pid(mut self, pid: u32) -> Self {
+        self.attributes.push(LinkAttribute::NetNsPid(pid));
+        self
+    }
+
+    /// Set the interface index to use in the target network namespace when
+    /// moving the interface by [Self::netns_fd()] or [Self::netns_pid()]
+    /// (`IFLA_NEW_IFINDEX`). Kernel keeps the current index if possible
+    /// when not set.
+    pub fn new_ifindex(mut self, index: i32) -> Self {
+        self.attributes.push(LinkAttribute::NewIfIndex(index));
+        self
+    }
+
+    /// Set the interface administratively up: [LinkFlags::Up] is set in
+    /// both the header flags and the change mask.
+    pub fn up(mut self) -> Self {
+        self.header.set_flags(LinkFlags::Up, LinkFlags::Up);
+        self
+    }
+
+    /// Set the interface administratively down: [LinkFlags::Up] is removed
+    /// from the header flags and set in the change mask.
+    pub fn down(mut self) -> Self {
+        self.header.set_flags(LinkFlags::empty(), LinkFlags::Up);
+        self
+    }
+
+    /// Set the interface kind (`IFLA_INFO_KIND`).
+    pub fn kind(mut self, kind: InfoKind) -> Self {
+        self.kind = Some(kind);
+        self
+    }
+
+    /// Set the kind specific data (`IFLA_INFO_DATA`). Ignored unless
+    /// [Self::kind()] is also set.
+    pub fn info_data(mut self, info_data: InfoData) -> Self {
+        self.info_data = Some(info_data);
+        self
+    }
+
+    /// Append an alternative interface name to the `IFLA_PROP_LIST`
+    /// attribute. All alternative names are nested into a single
+    /// `IFLA_PROP_LIST` by [Self::build()].
+    ///
+    /// The kernel adds or removes alternative names depending on the
+    /// netlink message type holding the built [LinkMessage]:
+    ///
+    /// ```
+    /// use netlink_packet_route::{link::LinkMessageBuilder, RouteNetlinkMessage};
+    ///
+    /// // ip link property del dev eth0 altname lan0
+    /// let message = RouteNetlinkMessage::DelLinkProp(
+    ///     LinkMessageBuilder::new()
+    ///         .index(2)
+    ///         .alt_name("lan0".to_string())
+    ///         .build(),
+    /// );
+    /// ```
+    pub fn alt_name(mut self, name: String) -> Self {
+        self.props.push(Prop::AltIfName(name));
+        self
+    }
+
+    pub fn build(self) -> LinkMessage {
+        let mut attributes = self.attributes;
+        if !self.props.is_empty() {
+            attributes.push(LinkAttribute::PropList(self.props));
+        }
+        if let Some(kind) = self.kind {
+            let mut link_infos = vec![LinkInfo::Kind(kind)];
+            if let Some(info_data) = self.info_data {
+                link_infos.push(LinkInfo::Data(info_data));
+            }
+            attributes.push(LinkAttribute::LinkInfo(link_infos));
+        }
+        LinkMessage {
+            header: self.header,
+            attributes,
+        }
+    }
+}
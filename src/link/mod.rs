@@ -2,7 +2,9 @@
 
 mod af_spec;
 mod attribute;
-mod buffer_tool;
+pub(crate) mod buffer_tool;
+mod devlink_port;
+mod diff;
 mod down_reason;
 mod event;
 pub(crate) mod ext_mask;
@@ -18,7 +20,7 @@ mod prop_list;
 mod proto_info;
 pub(crate) mod sriov;
 mod stats;
-mod stats64;
+pub(crate) mod stats64;
 mod vlan_protocol;
 mod wireless;
 mod xdp;
@@ -33,6 +35,8 @@ pub use self::af_spec::{
     Inet6StatsBuffer, InetDevConf,
 };
 pub use self::attribute::LinkAttribute;
+pub use self::devlink_port::LinkDevlinkPort;
+pub use self::diff::AttrChange;
 pub use self::down_reason::LinkProtocolDownReason;
 pub use self::event::LinkEvent;
 pub use self::ext_mask::LinkExtentMask;
@@ -42,19 +46,19 @@ pub use self::link_info::{
     BondAdInfo, BondArpValidate, BondMode, BondPortState, BridgeId,
     BridgeIdBuffer, BridgePortMulticastRouter, BridgePortState,
     BridgeQuerierState, GeneveDf, HsrProtocol, InfoBond, InfoBondPort,
-    InfoBridge, InfoBridgePort, InfoData, InfoGeneve, InfoGreTap, InfoGreTap6,
-    InfoGreTun, InfoGreTun6, InfoGtp, InfoHsr, InfoIpVlan, InfoIpVtap,
-    InfoIpoib, InfoKind, InfoMacSec, InfoMacVlan, InfoMacVtap, InfoPortData,
-    InfoPortKind, InfoSitTun, InfoTun, InfoVeth, InfoVlan, InfoVrf,
-    InfoVrfPort, InfoVti, InfoVxlan, InfoXfrm, IpVlanFlags, IpVlanMode,
-    IpVtapFlags, IpVtapMode, LinkInfo, LinkXstats, MacSecCipherId,
+    InfoBridge, InfoBridgePort, InfoData, InfoDsa, InfoGeneve, InfoGreTap,
+    InfoGreTap6, InfoGreTun, InfoGreTun6, InfoGtp, InfoHsr, InfoIpVlan,
+    InfoIpVtap, InfoIpoib, InfoKind, InfoMacSec, InfoMacVlan, InfoMacVtap,
+    InfoPortData, InfoPortKind, InfoSitTun, InfoTun, InfoVeth, InfoVlan,
+    InfoVrf, InfoVrfPort, InfoVti, InfoVxlan, InfoXfrm, IpVlanFlags,
+    IpVlanMode, IpVtapFlags, IpVtapMode, LinkInfo, LinkXstats, MacSecCipherId,
     MacSecOffload, MacSecValidate, MacVlanMode, MacVtapMode, MiiStatus,
-    VlanQosMapping,
+    VlanQosMapping, VxlanDf,
 };
 pub use self::link_layer_type::LinkLayerType;
 pub use self::link_state::State;
 pub use self::map::{Map, MapBuffer};
-pub use self::message::LinkMessage;
+pub use self::message::{LinkMessage, LinkMessageBuilder};
 pub use self::phys_id::LinkPhysId;
 pub use self::prop_list::Prop;
 pub use self::proto_info::{LinkProtoInfoBridge, LinkProtoInfoInet6};
@@ -71,4 +75,4 @@ pub use self::stats::{Stats, StatsBuffer};
 pub use self::stats64::{Stats64, Stats64Buffer};
 pub use self::vlan_protocol::VlanProtocol;
 pub use self::wireless::LinkWirelessEvent;
-pub use self::xdp::{LinkXdp, XdpAttached};
+pub use self::xdp::{LinkXdp, XdpAttached, XdpFlags};
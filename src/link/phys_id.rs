@@ -8,6 +8,7 @@ use netlink_packet_utils::{
 const MAX_PHYS_ITEM_ID_LEN: usize = 32;
 
 #[derive(Debug, Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[non_exhaustive]
 pub struct LinkPhysId {
     pub id: [u8; MAX_PHYS_ITEM_ID_LEN],
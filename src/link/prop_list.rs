@@ -11,9 +11,11 @@ use netlink_packet_utils::{
 const IFLA_ALT_IFNAME: u16 = 53;
 
 #[derive(Debug, PartialEq, Eq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[non_exhaustive]
 pub enum Prop {
     AltIfName(String),
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_nla"))]
     Other(DefaultNla),
 }
 
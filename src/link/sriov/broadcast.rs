@@ -5,6 +5,7 @@ use netlink_packet_utils::{DecodeError, Emitable, Parseable};
 const VF_INFO_BROADCAST_LEN: usize = 32;
 
 #[derive(Debug, Clone, Copy, Eq, PartialEq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[non_exhaustive]
 pub struct VfInfoBroadcast {
     pub addr: [u8; VF_INFO_BROADCAST_LEN],
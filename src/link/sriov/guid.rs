@@ -5,6 +5,7 @@ use netlink_packet_utils::{DecodeError, Emitable, Parseable};
 const VF_INFO_GUID_LEN: usize = 12;
 
 #[derive(Debug, Clone, Copy, Eq, PartialEq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[non_exhaustive]
 pub struct VfInfoGuid {
     pub vf_id: u32,
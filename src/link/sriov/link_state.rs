@@ -5,6 +5,7 @@ use netlink_packet_utils::{DecodeError, Emitable, Parseable};
 const VF_INFO_LINK_STATE_LEN: usize = 8;
 
 #[derive(Debug, Clone, Copy, Eq, PartialEq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[non_exhaustive]
 pub struct VfInfoLinkState {
     pub vf_id: u32,
@@ -47,6 +48,7 @@ const IFLA_VF_LINK_STATE_ENABLE: u32 = 1;
 const IFLA_VF_LINK_STATE_DISABLE: u32 = 2;
 
 #[derive(Debug, Clone, Copy, Eq, PartialEq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[non_exhaustive]
 pub enum VfLinkState {
     #[default]
@@ -7,6 +7,7 @@ const MAX_ADDR_LEN: usize = 32;
 const VF_INFO_MAC_LEN: usize = MAX_ADDR_LEN + 4;
 
 #[derive(Debug, Clone, Copy, Eq, PartialEq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[non_exhaustive]
 pub struct VfInfoMac {
     pub vf_id: u32,
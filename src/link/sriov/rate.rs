@@ -5,6 +5,7 @@ use netlink_packet_utils::{DecodeError, Emitable, Parseable};
 const VF_INFO_RATE_LEN: usize = 12;
 
 #[derive(Debug, Clone, Copy, Eq, PartialEq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[non_exhaustive]
 pub struct VfInfoRate {
     pub vf_id: u32,
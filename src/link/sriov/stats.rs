@@ -19,6 +19,7 @@ const IFLA_VF_STATS_RX_DROPPED: u16 = 7;
 const IFLA_VF_STATS_TX_DROPPED: u16 = 8;
 
 #[derive(Debug, Clone, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[non_exhaustive]
 pub enum VfStats {
     RxPackets(u64),
@@ -29,6 +30,7 @@ pub enum VfStats {
     Multicast(u64),
     RxDropped(u64),
     TxDropped(u64),
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_nla"))]
     Other(DefaultNla),
 }
 
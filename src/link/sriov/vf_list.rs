@@ -18,6 +18,7 @@ use crate::link::{
 const IFLA_VF_INFO: u16 = 1;
 
 #[derive(Debug, Clone, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub(crate) struct VecLinkVfInfo(pub(crate) Vec<LinkVfInfo>);
 
 impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>>
@@ -47,6 +48,7 @@ impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>>
 }
 
 #[derive(Debug, Clone, Default, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct LinkVfInfo(pub Vec<VfInfo>);
 
 impl Nla for LinkVfInfo {
@@ -92,6 +94,7 @@ const IFLA_VF_VLAN_LIST: u16 = 12;
 const IFLA_VF_BROADCAST: u16 = 13;
 
 #[derive(Debug, Clone, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[non_exhaustive]
 pub enum VfInfo {
     Mac(VfInfoMac),
@@ -107,6 +110,7 @@ pub enum VfInfo {
     IbPortGuid(VfInfoGuid),
     VlanList(Vec<VfVlan>),
     Stats(Vec<VfStats>),
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_nla"))]
     Other(DefaultNla),
 }
 
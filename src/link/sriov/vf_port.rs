@@ -7,6 +7,7 @@ use netlink_packet_utils::{
 };
 
 #[derive(Debug, Clone, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub(crate) struct VecLinkVfPort(pub(crate) Vec<LinkVfPort>);
 
 impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>>
@@ -38,6 +39,7 @@ impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>>
 const IFLA_VF_PORT: u16 = 1;
 
 #[derive(Debug, Clone, Default, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct LinkVfPort(pub Vec<VfPort>);
 
 impl Nla for LinkVfPort {
@@ -82,6 +84,7 @@ const UUID_LEN: usize = 16;
 */
 
 #[derive(Debug, Clone, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[non_exhaustive]
 pub enum VfPort {
     //    Vf(u32),
@@ -89,6 +92,7 @@ pub enum VfPort {
     //    InstanceUuid([u8; UUID_LEN]),
     //    HostUuid([u8; UUID_LEN]),
     //    Request(u8),
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_nla"))]
     Other(DefaultNla),
 }
 
@@ -11,9 +11,11 @@ use crate::link::VlanProtocol;
 const IFLA_VF_VLAN_INFO: u16 = 1;
 
 #[derive(Debug, Clone, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[non_exhaustive]
 pub enum VfVlan {
     Info(VfVlanInfo),
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_nla"))]
     Other(DefaultNla),
 }
 
@@ -59,6 +61,7 @@ impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>> for VfVlan {
 const VF_VLAN_INFO_LEN: usize = 16; // with 2 bytes padding
 
 #[derive(Debug, Clone, Copy, Eq, PartialEq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[non_exhaustive]
 pub struct VfVlanInfo {
     pub vf_id: u32,
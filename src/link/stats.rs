@@ -8,6 +8,7 @@ use netlink_packet_utils::{
 pub(crate) const LINK_STATS_LEN: usize = 96;
 
 #[derive(Debug, Clone, Copy, Eq, PartialEq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[non_exhaustive]
 pub struct Stats {
     /// total packets received
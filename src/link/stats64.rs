@@ -35,7 +35,15 @@ buffer!(Stats64Buffer(LINK_STATS64_LEN) {
     rx_otherhost_dropped: (u64, 192..200),
 });
 
+impl_new_strict!(Stats64Buffer, LINK_STATS64_LEN);
+
+/// Link statistics of `IFLA_STATS64`.
+///
+/// When parsed from link message, data longer than expected is ignored and
+/// shorter data(old kernel) is padded with zero, unless
+/// [crate::set_strict_parsing()] is enabled.
 #[derive(Debug, Clone, Copy, Eq, PartialEq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[non_exhaustive]
 pub struct Stats64 {
     /// total packets received
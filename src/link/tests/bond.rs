@@ -1,5 +1,7 @@
 // SPDX-License-Identifier: MIT
 
+use std::net::{Ipv4Addr, Ipv6Addr};
+
 use netlink_packet_utils::{Emitable, Parseable};
 
 use crate::link::link_flag::LinkFlags;
@@ -223,3 +225,322 @@ fn test_bond_arp_validate() {
 
     assert_eq!(raw, buf);
 }
+
+// Hand-crafted RTM_NEWLINK payload equivalent to the one replied by kernel
+// for an active-backup bond `bond0` holding interface index 10, with port
+// holding interface index 3 as both primary and active port.
+// Unrelated attributes are omitted.
+#[test]
+fn test_bond_active_backup_active_port() {
+    let raw: Vec<u8> = vec![
+        0x00, // interface family AF_UNSPEC
+        0x00, // reserved
+        0x01, 0x00, // link layer type 1 = ether
+        0x0a, 0x00, 0x00, 0x00, // interface index 10
+        0x00, 0x00, 0x00, 0x00, // flags
+        0x00, 0x00, 0x00, 0x00, // change mask
+        0x2c, 0x00, // length 44
+        0x12, 0x00, // IFLA_LINKINFO 18
+        0x09, 0x00, // length 9
+        0x01, 0x00, // IFLA_INFO_KIND 1
+        0x62, 0x6f, 0x6e, 0x64, 0x00, // 'bond\0'
+        0x00, 0x00, 0x00, // padding
+        0x1c, 0x00, // length 28
+        0x02, 0x00, // IFLA_INFO_DATA
+        0x05, 0x00, // length 5
+        0x01, 0x00, // IFLA_BOND_MODE
+        0x01, 0x00, 0x00, 0x00, // active-backup and 3 bytes pad
+        0x08, 0x00, // length 8
+        0x02, 0x00, // IFLA_BOND_ACTIVE_SLAVE
+        0x03, 0x00, 0x00, 0x00, // 3
+        0x08, 0x00, // length 8
+        0x0b, 0x00, // IFLA_BOND_PRIMARY
+        0x03, 0x00, 0x00, 0x00, // 3
+    ];
+
+    let expected = LinkMessage {
+        header: LinkHeader {
+            interface_family: AddressFamily::Unspec,
+            index: 10,
+            link_layer_type: LinkLayerType::Ether,
+            flags: LinkFlags::empty(),
+            change_mask: LinkFlags::empty(),
+        },
+        attributes: vec![LinkAttribute::LinkInfo(vec![
+            LinkInfo::Kind(InfoKind::Bond),
+            LinkInfo::Data(InfoData::Bond(vec![
+                InfoBond::Mode(BondMode::ActiveBackup),
+                InfoBond::ActivePort(3),
+                InfoBond::Primary(3),
+            ])),
+        ])],
+    };
+
+    let parsed = LinkMessage::parse(&LinkMessageBuffer::new(&raw)).unwrap();
+    assert_eq!(expected, parsed);
+    assert_eq!(parsed.bond_active_port(), Some(3));
+    assert_eq!(parsed.bond_primary(), Some(3));
+
+    let mut buf = vec![0; expected.buffer_len()];
+    expected.emit(&mut buf);
+    assert_eq!(buf, raw);
+
+    assert_eq!(LinkMessage::default().bond_active_port(), None);
+}
+
+// Hand-crafted RTM_NEWLINK payload laid out as bond_fill_info() of kernel
+// replies for an active-backup bond `bond0` with interface index 11 after
+//      ip link set bond0 type bond arp_interval 1000 \
+//          arp_ip_target 192.0.2.1,192.0.2.2 ns_ip6_target 2001:db8::1 \
+//          ad_actor_sys_prio 100 ad_user_port_key 5 \
+//          ad_actor_system 00:11:22:33:44:55
+// Unrelated attributes are omitted. Not a capture: the kernel of the capture
+// host has no bonding driver, see `test_bond_arp_targets_request()` for the
+// captured request instead.
+#[test]
+fn test_bond_active_backup_arp_targets() {
+    let raw: Vec<u8> = vec![
+        0x00, // interface family AF_UNSPEC
+        0x00, // reserved
+        0x01, 0x00, // link layer type 1 = ether
+        0x0b, 0x00, 0x00, 0x00, // interface index 11
+        0x00, 0x00, 0x00, 0x00, // flags
+        0x00, 0x00, 0x00, 0x00, // change mask
+        0x6c, 0x00, // length 108
+        0x12, 0x00, // IFLA_LINKINFO 18
+        0x09, 0x00, // length 9
+        0x01, 0x00, // IFLA_INFO_KIND 1
+        0x62, 0x6f, 0x6e, 0x64, 0x00, // 'bond\0'
+        0x00, 0x00, 0x00, // padding
+        0x5c, 0x00, // length 92
+        0x02, 0x00, // IFLA_INFO_DATA
+        0x05, 0x00, // length 5
+        0x01, 0x00, // IFLA_BOND_MODE
+        0x01, 0x00, 0x00, 0x00, // active-backup and 3 bytes pad
+        0x08, 0x00, // length 8
+        0x07, 0x00, // IFLA_BOND_ARP_INTERVAL
+        0xe8, 0x03, 0x00, 0x00, // 1000
+        0x14, 0x00, // length 20
+        0x08, 0x00, // IFLA_BOND_ARP_IP_TARGET
+        0x08, 0x00, 0x00, 0x00, // length 8, index 0
+        0xc0, 0x00, 0x02, 0x01, // 192.0.2.1
+        0x08, 0x00, 0x01, 0x00, // length 8, index 1
+        0xc0, 0x00, 0x02, 0x02, // 192.0.2.2
+        0x06, 0x00, // length 6
+        0x18, 0x00, // IFLA_BOND_AD_ACTOR_SYS_PRIO
+        0x64, 0x00, 0x00, 0x00, // 100 and 2 bytes pad
+        0x06, 0x00, // length 6
+        0x19, 0x00, // IFLA_BOND_AD_USER_PORT_KEY
+        0x05, 0x00, 0x00, 0x00, // 5 and 2 bytes pad
+        0x0a, 0x00, // length 10
+        0x1a, 0x00, // IFLA_BOND_AD_ACTOR_SYSTEM
+        0x00, 0x11, 0x22, 0x33, 0x44, 0x55, // 00:11:22:33:44:55
+        0x00, 0x00, // padding
+        0x18, 0x00, // length 24
+        0x1f, 0x00, // IFLA_BOND_NS_IP6_TARGET
+        0x14, 0x00, 0x00, 0x00, // length 20, index 0
+        0x20, 0x01, 0x0d, 0xb8, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x01, // 2001:db8::1
+    ];
+
+    let expected = LinkMessage {
+        header: LinkHeader {
+            interface_family: AddressFamily::Unspec,
+            index: 11,
+            link_layer_type: LinkLayerType::Ether,
+            flags: LinkFlags::empty(),
+            change_mask: LinkFlags::empty(),
+        },
+        attributes: vec![LinkAttribute::LinkInfo(vec![
+            LinkInfo::Kind(InfoKind::Bond),
+            LinkInfo::Data(InfoData::Bond(vec![
+                InfoBond::Mode(BondMode::ActiveBackup),
+                InfoBond::ArpInterval(1000),
+                InfoBond::ArpIpTarget(vec![
+                    Ipv4Addr::new(192, 0, 2, 1),
+                    Ipv4Addr::new(192, 0, 2, 2),
+                ]),
+                InfoBond::AdActorSysPrio(100),
+                InfoBond::AdUserPortKey(5),
+                InfoBond::AdActorSystem([0x00, 0x11, 0x22, 0x33, 0x44, 0x55]),
+                InfoBond::NsIp6Target(vec![Ipv6Addr::new(
+                    0x2001, 0xdb8, 0, 0, 0, 0, 0, 1,
+                )]),
+            ])),
+        ])],
+    };
+
+    assert_eq!(
+        expected,
+        LinkMessage::parse(&LinkMessageBuffer::new(&raw)).unwrap()
+    );
+
+    let mut buf = vec![0; expected.buffer_len()];
+    expected.emit(&mut buf);
+    assert_eq!(buf, raw);
+}
+
+// Captured RTM_NEWLINK request sent by iproute2 6.1 for
+//      ip link add bond0 type bond mode active-backup arp_interval 1000 \
+//          arp_ip_target 192.0.2.1,192.0.2.2 ns_ip6_target 2001:db8::1 \
+//          ad_actor_sys_prio 100 ad_user_port_key 5 \
+//          ad_actor_system 00:11:22:33:44:55
+// with netlink header removed. iproute2 sends IFLA_INFO_KIND without the
+// trailing NUL, hence only parsing is checked.
+#[test]
+fn test_bond_arp_targets_request() {
+    let raw: Vec<u8> = vec![
+        0x00, // interface family AF_UNSPEC
+        0x00, // reserved
+        0x00, 0x00, // link layer type 0 = netrom
+        0x00, 0x00, 0x00, 0x00, // interface index 0
+        0x00, 0x00, 0x00, 0x00, // flags
+        0x00, 0x00, 0x00, 0x00, // change mask
+        0x0a, 0x00, // length 10
+        0x03, 0x00, // IFLA_IFNAME 3
+        0x62, 0x6f, 0x6e, 0x64, 0x30, 0x00, // 'bond0\0'
+        0x00, 0x00, // padding
+        0x68, 0x00, // length 104
+        0x12, 0x00, // IFLA_LINKINFO 18
+        0x08, 0x00, // length 8
+        0x01, 0x00, // IFLA_INFO_KIND 1
+        0x62, 0x6f, 0x6e, 0x64, // 'bond'
+        0x5c, 0x00, // length 92
+        0x02, 0x00, // IFLA_INFO_DATA
+        0x05, 0x00, // length 5
+        0x01, 0x00, // IFLA_BOND_MODE
+        0x01, 0x00, 0x00, 0x00, // active-backup and 3 bytes pad
+        0x08, 0x00, // length 8
+        0x07, 0x00, // IFLA_BOND_ARP_INTERVAL
+        0xe8, 0x03, 0x00, 0x00, // 1000
+        0x14, 0x00, // length 20
+        0x08, 0x00, // IFLA_BOND_ARP_IP_TARGET
+        0x08, 0x00, 0x00, 0x00, // length 8, index 0
+        0xc0, 0x00, 0x02, 0x01, // 192.0.2.1
+        0x08, 0x00, 0x01, 0x00, // length 8, index 1
+        0xc0, 0x00, 0x02, 0x02, // 192.0.2.2
+        0x18, 0x00, // length 24
+        0x1f, 0x00, // IFLA_BOND_NS_IP6_TARGET
+        0x14, 0x00, 0x00, 0x00, // length 20, index 0
+        0x20, 0x01, 0x0d, 0xb8, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x01, // 2001:db8::1
+        0x06, 0x00, // length 6
+        0x18, 0x00, // IFLA_BOND_AD_ACTOR_SYS_PRIO
+        0x64, 0x00, 0x00, 0x00, // 100 and 2 bytes pad
+        0x06, 0x00, // length 6
+        0x19, 0x00, // IFLA_BOND_AD_USER_PORT_KEY
+        0x05, 0x00, 0x00, 0x00, // 5 and 2 bytes pad
+        0x0a, 0x00, // length 10
+        0x1a, 0x00, // IFLA_BOND_AD_ACTOR_SYSTEM
+        0x00, 0x11, 0x22, 0x33, 0x44, 0x55, // 00:11:22:33:44:55
+        0x00, 0x00, // padding
+    ];
+
+    let expected = LinkMessage {
+        header: LinkHeader {
+            link_layer_type: LinkLayerType::Netrom,
+            ..Default::default()
+        },
+        attributes: vec![
+            LinkAttribute::IfName("bond0".to_string()),
+            LinkAttribute::LinkInfo(vec![
+                LinkInfo::Kind(InfoKind::Bond),
+                LinkInfo::Data(InfoData::Bond(vec![
+                    InfoBond::Mode(BondMode::ActiveBackup),
+                    InfoBond::ArpInterval(1000),
+                    InfoBond::ArpIpTarget(vec![
+                        Ipv4Addr::new(192, 0, 2, 1),
+                        Ipv4Addr::new(192, 0, 2, 2),
+                    ]),
+                    InfoBond::NsIp6Target(vec![Ipv6Addr::new(
+                        0x2001, 0xdb8, 0, 0, 0, 0, 0, 1,
+                    )]),
+                    InfoBond::AdActorSysPrio(100),
+                    InfoBond::AdUserPortKey(5),
+                    InfoBond::AdActorSystem([
+                        0x00, 0x11, 0x22, 0x33, 0x44, 0x55,
+                    ]),
+                ])),
+            ]),
+        ],
+    };
+
+    assert_eq!(
+        expected,
+        LinkMessage::parse(&LinkMessageBuffer::new(&raw)).unwrap()
+    );
+}
+
+// Hand-crafted RTM_NEWLINK payload equivalent to the one replied by kernel
+// for veth port of 802.3ad bond after
+//      ip link set veth1 type bond_slave queue_id 2
+// Unrelated attributes are omitted.
+#[test]
+fn test_bond_port_queue_id_ad_info() {
+    let raw: Vec<u8> = vec![
+        0x00, // interface family AF_UNSPEC
+        0x00, // reserved
+        0x01, 0x00, // link layer type 1 = ether
+        0x15, 0x00, 0x00, 0x00, // interface index 21
+        0x00, 0x00, 0x00, 0x00, // flags
+        0x00, 0x00, 0x00, 0x00, // change mask
+        0x50, 0x00, // length 80
+        0x12, 0x00, // IFLA_LINKINFO 18
+        0x09, 0x00, // length 9
+        0x01, 0x00, // IFLA_INFO_KIND 1
+        0x76, 0x65, 0x74, 0x68, 0x00, // 'veth\0'
+        0x00, 0x00, 0x00, // padding
+        0x09, 0x00, // length 9
+        0x04, 0x00, // IFLA_INFO_SLAVE_KIND
+        0x62, 0x6f, 0x6e, 0x64, 0x00, // 'bond\0'
+        0x00, 0x00, 0x00, // padding
+        0x34, 0x00, // length 52
+        0x05, 0x00, // IFLA_INFO_SLAVE_DATA
+        0x05, 0x00, 0x01, 0x00, // length 5, IFLA_BOND_SLAVE_STATE
+        0x00, 0x00, 0x00, 0x00, // active and 3 bytes pad
+        0x06, 0x00, 0x05, 0x00, // length 6, IFLA_BOND_SLAVE_QUEUE_ID
+        0x02, 0x00, 0x00, 0x00, // 2 and 2 bytes pad
+        0x06, 0x00, 0x06,
+        0x00, // length 6, IFLA_BOND_SLAVE_AD_AGGREGATOR_ID
+        0x01, 0x00, 0x00, 0x00, // 1 and 2 bytes pad
+        0x05, 0x00, 0x07, 0x00, // length 5,
+        // IFLA_BOND_SLAVE_AD_ACTOR_OPER_PORT_STATE
+        0x3d, 0x00, 0x00, 0x00, // 0x3d and 3 bytes pad
+        0x06, 0x00, 0x08, 0x00, // length 6,
+        // IFLA_BOND_SLAVE_AD_PARTNER_OPER_PORT_STATE
+        0x3d, 0x00, 0x00, 0x00, // 0x3d and 2 bytes pad
+        0x08, 0x00, 0x09, 0x00, // length 8, IFLA_BOND_SLAVE_PRIO
+        0x00, 0x00, 0x00, 0x00, // 0
+    ];
+
+    let expected = LinkMessage {
+        header: LinkHeader {
+            interface_family: AddressFamily::Unspec,
+            index: 21,
+            link_layer_type: LinkLayerType::Ether,
+            flags: LinkFlags::empty(),
+            change_mask: LinkFlags::empty(),
+        },
+        attributes: vec![LinkAttribute::LinkInfo(vec![
+            LinkInfo::Kind(InfoKind::Veth),
+            LinkInfo::PortKind(InfoPortKind::Bond),
+            LinkInfo::PortData(InfoPortData::BondPort(vec![
+                InfoBondPort::BondPortState(BondPortState::Active),
+                InfoBondPort::QueueId(2),
+                InfoBondPort::AdAggregatorId(1),
+                InfoBondPort::AdActorOperPortState(0x3d),
+                InfoBondPort::AdPartnerOperPortState(0x3d),
+                InfoBondPort::Prio(0),
+            ])),
+        ])],
+    };
+
+    assert_eq!(
+        expected,
+        LinkMessage::parse(&LinkMessageBuffer::new(&raw)).unwrap()
+    );
+
+    let mut buf = vec![0; expected.buffer_len()];
+    expected.emit(&mut buf);
+    assert_eq!(buf, raw);
+}
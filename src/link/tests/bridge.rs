@@ -1,7 +1,7 @@
 // SPDX-License-Identifier: MIT
 
 use netlink_packet_utils::{
-    nla::{DefaultNla, NlaBuffer},
+    nla::{DefaultNla, NlaBuffer, NlasIterator},
     Emitable, Parseable,
 };
 
@@ -169,9 +169,9 @@ fn test_parse_link_bridge_no_extention_mask() {
             LinkAttribute::NumTxQueues(1),
             LinkAttribute::GsoMaxSegs(65535),
             LinkAttribute::GsoMaxSize(65536),
-            LinkAttribute::Other(DefaultNla::new(58, vec![0, 0, 1, 0])),
-            LinkAttribute::Other(DefaultNla::new(63, vec![0, 0, 1, 0])),
-            LinkAttribute::Other(DefaultNla::new(64, vec![0, 0, 1, 0])),
+            LinkAttribute::GroMaxSize(65536),
+            LinkAttribute::GsoIpv4MaxSize(65536),
+            LinkAttribute::GroIpv4MaxSize(65536),
             LinkAttribute::Other(DefaultNla::new(59, vec![248, 255, 7, 0])),
             LinkAttribute::Other(DefaultNla::new(60, vec![255, 255, 0, 0])),
             LinkAttribute::NumRxQueues(1),
@@ -409,7 +409,7 @@ fn test_parse_link_bridge_no_extention_mask() {
                     }),
                 ]),
             ]),
-            LinkAttribute::Other(DefaultNla::new(32830, vec![])),
+            LinkAttribute::DevlinkPort(vec![]),
         ],
     };
 
@@ -637,3 +637,130 @@ fn test_af_spec_bridge_vlan_tunnel_info() {
         expected
     );
 }
+
+// Hand-crafted IFLA_AF_SPEC payload of RTM_SETLINK equivalent to command:
+//      bridge vlan add dev vxlan0 vid 100-110 tunnel_info id 1000-1010
+#[test]
+fn test_af_spec_bridge_vlan_tunnel_info_range() {
+    let raw: Vec<u8> = vec![
+        0x1c, 0x00, 0x03, 0x00, // length 28, IFLA_BRIDGE_VLAN_TUNNEL_INFO
+        0x08, 0x00, 0x01, 0x00, // length 8, IFLA_BRIDGE_VLAN_TUNNEL_ID
+        0xe8, 0x03, 0x00, 0x00, // 1000
+        0x06, 0x00, 0x02, 0x00, // length 6, IFLA_BRIDGE_VLAN_TUNNEL_VID
+        0x64, 0x00, 0x00, 0x00, // 100 and padding
+        0x06, 0x00, 0x03, 0x00, // length 6, IFLA_BRIDGE_VLAN_TUNNEL_FLAGS
+        0x08, 0x00, 0x00,
+        0x00, // BRIDGE_VLAN_INFO_RANGE_BEGIN and padding
+        0x1c, 0x00, 0x03, 0x00, // length 28, IFLA_BRIDGE_VLAN_TUNNEL_INFO
+        0x08, 0x00, 0x01, 0x00, // length 8, IFLA_BRIDGE_VLAN_TUNNEL_ID
+        0xf2, 0x03, 0x00, 0x00, // 1010
+        0x06, 0x00, 0x02, 0x00, // length 6, IFLA_BRIDGE_VLAN_TUNNEL_VID
+        0x6e, 0x00, 0x00, 0x00, // 110 and padding
+        0x06, 0x00, 0x03, 0x00, // length 6, IFLA_BRIDGE_VLAN_TUNNEL_FLAGS
+        0x10, 0x00, 0x00, 0x00, // BRIDGE_VLAN_INFO_RANGE_END and padding
+    ];
+
+    let expected = vec![
+        AfSpecBridge::VlanTunnelInfo(vec![
+            BridgeVlanTunnelInfo::Id(1000),
+            BridgeVlanTunnelInfo::Vid(100),
+            BridgeVlanTunnelInfo::Flags(BridgeVlanInfoFlags::RangeBegin),
+        ]),
+        AfSpecBridge::VlanTunnelInfo(vec![
+            BridgeVlanTunnelInfo::Id(1010),
+            BridgeVlanTunnelInfo::Vid(110),
+            BridgeVlanTunnelInfo::Flags(BridgeVlanInfoFlags::RangeEnd),
+        ]),
+    ];
+
+    let entries =
+        AfSpecBridge::vlan_tunnel_info_ranges((100..=110).zip(1000..=1010));
+    assert_eq!(entries, expected);
+
+    let mut buf = vec![0; entries.as_slice().buffer_len()];
+    entries.as_slice().emit(&mut buf);
+    assert_eq!(buf, raw);
+
+    assert_eq!(
+        VecAfSpecBridge::parse(&NlaBuffer::new(&raw)).unwrap().0,
+        expected
+    );
+
+    // Gaps in either VID or tunnel ID break the range
+    assert_eq!(
+        AfSpecBridge::vlan_tunnel_info_ranges([
+            (100, 1000),
+            (101, 1001),
+            (102, 1005),
+            (104, 1006),
+        ]),
+        vec![
+            AfSpecBridge::VlanTunnelInfo(vec![
+                BridgeVlanTunnelInfo::Id(1000),
+                BridgeVlanTunnelInfo::Vid(100),
+                BridgeVlanTunnelInfo::Flags(BridgeVlanInfoFlags::RangeBegin),
+            ]),
+            AfSpecBridge::VlanTunnelInfo(vec![
+                BridgeVlanTunnelInfo::Id(1001),
+                BridgeVlanTunnelInfo::Vid(101),
+                BridgeVlanTunnelInfo::Flags(BridgeVlanInfoFlags::RangeEnd),
+            ]),
+            AfSpecBridge::VlanTunnelInfo(vec![
+                BridgeVlanTunnelInfo::Id(1005),
+                BridgeVlanTunnelInfo::Vid(102),
+                BridgeVlanTunnelInfo::Flags(BridgeVlanInfoFlags::empty()),
+            ]),
+            AfSpecBridge::VlanTunnelInfo(vec![
+                BridgeVlanTunnelInfo::Id(1006),
+                BridgeVlanTunnelInfo::Vid(104),
+                BridgeVlanTunnelInfo::Flags(BridgeVlanInfoFlags::empty()),
+            ]),
+        ]
+    );
+}
+
+// Hand-crafted IFLA_BR_* attributes nested in IFLA_INFO_DATA of a bridge dump
+// with multicast intervals (clock_t in USER_HZ) beyond 32 bits, to make sure
+// they are not truncated.
+#[test]
+fn test_bridge_mcast_intervals_64bit() {
+    let raw = vec![
+        0x06, 0x00, 0x09, 0x00, // length 6, IFLA_BR_GROUP_FWD_MASK 9
+        0x08, 0x40, 0x00, 0x00, // 0x4008 and padding
+        0x0c, 0x00, 0x1e,
+        0x00, // length 12, IFLA_BR_MCAST_LAST_MEMBER_INTVL
+        0x64, 0x00, 0x00, 0x00, 0x01, 0x00, 0x00, 0x00, // 0x1_0000_0064
+        0x0c, 0x00, 0x1f,
+        0x00, // length 12, IFLA_BR_MCAST_MEMBERSHIP_INTVL
+        0x9b, 0x65, 0x00, 0x00, 0x01, 0x00, 0x00, 0x00, // 0x1_0000_659b
+        0x0c, 0x00, 0x20, 0x00, // length 12, IFLA_BR_MCAST_QUERIER_INTVL
+        0x8f, 0x63, 0x00, 0x00, 0x02, 0x00, 0x00, 0x00, // 0x2_0000_638f
+        0x0c, 0x00, 0x21, 0x00, // length 12, IFLA_BR_MCAST_QUERY_INTVL
+        0xd3, 0x30, 0x00, 0x00, 0x03, 0x00, 0x00, 0x00, // 0x3_0000_30d3
+        0x0c, 0x00, 0x22,
+        0x00, // length 12, IFLA_BR_MCAST_QUERY_RESPONSE_INTVL
+        0xe8, 0x03, 0x00, 0x00, 0x04, 0x00, 0x00, 0x00, // 0x4_0000_03e8
+        0x0c, 0x00, 0x23,
+        0x00, // length 12, IFLA_BR_MCAST_STARTUP_QUERY_INTVL
+        0x34, 0x0c, 0x00, 0x00, 0x05, 0x00, 0x00, 0x00, // 0x5_0000_0c34
+    ];
+
+    let expected = vec![
+        InfoBridge::GroupFwdMask(0x4008),
+        InfoBridge::MulticastLastMemberInterval(0x1_0000_0064),
+        InfoBridge::MulticastMembershipInterval(0x1_0000_659b),
+        InfoBridge::MulticastQuerierInterval(0x2_0000_638f),
+        InfoBridge::MulticastQueryInterval(0x3_0000_30d3),
+        InfoBridge::MulticastQueryResponseInterval(0x4_0000_03e8),
+        InfoBridge::MulticastStartupQueryInterval(0x5_0000_0c34),
+    ];
+
+    let parsed = NlasIterator::new(&raw)
+        .map(|nla| InfoBridge::parse(&nla.unwrap()).unwrap())
+        .collect::<Vec<_>>();
+    assert_eq!(parsed, expected);
+
+    let mut buf = vec![0; expected.as_slice().buffer_len()];
+    expected.as_slice().emit(&mut buf);
+    assert_eq!(buf, raw);
+}
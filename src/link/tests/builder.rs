@@ -0,0 +1,256 @@
+// SPDX-License-Identifier: MIT
+
+use netlink_packet_utils::{Emitable, Parseable};
+
+use crate::link::link_flag::LinkFlags;
+use crate::link::{
+    InfoData, InfoKind, InfoVlan, LinkAttribute, LinkExtentMask, LinkHeader,
+    LinkInfo, LinkMessage, LinkMessageBuffer, LinkMessageBuilder,
+};
+
+// Payload of RTM_NEWLINK generated by command:
+//   ip link add link eth0 name eth0.101 up type vlan id 101
+// with eth0 holding interface index 2.
+#[test]
+fn test_link_message_builder_vlan() {
+    let raw = vec![
+        0x00, // interface family AF_UNSPEC
+        0x00, // reserved
+        0x00, 0x00, // link layer type 0
+        0x00, 0x00, 0x00, 0x00, // interface index 0
+        0x01, 0x00, 0x00, 0x00, // flags IFF_UP
+        0x01, 0x00, 0x00, 0x00, // change mask IFF_UP
+        0x0d, 0x00, // length 13
+        0x03, 0x00, // IFLA_IFNAME
+        0x65, 0x74, 0x68, 0x30, 0x2e, 0x31, 0x30, 0x31,
+        0x00, // 'eth0.101\0'
+        0x00, 0x00, 0x00, // padding
+        0x08, 0x00, // length 8
+        0x05, 0x00, // IFLA_LINK
+        0x02, 0x00, 0x00, 0x00, // 2
+        0x1c, 0x00, // length 28
+        0x12, 0x00, // IFLA_LINKINFO 18
+        0x09, 0x00, // length 9
+        0x01, 0x00, // IFLA_INFO_KIND 1
+        0x76, 0x6c, 0x61, 0x6e, 0x00, // 'vlan\0'
+        0x00, 0x00, 0x00, // padding
+        0x0c, 0x00, // length 12
+        0x02, 0x00, // IFLA_INFO_DATA 2
+        0x06, 0x00, // length 6
+        0x01, 0x00, // IFLA_VLAN_ID 1
+        0x65, 0x00, // VLAN ID 101
+        0x00, 0x00, // padding
+    ];
+
+    let expected = LinkMessage {
+        header: LinkHeader {
+            flags: LinkFlags::Up,
+            change_mask: LinkFlags::Up,
+            ..Default::default()
+        },
+        attributes: vec![
+            LinkAttribute::IfName("eth0.101".to_string()),
+            LinkAttribute::Link(2),
+            LinkAttribute::LinkInfo(vec![
+                LinkInfo::Kind(InfoKind::Vlan),
+                LinkInfo::Data(InfoData::Vlan(vec![InfoVlan::Id(101)])),
+            ]),
+        ],
+    };
+
+    // The order of kind() and info_data() should not matter
+    let message = LinkMessageBuilder::new()
+        .name("eth0.101".to_string())
+        .link(2)
+        .up()
+        .info_data(InfoData::Vlan(vec![InfoVlan::Id(101)]))
+        .kind(InfoKind::Vlan)
+        .build();
+
+    assert_eq!(message, expected);
+
+    let mut buf = vec![0; message.buffer_len()];
+    message.emit(&mut buf);
+    assert_eq!(buf, raw);
+
+    assert_eq!(
+        expected,
+        LinkMessage::parse(&LinkMessageBuffer::new(&raw)).unwrap()
+    );
+}
+
+#[test]
+fn test_link_message_builder_set() {
+    let message = LinkMessageBuilder::new()
+        .index(3)
+        .mtu(9000)
+        .up()
+        .down()
+        .build();
+
+    assert_eq!(
+        message,
+        LinkMessage {
+            header: LinkHeader {
+                index: 3,
+                flags: LinkFlags::empty(),
+                change_mask: LinkFlags::Up,
+                ..Default::default()
+            },
+            attributes: vec![LinkAttribute::Mtu(9000)],
+        }
+    );
+}
+
+// Hand-crafted RTM_GETLINK dump request payload asking kernel to include
+// SR-IOV VF information in reply.
+#[test]
+fn test_link_message_builder_dump_vf() {
+    let raw = vec![
+        0x00, // interface family AF_UNSPEC
+        0x00, // reserved
+        0x00, 0x00, // link layer type 0
+        0x00, 0x00, 0x00, 0x00, // interface index 0
+        0x00, 0x00, 0x00, 0x00, // flags
+        0x00, 0x00, 0x00, 0x00, // change mask
+        0x08, 0x00, // length 8
+        0x1d, 0x00, // IFLA_EXT_MASK 29
+        0x01, 0x00, 0x00, 0x00, // RTEXT_FILTER_VF
+    ];
+
+    let message =
+        LinkMessageBuilder::for_dump(vec![LinkExtentMask::Vf]).build();
+
+    assert_eq!(
+        message,
+        LinkMessage {
+            header: LinkHeader::default(),
+            attributes: vec![LinkAttribute::ExtMask(vec![LinkExtentMask::Vf])],
+        }
+    );
+
+    let mut buf = vec![0; message.buffer_len()];
+    message.emit(&mut buf);
+    assert_eq!(buf, raw);
+
+    assert_eq!(
+        LinkMessageBuilder::for_dump(vec![]).build(),
+        LinkMessage::default()
+    );
+}
+
+// Hand-crafted RTM_NEWLINK request payload equivalent to moving eth1 holding
+// interface index 3 into the network namespace of process 1234 with new
+// interface index 10.
+#[test]
+fn test_link_message_builder_netns_move() {
+    let raw = vec![
+        0x00, // interface family AF_UNSPEC
+        0x00, // reserved
+        0x00, 0x00, // link layer type 0
+        0x03, 0x00, 0x00, 0x00, // interface index 3
+        0x00, 0x00, 0x00, 0x00, // flags
+        0x00, 0x00, 0x00, 0x00, // change mask
+        0x08, 0x00, // length 8
+        0x13, 0x00, // IFLA_NET_NS_PID 19
+        0xd2, 0x04, 0x00, 0x00, // 1234
+        0x08, 0x00, // length 8
+        0x31, 0x00, // IFLA_NEW_IFINDEX 49
+        0x0a, 0x00, 0x00, 0x00, // 10
+    ];
+
+    let message = LinkMessageBuilder::new()
+        .index(3)
+        .netns_pid(1234)
+        .new_ifindex(10)
+        .build();
+
+    assert_eq!(
+        message,
+        LinkMessage {
+            header: LinkHeader {
+                index: 3,
+                ..Default::default()
+            },
+            attributes: vec![
+                LinkAttribute::NetNsPid(1234),
+                LinkAttribute::NewIfIndex(10),
+            ],
+        }
+    );
+
+    let mut buf = vec![0; message.buffer_len()];
+    message.emit(&mut buf);
+    assert_eq!(buf, raw);
+
+    assert_eq!(
+        message,
+        LinkMessage::parse(&LinkMessageBuffer::new(&raw)).unwrap()
+    );
+}
+
+// Hand-crafted RTM_DELLINK notification payload of the link move above seen
+// from the original network namespace, where the target network namespace
+// holds ID 1.
+#[test]
+fn test_link_netns_move_notification() {
+    let raw = vec![
+        0x00, // interface family AF_UNSPEC
+        0x00, // reserved
+        0x01, 0x00, // link layer type ARPHRD_ETHER
+        0x03, 0x00, 0x00, 0x00, // interface index 3
+        0x00, 0x00, 0x00, 0x00, // flags
+        0x00, 0x00, 0x00, 0x00, // change mask
+        0x08, 0x00, // length 8
+        0x2d, 0x00, // IFLA_NEW_NETNSID 45
+        0x01, 0x00, 0x00, 0x00, // 1
+        0x08, 0x00, // length 8
+        0x31, 0x00, // IFLA_NEW_IFINDEX 49
+        0x0a, 0x00, 0x00, 0x00, // 10
+    ];
+
+    let message = LinkMessage::parse(&LinkMessageBuffer::new(&raw)).unwrap();
+    assert_eq!(message.new_netns_id(), Some(1));
+    assert_eq!(message.new_ifindex(), Some(10));
+
+    let mut buf = vec![0; message.buffer_len()];
+    message.emit(&mut buf);
+    assert_eq!(buf, raw);
+}
+
+#[test]
+fn test_link_message_up_down() {
+    let mut message = LinkMessage::default();
+    message.header.index = 2;
+    message.up();
+    assert_eq!(message.header.flags, LinkFlags::Up);
+    assert_eq!(message.header.change_mask, LinkFlags::Up);
+    assert!(message.is_up());
+
+    message.down();
+    assert_eq!(message.header.flags, LinkFlags::empty());
+    assert_eq!(message.header.change_mask, LinkFlags::Up);
+    assert!(!message.is_up());
+}
+
+#[test]
+fn test_link_header_set_flags() {
+    let mut header = LinkHeader {
+        flags: LinkFlags::Up | LinkFlags::Broadcast,
+        ..Default::default()
+    };
+    // Bits of flags outside of mask are ignored
+    header.set_flags(
+        LinkFlags::Promisc | LinkFlags::Multicast,
+        LinkFlags::Promisc,
+    );
+    assert_eq!(
+        header.flags,
+        LinkFlags::Up | LinkFlags::Broadcast | LinkFlags::Promisc
+    );
+    assert_eq!(header.change_mask, LinkFlags::Promisc);
+
+    header.set_flags(LinkFlags::empty(), LinkFlags::Up);
+    assert_eq!(header.flags, LinkFlags::Broadcast | LinkFlags::Promisc);
+    assert_eq!(header.change_mask, LinkFlags::Promisc | LinkFlags::Up);
+}
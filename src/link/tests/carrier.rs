@@ -0,0 +1,66 @@
+// SPDX-License-Identifier: MIT
+
+use netlink_packet_utils::{Emitable, Parseable};
+
+use crate::link::link_flag::LinkFlags;
+use crate::link::{
+    LinkAttribute, LinkHeader, LinkLayerType, LinkMessage, LinkMessageBuffer,
+};
+use crate::AddressFamily;
+
+// Captured RTM_NEWLINK reply of veth interface vc0, trimmed to the carrier
+// attributes, after:
+//      ip link add vc0 type veth peer name vc1
+//      ip link set vc0 up
+//      ip link set vc1 up
+//      ip link set vc1 down
+//      ip link set vc1 up
+#[test]
+fn test_link_carrier_counts() {
+    let raw = vec![
+        0x00, // interface family AF_UNSPEC
+        0x00, // reserved
+        0x01, 0x00, // link layer type ARPHRD_ETHER
+        0x0d, 0x00, 0x00, 0x00, // interface index 13
+        0x43, 0x10, 0x01, 0x00, // UP|BROADCAST|RUNNING|MULTICAST|LOWER_UP
+        0x00, 0x00, 0x00, 0x00, // change mask
+        0x05, 0x00, 0x21, 0x00, // length 5, IFLA_CARRIER 33
+        0x01, 0x00, 0x00, 0x00, // 1 and padding
+        0x08, 0x00, 0x23, 0x00, // length 8, IFLA_CARRIER_CHANGES 35
+        0x04, 0x00, 0x00, 0x00, // 4
+        0x08, 0x00, 0x2f, 0x00, // length 8, IFLA_CARRIER_UP_COUNT 47
+        0x02, 0x00, 0x00, 0x00, // 2
+        0x08, 0x00, 0x30, 0x00, // length 8, IFLA_CARRIER_DOWN_COUNT 48
+        0x02, 0x00, 0x00, 0x00, // 2
+    ];
+
+    let expected = LinkMessage {
+        header: LinkHeader {
+            interface_family: AddressFamily::Unspec,
+            index: 13,
+            link_layer_type: LinkLayerType::Ether,
+            flags: LinkFlags::Up
+                | LinkFlags::Broadcast
+                | LinkFlags::Running
+                | LinkFlags::Multicast
+                | LinkFlags::LowerUp,
+            change_mask: LinkFlags::empty(),
+        },
+        attributes: vec![
+            LinkAttribute::Carrier(1),
+            LinkAttribute::CarrierChanges(4),
+            LinkAttribute::CarrierUpCount(2),
+            LinkAttribute::CarrierDownCount(2),
+        ],
+    };
+
+    let parsed = LinkMessage::parse(&LinkMessageBuffer::new(&raw)).unwrap();
+    assert_eq!(parsed, expected);
+    assert_eq!(parsed.carrier_up_count(), Some(2));
+    assert_eq!(parsed.carrier_down_count(), Some(2));
+    assert_eq!(LinkMessage::default().carrier_up_count(), None);
+
+    let mut buf = vec![0; expected.buffer_len()];
+    expected.emit(&mut buf);
+    assert_eq!(buf, raw);
+}
@@ -0,0 +1,70 @@
+// SPDX-License-Identifier: MIT
+
+use netlink_packet_utils::{Emitable, Parseable};
+
+use crate::link::link_flag::LinkFlags;
+use crate::link::{
+    LinkAttribute, LinkDevlinkPort, LinkHeader, LinkLayerType, LinkMessage,
+    LinkMessageBuffer,
+};
+use crate::AddressFamily;
+
+// Hand-crafted RTM_NEWLINK reply payload equivalent to `ip link show` of a
+// SmartNIC uplink representor enp3s0f0np0 backed by devlink port
+// pci/0000:03:00.0/65535, holding IFLA_DEVLINK_PORT only.
+#[test]
+fn test_devlink_port() {
+    let raw = vec![
+        0x00, // interface family AF_UNSPEC
+        0x00, // reserved
+        0x01, 0x00, // link layer type ARPHRD_ETHER
+        0x04, 0x00, 0x00, 0x00, // interface index 4
+        0x43, 0x10, 0x01,
+        0x00, // flags UP,BROADCAST,RUNNING,MULTICAST,LOWER_UP
+        0x00, 0x00, 0x00, 0x00, // change mask
+        0x28, 0x00, // length 40
+        0x3e, 0x80, // IFLA_DEVLINK_PORT | NLA_F_NESTED
+        0x08, 0x00, // length 8
+        0x01, 0x00, // DEVLINK_ATTR_BUS_NAME
+        0x70, 0x63, 0x69, 0x00, // "pci\0"
+        0x11, 0x00, // length 17
+        0x02, 0x00, // DEVLINK_ATTR_DEV_NAME
+        0x30, 0x30, 0x30, 0x30, 0x3a, 0x30, 0x33, 0x3a, 0x30, 0x30, 0x2e, 0x30,
+        0x00, // "0000:03:00.0\0"
+        0x00, 0x00, 0x00, // padding
+        0x08, 0x00, // length 8
+        0x03, 0x00, // DEVLINK_ATTR_PORT_INDEX
+        0xff, 0xff, 0x00, 0x00, // 65535
+    ];
+
+    let port = vec![
+        LinkDevlinkPort::BusName("pci".to_string()),
+        LinkDevlinkPort::DevName("0000:03:00.0".to_string()),
+        LinkDevlinkPort::PortIndex(65535),
+    ];
+
+    let expected = LinkMessage {
+        header: LinkHeader {
+            interface_family: AddressFamily::Unspec,
+            index: 4,
+            link_layer_type: LinkLayerType::Ether,
+            flags: LinkFlags::Broadcast
+                | LinkFlags::LowerUp
+                | LinkFlags::Multicast
+                | LinkFlags::Running
+                | LinkFlags::Up,
+            change_mask: LinkFlags::empty(),
+        },
+        attributes: vec![LinkAttribute::DevlinkPort(port.clone())],
+    };
+
+    let parsed = LinkMessage::parse(&LinkMessageBuffer::new(&raw)).unwrap();
+    assert_eq!(parsed, expected);
+    assert_eq!(parsed.devlink_port(), Some(port.as_slice()));
+
+    let mut buf = vec![0; expected.buffer_len()];
+    expected.emit(&mut buf);
+    assert_eq!(buf, raw);
+
+    assert_eq!(LinkMessage::default().devlink_port(), None);
+}
@@ -0,0 +1,109 @@
+// SPDX-License-Identifier: MIT
+
+use netlink_packet_utils::{Emitable, Parseable};
+
+use crate::link::link_flag::LinkFlags;
+use crate::link::{
+    InfoData, InfoDsa, InfoKind, LinkAttribute, LinkHeader, LinkInfo,
+    LinkLayerType, LinkMessage, LinkMessageBuffer,
+};
+use crate::AddressFamily;
+
+// Hand-crafted RTM_NEWLINK reply payload, trimmed to IFLA_LINKINFO only, of
+// `ip -d link show lan1` for DSA user port lan1 holding interface index 5
+// attached to conduit eth0 holding interface index 2. Not a capture: no DSA
+// switch is available on the capture host, see `test_dsa_conduit_request()`
+// for the captured request instead.
+#[test]
+fn test_parsing_link_dsa() {
+    let raw = vec![
+        0x00, // interface family AF_UNSPEC
+        0x00, // reserved
+        0x01, 0x00, // link layer type ARPHRD_ETHER
+        0x05, 0x00, 0x00, 0x00, // interface index 5
+        0x43, 0x10, 0x01, 0x00, // flags
+        0x00, 0x00, 0x00, 0x00, // change mask
+        0x18, 0x00, // length 24
+        0x12, 0x00, // IFLA_LINKINFO 18
+        0x08, 0x00, // length 8
+        0x01, 0x00, // IFLA_INFO_KIND 1
+        0x64, 0x73, 0x61, 0x00, // 'dsa\0'
+        0x0c, 0x00, // length 12
+        0x02, 0x00, // IFLA_INFO_DATA 2
+        0x08, 0x00, // length 8
+        0x01, 0x00, // IFLA_DSA_CONDUIT 1
+        0x02, 0x00, 0x00, 0x00, // 2
+    ];
+
+    let expected = LinkMessage {
+        header: LinkHeader {
+            interface_family: AddressFamily::Unspec,
+            index: 5,
+            link_layer_type: LinkLayerType::Ether,
+            flags: LinkFlags::Broadcast
+                | LinkFlags::LowerUp
+                | LinkFlags::Multicast
+                | LinkFlags::Running
+                | LinkFlags::Up,
+            change_mask: LinkFlags::empty(),
+        },
+        attributes: vec![LinkAttribute::LinkInfo(vec![
+            LinkInfo::Kind(InfoKind::Dsa),
+            LinkInfo::Data(InfoData::Dsa(vec![InfoDsa::Conduit(2)])),
+        ])],
+    };
+
+    assert_eq!(
+        expected,
+        LinkMessage::parse(&LinkMessageBuffer::new(&raw)).unwrap()
+    );
+
+    let mut buf = vec![0; expected.buffer_len()];
+
+    expected.emit(&mut buf);
+
+    assert_eq!(buf, raw);
+}
+
+// Captured RTM_NEWLINK request sent by iproute2 6.1 for
+//      ip link set veth9b type dsa conduit veth9a
+// with veth9b holding interface index 10 and veth9a holding interface index
+// 11, netlink header removed. iproute2 sends IFLA_INFO_KIND without the
+// trailing NUL, hence only parsing is checked.
+#[test]
+fn test_dsa_conduit_request() {
+    let raw = vec![
+        0x00, // interface family AF_UNSPEC
+        0x00, // reserved
+        0x00, 0x00, // link layer type 0 = netrom
+        0x0a, 0x00, 0x00, 0x00, // interface index 10
+        0x00, 0x00, 0x00, 0x00, // flags
+        0x00, 0x00, 0x00, 0x00, // change mask
+        0x18, 0x00, // length 24
+        0x12, 0x00, // IFLA_LINKINFO 18
+        0x07, 0x00, // length 7
+        0x01, 0x00, // IFLA_INFO_KIND 1
+        0x64, 0x73, 0x61, 0x00, // 'dsa' and 1 byte pad
+        0x0c, 0x00, // length 12
+        0x02, 0x00, // IFLA_INFO_DATA 2
+        0x08, 0x00, // length 8
+        0x01, 0x00, // IFLA_DSA_CONDUIT 1
+        0x0b, 0x00, 0x00, 0x00, // 11
+    ];
+
+    let expected = LinkMessage {
+        header: LinkHeader {
+            index: 10,
+            ..Default::default()
+        },
+        attributes: vec![LinkAttribute::LinkInfo(vec![
+            LinkInfo::Kind(InfoKind::Dsa),
+            LinkInfo::Data(InfoData::Dsa(vec![InfoDsa::Conduit(11)])),
+        ])],
+    };
+
+    assert_eq!(
+        expected,
+        LinkMessage::parse(&LinkMessageBuffer::new(&raw)).unwrap()
+    );
+}
@@ -0,0 +1,71 @@
+// SPDX-License-Identifier: MIT
+
+use netlink_packet_utils::{Emitable, Parseable};
+
+use crate::link::link_flag::LinkFlags;
+use crate::link::{
+    InfoData, InfoGreTap, InfoKind, LinkAttribute, LinkHeader, LinkInfo,
+    LinkLayerType, LinkMessage, LinkMessageBuffer,
+};
+use crate::AddressFamily;
+
+// Hand-crafted RTM_NEWLINK payload equivalent to:
+//   ip link add gretap1 type gretap external fwmark 0x10
+#[test]
+fn test_gretap_collect_metadata_fwmark() {
+    let raw: Vec<u8> = vec![
+        0x00, // interface family AF_UNSPEC
+        0x00, // reserved
+        0x01, 0x00, // link layer type ARPHRD_ETHER
+        0x0c, 0x00, 0x00, 0x00, // interface index 12
+        0x02, 0x10, 0x00, 0x00, // flags IFF_BROADCAST | IFF_MULTICAST
+        0x00, 0x00, 0x00, 0x00, // change mask
+        0x0c, 0x00, // length 12
+        0x03, 0x00, // IFLA_IFNAME
+        0x67, 0x72, 0x65, 0x74, 0x61, 0x70, 0x31, 0x00, // 'gretap1\0'
+        0x20, 0x00, // length 32
+        0x12, 0x00, // IFLA_LINKINFO 18
+        0x0b, 0x00, // length 11
+        0x01, 0x00, // IFLA_INFO_KIND 1
+        0x67, 0x72, 0x65, 0x74, 0x61, 0x70, 0x00, // 'gretap\0'
+        0x00, // padding
+        0x10, 0x00, // length 16
+        0x02, 0x00, // IFLA_INFO_DATA 2
+        0x04, 0x00, // length 4
+        0x12, 0x00, // IFLA_GRE_COLLECT_METADATA 18
+        0x08, 0x00, // length 8
+        0x14, 0x00, // IFLA_GRE_FWMARK 20
+        0x10, 0x00, 0x00, 0x00, // 0x10
+    ];
+
+    let expected = LinkMessage {
+        header: LinkHeader {
+            interface_family: AddressFamily::Unspec,
+            index: 12,
+            link_layer_type: LinkLayerType::Ether,
+            flags: LinkFlags::Broadcast | LinkFlags::Multicast,
+            change_mask: LinkFlags::empty(),
+        },
+        attributes: vec![
+            LinkAttribute::IfName("gretap1".to_string()),
+            LinkAttribute::LinkInfo(vec![
+                LinkInfo::Kind(InfoKind::GreTap),
+                LinkInfo::Data(InfoData::GreTap(vec![
+                    InfoGreTap::CollectMetadata,
+                    InfoGreTap::FwMark(0x10),
+                ])),
+            ]),
+        ],
+    };
+
+    assert_eq!(
+        expected,
+        LinkMessage::parse(&LinkMessageBuffer::new(&raw)).unwrap()
+    );
+
+    let mut buf = vec![0; expected.buffer_len()];
+
+    expected.emit(&mut buf);
+
+    assert_eq!(buf, raw);
+}
@@ -0,0 +1,37 @@
+// SPDX-License-Identifier: MIT
+
+use std::str::FromStr;
+
+use crate::link::InfoKind;
+
+#[test]
+fn test_info_kind_from_str() {
+    for (s, kind) in [
+        ("bridge", InfoKind::Bridge),
+        ("vlan", InfoKind::Vlan),
+        ("vxlan", InfoKind::Vxlan),
+        ("wireguard", InfoKind::Wireguard),
+        ("ip6gretap", InfoKind::GreTap6),
+        ("ipip", InfoKind::IpTun),
+    ] {
+        assert_eq!(InfoKind::from_str(s).unwrap(), kind);
+        assert_eq!(InfoKind::from(s), kind);
+        assert_eq!(kind.as_str(), s);
+        assert_eq!(kind.to_string(), s);
+    }
+}
+
+#[test]
+fn test_info_kind_from_str_unknown() {
+    let kind: InfoKind = "foo".parse().unwrap();
+    assert_eq!(kind, InfoKind::Other("foo".to_string()));
+    assert_eq!(kind.as_str(), "foo");
+}
+
+#[test]
+fn test_info_kind_wireguard_freebsd_alias() {
+    let kind: InfoKind = "wg".parse().unwrap();
+    assert_eq!(kind, InfoKind::Wireguard);
+    // Always emitted as the Linux kind string
+    assert_eq!(kind.as_str(), "wireguard");
+}
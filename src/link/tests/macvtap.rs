@@ -130,9 +130,9 @@ fn test_macvtap_link_info() {
             LinkAttribute::NumTxQueues(1),
             LinkAttribute::GsoMaxSegs(65535),
             LinkAttribute::GsoMaxSize(65536),
-            LinkAttribute::Other(DefaultNla::new(58, vec![0, 0, 1, 0])),
-            LinkAttribute::Other(DefaultNla::new(63, vec![0, 0, 1, 0])),
-            LinkAttribute::Other(DefaultNla::new(64, vec![0, 0, 1, 0])),
+            LinkAttribute::GroMaxSize(65536),
+            LinkAttribute::GsoIpv4MaxSize(65536),
+            LinkAttribute::GroIpv4MaxSize(65536),
             LinkAttribute::Other(DefaultNla::new(59, vec![0, 0, 1, 0])),
             LinkAttribute::Other(DefaultNla::new(60, vec![255, 255, 0, 0])),
             LinkAttribute::NumRxQueues(1),
@@ -326,8 +326,70 @@ fn test_macvtap_link_info() {
                     }),
                 ]),
             ]),
-            // TODO: Need to parse NLA_F_NESTED|IFLA_DEVLINK_PORT
-            LinkAttribute::Other(DefaultNla::new(32830, Vec::new())),
+            LinkAttribute::DevlinkPort(vec![]),
+        ],
+    };
+
+    assert_eq!(
+        expected,
+        LinkMessage::parse(&LinkMessageBuffer::new(&raw)).unwrap()
+    );
+
+    let mut buf = vec![0; expected.buffer_len()];
+
+    expected.emit(&mut buf);
+
+    assert_eq!(buf, raw);
+}
+
+// Hand-crafted RTM_NEWLINK payload equivalent to:
+//   ip link add link eth0 name macvtap0 type macvtap mode bridge
+// with eth0 holding interface index 2.
+#[test]
+fn test_macvtap_bridge_mode() {
+    let raw: Vec<u8> = vec![
+        0x00, // interface family AF_UNSPEC
+        0x00, // reserved
+        0x00, 0x00, // link layer type 0
+        0x00, 0x00, 0x00, 0x00, // interface index 0
+        0x00, 0x00, 0x00, 0x00, // flags
+        0x00, 0x00, 0x00, 0x00, // change mask
+        0x0d, 0x00, // length 13
+        0x03, 0x00, // IFLA_IFNAME
+        0x6d, 0x61, 0x63, 0x76, 0x74, 0x61, 0x70, 0x30,
+        0x00, // 'macvtap0\0'
+        0x00, 0x00, 0x00, // padding
+        0x08, 0x00, // length 8
+        0x05, 0x00, // IFLA_LINK
+        0x02, 0x00, 0x00, 0x00, // 2
+        0x24, 0x00, // length 36
+        0x12, 0x00, // IFLA_LINKINFO 18
+        0x0c, 0x00, // length 12
+        0x01, 0x00, // IFLA_INFO_KIND 1
+        0x6d, 0x61, 0x63, 0x76, 0x74, 0x61, 0x70, 0x00, // 'macvtap\0'
+        0x14, 0x00, // length 20
+        0x02, 0x00, // IFLA_INFO_DATA 2
+        0x08, 0x00, // length 8
+        0x01, 0x00, // IFLA_MACVLAN_MODE 1
+        0x04, 0x00, 0x00, 0x00, // MACVLAN_MODE_BRIDGE
+        0x06, 0x00, // length 6
+        0x02, 0x00, // IFLA_MACVLAN_FLAGS 2
+        0x00, 0x00, // 0
+        0x00, 0x00, // padding
+    ];
+
+    let expected = LinkMessage {
+        header: LinkHeader::default(),
+        attributes: vec![
+            LinkAttribute::IfName("macvtap0".to_string()),
+            LinkAttribute::Link(2),
+            LinkAttribute::LinkInfo(vec![
+                LinkInfo::Kind(InfoKind::MacVtap),
+                LinkInfo::Data(InfoData::MacVtap(vec![
+                    InfoMacVtap::Mode(MacVtapMode::Bridge),
+                    InfoMacVtap::Flags(0),
+                ])),
+            ]),
         ],
     };
 
@@ -1,11 +1,16 @@
 // SPDX-License-Identifier: MIT
 
-use netlink_packet_utils::traits::{Emitable, ParseableParametrized};
+#[cfg(feature = "serde")]
+use netlink_packet_utils::nla::DefaultNla;
+use netlink_packet_utils::traits::{
+    Emitable, Parseable, ParseableParametrized,
+};
+use netlink_packet_utils::DecodeError;
 
 use crate::link::link_flag::LinkFlags;
 use crate::link::{
-    LinkAttribute, LinkHeader, LinkLayerType, LinkMessage, LinkMessageBuffer,
-    State,
+    AttrChange, LinkAttribute, LinkHeader, LinkLayerType, LinkMessage,
+    LinkMessageBuffer, State,
 };
 use crate::AddressFamily;
 
@@ -40,9 +45,9 @@ static LINK_MSG: [u8; 96] = [
 #[test]
 fn link_message_packet_header_read() {
     let packet = LinkMessageBuffer::new(&LINK_MSG[0..16]);
-    assert_eq!(packet.interface_family(), AddressFamily::Unspec.into());
+    assert_eq!(packet.interface_family(), u8::from(AddressFamily::Unspec));
     assert_eq!(packet.reserved_1(), 0);
-    assert_eq!(packet.link_layer_type(), LinkLayerType::Loopback.into());
+    assert_eq!(packet.link_layer_type(), u16::from(LinkLayerType::Loopback));
     assert_eq!(packet.link_index(), 1);
     assert_eq!(
         packet.flags(),
@@ -200,3 +205,100 @@ fn link_message_emit() {
 
     assert_eq!(buf, &LINK_MSG[..96]);
 }
+
+#[test]
+fn link_message_accessors() {
+    let packet = LinkMessageBuffer::new(&LINK_MSG);
+    let msg = LinkMessage::parse(&packet).unwrap();
+    assert_eq!(msg.name(), Some("lo"));
+    assert_eq!(msg.mtu(), Some(65536));
+    assert_eq!(msg.mac(), None);
+    assert_eq!(msg.kind(), None);
+    assert!(msg.is_up());
+}
+
+#[test]
+fn link_message_diff_mtu_change() {
+    let old = LinkMessage::parse(&LinkMessageBuffer::new(&LINK_MSG)).unwrap();
+    let mut new = old.clone();
+    for attr in new.attributes.iter_mut() {
+        if let LinkAttribute::Mtu(mtu) = attr {
+            *mtu = 1500;
+        }
+    }
+    new.attributes.push(LinkAttribute::Carrier(1));
+    new.attributes
+        .retain(|attr| !matches!(attr, LinkAttribute::Promiscuity(_)));
+
+    assert_eq!(
+        old.diff(&new),
+        vec![
+            AttrChange::Changed {
+                old: LinkAttribute::Mtu(0x1_0000),
+                new: LinkAttribute::Mtu(1500),
+            },
+            AttrChange::Removed(LinkAttribute::Promiscuity(0)),
+            AttrChange::Added(LinkAttribute::Carrier(1)),
+        ]
+    );
+    assert!(old.diff(&old).is_empty());
+}
+
+#[cfg(feature = "serde")]
+#[test]
+fn link_message_serde_json() {
+    let mut msg =
+        LinkMessage::parse(&LinkMessageBuffer::new(&LINK_MSG)).unwrap();
+    msg.attributes.push(LinkAttribute::Other(DefaultNla::new(
+        1000,
+        vec![0xab, 0x01],
+    )));
+    msg.attributes
+        .push(LinkAttribute::Address(vec![0x52, 0x54, 0x00, 0, 0, 0x01]));
+
+    let json = serde_json::to_string(&msg).unwrap();
+    assert!(json.contains(r#""IfName":"lo""#));
+    assert!(json.contains(r#"{"kind":1000,"value":"ab01"}"#));
+    assert!(json.contains(r#""Address":"525400000001""#));
+
+    let parsed: LinkMessage = serde_json::from_str(&json).unwrap();
+    assert_eq!(parsed, msg);
+}
+
+#[test]
+fn link_message_parsed_attributes() {
+    let packet = LinkMessageBuffer::new(&LINK_MSG);
+    let msg = LinkMessage::parse(&packet).unwrap();
+
+    let attributes: Vec<LinkAttribute> = packet
+        .parsed_attributes()
+        .collect::<Result<_, DecodeError>>()
+        .unwrap();
+    assert_eq!(attributes, msg.attributes);
+}
+
+#[test]
+fn link_message_parsed_attributes_early_exit() {
+    // IFLA_IFNAME followed by many IFLA_MTU holding 2 bytes instead of 4,
+    // hence only the first attribute could be parsed.
+    let mut raw = LINK_MSG[..24].to_vec();
+    for _ in 0..1000 {
+        raw.extend_from_slice(&[
+            0x06, 0x00, 0x04, 0x00, 0x00, 0x01, 0x00, 0x00,
+        ]);
+    }
+    let packet = LinkMessageBuffer::new(&raw);
+
+    assert!(LinkMessage::parse(&packet).is_err());
+
+    let mut parsed = 0;
+    let name = packet.parsed_attributes().find_map(|attr| {
+        parsed += 1;
+        match attr {
+            Ok(LinkAttribute::IfName(name)) => Some(name),
+            _ => None,
+        }
+    });
+    assert_eq!(name.as_deref(), Some("lo"));
+    assert_eq!(parsed, 1);
+}
@@ -5,10 +5,22 @@ mod bond;
 #[cfg(test)]
 mod bridge;
 #[cfg(test)]
+mod builder;
+#[cfg(test)]
+mod carrier;
+#[cfg(test)]
+mod devlink_port;
+#[cfg(test)]
+mod dsa;
+#[cfg(test)]
 mod geneve;
 #[cfg(test)]
+mod gre;
+#[cfg(test)]
 mod hsr;
 #[cfg(test)]
+mod info_kind;
+#[cfg(test)]
 mod ipvlan;
 #[cfg(test)]
 mod ipvtap;
@@ -25,6 +37,8 @@ mod message;
 #[cfg(test)]
 mod prop_list;
 #[cfg(test)]
+mod raw_attribute;
+#[cfg(test)]
 mod sriov;
 #[cfg(test)]
 mod statistics;
@@ -37,6 +51,10 @@ mod vrf;
 #[cfg(test)]
 mod vxlan;
 #[cfg(test)]
+mod wireguard;
+#[cfg(test)]
+mod wireless;
+#[cfg(test)]
 mod xdp;
 #[cfg(test)]
 mod xfrm;
@@ -1,13 +1,17 @@
 // SPDX-License-Identifier: MIT
 
+use netlink_packet_core::{
+    NetlinkHeader, NetlinkMessage, NetlinkPayload, NLM_F_ACK, NLM_F_CREATE,
+    NLM_F_EXCL, NLM_F_REQUEST,
+};
 use netlink_packet_utils::{Emitable, Parseable};
 
 use crate::link::link_flag::LinkFlags;
 use crate::link::{
     LinkAttribute, LinkHeader, LinkLayerType, LinkMessage, LinkMessageBuffer,
-    Prop,
+    LinkMessageBuilder, Prop,
 };
-use crate::AddressFamily;
+use crate::{AddressFamily, RouteNetlinkMessage};
 
 #[test]
 fn test_wlan0_with_prop_altname() {
@@ -49,3 +53,119 @@ fn test_wlan0_with_prop_altname() {
 
     assert_eq!(buf, raw);
 }
+
+// Hand-crafted RTM_DELLINKPROP request equivalent to
+//   ip link property del dev wlan0 altname wifi
+// with wlan0 holding interface index 2.
+#[test]
+fn test_del_prop_altname() {
+    let raw = vec![
+        0x30, 0x00, 0x00, 0x00, // length 48
+        0x6d, 0x00, // RTM_DELLINKPROP 109
+        0x05, 0x00, // NLM_F_REQUEST | NLM_F_ACK
+        0x00, 0x00, 0x00, 0x00, // sequence number
+        0x00, 0x00, 0x00, 0x00, // port id
+        0x00, // interface family AF_UNSPEC
+        0x00, // reserved
+        0x00, 0x00, // link layer type 0
+        0x02, 0x00, 0x00, 0x00, // interface index 2
+        0x00, 0x00, 0x00, 0x00, // flags
+        0x00, 0x00, 0x00, 0x00, // change mask
+        0x10, 0x00, // length 16
+        0x34, 0x80, // IFLA_PROP_LIST 52 | NLA_F_NESTED
+        0x09, 0x00, // length 9
+        0x35, 0x00, // IFLA_ALT_IFNAME 53
+        0x77, 0x69, 0x66, 0x69, 0x00, // 'wifi\0'
+        0x00, 0x00, 0x00, // padding
+    ];
+
+    let link_msg = LinkMessageBuilder::new()
+        .index(2)
+        .alt_name("wifi".to_string())
+        .build();
+
+    assert_eq!(
+        link_msg.attributes,
+        vec![LinkAttribute::PropList(vec![Prop::AltIfName(
+            "wifi".to_string()
+        )])]
+    );
+
+    let mut header = NetlinkHeader::default();
+    header.flags = NLM_F_REQUEST | NLM_F_ACK;
+    let mut message = NetlinkMessage::new(
+        header,
+        NetlinkPayload::from(RouteNetlinkMessage::DelLinkProp(link_msg)),
+    );
+    message.finalize();
+
+    let mut buf = vec![0; message.buffer_len()];
+    message.emit(&mut buf);
+    assert_eq!(buf, raw);
+
+    assert_eq!(NetlinkMessage::deserialize(&raw).unwrap(), message);
+}
+
+// Hand-crafted RTM_NEWLINKPROP request equivalent to
+//   ip link property add dev eth0 altname lan0 altname uplink
+// with eth0 holding interface index 2.
+#[test]
+fn test_new_prop_altnames() {
+    let raw = vec![
+        0x3c, 0x00, 0x00, 0x00, // length 60
+        0x6c, 0x00, // RTM_NEWLINKPROP 108
+        0x05,
+        0x06, // NLM_F_REQUEST | NLM_F_ACK | NLM_F_EXCL | NLM_F_CREATE
+        0x00, 0x00, 0x00, 0x00, // sequence number
+        0x00, 0x00, 0x00, 0x00, // port id
+        0x00, // interface family AF_UNSPEC
+        0x00, // reserved
+        0x00, 0x00, // link layer type 0
+        0x02, 0x00, 0x00, 0x00, // interface index 2
+        0x00, 0x00, 0x00, 0x00, // flags
+        0x00, 0x00, 0x00, 0x00, // change mask
+        0x1c, 0x00, // length 28
+        0x34, 0x80, // IFLA_PROP_LIST 52 | NLA_F_NESTED
+        0x09, 0x00, // length 9
+        0x35, 0x00, // IFLA_ALT_IFNAME 53
+        0x6c, 0x61, 0x6e, 0x30, 0x00, // 'lan0\0'
+        0x00, 0x00, 0x00, // padding
+        0x0b, 0x00, // length 11
+        0x35, 0x00, // IFLA_ALT_IFNAME 53
+        0x75, 0x70, 0x6c, 0x69, 0x6e, 0x6b, 0x00, // 'uplink\0'
+        0x00, // padding
+    ];
+
+    let link_msg = LinkMessageBuilder::new()
+        .index(2)
+        .alt_name("lan0".to_string())
+        .alt_name("uplink".to_string())
+        .build();
+    assert_eq!(link_msg.alt_names(), vec!["lan0", "uplink"]);
+
+    let mut header = NetlinkHeader::default();
+    header.flags = NLM_F_REQUEST | NLM_F_ACK | NLM_F_EXCL | NLM_F_CREATE;
+    let mut message = NetlinkMessage::new(
+        header,
+        NetlinkPayload::from(RouteNetlinkMessage::NewLinkProp(link_msg)),
+    );
+    message.finalize();
+
+    let mut buf = vec![0; message.buffer_len()];
+    message.emit(&mut buf);
+    assert_eq!(buf, raw);
+
+    let parsed = NetlinkMessage::deserialize(&raw).unwrap();
+    assert_eq!(parsed, message);
+    match parsed.payload {
+        NetlinkPayload::InnerMessage(RouteNetlinkMessage::NewLinkProp(m)) => {
+            assert_eq!(m.alt_names(), vec!["lan0", "uplink"])
+        }
+        _ => panic!("expecting RTM_NEWLINKPROP"),
+    }
+}
+
+#[test]
+fn test_alt_names_without_prop_list() {
+    assert!(LinkMessage::default().alt_names().is_empty());
+}
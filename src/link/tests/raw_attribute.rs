@@ -0,0 +1,54 @@
+// SPDX-License-Identifier: MIT
+
+use netlink_packet_utils::nla::{DefaultNla, NLA_F_NESTED};
+use netlink_packet_utils::traits::{Emitable, Parseable};
+
+use crate::link::{
+    LinkAttribute, LinkMessage, LinkMessageBuffer, LinkMessageBuilder,
+};
+
+// Hand-crafted RTM_NEWLINK request payload carrying attributes unknown to
+// this crate for interface index 2.
+#[test]
+fn test_link_message_raw_attribute() {
+    let raw = vec![
+        0x00, // interface family AF_UNSPEC
+        0x00, // reserved
+        0x00, 0x00, // link layer type 0
+        0x02, 0x00, 0x00, 0x00, // interface index 2
+        0x00, 0x00, 0x00, 0x00, // flags
+        0x00, 0x00, 0x00, 0x00, // change mask
+        0x09, 0x00, // length 9
+        0xe8, 0x03, // unknown attribute 1000
+        0x01, 0x02, 0x03, 0x04, 0x05, // value
+        0x00, 0x00, 0x00, // padding
+        0x0c, 0x00, // length 12
+        0xe9, 0x83, // unknown attribute 1001 with NLA_F_NESTED
+        0x08, 0x00, // length 8
+        0x01, 0x00, // nested attribute 1
+        0x2a, 0x00, 0x00, 0x00, // 42
+    ];
+
+    let mut message = LinkMessageBuilder::new().index(2).build();
+    message
+        .attributes
+        .push(LinkAttribute::Other(DefaultNla::new(
+            1000,
+            vec![0x01, 0x02, 0x03, 0x04, 0x05],
+        )));
+    message
+        .attributes
+        .push(LinkAttribute::Other(DefaultNla::new(
+            1001 | NLA_F_NESTED,
+            vec![0x08, 0x00, 0x01, 0x00, 0x2a, 0x00, 0x00, 0x00],
+        )));
+
+    let mut buf = vec![0; message.buffer_len()];
+    message.emit(&mut buf);
+    assert_eq!(buf, raw);
+
+    assert_eq!(
+        message,
+        LinkMessage::parse(&LinkMessageBuffer::new(&raw)).unwrap()
+    );
+}
@@ -183,3 +183,58 @@ fn test_parsing_link_sriov() {
 
     assert_eq!(buf, raw);
 }
+
+// Hand-crafted IFLA_VFINFO_LIST equivalent to the one replied by kernel
+// after
+//      ip link set eth0 vf 0 vlan 100 qos 3 proto 802.1ad
+// Only IFLA_VF_VLAN and IFLA_VF_VLAN_LIST included.
+#[test]
+fn test_sriov_vf_vlan_list_8021ad() {
+    let raw = vec![
+        0x30, 0x00, // length 48
+        0x16, 0x00, // IFLA_VFINFO_LIST 22
+        0x2c, 0x00, // length 44
+        0x01, 0x00, // IFLA_VF_INFO
+        0x10, 0x00, // length 16
+        0x02, 0x00, // IFLA_VF_VLAN
+        0x00, 0x00, 0x00, 0x00, // vf 0
+        0x64, 0x00, 0x00, 0x00, // vlan 100
+        0x03, 0x00, 0x00, 0x00, // qos 3
+        0x18, 0x00, // length 24
+        0x0c, 0x00, // IFLA_VF_VLAN_LIST
+        0x14, 0x00, // length 20
+        0x01, 0x00, // IFLA_VF_VLAN_INFO
+        0x00, 0x00, 0x00, 0x00, // vf 0
+        0x64, 0x00, 0x00, 0x00, // vlan 100
+        0x03, 0x00, 0x00, 0x00, // qos 3
+        0x88, 0xa8, // ETH_P_8021AD in big endian
+        0x00, 0x00, // padding
+    ];
+
+    let expected = LinkAttribute::VfInfoList(vec![LinkVfInfo(vec![
+        VfInfo::Vlan(VfInfoVlan {
+            vf_id: 0,
+            vlan_id: 100,
+            qos: 3,
+        }),
+        VfInfo::VlanList(vec![VfVlan::Info(VfVlanInfo::new(
+            0,
+            100,
+            3,
+            VlanProtocol::Ieee8021Ad,
+        ))]),
+    ])]);
+
+    assert_eq!(
+        expected,
+        LinkAttribute::parse_with_param(
+            &NlaBuffer::new(&raw),
+            AddressFamily::Unspec
+        )
+        .unwrap(),
+    );
+
+    let mut buf = vec![0; expected.buffer_len()];
+    expected.emit(&mut buf);
+    assert_eq!(buf, raw);
+}
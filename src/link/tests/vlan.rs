@@ -83,4 +83,10 @@ fn test_parsing_link_vlan() {
     expected.emit(&mut buf);
 
     assert_eq!(buf, raw);
+
+    let parsed = LinkMessage::parse(&LinkMessageBuffer::new(&raw)).unwrap();
+    assert_eq!(parsed.kind(), Some(&InfoKind::Vlan));
+    assert_eq!(parsed.name(), None);
+    assert_eq!(parsed.mtu(), None);
+    assert!(parsed.is_up());
 }
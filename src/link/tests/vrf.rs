@@ -258,7 +258,7 @@ fn test_link_info_with_ifla_vrf_port_table() {
             LinkAttribute::NumTxQueues(26),
             LinkAttribute::GsoMaxSegs(65535),
             LinkAttribute::GsoMaxSize(65536),
-            LinkAttribute::Other(DefaultNla::new(58, vec![0, 0, 1, 0])),
+            LinkAttribute::GroMaxSize(65536),
             LinkAttribute::Other(DefaultNla::new(59, vec![248, 255, 7, 0])),
             LinkAttribute::Other(DefaultNla::new(60, vec![255, 255, 0, 0])),
             LinkAttribute::NumRxQueues(26),
@@ -338,6 +338,7 @@ fn test_link_info_with_ifla_vrf_port_table() {
                     }),
                     AfSpecInet6::Stats(Inet6Stats {
                         num: 37,
+                        extra_counters: vec![0],
                         ..Default::default()
                     }),
                     AfSpecInet6::Icmp6Stats(Icmp6Stats {
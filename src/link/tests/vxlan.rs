@@ -3,17 +3,39 @@
 use std::net::{Ipv4Addr, Ipv6Addr};
 use std::str::FromStr;
 
-use netlink_packet_utils::{nla::DefaultNla, Emitable, Parseable};
+use netlink_packet_utils::{
+    nla::{DefaultNla, NlaBuffer},
+    Emitable, Parseable,
+};
 
 use crate::link::link_flag::LinkFlags;
 use crate::link::{
     AfSpecInet, AfSpecInet6, AfSpecUnspec, Inet6CacheInfo, Inet6DevConf,
     Inet6IfaceFlags, InetDevConf, InfoData, InfoKind, InfoVxlan, LinkAttribute,
     LinkHeader, LinkInfo, LinkLayerType, LinkMessage, LinkMessageBuffer,
-    LinkXdp, Map, State, Stats, Stats64, XdpAttached,
+    LinkXdp, Map, State, Stats, Stats64, VxlanDf, XdpAttached,
 };
 use crate::AddressFamily;
 
+// Kernel dumps IFLA_VXLAN_TTL_INHERIT as u8 but only accepts zero-length
+// flag NLA, hence `InfoVxlan::TtlInherit(false)` is omitted on emit.
+// Return `raw` without the 8 bytes u8 IFLA_VXLAN_TTL_INHERIT at `offset`,
+// shrinking the length of IFLA_LINKINFO and IFLA_INFO_DATA at `parents`.
+fn without_ttl_inherit(
+    raw: &[u8],
+    offset: usize,
+    parents: [usize; 2],
+) -> Vec<u8> {
+    let mut ret = raw.to_vec();
+    assert_eq!(&ret[offset..offset + 5], &[0x05, 0x00, 0x1c, 0x00, 0x00]);
+    ret.drain(offset..offset + 8);
+    for parent in parents {
+        let len = u16::from_ne_bytes([ret[parent], ret[parent + 1]]) - 8;
+        ret[parent..parent + 2].copy_from_slice(&len.to_ne_bytes());
+    }
+    ret
+}
+
 #[test]
 fn test_parsing_link_vxlan() {
     let raw = vec![
@@ -149,9 +171,9 @@ fn test_parsing_link_vxlan() {
             LinkAttribute::NumTxQueues(1),
             LinkAttribute::GsoMaxSegs(65535),
             LinkAttribute::GsoMaxSize(65536),
-            LinkAttribute::Other(DefaultNla::new(58, vec![0, 0, 1, 0])),
-            LinkAttribute::Other(DefaultNla::new(63, vec![0, 0, 1, 0])),
-            LinkAttribute::Other(DefaultNla::new(64, vec![0, 0, 1, 0])),
+            LinkAttribute::GroMaxSize(65536),
+            LinkAttribute::GsoIpv4MaxSize(65536),
+            LinkAttribute::GroIpv4MaxSize(65536),
             LinkAttribute::Other(DefaultNla::new(59, vec![248, 255, 7, 0])),
             LinkAttribute::Other(DefaultNla::new(60, vec![255, 255, 0, 0])),
             LinkAttribute::NumRxQueues(1),
@@ -235,7 +257,7 @@ fn test_parsing_link_vxlan() {
                     InfoVxlan::Ttl(0),
                     InfoVxlan::TtlInherit(false),
                     InfoVxlan::Tos(0),
-                    InfoVxlan::Df(0),
+                    InfoVxlan::Df(VxlanDf::Unset),
                     InfoVxlan::Label(0),
                     InfoVxlan::Learning(true),
                     InfoVxlan::Proxy(false),
@@ -364,7 +386,7 @@ fn test_parsing_link_vxlan() {
                     }),
                 ]),
             ]),
-            LinkAttribute::Other(DefaultNla::new(32830, vec![])),
+            LinkAttribute::DevlinkPort(vec![]),
         ],
     };
 
@@ -377,7 +399,7 @@ fn test_parsing_link_vxlan() {
 
     expected.emit(&mut buf);
 
-    assert_eq!(buf, raw);
+    assert_eq!(buf, without_ttl_inherit(&raw, 660, [600, 616]));
 }
 
 #[test]
@@ -465,7 +487,7 @@ fn test_parsing_link_vxlan_ipv6() {
                     InfoVxlan::Ttl(0),
                     InfoVxlan::TtlInherit(false),
                     InfoVxlan::Tos(0),
-                    InfoVxlan::Df(0),
+                    InfoVxlan::Df(VxlanDf::Unset),
                     InfoVxlan::Label(0),
                     InfoVxlan::Other(DefaultNla::new(32, vec![0, 0, 0, 0])),
                     InfoVxlan::Learning(true),
@@ -498,5 +520,100 @@ fn test_parsing_link_vxlan_ipv6() {
 
     expected.emit(&mut buf);
 
+    assert_eq!(buf, without_ttl_inherit(&raw, 296, [212, 228]));
+}
+
+// Hand-crafted RTM_NEWLINK payload equivalent to a dump of:
+//   ip link add vxlan1 type vxlan id 10 dstport 4790 gpe df set
+// trimmed down to the attributes of interest.
+#[test]
+fn test_vxlan_gpe_df_set() {
+    let raw: Vec<u8> = vec![
+        0x00, // interface family AF_UNSPEC
+        0x00, // reserved
+        0x01, 0x00, // link layer type ARPHRD_ETHER
+        0x10, 0x00, 0x00, 0x00, // interface index 16
+        0x02, 0x10, 0x00, 0x00, // flags IFF_BROADCAST | IFF_MULTICAST
+        0x00, 0x00, 0x00, 0x00, // change mask
+        0x0b, 0x00, // length 11
+        0x03, 0x00, // IFLA_IFNAME
+        0x76, 0x78, 0x6c, 0x61, 0x6e, 0x31, 0x00, // 'vxlan1\0'
+        0x00, // padding
+        0x38, 0x00, // length 56
+        0x12, 0x00, // IFLA_LINKINFO 18
+        0x0a, 0x00, // length 10
+        0x01, 0x00, // IFLA_INFO_KIND 1
+        0x76, 0x78, 0x6c, 0x61, 0x6e, 0x00, // 'vxlan\0'
+        0x00, 0x00, // padding
+        0x28, 0x00, // length 40
+        0x02, 0x00, // IFLA_INFO_DATA 2
+        0x08, 0x00, // length 8
+        0x01, 0x00, // IFLA_VXLAN_ID 1
+        0x0a, 0x00, 0x00, 0x00, // 10
+        0x05, 0x00, // length 5
+        0x1c, 0x00, // IFLA_VXLAN_TTL_INHERIT 28
+        0x00, // false
+        0x00, 0x00, 0x00, // padding
+        0x05, 0x00, // length 5
+        0x1d, 0x00, // IFLA_VXLAN_DF 29
+        0x01, // VXLAN_DF_SET
+        0x00, 0x00, 0x00, // padding
+        0x06, 0x00, // length 6
+        0x0f, 0x00, // IFLA_VXLAN_PORT 15
+        0x12, 0xb6, // 4790 in big endian
+        0x00, 0x00, // padding
+        0x04, 0x00, // length 4
+        0x1b, 0x00, // IFLA_VXLAN_GPE 27
+    ];
+
+    let expected = LinkMessage {
+        header: LinkHeader {
+            interface_family: AddressFamily::Unspec,
+            index: 16,
+            link_layer_type: LinkLayerType::Ether,
+            flags: LinkFlags::Broadcast | LinkFlags::Multicast,
+            change_mask: LinkFlags::empty(),
+        },
+        attributes: vec![
+            LinkAttribute::IfName("vxlan1".to_string()),
+            LinkAttribute::LinkInfo(vec![
+                LinkInfo::Kind(InfoKind::Vxlan),
+                LinkInfo::Data(InfoData::Vxlan(vec![
+                    InfoVxlan::Id(10),
+                    InfoVxlan::TtlInherit(false),
+                    InfoVxlan::Df(VxlanDf::Set),
+                    InfoVxlan::Port(4790),
+                    InfoVxlan::Gpe(true),
+                ])),
+            ]),
+        ],
+    };
+
+    assert_eq!(
+        expected,
+        LinkMessage::parse(&LinkMessageBuffer::new(&raw)).unwrap()
+    );
+
+    let mut buf = vec![0; expected.buffer_len()];
+
+    expected.emit(&mut buf);
+
+    assert_eq!(buf, without_ttl_inherit(&raw, 56, [28, 44]));
+}
+
+#[test]
+fn test_vxlan_ttl_inherit_flag() {
+    // iproute2 sends IFLA_VXLAN_TTL_INHERIT as zero-length flag NLA
+    let raw: Vec<u8> = vec![
+        0x04, 0x00, // length 4
+        0x1c, 0x00, // IFLA_VXLAN_TTL_INHERIT 28
+    ];
+
+    let nla = NlaBuffer::new_checked(&raw).unwrap();
+    let expected = InfoVxlan::TtlInherit(true);
+    assert_eq!(expected, InfoVxlan::parse(&nla).unwrap());
+
+    let mut buf = vec![0; expected.buffer_len()];
+    expected.emit(&mut buf);
     assert_eq!(buf, raw);
 }
@@ -0,0 +1,97 @@
+// SPDX-License-Identifier: MIT
+
+use netlink_packet_utils::{Emitable, Parseable};
+
+use crate::link::link_flag::LinkFlags;
+use crate::link::{
+    InfoKind, LinkAttribute, LinkHeader, LinkInfo, LinkLayerType, LinkMessage,
+    LinkMessageBuffer, LinkMessageBuilder,
+};
+use crate::AddressFamily;
+
+// Hand-crafted RTM_NEWLINK request payload equivalent to
+//   ip link add wg0 type wireguard
+#[test]
+fn test_wireguard_link_add() {
+    let raw = vec![
+        0x00, // interface family AF_UNSPEC
+        0x00, // reserved
+        0x00, 0x00, // link layer type 0
+        0x00, 0x00, 0x00, 0x00, // interface index 0
+        0x00, 0x00, 0x00, 0x00, // flags
+        0x00, 0x00, 0x00, 0x00, // change mask
+        0x08, 0x00, // length 8
+        0x03, 0x00, // IFLA_IFNAME
+        0x77, 0x67, 0x30, 0x00, // "wg0\0"
+        0x14, 0x00, // length 20
+        0x12, 0x00, // IFLA_LINKINFO
+        0x0e, 0x00, // length 14
+        0x01, 0x00, // IFLA_INFO_KIND
+        0x77, 0x69, 0x72, 0x65, 0x67, 0x75, 0x61, 0x72, 0x64,
+        0x00, // "wireguard\0"
+        0x00, 0x00, // padding
+    ];
+
+    let message = LinkMessageBuilder::new()
+        .name("wg0".to_string())
+        .kind(InfoKind::Wireguard)
+        .build();
+
+    let mut buf = vec![0; message.buffer_len()];
+    message.emit(&mut buf);
+    assert_eq!(buf, raw);
+
+    assert_eq!(
+        message,
+        LinkMessage::parse(&LinkMessageBuffer::new(&raw)).unwrap()
+    );
+}
+
+// Hand-crafted RTM_NEWLINK reply payload equivalent to the IFLA_LINKINFO of
+//   ip -d link show wg0
+// WireGuard holds no IFLA_INFO_DATA, the device configuration is only
+// available through the `wireguard` generic netlink family.
+#[test]
+fn test_wireguard_link_info() {
+    let raw = vec![
+        0x00, // interface family AF_UNSPEC
+        0x00, // reserved
+        0xfe, 0xff, // link layer type ARPHRD_NONE
+        0x05, 0x00, 0x00, 0x00, // interface index 5
+        0xd1, 0x00, 0x01,
+        0x00, // flags UP,POINTOPOINT,RUNNING,NOARP,LOWER_UP
+        0x00, 0x00, 0x00, 0x00, // change mask
+        0x14, 0x00, // length 20
+        0x12, 0x00, // IFLA_LINKINFO
+        0x0e, 0x00, // length 14
+        0x01, 0x00, // IFLA_INFO_KIND
+        0x77, 0x69, 0x72, 0x65, 0x67, 0x75, 0x61, 0x72, 0x64,
+        0x00, // "wireguard\0"
+        0x00, 0x00, // padding
+    ];
+
+    let expected = LinkMessage {
+        header: LinkHeader {
+            interface_family: AddressFamily::Unspec,
+            index: 5,
+            link_layer_type: LinkLayerType::None,
+            flags: LinkFlags::Up
+                | LinkFlags::Pointopoint
+                | LinkFlags::Running
+                | LinkFlags::Noarp
+                | LinkFlags::LowerUp,
+            change_mask: LinkFlags::empty(),
+        },
+        attributes: vec![LinkAttribute::LinkInfo(vec![LinkInfo::Kind(
+            InfoKind::Wireguard,
+        )])],
+    };
+
+    let parsed = LinkMessage::parse(&LinkMessageBuffer::new(&raw)).unwrap();
+    assert_eq!(parsed, expected);
+    assert_eq!(parsed.kind(), Some(&InfoKind::Wireguard));
+
+    let mut buf = vec![0; expected.buffer_len()];
+    expected.emit(&mut buf);
+    assert_eq!(buf, raw);
+}
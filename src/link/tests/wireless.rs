@@ -0,0 +1,74 @@
+// SPDX-License-Identifier: MIT
+
+use netlink_packet_utils::{Emitable, Parseable};
+
+use crate::link::{
+    LinkAttribute, LinkFlags, LinkHeader, LinkLayerType, LinkMessage,
+    LinkMessageBuffer, LinkWirelessEvent,
+};
+use crate::AddressFamily;
+
+// Hand-crafted RTM_NEWLINK payload laid out as kernel `wireless_send_event()`
+// builds it on 64 bits platform when wireless interface with index 3
+// associated with AP 00:11:22:33:44:55. Not a capture: the kernel only emits
+// IFLA_WIRELESS from wireless extension drivers, and no such device is
+// available for the test.
+// Unrelated attributes are omitted.
+#[test]
+fn test_wireless_event_ap_associated() {
+    let raw: Vec<u8> = vec![
+        0x00, // interface family AF_UNSPEC
+        0x00, // reserved
+        0x01, 0x00, // link layer type 1 = ether
+        0x03, 0x00, 0x00, 0x00, // interface index 3
+        0x00, 0x00, 0x00, 0x00, // flags
+        0x00, 0x00, 0x00, 0x00, // change mask
+        0x1c, 0x00, // length 28
+        0x0b, 0x00, // IFLA_WIRELESS 11
+        0x18, 0x00, // iw_event.len 24
+        0x15, 0x8b, // iw_event.cmd SIOCGIWAP
+        0x00, 0x00, 0x00, 0x00, // padding of union iwreq_data
+        0x01, 0x00, // sockaddr.sa_family ARPHRD_ETHER
+        0x00, 0x11, 0x22, 0x33, 0x44, 0x55, // 00:11:22:33:44:55
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, // sa_data padding
+    ];
+
+    let parsed = LinkMessage::parse(&LinkMessageBuffer::new(&raw)).unwrap();
+
+    assert_eq!(
+        parsed.header,
+        LinkHeader {
+            interface_family: AddressFamily::Unspec,
+            index: 3,
+            link_layer_type: LinkLayerType::Ether,
+            flags: LinkFlags::empty(),
+            change_mask: LinkFlags::empty(),
+        }
+    );
+    let event = match parsed.attributes.as_slice() {
+        [LinkAttribute::Wireless(event)] => event,
+        _ => panic!("Expecting single LinkAttribute::Wireless"),
+    };
+    assert_eq!(event.event_len(), Some(24));
+    assert_eq!(event.cmd(), Some(0x8b15));
+    assert_eq!(event.payload(), &raw[24..]);
+
+    let mut buf = vec![0; parsed.buffer_len()];
+    parsed.emit(&mut buf);
+    assert_eq!(buf, raw);
+}
+
+#[test]
+fn test_wireless_event_too_short() {
+    let raw = [0x04u8, 0x00];
+    let event = LinkWirelessEvent::parse(&raw).unwrap();
+
+    assert_eq!(event.event_len(), None);
+    assert_eq!(event.cmd(), None);
+    assert!(event.payload().is_empty());
+    assert_eq!(event.as_bytes(), &raw);
+
+    let mut buf = vec![0; event.buffer_len()];
+    event.emit(&mut buf);
+    assert_eq!(buf, raw);
+}
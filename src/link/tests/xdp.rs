@@ -5,7 +5,10 @@ use netlink_packet_utils::{
     Emitable, Parseable,
 };
 
-use crate::link::{xdp::VecLinkXdp, LinkXdp, XdpAttached};
+use crate::link::{
+    xdp::VecLinkXdp, LinkAttribute, LinkHeader, LinkLayerType, LinkMessage,
+    LinkMessageBuffer, LinkXdp, XdpAttached, XdpFlags,
+};
 
 static ATTACHED: [u8; 48] = [
     0x05, 0x00, // length = 5
@@ -173,3 +176,90 @@ fn emit_xdp() {
     nlas.as_slice().emit(&mut vec);
     assert_eq!(&vec[..], &XDP[..]);
 }
+
+// Hand-crafted RTM_NEWLINK payload equivalent to the one replied by kernel
+// for
+//      ip link show dev eth0
+// after
+//      ip link set dev eth0 xdpdrv obj xdp.o
+// with eth0 holding interface index 2 and the XDP program holding ID 42.
+// Unrelated attributes are omitted.
+#[test]
+fn test_xdp_driver_mode_link_message() {
+    let raw: Vec<u8> = vec![
+        0x00, // interface family AF_UNSPEC
+        0x00, // reserved
+        0x01, 0x00, // link layer type 1 = ether
+        0x02, 0x00, 0x00, 0x00, // interface index 2
+        0x00, 0x00, 0x00, 0x00, // flags
+        0x00, 0x00, 0x00, 0x00, // change mask
+        0x1c, 0x00, // length 28
+        0x2b, 0x00, // IFLA_XDP 43
+        0x05, 0x00, // length 5
+        0x02, 0x00, // IFLA_XDP_ATTACHED
+        0x01, 0x00, 0x00, 0x00, // XDP_ATTACHED_DRV and 3 bytes padding
+        0x08, 0x00, // length 8
+        0x05, 0x00, // IFLA_XDP_DRV_PROG_ID
+        0x2a, 0x00, 0x00, 0x00, // 42
+        0x08, 0x00, // length 8
+        0x04, 0x00, // IFLA_XDP_PROG_ID
+        0x2a, 0x00, 0x00, 0x00, // 42
+    ];
+
+    let expected = LinkMessage {
+        header: LinkHeader {
+            index: 2,
+            link_layer_type: LinkLayerType::Ether,
+            ..Default::default()
+        },
+        attributes: vec![LinkAttribute::Xdp(vec![
+            LinkXdp::Attached(XdpAttached::Driver),
+            LinkXdp::DrvProgId(42),
+            LinkXdp::ProgId(42),
+        ])],
+    };
+
+    assert_eq!(
+        expected,
+        LinkMessage::parse(&LinkMessageBuffer::new(&raw)).unwrap()
+    );
+
+    let mut buf = vec![0; expected.buffer_len()];
+    expected.emit(&mut buf);
+    assert_eq!(buf, raw);
+}
+
+#[test]
+fn test_xdp_flags_expected_fd() {
+    let raw: Vec<u8> = vec![
+        0x08, 0x00, // length 8
+        0x01, 0x00, // IFLA_XDP_FD
+        0x05, 0x00, 0x00, 0x00, // 5
+        0x08, 0x00, // length 8
+        0x03, 0x00, // IFLA_XDP_FLAGS
+        0x14, 0x00, 0x00, 0x00, // XDP_FLAGS_DRV_MODE | XDP_FLAGS_REPLACE
+        0x08, 0x00, // length 8
+        0x08, 0x00, // IFLA_XDP_EXPECTED_FD
+        0x04, 0x00, 0x00, 0x00, // 4
+    ];
+    let nlas = vec![
+        LinkXdp::Fd(5),
+        LinkXdp::Flags((XdpFlags::DrvMode | XdpFlags::Replace).bits()),
+        LinkXdp::ExpectedFd(4),
+    ];
+
+    let mut buf = vec![0; nlas.as_slice().buffer_len()];
+    nlas.as_slice().emit(&mut buf);
+    assert_eq!(buf, raw);
+
+    let nla = NlaBuffer::new_checked(&raw[..]).unwrap();
+    assert_eq!(VecLinkXdp::parse(&nla).unwrap().0, nlas);
+
+    assert_eq!(
+        nlas[1].xdp_flags(),
+        Some(XdpFlags::DrvMode | XdpFlags::Replace)
+    );
+    assert_eq!(nlas[2].expected_fd(), Some(4));
+    assert_eq!(LinkXdp::ExpectedFd(u32::MAX).expected_fd(), Some(-1));
+    assert_eq!(nlas[0].xdp_flags(), None);
+}
@@ -2,10 +2,53 @@
 
 use netlink_packet_utils::{DecodeError, Emitable, Parseable};
 
-// Place holder for kernel code is `struct iw_event`
+const IW_EVENT_HEADER_LEN: usize = 4;
+
+buffer!(LinkWirelessEventBuffer(IW_EVENT_HEADER_LEN) {
+    len: (u16, 0..2),
+    cmd: (u16, 2..4),
+    payload: (slice, IW_EVENT_HEADER_LEN..),
+});
+
+/// Kernel representative is `struct iw_event`. The raw bytes are kept as is,
+/// the leading `len` and `cmd` fields are decoded on demand. Payload shorter
+/// than the `iw_event` header is kept untouched, with [Self::event_len()] and
+/// [Self::cmd()] returning `None`.
 #[derive(Debug, PartialEq, Eq, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[non_exhaustive]
-pub struct LinkWirelessEvent(Vec<u8>);
+pub struct LinkWirelessEvent(
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_hex"))] Vec<u8>,
+);
+
+impl LinkWirelessEvent {
+    fn header(&self) -> Option<LinkWirelessEventBuffer<&[u8]>> {
+        LinkWirelessEventBuffer::new_checked(self.0.as_slice()).ok()
+    }
+
+    /// Length of the event in bytes, including the `len` and `cmd` fields
+    pub fn event_len(&self) -> Option<u16> {
+        self.header().map(|buf| buf.len())
+    }
+
+    /// Wireless extension ioctl or event id, e.g. `SIOCGIWAP` (`0x8B15`)
+    pub fn cmd(&self) -> Option<u16> {
+        self.header().map(|buf| buf.cmd())
+    }
+
+    /// Raw bytes following `cmd`. On 64 bits platform, kernel aligns
+    /// `union iwreq_data` to 8 bytes, hence this payload starts with
+    /// 4 bytes of padding. Empty if the event is shorter than the
+    /// `iw_event` header.
+    pub fn payload(&self) -> &[u8] {
+        self.header().map(|buf| buf.payload()).unwrap_or_default()
+    }
+
+    /// Raw bytes of the whole event
+    pub fn as_bytes(&self) -> &[u8] {
+        self.0.as_slice()
+    }
+}
 
 impl<T: AsRef<[u8]> + ?Sized> Parseable<T> for LinkWirelessEvent {
     fn parse(buf: &T) -> Result<Self, DecodeError> {
@@ -25,20 +25,57 @@ const XDP_ATTACHED_SKB: u8 = 2;
 const XDP_ATTACHED_HW: u8 = 3;
 const XDP_ATTACHED_MULTI: u8 = 4;
 
+const XDP_FLAGS_UPDATE_IF_NOEXIST: u32 = 1 << 0;
+const XDP_FLAGS_SKB_MODE: u32 = 1 << 1;
+const XDP_FLAGS_DRV_MODE: u32 = 1 << 2;
+const XDP_FLAGS_HW_MODE: u32 = 1 << 3;
+const XDP_FLAGS_REPLACE: u32 = 1 << 4;
+
 #[non_exhaustive]
 #[derive(Debug, PartialEq, Eq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum LinkXdp {
+    /// File descriptor of XDP program to attach, `-1` to detach.
     Fd(RawFd),
+    /// Attach mode reported by kernel.
     Attached(XdpAttached),
+    /// Flags used when attaching XDP program, use [LinkXdp::xdp_flags()] to
+    /// get them as [XdpFlags].
     Flags(u32),
+    /// ID of attached XDP program, only reported when attached in single mode.
     ProgId(u32),
+    /// ID of XDP program attached in driver mode.
     DrvProgId(u32),
+    /// ID of XDP program attached in generic(skb) mode.
     SkbProgId(u32),
+    /// ID of XDP program attached in hardware offload mode.
     HwProgId(u32),
+    /// File descriptor of the XDP program expected to be replaced, only valid
+    /// with [XdpFlags::Replace]. Kernel defines it as `s32`, use
+    /// [LinkXdp::expected_fd()] to get it as [RawFd].
     ExpectedFd(u32),
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_nla"))]
     Other(DefaultNla),
 }
 
+impl LinkXdp {
+    /// Value of [LinkXdp::Flags] as [XdpFlags].
+    pub fn xdp_flags(&self) -> Option<XdpFlags> {
+        match self {
+            Self::Flags(v) => Some(XdpFlags::from_bits_retain(*v)),
+            _ => None,
+        }
+    }
+
+    /// Value of [LinkXdp::ExpectedFd] as the `s32` defined by kernel.
+    pub fn expected_fd(&self) -> Option<RawFd> {
+        match self {
+            Self::ExpectedFd(v) => Some(*v as RawFd),
+            _ => None,
+        }
+    }
+}
+
 impl Nla for LinkXdp {
     fn value_len(&self) -> usize {
         match self {
@@ -110,16 +147,20 @@ impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>> for LinkXdp {
                 parse_u32(payload).context("invalid IFLA_XDP_PROG_ID value")?,
             ),
             IFLA_XDP_DRV_PROG_ID => Self::DrvProgId(
-                parse_u32(payload).context("invalid IFLA_XDP_PROG_ID value")?,
+                parse_u32(payload)
+                    .context("invalid IFLA_XDP_DRV_PROG_ID value")?,
             ),
             IFLA_XDP_SKB_PROG_ID => Self::SkbProgId(
-                parse_u32(payload).context("invalid IFLA_XDP_PROG_ID value")?,
+                parse_u32(payload)
+                    .context("invalid IFLA_XDP_SKB_PROG_ID value")?,
             ),
             IFLA_XDP_HW_PROG_ID => Self::HwProgId(
-                parse_u32(payload).context("invalid IFLA_XDP_PROG_ID value")?,
+                parse_u32(payload)
+                    .context("invalid IFLA_XDP_HW_PROG_ID value")?,
             ),
             IFLA_XDP_EXPECTED_FD => Self::ExpectedFd(
-                parse_u32(payload).context("invalid IFLA_XDP_PROG_ID value")?,
+                parse_u32(payload)
+                    .context("invalid IFLA_XDP_EXPECTED_FD value")?,
             ),
             _ => Self::Other(
                 DefaultNla::parse(nla)
@@ -150,6 +191,7 @@ impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>> for VecLinkXdp {
 }
 
 #[derive(Debug, PartialEq, Eq, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[non_exhaustive]
 pub enum XdpAttached {
     /// XDP_ATTACHED_NONE
@@ -193,3 +235,23 @@ impl XdpAttached {
         }
     }
 }
+
+bitflags! {
+    /// Flags of `IFLA_XDP_FLAGS`, the mode flags are mutually exclusive.
+    #[derive(Debug, PartialEq, Eq, Clone, Copy, Default)]
+    #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+    #[non_exhaustive]
+    pub struct XdpFlags: u32 {
+        /// Fail if XDP program is already attached
+        const UpdateIfNoexist = XDP_FLAGS_UPDATE_IF_NOEXIST;
+        /// Generic(skb) mode
+        const SkbMode = XDP_FLAGS_SKB_MODE;
+        /// Driver mode
+        const DrvMode = XDP_FLAGS_DRV_MODE;
+        /// Hardware offload mode
+        const HwMode = XDP_FLAGS_HW_MODE;
+        /// Replace the program referred by [LinkXdp::ExpectedFd]
+        const Replace = XDP_FLAGS_REPLACE;
+        const _ = !0;
+    }
+}
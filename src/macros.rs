@@ -0,0 +1,41 @@
+// SPDX-License-Identifier: MIT
+
+// Add `new_strict()` to a fixed-size buffer generated by `buffer!()`.
+macro_rules! impl_new_strict {
+    ($name:ident, $len:expr) => {
+        impl<T: AsRef<[u8]>> $name<T> {
+            /// Like `new_checked()`, but also fail if the buffer holds more
+            /// data than expected. Used by the parsers when
+            /// [crate::set_strict_parsing()] is enabled.
+            pub fn new_strict(
+                buffer: T,
+            ) -> Result<Self, netlink_packet_utils::DecodeError> {
+                let len = buffer.as_ref().len();
+                if len != $len {
+                    return Err(format!(
+                        "invalid {}: length {len} != {}",
+                        stringify!($name),
+                        $len
+                    )
+                    .into());
+                }
+                Ok(Self::new(buffer))
+            }
+        }
+    };
+}
+
+// Add `TryFrom<&[u8]>` to a message buffer generated by `buffer!()`.
+macro_rules! impl_try_from_slice {
+    ($name:ident) => {
+        /// Same as [Self::new_checked()], failing when the slice is shorter
+        /// than the header.
+        impl<'a> TryFrom<&'a [u8]> for $name<&'a [u8]> {
+            type Error = netlink_packet_utils::DecodeError;
+
+            fn try_from(buf: &'a [u8]) -> Result<Self, Self::Error> {
+                Self::new_checked(buf)
+            }
+        }
+    };
+}
@@ -0,0 +1,89 @@
+// SPDX-License-Identifier: MIT
+
+use anyhow::Context;
+use netlink_packet_utils::{
+    nla::{DefaultNla, Nla, NlaBuffer, NlasIterator},
+    traits::{Emitable, Parseable},
+    DecodeError,
+};
+
+use super::{
+    entry_info::{BridgeMdbEntryBuffer, BRIDGE_MDB_ENTRY_LEN},
+    BridgeMdbEntry, MdbEntry,
+};
+
+// Kernel use the same attribute type for `MDBA_MDB` in dump replies and
+// `MDBA_SET_ENTRY` in `RTM_NEWMDB` and `RTM_DELMDB` requests.
+const MDBA_MDB: u16 = 1;
+const MDBA_SET_ENTRY: u16 = 1;
+// const MDBA_ROUTER: u16 = 2;
+// const MDBA_SET_ENTRY_ATTRS: u16 = 2;
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[non_exhaustive]
+pub enum MdbAttribute {
+    /// Multicast group entries of the bridge, found in dump replies.
+    Mdb(Vec<MdbEntry>),
+    /// Multicast group entry to be added or deleted in requests.
+    /// When parsing, a `MDBA_MDB`/`MDBA_SET_ENTRY` attribute holding exactly
+    /// the size of kernel struct `br_mdb_entry` is treated as this variant.
+    SetEntry(BridgeMdbEntry),
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_nla"))]
+    Other(DefaultNla),
+}
+
+impl Nla for MdbAttribute {
+    fn value_len(&self) -> usize {
+        match self {
+            Self::Mdb(v) => v.as_slice().buffer_len(),
+            Self::SetEntry(v) => v.buffer_len(),
+            Self::Other(attr) => attr.value_len(),
+        }
+    }
+
+    fn emit_value(&self, buffer: &mut [u8]) {
+        match self {
+            Self::Mdb(v) => v.as_slice().emit(buffer),
+            Self::SetEntry(v) => v.emit(buffer),
+            Self::Other(attr) => attr.emit_value(buffer),
+        }
+    }
+
+    fn kind(&self) -> u16 {
+        match self {
+            Self::Mdb(_) => MDBA_MDB,
+            Self::SetEntry(_) => MDBA_SET_ENTRY,
+            Self::Other(attr) => attr.kind(),
+        }
+    }
+}
+
+impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>> for MdbAttribute {
+    fn parse(buf: &NlaBuffer<&'a T>) -> Result<Self, DecodeError> {
+        let payload = buf.value();
+        Ok(match buf.kind() {
+            MDBA_SET_ENTRY if payload.len() == BRIDGE_MDB_ENTRY_LEN => {
+                Self::SetEntry(
+                    BridgeMdbEntry::parse(&BridgeMdbEntryBuffer::new(payload))
+                        .context("invalid MDBA_SET_ENTRY value")?,
+                )
+            }
+            MDBA_MDB => {
+                let mut entries = vec![];
+                for nla in NlasIterator::new(payload) {
+                    let nla = nla.context("invalid MDBA_MDB")?;
+                    entries.push(
+                        MdbEntry::parse(&nla)
+                            .context("failed to parse MDBA_MDB")?,
+                    );
+                }
+                Self::Mdb(entries)
+            }
+            kind => Self::Other(
+                DefaultNla::parse(buf)
+                    .context(format!("unknown NLA type {kind}"))?,
+            ),
+        })
+    }
+}
@@ -0,0 +1,218 @@
+// SPDX-License-Identifier: MIT
+
+use anyhow::Context;
+use byteorder::{ByteOrder, NativeEndian};
+use netlink_packet_utils::{
+    nla::{DefaultNla, Nla, NlaBuffer, NlasIterator},
+    parsers::{parse_u32, parse_u8},
+    traits::{Emitable, Parseable},
+    DecodeError,
+};
+
+use super::entry_info::{
+    BridgeMdbEntry, BridgeMdbEntryBuffer, BRIDGE_MDB_ENTRY_LEN,
+};
+use crate::route::RouteProtocol;
+
+const MDBA_MDB_ENTRY: u16 = 1;
+
+const MDBA_MDB_ENTRY_INFO: u16 = 1;
+
+const MDBA_MDB_EATTR_TIMER: u16 = 1;
+// const MDBA_MDB_EATTR_SRC_LIST: u16 = 2;
+const MDBA_MDB_EATTR_GROUP_MODE: u16 = 3;
+// const MDBA_MDB_EATTR_SOURCE: u16 = 4;
+const MDBA_MDB_EATTR_RTPROT: u16 = 5;
+
+/// Entries of the `MDBA_MDB` attribute.
+#[derive(Debug, PartialEq, Eq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[non_exhaustive]
+pub enum MdbEntry {
+    Entry(Vec<MdbEntryInfo>),
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_nla"))]
+    Other(DefaultNla),
+}
+
+impl Nla for MdbEntry {
+    fn value_len(&self) -> usize {
+        match self {
+            Self::Entry(v) => v.as_slice().buffer_len(),
+            Self::Other(attr) => attr.value_len(),
+        }
+    }
+
+    fn emit_value(&self, buffer: &mut [u8]) {
+        match self {
+            Self::Entry(v) => v.as_slice().emit(buffer),
+            Self::Other(attr) => attr.emit_value(buffer),
+        }
+    }
+
+    fn kind(&self) -> u16 {
+        match self {
+            Self::Entry(_) => MDBA_MDB_ENTRY,
+            Self::Other(attr) => attr.kind(),
+        }
+    }
+}
+
+impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>> for MdbEntry {
+    fn parse(buf: &NlaBuffer<&'a T>) -> Result<Self, DecodeError> {
+        let payload = buf.value();
+        Ok(match buf.kind() {
+            MDBA_MDB_ENTRY => {
+                let mut infos = vec![];
+                for nla in NlasIterator::new(payload) {
+                    let nla = nla.context("invalid MDBA_MDB_ENTRY")?;
+                    infos.push(
+                        MdbEntryInfo::parse(&nla)
+                            .context("failed to parse MDBA_MDB_ENTRY")?,
+                    );
+                }
+                Self::Entry(infos)
+            }
+            kind => Self::Other(
+                DefaultNla::parse(buf)
+                    .context(format!("unknown NLA type {kind}"))?,
+            ),
+        })
+    }
+}
+
+/// Entries of the `MDBA_MDB_ENTRY` attribute.
+#[derive(Debug, PartialEq, Eq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[non_exhaustive]
+pub enum MdbEntryInfo {
+    /// The `MDBA_MDB_ENTRY_INFO` holding the group entry followed by its
+    /// attributes.
+    Info(BridgeMdbEntry, Vec<MdbEntryAttribute>),
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_nla"))]
+    Other(DefaultNla),
+}
+
+impl Nla for MdbEntryInfo {
+    fn value_len(&self) -> usize {
+        match self {
+            Self::Info(entry, attrs) => {
+                entry.buffer_len() + attrs.as_slice().buffer_len()
+            }
+            Self::Other(attr) => attr.value_len(),
+        }
+    }
+
+    fn emit_value(&self, buffer: &mut [u8]) {
+        match self {
+            Self::Info(entry, attrs) => {
+                entry.emit(buffer);
+                attrs.as_slice().emit(&mut buffer[BRIDGE_MDB_ENTRY_LEN..]);
+            }
+            Self::Other(attr) => attr.emit_value(buffer),
+        }
+    }
+
+    fn kind(&self) -> u16 {
+        match self {
+            Self::Info(_, _) => MDBA_MDB_ENTRY_INFO,
+            Self::Other(attr) => attr.kind(),
+        }
+    }
+}
+
+impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>> for MdbEntryInfo {
+    fn parse(buf: &NlaBuffer<&'a T>) -> Result<Self, DecodeError> {
+        let payload = buf.value();
+        Ok(match buf.kind() {
+            MDBA_MDB_ENTRY_INFO => {
+                let entry = BridgeMdbEntry::parse(
+                    &BridgeMdbEntryBuffer::new_checked(payload)
+                        .context("invalid MDBA_MDB_ENTRY_INFO value")?,
+                )?;
+                let mut attrs = vec![];
+                for nla in NlasIterator::new(&payload[BRIDGE_MDB_ENTRY_LEN..]) {
+                    let nla = nla.context("invalid MDBA_MDB_ENTRY_INFO")?;
+                    attrs.push(
+                        MdbEntryAttribute::parse(&nla)
+                            .context("failed to parse MDBA_MDB_ENTRY_INFO")?,
+                    );
+                }
+                Self::Info(entry, attrs)
+            }
+            kind => Self::Other(
+                DefaultNla::parse(buf)
+                    .context(format!("unknown NLA type {kind}"))?,
+            ),
+        })
+    }
+}
+
+/// Attributes following the group entry in `MDBA_MDB_ENTRY_INFO`.
+#[derive(Debug, PartialEq, Eq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[non_exhaustive]
+pub enum MdbEntryAttribute {
+    /// Remaining time in centiseconds(USER_HZ) before the entry expire.
+    Timer(u32),
+    /// `MCAST_EXCLUDE`(0) or `MCAST_INCLUDE`(1)
+    GroupMode(u8),
+    /// Protocol installed this entry.
+    RtProt(RouteProtocol),
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_nla"))]
+    Other(DefaultNla),
+}
+
+impl Nla for MdbEntryAttribute {
+    fn value_len(&self) -> usize {
+        match self {
+            Self::Timer(_) => 4,
+            Self::GroupMode(_) | Self::RtProt(_) => 1,
+            Self::Other(attr) => attr.value_len(),
+        }
+    }
+
+    fn emit_value(&self, buffer: &mut [u8]) {
+        match self {
+            Self::Timer(v) => NativeEndian::write_u32(buffer, *v),
+            Self::GroupMode(v) => buffer[0] = *v,
+            Self::RtProt(v) => buffer[0] = u8::from(*v),
+            Self::Other(attr) => attr.emit_value(buffer),
+        }
+    }
+
+    fn kind(&self) -> u16 {
+        match self {
+            Self::Timer(_) => MDBA_MDB_EATTR_TIMER,
+            Self::GroupMode(_) => MDBA_MDB_EATTR_GROUP_MODE,
+            Self::RtProt(_) => MDBA_MDB_EATTR_RTPROT,
+            Self::Other(attr) => attr.kind(),
+        }
+    }
+}
+
+impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>>
+    for MdbEntryAttribute
+{
+    fn parse(buf: &NlaBuffer<&'a T>) -> Result<Self, DecodeError> {
+        let payload = buf.value();
+        Ok(match buf.kind() {
+            MDBA_MDB_EATTR_TIMER => Self::Timer(
+                parse_u32(payload)
+                    .context("invalid MDBA_MDB_EATTR_TIMER value")?,
+            ),
+            MDBA_MDB_EATTR_GROUP_MODE => Self::GroupMode(
+                parse_u8(payload)
+                    .context("invalid MDBA_MDB_EATTR_GROUP_MODE value")?,
+            ),
+            MDBA_MDB_EATTR_RTPROT => Self::RtProt(
+                parse_u8(payload)
+                    .context("invalid MDBA_MDB_EATTR_RTPROT value")?
+                    .into(),
+            ),
+            kind => Self::Other(
+                DefaultNla::parse(buf)
+                    .context(format!("unknown NLA type {kind}"))?,
+            ),
+        })
+    }
+}
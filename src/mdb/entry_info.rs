@@ -0,0 +1,191 @@
+// SPDX-License-Identifier: MIT
+
+use std::net::{Ipv4Addr, Ipv6Addr};
+
+use netlink_packet_utils::{
+    traits::{Emitable, Parseable},
+    DecodeError,
+};
+
+const ETH_P_IP: u16 = 0x0800;
+const ETH_P_IPV6: u16 = 0x86dd;
+
+const MDB_TEMPORARY: u8 = 0;
+const MDB_PERMANENT: u8 = 1;
+
+const MDB_FLAGS_OFFLOAD: u8 = 1 << 0;
+const MDB_FLAGS_FAST_LEAVE: u8 = 1 << 1;
+const MDB_FLAGS_STAR_EXCL: u8 = 1 << 2;
+const MDB_FLAGS_BLOCKED: u8 = 1 << 3;
+const MDB_FLAGS_OFFLOAD_FAILED: u8 = 1 << 4;
+
+pub(crate) const BRIDGE_MDB_ENTRY_LEN: usize = 28;
+const MDB_ADDR_LEN: usize = 16;
+
+// kernel struct `br_mdb_entry`
+buffer!(BridgeMdbEntryBuffer(BRIDGE_MDB_ENTRY_LEN) {
+    ifindex: (u32, 0..4),
+    state: (u8, 4),
+    flags: (u8, 5),
+    vid: (u16, 6..8),
+    addr: (slice, 8..24),
+    proto: (u16, 24..26),
+    pad: (u16, 26..28),
+});
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[non_exhaustive]
+pub enum MdbEntryState {
+    #[default]
+    Temporary,
+    Permanent,
+    Other(u8),
+}
+
+impl From<u8> for MdbEntryState {
+    fn from(d: u8) -> Self {
+        match d {
+            MDB_TEMPORARY => Self::Temporary,
+            MDB_PERMANENT => Self::Permanent,
+            _ => Self::Other(d),
+        }
+    }
+}
+
+impl From<MdbEntryState> for u8 {
+    fn from(v: MdbEntryState) -> u8 {
+        match v {
+            MdbEntryState::Temporary => MDB_TEMPORARY,
+            MdbEntryState::Permanent => MDB_PERMANENT,
+            MdbEntryState::Other(d) => d,
+        }
+    }
+}
+
+bitflags! {
+    #[non_exhaustive]
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+    #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+    pub struct MdbFlags: u8 {
+        const Offload = MDB_FLAGS_OFFLOAD;
+        const FastLeave = MDB_FLAGS_FAST_LEAVE;
+        const StarExcl = MDB_FLAGS_STAR_EXCL;
+        const Blocked = MDB_FLAGS_BLOCKED;
+        const OffloadFailed = MDB_FLAGS_OFFLOAD_FAILED;
+        const _ = !0;
+    }
+}
+
+/// Multicast group address with its protocol.
+#[derive(Debug, PartialEq, Eq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[non_exhaustive]
+pub enum MdbGroup {
+    Inet(Ipv4Addr),
+    Inet6(Ipv6Addr),
+    /// Layer 2 multicast group, kernel uses protocol 0 for it.
+    Mac([u8; 6]),
+    /// Protocol and the 16 bytes address
+    Other(
+        u16,
+        #[cfg_attr(feature = "serde", serde(with = "crate::serde_hex"))]
+        Vec<u8>,
+    ),
+}
+
+impl Default for MdbGroup {
+    fn default() -> Self {
+        Self::Inet(Ipv4Addr::UNSPECIFIED)
+    }
+}
+
+impl MdbGroup {
+    fn parse(proto: u16, addr: &[u8]) -> Self {
+        match proto {
+            ETH_P_IP => {
+                let mut octets = [0u8; 4];
+                octets.copy_from_slice(&addr[..4]);
+                Self::Inet(Ipv4Addr::from(octets))
+            }
+            ETH_P_IPV6 => {
+                let mut octets = [0u8; 16];
+                octets.copy_from_slice(&addr[..16]);
+                Self::Inet6(Ipv6Addr::from(octets))
+            }
+            0 => {
+                let mut mac = [0u8; 6];
+                mac.copy_from_slice(&addr[..6]);
+                Self::Mac(mac)
+            }
+            _ => Self::Other(proto, addr.to_vec()),
+        }
+    }
+
+    fn proto(&self) -> u16 {
+        match self {
+            Self::Inet(_) => ETH_P_IP,
+            Self::Inet6(_) => ETH_P_IPV6,
+            Self::Mac(_) => 0,
+            Self::Other(proto, _) => *proto,
+        }
+    }
+
+    fn emit(&self, buffer: &mut [u8]) {
+        buffer.fill(0);
+        match self {
+            Self::Inet(v) => buffer[..4].copy_from_slice(&v.octets()),
+            Self::Inet6(v) => buffer.copy_from_slice(&v.octets()),
+            Self::Mac(v) => buffer[..6].copy_from_slice(v),
+            Self::Other(_, v) => {
+                let len = v.len().min(MDB_ADDR_LEN);
+                buffer[..len].copy_from_slice(&v[..len]);
+            }
+        }
+    }
+}
+
+/// Multicast group entry, kernel struct `br_mdb_entry`.
+#[derive(Debug, PartialEq, Eq, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[non_exhaustive]
+pub struct BridgeMdbEntry {
+    /// Interface index of the bridge port, or the bridge itself for host
+    /// joined group.
+    pub ifindex: u32,
+    pub state: MdbEntryState,
+    pub flags: MdbFlags,
+    pub vid: u16,
+    pub group: MdbGroup,
+}
+
+impl<T: AsRef<[u8]> + ?Sized> Parseable<BridgeMdbEntryBuffer<&T>>
+    for BridgeMdbEntry
+{
+    fn parse(buf: &BridgeMdbEntryBuffer<&T>) -> Result<Self, DecodeError> {
+        Ok(Self {
+            ifindex: buf.ifindex(),
+            state: buf.state().into(),
+            flags: MdbFlags::from_bits_retain(buf.flags()),
+            vid: buf.vid(),
+            group: MdbGroup::parse(u16::from_be(buf.proto()), buf.addr()),
+        })
+    }
+}
+
+impl Emitable for BridgeMdbEntry {
+    fn buffer_len(&self) -> usize {
+        BRIDGE_MDB_ENTRY_LEN
+    }
+
+    fn emit(&self, buffer: &mut [u8]) {
+        let mut buffer = BridgeMdbEntryBuffer::new(buffer);
+        buffer.set_ifindex(self.ifindex);
+        buffer.set_state(self.state.into());
+        buffer.set_flags(self.flags.bits());
+        buffer.set_vid(self.vid);
+        self.group.emit(buffer.addr_mut());
+        buffer.set_proto(self.group.proto().to_be());
+        buffer.set_pad(0);
+    }
+}
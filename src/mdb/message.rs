@@ -0,0 +1,57 @@
+// SPDX-License-Identifier: MIT
+
+use anyhow::Context;
+use netlink_packet_utils::{
+    traits::{Emitable, Parseable},
+    DecodeError,
+};
+
+use crate::mdb::{MdbAttribute, MdbHeader, MdbMessageBuffer};
+
+/// Message of `RTM_NEWMDB`, `RTM_DELMDB` and `RTM_GETMDB`, used by
+/// `bridge mdb` command to manage the multicast group database of bridge.
+#[derive(Debug, PartialEq, Eq, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[non_exhaustive]
+pub struct MdbMessage {
+    pub header: MdbHeader,
+    pub attributes: Vec<MdbAttribute>,
+}
+
+impl<'a, T: AsRef<[u8]> + 'a> Parseable<MdbMessageBuffer<&'a T>>
+    for MdbMessage
+{
+    fn parse(buf: &MdbMessageBuffer<&'a T>) -> Result<Self, DecodeError> {
+        Ok(Self {
+            header: MdbHeader::parse(buf)
+                .context("failed to parse mdb message header")?,
+            attributes: Vec::<MdbAttribute>::parse(buf)
+                .context("failed to parse mdb message NLAs")?,
+        })
+    }
+}
+
+impl<'a, T: AsRef<[u8]> + 'a> Parseable<MdbMessageBuffer<&'a T>>
+    for Vec<MdbAttribute>
+{
+    fn parse(buf: &MdbMessageBuffer<&'a T>) -> Result<Self, DecodeError> {
+        let mut attributes = vec![];
+        for nla_buf in buf.attributes() {
+            attributes.push(MdbAttribute::parse(&nla_buf?)?);
+        }
+        Ok(attributes)
+    }
+}
+
+impl Emitable for MdbMessage {
+    fn buffer_len(&self) -> usize {
+        self.header.buffer_len() + self.attributes.as_slice().buffer_len()
+    }
+
+    fn emit(&self, buffer: &mut [u8]) {
+        self.header.emit(buffer);
+        self.attributes
+            .as_slice()
+            .emit(&mut buffer[self.header.buffer_len()..]);
+    }
+}
@@ -0,0 +1,17 @@
+// SPDX-License-Identifier: MIT
+
+mod attribute;
+mod entry;
+mod entry_info;
+mod header;
+mod message;
+#[cfg(test)]
+mod tests;
+
+pub use self::attribute::MdbAttribute;
+pub use self::entry::{MdbEntry, MdbEntryAttribute, MdbEntryInfo};
+pub use self::entry_info::{
+    BridgeMdbEntry, BridgeMdbEntryBuffer, MdbEntryState, MdbFlags, MdbGroup,
+};
+pub use self::header::{MdbHeader, MdbMessageBuffer};
+pub use self::message::MdbMessage;
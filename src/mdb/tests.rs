@@ -0,0 +1,223 @@
+// SPDX-License-Identifier: MIT
+
+use std::net::{Ipv4Addr, Ipv6Addr};
+
+use netlink_packet_core::{
+    NetlinkHeader, NetlinkMessage, NetlinkPayload, NLM_F_ACK, NLM_F_CREATE,
+    NLM_F_REQUEST,
+};
+use netlink_packet_utils::{nla::NlaBuffer, Emitable, Parseable};
+
+use crate::{
+    mdb::{
+        BridgeMdbEntry, MdbAttribute, MdbEntry, MdbEntryAttribute,
+        MdbEntryInfo, MdbEntryState, MdbFlags, MdbGroup, MdbHeader, MdbMessage,
+        MdbMessageBuffer,
+    },
+    route::RouteProtocol,
+    AddressFamily, RouteNetlinkMessage,
+};
+
+// Captured RTM_NEWMDB dump reply of `bridge mdb show` on bridge br9
+// (ifindex 9) with veth9a (ifindex 11) as port after
+//      bridge mdb add dev br9 port veth9a grp 239.1.1.1 permanent
+// The other entries were joined by IPv6 on link up.
+#[test]
+fn test_mdb_dump() {
+    let raw = vec![
+        0x00, // AF_UNSPEC
+        0x00, 0x00, 0x00, // pad
+        0x09, 0x00, 0x00, 0x00, // ifindex 9
+        0xc4, 0x00, 0x01, 0x00, // length 196, MDBA_MDB
+        0x34, 0x00, 0x01, 0x00, // length 52, MDBA_MDB_ENTRY
+        0x30, 0x00, 0x01, 0x00, // length 48, MDBA_MDB_ENTRY_INFO
+        0x0b, 0x00, 0x00, 0x00, // ifindex 11
+        0x00, // MDB_TEMPORARY
+        0x00, // flags
+        0x00, 0x00, // vid
+        0xff, 0x02, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x01,
+        0xff, 0xff, 0x6c, 0x2d, // ff02::1:ffff:6c2d
+        0x86, 0xdd, // ETH_P_IPV6
+        0x00, 0x00, // pad
+        0x08, 0x00, 0x01, 0x00, // length 8, MDBA_MDB_EATTR_TIMER
+        0x88, 0x65, 0x00, 0x00, // 25992
+        0x05, 0x00, 0x05, 0x00, // length 5, MDBA_MDB_EATTR_RTPROT
+        0x02, 0x00, 0x00, 0x00, // RTPROT_KERNEL
+        0x2c, 0x00, 0x01, 0x00, // length 44, MDBA_MDB_ENTRY
+        0x28, 0x00, 0x01, 0x00, // length 40, MDBA_MDB_ENTRY_INFO
+        0x09, 0x00, 0x00, 0x00, // ifindex 9
+        0x00, // MDB_TEMPORARY
+        0x00, // flags
+        0x00, 0x00, // vid
+        0xff, 0x02, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x6a, // ff02::6a
+        0x86, 0xdd, // ETH_P_IPV6
+        0x00, 0x00, // pad
+        0x08, 0x00, 0x01, 0x00, // length 8, MDBA_MDB_EATTR_TIMER
+        0x88, 0x65, 0x00, 0x00, // 25992
+        0x2c, 0x00, 0x01, 0x00, // length 44, MDBA_MDB_ENTRY
+        0x28, 0x00, 0x01, 0x00, // length 40, MDBA_MDB_ENTRY_INFO
+        0x09, 0x00, 0x00, 0x00, // ifindex 9
+        0x00, // MDB_TEMPORARY
+        0x00, // flags
+        0x00, 0x00, // vid
+        0xff, 0x02, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x01,
+        0xff, 0xc5, 0x25, 0x50, // ff02::1:ffc5:2550
+        0x86, 0xdd, // ETH_P_IPV6
+        0x00, 0x00, // pad
+        0x08, 0x00, 0x01, 0x00, // length 8, MDBA_MDB_EATTR_TIMER
+        0x88, 0x65, 0x00, 0x00, // 25992
+        0x34, 0x00, 0x01, 0x00, // length 52, MDBA_MDB_ENTRY
+        0x30, 0x00, 0x01, 0x00, // length 48, MDBA_MDB_ENTRY_INFO
+        0x0b, 0x00, 0x00, 0x00, // ifindex 11
+        0x01, // MDB_PERMANENT
+        0x00, // flags
+        0x00, 0x00, // vid
+        0xef, 0x01, 0x01, 0x01, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x00, // 239.1.1.1
+        0x08, 0x00, // ETH_P_IP
+        0x00, 0x00, // pad
+        0x08, 0x00, 0x01, 0x00, // length 8, MDBA_MDB_EATTR_TIMER
+        0x00, 0x00, 0x00, 0x00, // 0
+        0x05, 0x00, 0x05, 0x00, // length 5, MDBA_MDB_EATTR_RTPROT
+        0x04, 0x00, 0x00, 0x00, // RTPROT_STATIC
+    ];
+
+    let ipv6_entry = |ifindex, group, rtprot: Option<RouteProtocol>| {
+        let mut attrs = vec![MdbEntryAttribute::Timer(25992)];
+        if let Some(rtprot) = rtprot {
+            attrs.push(MdbEntryAttribute::RtProt(rtprot));
+        }
+        MdbEntry::Entry(vec![MdbEntryInfo::Info(
+            BridgeMdbEntry {
+                ifindex,
+                state: MdbEntryState::Temporary,
+                flags: MdbFlags::empty(),
+                vid: 0,
+                group: MdbGroup::Inet6(group),
+            },
+            attrs,
+        )])
+    };
+
+    let expected = MdbMessage {
+        header: MdbHeader {
+            family: AddressFamily::Unspec,
+            ifindex: 9,
+        },
+        attributes: vec![MdbAttribute::Mdb(vec![
+            ipv6_entry(
+                11,
+                Ipv6Addr::new(0xff02, 0, 0, 0, 0, 1, 0xffff, 0x6c2d),
+                Some(RouteProtocol::Kernel),
+            ),
+            ipv6_entry(9, Ipv6Addr::new(0xff02, 0, 0, 0, 0, 0, 0, 0x6a), None),
+            ipv6_entry(
+                9,
+                Ipv6Addr::new(0xff02, 0, 0, 0, 0, 1, 0xffc5, 0x2550),
+                None,
+            ),
+            MdbEntry::Entry(vec![MdbEntryInfo::Info(
+                BridgeMdbEntry {
+                    ifindex: 11,
+                    state: MdbEntryState::Permanent,
+                    flags: MdbFlags::empty(),
+                    vid: 0,
+                    group: MdbGroup::Inet(Ipv4Addr::new(239, 1, 1, 1)),
+                },
+                vec![
+                    MdbEntryAttribute::Timer(0),
+                    MdbEntryAttribute::RtProt(RouteProtocol::Static),
+                ],
+            )]),
+        ])],
+    };
+
+    assert_eq!(
+        expected,
+        MdbMessage::parse(&MdbMessageBuffer::new(&raw)).unwrap()
+    );
+
+    let mut buf = vec![0; expected.buffer_len()];
+    expected.emit(&mut buf);
+    assert_eq!(buf, raw);
+}
+
+// Hand-crafted RTM_NEWMDB request equivalent to the one sent by
+//      bridge mdb add dev br9 port veth9a grp 239.2.2.2 permanent
+// with br9 holding interface index 9 and veth9a holding 11. Verified
+// to be accepted by kernel 6.18.
+#[test]
+fn test_new_mdb_netlink_message() {
+    let raw = vec![
+        0x38, 0x00, 0x00, 0x00, // length 56
+        0x54, 0x00, // RTM_NEWMDB 84
+        0x05, 0x04, // NLM_F_REQUEST | NLM_F_ACK | NLM_F_CREATE
+        0x00, 0x00, 0x00, 0x00, // sequence number
+        0x00, 0x00, 0x00, 0x00, // port id
+        0x07, // AF_BRIDGE
+        0x00, 0x00, 0x00, // pad
+        0x09, 0x00, 0x00, 0x00, // ifindex 9
+        0x20, 0x00, 0x01, 0x00, // length 32, MDBA_SET_ENTRY
+        0x0b, 0x00, 0x00, 0x00, // ifindex 11
+        0x01, // MDB_PERMANENT
+        0x00, // flags
+        0x00, 0x00, // vid
+        0xef, 0x02, 0x02, 0x02, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x00, // 239.2.2.2
+        0x08, 0x00, // ETH_P_IP
+        0x00, 0x00, // pad
+    ];
+
+    let mut header = NetlinkHeader::default();
+    header.flags = NLM_F_REQUEST | NLM_F_ACK | NLM_F_CREATE;
+    let mut expected = NetlinkMessage::new(
+        header,
+        NetlinkPayload::from(RouteNetlinkMessage::NewMdb(MdbMessage {
+            header: MdbHeader {
+                family: AddressFamily::Bridge,
+                ifindex: 9,
+            },
+            attributes: vec![MdbAttribute::SetEntry(BridgeMdbEntry {
+                ifindex: 11,
+                state: MdbEntryState::Permanent,
+                flags: MdbFlags::empty(),
+                vid: 0,
+                group: MdbGroup::Inet(Ipv4Addr::new(239, 2, 2, 2)),
+            })],
+        })),
+    );
+    expected.finalize();
+
+    let mut buf = vec![0; expected.buffer_len()];
+    expected.emit(&mut buf);
+    assert_eq!(buf, raw);
+
+    let parsed =
+        NetlinkMessage::<RouteNetlinkMessage>::deserialize(&raw).unwrap();
+    assert_eq!(parsed, expected);
+    if let NetlinkPayload::InnerMessage(msg) = parsed.payload {
+        assert!(msg.is_new_mdb());
+    } else {
+        panic!("expecting RTM_NEWMDB message");
+    }
+}
+
+#[test]
+fn test_mdb_mac_group() {
+    let entry = BridgeMdbEntry {
+        ifindex: 11,
+        state: MdbEntryState::Permanent,
+        flags: MdbFlags::Offload,
+        vid: 10,
+        group: MdbGroup::Mac([0x01, 0x00, 0x5e, 0x01, 0x02, 0x03]),
+    };
+    let attr = MdbAttribute::SetEntry(entry);
+
+    let mut buf = vec![0; attr.buffer_len()];
+    attr.emit(&mut buf);
+    assert_eq!(&buf[12..18], &[0x01, 0x00, 0x5e, 0x01, 0x02, 0x03]);
+    assert_eq!(&buf[28..30], &[0x00, 0x00]); // proto 0 for L2 group
+
+    assert_eq!(MdbAttribute::parse(&NlaBuffer::new(&buf)).unwrap(), attr);
+}
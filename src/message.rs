@@ -1,24 +1,32 @@
 // SPDX-License-Identifier: MIT
 
+use std::fmt;
+
 use anyhow::Context;
 use netlink_packet_core::{
-    NetlinkDeserializable, NetlinkHeader, NetlinkPayload, NetlinkSerializable,
+    NetlinkBuffer, NetlinkDeserializable, NetlinkHeader, NetlinkMessage,
+    NetlinkPayload, NetlinkSerializable, NETLINK_HEADER_LEN,
 };
 use netlink_packet_utils::{
-    DecodeError, Emitable, Parseable, ParseableParametrized,
+    nla::NlasIterator, DecodeError, Emitable, Parseable, ParseableParametrized,
 };
 
 use crate::tc::{TcActionMessage, TcActionMessageBuffer};
 use crate::{
     address::{AddressHeader, AddressMessage, AddressMessageBuffer},
     link::{LinkMessage, LinkMessageBuffer},
+    mdb::{MdbMessage, MdbMessageBuffer},
     neighbour::{NeighbourMessage, NeighbourMessageBuffer},
     neighbour_table::{NeighbourTableMessage, NeighbourTableMessageBuffer},
+    nexthop::{NexthopMessage, NexthopMessageBuffer},
     nsid::{NsidMessage, NsidMessageBuffer},
     prefix::{PrefixMessage, PrefixMessageBuffer},
     route::{RouteHeader, RouteMessage, RouteMessageBuffer},
     rule::{RuleMessage, RuleMessageBuffer},
+    stats::{StatsMessage, StatsMessageBuffer},
     tc::{TcMessage, TcMessageBuffer},
+    tunnel::{TunnelMessage, TunnelMessageBuffer},
+    vlan::{VlanMessage, VlanMessageBuffer},
 };
 
 const RTM_NEWLINK: u16 = 16;
@@ -64,20 +72,32 @@ const RTM_SETNEIGHTBL: u16 = 67;
 // const RTM_NEWNETCONF: u16 = 80;
 // const RTM_DELNETCONF: u16 = 81;
 // const RTM_GETNETCONF: u16 = 82;
-// const RTM_NEWMDB: u16 = 84;
-// const RTM_DELMDB: u16 = 85;
-// const RTM_GETMDB: u16 = 86;
+const RTM_NEWMDB: u16 = 84;
+const RTM_DELMDB: u16 = 85;
+const RTM_GETMDB: u16 = 86;
 const RTM_NEWNSID: u16 = 88;
 const RTM_DELNSID: u16 = 89;
 const RTM_GETNSID: u16 = 90;
-// const RTM_NEWSTATS: u16 = 92;
-// const RTM_GETSTATS: u16 = 94;
+const RTM_NEWSTATS: u16 = 92;
+const RTM_GETSTATS: u16 = 94;
 // const RTM_NEWCACHEREPORT: u16 = 96;
 const RTM_NEWCHAIN: u16 = 100;
 const RTM_DELCHAIN: u16 = 101;
 const RTM_GETCHAIN: u16 = 102;
+const RTM_NEWNEXTHOP: u16 = 104;
+const RTM_DELNEXTHOP: u16 = 105;
+const RTM_GETNEXTHOP: u16 = 106;
 const RTM_NEWLINKPROP: u16 = 108;
 const RTM_DELLINKPROP: u16 = 109;
+const RTM_NEWVLAN: u16 = 112;
+const RTM_DELVLAN: u16 = 113;
+const RTM_GETVLAN: u16 = 114;
+const RTM_NEWNEXTHOPBUCKET: u16 = 116;
+const RTM_DELNEXTHOPBUCKET: u16 = 117;
+const RTM_GETNEXTHOPBUCKET: u16 = 118;
+const RTM_NEWTUNNEL: u16 = 120;
+const RTM_DELTUNNEL: u16 = 121;
+const RTM_GETTUNNEL: u16 = 122;
 
 buffer!(RouteNetlinkMessageBuffer);
 
@@ -118,6 +138,20 @@ impl<'a, T: AsRef<[u8]> + ?Sized>
                 }
             }
 
+            // Link property messages
+            RTM_NEWLINKPROP | RTM_DELLINKPROP => {
+                let msg = LinkMessage::parse(
+                    &LinkMessageBuffer::new_checked(&buf.inner())
+                        .context("invalid link property message")?,
+                )
+                .context("invalid link property message")?;
+                match message_type {
+                    RTM_NEWLINKPROP => RouteNetlinkMessage::NewLinkProp(msg),
+                    RTM_DELLINKPROP => RouteNetlinkMessage::DelLinkProp(msg),
+                    _ => unreachable!(),
+                }
+            }
+
             // Address messages
             RTM_NEWADDR | RTM_GETADDR | RTM_DELADDR => {
                 let msg = match AddressMessageBuffer::new_checked(&buf.inner())
@@ -322,6 +356,108 @@ impl<'a, T: AsRef<[u8]> + ?Sized>
                 }
             }
 
+            // Stats messages
+            RTM_NEWSTATS | RTM_GETSTATS => {
+                let err = "invalid stats message";
+                let msg = StatsMessage::parse(
+                    &StatsMessageBuffer::new_checked(&buf.inner())
+                        .context(err)?,
+                )
+                .context(err)?;
+                match message_type {
+                    RTM_NEWSTATS => RouteNetlinkMessage::NewStats(msg),
+                    RTM_GETSTATS => RouteNetlinkMessage::GetStats(msg),
+                    _ => unreachable!(),
+                }
+            }
+
+            // Nexthop messages
+            RTM_NEWNEXTHOP | RTM_DELNEXTHOP | RTM_GETNEXTHOP => {
+                let err = "invalid nexthop message";
+                let msg = NexthopMessage::parse(
+                    &NexthopMessageBuffer::new_checked(&buf.inner())
+                        .context(err)?,
+                )
+                .context(err)?;
+                match message_type {
+                    RTM_NEWNEXTHOP => RouteNetlinkMessage::NewNexthop(msg),
+                    RTM_DELNEXTHOP => RouteNetlinkMessage::DelNexthop(msg),
+                    RTM_GETNEXTHOP => RouteNetlinkMessage::GetNexthop(msg),
+                    _ => unreachable!(),
+                }
+            }
+
+            // Nexthop bucket messages
+            RTM_NEWNEXTHOPBUCKET | RTM_DELNEXTHOPBUCKET
+            | RTM_GETNEXTHOPBUCKET => {
+                let err = "invalid nexthop bucket message";
+                let msg = NexthopMessage::parse(
+                    &NexthopMessageBuffer::new_checked(&buf.inner())
+                        .context(err)?,
+                )
+                .context(err)?;
+                match message_type {
+                    RTM_NEWNEXTHOPBUCKET => {
+                        RouteNetlinkMessage::NewNexthopBucket(msg)
+                    }
+                    RTM_DELNEXTHOPBUCKET => {
+                        RouteNetlinkMessage::DelNexthopBucket(msg)
+                    }
+                    RTM_GETNEXTHOPBUCKET => {
+                        RouteNetlinkMessage::GetNexthopBucket(msg)
+                    }
+                    _ => unreachable!(),
+                }
+            }
+
+            // Tunnel messages
+            RTM_NEWTUNNEL | RTM_DELTUNNEL | RTM_GETTUNNEL => {
+                let err = "invalid tunnel message";
+                let msg = TunnelMessage::parse(
+                    &TunnelMessageBuffer::new_checked(&buf.inner())
+                        .context(err)?,
+                )
+                .context(err)?;
+                match message_type {
+                    RTM_NEWTUNNEL => RouteNetlinkMessage::NewTunnel(msg),
+                    RTM_DELTUNNEL => RouteNetlinkMessage::DelTunnel(msg),
+                    RTM_GETTUNNEL => RouteNetlinkMessage::GetTunnel(msg),
+                    _ => unreachable!(),
+                }
+            }
+
+            // Bridge multicast database messages
+            RTM_NEWMDB | RTM_DELMDB | RTM_GETMDB => {
+                let err = "invalid mdb message";
+                let msg = MdbMessage::parse(
+                    &MdbMessageBuffer::new_checked(&buf.inner())
+                        .context(err)?,
+                )
+                .context(err)?;
+                match message_type {
+                    RTM_NEWMDB => RouteNetlinkMessage::NewMdb(msg),
+                    RTM_DELMDB => RouteNetlinkMessage::DelMdb(msg),
+                    RTM_GETMDB => RouteNetlinkMessage::GetMdb(msg),
+                    _ => unreachable!(),
+                }
+            }
+
+            // Bridge VLAN messages
+            RTM_NEWVLAN | RTM_DELVLAN | RTM_GETVLAN => {
+                let err = "invalid vlan message";
+                let msg = VlanMessage::parse(
+                    &VlanMessageBuffer::new_checked(&buf.inner())
+                        .context(err)?,
+                )
+                .context(err)?;
+                match message_type {
+                    RTM_NEWVLAN => RouteNetlinkMessage::NewVlan(msg),
+                    RTM_DELVLAN => RouteNetlinkMessage::DelVlan(msg),
+                    RTM_GETVLAN => RouteNetlinkMessage::GetVlan(msg),
+                    _ => unreachable!(),
+                }
+            }
+
             _ => {
                 return Err(
                     format!("Unknown message type: {message_type}").into()
@@ -333,6 +469,7 @@ impl<'a, T: AsRef<[u8]> + ?Sized>
 }
 
 #[derive(Debug, PartialEq, Eq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[non_exhaustive]
 pub enum RouteNetlinkMessage {
     NewLink(LinkMessage),
@@ -372,9 +509,34 @@ pub enum RouteNetlinkMessage {
     NewNsId(NsidMessage),
     DelNsId(NsidMessage),
     GetNsId(NsidMessage),
+    /// Interface statistics selected by
+    /// [crate::stats::StatsHeader::filter_mask].
+    NewStats(StatsMessage),
+    GetStats(StatsMessage),
     NewRule(RuleMessage),
     DelRule(RuleMessage),
     GetRule(RuleMessage),
+    NewNexthop(NexthopMessage),
+    DelNexthop(NexthopMessage),
+    GetNexthop(NexthopMessage),
+    /// Hash bucket of resilient nexthop group, holding
+    /// [crate::nexthop::NexthopAttribute::Id] of the group and
+    /// [crate::nexthop::NexthopAttribute::ResBucket].
+    NewNexthopBucket(NexthopMessage),
+    DelNexthopBucket(NexthopMessage),
+    GetNexthopBucket(NexthopMessage),
+    /// VNI filter entries of VXLAN device.
+    NewTunnel(TunnelMessage),
+    DelTunnel(TunnelMessage),
+    GetTunnel(TunnelMessage),
+    /// Multicast group database of bridge.
+    NewMdb(MdbMessage),
+    DelMdb(MdbMessage),
+    GetMdb(MdbMessage),
+    /// Per-VLAN options of bridge and bridge port.
+    NewVlan(VlanMessage),
+    DelVlan(VlanMessage),
+    GetVlan(VlanMessage),
 }
 
 impl RouteNetlinkMessage {
@@ -514,6 +676,14 @@ impl RouteNetlinkMessage {
         matches!(self, RouteNetlinkMessage::DelNsId(_))
     }
 
+    pub fn is_new_stats(&self) -> bool {
+        matches!(self, RouteNetlinkMessage::NewStats(_))
+    }
+
+    pub fn is_get_stats(&self) -> bool {
+        matches!(self, RouteNetlinkMessage::GetStats(_))
+    }
+
     pub fn is_get_rule(&self) -> bool {
         matches!(self, RouteNetlinkMessage::GetRule(_))
     }
@@ -526,6 +696,66 @@ impl RouteNetlinkMessage {
         matches!(self, RouteNetlinkMessage::DelRule(_))
     }
 
+    pub fn is_new_nexthop(&self) -> bool {
+        matches!(self, RouteNetlinkMessage::NewNexthop(_))
+    }
+
+    pub fn is_del_nexthop(&self) -> bool {
+        matches!(self, RouteNetlinkMessage::DelNexthop(_))
+    }
+
+    pub fn is_get_nexthop(&self) -> bool {
+        matches!(self, RouteNetlinkMessage::GetNexthop(_))
+    }
+
+    pub fn is_new_nexthop_bucket(&self) -> bool {
+        matches!(self, RouteNetlinkMessage::NewNexthopBucket(_))
+    }
+
+    pub fn is_del_nexthop_bucket(&self) -> bool {
+        matches!(self, RouteNetlinkMessage::DelNexthopBucket(_))
+    }
+
+    pub fn is_get_nexthop_bucket(&self) -> bool {
+        matches!(self, RouteNetlinkMessage::GetNexthopBucket(_))
+    }
+
+    pub fn is_new_tunnel(&self) -> bool {
+        matches!(self, RouteNetlinkMessage::NewTunnel(_))
+    }
+
+    pub fn is_del_tunnel(&self) -> bool {
+        matches!(self, RouteNetlinkMessage::DelTunnel(_))
+    }
+
+    pub fn is_get_tunnel(&self) -> bool {
+        matches!(self, RouteNetlinkMessage::GetTunnel(_))
+    }
+
+    pub fn is_new_mdb(&self) -> bool {
+        matches!(self, RouteNetlinkMessage::NewMdb(_))
+    }
+
+    pub fn is_del_mdb(&self) -> bool {
+        matches!(self, RouteNetlinkMessage::DelMdb(_))
+    }
+
+    pub fn is_get_mdb(&self) -> bool {
+        matches!(self, RouteNetlinkMessage::GetMdb(_))
+    }
+
+    pub fn is_new_vlan(&self) -> bool {
+        matches!(self, RouteNetlinkMessage::NewVlan(_))
+    }
+
+    pub fn is_del_vlan(&self) -> bool {
+        matches!(self, RouteNetlinkMessage::DelVlan(_))
+    }
+
+    pub fn is_get_vlan(&self) -> bool {
+        matches!(self, RouteNetlinkMessage::GetVlan(_))
+    }
+
     pub fn message_type(&self) -> u16 {
         use self::RouteNetlinkMessage::*;
 
@@ -567,9 +797,218 @@ impl RouteNetlinkMessage {
             GetNsId(_) => RTM_GETNSID,
             NewNsId(_) => RTM_NEWNSID,
             DelNsId(_) => RTM_DELNSID,
+            NewStats(_) => RTM_NEWSTATS,
+            GetStats(_) => RTM_GETSTATS,
             GetRule(_) => RTM_GETRULE,
             NewRule(_) => RTM_NEWRULE,
             DelRule(_) => RTM_DELRULE,
+            NewNexthop(_) => RTM_NEWNEXTHOP,
+            DelNexthop(_) => RTM_DELNEXTHOP,
+            GetNexthop(_) => RTM_GETNEXTHOP,
+            NewNexthopBucket(_) => RTM_NEWNEXTHOPBUCKET,
+            DelNexthopBucket(_) => RTM_DELNEXTHOPBUCKET,
+            GetNexthopBucket(_) => RTM_GETNEXTHOPBUCKET,
+            NewTunnel(_) => RTM_NEWTUNNEL,
+            DelTunnel(_) => RTM_DELTUNNEL,
+            GetTunnel(_) => RTM_GETTUNNEL,
+            NewMdb(_) => RTM_NEWMDB,
+            DelMdb(_) => RTM_DELMDB,
+            GetMdb(_) => RTM_GETMDB,
+            NewVlan(_) => RTM_NEWVLAN,
+            DelVlan(_) => RTM_DELVLAN,
+            GetVlan(_) => RTM_GETVLAN,
+        }
+    }
+
+    /// Find the top-level attribute of the serialized netlink `message`
+    /// (netlink header included) which holds the byte at `offset`.
+    ///
+    /// This is intended for resolving the `NLMSGERR_ATTR_OFFS` of a kernel
+    /// extended ACK, which points into the request that failed. Offsets
+    /// inside a nested attribute resolve to the top-level attribute holding
+    /// it. Returns `None` for unknown message types, or when `offset` falls
+    /// into the headers, padding or outside of `message`.
+    ///
+    /// ```
+    /// use netlink_packet_core::{NetlinkHeader, NetlinkMessage};
+    /// use netlink_packet_route::{
+    ///     route::{RouteAttribute, RouteMessage},
+    ///     RouteNetlinkMessage,
+    /// };
+    ///
+    /// let mut route = RouteMessage::default();
+    /// route.attributes.push(RouteAttribute::Oif(2));
+    /// let mut msg = NetlinkMessage::new(
+    ///     NetlinkHeader::default(),
+    ///     RouteNetlinkMessage::NewRoute(route).into(),
+    /// );
+    /// msg.finalize();
+    /// let mut buf = vec![0; msg.buffer_len()];
+    /// msg.serialize(&mut buf);
+    ///
+    /// // netlink header(16) + route header(12) + attribute header(4)
+    /// let attr = RouteNetlinkMessage::attribute_at_offset(&buf, 32).unwrap();
+    /// assert_eq!(attr.to_string(), "route/RTA_OIF");
+    /// ```
+    pub fn attribute_at_offset(
+        message: &[u8],
+        offset: usize,
+    ) -> Option<AttributeLocation> {
+        let buf = NetlinkBuffer::new_checked(message).ok()?;
+        let (module, header_len) = message_module(buf.message_type())?;
+        let mut start = NETLINK_HEADER_LEN + header_len;
+        for nla in NlasIterator::new(buf.payload().get(header_len..)?) {
+            let nla = nla.ok()?;
+            let length = nla.length() as usize;
+            if (start..start + length).contains(&offset) {
+                let kind = nla.kind();
+                return Some(AttributeLocation {
+                    module,
+                    kind,
+                    name: match module {
+                        "route" => crate::route::attribute_name(kind),
+                        _ => None,
+                    },
+                    offset: start,
+                    length,
+                });
+            }
+            start += (length + 3) & !3;
+        }
+        None
+    }
+
+    /// Deserialize a single netlink message like
+    /// [NetlinkMessage::deserialize()], but fail if `buffer` holds any bytes
+    /// beyond the `nlmsg_len` of the message header, which are silently
+    /// ignored otherwise.
+    ///
+    /// This is meant for validating captured or generated messages holding
+    /// exactly one message. Kernel replies to dump requests batch several
+    /// messages in one buffer, which this function rejects, use
+    /// [Self::iter_messages()] to split them.
+    pub fn parse_strict(
+        buffer: &[u8],
+    ) -> Result<NetlinkMessage<Self>, DecodeError> {
+        let length = NetlinkBuffer::new_checked(buffer)?.length() as usize;
+        if buffer.len() != length {
+            return Err(format!(
+                "{} bytes trailing the netlink message of length {length}",
+                buffer.len() - length
+            )
+            .into());
+        }
+        NetlinkMessage::deserialize(buffer)
+    }
+
+    /// Split the buffer holding multiple netlink messages, e.g. received
+    /// by `recvmsg()` for a dump request, into one [NetlinkBuffer] per
+    /// message by walking the 4 bytes aligned `nlmsg_len`.
+    ///
+    /// A truncated message yields an error and ends the iteration.
+    ///
+    /// ```
+    /// use netlink_packet_core::{NetlinkHeader, NetlinkMessage};
+    /// use netlink_packet_route::{link::LinkMessage, RouteNetlinkMessage};
+    ///
+    /// let mut buf = vec![];
+    /// for index in [1, 2] {
+    ///     let mut link = LinkMessage::default();
+    ///     link.header.index = index;
+    ///     let mut msg = NetlinkMessage::new(
+    ///         NetlinkHeader::default(),
+    ///         RouteNetlinkMessage::NewLink(link).into(),
+    ///     );
+    ///     msg.finalize();
+    ///     let start = buf.len();
+    ///     buf.resize(start + msg.buffer_len(), 0);
+    ///     msg.serialize(&mut buf[start..]);
+    /// }
+    ///
+    /// let msgs = RouteNetlinkMessage::iter_messages(&buf)
+    ///     .collect::<Result<Vec<_>, _>>()
+    ///     .unwrap();
+    /// assert_eq!(msgs.len(), 2);
+    /// ```
+    pub fn iter_messages(
+        buffer: &[u8],
+    ) -> impl Iterator<Item = Result<NetlinkBuffer<&[u8]>, DecodeError>> {
+        let mut rest = buffer;
+        std::iter::from_fn(move || {
+            if rest.is_empty() {
+                return None;
+            }
+            let length = match NetlinkBuffer::new_checked(rest) {
+                Ok(buf) => buf.length() as usize,
+                Err(e) => {
+                    rest = &[];
+                    return Some(Err(e));
+                }
+            };
+            let (message, remains) = rest.split_at(length);
+            rest = remains.get((4 - length % 4) % 4..).unwrap_or_default();
+            Some(Ok(NetlinkBuffer::new(message)))
+        })
+    }
+}
+
+// Module name and family header length of rtnetlink message type.
+fn message_module(message_type: u16) -> Option<(&'static str, usize)> {
+    Some(match message_type {
+        RTM_NEWLINK | RTM_DELLINK | RTM_GETLINK | RTM_SETLINK
+        | RTM_NEWLINKPROP | RTM_DELLINKPROP => ("link", 16),
+        RTM_NEWADDR | RTM_DELADDR | RTM_GETADDR => ("address", 8),
+        RTM_NEWROUTE | RTM_DELROUTE | RTM_GETROUTE => ("route", 12),
+        RTM_NEWNEIGH | RTM_DELNEIGH | RTM_GETNEIGH => ("neighbour", 12),
+        RTM_NEWRULE | RTM_DELRULE | RTM_GETRULE => ("rule", 12),
+        RTM_NEWQDISC | RTM_DELQDISC | RTM_GETQDISC | RTM_NEWTCLASS
+        | RTM_DELTCLASS | RTM_GETTCLASS | RTM_NEWTFILTER | RTM_DELTFILTER
+        | RTM_GETTFILTER | RTM_NEWCHAIN | RTM_DELCHAIN | RTM_GETCHAIN => {
+            ("tc", 20)
+        }
+        RTM_NEWACTION | RTM_DELACTION | RTM_GETACTION => ("tc", 4),
+        RTM_NEWPREFIX => ("prefix", 12),
+        RTM_NEWNEIGHTBL | RTM_GETNEIGHTBL | RTM_SETNEIGHTBL => {
+            ("neighbour_table", 4)
+        }
+        RTM_NEWNSID | RTM_DELNSID | RTM_GETNSID => ("nsid", 4),
+        RTM_NEWSTATS | RTM_GETSTATS => ("stats", 12),
+        RTM_NEWNEXTHOP | RTM_DELNEXTHOP | RTM_GETNEXTHOP
+        | RTM_NEWNEXTHOPBUCKET | RTM_DELNEXTHOPBUCKET
+        | RTM_GETNEXTHOPBUCKET => ("nexthop", 8),
+        RTM_NEWTUNNEL | RTM_DELTUNNEL | RTM_GETTUNNEL => ("tunnel", 8),
+        RTM_NEWMDB | RTM_DELMDB | RTM_GETMDB => ("mdb", 8),
+        RTM_NEWVLAN | RTM_DELVLAN | RTM_GETVLAN => ("vlan", 8),
+        _ => return None,
+    })
+}
+
+/// Top-level attribute located by
+/// [RouteNetlinkMessage::attribute_at_offset()].
+///
+/// The [fmt::Display] format is `<module>/<name>`, for example
+/// `route/RTA_GATEWAY`. Attribute names are only known for the `route`
+/// module for now, others are displayed with numeric kind like `link/3`.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+#[non_exhaustive]
+pub struct AttributeLocation {
+    /// Module of this crate holding the message, e.g. `route`.
+    pub module: &'static str,
+    /// Attribute kind with `NLA_F_NESTED` and `NLA_F_NET_BYTEORDER` removed.
+    pub kind: u16,
+    /// Kernel name of the attribute, e.g. `RTA_GATEWAY`.
+    pub name: Option<&'static str>,
+    /// Offset of the attribute header in the message.
+    pub offset: usize,
+    /// Length of the attribute including its header but not the padding.
+    pub length: usize,
+}
+
+impl fmt::Display for AttributeLocation {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self.name {
+            Some(name) => write!(f, "{}/{name}", self.module),
+            None => write!(f, "{}/{}", self.module, self.kind),
         }
     }
 }
@@ -628,6 +1067,10 @@ impl Emitable for RouteNetlinkMessage {
             | GetNsId(ref msg)
             => msg.buffer_len(),
 
+            | NewStats(ref msg)
+            | GetStats(ref msg)
+            => msg.buffer_len(),
+
             | NewRule(ref msg)
             | DelRule(ref msg)
             | GetRule(ref msg)
@@ -637,6 +1080,29 @@ impl Emitable for RouteNetlinkMessage {
             | DelTrafficAction(ref msg)
             | GetTrafficAction(ref msg)
             => msg.buffer_len(),
+
+            | NewNexthop(ref msg)
+            | DelNexthop(ref msg)
+            | GetNexthop(ref msg)
+            | NewNexthopBucket(ref msg)
+            | DelNexthopBucket(ref msg)
+            | GetNexthopBucket(ref msg)
+            => msg.buffer_len(),
+
+            | NewTunnel(ref msg)
+            | DelTunnel(ref msg)
+            | GetTunnel(ref msg)
+            => msg.buffer_len(),
+
+            | NewMdb(ref msg)
+            | DelMdb(ref msg)
+            | GetMdb(ref msg)
+            => msg.buffer_len(),
+
+            | NewVlan(ref msg)
+            | DelVlan(ref msg)
+            | GetVlan(ref msg)
+            => msg.buffer_len(),
         }
     }
 
@@ -693,6 +1159,10 @@ impl Emitable for RouteNetlinkMessage {
             | GetNsId(ref msg)
             => msg.emit(buffer),
 
+            | NewStats(ref msg)
+            | GetStats(ref msg)
+            => msg.emit(buffer),
+
             | NewRule(ref msg)
             | DelRule(ref msg)
             | GetRule(ref msg)
@@ -702,6 +1172,29 @@ impl Emitable for RouteNetlinkMessage {
             | DelTrafficAction(ref msg)
             | GetTrafficAction(ref msg)
             => msg.emit(buffer),
+
+            | NewNexthop(ref msg)
+            | DelNexthop(ref msg)
+            | GetNexthop(ref msg)
+            | NewNexthopBucket(ref msg)
+            | DelNexthopBucket(ref msg)
+            | GetNexthopBucket(ref msg)
+            => msg.emit(buffer),
+
+            | NewTunnel(ref msg)
+            | DelTunnel(ref msg)
+            | GetTunnel(ref msg)
+            => msg.emit(buffer),
+
+            | NewMdb(ref msg)
+            | DelMdb(ref msg)
+            | GetMdb(ref msg)
+            => msg.emit(buffer),
+
+            | NewVlan(ref msg)
+            | DelVlan(ref msg)
+            | GetVlan(ref msg)
+            => msg.emit(buffer),
         }
     }
 }
@@ -1,6 +1,6 @@
 // SPDX-License-Identifier: MIT
 
-use std::net::{Ipv4Addr, Ipv6Addr};
+use std::net::{IpAddr, Ipv4Addr, Ipv6Addr};
 
 use netlink_packet_utils::{DecodeError, Emitable};
 
@@ -13,10 +13,12 @@ use crate::{
 };
 
 #[derive(Debug, PartialEq, Eq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[non_exhaustive]
 pub enum NeighbourAddress {
     Inet(Ipv4Addr),
     Inet6(Ipv6Addr),
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_hex"))]
     Other(Vec<u8>),
 }
 
@@ -28,6 +30,16 @@ impl NeighbourAddress {
         Ok(match address_family {
             AddressFamily::Inet => Self::Inet(parse_ipv4_addr(payload)?),
             AddressFamily::Inet6 => Self::Inet6(parse_ipv6_addr(payload)?),
+            // The NDA_DST of VxLAN FDB entry holds the IP address of remote
+            // VTEP
+            #[cfg(any(target_os = "linux", target_os = "fuchsia"))]
+            AddressFamily::Bridge if payload.len() == IPV4_ADDR_LEN => {
+                Self::Inet(parse_ipv4_addr(payload)?)
+            }
+            #[cfg(any(target_os = "linux", target_os = "fuchsia"))]
+            AddressFamily::Bridge if payload.len() == IPV6_ADDR_LEN => {
+                Self::Inet6(parse_ipv6_addr(payload)?)
+            }
             _ => Self::Other(payload.to_vec()),
         })
     }
@@ -62,3 +74,12 @@ impl From<Ipv6Addr> for NeighbourAddress {
         Self::Inet6(v)
     }
 }
+
+impl From<IpAddr> for NeighbourAddress {
+    fn from(v: IpAddr) -> Self {
+        match v {
+            IpAddr::V4(v) => Self::Inet(v),
+            IpAddr::V6(v) => Self::Inet6(v),
+        }
+    }
+}
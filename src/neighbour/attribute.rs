@@ -3,12 +3,15 @@
 use anyhow::Context;
 use byteorder::{BigEndian, ByteOrder, NativeEndian};
 use netlink_packet_utils::{
-    nla::{DefaultNla, Nla, NlaBuffer},
+    nla::{DefaultNla, Nla, NlaBuffer, NlasIterator, NLA_F_NESTED},
     parsers::{parse_u16, parse_u16_be, parse_u32},
     DecodeError, Emitable, Parseable, ParseableParametrized,
 };
 
-use super::{NeighbourAddress, NeighbourCacheInfo, NeighbourCacheInfoBuffer};
+use super::{
+    NeighbourAddress, NeighbourCacheInfo, NeighbourCacheInfoBuffer,
+    NeighbourFdbExtAttribute,
+};
 use crate::{route::RouteProtocol, AddressFamily};
 
 const NDA_DST: u16 = 1;
@@ -24,13 +27,15 @@ const NDA_CONTROLLER: u16 = 9;
 const NDA_LINK_NETNSID: u16 = 10;
 const NDA_SRC_VNI: u16 = 11;
 const NDA_PROTOCOL: u16 = 12;
-// const NDA_NH_ID: u16 = 13;
-// const NDA_FDB_EXT_ATTRS: u16 = 14;
+const NDA_NH_ID: u16 = 13;
+const NDA_FDB_EXT_ATTRS: u16 = 14;
 
 #[derive(Debug, PartialEq, Eq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[non_exhaustive]
 pub enum NeighbourAttribute {
     Destination(NeighbourAddress),
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_hex"))]
     LinkLocalAddress(Vec<u8>),
     CacheInfo(NeighbourCacheInfo),
     Probes(u32),
@@ -42,6 +47,11 @@ pub enum NeighbourAttribute {
     LinkNetNsId(u32),
     SourceVni(u32),
     Protocol(RouteProtocol),
+    /// Nexthop group ID of FDB entry, see `bridge fdb add ... nhid`
+    NhId(u32),
+    /// Extended attributes of bridge FDB entry
+    FdbExtAttrs(Vec<NeighbourFdbExtAttribute>),
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_nla"))]
     Other(DefaultNla),
 }
 
@@ -53,12 +63,14 @@ impl Nla for NeighbourAttribute {
             Self::CacheInfo(v) => v.buffer_len(),
             Self::Vlan(_) | Self::Port(_) => 2,
             Self::Protocol(v) => v.buffer_len(),
+            Self::FdbExtAttrs(v) => v.as_slice().buffer_len(),
             Self::Probes(_)
             | Self::LinkNetNsId(_)
             | Self::Controller(_)
             | Self::Vni(_)
             | Self::IfIndex(_)
-            | Self::SourceVni(_) => 4,
+            | Self::SourceVni(_)
+            | Self::NhId(_) => 4,
             Self::Other(attr) => attr.value_len(),
         }
     }
@@ -77,8 +89,10 @@ impl Nla for NeighbourAttribute {
             | Self::Controller(value)
             | Self::Vni(value)
             | Self::IfIndex(value)
-            | Self::SourceVni(value) => NativeEndian::write_u32(buffer, *value),
+            | Self::SourceVni(value)
+            | Self::NhId(value) => NativeEndian::write_u32(buffer, *value),
             Self::Protocol(v) => v.emit(buffer),
+            Self::FdbExtAttrs(v) => v.as_slice().emit(buffer),
             Self::Other(attr) => attr.emit_value(buffer),
         }
     }
@@ -97,6 +111,8 @@ impl Nla for NeighbourAttribute {
             Self::LinkNetNsId(_) => NDA_LINK_NETNSID,
             Self::SourceVni(_) => NDA_SRC_VNI,
             Self::Protocol(_) => NDA_PROTOCOL,
+            Self::NhId(_) => NDA_NH_ID,
+            Self::FdbExtAttrs(_) => NDA_FDB_EXT_ATTRS | NLA_F_NESTED,
             Self::Other(nla) => nla.kind(),
         }
     }
@@ -147,12 +163,30 @@ impl<'a, T: AsRef<[u8]> + ?Sized>
             NDA_LINK_NETNSID => Self::LinkNetNsId(parse_u32(payload).context(
                 format!("invalid NDA_LINK_NETNSID value {payload:?}"),
             )?),
-            NDA_SRC_VNI => Self::SourceVni(parse_u32(payload)?),
+            NDA_SRC_VNI => {
+                Self::SourceVni(parse_u32(payload).context(format!(
+                    "invalid NDA_SRC_VNI value {payload:?}"
+                ))?)
+            }
             NDA_PROTOCOL => {
                 Self::Protocol(RouteProtocol::parse(payload).context(
                     format!("invalid NDA_PROTOCOL value {:?}", payload),
                 )?)
             }
+            NDA_NH_ID => Self::NhId(
+                parse_u32(payload)
+                    .context(format!("invalid NDA_NH_ID value {payload:?}"))?,
+            ),
+            NDA_FDB_EXT_ATTRS => {
+                let mut attrs = vec![];
+                for nla in NlasIterator::new(payload) {
+                    let nla = nla.context(format!(
+                        "invalid NDA_FDB_EXT_ATTRS value {payload:?}"
+                    ))?;
+                    attrs.push(NeighbourFdbExtAttribute::parse(&nla)?);
+                }
+                Self::FdbExtAttrs(attrs)
+            }
             _ => Self::Other(
                 DefaultNla::parse(buf)
                     .context("invalid link NLA value (unknown type)")?,
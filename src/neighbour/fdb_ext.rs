@@ -0,0 +1,91 @@
+// SPDX-License-Identifier: MIT
+
+use anyhow::Context;
+use netlink_packet_utils::{
+    nla::{DefaultNla, Nla, NlaBuffer},
+    parsers::parse_u8,
+    DecodeError, Parseable,
+};
+
+const NFEA_ACTIVITY_NOTIFY: u16 = 1;
+const NFEA_DONT_REFRESH: u16 = 2;
+
+const FDB_NOTIFY_BIT: u8 = 1 << 0;
+const FDB_NOTIFY_INACTIVE_BIT: u8 = 1 << 1;
+
+/// Extended attributes of bridge FDB entry stored in
+/// [crate::neighbour::NeighbourAttribute::FdbExtAttrs].
+#[derive(Debug, PartialEq, Eq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[non_exhaustive]
+pub enum NeighbourFdbExtAttribute {
+    /// Activity notification state, see `bridge fdb add ... activity_notify`
+    ActivityNotify(NeighbourFdbNotifyFlags),
+    /// Do not refresh the entry on traffic, only valid in request, see
+    /// `bridge fdb replace ... norefresh`
+    DontRefresh,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_nla"))]
+    Other(DefaultNla),
+}
+
+impl Nla for NeighbourFdbExtAttribute {
+    fn value_len(&self) -> usize {
+        match self {
+            Self::ActivityNotify(_) => 1,
+            Self::DontRefresh => 0,
+            Self::Other(attr) => attr.value_len(),
+        }
+    }
+
+    fn emit_value(&self, buffer: &mut [u8]) {
+        match self {
+            Self::ActivityNotify(v) => buffer[0] = v.bits(),
+            Self::DontRefresh => (),
+            Self::Other(attr) => attr.emit_value(buffer),
+        }
+    }
+
+    fn kind(&self) -> u16 {
+        match self {
+            Self::ActivityNotify(_) => NFEA_ACTIVITY_NOTIFY,
+            Self::DontRefresh => NFEA_DONT_REFRESH,
+            Self::Other(attr) => attr.kind(),
+        }
+    }
+}
+
+impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>>
+    for NeighbourFdbExtAttribute
+{
+    fn parse(buf: &NlaBuffer<&'a T>) -> Result<Self, DecodeError> {
+        let payload = buf.value();
+        Ok(match buf.kind() {
+            NFEA_ACTIVITY_NOTIFY => {
+                Self::ActivityNotify(NeighbourFdbNotifyFlags::from_bits_retain(
+                    parse_u8(payload).context(format!(
+                        "invalid NFEA_ACTIVITY_NOTIFY value {payload:?}"
+                    ))?,
+                ))
+            }
+            NFEA_DONT_REFRESH => Self::DontRefresh,
+            kind => Self::Other(
+                DefaultNla::parse(buf)
+                    .context(format!("unknown NLA type {kind}"))?,
+            ),
+        })
+    }
+}
+
+bitflags! {
+    /// Activity notification state of bridge FDB entry.
+    #[derive(Clone, Eq, PartialEq, Debug, Copy, Default)]
+    #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+    #[non_exhaustive]
+    pub struct NeighbourFdbNotifyFlags: u8 {
+        /// Notify on activity changes of the entry.
+        const Notify = FDB_NOTIFY_BIT;
+        /// The entry is inactive.
+        const Inactive = FDB_NOTIFY_INACTIVE_BIT;
+        const _ = !0;
+    }
+}
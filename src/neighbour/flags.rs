@@ -12,6 +12,7 @@ const NTF_ROUTER: u8 = 1 << 7;
 
 bitflags! {
     #[derive(Clone, Eq, PartialEq, Debug, Copy, Default)]
+    #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
     #[non_exhaustive]
     pub struct NeighbourFlags: u8 {
         const Use = NTF_USE;
@@ -20,6 +20,8 @@ buffer!(NeighbourMessageBuffer(NEIGHBOUR_HEADER_LEN) {
     payload:(slice, NEIGHBOUR_HEADER_LEN..),
 });
 
+impl_try_from_slice!(NeighbourMessageBuffer);
+
 impl<'a, T: AsRef<[u8]> + ?Sized> NeighbourMessageBuffer<&'a T> {
     pub fn attributes(
         &self,
@@ -44,6 +46,7 @@ impl<'a, T: AsRef<[u8]> + ?Sized> NeighbourMessageBuffer<&'a T> {
 /// `NeighbourHeader` exposes all these fields.
 // Linux kernel struct `struct ndmsg`
 #[derive(Debug, PartialEq, Eq, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct NeighbourHeader {
     pub family: AddressFamily,
     pub ifindex: u32,
@@ -13,6 +13,7 @@ const NUD_NONE: u16 = 0x00;
 /// Types that can be set in a `RTM_GETROUTE`
 /// ([`NeightbourNetlinkMessage::GetNeightbour`]) message.
 #[derive(Clone, Eq, PartialEq, Debug, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[non_exhaustive]
 pub enum NeightbourType {
     Incomplete,
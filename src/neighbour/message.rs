@@ -1,5 +1,7 @@
 // SPDX-License-Identifier: MIT
 
+use std::net::IpAddr;
+
 use anyhow::Context;
 use netlink_packet_utils::{
     traits::{Emitable, Parseable, ParseableParametrized},
@@ -7,17 +9,111 @@ use netlink_packet_utils::{
 };
 
 use super::{
-    super::AddressFamily, NeighbourAttribute, NeighbourHeader,
-    NeighbourMessageBuffer,
+    super::AddressFamily, NeighbourAttribute, NeighbourFlags, NeighbourHeader,
+    NeighbourMessageBuffer, NeighbourState,
 };
 
 #[derive(Debug, PartialEq, Eq, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[non_exhaustive]
 pub struct NeighbourMessage {
     pub header: NeighbourHeader,
     pub attributes: Vec<NeighbourAttribute>,
 }
 
+impl NeighbourMessage {
+    /// Whether header state is [NeighbourState::Permanent].
+    pub fn is_permanent(&self) -> bool {
+        self.header.state == NeighbourState::Permanent
+    }
+
+    /// Whether header state is [NeighbourState::Reachable].
+    pub fn is_reachable(&self) -> bool {
+        self.header.state == NeighbourState::Reachable
+    }
+}
+
+/// Builder of [NeighbourMessage] for the `ip neighbour add` and
+/// `ip neighbour del` requests.
+///
+/// ```
+/// use std::net::{IpAddr, Ipv4Addr};
+///
+/// use netlink_packet_route::neighbour::{
+///     NeighbourMessageBuilder, NeighbourState,
+/// };
+///
+/// // ip neighbour add 192.0.2.2 lladdr 00:11:22:33:44:55 dev eth1 \
+/// //      nud permanent
+/// let message = NeighbourMessageBuilder::new()
+///     .index(3)
+///     .destination(IpAddr::V4(Ipv4Addr::new(192, 0, 2, 2)))
+///     .link_local(vec![0x00, 0x11, 0x22, 0x33, 0x44, 0x55])
+///     .state(NeighbourState::Permanent)
+///     .build();
+/// ```
+#[derive(Debug, PartialEq, Eq, Clone, Default)]
+pub struct NeighbourMessageBuilder {
+    header: NeighbourHeader,
+    attributes: Vec<NeighbourAttribute>,
+}
+
+impl NeighbourMessageBuilder {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set the index of the interface the entry belongs to (`ndm_ifindex`).
+    pub fn index(mut self, index: u32) -> Self {
+        self.header.ifindex = index;
+        self
+    }
+
+    /// Set the header family from the address, and store it as `NDA_DST`
+    /// replacing the previous one.
+    pub fn destination(mut self, destination: IpAddr) -> Self {
+        self.header.family = match destination {
+            IpAddr::V4(_) => AddressFamily::Inet,
+            IpAddr::V6(_) => AddressFamily::Inet6,
+        };
+        self.attributes
+            .retain(|attr| !matches!(attr, NeighbourAttribute::Destination(_)));
+        self.attributes
+            .push(NeighbourAttribute::Destination(destination.into()));
+        self
+    }
+
+    /// Set the link layer address (`NDA_LLADDR`), e.g. the MAC address,
+    /// replacing the previous one.
+    pub fn link_local(mut self, address: Vec<u8>) -> Self {
+        self.attributes.retain(|attr| {
+            !matches!(attr, NeighbourAttribute::LinkLocalAddress(_))
+        });
+        self.attributes
+            .push(NeighbourAttribute::LinkLocalAddress(address));
+        self
+    }
+
+    /// Set the neighbour state in the header.
+    pub fn state(mut self, state: NeighbourState) -> Self {
+        self.header.state = state;
+        self
+    }
+
+    /// Set the neighbour flags in the header.
+    pub fn flags(mut self, flags: NeighbourFlags) -> Self {
+        self.header.flags = flags;
+        self
+    }
+
+    pub fn build(self) -> NeighbourMessage {
+        NeighbourMessage {
+            header: self.header,
+            attributes: self.attributes,
+        }
+    }
+}
+
 impl Emitable for NeighbourMessage {
     fn buffer_len(&self) -> usize {
         self.header.buffer_len() + self.attributes.as_slice().buffer_len()
@@ -3,6 +3,7 @@
 mod address;
 mod attribute;
 mod cache_info;
+mod fdb_ext;
 mod flags;
 mod header;
 mod message;
@@ -14,7 +15,8 @@ mod tests;
 pub use self::address::NeighbourAddress;
 pub use self::attribute::NeighbourAttribute;
 pub use self::cache_info::{NeighbourCacheInfo, NeighbourCacheInfoBuffer};
+pub use self::fdb_ext::{NeighbourFdbExtAttribute, NeighbourFdbNotifyFlags};
 pub use self::flags::NeighbourFlags;
 pub use self::header::{NeighbourHeader, NeighbourMessageBuffer};
-pub use self::message::NeighbourMessage;
+pub use self::message::{NeighbourMessage, NeighbourMessageBuilder};
 pub use self::state::NeighbourState;
@@ -11,6 +11,7 @@ const NUD_PERMANENT: u16 = 0x80;
 const NUD_NONE: u16 = 0x00;
 
 #[derive(Clone, Eq, PartialEq, Debug, Copy, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[non_exhaustive]
 pub enum NeighbourState {
     Incomplete,
@@ -1,10 +1,13 @@
 // SPDX-License-Identifier: MIT
 
+use std::net::Ipv4Addr;
+
 use netlink_packet_utils::{Emitable, Parseable};
 
 use crate::{
     neighbour::{
-        flags::NeighbourFlags, NeighbourAttribute, NeighbourHeader,
+        flags::NeighbourFlags, NeighbourAddress, NeighbourAttribute,
+        NeighbourFdbExtAttribute, NeighbourFdbNotifyFlags, NeighbourHeader,
         NeighbourMessage, NeighbourMessageBuffer, NeighbourState,
     },
     route::RouteType,
@@ -44,3 +47,158 @@ fn test_bridge_neighbour_show() {
 
     assert_eq!(buf, raw);
 }
+
+// Hand-crafted RTM_NEWNEIGH payload equivalent to command:
+//   bridge fdb add 00:11:22:33:44:55 dev vxlan0 nhid 10 src_vni 100 self
+// with vxlan0 holding interface index 5.
+#[test]
+fn test_bridge_fdb_nexthop_id() {
+    let raw = vec![
+        0x07, 0x00, 0x00, 0x00, // AF_BRIDGE and padding
+        0x05, 0x00, 0x00, 0x00, // ifindex 5
+        0x40, 0x00, // NUD_NOARP
+        0x02, // NTF_SELF
+        0x00, // RTN_UNSPEC
+        0x0a, 0x00, 0x02, 0x00, // length 10, NDA_LLADDR
+        0x00, 0x11, 0x22, 0x33, 0x44, 0x55, 0x00, 0x00, // padding
+        0x08, 0x00, 0x0d, 0x00, // length 8, NDA_NH_ID
+        0x0a, 0x00, 0x00, 0x00, // 10
+        0x08, 0x00, 0x0b, 0x00, // length 8, NDA_SRC_VNI
+        0x64, 0x00, 0x00, 0x00, // 100
+    ];
+
+    let expected = NeighbourMessage {
+        header: NeighbourHeader {
+            family: AddressFamily::Bridge,
+            ifindex: 5,
+            state: NeighbourState::Noarp,
+            flags: NeighbourFlags::Own,
+            kind: RouteType::Unspec,
+        },
+        attributes: vec![
+            NeighbourAttribute::LinkLocalAddress(vec![
+                0x00, 0x11, 0x22, 0x33, 0x44, 0x55,
+            ]),
+            NeighbourAttribute::NhId(10),
+            NeighbourAttribute::SourceVni(100),
+        ],
+    };
+
+    assert_eq!(
+        expected,
+        NeighbourMessage::parse(&NeighbourMessageBuffer::new(&raw)).unwrap()
+    );
+
+    let mut buf = vec![0; expected.buffer_len()];
+
+    expected.emit(&mut buf);
+
+    assert_eq!(buf, raw);
+}
+
+// Hand-crafted RTM_NEWNEIGH payload equivalent to the `bridge fdb show` reply
+// of VxLAN FDB entry created by command:
+//   bridge fdb append 00:00:00:00:00:00 dev vxlan0 dst 192.0.2.1 vni 100 \
+//      port 4790 via eth0 self permanent
+// with vxlan0 holding interface index 5 and eth0 holding index 2.
+#[test]
+fn test_bridge_vxlan_fdb_vni_port() {
+    let raw = vec![
+        0x07, 0x00, 0x00, 0x00, // AF_BRIDGE and padding
+        0x05, 0x00, 0x00, 0x00, // ifindex 5
+        0x80, 0x00, // NUD_PERMANENT
+        0x02, // NTF_SELF
+        0x01, // RTN_UNICAST
+        0x0a, 0x00, 0x02, 0x00, // length 10, NDA_LLADDR
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, // padding
+        0x08, 0x00, 0x01, 0x00, // length 8, NDA_DST
+        0xc0, 0x00, 0x02, 0x01, // 192.0.2.1
+        0x06, 0x00, 0x06, 0x00, // length 6, NDA_PORT
+        0x12, 0xb6, 0x00, 0x00, // 4790 in big endian
+        0x08, 0x00, 0x07, 0x00, // length 8, NDA_VNI
+        0x64, 0x00, 0x00, 0x00, // 100
+        0x08, 0x00, 0x08, 0x00, // length 8, NDA_IFINDEX
+        0x02, 0x00, 0x00, 0x00, // 2
+    ];
+
+    let expected = NeighbourMessage {
+        header: NeighbourHeader {
+            family: AddressFamily::Bridge,
+            ifindex: 5,
+            state: NeighbourState::Permanent,
+            flags: NeighbourFlags::Own,
+            kind: RouteType::Unicast,
+        },
+        attributes: vec![
+            NeighbourAttribute::LinkLocalAddress(vec![0, 0, 0, 0, 0, 0]),
+            NeighbourAttribute::Destination(NeighbourAddress::Inet(
+                Ipv4Addr::new(192, 0, 2, 1),
+            )),
+            NeighbourAttribute::Port(4790),
+            NeighbourAttribute::Vni(100),
+            NeighbourAttribute::IfIndex(2),
+        ],
+    };
+
+    assert_eq!(
+        expected,
+        NeighbourMessage::parse(&NeighbourMessageBuffer::new(&raw)).unwrap()
+    );
+
+    let mut buf = vec![0; expected.buffer_len()];
+
+    expected.emit(&mut buf);
+
+    assert_eq!(buf, raw);
+}
+
+// Hand-crafted RTM_NEWNEIGH payload equivalent to command:
+//   bridge fdb add 00:11:22:33:44:55 dev eth1 master dynamic activity_notify
+// with eth1 holding interface index 3.
+#[test]
+fn test_bridge_fdb_activity_notify() {
+    let raw = vec![
+        0x07, 0x00, 0x00, 0x00, // AF_BRIDGE and padding
+        0x03, 0x00, 0x00, 0x00, // ifindex 3
+        0x02, 0x00, // NUD_REACHABLE
+        0x04, // NTF_MASTER
+        0x00, // RTN_UNSPEC
+        0x0a, 0x00, 0x02, 0x00, // length 10, NDA_LLADDR
+        0x00, 0x11, 0x22, 0x33, 0x44, 0x55, 0x00, 0x00, // padding
+        0x0c, 0x00, 0x0e,
+        0x80, // length 12, NDA_FDB_EXT_ATTRS | NLA_F_NESTED
+        0x05, 0x00, 0x01, 0x00, // length 5, NFEA_ACTIVITY_NOTIFY
+        0x01, 0x00, 0x00, 0x00, // FDB_NOTIFY_BIT and padding
+    ];
+
+    let expected = NeighbourMessage {
+        header: NeighbourHeader {
+            family: AddressFamily::Bridge,
+            ifindex: 3,
+            state: NeighbourState::Reachable,
+            flags: NeighbourFlags::Controller,
+            kind: RouteType::Unspec,
+        },
+        attributes: vec![
+            NeighbourAttribute::LinkLocalAddress(vec![
+                0x00, 0x11, 0x22, 0x33, 0x44, 0x55,
+            ]),
+            NeighbourAttribute::FdbExtAttrs(vec![
+                NeighbourFdbExtAttribute::ActivityNotify(
+                    NeighbourFdbNotifyFlags::Notify,
+                ),
+            ]),
+        ],
+    };
+
+    assert_eq!(
+        expected,
+        NeighbourMessage::parse(&NeighbourMessageBuffer::new(&raw)).unwrap()
+    );
+
+    let mut buf = vec![0; expected.buffer_len()];
+
+    expected.emit(&mut buf);
+
+    assert_eq!(buf, raw);
+}
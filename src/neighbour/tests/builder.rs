@@ -0,0 +1,111 @@
+// SPDX-License-Identifier: MIT
+
+use std::net::{IpAddr, Ipv4Addr, Ipv6Addr};
+use std::str::FromStr;
+
+use netlink_packet_utils::{Emitable, Parseable};
+
+use crate::{
+    neighbour::{
+        NeighbourAttribute, NeighbourFlags, NeighbourHeader, NeighbourMessage,
+        NeighbourMessageBuffer, NeighbourMessageBuilder, NeighbourState,
+    },
+    route::RouteType,
+    AddressFamily,
+};
+
+// Captured RTM_NEWNEIGH request sent by iproute2 6.1 for
+//   ip neighbour add 192.0.2.2 lladdr 00:11:22:33:44:55 dev veth9a \
+//      nud permanent
+// with veth9a holding interface index 11, netlink header removed.
+#[test]
+fn test_neighbour_message_builder_permanent() {
+    let raw = vec![
+        0x02, // AF_INET
+        0x00, 0x00, 0x00, // padding
+        0x0b, 0x00, 0x00, 0x00, // interface index 11
+        0x80, 0x00, // NUD_PERMANENT
+        0x00, // flags
+        0x00, // RTN_UNSPEC
+        0x08, 0x00, // length 8
+        0x01, 0x00, // NDA_DST
+        0xc0, 0x00, 0x02, 0x02, // 192.0.2.2
+        0x0a, 0x00, // length 10
+        0x02, 0x00, // NDA_LLADDR
+        0x00, 0x11, 0x22, 0x33, 0x44, 0x55, // 00:11:22:33:44:55
+        0x00, 0x00, // padding
+    ];
+
+    let message = NeighbourMessageBuilder::new()
+        .index(11)
+        .destination(IpAddr::V4(Ipv4Addr::new(192, 0, 2, 2)))
+        .link_local(vec![0x00, 0x11, 0x22, 0x33, 0x44, 0x55])
+        .state(NeighbourState::Permanent)
+        .build();
+
+    let expected = NeighbourMessage {
+        header: NeighbourHeader {
+            family: AddressFamily::Inet,
+            ifindex: 11,
+            state: NeighbourState::Permanent,
+            flags: NeighbourFlags::empty(),
+            kind: RouteType::Unspec,
+        },
+        attributes: vec![
+            NeighbourAttribute::Destination(Ipv4Addr::new(192, 0, 2, 2).into()),
+            NeighbourAttribute::LinkLocalAddress(vec![
+                0x00, 0x11, 0x22, 0x33, 0x44, 0x55,
+            ]),
+        ],
+    };
+    assert_eq!(message, expected);
+    assert!(message.is_permanent());
+    assert!(!message.is_reachable());
+
+    let mut buf = vec![0; message.buffer_len()];
+    message.emit(&mut buf);
+    assert_eq!(buf, raw);
+
+    assert_eq!(
+        expected,
+        NeighbourMessage::parse(&NeighbourMessageBuffer::new(&raw)).unwrap()
+    );
+}
+
+#[test]
+fn test_neighbour_message_builder_ipv6_proxy() {
+    let message = NeighbourMessageBuilder::new()
+        .index(3)
+        .destination(Ipv6Addr::from_str("2001:db8::2").unwrap().into())
+        .state(NeighbourState::Reachable)
+        .flags(NeighbourFlags::Proxy)
+        .build();
+
+    assert_eq!(message.header.family, AddressFamily::Inet6);
+    assert_eq!(message.header.flags, NeighbourFlags::Proxy);
+    assert!(message.is_reachable());
+    assert!(!message.is_permanent());
+}
+
+#[test]
+fn test_neighbour_message_builder_replace() {
+    let message = NeighbourMessageBuilder::new()
+        .destination(IpAddr::V4(Ipv4Addr::new(192, 0, 2, 2)))
+        .link_local(vec![0x00, 0x11, 0x22, 0x33, 0x44, 0x55])
+        .destination(Ipv6Addr::from_str("2001:db8::2").unwrap().into())
+        .link_local(vec![0x00, 0x11, 0x22, 0x33, 0x44, 0x66])
+        .build();
+
+    assert_eq!(message.header.family, AddressFamily::Inet6);
+    assert_eq!(
+        message.attributes,
+        vec![
+            NeighbourAttribute::Destination(
+                Ipv6Addr::from_str("2001:db8::2").unwrap().into()
+            ),
+            NeighbourAttribute::LinkLocalAddress(vec![
+                0x00, 0x11, 0x22, 0x33, 0x44, 0x66,
+            ]),
+        ]
+    );
+}
@@ -3,4 +3,6 @@
 #[cfg(test)]
 mod bridge;
 #[cfg(test)]
+mod builder;
+#[cfg(test)]
 mod ip;
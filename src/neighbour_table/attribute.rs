@@ -24,6 +24,7 @@ const NDTA_STATS: u16 = 7;
 const NDTA_GC_INTERVAL: u16 = 8;
 
 #[derive(Debug, PartialEq, Eq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[non_exhaustive]
 pub enum NeighbourTableAttribute {
     Parms(Vec<NeighbourTableParameter>),
@@ -34,6 +35,7 @@ pub enum NeighbourTableAttribute {
     Config(NeighbourTableConfig),
     Stats(NeighbourTableStats),
     GcInterval(u64),
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_nla"))]
     Other(DefaultNla),
 }
 
@@ -25,6 +25,7 @@ impl<'a, T: AsRef<[u8]> + ?Sized> NeighbourTableMessageBuffer<&'a T> {
 
 // kernel code is `struct rtgenmsg`
 #[derive(Debug, PartialEq, Eq, Clone, Copy, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct NeighbourTableHeader {
     pub family: AddressFamily,
 }
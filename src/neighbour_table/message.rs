@@ -11,6 +11,7 @@ use super::{
 };
 
 #[derive(Debug, PartialEq, Eq, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[non_exhaustive]
 pub struct NeighbourTableMessage {
     pub header: NeighbourTableHeader,
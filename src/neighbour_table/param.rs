@@ -29,6 +29,7 @@ const NDTPA_MCAST_REPROBES: u16 = 17;
 const NDTPA_INTERVAL_PROBE_TIME_MS: u16 = 19;
 
 #[derive(Debug, PartialEq, Eq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[non_exhaustive]
 pub enum NeighbourTableParameter {
     Ifindex(u32),
@@ -49,6 +50,7 @@ pub enum NeighbourTableParameter {
     QueueLenbytes(u32),
     McastReprobes(u32),
     IntervalProbeTimeMs(u64),
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_nla"))]
     Other(DefaultNla),
 }
 
@@ -226,6 +228,7 @@ impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>>
 }
 
 #[derive(Debug, PartialEq, Eq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub(crate) struct VecNeighbourTableParameter(
     pub(crate) Vec<NeighbourTableParameter>,
 );
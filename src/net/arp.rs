@@ -0,0 +1,95 @@
+// SPDX-License-Identifier: MIT
+
+use netlink_packet_utils::DecodeError;
+
+const ARPOP_REQUEST: u16 = 1;
+const ARPOP_REPLY: u16 = 2;
+const ARPOP_RREQUEST: u16 = 3;
+const ARPOP_RREPLY: u16 = 4;
+
+/// ARP operation code, displayed and parsed using the names of the IANA
+/// "Operation Codes" registry, e.g. `REQUEST` or `reply Reverse`.
+/// Unknown codes are displayed as the decimal number.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[non_exhaustive]
+pub enum Operation {
+    Request,
+    Reply,
+    /// Reverse ARP (RARP) request
+    RarpRequest,
+    /// Reverse ARP (RARP) reply
+    RarpReply,
+    Other(u16),
+}
+
+impl From<u16> for Operation {
+    fn from(d: u16) -> Self {
+        match d {
+            ARPOP_REQUEST => Self::Request,
+            ARPOP_REPLY => Self::Reply,
+            ARPOP_RREQUEST => Self::RarpRequest,
+            ARPOP_RREPLY => Self::RarpReply,
+            _ => Self::Other(d),
+        }
+    }
+}
+
+impl From<u8> for Operation {
+    fn from(d: u8) -> Self {
+        u16::from(d).into()
+    }
+}
+
+impl From<Operation> for u16 {
+    fn from(v: Operation) -> u16 {
+        match v {
+            Operation::Request => ARPOP_REQUEST,
+            Operation::Reply => ARPOP_REPLY,
+            Operation::RarpRequest => ARPOP_RREQUEST,
+            Operation::RarpReply => ARPOP_RREPLY,
+            Operation::Other(d) => d,
+        }
+    }
+}
+
+impl TryFrom<Operation> for u8 {
+    type Error = DecodeError;
+
+    fn try_from(v: Operation) -> Result<Self, Self::Error> {
+        let d = u16::from(v);
+        u8::try_from(d).map_err(|_| {
+            format!("ARP operation {d} does not fit in 8 bits").into()
+        })
+    }
+}
+
+impl std::fmt::Display for Operation {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Request => write!(f, "REQUEST"),
+            Self::Reply => write!(f, "REPLY"),
+            Self::RarpRequest => write!(f, "request Reverse"),
+            Self::RarpReply => write!(f, "reply Reverse"),
+            Self::Other(d) => write!(f, "{d}"),
+        }
+    }
+}
+
+/// Accepts the IANA names in any case, and decimal operation codes.
+impl std::str::FromStr for Operation {
+    type Err = DecodeError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_ascii_lowercase().as_str() {
+            "request" => Ok(Self::Request),
+            "reply" => Ok(Self::Reply),
+            "request reverse" => Ok(Self::RarpRequest),
+            "reply reverse" => Ok(Self::RarpReply),
+            _ => s
+                .parse::<u16>()
+                .map(Self::from)
+                .map_err(|e| format!("invalid ARP operation {s}: {e}").into()),
+        }
+    }
+}
@@ -0,0 +1,59 @@
+// SPDX-License-Identifier: MIT
+
+/// Ethernet protocol number (`ETH_P_*`) of the frame payload.
+///
+/// Any 16 bits value is accepted, the associated constants only name the
+/// well-known ones. [Ethertype] is displayed in hex followed by the name
+/// when known, e.g. `0x0800 (IPv4)`.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Ethertype(pub u16);
+
+impl Ethertype {
+    pub const IPV4: Self = Self(0x0800);
+    pub const ARP: Self = Self(0x0806);
+    /// IEEE 802.1Q VLAN tag
+    pub const VLAN: Self = Self(0x8100);
+    pub const IPV6: Self = Self(0x86DD);
+    pub const MPLS_UC: Self = Self(0x8847);
+    pub const MPLS_MC: Self = Self(0x8848);
+    pub const PPPOE_DISC: Self = Self(0x8863);
+    pub const PPPOE_SESS: Self = Self(0x8864);
+
+    /// Name of well-known protocol, `None` for others.
+    pub fn name(&self) -> Option<&'static str> {
+        Some(match *self {
+            Self::IPV4 => "IPv4",
+            Self::ARP => "ARP",
+            Self::VLAN => "802.1Q",
+            Self::IPV6 => "IPv6",
+            Self::MPLS_UC => "MPLS unicast",
+            Self::MPLS_MC => "MPLS multicast",
+            Self::PPPOE_DISC => "PPPoE discovery",
+            Self::PPPOE_SESS => "PPPoE session",
+            _ => return None,
+        })
+    }
+}
+
+impl From<u16> for Ethertype {
+    fn from(d: u16) -> Self {
+        Self(d)
+    }
+}
+
+impl From<Ethertype> for u16 {
+    fn from(v: Ethertype) -> u16 {
+        v.0
+    }
+}
+
+impl std::fmt::Display for Ethertype {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{:#06x}", self.0)?;
+        if let Some(name) = self.name() {
+            write!(f, " ({name})")?;
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,10 @@
+// SPDX-License-Identifier: MIT
+
+//! Protocol numbers of link and network layer headers shared by several
+//! rtnetlink families.
+
+pub mod arp;
+pub mod ethernet;
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,37 @@
+// SPDX-License-Identifier: MIT
+
+use std::str::FromStr;
+
+use crate::net::arp::Operation;
+
+#[test]
+fn test_arp_operation_value() {
+    for (op, value, name) in [
+        (Operation::Request, 1u16, "REQUEST"),
+        (Operation::Reply, 2, "REPLY"),
+        (Operation::RarpRequest, 3, "request Reverse"),
+        (Operation::RarpReply, 4, "reply Reverse"),
+        (Operation::Other(8), 8, "8"),
+    ] {
+        assert_eq!(Operation::from(value), op);
+        assert_eq!(u16::from(op), value);
+        assert_eq!(op.to_string(), name);
+        assert_eq!(Operation::from_str(name).unwrap(), op);
+    }
+    assert_eq!(Operation::from(2u8), Operation::Reply);
+    assert_eq!(u8::try_from(Operation::Reply).unwrap(), 2);
+    assert_eq!(u8::try_from(Operation::Other(255)).unwrap(), 255);
+    assert!(u8::try_from(Operation::Other(256)).is_err());
+}
+
+#[test]
+fn test_arp_operation_from_str() {
+    assert_eq!(Operation::from_str("request").unwrap(), Operation::Request);
+    assert_eq!(
+        Operation::from_str("REPLY REVERSE").unwrap(),
+        Operation::RarpReply
+    );
+    assert_eq!(Operation::from_str("1").unwrap(), Operation::Request);
+    assert!(Operation::from_str("announce").is_err());
+    assert!(Operation::from_str("65536").is_err());
+}
@@ -0,0 +1,29 @@
+// SPDX-License-Identifier: MIT
+
+use crate::net::ethernet::Ethertype;
+
+#[test]
+fn test_ethertype_constants() {
+    for (ethertype, value, display) in [
+        (Ethertype::IPV4, 0x0800, "0x0800 (IPv4)"),
+        (Ethertype::IPV6, 0x86dd, "0x86dd (IPv6)"),
+        (Ethertype::ARP, 0x0806, "0x0806 (ARP)"),
+        (Ethertype::VLAN, 0x8100, "0x8100 (802.1Q)"),
+        (Ethertype::MPLS_UC, 0x8847, "0x8847 (MPLS unicast)"),
+        (Ethertype::MPLS_MC, 0x8848, "0x8848 (MPLS multicast)"),
+        (Ethertype::PPPOE_DISC, 0x8863, "0x8863 (PPPoE discovery)"),
+        (Ethertype::PPPOE_SESS, 0x8864, "0x8864 (PPPoE session)"),
+    ] {
+        assert_eq!(u16::from(ethertype), value);
+        assert_eq!(Ethertype::from(value), ethertype);
+        assert_eq!(ethertype.to_string(), display);
+    }
+}
+
+#[test]
+fn test_ethertype_display_unknown() {
+    let lldp = Ethertype(0x88cc);
+    assert_eq!(lldp.name(), None);
+    assert_eq!(lldp.to_string(), "0x88cc");
+    assert_eq!(Ethertype(0x4).to_string(), "0x0004");
+}
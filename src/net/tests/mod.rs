@@ -0,0 +1,6 @@
+// SPDX-License-Identifier: MIT
+
+#[cfg(test)]
+mod arp;
+#[cfg(test)]
+mod ethernet;
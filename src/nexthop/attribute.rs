@@ -0,0 +1,210 @@
+// SPDX-License-Identifier: MIT
+
+use std::net::IpAddr;
+
+use anyhow::Context;
+use byteorder::{ByteOrder, NativeEndian};
+use netlink_packet_utils::{
+    nla::{DefaultNla, Nla, NlaBuffer, NlasIterator, NLA_F_NESTED},
+    parsers::{parse_u16, parse_u32},
+    traits::{Emitable, Parseable, ParseableParametrized},
+    DecodeError,
+};
+
+use super::{
+    group::{VecNexthopGroup, NEXTHOP_GROUP_LEN},
+    NexthopGroup, NexthopGroupType, NexthopResBucket, NexthopResGroup,
+};
+use crate::{
+    ip::{
+        emit_ip_addr, ip_addr_len, parse_ip_addr, parse_ipv4_addr,
+        parse_ipv6_addr,
+    },
+    route::{
+        lwtunnel::VecRouteLwTunnelEncap, RouteLwEnCapType, RouteLwTunnelEncap,
+    },
+    AddressFamily,
+};
+
+const NHA_ID: u16 = 1;
+const NHA_GROUP: u16 = 2;
+const NHA_GROUP_TYPE: u16 = 3;
+const NHA_BLACKHOLE: u16 = 4;
+const NHA_OIF: u16 = 5;
+const NHA_GATEWAY: u16 = 6;
+pub(crate) const NHA_ENCAP_TYPE: u16 = 7;
+const NHA_ENCAP: u16 = 8;
+const NHA_GROUPS: u16 = 9;
+// Kernel constant name is NHA_MASTER
+const NHA_CONTROLLER: u16 = 10;
+const NHA_FDB: u16 = 11;
+const NHA_RES_GROUP: u16 = 12;
+const NHA_RES_BUCKET: u16 = 13;
+
+/// Netlink attributes for `RTM_NEWNEXTHOP`, `RTM_DELNEXTHOP` and
+/// `RTM_GETNEXTHOP` netlink messages.
+#[derive(Debug, PartialEq, Eq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[non_exhaustive]
+pub enum NexthopAttribute {
+    Id(u32),
+    Group(Vec<NexthopGroup>),
+    GroupType(NexthopGroupType),
+    /// Nexthop dropping all packets, cannot be combined with
+    /// [NexthopAttribute::Oif] or [NexthopAttribute::Gateway]
+    Blackhole,
+    Oif(u32),
+    Gateway(IpAddr),
+    EncapType(RouteLwEnCapType),
+    Encap(Vec<RouteLwTunnelEncap>),
+    /// Only dump nexthop groups, used in `RTM_GETNEXTHOP` dump request
+    Groups,
+    /// Only dump nexthops using this controller(master) interface, used in
+    /// `RTM_GETNEXTHOP` dump request
+    Controller(u32),
+    /// Nexthop used by FDB entries of VxLAN, see
+    /// [crate::neighbour::NeighbourAttribute::NhId]
+    Fdb,
+    ResGroup(Vec<NexthopResGroup>),
+    ResBucket(Vec<NexthopResBucket>),
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_nla"))]
+    Other(DefaultNla),
+}
+
+impl Nla for NexthopAttribute {
+    fn value_len(&self) -> usize {
+        match self {
+            Self::Id(_) | Self::Oif(_) | Self::Controller(_) => 4,
+            Self::Group(v) => v.len() * NEXTHOP_GROUP_LEN,
+            Self::GroupType(_) => 2,
+            Self::Blackhole | Self::Groups | Self::Fdb => 0,
+            Self::Gateway(v) => ip_addr_len(v),
+            Self::EncapType(v) => v.buffer_len(),
+            Self::Encap(v) => v.as_slice().buffer_len(),
+            Self::ResGroup(v) => v.as_slice().buffer_len(),
+            Self::ResBucket(v) => v.as_slice().buffer_len(),
+            Self::Other(attr) => attr.value_len(),
+        }
+    }
+
+    fn emit_value(&self, buffer: &mut [u8]) {
+        match self {
+            Self::Id(v) | Self::Oif(v) | Self::Controller(v) => {
+                NativeEndian::write_u32(buffer, *v)
+            }
+            Self::Group(v) => {
+                for (grp, chunk) in
+                    v.iter().zip(buffer.chunks_exact_mut(NEXTHOP_GROUP_LEN))
+                {
+                    grp.emit(chunk);
+                }
+            }
+            Self::GroupType(v) => {
+                NativeEndian::write_u16(buffer, u16::from(*v))
+            }
+            Self::Blackhole | Self::Groups | Self::Fdb => (),
+            Self::Gateway(v) => emit_ip_addr(v, buffer),
+            Self::EncapType(v) => v.emit(buffer),
+            Self::Encap(v) => v.as_slice().emit(buffer),
+            Self::ResGroup(v) => v.as_slice().emit(buffer),
+            Self::ResBucket(v) => v.as_slice().emit(buffer),
+            Self::Other(attr) => attr.emit_value(buffer),
+        }
+    }
+
+    fn kind(&self) -> u16 {
+        match self {
+            Self::Id(_) => NHA_ID,
+            Self::Group(_) => NHA_GROUP,
+            Self::GroupType(_) => NHA_GROUP_TYPE,
+            Self::Blackhole => NHA_BLACKHOLE,
+            Self::Oif(_) => NHA_OIF,
+            Self::Gateway(_) => NHA_GATEWAY,
+            Self::EncapType(_) => NHA_ENCAP_TYPE,
+            Self::Encap(_) => NHA_ENCAP,
+            Self::Groups => NHA_GROUPS,
+            Self::Controller(_) => NHA_CONTROLLER,
+            Self::Fdb => NHA_FDB,
+            Self::ResGroup(_) => NHA_RES_GROUP | NLA_F_NESTED,
+            Self::ResBucket(_) => NHA_RES_BUCKET | NLA_F_NESTED,
+            Self::Other(attr) => attr.kind(),
+        }
+    }
+}
+
+impl<'a, T: AsRef<[u8]> + ?Sized>
+    ParseableParametrized<NlaBuffer<&'a T>, (AddressFamily, RouteLwEnCapType)>
+    for NexthopAttribute
+{
+    fn parse_with_param(
+        buf: &NlaBuffer<&'a T>,
+        (address_family, encap_type): (AddressFamily, RouteLwEnCapType),
+    ) -> Result<Self, DecodeError> {
+        let payload = buf.value();
+        Ok(match buf.kind() {
+            NHA_ID => {
+                Self::Id(parse_u32(payload).context("invalid NHA_ID value")?)
+            }
+            NHA_GROUP => Self::Group(
+                VecNexthopGroup::parse(payload)
+                    .context(format!("invalid NHA_GROUP value {payload:?}"))?
+                    .0,
+            ),
+            NHA_GROUP_TYPE => Self::GroupType(
+                parse_u16(payload)
+                    .context("invalid NHA_GROUP_TYPE value")?
+                    .into(),
+            ),
+            NHA_BLACKHOLE => Self::Blackhole,
+            NHA_OIF => {
+                Self::Oif(parse_u32(payload).context("invalid NHA_OIF value")?)
+            }
+            NHA_GATEWAY => Self::Gateway(
+                match address_family {
+                    AddressFamily::Inet => {
+                        parse_ipv4_addr(payload).map(IpAddr::from)
+                    }
+                    AddressFamily::Inet6 => {
+                        parse_ipv6_addr(payload).map(IpAddr::from)
+                    }
+                    _ => parse_ip_addr(payload),
+                }
+                .context(format!("invalid NHA_GATEWAY value {payload:?}"))?,
+            ),
+            NHA_ENCAP_TYPE => Self::EncapType(
+                parse_u16(payload)
+                    .context("invalid NHA_ENCAP_TYPE value")?
+                    .into(),
+            ),
+            NHA_ENCAP => Self::Encap(
+                VecRouteLwTunnelEncap::parse_with_param(buf, encap_type)
+                    .context("invalid NHA_ENCAP value")?
+                    .0,
+            ),
+            NHA_GROUPS => Self::Groups,
+            NHA_CONTROLLER => Self::Controller(
+                parse_u32(payload).context("invalid NHA_MASTER value")?,
+            ),
+            NHA_FDB => Self::Fdb,
+            NHA_RES_GROUP => {
+                let mut nlas = vec![];
+                for nla in NlasIterator::new(payload) {
+                    let nla = nla.context("invalid NHA_RES_GROUP value")?;
+                    nlas.push(NexthopResGroup::parse(&nla)?);
+                }
+                Self::ResGroup(nlas)
+            }
+            NHA_RES_BUCKET => {
+                let mut nlas = vec![];
+                for nla in NlasIterator::new(payload) {
+                    let nla = nla.context("invalid NHA_RES_BUCKET value")?;
+                    nlas.push(NexthopResBucket::parse(&nla)?);
+                }
+                Self::ResBucket(nlas)
+            }
+            _ => Self::Other(
+                DefaultNla::parse(buf).context("invalid NLA (unknown kind)")?,
+            ),
+        })
+    }
+}
@@ -0,0 +1,130 @@
+// SPDX-License-Identifier: MIT
+
+use netlink_packet_utils::{
+    traits::{Emitable, Parseable},
+    DecodeError,
+};
+
+pub(crate) const NEXTHOP_GROUP_LEN: usize = 8;
+
+buffer!(NexthopGroupBuffer(NEXTHOP_GROUP_LEN) {
+    id: (u32, 0..4),
+    weight: (u8, 4),
+    weight_high: (u8, 5),
+    reserved: (u16, 6..8),
+});
+
+/// Member of nexthop group stored in `NHA_GROUP`.
+// Linux kernel code `struct nexthop_grp`
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[non_exhaustive]
+pub struct NexthopGroup {
+    /// ID of the member nexthop
+    pub id: u32,
+    /// Lower 8 bits of the weight minus one, e.g. `ip nexthop add id 10
+    /// group 1/2,3` stores 0 for nexthop 1 and 2 for nexthop 2.
+    pub weight: u8,
+    /// Higher 8 bits of the weight minus one, supported since Linux
+    /// kernel 6.12.
+    pub weight_high: u8,
+}
+
+impl NexthopGroup {
+    pub fn new(id: u32) -> Self {
+        Self {
+            id,
+            ..Default::default()
+        }
+    }
+}
+
+impl<T: AsRef<[u8]>> Parseable<NexthopGroupBuffer<T>> for NexthopGroup {
+    fn parse(buf: &NexthopGroupBuffer<T>) -> Result<Self, DecodeError> {
+        Ok(Self {
+            id: buf.id(),
+            weight: buf.weight(),
+            weight_high: buf.weight_high(),
+        })
+    }
+}
+
+impl Emitable for NexthopGroup {
+    fn buffer_len(&self) -> usize {
+        NEXTHOP_GROUP_LEN
+    }
+
+    fn emit(&self, buffer: &mut [u8]) {
+        let mut buffer = NexthopGroupBuffer::new(buffer);
+        buffer.set_id(self.id);
+        buffer.set_weight(self.weight);
+        buffer.set_weight_high(self.weight_high);
+        buffer.set_reserved(0);
+    }
+}
+
+pub(crate) struct VecNexthopGroup(pub(crate) Vec<NexthopGroup>);
+
+impl VecNexthopGroup {
+    pub(crate) fn parse(payload: &[u8]) -> Result<Self, DecodeError> {
+        let chunks = payload.chunks_exact(NEXTHOP_GROUP_LEN);
+        if !chunks.remainder().is_empty() {
+            return Err(format!(
+                "invalid NHA_GROUP length {}, should be multiple of \
+                 {NEXTHOP_GROUP_LEN}",
+                payload.len()
+            )
+            .into());
+        }
+        let mut ret = Vec::new();
+        for chunk in chunks {
+            ret.push(NexthopGroup::parse(&NexthopGroupBuffer::new(chunk))?);
+        }
+        Ok(Self(ret))
+    }
+}
+
+const NEXTHOP_GRP_TYPE_MPATH: u16 = 0;
+const NEXTHOP_GRP_TYPE_RES: u16 = 1;
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[non_exhaustive]
+pub enum NexthopGroupType {
+    /// Hash-threshold multipath group
+    #[default]
+    Multipath,
+    /// Resilient group with fixed number of hash buckets
+    Resilient,
+    Other(u16),
+}
+
+impl From<u16> for NexthopGroupType {
+    fn from(d: u16) -> Self {
+        match d {
+            NEXTHOP_GRP_TYPE_MPATH => Self::Multipath,
+            NEXTHOP_GRP_TYPE_RES => Self::Resilient,
+            _ => Self::Other(d),
+        }
+    }
+}
+
+impl From<NexthopGroupType> for u16 {
+    fn from(v: NexthopGroupType) -> u16 {
+        match v {
+            NexthopGroupType::Multipath => NEXTHOP_GRP_TYPE_MPATH,
+            NexthopGroupType::Resilient => NEXTHOP_GRP_TYPE_RES,
+            NexthopGroupType::Other(d) => d,
+        }
+    }
+}
+
+impl std::fmt::Display for NexthopGroupType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Multipath => write!(f, "mpath"),
+            Self::Resilient => write!(f, "resilient"),
+            Self::Other(d) => write!(f, "other({d})"),
+        }
+    }
+}
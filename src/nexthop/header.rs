@@ -0,0 +1,73 @@
+// SPDX-License-Identifier: MIT
+
+use netlink_packet_utils::{
+    nla::{NlaBuffer, NlasIterator},
+    traits::{Emitable, Parseable},
+    DecodeError,
+};
+
+use super::NexthopFlags;
+use crate::{
+    route::{RouteProtocol, RouteScope},
+    AddressFamily,
+};
+
+const NEXTHOP_HEADER_LEN: usize = 8;
+
+buffer!(NexthopMessageBuffer(NEXTHOP_HEADER_LEN) {
+    family: (u8, 0),
+    scope: (u8, 1),
+    protocol: (u8, 2),
+    reserved: (u8, 3),
+    flags: (u32, 4..NEXTHOP_HEADER_LEN),
+    payload: (slice, NEXTHOP_HEADER_LEN..),
+});
+
+impl<'a, T: AsRef<[u8]> + ?Sized> NexthopMessageBuffer<&'a T> {
+    pub fn attributes(
+        &self,
+    ) -> impl Iterator<Item = Result<NlaBuffer<&'a [u8]>, DecodeError>> {
+        NlasIterator::new(self.payload())
+    }
+}
+
+// Linux kernel code `struct nhmsg`
+#[derive(Debug, PartialEq, Eq, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[non_exhaustive]
+pub struct NexthopHeader {
+    /// Address family of the gateway, [AddressFamily::Unspec] for nexthop
+    /// group.
+    pub address_family: AddressFamily,
+    pub scope: RouteScope,
+    pub protocol: RouteProtocol,
+    pub flags: NexthopFlags,
+}
+
+impl Emitable for NexthopHeader {
+    fn buffer_len(&self) -> usize {
+        NEXTHOP_HEADER_LEN
+    }
+
+    fn emit(&self, buffer: &mut [u8]) {
+        let mut packet = NexthopMessageBuffer::new(buffer);
+        packet.set_family(self.address_family.into());
+        packet.set_scope(self.scope.into());
+        packet.set_protocol(self.protocol.into());
+        packet.set_reserved(0);
+        packet.set_flags(self.flags.bits());
+    }
+}
+
+impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NexthopMessageBuffer<&'a T>>
+    for NexthopHeader
+{
+    fn parse(buf: &NexthopMessageBuffer<&'a T>) -> Result<Self, DecodeError> {
+        Ok(Self {
+            address_family: buf.family().into(),
+            scope: buf.scope().into(),
+            protocol: buf.protocol().into(),
+            flags: NexthopFlags::from_bits_retain(buf.flags()),
+        })
+    }
+}
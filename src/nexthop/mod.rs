@@ -0,0 +1,19 @@
+// SPDX-License-Identifier: MIT
+
+mod attribute;
+mod flags;
+mod group;
+mod header;
+mod message;
+mod res_bucket;
+mod res_group;
+#[cfg(test)]
+mod tests;
+
+pub use self::attribute::NexthopAttribute;
+pub use self::flags::NexthopFlags;
+pub use self::group::{NexthopGroup, NexthopGroupBuffer, NexthopGroupType};
+pub use self::header::{NexthopHeader, NexthopMessageBuffer};
+pub use self::message::NexthopMessage;
+pub use self::res_bucket::NexthopResBucket;
+pub use self::res_group::NexthopResGroup;
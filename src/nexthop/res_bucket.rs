@@ -0,0 +1,84 @@
+// SPDX-License-Identifier: MIT
+
+use anyhow::Context;
+use byteorder::{ByteOrder, NativeEndian};
+use netlink_packet_utils::{
+    nla::{DefaultNla, Nla, NlaBuffer},
+    parsers::{parse_u16, parse_u32, parse_u64},
+    traits::Parseable,
+    DecodeError,
+};
+
+const NHA_RES_BUCKET_INDEX: u16 = 1;
+const NHA_RES_BUCKET_IDLE_TIME: u16 = 2;
+const NHA_RES_BUCKET_NH_ID: u16 = 3;
+
+/// Attributes nested in `NHA_RES_BUCKET` describing a single hash bucket of
+/// resilient nexthop group.
+#[derive(Debug, PartialEq, Eq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[non_exhaustive]
+pub enum NexthopResBucket {
+    Index(u16),
+    /// Time in `USER_HZ` clock ticks since the bucket was last used
+    IdleTime(u64),
+    /// ID of the nexthop the bucket is assigned to
+    NhId(u32),
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_nla"))]
+    Other(DefaultNla),
+}
+
+impl Nla for NexthopResBucket {
+    fn value_len(&self) -> usize {
+        match self {
+            Self::Index(_) => 2,
+            Self::IdleTime(_) => 8,
+            Self::NhId(_) => 4,
+            Self::Other(attr) => attr.value_len(),
+        }
+    }
+
+    fn emit_value(&self, buffer: &mut [u8]) {
+        match self {
+            Self::Index(v) => NativeEndian::write_u16(buffer, *v),
+            Self::IdleTime(v) => NativeEndian::write_u64(buffer, *v),
+            Self::NhId(v) => NativeEndian::write_u32(buffer, *v),
+            Self::Other(attr) => attr.emit_value(buffer),
+        }
+    }
+
+    fn kind(&self) -> u16 {
+        match self {
+            Self::Index(_) => NHA_RES_BUCKET_INDEX,
+            Self::IdleTime(_) => NHA_RES_BUCKET_IDLE_TIME,
+            Self::NhId(_) => NHA_RES_BUCKET_NH_ID,
+            Self::Other(attr) => attr.kind(),
+        }
+    }
+}
+
+impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>>
+    for NexthopResBucket
+{
+    fn parse(buf: &NlaBuffer<&'a T>) -> Result<Self, DecodeError> {
+        let payload = buf.value();
+        Ok(match buf.kind() {
+            NHA_RES_BUCKET_INDEX => Self::Index(
+                parse_u16(payload)
+                    .context("invalid NHA_RES_BUCKET_INDEX value")?,
+            ),
+            NHA_RES_BUCKET_IDLE_TIME => Self::IdleTime(
+                parse_u64(payload)
+                    .context("invalid NHA_RES_BUCKET_IDLE_TIME value")?,
+            ),
+            NHA_RES_BUCKET_NH_ID => Self::NhId(
+                parse_u32(payload)
+                    .context("invalid NHA_RES_BUCKET_NH_ID value")?,
+            ),
+            _ => Self::Other(
+                DefaultNla::parse(buf)
+                    .context("invalid NHA_RES_BUCKET NLA (unknown kind)")?,
+            ),
+        })
+    }
+}
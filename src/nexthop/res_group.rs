@@ -0,0 +1,98 @@
+// SPDX-License-Identifier: MIT
+
+use anyhow::Context;
+use byteorder::{ByteOrder, NativeEndian};
+use netlink_packet_utils::{
+    nla::{DefaultNla, Nla, NlaBuffer},
+    parsers::{parse_u16, parse_u32, parse_u64},
+    traits::Parseable,
+    DecodeError,
+};
+
+const NHA_RES_GROUP_BUCKETS: u16 = 1;
+const NHA_RES_GROUP_IDLE_TIMER: u16 = 2;
+const NHA_RES_GROUP_UNBALANCED_TIMER: u16 = 3;
+const NHA_RES_GROUP_UNBALANCED_TIME: u16 = 4;
+
+/// Attributes nested in `NHA_RES_GROUP` of resilient nexthop group.
+///
+/// The timers and time are in clock ticks of `USER_HZ`, see
+/// [crate::route::RouteCacheInfo::USER_HZ].
+#[derive(Debug, PartialEq, Eq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[non_exhaustive]
+pub enum NexthopResGroup {
+    /// Number of hash buckets
+    Buckets(u16),
+    /// Idle time before an idle bucket could be migrated to another nexthop
+    IdleTimer(u32),
+    /// Maximum time for the group to stay unbalanced before migrating
+    /// buckets regardless their idle time
+    UnbalancedTimer(u32),
+    /// Time for which the group has been unbalanced, query only
+    UnbalancedTime(u64),
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_nla"))]
+    Other(DefaultNla),
+}
+
+impl Nla for NexthopResGroup {
+    fn value_len(&self) -> usize {
+        match self {
+            Self::Buckets(_) => 2,
+            Self::IdleTimer(_) | Self::UnbalancedTimer(_) => 4,
+            Self::UnbalancedTime(_) => 8,
+            Self::Other(attr) => attr.value_len(),
+        }
+    }
+
+    fn emit_value(&self, buffer: &mut [u8]) {
+        match self {
+            Self::Buckets(v) => NativeEndian::write_u16(buffer, *v),
+            Self::IdleTimer(v) | Self::UnbalancedTimer(v) => {
+                NativeEndian::write_u32(buffer, *v)
+            }
+            Self::UnbalancedTime(v) => NativeEndian::write_u64(buffer, *v),
+            Self::Other(attr) => attr.emit_value(buffer),
+        }
+    }
+
+    fn kind(&self) -> u16 {
+        match self {
+            Self::Buckets(_) => NHA_RES_GROUP_BUCKETS,
+            Self::IdleTimer(_) => NHA_RES_GROUP_IDLE_TIMER,
+            Self::UnbalancedTimer(_) => NHA_RES_GROUP_UNBALANCED_TIMER,
+            Self::UnbalancedTime(_) => NHA_RES_GROUP_UNBALANCED_TIME,
+            Self::Other(attr) => attr.kind(),
+        }
+    }
+}
+
+impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>>
+    for NexthopResGroup
+{
+    fn parse(buf: &NlaBuffer<&'a T>) -> Result<Self, DecodeError> {
+        let payload = buf.value();
+        Ok(match buf.kind() {
+            NHA_RES_GROUP_BUCKETS => Self::Buckets(
+                parse_u16(payload)
+                    .context("invalid NHA_RES_GROUP_BUCKETS value")?,
+            ),
+            NHA_RES_GROUP_IDLE_TIMER => Self::IdleTimer(
+                parse_u32(payload)
+                    .context("invalid NHA_RES_GROUP_IDLE_TIMER value")?,
+            ),
+            NHA_RES_GROUP_UNBALANCED_TIMER => Self::UnbalancedTimer(
+                parse_u32(payload)
+                    .context("invalid NHA_RES_GROUP_UNBALANCED_TIMER value")?,
+            ),
+            NHA_RES_GROUP_UNBALANCED_TIME => Self::UnbalancedTime(
+                parse_u64(payload)
+                    .context("invalid NHA_RES_GROUP_UNBALANCED_TIME value")?,
+            ),
+            _ => Self::Other(
+                DefaultNla::parse(buf)
+                    .context("invalid NHA_RES_GROUP NLA (unknown kind)")?,
+            ),
+        })
+    }
+}
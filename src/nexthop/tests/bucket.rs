@@ -0,0 +1,79 @@
+// SPDX-License-Identifier: MIT
+
+use netlink_packet_core::{
+    NetlinkHeader, NetlinkMessage, NetlinkPayload, NLM_F_MULTIPART,
+};
+use netlink_packet_utils::Emitable;
+
+use crate::{
+    nexthop::{
+        NexthopAttribute, NexthopFlags, NexthopHeader, NexthopMessage,
+        NexthopResBucket,
+    },
+    route::{RouteProtocol, RouteScope},
+    AddressFamily, RouteNetlinkMessage,
+};
+
+// Hand-crafted RTM_NEWNEXTHOPBUCKET dump reply equivalent to one entry of
+//      ip nexthop bucket show id 10
+// for resilient group 10 with bucket 3 assigned to nexthop 1 and idle for
+// 1500 clock ticks.
+#[test]
+fn test_nexthop_bucket() {
+    let raw = vec![
+        0x40, 0x00, 0x00, 0x00, // length 64
+        0x74, 0x00, // RTM_NEWNEXTHOPBUCKET 116
+        0x02, 0x00, // NLM_F_MULTI
+        0x00, 0x00, 0x00, 0x00, // sequence number
+        0x00, 0x00, 0x00, 0x00, // port id
+        0x00, // AF_UNSPEC
+        0x00, // RT_SCOPE_UNIVERSE
+        0x03, // RTPROT_BOOT
+        0x00, // reserved
+        0x00, 0x00, 0x00, 0x00, // flags
+        0x08, 0x00, 0x01, 0x00, // length 8, NHA_ID
+        0x0a, 0x00, 0x00, 0x00, // 10
+        0x20, 0x00, 0x0d,
+        0x80, // length 32, NHA_RES_BUCKET | NLA_F_NESTED
+        0x06, 0x00, 0x01, 0x00, // length 6, NHA_RES_BUCKET_INDEX
+        0x03, 0x00, 0x00, 0x00, // 3 and padding
+        0x08, 0x00, 0x03, 0x00, // length 8, NHA_RES_BUCKET_NH_ID
+        0x01, 0x00, 0x00, 0x00, // 1
+        0x0c, 0x00, 0x02, 0x00, // length 12, NHA_RES_BUCKET_IDLE_TIME
+        0xdc, 0x05, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, // 1500
+    ];
+
+    let bucket_msg = NexthopMessage {
+        header: NexthopHeader {
+            address_family: AddressFamily::Unspec,
+            scope: RouteScope::Universe,
+            protocol: RouteProtocol::Boot,
+            flags: NexthopFlags::empty(),
+        },
+        attributes: vec![
+            NexthopAttribute::Id(10),
+            NexthopAttribute::ResBucket(vec![
+                NexthopResBucket::Index(3),
+                NexthopResBucket::NhId(1),
+                NexthopResBucket::IdleTime(1500),
+            ]),
+        ],
+    };
+
+    let mut header = NetlinkHeader::default();
+    header.flags = NLM_F_MULTIPART;
+    let mut expected = NetlinkMessage::new(
+        header,
+        NetlinkPayload::from(RouteNetlinkMessage::NewNexthopBucket(bucket_msg)),
+    );
+    expected.finalize();
+
+    assert_eq!(
+        NetlinkMessage::<RouteNetlinkMessage>::deserialize(&raw).unwrap(),
+        expected
+    );
+
+    let mut buf = vec![0; expected.buffer_len()];
+    expected.emit(&mut buf);
+    assert_eq!(buf, raw);
+}
@@ -0,0 +1,124 @@
+// SPDX-License-Identifier: MIT
+
+use netlink_packet_utils::traits::{Emitable, Parseable};
+
+use crate::{
+    nexthop::{
+        NexthopAttribute, NexthopFlags, NexthopGroup, NexthopGroupType,
+        NexthopHeader, NexthopMessage, NexthopMessageBuffer, NexthopResGroup,
+    },
+    route::{RouteProtocol, RouteScope},
+    AddressFamily,
+};
+
+// Hand-crafted RTM_NEWNEXTHOP payload equivalent to command:
+//      ip nexthop add id 10 group 1/2,3
+#[test]
+fn test_nexthop_multipath_group() {
+    let raw = vec![
+        0x00, // AF_UNSPEC
+        0x00, // RT_SCOPE_UNIVERSE
+        0x03, // RTPROT_BOOT
+        0x00, // reserved
+        0x00, 0x00, 0x00, 0x00, // flags
+        0x08, 0x00, 0x01, 0x00, // length 8, NHA_ID
+        0x0a, 0x00, 0x00, 0x00, // 10
+        0x14, 0x00, 0x02, 0x00, // length 20, NHA_GROUP
+        0x01, 0x00, 0x00, 0x00, // id 1
+        0x00, 0x00, 0x00, 0x00, // weight 1
+        0x02, 0x00, 0x00, 0x00, // id 2
+        0x02, 0x00, 0x00, 0x00, // weight 3
+        0x06, 0x00, 0x03, 0x00, // length 6, NHA_GROUP_TYPE
+        0x00, 0x00, 0x00, 0x00, // NEXTHOP_GRP_TYPE_MPATH
+    ];
+
+    let expected = NexthopMessage {
+        header: NexthopHeader {
+            address_family: AddressFamily::Unspec,
+            scope: RouteScope::Universe,
+            protocol: RouteProtocol::Boot,
+            flags: NexthopFlags::empty(),
+        },
+        attributes: vec![
+            NexthopAttribute::Id(10),
+            NexthopAttribute::Group(vec![
+                NexthopGroup::new(1),
+                NexthopGroup {
+                    id: 2,
+                    weight: 2,
+                    weight_high: 0,
+                },
+            ]),
+            NexthopAttribute::GroupType(NexthopGroupType::Multipath),
+        ],
+    };
+
+    assert_eq!(
+        expected,
+        NexthopMessage::parse(&NexthopMessageBuffer::new(&raw)).unwrap()
+    );
+
+    let mut buf = vec![0; expected.buffer_len()];
+
+    expected.emit(&mut buf);
+
+    assert_eq!(buf, raw);
+}
+
+// Hand-crafted RTM_NEWNEXTHOP payload equivalent to command:
+//      ip nexthop add id 20 group 1/2 type resilient buckets 8 \
+//          idle_timer 120 unbalanced_timer 60
+#[test]
+fn test_nexthop_resilient_group() {
+    let raw = vec![
+        0x00, 0x00, 0x03, 0x00, 0x00, 0x00, 0x00, 0x00, // nhmsg
+        0x08, 0x00, 0x01, 0x00, // length 8, NHA_ID
+        0x14, 0x00, 0x00, 0x00, // 20
+        0x14, 0x00, 0x02, 0x00, // length 20, NHA_GROUP
+        0x01, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, // id 1
+        0x02, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, // id 2
+        0x06, 0x00, 0x03, 0x00, // length 6, NHA_GROUP_TYPE
+        0x01, 0x00, 0x00, 0x00, // NEXTHOP_GRP_TYPE_RES
+        0x1c, 0x00, 0x0c, 0x80, // length 28, NHA_RES_GROUP | NLA_F_NESTED
+        0x06, 0x00, 0x01, 0x00, // length 6, NHA_RES_GROUP_BUCKETS
+        0x08, 0x00, 0x00, 0x00, // 8
+        0x08, 0x00, 0x02, 0x00, // length 8, NHA_RES_GROUP_IDLE_TIMER
+        0xe0, 0x2e, 0x00, 0x00, // 12000 clock ticks
+        0x08, 0x00, 0x03,
+        0x00, // length 8, NHA_RES_GROUP_UNBALANCED_TIMER
+        0x70, 0x17, 0x00, 0x00, // 6000 clock ticks
+    ];
+
+    let expected = NexthopMessage {
+        header: NexthopHeader {
+            address_family: AddressFamily::Unspec,
+            scope: RouteScope::Universe,
+            protocol: RouteProtocol::Boot,
+            flags: NexthopFlags::empty(),
+        },
+        attributes: vec![
+            NexthopAttribute::Id(20),
+            NexthopAttribute::Group(vec![
+                NexthopGroup::new(1),
+                NexthopGroup::new(2),
+            ]),
+            NexthopAttribute::GroupType(NexthopGroupType::Resilient),
+            NexthopAttribute::ResGroup(vec![
+                NexthopResGroup::Buckets(8),
+                NexthopResGroup::IdleTimer(12000),
+                NexthopResGroup::UnbalancedTimer(6000),
+            ]),
+        ],
+    };
+
+    assert_eq!(
+        expected,
+        NexthopMessage::parse(&NexthopMessageBuffer::new(&raw)).unwrap()
+    );
+
+    let mut buf = vec![0; expected.buffer_len()];
+
+    expected.emit(&mut buf);
+
+    assert_eq!(buf, raw);
+}
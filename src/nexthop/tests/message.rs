@@ -0,0 +1,74 @@
+// SPDX-License-Identifier: MIT
+
+use std::net::Ipv4Addr;
+use std::str::FromStr;
+
+use netlink_packet_core::{NetlinkHeader, NetlinkMessage, NetlinkPayload};
+use netlink_packet_utils::Emitable;
+
+use crate::{
+    nexthop::{NexthopAttribute, NexthopFlags, NexthopHeader, NexthopMessage},
+    route::{RouteProtocol, RouteScope},
+    AddressFamily, RouteNetlinkMessage,
+};
+
+// Hand-crafted RTM_NEWNEXTHOP notification equivalent to the one received by
+//      ip monitor nexthop
+// after
+//      ip nexthop add id 1 via 192.0.2.1 dev eth0
+// with eth0 holding interface index 2.
+#[test]
+fn test_new_nexthop_netlink_message() {
+    let raw = vec![
+        0x30, 0x00, 0x00, 0x00, // length 48
+        0x68, 0x00, // RTM_NEWNEXTHOP 104
+        0x00, 0x00, // flags
+        0x00, 0x00, 0x00, 0x00, // sequence number
+        0x00, 0x00, 0x00, 0x00, // port id
+        0x02, // AF_INET
+        0x00, // RT_SCOPE_UNIVERSE
+        0x03, // RTPROT_BOOT
+        0x00, // reserved
+        0x00, 0x00, 0x00, 0x00, // flags
+        0x08, 0x00, 0x01, 0x00, // length 8, NHA_ID
+        0x01, 0x00, 0x00, 0x00, // 1
+        0x08, 0x00, 0x05, 0x00, // length 8, NHA_OIF
+        0x02, 0x00, 0x00, 0x00, // 2
+        0x08, 0x00, 0x06, 0x00, // length 8, NHA_GATEWAY
+        0xc0, 0x00, 0x02, 0x01, // 192.0.2.1
+    ];
+
+    let nexthop_msg = NexthopMessage {
+        header: NexthopHeader {
+            address_family: AddressFamily::Inet,
+            scope: RouteScope::Universe,
+            protocol: RouteProtocol::Boot,
+            flags: NexthopFlags::empty(),
+        },
+        attributes: vec![
+            NexthopAttribute::Id(1),
+            NexthopAttribute::Oif(2),
+            NexthopAttribute::Gateway(
+                Ipv4Addr::from_str("192.0.2.1").unwrap().into(),
+            ),
+        ],
+    };
+
+    let mut expected = NetlinkMessage::new(
+        NetlinkHeader::default(),
+        NetlinkPayload::from(RouteNetlinkMessage::NewNexthop(nexthop_msg)),
+    );
+    expected.finalize();
+
+    let parsed =
+        NetlinkMessage::<RouteNetlinkMessage>::deserialize(&raw).unwrap();
+    assert_eq!(parsed, expected);
+    assert!(matches!(
+        &parsed.payload,
+        NetlinkPayload::InnerMessage(m) if m.is_new_nexthop()
+    ));
+
+    let mut buf = vec![0; expected.buffer_len()];
+    expected.emit(&mut buf);
+    assert_eq!(buf, raw);
+}
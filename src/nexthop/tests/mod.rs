@@ -0,0 +1,10 @@
+// SPDX-License-Identifier: MIT
+
+#[cfg(test)]
+mod bucket;
+#[cfg(test)]
+mod group;
+#[cfg(test)]
+mod message;
+#[cfg(test)]
+mod single;
@@ -0,0 +1,125 @@
+// SPDX-License-Identifier: MIT
+
+use std::net::Ipv4Addr;
+use std::str::FromStr;
+
+use netlink_packet_utils::traits::{Emitable, Parseable};
+
+use crate::{
+    nexthop::{
+        NexthopAttribute, NexthopFlags, NexthopHeader, NexthopMessage,
+        NexthopMessageBuffer,
+    },
+    route::{
+        MplsLabel, RouteLwEnCapType, RouteLwTunnelEncap, RouteMplsIpTunnel,
+        RouteProtocol, RouteScope,
+    },
+    AddressFamily,
+};
+
+// Hand-crafted RTM_NEWNEXTHOP payload equivalent to the reply of
+//      ip nexthop add id 1 via 192.0.2.1 dev eth0
+//      ip nexthop show id 1
+// with eth0 holding interface index 2.
+#[test]
+fn test_nexthop_ipv4_gateway() {
+    let raw = vec![
+        0x02, // AF_INET
+        0x00, // RT_SCOPE_UNIVERSE
+        0x03, // RTPROT_BOOT
+        0x00, // reserved
+        0x00, 0x00, 0x00, 0x00, // flags
+        0x08, 0x00, 0x01, 0x00, // length 8, NHA_ID
+        0x01, 0x00, 0x00, 0x00, // 1
+        0x08, 0x00, 0x05, 0x00, // length 8, NHA_OIF
+        0x02, 0x00, 0x00, 0x00, // 2
+        0x08, 0x00, 0x06, 0x00, // length 8, NHA_GATEWAY
+        0xc0, 0x00, 0x02, 0x01, // 192.0.2.1
+    ];
+
+    let expected = NexthopMessage {
+        header: NexthopHeader {
+            address_family: AddressFamily::Inet,
+            scope: RouteScope::Universe,
+            protocol: RouteProtocol::Boot,
+            flags: NexthopFlags::empty(),
+        },
+        attributes: vec![
+            NexthopAttribute::Id(1),
+            NexthopAttribute::Oif(2),
+            NexthopAttribute::Gateway(
+                Ipv4Addr::from_str("192.0.2.1").unwrap().into(),
+            ),
+        ],
+    };
+
+    assert_eq!(
+        expected,
+        NexthopMessage::parse(&NexthopMessageBuffer::new(&raw)).unwrap()
+    );
+
+    let mut buf = vec![0; expected.buffer_len()];
+
+    expected.emit(&mut buf);
+
+    assert_eq!(buf, raw);
+}
+
+// Hand-crafted RTM_NEWNEXTHOP payload equivalent to the reply of
+//      ip nexthop add id 2 encap mpls 100 via 192.0.2.1 dev eth0
+//      ip nexthop show id 2
+// with eth0 holding interface index 2. The NHA_ENCAP is placed before
+// NHA_ENCAP_TYPE by kernel.
+#[test]
+fn test_nexthop_mpls_encap() {
+    let raw = vec![
+        0x02, 0x00, 0x03, 0x00, 0x00, 0x00, 0x00, 0x00, // nhmsg
+        0x08, 0x00, 0x01, 0x00, // length 8, NHA_ID
+        0x02, 0x00, 0x00, 0x00, // 2
+        0x08, 0x00, 0x05, 0x00, // length 8, NHA_OIF
+        0x02, 0x00, 0x00, 0x00, // 2
+        0x08, 0x00, 0x06, 0x00, // length 8, NHA_GATEWAY
+        0xc0, 0x00, 0x02, 0x01, // 192.0.2.1
+        0x0c, 0x00, 0x08, 0x00, // length 12, NHA_ENCAP
+        0x08, 0x00, 0x01, 0x00, // length 8, MPLS_IPTUNNEL_DST
+        0x00, 0x06, 0x41, 0x00, // label 100, bottom of stack
+        0x06, 0x00, 0x07, 0x00, // length 6, NHA_ENCAP_TYPE
+        0x01, 0x00, 0x00, 0x00, // LWTUNNEL_ENCAP_MPLS
+    ];
+
+    let expected = NexthopMessage {
+        header: NexthopHeader {
+            address_family: AddressFamily::Inet,
+            scope: RouteScope::Universe,
+            protocol: RouteProtocol::Boot,
+            flags: NexthopFlags::empty(),
+        },
+        attributes: vec![
+            NexthopAttribute::Id(2),
+            NexthopAttribute::Oif(2),
+            NexthopAttribute::Gateway(
+                Ipv4Addr::from_str("192.0.2.1").unwrap().into(),
+            ),
+            NexthopAttribute::Encap(vec![RouteLwTunnelEncap::Mpls(
+                RouteMplsIpTunnel::Destination(vec![MplsLabel {
+                    label: 100,
+                    traffic_class: 0,
+                    bottom_of_stack: true,
+                    ttl: 0,
+                }]),
+            )]),
+            NexthopAttribute::EncapType(RouteLwEnCapType::Mpls),
+        ],
+    };
+
+    assert_eq!(
+        expected,
+        NexthopMessage::parse(&NexthopMessageBuffer::new(&raw)).unwrap()
+    );
+
+    let mut buf = vec![0; expected.buffer_len()];
+
+    expected.emit(&mut buf);
+
+    assert_eq!(buf, raw);
+}
@@ -17,6 +17,7 @@ const NETNSA_TARGET_NSID: u16 = 4;
 const NETNSA_CURRENT_NSID: u16 = 5;
 
 #[derive(Debug, PartialEq, Eq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[non_exhaustive]
 pub enum NsidAttribute {
     /// -1 means not assigned
@@ -25,6 +26,7 @@ pub enum NsidAttribute {
     Fd(u32),
     TargetNsid(i32),
     CurrentNsid(i32),
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_nla"))]
     Other(DefaultNla),
 }
 
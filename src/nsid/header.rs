@@ -23,6 +23,7 @@ impl<'a, T: AsRef<[u8]> + ?Sized> NsidMessageBuffer<&'a T> {
 }
 
 #[derive(Debug, PartialEq, Eq, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct NsidHeader {
     pub family: AddressFamily,
 }
@@ -40,8 +41,16 @@ impl Emitable for NsidHeader {
 
 impl<T: AsRef<[u8]>> Parseable<NsidMessageBuffer<T>> for NsidHeader {
     fn parse(buf: &NsidMessageBuffer<T>) -> Result<Self, DecodeError> {
-        Ok(NsidHeader {
-            family: buf.family().into(),
-        })
+        // The `rtgen_family` of NSID messages is always AF_UNSPEC in both
+        // kernel replies and iproute2 requests.
+        let family = AddressFamily::from(buf.family());
+        if family != AddressFamily::Unspec {
+            return Err(format!(
+                "invalid rtgen family {family:?} for NSID message, \
+                expecting AF_UNSPEC"
+            )
+            .into());
+        }
+        Ok(NsidHeader { family })
     }
 }
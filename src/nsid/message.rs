@@ -9,6 +9,7 @@ use netlink_packet_utils::{
 use crate::nsid::{NsidAttribute, NsidHeader, NsidMessageBuffer};
 
 #[derive(Debug, PartialEq, Eq, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[non_exhaustive]
 pub struct NsidMessage {
     pub header: NsidHeader,
@@ -52,3 +53,52 @@ impl Emitable for NsidMessage {
             .emit(&mut buffer[self.header.buffer_len()..]);
     }
 }
+
+/// Builder of [NsidMessage] for `RTM_GETNSID` requests querying the NSID of
+/// network namespace referred by process ID or file descriptor.
+#[derive(Debug, PartialEq, Eq, Clone, Default)]
+pub struct NsidMessageBuilder {
+    selector: Option<NsidAttribute>,
+    target_nsid: Option<i32>,
+}
+
+impl NsidMessageBuilder {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Query network namespace of specified process ID. Overrides previous
+    /// [Self::fd()].
+    pub fn pid(mut self, pid: u32) -> Self {
+        self.selector = Some(NsidAttribute::Pid(pid));
+        self
+    }
+
+    /// Query network namespace of specified file descriptor, for example
+    /// opened from `/run/netns/<name>`. Overrides previous [Self::pid()].
+    pub fn fd(mut self, fd: u32) -> Self {
+        self.selector = Some(NsidAttribute::Fd(fd));
+        self
+    }
+
+    /// Query the NSID assigned in the network namespace with specified NSID
+    /// instead of current network namespace.
+    pub fn target_nsid(mut self, nsid: i32) -> Self {
+        self.target_nsid = Some(nsid);
+        self
+    }
+
+    pub fn build(self) -> NsidMessage {
+        let mut attributes = Vec::new();
+        if let Some(selector) = self.selector {
+            attributes.push(selector);
+        }
+        if let Some(nsid) = self.target_nsid {
+            attributes.push(NsidAttribute::TargetNsid(nsid));
+        }
+        NsidMessage {
+            header: NsidHeader::default(),
+            attributes,
+        }
+    }
+}
@@ -8,4 +8,4 @@ mod tests;
 
 pub use self::attribute::NsidAttribute;
 pub use self::header::{NsidHeader, NsidMessageBuffer};
-pub use self::message::NsidMessage;
+pub use self::message::{NsidMessage, NsidMessageBuilder};
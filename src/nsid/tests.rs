@@ -3,7 +3,10 @@
 use netlink_packet_utils::{Emitable, Parseable};
 
 use crate::{
-    nsid::{NsidAttribute, NsidHeader, NsidMessage, NsidMessageBuffer},
+    nsid::{
+        NsidAttribute, NsidHeader, NsidMessage, NsidMessageBuffer,
+        NsidMessageBuilder,
+    },
     AddressFamily,
 };
 
@@ -93,3 +96,43 @@ fn test_ip_netns_query_target_ns_id() {
 
     assert_eq!(buf, raw);
 }
+
+// RTM_GETNSID request(netlink message header removed) querying the NSID of
+// network namespace used by PID 1
+#[test]
+fn test_get_nsid_by_pid() {
+    let raw = vec![
+        0x00, 0x00, 0x00, 0x00, 0x08, 0x00, 0x02, 0x00, 0x01, 0x00, 0x00, 0x00,
+    ];
+
+    let expected = NsidMessage {
+        header: NsidHeader {
+            family: AddressFamily::Unspec,
+        },
+        attributes: vec![NsidAttribute::Pid(1)],
+    };
+
+    let message = NsidMessageBuilder::new().fd(6).pid(1).build();
+
+    assert_eq!(message, expected);
+
+    let mut buf = vec![0; message.buffer_len()];
+
+    message.emit(&mut buf);
+
+    assert_eq!(buf, raw);
+
+    assert_eq!(
+        expected,
+        NsidMessage::parse(&NsidMessageBuffer::new(&raw)).unwrap()
+    );
+}
+
+#[test]
+fn test_nsid_invalid_family() {
+    let raw = vec![
+        0x02, 0x00, 0x00, 0x00, 0x08, 0x00, 0x01, 0x00, 0x63, 0x00, 0x00, 0x00,
+    ];
+
+    assert!(NsidMessage::parse(&NsidMessageBuffer::new(&raw)).is_err());
+}
@@ -3,6 +3,7 @@
 use netlink_packet_utils::{traits::Parseable, DecodeError, Emitable};
 
 #[derive(Debug, Clone, Copy, Eq, PartialEq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[non_exhaustive]
 pub struct CacheInfo {
     pub preferred_time: u32,
@@ -28,6 +28,7 @@ impl<'a, T: AsRef<[u8]> + ?Sized> PrefixMessageBuffer<&'a T> {
 }
 
 #[derive(Debug, PartialEq, Eq, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct PrefixHeader {
     pub prefix_family: u8,
     pub ifindex: i32,
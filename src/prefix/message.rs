@@ -1,5 +1,7 @@
 // SPDX-License-Identifier: MIT
 
+use std::net::IpAddr;
+
 use anyhow::Context;
 
 use netlink_packet_utils::{
@@ -13,11 +15,36 @@ use super::{
 };
 
 #[derive(Debug, PartialEq, Eq, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct PrefixMessage {
     pub header: PrefixHeader,
     pub attributes: Vec<PrefixAttribute>,
 }
 
+impl PrefixMessage {
+    /// The on-link prefix stored in `PREFIX_ADDRESS` along with the prefix
+    /// length of header.
+    pub fn prefix(&self) -> Option<(IpAddr, u8)> {
+        self.attributes.iter().find_map(|attr| match attr {
+            PrefixAttribute::Address(addr) => {
+                Some((IpAddr::V6(*addr), self.header.prefix_len))
+            }
+            _ => None,
+        })
+    }
+
+    /// The `(preferred, valid)` lifetimes in seconds stored in
+    /// `PREFIX_CACHEINFO`, `u32::MAX` means infinity.
+    pub fn lifetimes(&self) -> Option<(u32, u32)> {
+        self.attributes.iter().find_map(|attr| match attr {
+            PrefixAttribute::CacheInfo(info) => {
+                Some((info.preferred_time, info.valid_time))
+            }
+            _ => None,
+        })
+    }
+}
+
 impl Emitable for PrefixMessage {
     fn buffer_len(&self) -> usize {
         self.header.buffer_len() + self.attributes.as_slice().buffer_len()
@@ -1,6 +1,9 @@
 // SPDX-License-Identifier: MIT
 
-use std::{net::Ipv6Addr, str::FromStr};
+use std::{
+    net::{IpAddr, Ipv6Addr},
+    str::FromStr,
+};
 
 use netlink_packet_utils::{Emitable, Parseable};
 
@@ -57,3 +60,39 @@ fn test_new_prefix() {
     expected.emit(&mut buf);
     assert_eq!(data, buf);
 }
+
+// Hand-crafted RTM_NEWPREFIX payload equivalent to the kernel notification of
+// receiving router advertisement of on-link prefix 2001:db8:1::/64 with
+// preferred lifetime 14400 and valid lifetime 86400 on interface 3.
+#[test]
+fn test_prefix_accessors() {
+    #[rustfmt::skip]
+    let data = vec![
+        // AF_INET6 + padding
+        0x0a, 0x00, 0x00, 0x00,
+        // ifindex 3
+        0x03, 0x00, 0x00, 0x00,
+        // type 3(ND_OPT_PREFIX_INFORMATION), prefix length 64,
+        // flags IF_PREFIX_ONLINK | IF_PREFIX_AUTOCONF, padding
+        0x03, 0x40, 0x03, 0x00,
+        // PREFIX_ADDRESS attribute
+        0x14, 0x00, 0x01, 0x00,
+        0x20, 0x01, 0x0d, 0xb8, 0x00, 0x01, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        // PREFIX_CACHEINFO attribute
+        0x0c, 0x00, 0x02, 0x00,
+        0x40, 0x38, 0x00, 0x00, // preferred 14400
+        0x80, 0x51, 0x01, 0x00, // valid 86400
+    ];
+    let msg = PrefixMessage::parse(&PrefixMessageBuffer::new(&data)).unwrap();
+
+    assert_eq!(
+        msg.prefix(),
+        Some((IpAddr::from_str("2001:db8:1::").unwrap(), 64))
+    );
+    assert_eq!(msg.lifetimes(), Some((14400, 86400)));
+    assert_eq!(msg.attributes.len(), 2);
+
+    assert_eq!(PrefixMessage::default().prefix(), None);
+    assert_eq!(PrefixMessage::default().lifetimes(), None);
+}
@@ -14,11 +14,13 @@ use crate::{
 };
 
 #[derive(Debug, PartialEq, Eq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[non_exhaustive]
 pub enum RouteAddress {
     Inet(Ipv4Addr),
     Inet6(Ipv6Addr),
     Mpls(MplsLabel),
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_hex"))]
     Other(Vec<u8>),
 }
 
@@ -1,5 +1,7 @@
 // SPDX-License-Identifier: MIT
 
+use std::time::Duration;
+
 use anyhow::Context;
 use byteorder::{ByteOrder, NativeEndian};
 use netlink_packet_utils::{
@@ -47,11 +49,43 @@ const RTA_TTL_PROPAGATE: u16 = 26;
 // const RTA_IP_PROTO:u16 = 27;
 // const RTA_SPORT:u16 = 28;
 // const RTA_DPORT:u16 = 29;
-// const RTA_NH_ID:u16 = 30;
+const RTA_NH_ID: u16 = 30;
+
+/// Kernel name of route attribute `kind`, used by
+/// [crate::RouteNetlinkMessage::attribute_at_offset()].
+pub(crate) fn attribute_name(kind: u16) -> Option<&'static str> {
+    Some(match kind {
+        RTA_DST => "RTA_DST",
+        RTA_SRC => "RTA_SRC",
+        RTA_IIF => "RTA_IIF",
+        RTA_OIF => "RTA_OIF",
+        RTA_GATEWAY => "RTA_GATEWAY",
+        RTA_PRIORITY => "RTA_PRIORITY",
+        RTA_PREFSRC => "RTA_PREFSRC",
+        RTA_METRICS => "RTA_METRICS",
+        RTA_MULTIPATH => "RTA_MULTIPATH",
+        RTA_FLOW => "RTA_FLOW",
+        RTA_CACHEINFO => "RTA_CACHEINFO",
+        RTA_TABLE => "RTA_TABLE",
+        RTA_MARK => "RTA_MARK",
+        RTA_MFC_STATS => "RTA_MFC_STATS",
+        RTA_VIA => "RTA_VIA",
+        RTA_NEWDST => "RTA_NEWDST",
+        RTA_PREF => "RTA_PREF",
+        RTA_ENCAP_TYPE => "RTA_ENCAP_TYPE",
+        RTA_ENCAP => "RTA_ENCAP",
+        RTA_EXPIRES => "RTA_EXPIRES",
+        RTA_UID => "RTA_UID",
+        RTA_TTL_PROPAGATE => "RTA_TTL_PROPAGATE",
+        RTA_NH_ID => "RTA_NH_ID",
+        _ => return None,
+    })
+}
 
 /// Netlink attributes for `RTM_NEWROUTE`, `RTM_DELROUTE`,
 /// `RTM_GETROUTE` netlink messages.
 #[derive(Debug, PartialEq, Eq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[non_exhaustive]
 pub enum RouteAttribute {
     Metrics(Vec<RouteMetric>),
@@ -71,7 +105,9 @@ pub enum RouteAttribute {
     // The RTA_EXPIRES holds different data type in kernel 6.5.8.
     // For non-multipath route, it is u32 and only used for modifying routes.
     // For multipath route, it is u64 for querying only.
-    /// This is only for non-multicast route
+    /// Lifetime in seconds of non-multicast route, only honored by kernel
+    /// for IPv6 route. `u32::MAX` means infinite. Use
+    /// [RouteAttribute::expires()] to create from [Duration].
     Expires(u32),
     /// This is only for multicast route
     MulticastExpires(u64),
@@ -84,9 +120,33 @@ pub enum RouteAttribute {
     Realm(RouteRealm),
     Table(u32),
     Mark(u32),
+    /// ID of nexthop object (`RTM_NEWNEXTHOP`) used by this route. Kernel
+    /// refuses it combined with [RouteAttribute::Gateway],
+    /// [RouteAttribute::MultiPath] or [RouteAttribute::Oif], this crate does
+    /// not check that.
+    NhId(u32),
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_nla"))]
     Other(DefaultNla),
 }
 
+impl RouteAttribute {
+    /// Create [RouteAttribute::Expires] for temporary route, the sub-second
+    /// part of `lifetime` is ignored as kernel uses seconds.
+    /// Returns error if `lifetime` does not fit into 32 bits seconds.
+    pub fn expires(lifetime: Duration) -> Result<Self, DecodeError> {
+        u32::try_from(lifetime.as_secs())
+            .map(Self::Expires)
+            .map_err(|_| {
+                format!(
+                    "invalid route lifetime {lifetime:?}, should be less or \
+                     equal to {} seconds",
+                    u32::MAX
+                )
+                .into()
+            })
+    }
+}
+
 impl Nla for RouteAttribute {
     fn value_len(&self) -> usize {
         match self {
@@ -113,7 +173,8 @@ impl Nla for RouteAttribute {
             | Self::Oif(_)
             | Self::Priority(_)
             | Self::Table(_)
-            | Self::Mark(_) => 4,
+            | Self::Mark(_)
+            | Self::NhId(_) => 4,
             Self::MulticastExpires(_) => 8,
             Self::Other(attr) => attr.value_len(),
         }
@@ -149,7 +210,8 @@ impl Nla for RouteAttribute {
             | Self::Oif(value)
             | Self::Priority(value)
             | Self::Table(value)
-            | Self::Mark(value) => NativeEndian::write_u32(buffer, *value),
+            | Self::Mark(value)
+            | Self::NhId(value) => NativeEndian::write_u32(buffer, *value),
             Self::Realm(v) => v.emit(buffer),
             Self::MulticastExpires(value) => {
                 NativeEndian::write_u64(buffer, *value)
@@ -183,6 +245,7 @@ impl Nla for RouteAttribute {
             Self::MulticastExpires(_) => RTA_EXPIRES,
             Self::Uid(_) => RTA_UID,
             Self::TtlPropagate(_) => RTA_TTL_PROPAGATE,
+            Self::NhId(_) => RTA_NH_ID,
             Self::Other(ref attr) => attr.kind(),
         }
     }
@@ -280,6 +343,9 @@ impl<'a, T: AsRef<[u8]> + ?Sized>
             RTA_MARK => Self::Mark(
                 parse_u32(payload).context("invalid RTA_MARK value")?,
             ),
+            RTA_NH_ID => Self::NhId(
+                parse_u32(payload).context("invalid RTA_NH_ID value")?,
+            ),
 
             RTA_CACHEINFO => Self::CacheInfo(
                 RouteCacheInfo::parse(
@@ -1,11 +1,14 @@
 // SPDX-License-Identifier: MIT
 
+use std::time::Duration;
+
 use netlink_packet_utils::{
     traits::{Emitable, Parseable},
     DecodeError,
 };
 
 #[derive(Debug, Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[non_exhaustive]
 pub struct RouteCacheInfo {
     pub clntref: u32,
@@ -18,6 +21,29 @@ pub struct RouteCacheInfo {
     pub ts_age: u32,
 }
 
+impl RouteCacheInfo {
+    /// Clock ticks per second used by kernel when reporting time to user
+    /// space, the kernel always converts jiffies to this fixed `USER_HZ`
+    /// regardless of its internal `CONFIG_HZ`.
+    pub const USER_HZ: u32 = 100;
+
+    /// Remaining lifetime of the route converted from the `rta_expires`
+    /// clock ticks using [RouteCacheInfo::USER_HZ].
+    ///
+    /// Return `None` if the route never expires(`rta_expires` is 0). The
+    /// kernel reports negative ticks for routes already expired but not
+    /// garbage collected yet, those are represented by [Duration::ZERO].
+    pub fn expires(&self) -> Option<Duration> {
+        match self.expires as i32 {
+            0 => None,
+            ticks if ticks < 0 => Some(Duration::ZERO),
+            ticks => Some(Duration::from_millis(
+                ticks as u64 * 1000 / u64::from(Self::USER_HZ),
+            )),
+        }
+    }
+}
+
 const CACHE_INFO_LEN: usize = 32;
 
 buffer!(RouteCacheInfoBuffer(CACHE_INFO_LEN) {
@@ -17,6 +17,7 @@ const RTM_F_OFFLOAD_FAILED: u32 = 0x20000000;
 
 bitflags! {
     #[derive(Clone, Eq, PartialEq, Debug, Copy, Default)]
+    #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
     #[non_exhaustive]
     pub struct RouteFlags: u32 {
         const Dead = RTNH_F_DEAD as u32;
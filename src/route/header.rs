@@ -23,6 +23,8 @@ buffer!(RouteMessageBuffer(ROUTE_HEADER_LEN) {
     payload: (slice, ROUTE_HEADER_LEN..),
 });
 
+impl_try_from_slice!(RouteMessageBuffer);
+
 impl<'a, T: AsRef<[u8]> + ?Sized> RouteMessageBuffer<&'a T> {
     pub fn attributes(
         &self,
@@ -34,6 +36,7 @@ impl<'a, T: AsRef<[u8]> + ?Sized> RouteMessageBuffer<&'a T> {
 /// High level representation of `RTM_GETROUTE`, `RTM_ADDROUTE`, `RTM_DELROUTE`
 /// messages headers.
 #[derive(Debug, PartialEq, Eq, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct RouteHeader {
     /// Address family of the route: either [AddressFamily::Inet] for IPv4,
     /// or [AddressFamily::Inet6] for IPv6.
@@ -57,6 +60,9 @@ pub struct RouteHeader {
 }
 
 impl RouteHeader {
+    /// Used in header when the table ID does not fit into 8 bits, the real
+    /// table ID is stored in [super::RouteAttribute::Table].
+    pub const RT_TABLE_COMPAT: u8 = 252;
     pub const RT_TABLE_MAIN: u8 = 254;
     pub const RT_TABLE_UNSPEC: u8 = 0;
 }
@@ -99,6 +105,7 @@ impl Emitable for RouteHeader {
 }
 
 #[derive(Debug, PartialEq, Eq, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[non_exhaustive]
 pub enum RouteProtocol {
     Unspec,
@@ -118,6 +125,7 @@ pub enum RouteProtocol {
     Mrouted,
     KeepAlived,
     Babel,
+    OpenR,
     Bgp,
     Isis,
     Ospf,
@@ -143,6 +151,7 @@ const RTPROT_DHCP: u8 = 16;
 const RTPROT_MROUTED: u8 = 17;
 const RTPROT_KEEPALIVED: u8 = 18;
 const RTPROT_BABEL: u8 = 42;
+const RTPROT_OPENR: u8 = 99;
 const RTPROT_BGP: u8 = 186;
 const RTPROT_ISIS: u8 = 187;
 const RTPROT_OSPF: u8 = 188;
@@ -169,6 +178,7 @@ impl From<RouteProtocol> for u8 {
             RouteProtocol::Mrouted => RTPROT_MROUTED,
             RouteProtocol::KeepAlived => RTPROT_KEEPALIVED,
             RouteProtocol::Babel => RTPROT_BABEL,
+            RouteProtocol::OpenR => RTPROT_OPENR,
             RouteProtocol::Bgp => RTPROT_BGP,
             RouteProtocol::Isis => RTPROT_ISIS,
             RouteProtocol::Ospf => RTPROT_OSPF,
@@ -199,6 +209,7 @@ impl From<u8> for RouteProtocol {
             RTPROT_MROUTED => RouteProtocol::Mrouted,
             RTPROT_KEEPALIVED => RouteProtocol::KeepAlived,
             RTPROT_BABEL => RouteProtocol::Babel,
+            RTPROT_OPENR => RouteProtocol::OpenR,
             RTPROT_BGP => RouteProtocol::Bgp,
             RTPROT_ISIS => RouteProtocol::Isis,
             RTPROT_OSPF => RouteProtocol::Ospf,
@@ -229,6 +240,7 @@ impl std::fmt::Display for RouteProtocol {
             Self::Mrouted => write!(f, "multicast_daemon"),
             Self::KeepAlived => write!(f, "keepalived_daemon"),
             Self::Babel => write!(f, "babel"),
+            Self::OpenR => write!(f, "openr"),
             Self::Bgp => write!(f, "bgp"),
             Self::Isis => write!(f, "isis"),
             Self::Ospf => write!(f, "ospf"),
@@ -275,6 +287,7 @@ const RT_SCOPE_HOST: u8 = 254;
 const RT_SCOPE_NOWHERE: u8 = 255;
 
 #[derive(Debug, PartialEq, Eq, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[non_exhaustive]
 pub enum RouteScope {
     Universe,
@@ -331,6 +344,7 @@ impl std::fmt::Display for RouteScope {
 }
 
 #[derive(Debug, PartialEq, Eq, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[non_exhaustive]
 pub enum RouteType {
     /// Unknown
@@ -7,7 +7,7 @@ use netlink_packet_utils::{
     DecodeError,
 };
 
-use super::RouteMplsIpTunnel;
+use super::{RouteMplsIpTunnel, RouteSeg6IpTunnel, RouteSeg6LocalIpTunnel};
 
 const LWTUNNEL_ENCAP_NONE: u16 = 0;
 const LWTUNNEL_ENCAP_MPLS: u16 = 1;
@@ -22,6 +22,7 @@ const LWTUNNEL_ENCAP_IOAM6: u16 = 9;
 const LWTUNNEL_ENCAP_XFRM: u16 = 10;
 
 #[derive(Debug, PartialEq, Eq, Clone, Copy, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[non_exhaustive]
 pub enum RouteLwEnCapType {
     #[default]
@@ -107,9 +108,13 @@ impl std::fmt::Display for RouteLwEnCapType {
 }
 
 #[derive(Debug, PartialEq, Eq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[non_exhaustive]
 pub enum RouteLwTunnelEncap {
     Mpls(RouteMplsIpTunnel),
+    Seg6(RouteSeg6IpTunnel),
+    Seg6Local(RouteSeg6LocalIpTunnel),
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_nla"))]
     Other(DefaultNla),
 }
 
@@ -117,6 +122,8 @@ impl Nla for RouteLwTunnelEncap {
     fn value_len(&self) -> usize {
         match self {
             Self::Mpls(v) => v.value_len(),
+            Self::Seg6(v) => v.value_len(),
+            Self::Seg6Local(v) => v.value_len(),
             Self::Other(v) => v.value_len(),
         }
     }
@@ -124,6 +131,8 @@ impl Nla for RouteLwTunnelEncap {
     fn emit_value(&self, buffer: &mut [u8]) {
         match self {
             Self::Mpls(v) => v.emit_value(buffer),
+            Self::Seg6(v) => v.emit_value(buffer),
+            Self::Seg6Local(v) => v.emit_value(buffer),
             Self::Other(v) => v.emit_value(buffer),
         }
     }
@@ -131,6 +140,8 @@ impl Nla for RouteLwTunnelEncap {
     fn kind(&self) -> u16 {
         match self {
             Self::Mpls(v) => v.kind(),
+            Self::Seg6(v) => v.kind(),
+            Self::Seg6Local(v) => v.kind(),
             Self::Other(v) => v.kind(),
         }
     }
@@ -149,12 +160,19 @@ where
             RouteLwEnCapType::Mpls => {
                 Self::Mpls(RouteMplsIpTunnel::parse(buf)?)
             }
+            RouteLwEnCapType::Seg6 => {
+                Self::Seg6(RouteSeg6IpTunnel::parse(buf)?)
+            }
+            RouteLwEnCapType::Seg6Local => {
+                Self::Seg6Local(RouteSeg6LocalIpTunnel::parse(buf)?)
+            }
             _ => Self::Other(DefaultNla::parse(buf)?),
         })
     }
 }
 
 #[derive(Debug, PartialEq, Eq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[non_exhaustive]
 pub(crate) struct VecRouteLwTunnelEncap(pub(crate) Vec<RouteLwTunnelEncap>);
 
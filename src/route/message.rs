@@ -1,5 +1,7 @@
 // SPDX-License-Identifier: MIT
 
+use std::net::{IpAddr, Ipv4Addr, Ipv6Addr};
+
 use anyhow::Context;
 use netlink_packet_utils::{
     traits::{Emitable, Parseable, ParseableParametrized},
@@ -7,17 +9,255 @@ use netlink_packet_utils::{
 };
 
 use super::{
-    super::AddressFamily, attribute::RTA_ENCAP_TYPE, RouteAttribute,
-    RouteHeader, RouteLwEnCapType, RouteMessageBuffer, RouteType,
+    super::AddressFamily, attribute::RTA_ENCAP_TYPE, RouteAddress,
+    RouteAttribute, RouteCacheInfo, RouteHeader, RouteLwEnCapType,
+    RouteMessageBuffer, RouteType, RouteVia,
 };
 
 #[derive(Debug, PartialEq, Eq, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[non_exhaustive]
 pub struct RouteMessage {
     pub header: RouteHeader,
     pub attributes: Vec<RouteAttribute>,
 }
 
+impl RouteMessage {
+    /// Firewall mark stored in the first [RouteAttribute::Mark], used by
+    /// fwmark-based route lookup.
+    pub fn mark(&self) -> Option<u32> {
+        self.attributes.iter().find_map(|attr| match attr {
+            RouteAttribute::Mark(mark) => Some(*mark),
+            _ => None,
+        })
+    }
+
+    /// ID of nexthop object stored in the first [RouteAttribute::NhId].
+    pub fn nexthop_id(&self) -> Option<u32> {
+        self.attributes.iter().find_map(|attr| match attr {
+            RouteAttribute::NhId(id) => Some(*id),
+            _ => None,
+        })
+    }
+
+    /// Gateway address stored in the first [RouteAttribute::Via], which may
+    /// use a different address family from the route itself, e.g. IPv4
+    /// route via IPv6 gateway. Returns `None` for non-IP gateways like the
+    /// `AF_PACKET` one of MPLS route.
+    pub fn via(&self) -> Option<(AddressFamily, IpAddr)> {
+        self.attributes.iter().find_map(|attr| match attr {
+            RouteAttribute::Via(RouteVia::Inet(ip)) => {
+                Some((AddressFamily::Inet, IpAddr::V4(*ip)))
+            }
+            RouteAttribute::Via(RouteVia::Inet6(ip)) => {
+                Some((AddressFamily::Inet6, IpAddr::V6(*ip)))
+            }
+            _ => None,
+        })
+    }
+
+    /// Route cache information stored in the first
+    /// [RouteAttribute::CacheInfo], see [RouteCacheInfo::expires()] for the
+    /// remaining lifetime of the route.
+    pub fn cache_info(&self) -> Option<&RouteCacheInfo> {
+        self.attributes.iter().find_map(|attr| match attr {
+            RouteAttribute::CacheInfo(info) => Some(info),
+            _ => None,
+        })
+    }
+
+    /// Routing table ID stored in [RouteAttribute::Table], or the 8 bits
+    /// table ID of header if not found.
+    pub fn table(&self) -> u32 {
+        self.attributes
+            .iter()
+            .find_map(|attr| match attr {
+                RouteAttribute::Table(table) => Some(*table),
+                _ => None,
+            })
+            .unwrap_or(self.header.table.into())
+    }
+
+    /// Set the routing table ID following the kernel convention: table ID
+    /// bigger than 255 is stored in [RouteAttribute::Table] with header
+    /// holding [RouteHeader::RT_TABLE_COMPAT], otherwise only the header is
+    /// used. Existing [RouteAttribute::Table] is removed.
+    pub fn set_table(&mut self, table: u32) {
+        self.attributes
+            .retain(|attr| !matches!(attr, RouteAttribute::Table(_)));
+        match u8::try_from(table) {
+            Ok(t) => self.header.table = t,
+            Err(_) => {
+                self.header.table = RouteHeader::RT_TABLE_COMPAT;
+                self.attributes.push(RouteAttribute::Table(table));
+            }
+        }
+    }
+
+    /// Set the destination prefix to both [RouteHeader] and
+    /// [RouteAttribute::Destination], the address family of header is set
+    /// using the address. Host bits of the address beyond the prefix length
+    /// are cleared, as kernel rejects IPv4 route holding them.
+    /// Existing [RouteAttribute::Destination] is removed.
+    ///
+    /// Returns error without changing the route if `prefix_length` is bigger
+    /// than the address length, or if the address family of header, when not
+    /// [AddressFamily::Unspec], or of existing [RouteAttribute::Source],
+    /// [RouteAttribute::Gateway] or [RouteAttribute::Via] conflicts with the
+    /// address. Hence changing the address family of route requires a new
+    /// [RouteMessage].
+    pub fn set_destination(
+        &mut self,
+        address: IpAddr,
+        prefix_length: u8,
+    ) -> Result<(), DecodeError> {
+        let address = ip_prefix(address, prefix_length)?;
+        let family = ip_family(&address);
+        self.check_family(family)
+            .map_err(|e| format!("destination {address} conflicts with {e}"))?;
+        self.header.address_family = family;
+        self.header.destination_prefix_length = prefix_length;
+        self.attributes
+            .retain(|attr| !matches!(attr, RouteAttribute::Destination(_)));
+        self.attributes
+            .push(RouteAttribute::Destination(address.into()));
+        Ok(())
+    }
+
+    /// Set the source prefix to both [RouteHeader] and
+    /// [RouteAttribute::Source], host bits are cleared the same way as
+    /// [RouteMessage::set_destination()]. Existing [RouteAttribute::Source]
+    /// is removed.
+    ///
+    /// Returns error without changing the route if `prefix_length` is bigger
+    /// than the address length, or if the address family conflicts with the
+    /// route the same way as [RouteMessage::set_destination()].
+    pub fn set_source(
+        &mut self,
+        address: IpAddr,
+        prefix_length: u8,
+    ) -> Result<(), DecodeError> {
+        let address = ip_prefix(address, prefix_length)?;
+        let family = ip_family(&address);
+        self.check_family(family)
+            .map_err(|e| format!("source {address} conflicts with {e}"))?;
+        self.header.address_family = family;
+        self.header.source_prefix_length = prefix_length;
+        self.attributes
+            .retain(|attr| !matches!(attr, RouteAttribute::Source(_)));
+        self.attributes.push(RouteAttribute::Source(address.into()));
+        Ok(())
+    }
+
+    /// Set the gateway as [RouteAttribute::Gateway] when sharing the address
+    /// family of route or as [RouteAttribute::Via] otherwise, e.g. IPv4
+    /// route via IPv6 gateway. The address family of header is set using
+    /// the gateway when it is [AddressFamily::Unspec], hence this should be
+    /// invoked after [RouteMessage::set_destination()]. Existing
+    /// [RouteAttribute::Gateway] and [RouteAttribute::Via] are removed.
+    ///
+    /// Returns error without changing the route for IPv4 gateway of IPv6
+    /// route, as kernel does not support [RouteAttribute::Via] for IPv6.
+    pub fn set_gateway(&mut self, address: IpAddr) -> Result<(), DecodeError> {
+        let family = ip_family(&address);
+        let route_family = match self.header.address_family {
+            AddressFamily::Unspec => family,
+            route_family => route_family,
+        };
+        if route_family == AddressFamily::Inet6 && family != route_family {
+            return Err(format!(
+                "gateway {address} conflicts with the route address family \
+                 {route_family:?}"
+            )
+            .into());
+        }
+        self.header.address_family = route_family;
+        self.attributes.retain(|attr| {
+            !matches!(attr, RouteAttribute::Gateway(_) | RouteAttribute::Via(_))
+        });
+        if route_family == family {
+            self.attributes
+                .push(RouteAttribute::Gateway(address.into()));
+        } else {
+            self.attributes
+                .push(RouteAttribute::Via(RouteVia::new(address)));
+        }
+        Ok(())
+    }
+
+    // Check whether the route could hold a destination or source prefix of
+    // specified address family, returning the conflicting part otherwise.
+    fn check_family(&self, family: AddressFamily) -> Result<(), String> {
+        if self.header.address_family != AddressFamily::Unspec
+            && self.header.address_family != family
+        {
+            return Err(format!(
+                "the route address family {:?}",
+                self.header.address_family
+            ));
+        }
+        let conflict = self.attributes.iter().find(|attr| match attr {
+            RouteAttribute::Source(RouteAddress::Inet(_))
+            | RouteAttribute::Destination(RouteAddress::Inet(_))
+            | RouteAttribute::Gateway(RouteAddress::Inet(_)) => {
+                family != AddressFamily::Inet
+            }
+            RouteAttribute::Source(RouteAddress::Inet6(_))
+            | RouteAttribute::Destination(RouteAddress::Inet6(_))
+            | RouteAttribute::Gateway(RouteAddress::Inet6(_)) => {
+                family != AddressFamily::Inet6
+            }
+            // Kernel does not support RTA_VIA for IPv6 route
+            RouteAttribute::Via(_) => family == AddressFamily::Inet6,
+            _ => false,
+        });
+        match conflict {
+            Some(attr) => Err(format!("{attr:?}")),
+            None => Ok(()),
+        }
+    }
+}
+
+fn ip_family(address: &IpAddr) -> AddressFamily {
+    match address {
+        IpAddr::V4(_) => AddressFamily::Inet,
+        IpAddr::V6(_) => AddressFamily::Inet6,
+    }
+}
+
+// Clear the host bits of address, fail if prefix length is bigger than
+// the address length
+fn ip_prefix(
+    address: IpAddr,
+    prefix_length: u8,
+) -> Result<IpAddr, DecodeError> {
+    let max_prefix_length = match address {
+        IpAddr::V4(_) => 32,
+        IpAddr::V6(_) => 128,
+    };
+    if prefix_length > max_prefix_length {
+        return Err(format!(
+            "invalid prefix length {prefix_length} of {address}, should be \
+             less or equal to {max_prefix_length}"
+        )
+        .into());
+    }
+    Ok(match address {
+        IpAddr::V4(ip) => {
+            let mask = u32::MAX
+                .checked_shl(32 - u32::from(prefix_length))
+                .unwrap_or(0);
+            IpAddr::V4(Ipv4Addr::from(u32::from(ip) & mask))
+        }
+        IpAddr::V6(ip) => {
+            let mask = u128::MAX
+                .checked_shl(128 - u32::from(prefix_length))
+                .unwrap_or(0);
+            IpAddr::V6(Ipv6Addr::from(u128::from(ip) & mask))
+        }
+    })
+}
+
 impl Emitable for RouteMessage {
     fn buffer_len(&self) -> usize {
         self.header.buffer_len() + self.attributes.as_slice().buffer_len()
@@ -30,6 +30,7 @@ const RTAX_CC_ALGO: u16 = 16;
 const RTAX_FASTOPEN_NO_COOKIE: u16 = 17;
 
 #[derive(Debug, PartialEq, Eq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[non_exhaustive]
 pub enum RouteMetric {
     Lock(u32),
@@ -49,6 +50,7 @@ pub enum RouteMetric {
     QuickAck(u32),
     CcAlgo(u32),
     FastopenNoCookie(u32),
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_nla"))]
     Other(DefaultNla),
 }
 
@@ -6,6 +6,7 @@ use netlink_packet_utils::{
 };
 
 #[derive(Debug, Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[non_exhaustive]
 pub struct RouteMfcStats {
     pub packets: u64,
@@ -5,7 +5,7 @@ mod attribute;
 mod cache_info;
 mod flags;
 mod header;
-mod lwtunnel;
+pub(crate) mod lwtunnel;
 mod message;
 pub(crate) mod metrics;
 mod mfc_stats;
@@ -13,12 +13,15 @@ mod mpls;
 mod next_hops;
 mod preference;
 mod realm;
+mod seg6;
+mod seg6_local;
 mod via;
 
 #[cfg(test)]
 mod tests;
 
 pub use self::address::RouteAddress;
+pub(crate) use self::attribute::attribute_name;
 pub use self::attribute::RouteAttribute;
 pub use self::cache_info::{RouteCacheInfo, RouteCacheInfoBuffer};
 pub use self::header::{
@@ -34,5 +37,10 @@ pub use self::next_hops::{
 };
 pub use self::preference::RoutePreference;
 pub use self::realm::RouteRealm;
+pub use self::seg6::{
+    RouteSeg6IpTunnel, Seg6Flags, Seg6Header, Seg6Hmac,
+    Seg6IpTunnelEncapBuffer, Seg6Mode, Seg6SrhBuffer, Seg6Tlv,
+};
+pub use self::seg6_local::{RouteSeg6LocalIpTunnel, Seg6LocalAction};
 pub use self::via::{RouteVia, RouteViaBuffer};
 pub use flags::RouteFlags;
@@ -14,10 +14,12 @@ const MPLS_IPTUNNEL_TTL: u16 = 2;
 /// Netlink attributes for `RTA_ENCAP` with `RTA_ENCAP_TYPE` set to
 /// `LWTUNNEL_ENCAP_MPLS`.
 #[derive(Debug, PartialEq, Eq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[non_exhaustive]
 pub enum RouteMplsIpTunnel {
     Destination(Vec<MplsLabel>),
     Ttl(u8),
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_nla"))]
     Other(DefaultNla),
 }
 
@@ -81,10 +83,26 @@ const MPLS_LS_S_SHIFT: u32 = 8;
 const MPLS_LS_TTL_MASK: u32 = 0x000000FF;
 const MPLS_LS_TTL_SHIFT: u32 = 0;
 
-#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+// The derived `Ord` compares fields in declaration order, which matches the
+// order of the encoded 32 bits label stack entry.
+#[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 /// MPLS label defined in RFC 3032 and updated by RFC 5462
+///
+/// On wire, each label stack entry is a 32 bits big endian integer:
+///
+/// ```text
+///  0                   1                   2                   3
+///  0 1 2 3 4 5 6 7 8 9 0 1 2 3 4 5 6 7 8 9 0 1 2 3 4 5 6 7 8 9 0 1
+/// +-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+
+/// |                Label                  | TC  |S|       TTL     |
+/// +-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+
+/// ```
+///
+/// The `From<u32>` and `Into<u32>` conversions use this encoded form, use
+/// [MplsLabel::new()] to create from the 20 bits label value only.
 pub struct MplsLabel {
-    /// label, 20 bytes
+    /// label, 20 bits
     pub label: u32,
     /// Traffic Class, 3 bits
     pub traffic_class: u8,
@@ -95,6 +113,31 @@ pub struct MplsLabel {
 }
 
 impl MplsLabel {
+    /// Largest label value fitting into 20 bits.
+    pub const MAX_LABEL: u32 = 0xFFFFF;
+
+    /// Create MPLS label with traffic class, bottom of stack and TTL cleared.
+    ///
+    /// Returns error if `label` does not fit into 20 bits. Kernel expects the
+    /// bottom of stack bit set on the last label of `RTA_NEWDST` and
+    /// `MPLS_IPTUNNEL_DST`.
+    pub fn new(label: u32) -> Result<Self, DecodeError> {
+        if label > Self::MAX_LABEL {
+            Err(format!(
+                "invalid MPLS label {label}, should be less or equal to {}",
+                Self::MAX_LABEL
+            )
+            .into())
+        } else {
+            Ok(Self {
+                label,
+                traffic_class: 0,
+                bottom_of_stack: false,
+                ttl: 0,
+            })
+        }
+    }
+
     pub(crate) fn parse(payload: &[u8]) -> Result<Self, DecodeError> {
         if payload.len() == 4 {
             Ok(Self::from(u32::from_be_bytes([
@@ -176,6 +219,7 @@ const MPLS_TTL_PROP_ENABLED: u8 = 1;
 const MPLS_TTL_PROP_DISABLED: u8 = 2;
 
 #[derive(Debug, PartialEq, Eq, Clone, Default, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[non_exhaustive]
 pub enum RouteMplsTtlPropagation {
     #[default]
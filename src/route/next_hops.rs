@@ -8,7 +8,8 @@ use netlink_packet_utils::{
 };
 
 use super::{
-    super::AddressFamily, RouteAttribute, RouteLwEnCapType, RouteType,
+    super::AddressFamily, attribute::RTA_ENCAP_TYPE, RouteAttribute,
+    RouteLwEnCapType, RouteType,
 };
 
 pub(crate) const RTNH_F_DEAD: u8 = 1;
@@ -21,6 +22,7 @@ pub(crate) const RTNH_F_TRAP: u8 = 64;
 
 bitflags! {
     #[derive(Clone, Eq, PartialEq, Debug, Copy, Default)]
+    #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
     #[non_exhaustive]
     pub struct RouteNextHopFlags: u8 {
         const Dead = RTNH_F_DEAD;
@@ -59,6 +61,13 @@ impl<T: AsRef<[u8]>> RouteNextHopBuffer<T> {
             )
             .into());
         }
+        if (self.length() as usize) < PAYLOAD_OFFSET {
+            return Err(format!(
+                "invalid RouteNextHopBuffer: rtnh_len {} < {PAYLOAD_OFFSET}",
+                self.length(),
+            )
+            .into());
+        }
         if len < self.length() as usize {
             return Err(format!(
                 "invalid RouteNextHopBuffer: length {} < {}",
@@ -82,6 +91,7 @@ impl<'a, T: AsRef<[u8]> + ?Sized> RouteNextHopBuffer<&'a T> {
 }
 
 #[derive(Debug, Clone, Eq, PartialEq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[non_exhaustive]
 pub struct RouteNextHop {
     /// Next-hop flags
@@ -138,6 +148,22 @@ impl<'a, T: AsRef<[u8]> + 'a>
         ),
     ) -> Result<Self, DecodeError> {
         let mut nlas = vec![];
+        // Each next-hop could carry its own RTA_ENCAP_TYPE which, like in
+        // the route message, is placed __after__ RTA_ENCAP.
+        let mut encap_type = encap_type;
+        for nla in buf.attributes().flatten() {
+            if nla.kind() == RTA_ENCAP_TYPE {
+                if let Ok(RouteAttribute::EncapType(v)) =
+                    RouteAttribute::parse_with_param(
+                        &nla,
+                        (address_family, route_type, encap_type),
+                    )
+                {
+                    encap_type = v;
+                    break;
+                }
+            }
+        }
         for nla_buf in buf.attributes() {
             nlas.push(RouteAttribute::parse_with_param(
                 &nla_buf?,
@@ -5,7 +5,10 @@ const ICMPV6_ROUTER_PREF_MEDIUM: u8 = 0x0;
 const ICMPV6_ROUTER_PREF_HIGH: u8 = 0x1;
 const ICMPV6_ROUTER_PREF_INVALID: u8 = 0x2;
 
+/// ICMPv6 router preference (RFC 4191) of `RTA_PREF`, encoded as the
+/// kernel `ICMPV6_ROUTER_PREF_*`.
 #[derive(Debug, PartialEq, Eq, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[non_exhaustive]
 pub enum RoutePreference {
     Low,
@@ -0,0 +1,436 @@
+// SPDX-License-Identifier: MIT
+
+use std::net::Ipv6Addr;
+
+use anyhow::Context;
+use byteorder::{BigEndian, ByteOrder};
+use netlink_packet_utils::{
+    nla::{DefaultNla, Nla, NlaBuffer},
+    parsers::parse_u32_be,
+    traits::{Emitable, Parseable},
+    DecodeError, EncodeError,
+};
+
+use crate::ip::parse_ipv6_addr;
+
+const SEG6_IPTUNNEL_SRH: u16 = 1;
+
+/// Netlink attributes for `RTA_ENCAP` with `RTA_ENCAP_TYPE` set to
+/// `LWTUNNEL_ENCAP_SEG6`.
+#[derive(Debug, PartialEq, Eq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[non_exhaustive]
+pub enum RouteSeg6IpTunnel {
+    Srh(Seg6Header),
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_nla"))]
+    Other(DefaultNla),
+}
+
+impl Nla for RouteSeg6IpTunnel {
+    fn value_len(&self) -> usize {
+        match self {
+            Self::Srh(v) => v.buffer_len(),
+            Self::Other(attr) => attr.value_len(),
+        }
+    }
+
+    fn kind(&self) -> u16 {
+        match self {
+            Self::Srh(_) => SEG6_IPTUNNEL_SRH,
+            Self::Other(attr) => attr.kind(),
+        }
+    }
+
+    fn emit_value(&self, buffer: &mut [u8]) {
+        match self {
+            Self::Srh(v) => v.emit(buffer),
+            Self::Other(attr) => attr.emit_value(buffer),
+        }
+    }
+}
+
+impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>>
+    for RouteSeg6IpTunnel
+{
+    fn parse(buf: &NlaBuffer<&'a T>) -> Result<Self, DecodeError> {
+        let payload = buf.value();
+        Ok(match buf.kind() {
+            SEG6_IPTUNNEL_SRH => Self::Srh(
+                Seg6Header::parse(
+                    &Seg6IpTunnelEncapBuffer::new_checked(payload)
+                        .context("invalid SEG6_IPTUNNEL_SRH value")?,
+                )
+                .context("invalid SEG6_IPTUNNEL_SRH value")?,
+            ),
+            _ => Self::Other(
+                DefaultNla::parse(buf)
+                    .context("invalid NLA value (unknown type) value")?,
+            ),
+        })
+    }
+}
+
+const SEG6_IPTUN_MODE_INLINE: i32 = 0;
+const SEG6_IPTUN_MODE_ENCAP: i32 = 1;
+const SEG6_IPTUN_MODE_L2ENCAP: i32 = 2;
+const SEG6_IPTUN_MODE_ENCAP_RED: i32 = 3;
+const SEG6_IPTUN_MODE_L2ENCAP_RED: i32 = 4;
+
+/// How the segment routing header is applied to the packet.
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[non_exhaustive]
+pub enum Seg6Mode {
+    /// Insert the SRH into the original IPv6 packet.
+    Inline,
+    /// Encapsulate the packet into an outer IPv6 header with the SRH.
+    #[default]
+    Encap,
+    /// Encapsulate the L2 frame into an outer IPv6 header with the SRH.
+    L2Encap,
+    /// Like [Seg6Mode::Encap] with reduced SRH omitting the first segment.
+    EncapRed,
+    /// Like [Seg6Mode::L2Encap] with reduced SRH omitting the first segment.
+    L2EncapRed,
+    Other(i32),
+}
+
+impl From<i32> for Seg6Mode {
+    fn from(d: i32) -> Self {
+        match d {
+            SEG6_IPTUN_MODE_INLINE => Self::Inline,
+            SEG6_IPTUN_MODE_ENCAP => Self::Encap,
+            SEG6_IPTUN_MODE_L2ENCAP => Self::L2Encap,
+            SEG6_IPTUN_MODE_ENCAP_RED => Self::EncapRed,
+            SEG6_IPTUN_MODE_L2ENCAP_RED => Self::L2EncapRed,
+            _ => Self::Other(d),
+        }
+    }
+}
+
+impl From<Seg6Mode> for i32 {
+    fn from(v: Seg6Mode) -> i32 {
+        match v {
+            Seg6Mode::Inline => SEG6_IPTUN_MODE_INLINE,
+            Seg6Mode::Encap => SEG6_IPTUN_MODE_ENCAP,
+            Seg6Mode::L2Encap => SEG6_IPTUN_MODE_L2ENCAP,
+            Seg6Mode::EncapRed => SEG6_IPTUN_MODE_ENCAP_RED,
+            Seg6Mode::L2EncapRed => SEG6_IPTUN_MODE_L2ENCAP_RED,
+            Seg6Mode::Other(d) => d,
+        }
+    }
+}
+
+const SR6_FLAG1_HMAC: u8 = 1 << 3;
+
+bitflags! {
+    /// Flags of the IPv6 segment routing header.
+    #[derive(Clone, Eq, PartialEq, Debug, Copy, Default)]
+    #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+    #[non_exhaustive]
+    pub struct Seg6Flags: u8 {
+        /// The SRH carries a [Seg6Tlv::Hmac].
+        const Hmac = SR6_FLAG1_HMAC;
+        const _ = !0;
+    }
+}
+
+// Length of `int mode` of `struct seg6_iptunnel_encap`
+const SEG6_MODE_LEN: usize = 4;
+// Length of `struct ipv6_sr_hdr` without segments and TLVs
+const SRH_FIXED_LEN: usize = 8;
+const SRH_SEGMENT_LEN: usize = 16;
+const IPV6_SRCRT_TYPE_4: u8 = 4;
+
+/// The `struct seg6_iptunnel_encap` of [RouteSeg6IpTunnel::Srh], holding the
+/// encapsulation mode and the IPv6 segment routing header (SRH) defined in
+/// RFC 8754.
+///
+/// The `hdrlen`, `type` and `first_segment` fields of the SRH are computed
+/// from [Seg6Header::segments] and [Seg6Header::tlvs] when emitting, see
+/// [Seg6Header::validate()].
+#[derive(Debug, PartialEq, Eq, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[non_exhaustive]
+pub struct Seg6Header {
+    pub mode: Seg6Mode,
+    /// Filled by kernel on transmit, 0 in requests.
+    pub next_header: u8,
+    pub segments_left: u8,
+    pub flags: Seg6Flags,
+    pub tag: u16,
+    /// Segment list in wire order, which is the reverse order of the path:
+    /// the last segment to visit comes first.
+    pub segments: Vec<Ipv6Addr>,
+    pub tlvs: Vec<Seg6Tlv>,
+}
+
+impl Seg6Header {
+    /// The first [Seg6Tlv::Hmac] of the SRH, if any.
+    pub fn hmac(&self) -> Option<&Seg6Hmac> {
+        self.tlvs.iter().find_map(|tlv| {
+            if let Seg6Tlv::Hmac(hmac) = tlv {
+                Some(hmac)
+            } else {
+                None
+            }
+        })
+    }
+
+    /// Check that the SRH could be emitted: its length is stored in units
+    /// of 8 bytes, so the [Seg6Header::tlvs] must add up to a multiple of 8
+    /// bytes (pad them with [Seg6Tlv::Pad1] or a PadN [Seg6Tlv::Other] if
+    /// needed), and it holds at least one and at most 256 segments.
+    pub fn validate(&self) -> Result<(), EncodeError> {
+        let srh_len = Seg6Srh(self).buffer_len();
+        if srh_len % 8 != 0 {
+            return Err(format!(
+                "SRH length {srh_len} is not a multiple of 8 bytes"
+            )
+            .into());
+        }
+        if srh_len / 8 > u8::MAX as usize + 1 {
+            return Err(format!("SRH length {srh_len} is too long").into());
+        }
+        if self.segments.is_empty() || self.segments.len() > 256 {
+            return Err(format!(
+                "SRH should hold 1 to 256 segments, got {}",
+                self.segments.len()
+            )
+            .into());
+        }
+        Ok(())
+    }
+}
+
+// kernel struct `seg6_iptunnel_encap`
+buffer!(Seg6IpTunnelEncapBuffer(SEG6_MODE_LEN + SRH_FIXED_LEN) {
+    mode: (i32, 0..SEG6_MODE_LEN),
+    srh: (slice, SEG6_MODE_LEN..),
+});
+
+// kernel struct `ipv6_sr_hdr`
+buffer!(Seg6SrhBuffer(SRH_FIXED_LEN) {
+    next_header: (u8, 0),
+    hdrlen: (u8, 1),
+    kind: (u8, 2),
+    segments_left: (u8, 3),
+    first_segment: (u8, 4),
+    flags: (u8, 5),
+    tag: (u16, 6..8),
+    payload: (slice, SRH_FIXED_LEN..),
+});
+
+/// Emits the `struct seg6_iptunnel_encap`.
+///
+/// The SRH should pass [Seg6Header::validate()], otherwise its `hdrlen`
+/// does not cover the emitted TLVs.
+impl Emitable for Seg6Header {
+    fn buffer_len(&self) -> usize {
+        SEG6_MODE_LEN + Seg6Srh(self).buffer_len()
+    }
+
+    fn emit(&self, buffer: &mut [u8]) {
+        let mut packet = Seg6IpTunnelEncapBuffer::new(buffer);
+        packet.set_mode(self.mode.into());
+        Seg6Srh(self).emit(packet.srh_mut());
+    }
+}
+
+impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<Seg6IpTunnelEncapBuffer<&'a T>>
+    for Seg6Header
+{
+    fn parse(
+        buf: &Seg6IpTunnelEncapBuffer<&'a T>,
+    ) -> Result<Self, DecodeError> {
+        Ok(Self {
+            mode: buf.mode().into(),
+            ..Self::parse(&Seg6SrhBuffer::new_checked(buf.srh())?)?
+        })
+    }
+}
+
+/// Parse the SRH without the mode, leaving [Seg6Header::mode] as default.
+impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<Seg6SrhBuffer<&'a T>>
+    for Seg6Header
+{
+    fn parse(buf: &Seg6SrhBuffer<&'a T>) -> Result<Self, DecodeError> {
+        let srh_len = (buf.hdrlen() as usize + 1) * 8;
+        let segment_count = buf.first_segment() as usize + 1;
+        let segments_end = SRH_FIXED_LEN + segment_count * SRH_SEGMENT_LEN;
+        let got = buf.into_inner().as_ref().len();
+        if got < srh_len || srh_len < segments_end {
+            return Err(format!(
+                "invalid SRH length {srh_len} holding {segment_count} \
+                 segments, got {got} bytes",
+            )
+            .into());
+        }
+        let payload = &buf.payload()[..srh_len - SRH_FIXED_LEN];
+        let (raw_segments, mut rest) =
+            payload.split_at(segments_end - SRH_FIXED_LEN);
+        let mut segments = Vec::new();
+        for raw in raw_segments.chunks(SRH_SEGMENT_LEN) {
+            segments.push(parse_ipv6_addr(raw)?);
+        }
+        let mut tlvs = Vec::new();
+        while !rest.is_empty() {
+            let tlv = Seg6Tlv::parse(rest)?;
+            rest = &rest[tlv.buffer_len()..];
+            tlvs.push(tlv);
+        }
+        Ok(Self {
+            mode: Seg6Mode::default(),
+            next_header: buf.next_header(),
+            segments_left: buf.segments_left(),
+            flags: Seg6Flags::from_bits_retain(buf.flags()),
+            tag: u16::from_be(buf.tag()),
+            segments,
+            tlvs,
+        })
+    }
+}
+
+// The SRH of a [Seg6Header] without the mode, as used by `SEG6_LOCAL_SRH`.
+pub(crate) struct Seg6Srh<'a>(pub(crate) &'a Seg6Header);
+
+impl Emitable for Seg6Srh<'_> {
+    fn buffer_len(&self) -> usize {
+        SRH_FIXED_LEN
+            + self.0.segments.len() * SRH_SEGMENT_LEN
+            + self
+                .0
+                .tlvs
+                .iter()
+                .map(|tlv| tlv.buffer_len())
+                .sum::<usize>()
+    }
+
+    fn emit(&self, buffer: &mut [u8]) {
+        debug_assert!(self.0.validate().is_ok(), "{:?}", self.0.validate());
+        let srh = self.0;
+        let srh_len = self.buffer_len();
+        let mut packet = Seg6SrhBuffer::new(buffer);
+        packet.set_next_header(srh.next_header);
+        packet.set_hdrlen((srh_len / 8).saturating_sub(1) as u8);
+        packet.set_kind(IPV6_SRCRT_TYPE_4);
+        packet.set_segments_left(srh.segments_left);
+        packet.set_first_segment(srh.segments.len().saturating_sub(1) as u8);
+        packet.set_flags(srh.flags.bits());
+        packet.set_tag(srh.tag.to_be());
+        let payload = packet.payload_mut();
+        let (raw_segments, raw_tlvs) =
+            payload.split_at_mut(srh.segments.len() * SRH_SEGMENT_LEN);
+        for (segment, raw) in srh
+            .segments
+            .iter()
+            .zip(raw_segments.chunks_exact_mut(SRH_SEGMENT_LEN))
+        {
+            raw.copy_from_slice(&segment.octets());
+        }
+        let mut offset = 0;
+        for tlv in &srh.tlvs {
+            tlv.emit(&mut raw_tlvs[offset..offset + tlv.buffer_len()]);
+            offset += tlv.buffer_len();
+        }
+    }
+}
+
+const SR6_TLV_PAD1: u8 = 0;
+const SR6_TLV_HMAC: u8 = 5;
+const SEG6_HMAC_FIELD_LEN: usize = 32;
+// Length of `struct sr6_tlv_hmac` excluding the type and length bytes
+const SR6_TLV_HMAC_LEN: usize = 6 + SEG6_HMAC_FIELD_LEN;
+
+/// TLV trailing the segment list of the SRH.
+#[derive(Debug, PartialEq, Eq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[non_exhaustive]
+pub enum Seg6Tlv {
+    /// Single byte padding without length.
+    Pad1,
+    Hmac(Seg6Hmac),
+    /// Other TLV holding its type and value.
+    Other(
+        u8,
+        #[cfg_attr(feature = "serde", serde(with = "crate::serde_hex"))]
+        Vec<u8>,
+    ),
+}
+
+impl Emitable for Seg6Tlv {
+    fn buffer_len(&self) -> usize {
+        match self {
+            Self::Pad1 => 1,
+            Self::Hmac(_) => 2 + SR6_TLV_HMAC_LEN,
+            Self::Other(_, value) => 2 + value.len(),
+        }
+    }
+
+    fn emit(&self, buffer: &mut [u8]) {
+        match self {
+            Self::Pad1 => buffer[0] = SR6_TLV_PAD1,
+            Self::Hmac(v) => {
+                buffer[0] = SR6_TLV_HMAC;
+                buffer[1] = SR6_TLV_HMAC_LEN as u8;
+                // reserved
+                buffer[2..4].fill(0);
+                BigEndian::write_u32(&mut buffer[4..8], v.key_id);
+                buffer[8..].copy_from_slice(&v.hmac);
+            }
+            Self::Other(kind, value) => {
+                buffer[0] = *kind;
+                buffer[1] = value.len() as u8;
+                buffer[2..].copy_from_slice(value.as_slice());
+            }
+        }
+    }
+}
+
+impl Parseable<[u8]> for Seg6Tlv {
+    fn parse(payload: &[u8]) -> Result<Self, DecodeError> {
+        if payload[0] == SR6_TLV_PAD1 {
+            return Ok(Self::Pad1);
+        }
+        let value = payload
+            .get(1)
+            .and_then(|len| payload.get(2..2 + *len as usize))
+            .ok_or_else(|| {
+                DecodeError::from(format!(
+                    "truncated SRH TLV of type {}",
+                    payload[0]
+                ))
+            })?;
+        Ok(match payload[0] {
+            SR6_TLV_HMAC if value.len() == SR6_TLV_HMAC_LEN => {
+                let mut hmac = [0u8; SEG6_HMAC_FIELD_LEN];
+                hmac.copy_from_slice(&value[6..]);
+                Self::Hmac(Seg6Hmac {
+                    key_id: parse_u32_be(&value[2..6])?,
+                    hmac,
+                })
+            }
+            kind => Self::Other(kind, value.to_vec()),
+        })
+    }
+}
+
+/// HMAC TLV of the SRH, kernel `struct sr6_tlv_hmac`.
+#[derive(Debug, PartialEq, Eq, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[non_exhaustive]
+pub struct Seg6Hmac {
+    /// ID of the HMAC key configured by `ip sr hmac set`.
+    pub key_id: u32,
+    /// HMAC computed by the kernel on transmit, all zero in requests.
+    pub hmac: [u8; SEG6_HMAC_FIELD_LEN],
+}
+
+impl Seg6Hmac {
+    pub fn new(key_id: u32) -> Self {
+        Self {
+            key_id,
+            ..Default::default()
+        }
+    }
+}
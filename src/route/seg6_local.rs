@@ -0,0 +1,252 @@
+// SPDX-License-Identifier: MIT
+
+use std::net::{Ipv4Addr, Ipv6Addr};
+
+use anyhow::Context;
+use byteorder::{ByteOrder, NativeEndian};
+use netlink_packet_utils::{
+    nla::{DefaultNla, Nla, NlaBuffer},
+    parsers::parse_u32,
+    traits::{Emitable, Parseable},
+    DecodeError,
+};
+
+use super::{
+    seg6::{Seg6Srh, Seg6SrhBuffer},
+    Seg6Header,
+};
+use crate::ip::{parse_ipv4_addr, parse_ipv6_addr};
+
+const SEG6_LOCAL_ACTION: u16 = 1;
+const SEG6_LOCAL_SRH: u16 = 2;
+const SEG6_LOCAL_TABLE: u16 = 3;
+const SEG6_LOCAL_NH4: u16 = 4;
+const SEG6_LOCAL_NH6: u16 = 5;
+const SEG6_LOCAL_IIF: u16 = 6;
+const SEG6_LOCAL_OIF: u16 = 7;
+// const SEG6_LOCAL_BPF: u16 = 8;
+const SEG6_LOCAL_VRFTABLE: u16 = 9;
+// const SEG6_LOCAL_COUNTERS: u16 = 10;
+// const SEG6_LOCAL_FLAVORS: u16 = 11;
+
+/// Netlink attributes for `RTA_ENCAP` with `RTA_ENCAP_TYPE` set to
+/// `LWTUNNEL_ENCAP_SEG6_LOCAL`, describing the SRv6 behavior applied to
+/// packets destined to the local segment.
+#[derive(Debug, PartialEq, Eq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[non_exhaustive]
+pub enum RouteSeg6LocalIpTunnel {
+    Action(Seg6LocalAction),
+    /// SRH pushed by [Seg6LocalAction::EndB6] and
+    /// [Seg6LocalAction::EndB6Encap]. The [Seg6Header::mode] is not part of
+    /// this attribute, it is ignored on emit and left as default on parse.
+    Srh(Seg6Header),
+    /// Routing table to lookup after decapsulation.
+    Table(u32),
+    /// IPv4 next hop of [Seg6LocalAction::EndDx4].
+    Nh4(Ipv4Addr),
+    /// IPv6 next hop of [Seg6LocalAction::EndX] and
+    /// [Seg6LocalAction::EndDx6].
+    Nh6(Ipv6Addr),
+    Iif(u32),
+    /// Output interface index of [Seg6LocalAction::EndDx2].
+    Oif(u32),
+    /// VRF routing table to lookup after decapsulation, used by
+    /// [Seg6LocalAction::EndDt4], [Seg6LocalAction::EndDt6] and
+    /// [Seg6LocalAction::EndDt46].
+    VrfTable(u32),
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_nla"))]
+    Other(DefaultNla),
+}
+
+impl Nla for RouteSeg6LocalIpTunnel {
+    fn value_len(&self) -> usize {
+        match self {
+            Self::Action(_)
+            | Self::Table(_)
+            | Self::Iif(_)
+            | Self::Oif(_)
+            | Self::VrfTable(_) => 4,
+            Self::Srh(v) => Seg6Srh(v).buffer_len(),
+            Self::Nh4(_) => 4,
+            Self::Nh6(_) => 16,
+            Self::Other(attr) => attr.value_len(),
+        }
+    }
+
+    fn kind(&self) -> u16 {
+        match self {
+            Self::Action(_) => SEG6_LOCAL_ACTION,
+            Self::Srh(_) => SEG6_LOCAL_SRH,
+            Self::Table(_) => SEG6_LOCAL_TABLE,
+            Self::Nh4(_) => SEG6_LOCAL_NH4,
+            Self::Nh6(_) => SEG6_LOCAL_NH6,
+            Self::Iif(_) => SEG6_LOCAL_IIF,
+            Self::Oif(_) => SEG6_LOCAL_OIF,
+            Self::VrfTable(_) => SEG6_LOCAL_VRFTABLE,
+            Self::Other(attr) => attr.kind(),
+        }
+    }
+
+    fn emit_value(&self, buffer: &mut [u8]) {
+        match self {
+            Self::Action(v) => NativeEndian::write_u32(buffer, (*v).into()),
+            Self::Srh(v) => Seg6Srh(v).emit(buffer),
+            Self::Table(v)
+            | Self::Iif(v)
+            | Self::Oif(v)
+            | Self::VrfTable(v) => NativeEndian::write_u32(buffer, *v),
+            Self::Nh4(v) => buffer.copy_from_slice(&v.octets()),
+            Self::Nh6(v) => buffer.copy_from_slice(&v.octets()),
+            Self::Other(attr) => attr.emit_value(buffer),
+        }
+    }
+}
+
+impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>>
+    for RouteSeg6LocalIpTunnel
+{
+    fn parse(buf: &NlaBuffer<&'a T>) -> Result<Self, DecodeError> {
+        let payload = buf.value();
+        Ok(match buf.kind() {
+            SEG6_LOCAL_ACTION => Self::Action(
+                parse_u32(payload)
+                    .context("invalid SEG6_LOCAL_ACTION value")?
+                    .into(),
+            ),
+            SEG6_LOCAL_SRH => Self::Srh(
+                Seg6Header::parse(
+                    &Seg6SrhBuffer::new_checked(payload)
+                        .context("invalid SEG6_LOCAL_SRH value")?,
+                )
+                .context("invalid SEG6_LOCAL_SRH value")?,
+            ),
+            SEG6_LOCAL_TABLE => Self::Table(
+                parse_u32(payload).context("invalid SEG6_LOCAL_TABLE value")?,
+            ),
+            SEG6_LOCAL_NH4 => Self::Nh4(
+                parse_ipv4_addr(payload)
+                    .context("invalid SEG6_LOCAL_NH4 value")?,
+            ),
+            SEG6_LOCAL_NH6 => Self::Nh6(
+                parse_ipv6_addr(payload)
+                    .context("invalid SEG6_LOCAL_NH6 value")?,
+            ),
+            SEG6_LOCAL_IIF => Self::Iif(
+                parse_u32(payload).context("invalid SEG6_LOCAL_IIF value")?,
+            ),
+            SEG6_LOCAL_OIF => Self::Oif(
+                parse_u32(payload).context("invalid SEG6_LOCAL_OIF value")?,
+            ),
+            SEG6_LOCAL_VRFTABLE => Self::VrfTable(
+                parse_u32(payload)
+                    .context("invalid SEG6_LOCAL_VRFTABLE value")?,
+            ),
+            _ => Self::Other(
+                DefaultNla::parse(buf)
+                    .context("invalid NLA value (unknown type) value")?,
+            ),
+        })
+    }
+}
+
+const SEG6_LOCAL_ACTION_END: u32 = 1;
+const SEG6_LOCAL_ACTION_END_X: u32 = 2;
+const SEG6_LOCAL_ACTION_END_T: u32 = 3;
+const SEG6_LOCAL_ACTION_END_DX2: u32 = 4;
+const SEG6_LOCAL_ACTION_END_DX6: u32 = 5;
+const SEG6_LOCAL_ACTION_END_DX4: u32 = 6;
+const SEG6_LOCAL_ACTION_END_DT6: u32 = 7;
+const SEG6_LOCAL_ACTION_END_DT4: u32 = 8;
+const SEG6_LOCAL_ACTION_END_B6: u32 = 9;
+const SEG6_LOCAL_ACTION_END_B6_ENCAP: u32 = 10;
+const SEG6_LOCAL_ACTION_END_BM: u32 = 11;
+const SEG6_LOCAL_ACTION_END_S: u32 = 12;
+const SEG6_LOCAL_ACTION_END_AS: u32 = 13;
+const SEG6_LOCAL_ACTION_END_AM: u32 = 14;
+const SEG6_LOCAL_ACTION_END_BPF: u32 = 15;
+const SEG6_LOCAL_ACTION_END_DT46: u32 = 16;
+
+/// SRv6 behaviors defined in RFC 8986.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[non_exhaustive]
+pub enum Seg6LocalAction {
+    /// `End`: advance to the next segment.
+    End,
+    /// `End.X`: advance to the next segment and forward to the next hop.
+    EndX,
+    /// `End.T`: advance to the next segment and lookup in the table.
+    EndT,
+    /// `End.DX2`: decapsulate and forward the L2 frame to the interface.
+    EndDx2,
+    /// `End.DX6`: decapsulate and forward to the IPv6 next hop.
+    EndDx6,
+    /// `End.DX4`: decapsulate and forward to the IPv4 next hop.
+    EndDx4,
+    /// `End.DT6`: decapsulate and lookup in the IPv6 table.
+    EndDt6,
+    /// `End.DT4`: decapsulate and lookup in the IPv4 table.
+    EndDt4,
+    /// `End.B6`: insert the SRH.
+    EndB6,
+    /// `End.B6.Encaps`: encapsulate into outer IPv6 header with the SRH.
+    EndB6Encap,
+    EndBm,
+    EndS,
+    EndAs,
+    EndAm,
+    /// `End.BPF`: run the BPF program.
+    EndBpf,
+    /// `End.DT46`: decapsulate and lookup in the IPv4 or IPv6 table.
+    EndDt46,
+    Other(u32),
+}
+
+impl From<u32> for Seg6LocalAction {
+    fn from(d: u32) -> Self {
+        match d {
+            SEG6_LOCAL_ACTION_END => Self::End,
+            SEG6_LOCAL_ACTION_END_X => Self::EndX,
+            SEG6_LOCAL_ACTION_END_T => Self::EndT,
+            SEG6_LOCAL_ACTION_END_DX2 => Self::EndDx2,
+            SEG6_LOCAL_ACTION_END_DX6 => Self::EndDx6,
+            SEG6_LOCAL_ACTION_END_DX4 => Self::EndDx4,
+            SEG6_LOCAL_ACTION_END_DT6 => Self::EndDt6,
+            SEG6_LOCAL_ACTION_END_DT4 => Self::EndDt4,
+            SEG6_LOCAL_ACTION_END_B6 => Self::EndB6,
+            SEG6_LOCAL_ACTION_END_B6_ENCAP => Self::EndB6Encap,
+            SEG6_LOCAL_ACTION_END_BM => Self::EndBm,
+            SEG6_LOCAL_ACTION_END_S => Self::EndS,
+            SEG6_LOCAL_ACTION_END_AS => Self::EndAs,
+            SEG6_LOCAL_ACTION_END_AM => Self::EndAm,
+            SEG6_LOCAL_ACTION_END_BPF => Self::EndBpf,
+            SEG6_LOCAL_ACTION_END_DT46 => Self::EndDt46,
+            _ => Self::Other(d),
+        }
+    }
+}
+
+impl From<Seg6LocalAction> for u32 {
+    fn from(v: Seg6LocalAction) -> u32 {
+        match v {
+            Seg6LocalAction::End => SEG6_LOCAL_ACTION_END,
+            Seg6LocalAction::EndX => SEG6_LOCAL_ACTION_END_X,
+            Seg6LocalAction::EndT => SEG6_LOCAL_ACTION_END_T,
+            Seg6LocalAction::EndDx2 => SEG6_LOCAL_ACTION_END_DX2,
+            Seg6LocalAction::EndDx6 => SEG6_LOCAL_ACTION_END_DX6,
+            Seg6LocalAction::EndDx4 => SEG6_LOCAL_ACTION_END_DX4,
+            Seg6LocalAction::EndDt6 => SEG6_LOCAL_ACTION_END_DT6,
+            Seg6LocalAction::EndDt4 => SEG6_LOCAL_ACTION_END_DT4,
+            Seg6LocalAction::EndB6 => SEG6_LOCAL_ACTION_END_B6,
+            Seg6LocalAction::EndB6Encap => SEG6_LOCAL_ACTION_END_B6_ENCAP,
+            Seg6LocalAction::EndBm => SEG6_LOCAL_ACTION_END_BM,
+            Seg6LocalAction::EndS => SEG6_LOCAL_ACTION_END_S,
+            Seg6LocalAction::EndAs => SEG6_LOCAL_ACTION_END_AS,
+            Seg6LocalAction::EndAm => SEG6_LOCAL_ACTION_END_AM,
+            Seg6LocalAction::EndBpf => SEG6_LOCAL_ACTION_END_BPF,
+            Seg6LocalAction::EndDt46 => SEG6_LOCAL_ACTION_END_DT46,
+            Seg6LocalAction::Other(d) => d,
+        }
+    }
+}
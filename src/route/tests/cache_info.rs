@@ -2,6 +2,7 @@
 
 use std::net::Ipv6Addr;
 use std::str::FromStr;
+use std::time::Duration;
 
 use netlink_packet_utils::traits::{Emitable, Parseable};
 
@@ -113,4 +114,29 @@ fn test_ipv6_route_cache_info() {
     expected.emit(&mut buf);
 
     assert_eq!(buf, raw);
+
+    let cache_info = expected.cache_info().unwrap();
+    assert_eq!(cache_info.expires(), Some(Duration::from_millis(3382170)));
+}
+
+#[test]
+fn test_route_cache_info_expires() {
+    let mut info = RouteCacheInfo {
+        clntref: 0,
+        last_use: 0,
+        expires: 338217,
+        error: 0,
+        used: 0,
+        id: 0,
+        ts: 0,
+        ts_age: 0,
+    };
+    assert_eq!(info.expires(), Some(Duration::from_millis(3382170)));
+
+    info.expires = 0;
+    assert_eq!(info.expires(), None);
+
+    // Already expired route holds negative clock ticks
+    info.expires = (-5i32) as u32;
+    assert_eq!(info.expires(), Some(Duration::ZERO));
 }
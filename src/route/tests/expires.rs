@@ -2,13 +2,14 @@
 
 use std::net::Ipv6Addr;
 use std::str::FromStr;
+use std::time::Duration;
 
 use netlink_packet_utils::traits::{Emitable, Parseable};
 
 use crate::route::flags::RouteFlags;
 use crate::route::{
-    RouteAttribute, RouteHeader, RouteMessage, RouteMessageBuffer,
-    RouteProtocol, RouteScope, RouteType,
+    RouteAttribute, RouteCacheInfo, RouteHeader, RouteMessage,
+    RouteMessageBuffer, RouteProtocol, RouteScope, RouteType,
 };
 use crate::AddressFamily;
 
@@ -55,3 +56,136 @@ fn test_ipv6_route_expires() {
 
     assert_eq!(buf, raw);
 }
+
+// Hand-crafted RTM_NEWROUTE payload equivalent to the kernel reply of
+//   ip -6 route show 2001:db8:1::/64
+// right after the route above was added, the remaining 3000 seconds
+// lifetime is reported as 300000 USER_HZ clock ticks.
+#[test]
+fn test_ipv6_route_expires_cache_info() {
+    let raw = vec![
+        0x0a, 0x40, 0x00, 0x00, 0xfe, 0x03, 0x00, 0x01, 0x00, 0x00, 0x00, 0x00,
+        0x14, 0x00, 0x01, 0x00, // length 20, RTA_DST
+        0x20, 0x01, 0x0d, 0xb8, 0x00, 0x01, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x00, // 2001:db8:1::
+        0x08, 0x00, 0x04, 0x00, // length 8, RTA_OIF
+        0x02, 0x00, 0x00, 0x00, // 2
+        0x24, 0x00, 0x0c, 0x00, // length 36, RTA_CACHEINFO
+        0x00, 0x00, 0x00, 0x00, // rta_clntref
+        0x00, 0x00, 0x00, 0x00, // rta_lastuse
+        0xe0, 0x93, 0x04, 0x00, // rta_expires 300000
+        0x00, 0x00, 0x00, 0x00, // rta_error
+        0x00, 0x00, 0x00, 0x00, // rta_used
+        0x00, 0x00, 0x00, 0x00, // rta_id
+        0x00, 0x00, 0x00, 0x00, // rta_ts
+        0x00, 0x00, 0x00, 0x00, // rta_tsage
+    ];
+
+    let expected = RouteMessage {
+        header: RouteHeader {
+            address_family: AddressFamily::Inet6,
+            destination_prefix_length: 64,
+            source_prefix_length: 0,
+            tos: 0,
+            table: 254,
+            protocol: RouteProtocol::Boot,
+            scope: RouteScope::Universe,
+            kind: RouteType::Unicast,
+            flags: RouteFlags::empty(),
+        },
+        attributes: vec![
+            RouteAttribute::Destination(
+                Ipv6Addr::from_str("2001:db8:1::").unwrap().into(),
+            ),
+            RouteAttribute::Oif(2),
+            RouteAttribute::CacheInfo(RouteCacheInfo {
+                clntref: 0,
+                last_use: 0,
+                expires: 300000,
+                error: 0,
+                used: 0,
+                id: 0,
+                ts: 0,
+                ts_age: 0,
+            }),
+        ],
+    };
+
+    assert_eq!(
+        expected,
+        RouteMessage::parse(&RouteMessageBuffer::new(&raw)).unwrap()
+    );
+
+    let mut buf = vec![0; expected.buffer_len()];
+
+    expected.emit(&mut buf);
+
+    assert_eq!(buf, raw);
+
+    assert_eq!(
+        expected.cache_info().and_then(|info| info.expires()),
+        Some(Duration::from_secs(3000))
+    );
+}
+
+// Hand-crafted RTM_NEWROUTE payload equivalent to the one generated by
+//   ip route add 2001:db8:2::/64 dev eth0 expires 300
+// with eth0 holding interface index 2.
+#[test]
+fn test_build_ipv6_route_expires_300() {
+    let raw = vec![
+        0x0a, 0x40, 0x00, 0x00, 0xfe, 0x03, 0x00, 0x01, 0x00, 0x00, 0x00, 0x00,
+        0x14, 0x00, 0x01, 0x00, // length 20, RTA_DST
+        0x20, 0x01, 0x0d, 0xb8, 0x00, 0x02, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x00, // 2001:db8:2::
+        0x08, 0x00, 0x17, 0x00, // length 8, RTA_EXPIRES
+        0x2c, 0x01, 0x00, 0x00, // 300
+        0x08, 0x00, 0x04, 0x00, // length 8, RTA_OIF
+        0x02, 0x00, 0x00, 0x00, // 2
+    ];
+
+    let route = RouteMessage {
+        header: RouteHeader {
+            address_family: AddressFamily::Inet6,
+            destination_prefix_length: 64,
+            table: RouteHeader::RT_TABLE_MAIN,
+            protocol: RouteProtocol::Boot,
+            scope: RouteScope::Universe,
+            kind: RouteType::Unicast,
+            ..Default::default()
+        },
+        attributes: vec![
+            RouteAttribute::Destination(
+                Ipv6Addr::from_str("2001:db8:2::").unwrap().into(),
+            ),
+            RouteAttribute::expires(Duration::from_secs(300)).unwrap(),
+            RouteAttribute::Oif(2),
+        ],
+    };
+    assert_eq!(route.attributes[1], RouteAttribute::Expires(300));
+
+    let mut buf = vec![0; route.buffer_len()];
+    route.emit(&mut buf);
+    assert_eq!(buf, raw);
+
+    assert_eq!(
+        route,
+        RouteMessage::parse(&RouteMessageBuffer::new(&raw)).unwrap()
+    );
+}
+
+#[test]
+fn test_route_expires_overflow() {
+    assert_eq!(
+        RouteAttribute::expires(Duration::from_millis(1500)).unwrap(),
+        RouteAttribute::Expires(1)
+    );
+    assert!(
+        RouteAttribute::expires(Duration::from_secs(u64::from(u32::MAX)))
+            .is_ok()
+    );
+    assert!(RouteAttribute::expires(Duration::from_secs(
+        u64::from(u32::MAX) + 1
+    ))
+    .is_err());
+}
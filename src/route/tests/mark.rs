@@ -0,0 +1,58 @@
+// SPDX-License-Identifier: MIT
+
+use std::net::Ipv4Addr;
+use std::str::FromStr;
+
+use netlink_packet_utils::traits::{Emitable, Parseable};
+
+use crate::route::flags::RouteFlags;
+use crate::route::{
+    RouteAttribute, RouteHeader, RouteMessage, RouteMessageBuffer,
+    RouteProtocol, RouteScope, RouteType,
+};
+use crate::AddressFamily;
+
+// Hand-crafted RTM_GETROUTE payload equivalent to:
+//      ip route get 198.51.100.1 mark 0x20
+#[test]
+fn test_ipv4_route_get_mark() {
+    let raw = vec![
+        0x02, 0x20, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x08, 0x00, 0x01, 0x00, // length 8, RTA_DST
+        0xc6, 0x33, 0x64, 0x01, // 198.51.100.1
+        0x08, 0x00, 0x10, 0x00, // length 8, RTA_MARK
+        0x20, 0x00, 0x00, 0x00, // 0x20
+    ];
+
+    let expected = RouteMessage {
+        header: RouteHeader {
+            address_family: AddressFamily::Inet,
+            destination_prefix_length: 32,
+            source_prefix_length: 0,
+            tos: 0,
+            table: 0,
+            protocol: RouteProtocol::Unspec,
+            scope: RouteScope::Universe,
+            kind: RouteType::Unspec,
+            flags: RouteFlags::empty(),
+        },
+        attributes: vec![
+            RouteAttribute::Destination(
+                Ipv4Addr::from_str("198.51.100.1").unwrap().into(),
+            ),
+            RouteAttribute::Mark(0x20),
+        ],
+    };
+
+    assert_eq!(
+        expected,
+        RouteMessage::parse(&RouteMessageBuffer::new(&raw)).unwrap()
+    );
+    assert_eq!(expected.mark(), Some(0x20));
+
+    let mut buf = vec![0; expected.buffer_len()];
+
+    expected.emit(&mut buf);
+
+    assert_eq!(buf, raw);
+}
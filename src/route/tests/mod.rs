@@ -7,14 +7,30 @@ mod expires;
 #[cfg(test)]
 mod loopback;
 #[cfg(test)]
+mod mark;
+#[cfg(test)]
 mod mpls;
 #[cfg(test)]
 mod multipath;
 #[cfg(test)]
+mod nh_id;
+#[cfg(test)]
+mod preference;
+#[cfg(test)]
+mod prefix;
+#[cfg(test)]
+mod protocol;
+#[cfg(test)]
 mod realm;
 #[cfg(test)]
 mod route_flags;
 #[cfg(test)]
+mod seg6;
+#[cfg(test)]
+mod seg6_local;
+#[cfg(test)]
+mod table;
+#[cfg(test)]
 mod uid;
 #[cfg(test)]
 mod via;
@@ -352,3 +352,35 @@ fn test_mpls_ttl_propagate() {
 
     assert_eq!(buf, raw);
 }
+
+#[test]
+fn test_mpls_label_sort() {
+    let raw: [u32; 4] = [0x00065140, 0x0001f1ff, 0x00064140, 0x00065040];
+    let mut labels: Vec<MplsLabel> =
+        raw.into_iter().map(MplsLabel::from).collect();
+    labels.sort();
+    let mut expected = raw.to_vec();
+    expected.sort();
+    assert_eq!(
+        labels.into_iter().map(u32::from).collect::<Vec<u32>>(),
+        expected
+    );
+}
+
+#[test]
+fn test_mpls_label_new() {
+    let label = MplsLabel::new(0xFFFFF).unwrap();
+    assert_eq!(
+        label,
+        MplsLabel {
+            label: 0xFFFFF,
+            traffic_class: 0,
+            bottom_of_stack: false,
+            ttl: 0,
+        }
+    );
+    assert_eq!(u32::from(label), 0xFFFFF000);
+
+    assert_eq!(MplsLabel::new(0).unwrap().label, 0);
+    assert!(MplsLabel::new(0x100000).is_err());
+}
@@ -1 +1,162 @@
 // SPDX-License-Identifier: MIT
+
+use std::net::Ipv4Addr;
+use std::str::FromStr;
+
+use netlink_packet_utils::traits::{Emitable, Parseable};
+
+use crate::route::flags::RouteFlags;
+use crate::route::{
+    MplsLabel, RouteAttribute, RouteHeader, RouteLwEnCapType,
+    RouteLwTunnelEncap, RouteMessage, RouteMessageBuffer, RouteMplsIpTunnel,
+    RouteNextHop, RouteNextHopFlags, RouteProtocol, RouteScope, RouteType,
+};
+use crate::AddressFamily;
+
+// Hand-crafted RTM_NEWROUTE payload equivalent to:
+//      ip route add 198.51.100.0/24 \
+//          nexthop encap mpls 100 via 192.0.2.2 dev dummy1 \
+//          nexthop encap mpls 200 via 192.0.2.3 dev dummy1
+// with dummy1 holding interface index 10.
+#[test]
+fn test_multipath_mpls_encap() {
+    let raw = vec![
+        0x02, // address family AF_INET
+        0x18, // destination prefix length 24
+        0x00, // source prefix length
+        0x00, // tos
+        0xfe, // table main
+        0x03, // protocol boot
+        0x00, // scope universe
+        0x01, // type unicast
+        0x00, 0x00, 0x00, 0x00, // flags
+        0x08, 0x00, 0x01, 0x00, // length 8, RTA_DST
+        0xc6, 0x33, 0x64, 0x00, // 198.51.100.0
+        0x4c, 0x00, 0x09, 0x00, // length 76, RTA_MULTIPATH
+        // First next-hop
+        0x24, 0x00, // rtnh_len 36
+        0x00, // rtnh_flags
+        0x00, // rtnh_hops
+        0x0a, 0x00, 0x00, 0x00, // rtnh_ifindex 10
+        0x08, 0x00, 0x05, 0x00, // length 8, RTA_GATEWAY
+        0xc0, 0x00, 0x02, 0x02, // 192.0.2.2
+        0x0c, 0x00, 0x16, 0x00, // length 12, RTA_ENCAP
+        0x08, 0x00, 0x01, 0x00, // length 8, MPLS_IPTUNNEL_DST
+        0x00, 0x06, 0x41, 0x00, // label 100, bottom of stack
+        0x06, 0x00, 0x15, 0x00, // length 6, RTA_ENCAP_TYPE
+        0x01, 0x00, 0x00, 0x00, // LWTUNNEL_ENCAP_MPLS and padding
+        // Second next-hop
+        0x24, 0x00, // rtnh_len 36
+        0x00, // rtnh_flags
+        0x00, // rtnh_hops
+        0x0a, 0x00, 0x00, 0x00, // rtnh_ifindex 10
+        0x08, 0x00, 0x05, 0x00, // length 8, RTA_GATEWAY
+        0xc0, 0x00, 0x02, 0x03, // 192.0.2.3
+        0x0c, 0x00, 0x16, 0x00, // length 12, RTA_ENCAP
+        0x08, 0x00, 0x01, 0x00, // length 8, MPLS_IPTUNNEL_DST
+        0x00, 0x0c, 0x81, 0x00, // label 200, bottom of stack
+        0x06, 0x00, 0x15, 0x00, // length 6, RTA_ENCAP_TYPE
+        0x01, 0x00, 0x00, 0x00, // LWTUNNEL_ENCAP_MPLS and padding
+    ];
+
+    let next_hop = |gateway: &str, label: u32| RouteNextHop {
+        interface_index: 10,
+        attributes: vec![
+            RouteAttribute::Gateway(
+                Ipv4Addr::from_str(gateway).unwrap().into(),
+            ),
+            RouteAttribute::Encap(vec![RouteLwTunnelEncap::Mpls(
+                RouteMplsIpTunnel::Destination(vec![MplsLabel {
+                    label,
+                    traffic_class: 0,
+                    bottom_of_stack: true,
+                    ttl: 0,
+                }]),
+            )]),
+            RouteAttribute::EncapType(RouteLwEnCapType::Mpls),
+        ],
+        ..Default::default()
+    };
+
+    let expected = RouteMessage {
+        header: RouteHeader {
+            address_family: AddressFamily::Inet,
+            destination_prefix_length: 24,
+            source_prefix_length: 0,
+            tos: 0,
+            table: 254,
+            protocol: RouteProtocol::Boot,
+            scope: RouteScope::Universe,
+            kind: RouteType::Unicast,
+            flags: RouteFlags::empty(),
+        },
+        attributes: vec![
+            RouteAttribute::Destination(
+                Ipv4Addr::from_str("198.51.100.0").unwrap().into(),
+            ),
+            RouteAttribute::MultiPath(vec![
+                next_hop("192.0.2.2", 100),
+                next_hop("192.0.2.3", 200),
+            ]),
+        ],
+    };
+
+    assert_eq!(
+        expected,
+        RouteMessage::parse(&RouteMessageBuffer::new(&raw)).unwrap()
+    );
+
+    let mut buf = vec![0; expected.buffer_len()];
+
+    expected.emit(&mut buf);
+
+    assert_eq!(buf, raw);
+
+    if let RouteAttribute::MultiPath(next_hops) = &expected.attributes[1] {
+        for nh in next_hops {
+            assert_eq!(nh.flags, RouteNextHopFlags::empty());
+            assert_eq!(nh.buffer_len(), 36);
+        }
+    }
+}
+
+fn multipath_route_with_payload(payload: &[u8]) -> Vec<u8> {
+    let mut raw = vec![
+        0x02, 0x18, 0x00, 0x00, 0xfe, 0x03, 0x00, 0x01, 0x00, 0x00, 0x00, 0x00,
+    ];
+    raw.extend_from_slice(&((payload.len() + 4) as u16).to_ne_bytes());
+    raw.extend_from_slice(&[0x09, 0x00]); // RTA_MULTIPATH
+    raw.extend_from_slice(payload);
+    raw
+}
+
+#[test]
+fn test_multipath_zeroed_next_hop() {
+    let raw = multipath_route_with_payload(&[0u8; 8]);
+
+    assert!(RouteMessage::parse(&RouteMessageBuffer::new(&raw)).is_err());
+}
+
+#[test]
+fn test_multipath_next_hop_length_underflow() {
+    let raw = multipath_route_with_payload(&[
+        0x03, 0x00, // rtnh_len 3
+        0x00, // rtnh_flags
+        0x00, // rtnh_hops
+        0x0a, 0x00, 0x00, 0x00, // rtnh_ifindex 10
+    ]);
+
+    assert!(RouteMessage::parse(&RouteMessageBuffer::new(&raw)).is_err());
+}
+
+#[test]
+fn test_multipath_next_hop_length_overflow() {
+    let raw = multipath_route_with_payload(&[
+        0x10, 0x00, // rtnh_len 16
+        0x00, // rtnh_flags
+        0x00, // rtnh_hops
+        0x0a, 0x00, 0x00, 0x00, // rtnh_ifindex 10
+    ]);
+
+    assert!(RouteMessage::parse(&RouteMessageBuffer::new(&raw)).is_err());
+}
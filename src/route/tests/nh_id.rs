@@ -0,0 +1,136 @@
+// SPDX-License-Identifier: MIT
+
+use std::net::Ipv4Addr;
+use std::str::FromStr;
+
+use netlink_packet_utils::traits::{Emitable, Parseable};
+
+use crate::route::flags::RouteFlags;
+use crate::route::{
+    RouteAttribute, RouteHeader, RouteMessage, RouteMessageBuffer,
+    RouteNextHop, RouteProtocol, RouteScope, RouteType,
+};
+use crate::AddressFamily;
+
+// Captured RTM_NEWROUTE request sent by iproute2 6.1 for the last command
+// below, netlink header removed:
+//      ip nexthop add id 1 via 192.0.2.2 dev veth9a
+//      ip nexthop add id 2 via 192.0.2.3 dev veth9a
+//      ip nexthop add id 10 group 1/2
+//      ip route add 198.51.100.0/24 nhid 10
+#[test]
+fn test_ipv4_route_nexthop_group_id() {
+    let raw = vec![
+        0x02, 0x18, 0x00, 0x00, 0xfe, 0x03, 0x00, 0x01, 0x00, 0x00, 0x00, 0x00,
+        0x08, 0x00, 0x01, 0x00, // length 8, RTA_DST
+        0xc6, 0x33, 0x64, 0x00, // 198.51.100.0
+        0x08, 0x00, 0x1e, 0x00, // length 8, RTA_NH_ID
+        0x0a, 0x00, 0x00, 0x00, // 10
+    ];
+
+    let expected = RouteMessage {
+        header: RouteHeader {
+            address_family: AddressFamily::Inet,
+            destination_prefix_length: 24,
+            source_prefix_length: 0,
+            tos: 0,
+            table: RouteHeader::RT_TABLE_MAIN,
+            protocol: RouteProtocol::Boot,
+            scope: RouteScope::Universe,
+            kind: RouteType::Unicast,
+            flags: RouteFlags::empty(),
+        },
+        attributes: vec![
+            RouteAttribute::Destination(
+                Ipv4Addr::from_str("198.51.100.0").unwrap().into(),
+            ),
+            RouteAttribute::NhId(10),
+        ],
+    };
+
+    assert_eq!(
+        expected,
+        RouteMessage::parse(&RouteMessageBuffer::new(&raw)).unwrap()
+    );
+    assert_eq!(expected.nexthop_id(), Some(10));
+
+    let mut buf = vec![0; expected.buffer_len()];
+
+    expected.emit(&mut buf);
+
+    assert_eq!(buf, raw);
+}
+
+// Captured RTM_NEWROUTE dump reply of the route added above with veth9a
+// holding interface index 11, netlink header removed. Kernel expands the
+// members of the nexthop group in RTA_MULTIPATH.
+#[test]
+fn test_ipv4_route_nexthop_group_id_dump() {
+    let raw = vec![
+        0x02, 0x18, 0x00, 0x00, 0xfe, 0x03, 0x00, 0x01, 0x00, 0x00, 0x00, 0x00,
+        0x08, 0x00, 0x0f, 0x00, // length 8, RTA_TABLE
+        0xfe, 0x00, 0x00, 0x00, // 254
+        0x08, 0x00, 0x01, 0x00, // length 8, RTA_DST
+        0xc6, 0x33, 0x64, 0x00, // 198.51.100.0
+        0x08, 0x00, 0x1e, 0x00, // length 8, RTA_NH_ID
+        0x0a, 0x00, 0x00, 0x00, // 10
+        0x24, 0x00, 0x09, 0x00, // length 36, RTA_MULTIPATH
+        0x10, 0x00, // rtnh_len 16
+        0x00, // rtnh_flags
+        0x00, // rtnh_hops
+        0x0b, 0x00, 0x00, 0x00, // rtnh_ifindex 11
+        0x08, 0x00, 0x05, 0x00, // length 8, RTA_GATEWAY
+        0xc0, 0x00, 0x02, 0x02, // 192.0.2.2
+        0x10, 0x00, // rtnh_len 16
+        0x00, // rtnh_flags
+        0x00, // rtnh_hops
+        0x0b, 0x00, 0x00, 0x00, // rtnh_ifindex 11
+        0x08, 0x00, 0x05, 0x00, // length 8, RTA_GATEWAY
+        0xc0, 0x00, 0x02, 0x03, // 192.0.2.3
+    ];
+
+    let next_hop = |gateway: &str| RouteNextHop {
+        interface_index: 11,
+        attributes: vec![RouteAttribute::Gateway(
+            Ipv4Addr::from_str(gateway).unwrap().into(),
+        )],
+        ..Default::default()
+    };
+
+    let expected = RouteMessage {
+        header: RouteHeader {
+            address_family: AddressFamily::Inet,
+            destination_prefix_length: 24,
+            source_prefix_length: 0,
+            tos: 0,
+            table: RouteHeader::RT_TABLE_MAIN,
+            protocol: RouteProtocol::Boot,
+            scope: RouteScope::Universe,
+            kind: RouteType::Unicast,
+            flags: RouteFlags::empty(),
+        },
+        attributes: vec![
+            RouteAttribute::Table(254),
+            RouteAttribute::Destination(
+                Ipv4Addr::from_str("198.51.100.0").unwrap().into(),
+            ),
+            RouteAttribute::NhId(10),
+            RouteAttribute::MultiPath(vec![
+                next_hop("192.0.2.2"),
+                next_hop("192.0.2.3"),
+            ]),
+        ],
+    };
+
+    assert_eq!(
+        expected,
+        RouteMessage::parse(&RouteMessageBuffer::new(&raw)).unwrap()
+    );
+    assert_eq!(expected.nexthop_id(), Some(10));
+
+    let mut buf = vec![0; expected.buffer_len()];
+
+    expected.emit(&mut buf);
+
+    assert_eq!(buf, raw);
+}
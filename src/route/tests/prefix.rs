@@ -0,0 +1,235 @@
+// SPDX-License-Identifier: MIT
+
+use std::net::{IpAddr, Ipv4Addr, Ipv6Addr};
+use std::str::FromStr;
+
+use netlink_packet_utils::traits::{Emitable, Parseable};
+
+use crate::route::{
+    RouteAttribute, RouteMessage, RouteMessageBuffer, RouteProtocol,
+    RouteScope, RouteType, RouteVia,
+};
+use crate::AddressFamily;
+
+// Hand-crafted RTM_NEWROUTE payload equivalent to:
+//      ip route add 198.51.100.0/24 via 192.0.2.1
+// with the RTA_TABLE and RTA_OIF omitted.
+#[test]
+fn test_ipv4_route_set_destination() {
+    let raw = vec![
+        0x02, // AF_INET
+        0x18, // destination prefix length 24
+        0x00, // source prefix length 0
+        0x00, // tos
+        0xfe, // table main
+        0x03, // protocol boot
+        0x00, // scope universe
+        0x01, // type unicast
+        0x00, 0x00, 0x00, 0x00, // flags
+        0x08, 0x00, // length 8
+        0x01, 0x00, // RTA_DST
+        0xc6, 0x33, 0x64, 0x00, // 198.51.100.0
+        0x08, 0x00, // length 8
+        0x05, 0x00, // RTA_GATEWAY
+        0xc0, 0x00, 0x02, 0x01, // 192.0.2.1
+    ];
+
+    let mut route = RouteMessage::default();
+    route.header.table = 254;
+    route.header.protocol = RouteProtocol::Boot;
+    route.header.scope = RouteScope::Universe;
+    route.header.kind = RouteType::Unicast;
+    // Host bits should be cleared
+    route
+        .set_destination(IpAddr::from_str("198.51.100.23").unwrap(), 24)
+        .unwrap();
+    route
+        .set_gateway(IpAddr::from_str("192.0.2.1").unwrap())
+        .unwrap();
+
+    assert_eq!(route.header.address_family, AddressFamily::Inet);
+    assert_eq!(route.header.destination_prefix_length, 24);
+
+    let mut buf = vec![0; route.buffer_len()];
+    route.emit(&mut buf);
+    assert_eq!(buf, raw);
+
+    assert_eq!(
+        route,
+        RouteMessage::parse(&RouteMessageBuffer::new(&raw)).unwrap()
+    );
+}
+
+#[test]
+fn test_ipv6_route_set_destination_and_source() {
+    let mut route = RouteMessage::default();
+    route
+        .set_destination(IpAddr::from_str("2001:db8:1::1").unwrap(), 64)
+        .unwrap();
+    route
+        .set_source(IpAddr::from_str("2001:db8:2:ffff::1").unwrap(), 48)
+        .unwrap();
+    route
+        .set_gateway(IpAddr::from_str("fe80::1").unwrap())
+        .unwrap();
+
+    assert_eq!(route.header.address_family, AddressFamily::Inet6);
+    assert_eq!(route.header.destination_prefix_length, 64);
+    assert_eq!(route.header.source_prefix_length, 48);
+    assert_eq!(
+        route.attributes,
+        vec![
+            RouteAttribute::Destination(
+                Ipv6Addr::from_str("2001:db8:1::").unwrap().into()
+            ),
+            RouteAttribute::Source(
+                Ipv6Addr::from_str("2001:db8:2::").unwrap().into()
+            ),
+            RouteAttribute::Gateway(
+                Ipv6Addr::from_str("fe80::1").unwrap().into()
+            ),
+        ]
+    );
+
+    let mut buf = vec![0; route.buffer_len()];
+    route.emit(&mut buf);
+    assert_eq!(
+        route,
+        RouteMessage::parse(&RouteMessageBuffer::new(&buf)).unwrap()
+    );
+}
+
+#[test]
+fn test_route_set_destination_replace() {
+    let mut route = RouteMessage::default();
+    route
+        .set_destination(IpAddr::from_str("192.0.2.1").unwrap(), 32)
+        .unwrap();
+    route
+        .set_destination(IpAddr::from_str("10.1.2.3").unwrap(), 0)
+        .unwrap();
+
+    assert_eq!(route.header.address_family, AddressFamily::Inet);
+    assert_eq!(route.header.destination_prefix_length, 0);
+    assert_eq!(
+        route.attributes,
+        vec![RouteAttribute::Destination(Ipv4Addr::UNSPECIFIED.into())]
+    );
+
+    // The address family of route cannot be changed
+    let expected = route.clone();
+    assert!(route
+        .set_destination(IpAddr::from_str("2001:db8::1").unwrap(), 128)
+        .is_err());
+    assert_eq!(route, expected);
+}
+
+#[test]
+fn test_ipv4_route_set_gateway_inet6() {
+    let mut route = RouteMessage::default();
+    route
+        .set_destination(IpAddr::from_str("192.0.2.1").unwrap(), 32)
+        .unwrap();
+    route
+        .set_gateway(IpAddr::from_str("192.0.2.254").unwrap())
+        .unwrap();
+    route
+        .set_gateway(IpAddr::from_str("2001:db8:1::").unwrap())
+        .unwrap();
+
+    assert_eq!(route.header.address_family, AddressFamily::Inet);
+    assert_eq!(
+        route.attributes,
+        vec![
+            RouteAttribute::Destination(
+                Ipv4Addr::from_str("192.0.2.1").unwrap().into()
+            ),
+            RouteAttribute::Via(RouteVia::Inet6(
+                Ipv6Addr::from_str("2001:db8:1::").unwrap()
+            )),
+        ]
+    );
+    assert_eq!(
+        route.via(),
+        Some((
+            AddressFamily::Inet6,
+            IpAddr::from_str("2001:db8:1::").unwrap()
+        ))
+    );
+}
+
+#[test]
+fn test_route_set_prefix_invalid() {
+    let mut route = RouteMessage::default();
+    route
+        .set_destination(IpAddr::from_str("192.0.2.1").unwrap(), 32)
+        .unwrap();
+    let expected = route.clone();
+
+    assert!(route
+        .set_destination(IpAddr::from_str("2001:db8::1").unwrap(), 200)
+        .is_err());
+    assert!(route
+        .set_source(IpAddr::from_str("192.0.2.1").unwrap(), 33)
+        .is_err());
+    // IPv6 source for IPv4 route
+    assert!(route
+        .set_source(IpAddr::from_str("2001:db8::1").unwrap(), 64)
+        .is_err());
+    assert_eq!(route, expected);
+
+    // IPv6 destination for route holding IPv4 source
+    route
+        .set_source(IpAddr::from_str("192.0.2.0").unwrap(), 24)
+        .unwrap();
+    let expected = route.clone();
+    assert!(route
+        .set_destination(IpAddr::from_str("2001:db8::1").unwrap(), 128)
+        .is_err());
+    assert_eq!(route, expected);
+}
+
+#[test]
+fn test_ipv6_route_set_gateway_inet() {
+    let mut route = RouteMessage::default();
+    route
+        .set_destination(IpAddr::from_str("2001:db8::").unwrap(), 64)
+        .unwrap();
+    route
+        .set_gateway(IpAddr::from_str("fe80::1").unwrap())
+        .unwrap();
+    let expected = route.clone();
+
+    // Kernel does not support RTA_VIA for IPv6 route
+    assert!(route
+        .set_gateway(IpAddr::from_str("192.0.2.1").unwrap())
+        .is_err());
+    assert_eq!(route, expected);
+}
+
+#[test]
+fn test_route_set_prefix_conflict_with_gateway() {
+    // Address family of header is left unset
+    let mut route = RouteMessage::default();
+    route.attributes.push(RouteAttribute::Via(RouteVia::Inet(
+        Ipv4Addr::from_str("192.0.2.1").unwrap(),
+    )));
+    let expected = route.clone();
+    assert!(route
+        .set_destination(IpAddr::from_str("2001:db8::").unwrap(), 64)
+        .is_err());
+    assert!(route
+        .set_source(IpAddr::from_str("2001:db8::").unwrap(), 64)
+        .is_err());
+    assert_eq!(route, expected);
+
+    let mut route = RouteMessage::default();
+    route.attributes.push(RouteAttribute::Gateway(
+        Ipv6Addr::from_str("fe80::1").unwrap().into(),
+    ));
+    let expected = route.clone();
+    assert!(route
+        .set_destination(IpAddr::from_str("192.0.2.0").unwrap(), 24)
+        .is_err());
+    assert_eq!(route, expected);
+}
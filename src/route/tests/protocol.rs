@@ -0,0 +1,68 @@
+// SPDX-License-Identifier: MIT
+
+use std::net::Ipv4Addr;
+
+use netlink_packet_utils::traits::{Emitable, Parseable};
+
+use crate::route::{
+    RouteAttribute, RouteFlags, RouteHeader, RouteMessage, RouteMessageBuffer,
+    RouteProtocol, RouteScope, RouteType,
+};
+use crate::AddressFamily;
+
+#[test]
+// Capture(netlink message header removed) of kernel reply to
+//   ip -4 route show proto bgp
+// after a route installed the same way as a BGP daemon does:
+//   ip route add 198.51.100.0/24 dev ifb0 proto bgp
+// with ifb0 holding interface index 2.
+fn test_ipv4_route_protocol_bgp() {
+    let raw = vec![
+        0x02, 0x18, 0x00, 0x00, 0xfe, 0xba, 0xfd, 0x01, 0x00, 0x00, 0x00, 0x00,
+        0x08, 0x00, 0x0f, 0x00, 0xfe, 0x00, 0x00, 0x00, 0x08, 0x00, 0x01, 0x00,
+        0xc6, 0x33, 0x64, 0x00, 0x08, 0x00, 0x04, 0x00, 0x02, 0x00, 0x00, 0x00,
+    ];
+
+    let expected = RouteMessage {
+        header: RouteHeader {
+            address_family: AddressFamily::Inet,
+            destination_prefix_length: 24,
+            source_prefix_length: 0,
+            tos: 0,
+            table: RouteHeader::RT_TABLE_MAIN,
+            protocol: RouteProtocol::Bgp,
+            scope: RouteScope::Link,
+            kind: RouteType::Unicast,
+            flags: RouteFlags::empty(),
+        },
+        attributes: vec![
+            RouteAttribute::Table(254),
+            RouteAttribute::Destination(Ipv4Addr::new(198, 51, 100, 0).into()),
+            RouteAttribute::Oif(2),
+        ],
+    };
+
+    assert_eq!(
+        expected,
+        RouteMessage::parse(&RouteMessageBuffer::new(&raw)).unwrap()
+    );
+
+    let mut buf = vec![0; expected.buffer_len()];
+    expected.emit(&mut buf);
+    assert_eq!(buf, raw);
+}
+
+#[test]
+fn test_route_protocol_daemon_names() {
+    assert_eq!(RouteProtocol::from(186), RouteProtocol::Bgp);
+    assert_eq!(u8::from(RouteProtocol::Bgp), 186);
+    assert_eq!(RouteProtocol::Bgp.to_string(), "bgp");
+
+    assert_eq!(RouteProtocol::from(188), RouteProtocol::Ospf);
+    assert_eq!(u8::from(RouteProtocol::Ospf), 188);
+    assert_eq!(RouteProtocol::Ospf.to_string(), "ospf");
+
+    assert_eq!(RouteProtocol::from(99), RouteProtocol::OpenR);
+    assert_eq!(RouteProtocol::from(200), RouteProtocol::Other(200));
+    assert_eq!(RouteProtocol::Other(200).to_string(), "other(200)");
+}
@@ -0,0 +1,124 @@
+// SPDX-License-Identifier: MIT
+
+use std::net::Ipv6Addr;
+use std::str::FromStr;
+
+use netlink_packet_utils::{
+    nla::NlaBuffer,
+    traits::{Emitable, Parseable},
+};
+
+use crate::route::flags::RouteFlags;
+use crate::route::{
+    RouteAttribute, RouteHeader, RouteLwEnCapType, RouteLwTunnelEncap,
+    RouteMessage, RouteMessageBuffer, RouteProtocol, RouteScope,
+    RouteSeg6LocalIpTunnel, RouteType, Seg6Flags, Seg6Header, Seg6LocalAction,
+};
+use crate::AddressFamily;
+
+// Hand-crafted RTM_NEWROUTE request payload equivalent to:
+//      ip -6 route add 2001:db8::100/128 encap seg6local action End.DT6 \
+//          table 100 dev eth0
+// with eth0 holding interface index 2.
+#[test]
+fn test_seg6_local_end_dt6() {
+    let raw = vec![
+        0x0a, // AF_INET6
+        0x80, // destination prefix length 128
+        0x00, // source prefix length 0
+        0x00, // tos 0
+        0xfe, // RT_TABLE_MAIN
+        0x03, // RTPROT_BOOT
+        0x00, // RT_SCOPE_UNIVERSE
+        0x01, // RTN_UNICAST
+        0x00, 0x00, 0x00, 0x00, // flags 0
+        0x14, 0x00, 0x01, 0x00, // length 20, RTA_DST
+        0x20, 0x01, 0x0d, 0xb8, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x01, 0x00, // 2001:db8::100
+        0x14, 0x00, 0x16, 0x00, // length 20, RTA_ENCAP
+        0x08, 0x00, 0x01, 0x00, // length 8, SEG6_LOCAL_ACTION
+        0x07, 0x00, 0x00, 0x00, // SEG6_LOCAL_ACTION_END_DT6
+        0x08, 0x00, 0x03, 0x00, // length 8, SEG6_LOCAL_TABLE
+        0x64, 0x00, 0x00, 0x00, // 100
+        0x06, 0x00, 0x15, 0x00, // length 6, RTA_ENCAP_TYPE
+        0x07, 0x00, 0x00, 0x00, // LWTUNNEL_ENCAP_SEG6_LOCAL and padding
+        0x08, 0x00, 0x04, 0x00, // length 8, RTA_OIF
+        0x02, 0x00, 0x00, 0x00, // 2
+    ];
+
+    let expected = RouteMessage {
+        header: RouteHeader {
+            address_family: AddressFamily::Inet6,
+            destination_prefix_length: 128,
+            source_prefix_length: 0,
+            tos: 0,
+            table: 254,
+            protocol: RouteProtocol::Boot,
+            scope: RouteScope::Universe,
+            kind: RouteType::Unicast,
+            flags: RouteFlags::empty(),
+        },
+        attributes: vec![
+            RouteAttribute::Destination(
+                Ipv6Addr::from_str("2001:db8::100").unwrap().into(),
+            ),
+            RouteAttribute::Encap(vec![
+                RouteLwTunnelEncap::Seg6Local(RouteSeg6LocalIpTunnel::Action(
+                    Seg6LocalAction::EndDt6,
+                )),
+                RouteLwTunnelEncap::Seg6Local(RouteSeg6LocalIpTunnel::Table(
+                    100,
+                )),
+            ]),
+            RouteAttribute::EncapType(RouteLwEnCapType::Seg6Local),
+            RouteAttribute::Oif(2),
+        ],
+    };
+
+    assert_eq!(
+        expected,
+        RouteMessage::parse(&RouteMessageBuffer::new(&raw)).unwrap()
+    );
+
+    let mut buf = vec![0; expected.buffer_len()];
+    expected.emit(&mut buf);
+    assert_eq!(buf, raw);
+}
+
+// SEG6_LOCAL_SRH holds the bare SRH without the leading mode of
+// SEG6_IPTUNNEL_SRH, like the one used by:
+//      ip -6 route add 2001:db8::200/128 encap seg6local action End.B6 \
+//          srh segs 2001:db8:2::1 dev eth0
+#[test]
+fn test_seg6_local_srh() {
+    let raw = vec![
+        0x1c, 0x00, 0x02, 0x00, // length 28, SEG6_LOCAL_SRH
+        0x00, // next header 0
+        0x02, // hdrlen 2, 16 bytes
+        0x04, // IPV6_SRCRT_TYPE_4
+        0x00, // segments left 0
+        0x00, // first segment 0
+        0x00, // flags 0
+        0x00, 0x00, // tag 0
+        0x20, 0x01, 0x0d, 0xb8, 0x00, 0x02, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x01, // 2001:db8:2::1
+    ];
+
+    let expected = RouteSeg6LocalIpTunnel::Srh(Seg6Header {
+        next_header: 0,
+        segments_left: 0,
+        flags: Seg6Flags::empty(),
+        tag: 0,
+        segments: vec![Ipv6Addr::from_str("2001:db8:2::1").unwrap()],
+        ..Default::default()
+    });
+
+    assert_eq!(
+        expected,
+        RouteSeg6LocalIpTunnel::parse(&NlaBuffer::new(&raw)).unwrap()
+    );
+
+    let mut buf = vec![0; expected.buffer_len()];
+    expected.emit(&mut buf);
+    assert_eq!(buf, raw);
+}
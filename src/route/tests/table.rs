@@ -0,0 +1,94 @@
+// SPDX-License-Identifier: MIT
+
+use std::net::Ipv4Addr;
+use std::str::FromStr;
+
+use netlink_packet_utils::traits::{Emitable, Parseable};
+
+use crate::route::{
+    RouteAttribute, RouteHeader, RouteMessage, RouteMessageBuffer,
+    RouteProtocol, RouteScope, RouteType,
+};
+use crate::AddressFamily;
+
+fn route_to(table: u32) -> RouteMessage {
+    let mut route = RouteMessage::default();
+    route.header.address_family = AddressFamily::Inet;
+    route.header.destination_prefix_length = 24;
+    route.header.protocol = RouteProtocol::Boot;
+    route.header.scope = RouteScope::Universe;
+    route.header.kind = RouteType::Unicast;
+    route.attributes.push(RouteAttribute::Destination(
+        Ipv4Addr::from_str("198.51.100.0").unwrap().into(),
+    ));
+    route.set_table(table);
+    route
+}
+
+// Hand-crafted RTM_NEWROUTE payload equivalent to:
+//      ip route add 198.51.100.0/24 dev dummy1 table 100
+// with the RTA_OIF omitted.
+#[test]
+fn test_route_set_table_8bits() {
+    let raw = vec![
+        0x02, 0x18, 0x00, 0x00, // family, dst_len, src_len, tos
+        0x64, // table 100
+        0x03, 0x00, 0x01, // protocol boot, scope universe, type unicast
+        0x00, 0x00, 0x00, 0x00, // flags
+        0x08, 0x00, 0x01, 0x00, // length 8, RTA_DST
+        0xc6, 0x33, 0x64, 0x00, // 198.51.100.0
+    ];
+
+    let route = route_to(100);
+
+    assert_eq!(route.header.table, 100);
+    assert_eq!(route.table(), 100);
+    assert!(!route
+        .attributes
+        .iter()
+        .any(|attr| matches!(attr, RouteAttribute::Table(_))));
+
+    let mut buf = vec![0; route.buffer_len()];
+    route.emit(&mut buf);
+    assert_eq!(buf, raw);
+
+    assert_eq!(
+        route,
+        RouteMessage::parse(&RouteMessageBuffer::new(&raw)).unwrap()
+    );
+}
+
+// Hand-crafted RTM_NEWROUTE payload equivalent to:
+//      ip route add 198.51.100.0/24 dev dummy1 table 1000
+// with the RTA_OIF omitted.
+#[test]
+fn test_route_set_table_32bits() {
+    let raw = vec![
+        0x02, 0x18, 0x00, 0x00, // family, dst_len, src_len, tos
+        0xfc, // RT_TABLE_COMPAT
+        0x03, 0x00, 0x01, // protocol boot, scope universe, type unicast
+        0x00, 0x00, 0x00, 0x00, // flags
+        0x08, 0x00, 0x01, 0x00, // length 8, RTA_DST
+        0xc6, 0x33, 0x64, 0x00, // 198.51.100.0
+        0x08, 0x00, 0x0f, 0x00, // length 8, RTA_TABLE
+        0xe8, 0x03, 0x00, 0x00, // 1000
+    ];
+
+    let mut route = route_to(1000);
+
+    assert_eq!(route.header.table, RouteHeader::RT_TABLE_COMPAT);
+    assert_eq!(route.table(), 1000);
+
+    let mut buf = vec![0; route.buffer_len()];
+    route.emit(&mut buf);
+    assert_eq!(buf, raw);
+
+    assert_eq!(
+        route,
+        RouteMessage::parse(&RouteMessageBuffer::new(&raw)).unwrap()
+    );
+
+    // Switching back to small table ID should remove the RTA_TABLE
+    route.set_table(100);
+    assert_eq!(route, route_to(100));
+}
@@ -1,6 +1,6 @@
 // SPDX-License-Identifier: MIT
 
-use std::net::{Ipv4Addr, Ipv6Addr};
+use std::net::{IpAddr, Ipv4Addr, Ipv6Addr};
 use std::str::FromStr;
 
 use netlink_packet_utils::traits::{Emitable, Parseable};
@@ -61,3 +61,122 @@ fn test_ipv4_route_via() {
 
     assert_eq!(buf, raw);
 }
+
+// Same payload as `test_ipv4_route_via`, with the route built from an
+// `IpAddr` by `RouteVia::new()`.
+#[test]
+fn test_build_ipv4_route_via_inet6() {
+    let raw = vec![
+        0x02, 0x20, 0x00, 0x00, 0xfe, 0x03, 0x00, 0x01, 0x00, 0x00, 0x00, 0x00,
+        0x08, 0x00, 0x0f, 0x00, 0xfe, 0x00, 0x00, 0x00, 0x08, 0x00, 0x01, 0x00,
+        0xc0, 0x00, 0x02, 0x01, 0x16, 0x00, 0x12, 0x00, 0x0a, 0x00, 0x20, 0x01,
+        0x0d, 0xb8, 0x00, 0x01, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x08, 0x00, 0x04, 0x00, 0x01, 0x00, 0x00, 0x00,
+    ];
+    let gateway = IpAddr::from_str("2001:db8:1::").unwrap();
+    let route = RouteMessage {
+        header: RouteHeader {
+            address_family: AddressFamily::Inet,
+            destination_prefix_length: 32,
+            table: RouteHeader::RT_TABLE_MAIN,
+            protocol: RouteProtocol::Boot,
+            scope: RouteScope::Universe,
+            kind: RouteType::Unicast,
+            ..Default::default()
+        },
+        attributes: vec![
+            RouteAttribute::Table(254),
+            RouteAttribute::Destination(
+                Ipv4Addr::from_str("192.0.2.1").unwrap().into(),
+            ),
+            RouteAttribute::Via(RouteVia::new(gateway)),
+            RouteAttribute::Oif(1),
+        ],
+    };
+
+    let mut buf = vec![0; route.buffer_len()];
+    route.emit(&mut buf);
+    assert_eq!(buf, raw);
+
+    let parsed = RouteMessage::parse(&RouteMessageBuffer::new(&raw)).unwrap();
+    assert_eq!(parsed, route);
+    assert_eq!(parsed.via(), Some((AddressFamily::Inet6, gateway)));
+
+    assert_eq!(
+        RouteVia::new(IpAddr::from_str("192.0.2.1").unwrap()),
+        RouteVia::Inet(Ipv4Addr::new(192, 0, 2, 1))
+    );
+}
+
+// Hand-crafted payload of an IPv6 route holding an IPv4 RTA_VIA. Kernel
+// refuses RTA_VIA for IPv6 routes (`ip -6 route add ... via inet ...` fails
+// with "IPv6 does not support RTA_VIA attribute"), so no capture exists;
+// this only checks that `RouteVia::Inet` and `via()` are family independent.
+#[test]
+fn test_ipv6_route_via_inet() {
+    let raw = vec![
+        0x0a, // address family AF_INET6
+        0x40, // destination prefix length 64
+        0x00, // source prefix length
+        0x00, // tos
+        0xfe, // table main
+        0x03, // protocol boot
+        0x00, // scope universe
+        0x01, // type unicast
+        0x00, 0x00, 0x00, 0x00, // flags
+        0x08, 0x00, 0x0f, 0x00, // length 8, RTA_TABLE
+        0xfe, 0x00, 0x00, 0x00, // 254
+        0x14, 0x00, 0x01, 0x00, // length 20, RTA_DST
+        0x20, 0x01, 0x0d, 0xb8, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x00, // 2001:db8::
+        0x0a, 0x00, 0x12, 0x00, // length 10, RTA_VIA
+        0x02, 0x00, // AF_INET
+        0xc0, 0x00, 0x02, 0x01, // 192.0.2.1
+        0x00, 0x00, // padding
+        0x08, 0x00, 0x04, 0x00, // length 8, RTA_OIF
+        0x01, 0x00, 0x00, 0x00, // 1
+    ];
+
+    let expected = RouteMessage {
+        header: RouteHeader {
+            address_family: AddressFamily::Inet6,
+            destination_prefix_length: 64,
+            source_prefix_length: 0,
+            tos: 0,
+            table: 254,
+            protocol: RouteProtocol::Boot,
+            scope: RouteScope::Universe,
+            kind: RouteType::Unicast,
+            flags: RouteFlags::empty(),
+        },
+        attributes: vec![
+            RouteAttribute::Table(254),
+            RouteAttribute::Destination(
+                Ipv6Addr::from_str("2001:db8::").unwrap().into(),
+            ),
+            RouteAttribute::Via(RouteVia::Inet(
+                Ipv4Addr::from_str("192.0.2.1").unwrap(),
+            )),
+            RouteAttribute::Oif(1),
+        ],
+    };
+
+    assert_eq!(
+        expected,
+        RouteMessage::parse(&RouteMessageBuffer::new(&raw)).unwrap()
+    );
+
+    assert_eq!(
+        expected.via(),
+        Some((
+            AddressFamily::Inet,
+            IpAddr::V4(Ipv4Addr::from_str("192.0.2.1").unwrap())
+        ))
+    );
+
+    let mut buf = vec![0; expected.buffer_len()];
+
+    expected.emit(&mut buf);
+
+    assert_eq!(buf, raw);
+}
@@ -1,6 +1,6 @@
 // SPDX-License-Identifier: MIT
 
-use std::net::{Ipv4Addr, Ipv6Addr};
+use std::net::{IpAddr, Ipv4Addr, Ipv6Addr};
 
 use netlink_packet_utils::{
     traits::{Emitable, Parseable},
@@ -13,6 +13,7 @@ use crate::ip::{
 use crate::AddressFamily;
 
 #[derive(Debug, PartialEq, Eq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[non_exhaustive]
 // Kernel representative is `struct rtvia`
 // In Linux kernel 6.18, MPLS route also use `AF_PACKET`
@@ -22,10 +23,21 @@ pub enum RouteVia {
     Inet(Ipv4Addr),
     Inet6(Ipv6Addr),
     #[cfg(any(target_os = "linux", target_os = "fuchsia"))]
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_hex"))]
     Packet(Vec<u8>),
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_hex::tagged"))]
     Other((AddressFamily, Vec<u8>)),
 }
 
+impl RouteVia {
+    /// Create [RouteVia::Inet] or [RouteVia::Inet6] from the gateway address,
+    /// which may use a different address family from the route, e.g. IPv4
+    /// route via IPv6 gateway.
+    pub fn new(gateway: IpAddr) -> Self {
+        gateway.into()
+    }
+}
+
 const RTVIA_LEN: usize = 2;
 
 buffer!(RouteViaBuffer(RTVIA_LEN) {
@@ -86,3 +98,12 @@ impl From<Ipv6Addr> for RouteVia {
         Self::Inet6(v)
     }
 }
+
+impl From<IpAddr> for RouteVia {
+    fn from(v: IpAddr) -> Self {
+        match v {
+            IpAddr::V4(ip) => Self::Inet(ip),
+            IpAddr::V6(ip) => Self::Inet6(ip),
+        }
+    }
+}
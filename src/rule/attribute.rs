@@ -42,6 +42,7 @@ const FRA_SPORT_RANGE: u16 = 23;
 const FRA_DPORT_RANGE: u16 = 24;
 
 #[derive(Debug, PartialEq, Eq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[non_exhaustive]
 pub enum RuleAttribute {
     /// destination address
@@ -69,6 +70,7 @@ pub enum RuleAttribute {
     IpProtocol(IpProtocol),
     SourcePortRange(RulePortRange),
     DestinationPortRange(RulePortRange),
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_nla"))]
     Other(DefaultNla),
 }
 
@@ -9,6 +9,7 @@ const FIB_RULE_OIF_DETACHED: u32 = 0x00000010;
 
 bitflags! {
     #[derive(Clone, Eq, PartialEq, Debug, Copy, Default)]
+    #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
     #[non_exhaustive]
     pub struct RuleFlags: u32 {
         const Permanent = FIB_RULE_PERMANENT;
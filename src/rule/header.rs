@@ -23,6 +23,8 @@ buffer!(RuleMessageBuffer(RULE_HEADER_LEN) {
     payload: (slice, RULE_HEADER_LEN..),
 });
 
+impl_try_from_slice!(RuleMessageBuffer);
+
 impl<'a, T: AsRef<[u8]> + ?Sized> RuleMessageBuffer<&'a T> {
     pub fn attributes(
         &self,
@@ -33,6 +35,7 @@ impl<'a, T: AsRef<[u8]> + ?Sized> RuleMessageBuffer<&'a T> {
 
 // Linux kernel code `struct fib_rule_hdr`
 #[derive(Debug, PartialEq, Eq, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct RuleHeader {
     pub family: AddressFamily,
     pub dst_len: u8,
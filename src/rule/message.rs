@@ -9,12 +9,31 @@ use netlink_packet_utils::{
 use super::{RuleAttribute, RuleHeader, RuleMessageBuffer};
 
 #[derive(Debug, PartialEq, Eq, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[non_exhaustive]
 pub struct RuleMessage {
     pub header: RuleHeader,
     pub attributes: Vec<RuleAttribute>,
 }
 
+impl RuleMessage {
+    /// Firewall mark stored in the first [RuleAttribute::FwMark].
+    pub fn fw_mark(&self) -> Option<u32> {
+        self.attributes.iter().find_map(|attr| match attr {
+            RuleAttribute::FwMark(mark) => Some(*mark),
+            _ => None,
+        })
+    }
+
+    /// Firewall mark mask stored in the first [RuleAttribute::FwMask].
+    pub fn fw_mask(&self) -> Option<u32> {
+        self.attributes.iter().find_map(|attr| match attr {
+            RuleAttribute::FwMask(mask) => Some(*mask),
+            _ => None,
+        })
+    }
+}
+
 impl Emitable for RuleMessage {
     fn buffer_len(&self) -> usize {
         self.header.buffer_len() + self.attributes.as_slice().buffer_len()
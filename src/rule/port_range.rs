@@ -5,6 +5,7 @@ use netlink_packet_utils::{DecodeError, Emitable};
 const RULE_PORT_RANGE_LEN: usize = 4;
 
 #[derive(Clone, Eq, PartialEq, Debug, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct RulePortRange {
     pub start: u16,
     pub end: u16,
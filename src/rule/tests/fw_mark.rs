@@ -48,6 +48,8 @@ fn test_ipv4_fwmark_suppress_prefixlength() {
         expected,
         RuleMessage::parse(&RuleMessageBuffer::new(&raw)).unwrap()
     );
+    assert_eq!(expected.fw_mark(), Some(0x20));
+    assert_eq!(expected.fw_mask(), Some(0xffffffff));
 
     let mut buf = vec![0; expected.buffer_len()];
 
@@ -95,6 +97,8 @@ fn test_ipv6_fwmark_suppress_ifgroup() {
         expected,
         RuleMessage::parse(&RuleMessageBuffer::new(&raw)).unwrap()
     );
+    assert_eq!(expected.fw_mark(), Some(0x20));
+    assert_eq!(expected.fw_mask(), Some(0xffffffff));
 
     let mut buf = vec![0; expected.buffer_len()];
 
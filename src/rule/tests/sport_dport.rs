@@ -127,3 +127,50 @@ fn test_ipv4_udp_sport_range_dport_range_reals_src_dst() {
 
     assert_eq!(buf, raw);
 }
+
+// Hand-crafted RTM_NEWRULE request payload equivalent to:
+//      ip -4 rule add ipproto udp sport 1000-2000 table 100
+#[test]
+fn test_build_ipv4_udp_sport_range() {
+    let raw = vec![
+        0x02, // AF_INET
+        0x00, // destination prefix length
+        0x00, // source prefix length
+        0x00, // tos
+        0x64, // table 100
+        0x00, 0x00, // reserved
+        0x01, // FR_ACT_TO_TBL
+        0x00, 0x00, 0x00, 0x00, // flags
+        0x08, 0x00, 0x0f, 0x00, // length 8, FRA_TABLE
+        0x64, 0x00, 0x00, 0x00, // 100
+        0x05, 0x00, 0x16, 0x00, // length 5, FRA_IP_PROTO
+        0x11, // IPPROTO_UDP
+        0x00, 0x00, 0x00, // padding
+        0x08, 0x00, 0x17, 0x00, // length 8, FRA_SPORT_RANGE
+        0xe8, 0x03, 0xd0, 0x07, // 1000-2000
+    ];
+
+    let mut message = RuleMessage::default();
+    message.header.family = AddressFamily::Inet;
+    message.header.table = 100;
+    message.header.action = RuleAction::ToTable;
+    message.attributes = vec![
+        RuleAttribute::Table(100),
+        RuleAttribute::IpProtocol(IpProtocol::Udp),
+        RuleAttribute::SourcePortRange(RulePortRange {
+            start: 1000,
+            end: 2000,
+        }),
+    ];
+
+    let mut buf = vec![0; message.buffer_len()];
+
+    message.emit(&mut buf);
+
+    assert_eq!(buf, raw);
+
+    assert_eq!(
+        message,
+        RuleMessage::parse(&RuleMessageBuffer::new(&raw)).unwrap()
+    );
+}
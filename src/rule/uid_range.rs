@@ -5,6 +5,7 @@ use netlink_packet_utils::{DecodeError, Emitable};
 const RULE_UID_RANGE_LEN: usize = 8;
 
 #[derive(Clone, Eq, PartialEq, Debug, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct RuleUidRange {
     pub start: u32,
     pub end: u32,
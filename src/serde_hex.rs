@@ -0,0 +1,77 @@
+// SPDX-License-Identifier: MIT
+
+//! Serialize raw bytes as hex string, to be used with
+//! `#[serde(with = "crate::serde_hex")]` on `Vec<u8>` fields.
+
+use serde::{de::Error, Deserialize, Deserializer, Serializer};
+
+pub(crate) fn to_hex(bytes: &[u8]) -> String {
+    bytes.iter().map(|b| format!("{b:02x}")).collect()
+}
+
+pub(crate) fn from_hex(value: &str) -> Result<Vec<u8>, String> {
+    if !value.len().is_multiple_of(2) || !value.is_ascii() {
+        return Err(format!("invalid hex string {value}"));
+    }
+    (0..value.len())
+        .step_by(2)
+        .map(|i| u8::from_str_radix(&value[i..i + 2], 16))
+        .collect::<Result<Vec<u8>, _>>()
+        .map_err(|e| format!("invalid hex string {value}: {e}"))
+}
+
+pub(crate) fn serialize<S>(
+    bytes: &[u8],
+    serializer: S,
+) -> Result<S::Ok, S::Error>
+where
+    S: Serializer,
+{
+    serializer.serialize_str(&to_hex(bytes))
+}
+
+pub(crate) fn deserialize<'de, D>(deserializer: D) -> Result<Vec<u8>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    from_hex(&String::deserialize(deserializer)?).map_err(D::Error::custom)
+}
+
+/// Same as the parent module, but for a `(T, Vec<u8>)` tuple.
+pub(crate) mod tagged {
+    use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+    #[derive(Serialize)]
+    struct Tagged<'a, T>(
+        &'a T,
+        #[serde(serialize_with = "super::serialize")] &'a [u8],
+    );
+
+    #[derive(Deserialize)]
+    struct TaggedOwned<T>(
+        T,
+        #[serde(deserialize_with = "super::deserialize")] Vec<u8>,
+    );
+
+    pub(crate) fn serialize<S, T>(
+        value: &(T, Vec<u8>),
+        serializer: S,
+    ) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+        T: Serialize,
+    {
+        Tagged(&value.0, &value.1).serialize(serializer)
+    }
+
+    pub(crate) fn deserialize<'de, D, T>(
+        deserializer: D,
+    ) -> Result<(T, Vec<u8>), D::Error>
+    where
+        D: Deserializer<'de>,
+        T: Deserialize<'de>,
+    {
+        let TaggedOwned(tag, bytes) = TaggedOwned::deserialize(deserializer)?;
+        Ok((tag, bytes))
+    }
+}
@@ -0,0 +1,41 @@
+// SPDX-License-Identifier: MIT
+
+//! Serialize [DefaultNla] as its kind and its value in hex string, to be
+//! used with `#[serde(with = "crate::serde_nla")]`.
+
+use netlink_packet_utils::nla::{DefaultNla, Nla};
+use serde::{de::Error, Deserialize, Deserializer, Serialize, Serializer};
+
+#[derive(Serialize, Deserialize)]
+struct RawNla {
+    kind: u16,
+    value: String,
+}
+
+pub(crate) fn serialize<S>(
+    nla: &DefaultNla,
+    serializer: S,
+) -> Result<S::Ok, S::Error>
+where
+    S: Serializer,
+{
+    let mut value = vec![0u8; nla.value_len()];
+    nla.emit_value(&mut value);
+    RawNla {
+        kind: nla.kind(),
+        value: crate::serde_hex::to_hex(&value),
+    }
+    .serialize(serializer)
+}
+
+pub(crate) fn deserialize<'de, D>(
+    deserializer: D,
+) -> Result<DefaultNla, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    let raw = RawNla::deserialize(deserializer)?;
+    let value = crate::serde_hex::from_hex(&raw.value)
+        .map_err(|e| D::Error::custom(format!("invalid NLA value: {e}")))?;
+    Ok(DefaultNla::new(raw.kind, value))
+}
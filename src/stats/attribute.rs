@@ -0,0 +1,149 @@
+// SPDX-License-Identifier: MIT
+
+use anyhow::Context;
+use netlink_packet_utils::{
+    nla::{DefaultNla, Nla, NlaBuffer, NlasIterator},
+    traits::{Emitable, Parseable},
+    DecodeError,
+};
+
+use super::{OffloadXstats, StatsAfSpec, StatsXstats};
+use crate::{
+    is_strict_parsing,
+    link::{
+        buffer_tool::expand_buffer_if_small, stats64::LINK_STATS64_LEN,
+        Stats64, Stats64Buffer,
+    },
+};
+
+const IFLA_STATS_LINK_64: u16 = 1;
+const IFLA_STATS_LINK_XSTATS: u16 = 2;
+const IFLA_STATS_LINK_XSTATS_SLAVE: u16 = 3;
+const IFLA_STATS_LINK_OFFLOAD_XSTATS: u16 = 4;
+const IFLA_STATS_AF_SPEC: u16 = 5;
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[non_exhaustive]
+pub enum StatsAttribute {
+    /// Software statistics of the interface, same as
+    /// [crate::link::LinkAttribute::Stats64].
+    Link64(Stats64),
+    /// Extended statistics of the interface itself, e.g. bridge.
+    LinkXstats(Vec<StatsXstats>),
+    /// Extended statistics of the interface as port of its controller, e.g.
+    /// bond port.
+    LinkXstatsSlave(Vec<StatsXstats>),
+    LinkOffloadXstats(Vec<OffloadXstats>),
+    /// Per address family statistics, e.g. `AF_MPLS`.
+    AfSpec(Vec<StatsAfSpec>),
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_nla"))]
+    Other(DefaultNla),
+}
+
+impl Nla for StatsAttribute {
+    fn value_len(&self) -> usize {
+        match self {
+            Self::Link64(v) => v.buffer_len(),
+            Self::LinkXstats(v) | Self::LinkXstatsSlave(v) => {
+                v.as_slice().buffer_len()
+            }
+            Self::LinkOffloadXstats(v) => v.as_slice().buffer_len(),
+            Self::AfSpec(v) => v.as_slice().buffer_len(),
+            Self::Other(attr) => attr.value_len(),
+        }
+    }
+
+    fn emit_value(&self, buffer: &mut [u8]) {
+        match self {
+            Self::Link64(v) => v.emit(buffer),
+            Self::LinkXstats(v) | Self::LinkXstatsSlave(v) => {
+                v.as_slice().emit(buffer)
+            }
+            Self::LinkOffloadXstats(v) => v.as_slice().emit(buffer),
+            Self::AfSpec(v) => v.as_slice().emit(buffer),
+            Self::Other(attr) => attr.emit_value(buffer),
+        }
+    }
+
+    fn kind(&self) -> u16 {
+        match self {
+            Self::Link64(_) => IFLA_STATS_LINK_64,
+            Self::LinkXstats(_) => IFLA_STATS_LINK_XSTATS,
+            Self::LinkXstatsSlave(_) => IFLA_STATS_LINK_XSTATS_SLAVE,
+            Self::LinkOffloadXstats(_) => IFLA_STATS_LINK_OFFLOAD_XSTATS,
+            Self::AfSpec(_) => IFLA_STATS_AF_SPEC,
+            Self::Other(attr) => attr.kind(),
+        }
+    }
+}
+
+impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>>
+    for StatsAttribute
+{
+    fn parse(buf: &NlaBuffer<&'a T>) -> Result<Self, DecodeError> {
+        let payload = buf.value();
+        Ok(match buf.kind() {
+            IFLA_STATS_LINK_64 if is_strict_parsing() => Self::Link64(
+                Stats64::parse(
+                    &Stats64Buffer::new_strict(payload)
+                        .context("invalid IFLA_STATS_LINK_64 value")?,
+                )
+                .context("invalid IFLA_STATS_LINK_64 value")?,
+            ),
+            IFLA_STATS_LINK_64 => {
+                let payload = expand_buffer_if_small(
+                    payload,
+                    LINK_STATS64_LEN,
+                    "IFLA_STATS_LINK_64",
+                );
+                Self::Link64(
+                    Stats64::parse(&Stats64Buffer::new(payload.as_slice()))
+                        .context("invalid IFLA_STATS_LINK_64 value")?,
+                )
+            }
+            IFLA_STATS_LINK_XSTATS => {
+                let mut stats = vec![];
+                for nla in NlasIterator::new(payload) {
+                    let nla =
+                        nla.context("invalid IFLA_STATS_LINK_XSTATS value")?;
+                    stats.push(StatsXstats::parse(&nla)?);
+                }
+                Self::LinkXstats(stats)
+            }
+            IFLA_STATS_LINK_XSTATS_SLAVE => {
+                let mut stats = vec![];
+                for nla in NlasIterator::new(payload) {
+                    let nla = nla.context(
+                        "invalid IFLA_STATS_LINK_XSTATS_SLAVE value",
+                    )?;
+                    stats.push(StatsXstats::parse(&nla)?);
+                }
+                Self::LinkXstatsSlave(stats)
+            }
+            IFLA_STATS_LINK_OFFLOAD_XSTATS => {
+                let mut stats = vec![];
+                for nla in NlasIterator::new(payload) {
+                    let nla = nla.context(
+                        "invalid IFLA_STATS_LINK_OFFLOAD_XSTATS value",
+                    )?;
+                    stats.push(OffloadXstats::parse(&nla)?);
+                }
+                Self::LinkOffloadXstats(stats)
+            }
+            IFLA_STATS_AF_SPEC => {
+                let mut stats = vec![];
+                for nla in NlasIterator::new(payload) {
+                    let nla =
+                        nla.context("invalid IFLA_STATS_AF_SPEC value")?;
+                    stats.push(StatsAfSpec::parse(&nla)?);
+                }
+                Self::AfSpec(stats)
+            }
+            _ => Self::Other(
+                DefaultNla::parse(buf)
+                    .context("invalid stats NLA (unknown kind)")?,
+            ),
+        })
+    }
+}
@@ -0,0 +1,89 @@
+// SPDX-License-Identifier: MIT
+
+use netlink_packet_utils::{
+    nla::{NlaBuffer, NlasIterator},
+    traits::{Emitable, Parseable},
+    DecodeError,
+};
+
+use crate::AddressFamily;
+
+const STATS_HEADER_LEN: usize = 12;
+
+buffer!(StatsMessageBuffer(STATS_HEADER_LEN) {
+    family: (u8, 0),
+    pad1: (u8, 1),
+    pad2: (u16, 2..4),
+    ifindex: (u32, 4..8),
+    filter_mask: (u32, 8..STATS_HEADER_LEN),
+    payload: (slice, STATS_HEADER_LEN..),
+});
+
+impl<'a, T: AsRef<[u8]> + ?Sized> StatsMessageBuffer<&'a T> {
+    pub fn attributes(
+        &self,
+    ) -> impl Iterator<Item = Result<NlaBuffer<&'a [u8]>, DecodeError>> {
+        NlasIterator::new(self.payload())
+    }
+}
+
+const IFLA_STATS_FILTER_LINK_64: u32 = 1 << 0;
+const IFLA_STATS_FILTER_LINK_XSTATS: u32 = 1 << 1;
+const IFLA_STATS_FILTER_LINK_XSTATS_SLAVE: u32 = 1 << 2;
+const IFLA_STATS_FILTER_LINK_OFFLOAD_XSTATS: u32 = 1 << 3;
+const IFLA_STATS_FILTER_AF_SPEC: u32 = 1 << 4;
+
+bitflags! {
+    /// Attributes requested in `RTM_GETSTATS` or included in
+    /// `RTM_NEWSTATS`, bit `N - 1` stands for the `IFLA_STATS_*` attribute
+    /// of kind `N`.
+    #[derive(Clone, Eq, PartialEq, Debug, Copy, Default)]
+    #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+    #[non_exhaustive]
+    pub struct StatsFilterMask: u32 {
+        const Link64 = IFLA_STATS_FILTER_LINK_64;
+        const LinkXstats = IFLA_STATS_FILTER_LINK_XSTATS;
+        const LinkXstatsSlave = IFLA_STATS_FILTER_LINK_XSTATS_SLAVE;
+        const LinkOffloadXstats = IFLA_STATS_FILTER_LINK_OFFLOAD_XSTATS;
+        const AfSpec = IFLA_STATS_FILTER_AF_SPEC;
+        const _ = !0;
+    }
+}
+
+// Linux kernel code `struct if_stats_msg`
+#[derive(Debug, PartialEq, Eq, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[non_exhaustive]
+pub struct StatsHeader {
+    pub family: AddressFamily,
+    /// Interface index, 0 for dumping all interfaces.
+    pub ifindex: u32,
+    pub filter_mask: StatsFilterMask,
+}
+
+impl Emitable for StatsHeader {
+    fn buffer_len(&self) -> usize {
+        STATS_HEADER_LEN
+    }
+
+    fn emit(&self, buffer: &mut [u8]) {
+        let mut packet = StatsMessageBuffer::new(buffer);
+        packet.set_family(self.family.into());
+        packet.set_pad1(0);
+        packet.set_pad2(0);
+        packet.set_ifindex(self.ifindex);
+        packet.set_filter_mask(self.filter_mask.bits());
+    }
+}
+
+impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<StatsMessageBuffer<&'a T>>
+    for StatsHeader
+{
+    fn parse(buf: &StatsMessageBuffer<&'a T>) -> Result<Self, DecodeError> {
+        Ok(Self {
+            family: buf.family().into(),
+            ifindex: buf.ifindex(),
+            filter_mask: StatsFilterMask::from_bits_retain(buf.filter_mask()),
+        })
+    }
+}
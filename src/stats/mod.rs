@@ -0,0 +1,30 @@
+// SPDX-License-Identifier: MIT
+
+mod af_spec;
+mod attribute;
+mod bond;
+mod bridge;
+mod header;
+mod message;
+mod offload;
+mod xstats;
+
+mod tests;
+
+pub use self::af_spec::{
+    AfSpecMpls, MplsLinkStats, MplsLinkStatsBuffer, StatsAfSpec,
+};
+pub use self::attribute::StatsAttribute;
+pub use self::bond::{Bond3adXstats, BondXstats};
+pub use self::bridge::{
+    BridgeMcastStats, BridgeMcastStatsBuffer, BridgeStpXstats,
+    BridgeStpXstatsBuffer, BridgeVlanXstats, BridgeVlanXstatsBuffer,
+    BridgeXstats,
+};
+pub use self::header::{StatsFilterMask, StatsHeader, StatsMessageBuffer};
+pub use self::message::StatsMessage;
+pub use self::offload::{
+    HwStats64, HwStats64Buffer, OffloadHwStatsInfo, OffloadHwStatsInfoState,
+    OffloadXstats,
+};
+pub use self::xstats::StatsXstats;
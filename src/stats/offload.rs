@@ -0,0 +1,309 @@
+// SPDX-License-Identifier: MIT
+
+use anyhow::Context;
+use netlink_packet_utils::{
+    nla::{DefaultNla, Nla, NlaBuffer, NlasIterator},
+    parsers::parse_u8,
+    traits::{Emitable, Parseable},
+    DecodeError,
+};
+
+use crate::{
+    is_strict_parsing,
+    link::{
+        buffer_tool::expand_buffer_if_small, stats64::LINK_STATS64_LEN,
+        Stats64, Stats64Buffer,
+    },
+};
+
+const IFLA_OFFLOAD_XSTATS_CPU_HIT: u16 = 1;
+const IFLA_OFFLOAD_XSTATS_HW_S_INFO: u16 = 2;
+const IFLA_OFFLOAD_XSTATS_L3_STATS: u16 = 3;
+
+/// Offloaded statistics nested in `IFLA_STATS_LINK_OFFLOAD_XSTATS`.
+///
+/// These counters are maintained by the hardware (e.g. switchdev ports) and
+/// are separated from the software statistics of `IFLA_STATS_LINK_64`.
+#[derive(Debug, PartialEq, Eq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[non_exhaustive]
+pub enum OffloadXstats {
+    /// Statistics of packets trapped from hardware to CPU
+    CpuHit(Stats64),
+    /// Whether each kind of hardware statistics is requested and used
+    HwStatsInfo(Vec<OffloadHwStatsInfo>),
+    /// Layer 3 statistics counted by hardware
+    L3Stats(HwStats64),
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_nla"))]
+    Other(DefaultNla),
+}
+
+impl Nla for OffloadXstats {
+    fn value_len(&self) -> usize {
+        match self {
+            Self::CpuHit(v) => v.buffer_len(),
+            Self::HwStatsInfo(v) => v.as_slice().buffer_len(),
+            Self::L3Stats(v) => v.buffer_len(),
+            Self::Other(attr) => attr.value_len(),
+        }
+    }
+
+    fn emit_value(&self, buffer: &mut [u8]) {
+        match self {
+            Self::CpuHit(v) => v.emit(buffer),
+            Self::HwStatsInfo(v) => v.as_slice().emit(buffer),
+            Self::L3Stats(v) => v.emit(buffer),
+            Self::Other(attr) => attr.emit_value(buffer),
+        }
+    }
+
+    fn kind(&self) -> u16 {
+        match self {
+            Self::CpuHit(_) => IFLA_OFFLOAD_XSTATS_CPU_HIT,
+            Self::HwStatsInfo(_) => IFLA_OFFLOAD_XSTATS_HW_S_INFO,
+            Self::L3Stats(_) => IFLA_OFFLOAD_XSTATS_L3_STATS,
+            Self::Other(attr) => attr.kind(),
+        }
+    }
+}
+
+impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>>
+    for OffloadXstats
+{
+    fn parse(buf: &NlaBuffer<&'a T>) -> Result<Self, DecodeError> {
+        let payload = buf.value();
+        Ok(match buf.kind() {
+            IFLA_OFFLOAD_XSTATS_CPU_HIT if is_strict_parsing() => Self::CpuHit(
+                Stats64::parse(
+                    &Stats64Buffer::new_strict(payload)
+                        .context("invalid IFLA_OFFLOAD_XSTATS_CPU_HIT value")?,
+                )
+                .context("invalid IFLA_OFFLOAD_XSTATS_CPU_HIT value")?,
+            ),
+            IFLA_OFFLOAD_XSTATS_CPU_HIT => {
+                let payload = expand_buffer_if_small(
+                    payload,
+                    LINK_STATS64_LEN,
+                    "IFLA_OFFLOAD_XSTATS_CPU_HIT",
+                );
+                Self::CpuHit(
+                    Stats64::parse(&Stats64Buffer::new(payload.as_slice()))
+                        .context("invalid IFLA_OFFLOAD_XSTATS_CPU_HIT value")?,
+                )
+            }
+            IFLA_OFFLOAD_XSTATS_HW_S_INFO => {
+                let mut infos = vec![];
+                for nla in NlasIterator::new(payload) {
+                    let nla = nla.context(
+                        "invalid IFLA_OFFLOAD_XSTATS_HW_S_INFO value",
+                    )?;
+                    infos.push(OffloadHwStatsInfo::parse(&nla)?);
+                }
+                Self::HwStatsInfo(infos)
+            }
+            IFLA_OFFLOAD_XSTATS_L3_STATS => {
+                let payload = expand_buffer_if_small(
+                    payload,
+                    HW_STATS64_LEN,
+                    "IFLA_OFFLOAD_XSTATS_L3_STATS",
+                );
+                Self::L3Stats(
+                    HwStats64::parse(&HwStats64Buffer::new(payload.as_slice()))
+                        .context(
+                            "invalid IFLA_OFFLOAD_XSTATS_L3_STATS value",
+                        )?,
+                )
+            }
+            _ => Self::Other(
+                DefaultNla::parse(buf)
+                    .context("invalid offload xstats NLA (unknown kind)")?,
+            ),
+        })
+    }
+}
+
+/// Hardware statistics status nested in `IFLA_OFFLOAD_XSTATS_HW_S_INFO`,
+/// indexed by the kind of statistics.
+#[derive(Debug, PartialEq, Eq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[non_exhaustive]
+pub enum OffloadHwStatsInfo {
+    L3Stats(Vec<OffloadHwStatsInfoState>),
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_nla"))]
+    Other(DefaultNla),
+}
+
+impl Nla for OffloadHwStatsInfo {
+    fn value_len(&self) -> usize {
+        match self {
+            Self::L3Stats(v) => v.as_slice().buffer_len(),
+            Self::Other(attr) => attr.value_len(),
+        }
+    }
+
+    fn emit_value(&self, buffer: &mut [u8]) {
+        match self {
+            Self::L3Stats(v) => v.as_slice().emit(buffer),
+            Self::Other(attr) => attr.emit_value(buffer),
+        }
+    }
+
+    fn kind(&self) -> u16 {
+        match self {
+            Self::L3Stats(_) => IFLA_OFFLOAD_XSTATS_L3_STATS,
+            Self::Other(attr) => attr.kind(),
+        }
+    }
+}
+
+impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>>
+    for OffloadHwStatsInfo
+{
+    fn parse(buf: &NlaBuffer<&'a T>) -> Result<Self, DecodeError> {
+        let payload = buf.value();
+        Ok(match buf.kind() {
+            IFLA_OFFLOAD_XSTATS_L3_STATS => {
+                let mut states = vec![];
+                for nla in NlasIterator::new(payload) {
+                    let nla = nla.context(
+                        "invalid IFLA_OFFLOAD_XSTATS_HW_S_INFO L3 stats value",
+                    )?;
+                    states.push(OffloadHwStatsInfoState::parse(&nla)?);
+                }
+                Self::L3Stats(states)
+            }
+            _ => {
+                Self::Other(DefaultNla::parse(buf).context(
+                    "invalid hardware stats info NLA (unknown kind)",
+                )?)
+            }
+        })
+    }
+}
+
+const IFLA_OFFLOAD_XSTATS_HW_S_INFO_REQUEST: u16 = 1;
+const IFLA_OFFLOAD_XSTATS_HW_S_INFO_USED: u16 = 2;
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[non_exhaustive]
+pub enum OffloadHwStatsInfoState {
+    /// Whether user space requested this kind of hardware statistics
+    Request(bool),
+    /// Whether driver is providing this kind of hardware statistics
+    Used(bool),
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_nla"))]
+    Other(DefaultNla),
+}
+
+impl Nla for OffloadHwStatsInfoState {
+    fn value_len(&self) -> usize {
+        match self {
+            Self::Request(_) | Self::Used(_) => 1,
+            Self::Other(attr) => attr.value_len(),
+        }
+    }
+
+    fn emit_value(&self, buffer: &mut [u8]) {
+        match self {
+            Self::Request(v) | Self::Used(v) => buffer[0] = (*v).into(),
+            Self::Other(attr) => attr.emit_value(buffer),
+        }
+    }
+
+    fn kind(&self) -> u16 {
+        match self {
+            Self::Request(_) => IFLA_OFFLOAD_XSTATS_HW_S_INFO_REQUEST,
+            Self::Used(_) => IFLA_OFFLOAD_XSTATS_HW_S_INFO_USED,
+            Self::Other(attr) => attr.kind(),
+        }
+    }
+}
+
+impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>>
+    for OffloadHwStatsInfoState
+{
+    fn parse(buf: &NlaBuffer<&'a T>) -> Result<Self, DecodeError> {
+        let payload = buf.value();
+        Ok(match buf.kind() {
+            IFLA_OFFLOAD_XSTATS_HW_S_INFO_REQUEST => Self::Request(
+                parse_u8(payload).context(
+                    "invalid IFLA_OFFLOAD_XSTATS_HW_S_INFO_REQUEST value",
+                )? > 0,
+            ),
+            IFLA_OFFLOAD_XSTATS_HW_S_INFO_USED => Self::Used(
+                parse_u8(payload).context(
+                    "invalid IFLA_OFFLOAD_XSTATS_HW_S_INFO_USED value",
+                )? > 0,
+            ),
+            _ => Self::Other(DefaultNla::parse(buf).context(
+                "invalid hardware stats info state NLA (unknown kind)",
+            )?),
+        })
+    }
+}
+
+const HW_STATS64_LEN: usize = 72;
+
+buffer!(HwStats64Buffer(HW_STATS64_LEN) {
+    rx_packets: (u64, 0..8),
+    tx_packets: (u64, 8..16),
+    rx_bytes: (u64, 16..24),
+    tx_bytes: (u64, 24..32),
+    rx_errors: (u64, 32..40),
+    tx_errors: (u64, 40..48),
+    rx_dropped: (u64, 48..56),
+    tx_dropped: (u64, 56..64),
+    multicast: (u64, 64..72),
+});
+
+/// Hardware statistics of `struct rtnl_hw_stats64`.
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[non_exhaustive]
+pub struct HwStats64 {
+    pub rx_packets: u64,
+    pub tx_packets: u64,
+    pub rx_bytes: u64,
+    pub tx_bytes: u64,
+    pub rx_errors: u64,
+    pub tx_errors: u64,
+    pub rx_dropped: u64,
+    pub tx_dropped: u64,
+    pub multicast: u64,
+}
+
+impl<T: AsRef<[u8]>> Parseable<HwStats64Buffer<T>> for HwStats64 {
+    fn parse(buf: &HwStats64Buffer<T>) -> Result<Self, DecodeError> {
+        Ok(Self {
+            rx_packets: buf.rx_packets(),
+            tx_packets: buf.tx_packets(),
+            rx_bytes: buf.rx_bytes(),
+            tx_bytes: buf.tx_bytes(),
+            rx_errors: buf.rx_errors(),
+            tx_errors: buf.tx_errors(),
+            rx_dropped: buf.rx_dropped(),
+            tx_dropped: buf.tx_dropped(),
+            multicast: buf.multicast(),
+        })
+    }
+}
+
+impl Emitable for HwStats64 {
+    fn buffer_len(&self) -> usize {
+        HW_STATS64_LEN
+    }
+
+    fn emit(&self, buffer: &mut [u8]) {
+        let mut buffer = HwStats64Buffer::new(buffer);
+        buffer.set_rx_packets(self.rx_packets);
+        buffer.set_tx_packets(self.tx_packets);
+        buffer.set_rx_bytes(self.rx_bytes);
+        buffer.set_tx_bytes(self.tx_bytes);
+        buffer.set_rx_errors(self.rx_errors);
+        buffer.set_tx_errors(self.tx_errors);
+        buffer.set_rx_dropped(self.rx_dropped);
+        buffer.set_tx_dropped(self.tx_dropped);
+        buffer.set_multicast(self.multicast);
+    }
+}
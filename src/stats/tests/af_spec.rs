@@ -0,0 +1,69 @@
+// SPDX-License-Identifier: MIT
+
+use netlink_packet_utils::{Emitable, Parseable};
+
+use crate::{
+    stats::{
+        AfSpecMpls, MplsLinkStats, StatsAfSpec, StatsAttribute,
+        StatsFilterMask, StatsHeader, StatsMessage, StatsMessageBuffer,
+    },
+    AddressFamily,
+};
+
+// Hand-crafted RTM_NEWSTATS reply payload for MPLS enabled eth0 holding
+// interface index 2, equivalent to the one used by:
+//      ip stats show dev eth0 group afstats subgroup mpls
+#[test]
+fn test_stats_af_spec_mpls() {
+    let raw = vec![
+        0x00, // family AF_UNSPEC
+        0x00, 0x00, 0x00, // padding
+        0x02, 0x00, 0x00, 0x00, // ifindex 2
+        0x10, 0x00, 0x00, 0x00, // IFLA_STATS_FILTER_BIT(AF_SPEC)
+        0x54, 0x00, // length 84
+        0x05, 0x00, // IFLA_STATS_AF_SPEC
+        0x50, 0x00, // length 80
+        0x1c, 0x00, // AF_MPLS
+        0x4c, 0x00, // length 76
+        0x01, 0x00, // MPLS_STATS_LINK
+        0x0a, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, // rx_packets 10
+        0x14, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, // tx_packets 20
+        0xe8, 0x03, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, // rx_bytes 1000
+        0xd0, 0x07, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, // tx_bytes 2000
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, // rx_errors 0
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, // tx_errors 0
+        0x01, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, // rx_dropped 1
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, // tx_dropped 0
+        0x03, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, // rx_noroute 3
+    ];
+
+    let expected = StatsMessage {
+        header: StatsHeader {
+            family: AddressFamily::Unspec,
+            ifindex: 2,
+            filter_mask: StatsFilterMask::AfSpec,
+        },
+        attributes: vec![StatsAttribute::AfSpec(vec![StatsAfSpec::Mpls(
+            vec![AfSpecMpls::Stats(MplsLinkStats {
+                rx_packets: 10,
+                tx_packets: 20,
+                rx_bytes: 1000,
+                tx_bytes: 2000,
+                rx_errors: 0,
+                tx_errors: 0,
+                rx_dropped: 1,
+                tx_dropped: 0,
+                rx_noroute: 3,
+            })],
+        )])],
+    };
+
+    assert_eq!(
+        expected,
+        StatsMessage::parse(&StatsMessageBuffer::new(&raw)).unwrap()
+    );
+
+    let mut buf = vec![0; expected.buffer_len()];
+    expected.emit(&mut buf);
+    assert_eq!(buf, raw);
+}
@@ -0,0 +1,131 @@
+// SPDX-License-Identifier: MIT
+
+use netlink_packet_core::{NetlinkHeader, NetlinkMessage, NetlinkPayload};
+use netlink_packet_utils::{Emitable, Parseable};
+
+use crate::{
+    link::Stats64,
+    stats::{
+        StatsAttribute, StatsFilterMask, StatsHeader, StatsMessage,
+        StatsMessageBuffer,
+    },
+    AddressFamily, RouteNetlinkMessage,
+};
+
+// Hand-crafted RTM_NEWSTATS reply payload for eth0 holding interface index 2,
+// equivalent to the one used by:
+//      ip stats show dev eth0 group link
+#[test]
+fn test_stats_link_64() {
+    let raw = vec![
+        0x00, // family AF_UNSPEC
+        0x00, 0x00, 0x00, // padding
+        0x02, 0x00, 0x00, 0x00, // ifindex 2
+        0x01, 0x00, 0x00,
+        0x00, // filter_mask IFLA_STATS_FILTER_BIT(LINK_64)
+        0xcc, 0x00, // length 204
+        0x01, 0x00, // IFLA_STATS_LINK_64
+        0x10, 0x9a, 0x06, 0x00, 0x00, 0x00, 0x00,
+        0x00, // rx_packets 432656
+        0x2f, 0x2d, 0x04, 0x00, 0x00, 0x00, 0x00,
+        0x00, // tx_packets 273711
+        0x9c, 0x0b, 0x1f, 0x3b, 0x01, 0x00, 0x00,
+        0x00, // rx_bytes 5286857628
+        0xd1, 0x6c, 0x5c, 0x02, 0x00, 0x00, 0x00,
+        0x00, // tx_bytes 39611601
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, // rx_errors 0
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, // tx_errors 0
+        0x05, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, // rx_dropped 5
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, // tx_dropped 0
+        0x2a, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, // multicast 42
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, // collisions 0
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, // rx_length_errors 0
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, // rx_over_errors 0
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, // rx_crc_errors 0
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, // rx_frame_errors 0
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, // rx_fifo_errors 0
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, // rx_missed_errors 0
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, // tx_aborted_errors 0
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, // tx_carrier_errors 0
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, // tx_fifo_errors 0
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, // tx_heartbeat_errors 0
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, // tx_window_errors 0
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, // rx_compressed 0
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, // tx_compressed 0
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, // rx_nohandler 0
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, // rx_otherhost_dropped 0
+    ];
+
+    let expected = StatsMessage {
+        header: StatsHeader {
+            family: AddressFamily::Unspec,
+            ifindex: 2,
+            filter_mask: StatsFilterMask::Link64,
+        },
+        attributes: vec![StatsAttribute::Link64(Stats64 {
+            rx_packets: 432656,
+            tx_packets: 273711,
+            rx_bytes: 5286857628,
+            tx_bytes: 39611601,
+            rx_dropped: 5,
+            multicast: 42,
+            ..Default::default()
+        })],
+    };
+
+    assert_eq!(
+        expected,
+        StatsMessage::parse(&StatsMessageBuffer::new(&raw)).unwrap()
+    );
+
+    let mut buf = vec![0; expected.buffer_len()];
+    expected.emit(&mut buf);
+    assert_eq!(buf, raw);
+}
+
+// Hand-crafted RTM_GETSTATS dump request equivalent to the one used by:
+//      ip stats show group link
+#[test]
+fn test_stats_get_request() {
+    let raw = vec![
+        0x1c, 0x00, 0x00, 0x00, // length 28
+        0x5e, 0x00, // RTM_GETSTATS
+        0x01, 0x03, // NLM_F_REQUEST | NLM_F_DUMP
+        0x00, 0x00, 0x00, 0x00, // sequence number 0
+        0x00, 0x00, 0x00, 0x00, // port number 0
+        0x00, // family AF_UNSPEC
+        0x00, 0x00, 0x00, // padding
+        0x00, 0x00, 0x00, 0x00, // ifindex 0
+        0x01, 0x00, 0x00,
+        0x00, // filter_mask IFLA_STATS_FILTER_BIT(LINK_64)
+    ];
+
+    let msg = NetlinkMessage::<RouteNetlinkMessage>::deserialize(&raw).unwrap();
+    let expected = RouteNetlinkMessage::GetStats(StatsMessage {
+        header: StatsHeader {
+            filter_mask: StatsFilterMask::Link64,
+            ..Default::default()
+        },
+        attributes: vec![],
+    });
+    assert_eq!(msg.payload, NetlinkPayload::InnerMessage(expected.clone()));
+
+    let mut header = NetlinkHeader::default();
+    header.flags = 0x0301;
+    let mut msg = NetlinkMessage::new(header, expected.into());
+    msg.finalize();
+    let mut buf = vec![0; msg.buffer_len()];
+    msg.serialize(&mut buf);
+    assert_eq!(buf, raw);
+}
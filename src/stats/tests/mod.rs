@@ -0,0 +1,10 @@
+// SPDX-License-Identifier: MIT
+
+#[cfg(test)]
+mod af_spec;
+#[cfg(test)]
+mod message;
+#[cfg(test)]
+mod offload;
+#[cfg(test)]
+mod xstats;
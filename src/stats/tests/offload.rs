@@ -0,0 +1,111 @@
+// SPDX-License-Identifier: MIT
+
+use netlink_packet_utils::{nla::NlasIterator, Emitable, Parseable};
+
+use crate::{
+    link::Stats64,
+    stats::{
+        HwStats64, OffloadHwStatsInfo, OffloadHwStatsInfoState, OffloadXstats,
+    },
+};
+
+// Hand-crafted payload of IFLA_STATS_LINK_OFFLOAD_XSTATS for a switchdev
+// port with L3 hardware statistics enabled, equivalent to the one used by:
+//      ip stats set dev swp1 l3_stats on
+//      ip stats show dev swp1 group offload
+#[test]
+fn test_offload_xstats() {
+    let raw = vec![
+        0xcc, 0x00, // length 204
+        0x01, 0x00, // IFLA_OFFLOAD_XSTATS_CPU_HIT
+        0x0c, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, // rx_packets 12
+        0x07, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, // tx_packets 7
+        0x80, 0x04, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, // rx_bytes 1152
+        0x32, 0x03, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, // tx_bytes 818
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, // rx_errors 0
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, // tx_errors 0
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, // rx_dropped 0
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, // tx_dropped 0
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, // multicast 0
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, // collisions 0
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, // rx_length_errors 0
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, // rx_over_errors 0
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, // rx_crc_errors 0
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, // rx_frame_errors 0
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, // rx_fifo_errors 0
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, // rx_missed_errors 0
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, // tx_aborted_errors 0
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, // tx_carrier_errors 0
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, // tx_fifo_errors 0
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, // tx_heartbeat_errors 0
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, // tx_window_errors 0
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, // rx_compressed 0
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, // tx_compressed 0
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, // rx_nohandler 0
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, // rx_otherhost_dropped 0
+        0x18, 0x00, // length 24
+        0x02, 0x00, // IFLA_OFFLOAD_XSTATS_HW_S_INFO
+        0x14, 0x00, // length 20
+        0x03, 0x00, // IFLA_OFFLOAD_XSTATS_L3_STATS
+        0x05, 0x00, // length 5
+        0x01, 0x00, // IFLA_OFFLOAD_XSTATS_HW_S_INFO_REQUEST
+        0x01, 0x00, 0x00, 0x00, // true and padding
+        0x05, 0x00, // length 5
+        0x02, 0x00, // IFLA_OFFLOAD_XSTATS_HW_S_INFO_USED
+        0x01, 0x00, 0x00, 0x00, // true and padding
+        0x4c, 0x00, // length 76
+        0x03, 0x00, // IFLA_OFFLOAD_XSTATS_L3_STATS
+        0xe8, 0x03, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, // rx_packets 1000
+        0x84, 0x03, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, // tx_packets 900
+        0x00, 0xf4, 0x01, 0x00, 0x00, 0x00, 0x00, 0x00, // rx_bytes 128000
+        0x00, 0xc2, 0x01, 0x00, 0x00, 0x00, 0x00, 0x00, // tx_bytes 115200
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, // rx_errors 0
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, // tx_errors 0
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, // rx_dropped 0
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, // tx_dropped 0
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, // multicast 0
+    ];
+
+    let expected = vec![
+        OffloadXstats::CpuHit(Stats64 {
+            rx_packets: 12,
+            tx_packets: 7,
+            rx_bytes: 1152,
+            tx_bytes: 818,
+            ..Default::default()
+        }),
+        OffloadXstats::HwStatsInfo(vec![OffloadHwStatsInfo::L3Stats(vec![
+            OffloadHwStatsInfoState::Request(true),
+            OffloadHwStatsInfoState::Used(true),
+        ])]),
+        OffloadXstats::L3Stats(HwStats64 {
+            rx_packets: 1000,
+            tx_packets: 900,
+            rx_bytes: 128000,
+            tx_bytes: 115200,
+            ..Default::default()
+        }),
+    ];
+
+    let parsed: Vec<OffloadXstats> = NlasIterator::new(&raw)
+        .map(|nla| OffloadXstats::parse(&nla.unwrap()).unwrap())
+        .collect();
+    assert_eq!(expected, parsed);
+
+    let mut buf = vec![0; expected.as_slice().buffer_len()];
+
+    expected.as_slice().emit(&mut buf);
+
+    assert_eq!(buf, raw);
+}
@@ -0,0 +1,95 @@
+// SPDX-License-Identifier: MIT
+
+use netlink_packet_utils::{nla::NlaBuffer, Emitable, Parseable};
+
+use crate::stats::{BridgeMcastStats, BridgeXstats, StatsXstats};
+
+// Hand-crafted LINK_XSTATS_TYPE_BRIDGE attribute nested in
+// IFLA_STATS_LINK_XSTATS of RTM_NEWSTATS reply for a bridge, equivalent to
+// the one used by:
+//      ip stats show dev br0 group xstats subgroup bridge suite mcast
+#[test]
+fn test_bridge_mcast_xstats() {
+    let raw = vec![
+        0xf8, 0x00, // length 248
+        0x01, 0x00, // LINK_XSTATS_TYPE_BRIDGE
+        0xf4, 0x00, // length 244
+        0x02, 0x00, // BRIDGE_XSTATS_MCAST
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, // igmp_v1queries_rx 0
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, // igmp_v1queries_tx 0
+        0x03, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, // igmp_v2queries_rx 3
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, // igmp_v2queries_tx 0
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, // igmp_v3queries_rx 0
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, // igmp_v3queries_tx 0
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, // igmp_leaves_rx 0
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, // igmp_leaves_tx 0
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, // igmp_v1reports_rx 0
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, // igmp_v1reports_tx 0
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, // igmp_v2reports_rx 0
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, // igmp_v2reports_tx 0
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, // igmp_v3reports_rx 0
+        0x05, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, // igmp_v3reports_tx 5
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, // igmp_parse_errors 0
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, // mld_v1queries_rx 0
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, // mld_v1queries_tx 0
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, // mld_v2queries_rx 0
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, // mld_v2queries_tx 0
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, // mld_leaves_rx 0
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, // mld_leaves_tx 0
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, // mld_v1reports_rx 0
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, // mld_v1reports_tx 0
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, // mld_v2reports_rx 0
+        0x02, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, // mld_v2reports_tx 2
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, // mld_parse_errors 0
+        0x58, 0x02, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, // mcast_bytes_rx 600
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, // mcast_bytes_tx 0
+        0x0a, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, // mcast_packets_rx 10
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, // mcast_packets_tx 0
+    ];
+
+    let expected =
+        StatsXstats::Bridge(vec![BridgeXstats::Mcast(BridgeMcastStats {
+            igmp_v2queries_rx: 3,
+            igmp_v3reports_tx: 5,
+            mld_v2reports_tx: 2,
+            mcast_bytes_rx: 600,
+            mcast_packets_rx: 10,
+            ..Default::default()
+        })]);
+
+    assert_eq!(expected, StatsXstats::parse(&NlaBuffer::new(&raw)).unwrap());
+
+    let mut buf = vec![0; expected.buffer_len()];
+
+    expected.emit(&mut buf);
+
+    assert_eq!(buf, raw);
+}
@@ -0,0 +1,85 @@
+// SPDX-License-Identifier: MIT
+
+use anyhow::Context;
+use netlink_packet_utils::{
+    nla::{DefaultNla, Nla, NlaBuffer, NlasIterator},
+    traits::{Emitable, Parseable},
+    DecodeError,
+};
+
+use super::{BondXstats, BridgeXstats};
+
+const LINK_XSTATS_TYPE_BRIDGE: u16 = 1;
+const LINK_XSTATS_TYPE_BOND: u16 = 2;
+
+/// Per-kind extended statistics nested in `IFLA_STATS_LINK_XSTATS` and
+/// `IFLA_STATS_LINK_XSTATS_SLAVE`.
+///
+/// Unlike `IFLA_INFO_XSTATS` of link message, the kind of interface is
+/// identified by the `LINK_XSTATS_TYPE_*` of each attribute, hence no
+/// [crate::link::InfoKind] is required for parsing.
+#[derive(Debug, PartialEq, Eq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[non_exhaustive]
+pub enum StatsXstats {
+    Bridge(Vec<BridgeXstats>),
+    Bond(Vec<BondXstats>),
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_nla"))]
+    Other(DefaultNla),
+}
+
+impl Nla for StatsXstats {
+    fn value_len(&self) -> usize {
+        match self {
+            Self::Bridge(v) => v.as_slice().buffer_len(),
+            Self::Bond(v) => v.as_slice().buffer_len(),
+            Self::Other(attr) => attr.value_len(),
+        }
+    }
+
+    fn emit_value(&self, buffer: &mut [u8]) {
+        match self {
+            Self::Bridge(v) => v.as_slice().emit(buffer),
+            Self::Bond(v) => v.as_slice().emit(buffer),
+            Self::Other(attr) => attr.emit_value(buffer),
+        }
+    }
+
+    fn kind(&self) -> u16 {
+        match self {
+            Self::Bridge(_) => LINK_XSTATS_TYPE_BRIDGE,
+            Self::Bond(_) => LINK_XSTATS_TYPE_BOND,
+            Self::Other(attr) => attr.kind(),
+        }
+    }
+}
+
+impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>> for StatsXstats {
+    fn parse(buf: &NlaBuffer<&'a T>) -> Result<Self, DecodeError> {
+        let payload = buf.value();
+        Ok(match buf.kind() {
+            LINK_XSTATS_TYPE_BRIDGE => {
+                let mut stats = vec![];
+                for nla in NlasIterator::new(payload) {
+                    let nla =
+                        nla.context("invalid LINK_XSTATS_TYPE_BRIDGE value")?;
+                    stats.push(BridgeXstats::parse(&nla)?);
+                }
+                Self::Bridge(stats)
+            }
+            LINK_XSTATS_TYPE_BOND => {
+                let mut stats = vec![];
+                for nla in NlasIterator::new(payload) {
+                    let nla =
+                        nla.context("invalid LINK_XSTATS_TYPE_BOND value")?;
+                    stats.push(BondXstats::parse(&nla)?);
+                }
+                Self::Bond(stats)
+            }
+            _ => Self::Other(
+                DefaultNla::parse(buf)
+                    .context("invalid link xstats NLA (unknown kind)")?,
+            ),
+        })
+    }
+}
@@ -0,0 +1,36 @@
+// SPDX-License-Identifier: MIT
+
+use std::cell::Cell;
+
+thread_local! {
+    static STRICT_PARSING: Cell<bool> = const { Cell::new(false) };
+}
+
+/// Enable or disable strict parsing on the current thread, disabled by
+/// default.
+///
+/// Fixed-size statistics are normally parsed leniently: data shorter than
+/// expected (older kernel) is padded with zero and data longer than expected
+/// (newer kernel) is either ignored or preserved as unknown data. In strict
+/// mode, any size mismatch of [crate::link::Stats64],
+/// [crate::link::Inet6Stats], [crate::link::Icmp6Stats] or
+/// [crate::tc::TcStatsBasic] fails the parsing with a `DecodeError` instead,
+/// which is useful for validation or fuzzing.
+///
+/// ```
+/// use netlink_packet_route::{is_strict_parsing, set_strict_parsing};
+///
+/// set_strict_parsing(true);
+/// assert!(is_strict_parsing());
+/// // parse ...
+/// set_strict_parsing(false);
+/// ```
+pub fn set_strict_parsing(enabled: bool) {
+    STRICT_PARSING.with(|s| s.set(enabled));
+}
+
+/// Whether strict parsing is enabled on the current thread, see
+/// [set_strict_parsing()].
+pub fn is_strict_parsing() -> bool {
+    STRICT_PARSING.with(|s| s.get())
+}
@@ -13,7 +13,10 @@ use netlink_packet_utils::{
 use crate::tc::TcStats2;
 
 use super::{
-    TcActionMirror, TcActionMirrorOption, TcActionNat, TcActionNatOption,
+    TcActionCt, TcActionCtOption, TcActionGact, TcActionGactOption,
+    TcActionMirror, TcActionMirrorOption, TcActionMpls, TcActionMplsOption,
+    TcActionNat, TcActionNatOption, TcActionPedit, TcActionPeditOption,
+    TcActionSample, TcActionSampleOption,
 };
 
 /// TODO: determine when and why to use this as opposed to the buffer's `kind`.
@@ -23,6 +26,7 @@ const TCA_ACT_TAB: u16 = 1;
 ///
 /// [tc-action]: https://man7.org/linux/man-pages/man8/tc-actions.8.html
 #[derive(Debug, PartialEq, Eq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[non_exhaustive]
 pub struct TcAction {
     /// Table id.
@@ -126,6 +130,7 @@ const TCA_ACT_IN_HW_COUNT: u16 = 10;
 
 /// Attributes of a traffic control action.
 #[derive(Debug, PartialEq, Eq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[non_exhaustive]
 pub enum TcActionAttribute {
     /// The [`Kind`] (general type or class) of the action (e.g. "mirred",
@@ -168,11 +173,13 @@ pub enum TcActionAttribute {
     /// data.
     ///
     /// [`Cookie`]: #variant.Cookie
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_hex"))]
     Cookie(Vec<u8>),
     /// Number of times the action has been installed in hardware.
     InHwCount(u32),
     /// Other attributes unknown at the time of writing or not yet supported by
     /// this library.
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_nla"))]
     Other(DefaultNla),
 }
 
@@ -276,6 +283,7 @@ where
 ///
 /// [tc-actions]: https://man7.org/linux/man-pages/man8/tc-actions.8.html
 #[derive(Debug, PartialEq, Eq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[non_exhaustive]
 pub enum TcActionOption {
     /// Mirror options.
@@ -287,7 +295,31 @@ pub enum TcActionOption {
     ///
     /// These options type can be used to perform network address translation.
     Nat(TcActionNatOption),
+    /// Generic action options.
+    ///
+    /// These options can be used to drop, pass, reclassify or pipe packets.
+    Gact(TcActionGactOption),
+    /// Packet edit options.
+    ///
+    /// These options can be used to rewrite arbitrary packet data, like
+    /// header fields.
+    Pedit(TcActionPeditOption),
+    /// Connection tracking options.
+    ///
+    /// These options can be used to commit, clear or NAT the connection of
+    /// packets.
+    Ct(TcActionCtOption),
+    /// MPLS options.
+    ///
+    /// These options can be used to push, pop or modify the MPLS label stack
+    /// of packets.
+    Mpls(TcActionMplsOption),
+    /// Sample options.
+    ///
+    /// These options can be used to sample packets into a `psample` group.
+    Sample(TcActionSampleOption),
     /// Other action types not yet supported by this library.
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_nla"))]
     Other(DefaultNla),
 }
 
@@ -296,6 +328,11 @@ impl Nla for TcActionOption {
         match self {
             Self::Mirror(nla) => nla.value_len(),
             Self::Nat(nla) => nla.value_len(),
+            Self::Gact(nla) => nla.value_len(),
+            Self::Pedit(nla) => nla.value_len(),
+            Self::Ct(nla) => nla.value_len(),
+            Self::Mpls(nla) => nla.value_len(),
+            Self::Sample(nla) => nla.value_len(),
             Self::Other(nla) => nla.value_len(),
         }
     }
@@ -304,6 +341,11 @@ impl Nla for TcActionOption {
         match self {
             Self::Mirror(nla) => nla.emit_value(buffer),
             Self::Nat(nla) => nla.emit_value(buffer),
+            Self::Gact(nla) => nla.emit_value(buffer),
+            Self::Pedit(nla) => nla.emit_value(buffer),
+            Self::Ct(nla) => nla.emit_value(buffer),
+            Self::Mpls(nla) => nla.emit_value(buffer),
+            Self::Sample(nla) => nla.emit_value(buffer),
             Self::Other(nla) => nla.emit_value(buffer),
         }
     }
@@ -312,6 +354,11 @@ impl Nla for TcActionOption {
         match self {
             Self::Mirror(nla) => nla.kind(),
             Self::Nat(nla) => nla.kind(),
+            Self::Gact(nla) => nla.kind(),
+            Self::Pedit(nla) => nla.kind(),
+            Self::Ct(nla) => nla.kind(),
+            Self::Mpls(nla) => nla.kind(),
+            Self::Sample(nla) => nla.kind(),
             Self::Other(nla) => nla.kind(),
         }
     }
@@ -335,6 +382,26 @@ where
                 TcActionNatOption::parse(buf)
                     .context("failed to parse nat action")?,
             ),
+            TcActionGact::KIND => Self::Gact(
+                TcActionGactOption::parse(buf)
+                    .context("failed to parse gact action")?,
+            ),
+            TcActionPedit::KIND => Self::Pedit(
+                TcActionPeditOption::parse(buf)
+                    .context("failed to parse pedit action")?,
+            ),
+            TcActionCt::KIND => Self::Ct(
+                TcActionCtOption::parse(buf)
+                    .context("failed to parse ct action")?,
+            ),
+            TcActionMpls::KIND => Self::Mpls(
+                TcActionMplsOption::parse(buf)
+                    .context("failed to parse mpls action")?,
+            ),
+            TcActionSample::KIND => Self::Sample(
+                TcActionSampleOption::parse(buf)
+                    .context("failed to parse sample action")?,
+            ),
             _ => Self::Other(
                 DefaultNla::parse(buf)
                     .context("failed to parse action options")?,
@@ -352,6 +419,7 @@ where
 ///
 /// [`tc_gen`]: https://elixir.bootlin.com/linux/v6.8.9/source/include/uapi/linux/pkt_cls.h#L179
 #[derive(Debug, PartialEq, Eq, Clone, Copy, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[non_exhaustive]
 pub struct TcActionGeneric {
     /// The [`index`] of the action is a unique identifier used to track
@@ -450,6 +518,59 @@ impl<T: AsRef<[u8]>> Parseable<TcActionGenericBuffer<T>> for TcActionGeneric {
     }
 }
 
+/// Timestamps of a traffic control action, in clock ticks (`USER_HZ`).
+///
+/// See `struct tcf_t` in `linux/pkt_cls.h`.
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[non_exhaustive]
+pub struct Tcf {
+    /// Time elapsed since the action was installed.
+    pub install: u64,
+    /// Time elapsed since the action was last used.
+    pub lastuse: u64,
+    /// Time left before the action expires, unused by most actions.
+    pub expires: u64,
+    /// Time elapsed since the action was first used.
+    pub firstuse: u64,
+}
+
+impl Tcf {
+    pub(crate) const BUF_LEN: usize = 32;
+}
+
+buffer!(TcfBuffer(Tcf::BUF_LEN) {
+    install: (u64, 0..8),
+    lastuse: (u64, 8..16),
+    expires: (u64, 16..24),
+    firstuse: (u64, 24..32),
+});
+
+impl Emitable for Tcf {
+    fn buffer_len(&self) -> usize {
+        Self::BUF_LEN
+    }
+
+    fn emit(&self, buffer: &mut [u8]) {
+        let mut packet = TcfBuffer::new(buffer);
+        packet.set_install(self.install);
+        packet.set_lastuse(self.lastuse);
+        packet.set_expires(self.expires);
+        packet.set_firstuse(self.firstuse);
+    }
+}
+
+impl<T: AsRef<[u8]>> Parseable<TcfBuffer<T>> for Tcf {
+    fn parse(buf: &TcfBuffer<T>) -> Result<Self, DecodeError> {
+        Ok(Self {
+            install: buf.install(),
+            lastuse: buf.lastuse(),
+            expires: buf.expires(),
+            firstuse: buf.firstuse(),
+        })
+    }
+}
+
 const TC_ACT_UNSPEC: i32 = -1;
 const TC_ACT_OK: i32 = 0;
 const TC_ACT_RECLASSIFY: i32 = 1;
@@ -469,6 +590,7 @@ const TC_ACT_TRAP: i32 = 8;
 /// This enum is non-exhaustive as new action types may be added to the kernel
 /// at any time.
 #[derive(Debug, PartialEq, Eq, Clone, Copy, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[non_exhaustive]
 pub enum TcActionType {
     /// No specific outcome specified (i.e., take the default for that action).
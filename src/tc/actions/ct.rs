@@ -0,0 +1,297 @@
+// SPDX-License-Identifier: MIT
+
+/// Connection tracking action
+///
+/// The ct action sends the packet to the conntrack module to commit, clear
+/// or NAT the connection it belongs to.
+use std::net::{Ipv4Addr, Ipv6Addr};
+
+use anyhow::Context;
+use byteorder::{BigEndian, ByteOrder, NativeEndian};
+use netlink_packet_utils::{
+    nla::{DefaultNla, Nla, NlaBuffer},
+    parsers::{parse_string, parse_u16, parse_u16_be, parse_u32, parse_u8},
+    traits::{Emitable, Parseable},
+    DecodeError,
+};
+
+use super::{
+    TcActionGeneric, TcActionGenericBuffer, TcCtActionFlags, Tcf, TcfBuffer,
+};
+use crate::ip::{parse_ipv4_addr, parse_ipv6_addr};
+
+const TCA_CT_PARMS: u16 = 1;
+const TCA_CT_TM: u16 = 2;
+const TCA_CT_ACTION: u16 = 3;
+const TCA_CT_ZONE: u16 = 4;
+const TCA_CT_MARK: u16 = 5;
+const TCA_CT_MARK_MASK: u16 = 6;
+const TCA_CT_LABELS: u16 = 7;
+const TCA_CT_LABELS_MASK: u16 = 8;
+const TCA_CT_NAT_IPV4_MIN: u16 = 9;
+const TCA_CT_NAT_IPV4_MAX: u16 = 10;
+const TCA_CT_NAT_IPV6_MIN: u16 = 11;
+const TCA_CT_NAT_IPV6_MAX: u16 = 12;
+const TCA_CT_NAT_PORT_MIN: u16 = 13;
+const TCA_CT_NAT_PORT_MAX: u16 = 14;
+// const TCA_CT_PAD: u16 = 15;
+const TCA_CT_HELPER_NAME: u16 = 16;
+const TCA_CT_HELPER_FAMILY: u16 = 17;
+const TCA_CT_HELPER_PROTO: u16 = 18;
+
+const CT_LABELS_LEN: usize = 16;
+
+/// Traffic control action used to perform connection tracking.
+#[derive(Debug, PartialEq, Eq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[non_exhaustive]
+pub struct TcActionCt {}
+
+impl TcActionCt {
+    /// The `TcActionAttribute::Kind` of this action.
+    pub const KIND: &'static str = "ct";
+}
+
+/// Options for the [`TcActionCt`] action.
+#[derive(Debug, PartialEq, Eq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[non_exhaustive]
+pub enum TcActionCtOption {
+    /// Parameters for the ct action.
+    Parms(TcCt),
+    /// Timestamps of the action.
+    Tm(Tcf),
+    /// What to do with the connection.
+    Action(TcCtActionFlags),
+    /// Conntrack zone.
+    Zone(u16),
+    /// Mark to set on the connection when committing.
+    Mark(u32),
+    /// Bits of [Self::Mark] to set.
+    MarkMask(u32),
+    /// Labels to set on the connection when committing.
+    Labels([u8; CT_LABELS_LEN]),
+    /// Bits of [Self::Labels] to set.
+    LabelsMask([u8; CT_LABELS_LEN]),
+    /// Start of the IPv4 NAT address range.
+    NatIpv4Min(Ipv4Addr),
+    /// End of the IPv4 NAT address range.
+    NatIpv4Max(Ipv4Addr),
+    /// Start of the IPv6 NAT address range.
+    NatIpv6Min(Ipv6Addr),
+    /// End of the IPv6 NAT address range.
+    NatIpv6Max(Ipv6Addr),
+    /// Start of the NAT port range.
+    NatPortMin(u16),
+    /// End of the NAT port range.
+    NatPortMax(u16),
+    /// Name of the conntrack helper, e.g. `ftp`.
+    HelperName(String),
+    /// Address family of the conntrack helper.
+    HelperFamily(u8),
+    /// IP protocol of the conntrack helper.
+    HelperProto(u8),
+    /// Other attributes unknown at the time of writing.
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_nla"))]
+    Other(DefaultNla),
+}
+
+impl Nla for TcActionCtOption {
+    fn value_len(&self) -> usize {
+        match self {
+            Self::Parms(v) => v.buffer_len(),
+            Self::Tm(v) => v.buffer_len(),
+            Self::Action(_)
+            | Self::Zone(_)
+            | Self::NatPortMin(_)
+            | Self::NatPortMax(_) => 2,
+            Self::Mark(_)
+            | Self::MarkMask(_)
+            | Self::NatIpv4Min(_)
+            | Self::NatIpv4Max(_) => 4,
+            Self::Labels(_)
+            | Self::LabelsMask(_)
+            | Self::NatIpv6Min(_)
+            | Self::NatIpv6Max(_) => 16,
+            Self::HelperName(s) => s.len() + 1,
+            Self::HelperFamily(_) | Self::HelperProto(_) => 1,
+            Self::Other(attr) => attr.value_len(),
+        }
+    }
+
+    fn emit_value(&self, buffer: &mut [u8]) {
+        match self {
+            Self::Parms(p) => p.emit(buffer),
+            Self::Tm(v) => v.emit(buffer),
+            Self::Action(f) => NativeEndian::write_u16(buffer, f.bits()),
+            Self::Zone(v) => NativeEndian::write_u16(buffer, *v),
+            Self::Mark(v) | Self::MarkMask(v) => {
+                NativeEndian::write_u32(buffer, *v)
+            }
+            Self::Labels(v) | Self::LabelsMask(v) => {
+                buffer.copy_from_slice(v.as_slice())
+            }
+            Self::NatIpv4Min(v) | Self::NatIpv4Max(v) => {
+                buffer.copy_from_slice(&v.octets())
+            }
+            Self::NatIpv6Min(v) | Self::NatIpv6Max(v) => {
+                buffer.copy_from_slice(&v.octets())
+            }
+            // Kernel is using big endian for NAT ports
+            Self::NatPortMin(v) | Self::NatPortMax(v) => {
+                BigEndian::write_u16(buffer, *v)
+            }
+            Self::HelperName(s) => {
+                buffer[..s.len()].copy_from_slice(s.as_bytes());
+                buffer[s.len()] = 0;
+            }
+            Self::HelperFamily(v) | Self::HelperProto(v) => buffer[0] = *v,
+            Self::Other(attr) => attr.emit_value(buffer),
+        }
+    }
+
+    fn kind(&self) -> u16 {
+        match self {
+            Self::Parms(_) => TCA_CT_PARMS,
+            Self::Tm(_) => TCA_CT_TM,
+            Self::Action(_) => TCA_CT_ACTION,
+            Self::Zone(_) => TCA_CT_ZONE,
+            Self::Mark(_) => TCA_CT_MARK,
+            Self::MarkMask(_) => TCA_CT_MARK_MASK,
+            Self::Labels(_) => TCA_CT_LABELS,
+            Self::LabelsMask(_) => TCA_CT_LABELS_MASK,
+            Self::NatIpv4Min(_) => TCA_CT_NAT_IPV4_MIN,
+            Self::NatIpv4Max(_) => TCA_CT_NAT_IPV4_MAX,
+            Self::NatIpv6Min(_) => TCA_CT_NAT_IPV6_MIN,
+            Self::NatIpv6Max(_) => TCA_CT_NAT_IPV6_MAX,
+            Self::NatPortMin(_) => TCA_CT_NAT_PORT_MIN,
+            Self::NatPortMax(_) => TCA_CT_NAT_PORT_MAX,
+            Self::HelperName(_) => TCA_CT_HELPER_NAME,
+            Self::HelperFamily(_) => TCA_CT_HELPER_FAMILY,
+            Self::HelperProto(_) => TCA_CT_HELPER_PROTO,
+            Self::Other(nla) => nla.kind(),
+        }
+    }
+}
+
+impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>>
+    for TcActionCtOption
+{
+    fn parse(buf: &NlaBuffer<&'a T>) -> Result<Self, DecodeError> {
+        let payload = buf.value();
+        Ok(match buf.kind() {
+            TCA_CT_PARMS => Self::Parms(
+                TcCt::parse(&TcCtBuffer::new_checked(payload)?)
+                    .context("failed to parse TCA_CT_PARMS")?,
+            ),
+            TCA_CT_TM => Self::Tm(
+                Tcf::parse(&TcfBuffer::new_checked(payload)?)
+                    .context("failed to parse TCA_CT_TM")?,
+            ),
+            TCA_CT_ACTION => Self::Action(TcCtActionFlags::from_bits_retain(
+                parse_u16(payload).context("failed to parse TCA_CT_ACTION")?,
+            )),
+            TCA_CT_ZONE => Self::Zone(
+                parse_u16(payload).context("failed to parse TCA_CT_ZONE")?,
+            ),
+            TCA_CT_MARK => Self::Mark(
+                parse_u32(payload).context("failed to parse TCA_CT_MARK")?,
+            ),
+            TCA_CT_MARK_MASK => Self::MarkMask(
+                parse_u32(payload)
+                    .context("failed to parse TCA_CT_MARK_MASK")?,
+            ),
+            TCA_CT_LABELS => Self::Labels(
+                parse_labels(payload)
+                    .context("failed to parse TCA_CT_LABELS")?,
+            ),
+            TCA_CT_LABELS_MASK => Self::LabelsMask(
+                parse_labels(payload)
+                    .context("failed to parse TCA_CT_LABELS_MASK")?,
+            ),
+            TCA_CT_NAT_IPV4_MIN => Self::NatIpv4Min(
+                parse_ipv4_addr(payload)
+                    .context("failed to parse TCA_CT_NAT_IPV4_MIN")?,
+            ),
+            TCA_CT_NAT_IPV4_MAX => Self::NatIpv4Max(
+                parse_ipv4_addr(payload)
+                    .context("failed to parse TCA_CT_NAT_IPV4_MAX")?,
+            ),
+            TCA_CT_NAT_IPV6_MIN => Self::NatIpv6Min(
+                parse_ipv6_addr(payload)
+                    .context("failed to parse TCA_CT_NAT_IPV6_MIN")?,
+            ),
+            TCA_CT_NAT_IPV6_MAX => Self::NatIpv6Max(
+                parse_ipv6_addr(payload)
+                    .context("failed to parse TCA_CT_NAT_IPV6_MAX")?,
+            ),
+            TCA_CT_NAT_PORT_MIN => Self::NatPortMin(
+                parse_u16_be(payload)
+                    .context("failed to parse TCA_CT_NAT_PORT_MIN")?,
+            ),
+            TCA_CT_NAT_PORT_MAX => Self::NatPortMax(
+                parse_u16_be(payload)
+                    .context("failed to parse TCA_CT_NAT_PORT_MAX")?,
+            ),
+            TCA_CT_HELPER_NAME => Self::HelperName(
+                parse_string(payload)
+                    .context("failed to parse TCA_CT_HELPER_NAME")?,
+            ),
+            TCA_CT_HELPER_FAMILY => Self::HelperFamily(
+                parse_u8(payload)
+                    .context("failed to parse TCA_CT_HELPER_FAMILY")?,
+            ),
+            TCA_CT_HELPER_PROTO => Self::HelperProto(
+                parse_u8(payload)
+                    .context("failed to parse TCA_CT_HELPER_PROTO")?,
+            ),
+            _ => Self::Other(DefaultNla::parse(buf)?),
+        })
+    }
+}
+
+fn parse_labels(payload: &[u8]) -> Result<[u8; CT_LABELS_LEN], DecodeError> {
+    payload.try_into().map_err(|_| {
+        DecodeError::from(format!(
+            "invalid conntrack labels, expecting {CT_LABELS_LEN} bytes, but \
+             got {payload:?}"
+        ))
+    })
+}
+
+const TC_CT_BUF_LEN: usize = TcActionGeneric::BUF_LEN;
+
+/// Parameters for the ct action.
+#[derive(Debug, PartialEq, Eq, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[non_exhaustive]
+pub struct TcCt {
+    /// Common attributes for all actions.
+    pub generic: TcActionGeneric,
+}
+
+// kernel struct `tc_ct`
+buffer!(TcCtBuffer(TC_CT_BUF_LEN) {
+    generic: (slice, 0..TC_CT_BUF_LEN),
+});
+
+impl Emitable for TcCt {
+    fn buffer_len(&self) -> usize {
+        TC_CT_BUF_LEN
+    }
+
+    fn emit(&self, buffer: &mut [u8]) {
+        let mut packet = TcCtBuffer::new(buffer);
+        self.generic.emit(packet.generic_mut());
+    }
+}
+
+impl<T: AsRef<[u8]> + ?Sized> Parseable<TcCtBuffer<&T>> for TcCt {
+    fn parse(buf: &TcCtBuffer<&T>) -> Result<Self, DecodeError> {
+        Ok(Self {
+            generic: TcActionGeneric::parse(&TcActionGenericBuffer::new(
+                buf.generic(),
+            ))?,
+        })
+    }
+}
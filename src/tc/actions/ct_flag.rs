@@ -0,0 +1,31 @@
+// SPDX-License-Identifier: MIT
+
+const TCA_CT_ACT_COMMIT: u16 = 1 << 0;
+const TCA_CT_ACT_FORCE: u16 = 1 << 1;
+const TCA_CT_ACT_CLEAR: u16 = 1 << 2;
+const TCA_CT_ACT_NAT: u16 = 1 << 3;
+const TCA_CT_ACT_NAT_SRC: u16 = 1 << 4;
+const TCA_CT_ACT_NAT_DST: u16 = 1 << 5;
+
+bitflags! {
+    /// Connection tracking action flags.
+    #[derive(Debug, PartialEq, Eq, Clone, Copy, Default)]
+    #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+    #[non_exhaustive]
+    pub struct TcCtActionFlags: u16 {
+        /// Commit the connection to the conntrack table.
+        const Commit = TCA_CT_ACT_COMMIT;
+        /// Commit as a new connection if the existing one is in the other
+        /// direction.
+        const Force = TCA_CT_ACT_FORCE;
+        /// Clear the conntrack information of the packet.
+        const Clear = TCA_CT_ACT_CLEAR;
+        /// Perform NAT on the connection.
+        const Nat = TCA_CT_ACT_NAT;
+        /// Source NAT, used with [Self::Nat].
+        const NatSrc = TCA_CT_ACT_NAT_SRC;
+        /// Destination NAT, used with [Self::Nat].
+        const NatDst = TCA_CT_ACT_NAT_DST;
+        const _ = !0;
+    }
+}
@@ -0,0 +1,221 @@
+// SPDX-License-Identifier: MIT
+
+/// Generic action
+///
+/// The gact action performs one of the generic control actions (e.g. `drop`,
+/// `pass`, `reclassify`, `pipe`) on the packet it receives, optionally chosen
+/// by probability.
+use netlink_packet_utils::{
+    nla::{DefaultNla, Nla, NlaBuffer},
+    traits::{Emitable, Parseable},
+    DecodeError,
+};
+
+use super::{
+    TcActionGeneric, TcActionGenericBuffer, TcActionType, Tcf, TcfBuffer,
+};
+
+const TCA_GACT_TM: u16 = 1;
+const TCA_GACT_PARMS: u16 = 2;
+const TCA_GACT_PROB: u16 = 3;
+
+/// Traffic control action used to drop, pass or reclassify packets.
+#[derive(Debug, PartialEq, Eq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[non_exhaustive]
+pub struct TcActionGact {}
+
+impl TcActionGact {
+    /// The `TcActionAttribute::Kind` of this action.
+    pub const KIND: &'static str = "gact";
+}
+
+/// Options for the [`TcActionGact`] action.
+#[derive(Debug, PartialEq, Eq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[non_exhaustive]
+pub enum TcActionGactOption {
+    /// Timestamps of the action.
+    Tm(Tcf),
+    /// Parameters for the gact action.
+    Parms(TcGact),
+    /// Probability based action selection.
+    Prob(TcGactProb),
+    /// Other attributes unknown at the time of writing.
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_nla"))]
+    Other(DefaultNla),
+}
+
+impl Nla for TcActionGactOption {
+    fn value_len(&self) -> usize {
+        match self {
+            Self::Tm(v) => v.buffer_len(),
+            Self::Parms(v) => v.buffer_len(),
+            Self::Prob(v) => v.buffer_len(),
+            Self::Other(attr) => attr.value_len(),
+        }
+    }
+
+    fn emit_value(&self, buffer: &mut [u8]) {
+        match self {
+            Self::Tm(v) => v.emit(buffer),
+            Self::Parms(p) => p.emit(buffer),
+            Self::Prob(p) => p.emit(buffer),
+            Self::Other(attr) => attr.emit_value(buffer),
+        }
+    }
+
+    fn kind(&self) -> u16 {
+        match self {
+            Self::Tm(_) => TCA_GACT_TM,
+            Self::Parms(_) => TCA_GACT_PARMS,
+            Self::Prob(_) => TCA_GACT_PROB,
+            Self::Other(nla) => nla.kind(),
+        }
+    }
+}
+
+impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>>
+    for TcActionGactOption
+{
+    fn parse(buf: &NlaBuffer<&'a T>) -> Result<Self, DecodeError> {
+        let payload = buf.value();
+        Ok(match buf.kind() {
+            TCA_GACT_TM => {
+                Self::Tm(Tcf::parse(&TcfBuffer::new_checked(payload)?)?)
+            }
+            TCA_GACT_PARMS => Self::Parms(TcGact::parse(
+                &TcGactBuffer::new_checked(payload)?,
+            )?),
+            TCA_GACT_PROB => Self::Prob(TcGactProb::parse(
+                &TcGactProbBuffer::new_checked(payload)?,
+            )?),
+            _ => Self::Other(DefaultNla::parse(buf)?),
+        })
+    }
+}
+
+const TC_GACT_BUF_LEN: usize = TcActionGeneric::BUF_LEN;
+
+/// Parameters for the gact action.
+#[derive(Debug, PartialEq, Eq, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[non_exhaustive]
+pub struct TcGact {
+    /// Generic action parameters, `generic.action` holds the action to
+    /// perform on the packet.
+    pub generic: TcActionGeneric,
+}
+
+// kernel struct `tc_gact`
+buffer!(TcGactBuffer(TC_GACT_BUF_LEN) {
+    generic: (slice, 0..TC_GACT_BUF_LEN),
+});
+
+impl Emitable for TcGact {
+    fn buffer_len(&self) -> usize {
+        TC_GACT_BUF_LEN
+    }
+
+    fn emit(&self, buffer: &mut [u8]) {
+        let mut packet = TcGactBuffer::new(buffer);
+        self.generic.emit(packet.generic_mut());
+    }
+}
+
+impl<T: AsRef<[u8]> + ?Sized> Parseable<TcGactBuffer<&T>> for TcGact {
+    fn parse(buf: &TcGactBuffer<&T>) -> Result<Self, DecodeError> {
+        Ok(Self {
+            generic: TcActionGeneric::parse(&TcActionGenericBuffer::new(
+                buf.generic(),
+            ))?,
+        })
+    }
+}
+
+const TC_GACT_PROB_BUF_LEN: usize = 8;
+
+/// Probability based action of the gact action.
+#[derive(Debug, PartialEq, Eq, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[non_exhaustive]
+pub struct TcGactProb {
+    /// How the action is chosen.
+    pub ptype: TcGactProbType,
+    /// Probability in the range of `0..=10000`, where `10000` means 100%.
+    pub pval: u16,
+    /// The action to perform when chosen.
+    pub paction: TcActionType,
+}
+
+// kernel struct `tc_gact_p`
+buffer!(TcGactProbBuffer(TC_GACT_PROB_BUF_LEN) {
+    ptype: (u16, 0..2),
+    pval: (u16, 2..4),
+    paction: (i32, 4..8),
+});
+
+impl Emitable for TcGactProb {
+    fn buffer_len(&self) -> usize {
+        TC_GACT_PROB_BUF_LEN
+    }
+
+    fn emit(&self, buffer: &mut [u8]) {
+        let mut packet = TcGactProbBuffer::new(buffer);
+        packet.set_ptype(self.ptype.into());
+        packet.set_pval(self.pval);
+        packet.set_paction(self.paction.into());
+    }
+}
+
+impl<T: AsRef<[u8]>> Parseable<TcGactProbBuffer<T>> for TcGactProb {
+    fn parse(buf: &TcGactProbBuffer<T>) -> Result<Self, DecodeError> {
+        Ok(Self {
+            ptype: buf.ptype().into(),
+            pval: buf.pval(),
+            paction: buf.paction().into(),
+        })
+    }
+}
+
+const PGACT_NONE: u16 = 0;
+const PGACT_NETRAND: u16 = 1;
+const PGACT_DETERM: u16 = 2;
+
+/// How the probability based action of gact is chosen.
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[non_exhaustive]
+pub enum TcGactProbType {
+    #[default]
+    /// No probability based action.
+    None,
+    /// Randomly chosen.
+    NetRand,
+    /// Deterministically chosen, every `pval`-th packet.
+    Determ,
+    /// Other type unknown at the time of writing.
+    Other(u16),
+}
+
+impl From<u16> for TcGactProbType {
+    fn from(d: u16) -> Self {
+        match d {
+            PGACT_NONE => Self::None,
+            PGACT_NETRAND => Self::NetRand,
+            PGACT_DETERM => Self::Determ,
+            _ => Self::Other(d),
+        }
+    }
+}
+
+impl From<TcGactProbType> for u16 {
+    fn from(v: TcGactProbType) -> u16 {
+        match v {
+            TcGactProbType::None => PGACT_NONE,
+            TcGactProbType::NetRand => PGACT_NETRAND,
+            TcGactProbType::Determ => PGACT_DETERM,
+            TcGactProbType::Other(d) => d,
+        }
+    }
+}
@@ -25,6 +25,7 @@ impl<'a, T: AsRef<[u8]> + ?Sized> TcActionMessageBuffer<&'a T> {
 
 /// Header for a traffic control action message.
 #[derive(Debug, PartialEq, Eq, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct TcActionMessageHeader {
     /// Address family (usually `AddressFamily::Unspec`).
     pub family: AddressFamily,
@@ -12,6 +12,7 @@ use crate::tc::TcAction;
 ///
 /// [tc-actions]: https://man7.org/linux/man-pages/man8/tc-actions.8.html
 #[derive(Debug, PartialEq, Eq, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[non_exhaustive]
 pub struct TcActionMessage {
     /// Header of the message.
@@ -26,6 +27,7 @@ const TCA_ACT_FLAG_TERSE_DUMP: u32 = 1 << 1;
 bitflags! {
     /// Flags to configure action dumps (list operations).
     #[derive(Debug, PartialEq, Eq, Clone, Copy, Default, PartialOrd, Ord, Hash)]
+    #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
     #[non_exhaustive]
     pub struct TcActionMessageFlags: u32 {
         /// If set, this flag enables more than `TCA_ACT_MAX_PRIO` actions in a single
@@ -45,6 +47,7 @@ bitflags! {
 /// [`flags`]: #structfield.flags
 /// [`selector`]: #structfield.selector
 #[derive(Debug, PartialEq, Eq, Clone, Copy, Default, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct TcActionMessageFlagsWithSelector {
     /// A bitmask of [`TcActionMessageFlags`] to be associated with an
     /// operation.
@@ -135,6 +138,7 @@ const TCA_ROOT_EXT_WARN_MSG: u16 = 5;
 ///
 /// This enum is non-exhaustive, additional variants may be added in the future.
 #[derive(Debug, PartialEq, Eq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[non_exhaustive]
 pub enum TcActionMessageAttribute {
     /// Collection of `TcActions`.
@@ -148,6 +152,7 @@ pub enum TcActionMessageAttribute {
     /// Extended warning message.
     RootExtWarnMsg(String),
     /// Other attributes unknown at the time of writing.
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_nla"))]
     Other(DefaultNla),
 }
 
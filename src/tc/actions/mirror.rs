@@ -16,6 +16,7 @@ use super::{TcActionGeneric, TcActionGenericBuffer};
 
 /// Traffic control action used to mirror or redirect packets.
 #[derive(Debug, PartialEq, Eq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[non_exhaustive]
 pub struct TcActionMirror {}
 impl TcActionMirror {
@@ -28,13 +29,16 @@ const TCA_MIRRED_PARMS: u16 = 2;
 
 /// Options for the `TcActionMirror` action.
 #[derive(Debug, PartialEq, Eq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[non_exhaustive]
 pub enum TcActionMirrorOption {
     /// TODO: document this after we make it something better than `Vec<u8>`
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_hex"))]
     Tm(Vec<u8>),
     /// Parameters for the mirred action.
     Parms(TcMirror),
     /// Other attributes unknown at the time of writing.
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_nla"))]
     Other(DefaultNla),
 }
 
@@ -82,6 +86,7 @@ const TC_MIRRED_BUF_LEN: usize = TcActionGeneric::BUF_LEN + 8;
 
 /// Parameters for the mirred action.
 #[derive(Debug, PartialEq, Eq, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[non_exhaustive]
 pub struct TcMirror {
     /// Generic action parameters.
@@ -133,6 +138,7 @@ const TCA_INGRESS_MIRROR: i32 = 4;
 
 /// Type of mirroring or redirecting action.
 #[derive(Debug, PartialEq, Eq, Clone, Copy, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[non_exhaustive]
 pub enum TcMirrorActionType {
     #[default]
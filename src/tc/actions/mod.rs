@@ -1,10 +1,16 @@
 // SPDX-License-Identifier: MIT
 
+pub use ct_flag::TcCtActionFlags;
 pub use nat_flag::TcNatFlags;
 
 pub use self::action::{
     TcAction, TcActionAttribute, TcActionGeneric, TcActionGenericBuffer,
-    TcActionOption, TcActionType,
+    TcActionOption, TcActionType, Tcf, TcfBuffer,
+};
+pub use self::ct::{TcActionCt, TcActionCtOption, TcCt, TcCtBuffer};
+pub use self::gact::{
+    TcActionGact, TcActionGactOption, TcGact, TcGactBuffer, TcGactProb,
+    TcGactProbBuffer, TcGactProbType,
 };
 pub use self::header::{TcActionMessageBuffer, TcActionMessageHeader};
 pub use self::message::{
@@ -15,14 +21,29 @@ pub use self::mirror::{
     TcActionMirror, TcActionMirrorOption, TcMirror, TcMirrorActionType,
     TcMirrorBuffer,
 };
+pub use self::mpls::{
+    TcActionMpls, TcActionMplsOption, TcMpls, TcMplsActionType, TcMplsBuffer,
+};
 pub use self::nat::{TcActionNat, TcActionNatOption, TcNat, TcNatBuffer};
+pub use self::pedit::{
+    TcActionPedit, TcActionPeditOption, TcPeditCommand, TcPeditHeaderType,
+    TcPeditKey, TcPeditKeyBuffer, TcPeditKeyEx, TcPeditKeyExOption, TcPeditSel,
+    TcPeditSelBuffer,
+};
+pub use self::sample::{TcActionSample, TcActionSampleOption};
 
 mod action;
+mod ct;
+mod ct_flag;
+mod gact;
 mod header;
 mod message;
 mod mirror;
+mod mpls;
 mod nat;
 mod nat_flag;
+mod pedit;
+mod sample;
 
 #[cfg(test)]
 pub mod tests;
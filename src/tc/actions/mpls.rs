@@ -0,0 +1,228 @@
+// SPDX-License-Identifier: MIT
+
+/// MPLS action
+///
+/// The mpls action pushes, pops or modifies the MPLS label stack entry of
+/// the packet it receives.
+use anyhow::Context;
+use byteorder::{BigEndian, ByteOrder, NativeEndian};
+use netlink_packet_utils::{
+    nla::{DefaultNla, Nla, NlaBuffer},
+    parsers::{parse_u16_be, parse_u32, parse_u8},
+    traits::{Emitable, Parseable},
+    DecodeError,
+};
+
+use super::{TcActionGeneric, TcActionGenericBuffer, Tcf, TcfBuffer};
+
+const TCA_MPLS_TM: u16 = 1;
+const TCA_MPLS_PARMS: u16 = 2;
+// const TCA_MPLS_PAD: u16 = 3;
+const TCA_MPLS_PROTO: u16 = 4;
+const TCA_MPLS_LABEL: u16 = 5;
+const TCA_MPLS_TC: u16 = 6;
+const TCA_MPLS_TTL: u16 = 7;
+const TCA_MPLS_BOS: u16 = 8;
+
+/// Traffic control action used to manipulate the MPLS label stack.
+#[derive(Debug, PartialEq, Eq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[non_exhaustive]
+pub struct TcActionMpls {}
+
+impl TcActionMpls {
+    /// The `TcActionAttribute::Kind` of this action.
+    pub const KIND: &'static str = "mpls";
+}
+
+/// Options for the [`TcActionMpls`] action.
+#[derive(Debug, PartialEq, Eq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[non_exhaustive]
+pub enum TcActionMplsOption {
+    /// Timestamps of the action.
+    Tm(Tcf),
+    /// Parameters for the mpls action.
+    Parms(TcMpls),
+    /// Ethernet protocol of the packet after push or pop, e.g. `0x8847` for
+    /// MPLS unicast.
+    Protocol(u16),
+    /// The 20 bits label value to push or set, see
+    /// [crate::route::MplsLabel::MAX_LABEL].
+    Label(u32),
+    /// Traffic class to push or set.
+    TrafficClass(u8),
+    /// TTL to push or set.
+    Ttl(u8),
+    /// Bottom of stack bit to push or set.
+    BottomOfStack(bool),
+    /// Other attributes unknown at the time of writing.
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_nla"))]
+    Other(DefaultNla),
+}
+
+impl Nla for TcActionMplsOption {
+    fn value_len(&self) -> usize {
+        match self {
+            Self::Tm(v) => v.buffer_len(),
+            Self::Parms(v) => v.buffer_len(),
+            Self::Protocol(_) => 2,
+            Self::Label(_) => 4,
+            Self::TrafficClass(_) | Self::Ttl(_) | Self::BottomOfStack(_) => 1,
+            Self::Other(attr) => attr.value_len(),
+        }
+    }
+
+    fn emit_value(&self, buffer: &mut [u8]) {
+        match self {
+            Self::Tm(v) => v.emit(buffer),
+            Self::Parms(p) => p.emit(buffer),
+            Self::Protocol(v) => BigEndian::write_u16(buffer, *v),
+            Self::Label(v) => NativeEndian::write_u32(buffer, *v),
+            Self::TrafficClass(v) | Self::Ttl(v) => buffer[0] = *v,
+            Self::BottomOfStack(v) => buffer[0] = (*v).into(),
+            Self::Other(attr) => attr.emit_value(buffer),
+        }
+    }
+
+    fn kind(&self) -> u16 {
+        match self {
+            Self::Tm(_) => TCA_MPLS_TM,
+            Self::Parms(_) => TCA_MPLS_PARMS,
+            Self::Protocol(_) => TCA_MPLS_PROTO,
+            Self::Label(_) => TCA_MPLS_LABEL,
+            Self::TrafficClass(_) => TCA_MPLS_TC,
+            Self::Ttl(_) => TCA_MPLS_TTL,
+            Self::BottomOfStack(_) => TCA_MPLS_BOS,
+            Self::Other(nla) => nla.kind(),
+        }
+    }
+}
+
+impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>>
+    for TcActionMplsOption
+{
+    fn parse(buf: &NlaBuffer<&'a T>) -> Result<Self, DecodeError> {
+        let payload = buf.value();
+        Ok(match buf.kind() {
+            TCA_MPLS_TM => Self::Tm(
+                Tcf::parse(&TcfBuffer::new_checked(payload)?)
+                    .context("failed to parse TCA_MPLS_TM")?,
+            ),
+            TCA_MPLS_PARMS => Self::Parms(TcMpls::parse(
+                &TcMplsBuffer::new_checked(payload)?,
+            )?),
+            TCA_MPLS_PROTO => Self::Protocol(
+                parse_u16_be(payload)
+                    .context("failed to parse TCA_MPLS_PROTO")?,
+            ),
+            TCA_MPLS_LABEL => Self::Label(
+                parse_u32(payload).context("failed to parse TCA_MPLS_LABEL")?,
+            ),
+            TCA_MPLS_TC => Self::TrafficClass(
+                parse_u8(payload).context("failed to parse TCA_MPLS_TC")?,
+            ),
+            TCA_MPLS_TTL => Self::Ttl(
+                parse_u8(payload).context("failed to parse TCA_MPLS_TTL")?,
+            ),
+            TCA_MPLS_BOS => Self::BottomOfStack(
+                parse_u8(payload).context("failed to parse TCA_MPLS_BOS")? > 0,
+            ),
+            _ => Self::Other(DefaultNla::parse(buf)?),
+        })
+    }
+}
+
+const TC_MPLS_BUF_LEN: usize = TcActionGeneric::BUF_LEN + 4;
+
+/// Parameters for the mpls action.
+#[derive(Debug, PartialEq, Eq, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[non_exhaustive]
+pub struct TcMpls {
+    /// Generic action parameters.
+    pub generic: TcActionGeneric,
+    /// What to do with the MPLS label stack.
+    pub m_action: TcMplsActionType,
+}
+
+// kernel struct `tc_mpls`
+buffer!(TcMplsBuffer(TC_MPLS_BUF_LEN) {
+    generic: (slice, 0..20),
+    m_action: (i32, 20..24),
+});
+
+impl Emitable for TcMpls {
+    fn buffer_len(&self) -> usize {
+        TC_MPLS_BUF_LEN
+    }
+
+    fn emit(&self, buffer: &mut [u8]) {
+        let mut packet = TcMplsBuffer::new(buffer);
+        self.generic.emit(packet.generic_mut());
+        packet.set_m_action(self.m_action.into());
+    }
+}
+
+impl<T: AsRef<[u8]> + ?Sized> Parseable<TcMplsBuffer<&T>> for TcMpls {
+    fn parse(buf: &TcMplsBuffer<&T>) -> Result<Self, DecodeError> {
+        Ok(Self {
+            generic: TcActionGeneric::parse(&TcActionGenericBuffer::new(
+                buf.generic(),
+            ))?,
+            m_action: buf.m_action().into(),
+        })
+    }
+}
+
+const TCA_MPLS_ACT_POP: i32 = 1;
+const TCA_MPLS_ACT_PUSH: i32 = 2;
+const TCA_MPLS_ACT_MODIFY: i32 = 3;
+const TCA_MPLS_ACT_DEC_TTL: i32 = 4;
+const TCA_MPLS_ACT_MAC_PUSH: i32 = 5;
+
+/// Type of MPLS label stack manipulation.
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[non_exhaustive]
+pub enum TcMplsActionType {
+    #[default]
+    /// Pop the outermost label stack entry.
+    Pop,
+    /// Push a label stack entry after the MAC header.
+    Push,
+    /// Modify the outermost label stack entry.
+    Modify,
+    /// Decrease the TTL of the outermost label stack entry.
+    DecTtl,
+    /// Push a label stack entry before the MAC header.
+    MacPush,
+    /// Other action type unknown at the time of writing.
+    Other(i32),
+}
+
+impl From<i32> for TcMplsActionType {
+    fn from(d: i32) -> Self {
+        match d {
+            TCA_MPLS_ACT_POP => Self::Pop,
+            TCA_MPLS_ACT_PUSH => Self::Push,
+            TCA_MPLS_ACT_MODIFY => Self::Modify,
+            TCA_MPLS_ACT_DEC_TTL => Self::DecTtl,
+            TCA_MPLS_ACT_MAC_PUSH => Self::MacPush,
+            _ => Self::Other(d),
+        }
+    }
+}
+
+impl From<TcMplsActionType> for i32 {
+    fn from(v: TcMplsActionType) -> i32 {
+        match v {
+            TcMplsActionType::Pop => TCA_MPLS_ACT_POP,
+            TcMplsActionType::Push => TCA_MPLS_ACT_PUSH,
+            TcMplsActionType::Modify => TCA_MPLS_ACT_MODIFY,
+            TcMplsActionType::DecTtl => TCA_MPLS_ACT_DEC_TTL,
+            TcMplsActionType::MacPush => TCA_MPLS_ACT_MAC_PUSH,
+            TcMplsActionType::Other(d) => d,
+        }
+    }
+}
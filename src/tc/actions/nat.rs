@@ -18,6 +18,7 @@ const TCA_NAT_TM: u16 = 2;
 
 /// Network address translation action.
 #[derive(Debug, PartialEq, Eq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[non_exhaustive]
 pub struct TcActionNat {}
 
@@ -27,13 +28,16 @@ impl TcActionNat {
 
 /// Options for the [`TcActionNat`] action.
 #[derive(Debug, PartialEq, Eq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[non_exhaustive]
 pub enum TcActionNatOption {
     /// TODO: document this after we make it something better than `Vec<u8>`
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_hex"))]
     Tm(Vec<u8>),
     /// Parameters for the nat action.
     Parms(TcNat),
     /// Other attributes unknown at the time of writing.
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_nla"))]
     Other(DefaultNla),
 }
 
@@ -81,6 +85,7 @@ const TC_NAT_BUF_LEN: usize = TcActionGeneric::BUF_LEN + 16;
 
 /// Network address translation action.
 #[derive(Debug, PartialEq, Eq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[non_exhaustive]
 pub struct TcNat {
     /// Common attributes for all actions.
@@ -11,6 +11,7 @@ const TCA_ACT_FLAGS_AT_INGRESS: u32 = 1u32 << (TCA_ACT_FLAGS_USER_BITS + 4);
 bitflags! {
     /// Network Address Translation flags.
     #[derive(Debug, PartialEq, Eq, Clone, Copy, Default)]
+    #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
     #[non_exhaustive]
     pub struct TcNatFlags: u32 {
         const Egress = TCA_NAT_FLAG_EGRESS;
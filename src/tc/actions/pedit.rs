@@ -0,0 +1,517 @@
+// SPDX-License-Identifier: MIT
+
+/// Pedit action
+///
+/// The pedit action edits arbitrary packet data, for example to rewrite
+/// header fields.
+use anyhow::Context;
+use byteorder::{BigEndian, ByteOrder, NativeEndian};
+use netlink_packet_utils::{
+    nla::{DefaultNla, Nla, NlaBuffer, NlasIterator},
+    parsers::parse_u16,
+    traits::{Emitable, Parseable},
+    DecodeError,
+};
+
+use super::{TcActionGeneric, TcActionGenericBuffer, Tcf, TcfBuffer};
+
+const TCA_PEDIT_TM: u16 = 1;
+const TCA_PEDIT_PARMS: u16 = 2;
+// const TCA_PEDIT_PAD: u16 = 3;
+const TCA_PEDIT_PARMS_EX: u16 = 4;
+const TCA_PEDIT_KEYS_EX: u16 = 5;
+const TCA_PEDIT_KEY_EX: u16 = 6;
+
+const TCA_PEDIT_KEY_EX_HTYPE: u16 = 1;
+const TCA_PEDIT_KEY_EX_CMD: u16 = 2;
+
+/// Traffic control action used to edit packet data.
+#[derive(Debug, PartialEq, Eq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[non_exhaustive]
+pub struct TcActionPedit {}
+
+impl TcActionPedit {
+    /// The `TcActionAttribute::Kind` of this action.
+    pub const KIND: &'static str = "pedit";
+}
+
+/// Options for the [`TcActionPedit`] action.
+#[derive(Debug, PartialEq, Eq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[non_exhaustive]
+pub enum TcActionPeditOption {
+    /// Timestamps of the action.
+    Tm(Tcf),
+    /// Parameters and keys of the pedit action.
+    Parms(TcPeditSel),
+    /// Same as [Self::Parms], used when the keys are accompanied by
+    /// [Self::KeysEx].
+    ParmsEx(TcPeditSel),
+    /// Extended information of each key in [Self::ParmsEx], in the same
+    /// order.
+    KeysEx(Vec<TcPeditKeyEx>),
+    /// Other attributes unknown at the time of writing.
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_nla"))]
+    Other(DefaultNla),
+}
+
+impl Nla for TcActionPeditOption {
+    fn value_len(&self) -> usize {
+        match self {
+            Self::Tm(v) => v.buffer_len(),
+            Self::Parms(v) | Self::ParmsEx(v) => v.buffer_len(),
+            Self::KeysEx(v) => v.as_slice().buffer_len(),
+            Self::Other(attr) => attr.value_len(),
+        }
+    }
+
+    fn emit_value(&self, buffer: &mut [u8]) {
+        match self {
+            Self::Tm(v) => v.emit(buffer),
+            Self::Parms(p) | Self::ParmsEx(p) => p.emit(buffer),
+            Self::KeysEx(v) => v.as_slice().emit(buffer),
+            Self::Other(attr) => attr.emit_value(buffer),
+        }
+    }
+
+    fn kind(&self) -> u16 {
+        match self {
+            Self::Tm(_) => TCA_PEDIT_TM,
+            Self::Parms(_) => TCA_PEDIT_PARMS,
+            Self::ParmsEx(_) => TCA_PEDIT_PARMS_EX,
+            Self::KeysEx(_) => TCA_PEDIT_KEYS_EX,
+            Self::Other(nla) => nla.kind(),
+        }
+    }
+}
+
+impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>>
+    for TcActionPeditOption
+{
+    fn parse(buf: &NlaBuffer<&'a T>) -> Result<Self, DecodeError> {
+        let payload = buf.value();
+        Ok(match buf.kind() {
+            TCA_PEDIT_TM => Self::Tm(
+                Tcf::parse(&TcfBuffer::new_checked(payload)?)
+                    .context("failed to parse TCA_PEDIT_TM")?,
+            ),
+            TCA_PEDIT_PARMS => Self::Parms(
+                TcPeditSel::parse(&TcPeditSelBuffer::new_checked(payload)?)
+                    .context("failed to parse TCA_PEDIT_PARMS")?,
+            ),
+            TCA_PEDIT_PARMS_EX => Self::ParmsEx(
+                TcPeditSel::parse(&TcPeditSelBuffer::new_checked(payload)?)
+                    .context("failed to parse TCA_PEDIT_PARMS_EX")?,
+            ),
+            TCA_PEDIT_KEYS_EX => {
+                let mut keys = vec![];
+                for nla in NlasIterator::new(payload) {
+                    let nla = nla.context("invalid TCA_PEDIT_KEYS_EX")?;
+                    keys.push(
+                        TcPeditKeyEx::parse(&nla)
+                            .context("failed to parse TCA_PEDIT_KEYS_EX")?,
+                    );
+                }
+                Self::KeysEx(keys)
+            }
+            _ => Self::Other(DefaultNla::parse(buf)?),
+        })
+    }
+}
+
+const TC_PEDIT_SEL_BUF_LEN: usize = TcActionGeneric::BUF_LEN + 4;
+
+/// Parameters of the pedit action, the `keys` are applied to the packet in
+/// order.
+#[derive(Debug, PartialEq, Eq, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[non_exhaustive]
+pub struct TcPeditSel {
+    /// Common attributes for all actions.
+    pub generic: TcActionGeneric,
+    /// Unused by kernel.
+    pub flags: u8,
+    /// Edits to perform, added by [Self::push_key()].
+    #[cfg_attr(
+        feature = "serde",
+        serde(deserialize_with = "deserialize_pedit_keys")
+    )]
+    keys: Vec<TcPeditKey>,
+}
+
+impl TcPeditSel {
+    /// Maximum number of keys in a single `tc_pedit_sel`, as the kernel holds
+    /// the number of keys in a `u8`.
+    pub const MAX_KEYS: usize = u8::MAX as usize;
+
+    /// Edits to perform, in order.
+    pub fn keys(&self) -> &[TcPeditKey] {
+        self.keys.as_slice()
+    }
+
+    /// Append an edit, returns error if already holding [Self::MAX_KEYS]
+    /// keys.
+    pub fn push_key(&mut self, key: TcPeditKey) -> Result<(), DecodeError> {
+        if self.keys.len() >= Self::MAX_KEYS {
+            return Err(format!(
+                "tc_pedit_sel cannot hold more than {} keys",
+                Self::MAX_KEYS
+            )
+            .into());
+        }
+        self.keys.push(key);
+        Ok(())
+    }
+}
+
+#[cfg(feature = "serde")]
+fn deserialize_pedit_keys<'de, D>(
+    deserializer: D,
+) -> Result<Vec<TcPeditKey>, D::Error>
+where
+    D: serde::Deserializer<'de>,
+{
+    use serde::{de::Error, Deserialize};
+
+    let keys = Vec::<TcPeditKey>::deserialize(deserializer)?;
+    if keys.len() > TcPeditSel::MAX_KEYS {
+        return Err(D::Error::custom(format!(
+            "tc_pedit_sel cannot hold more than {} keys",
+            TcPeditSel::MAX_KEYS
+        )));
+    }
+    Ok(keys)
+}
+
+// kernel struct `tc_pedit_sel`, followed by `nkeys` of `tc_pedit_key` after
+// 2 bytes padding.
+buffer!(TcPeditSelBuffer(TC_PEDIT_SEL_BUF_LEN) {
+    generic: (slice, 0..TcActionGeneric::BUF_LEN),
+    nkeys: (u8, TcActionGeneric::BUF_LEN),
+    flags: (u8, TcActionGeneric::BUF_LEN + 1),
+    keys: (slice, TC_PEDIT_SEL_BUF_LEN..),
+});
+
+impl Emitable for TcPeditSel {
+    fn buffer_len(&self) -> usize {
+        TC_PEDIT_SEL_BUF_LEN + self.keys.len() * TC_PEDIT_KEY_BUF_LEN
+    }
+
+    fn emit(&self, buffer: &mut [u8]) {
+        let mut packet = TcPeditSelBuffer::new(buffer);
+        self.generic.emit(packet.generic_mut());
+        // push_key() never holds more than MAX_KEYS, hence fit in u8
+        packet.set_nkeys(self.keys.len() as u8);
+        packet.set_flags(self.flags);
+        for (key, chunk) in self
+            .keys
+            .iter()
+            .zip(packet.keys_mut().chunks_exact_mut(TC_PEDIT_KEY_BUF_LEN))
+        {
+            key.emit(chunk);
+        }
+    }
+}
+
+impl<T: AsRef<[u8]> + ?Sized> Parseable<TcPeditSelBuffer<&T>> for TcPeditSel {
+    fn parse(buf: &TcPeditSelBuffer<&T>) -> Result<Self, DecodeError> {
+        let nkeys = buf.nkeys() as usize;
+        let payload = buf.keys();
+        if payload.len() < nkeys * TC_PEDIT_KEY_BUF_LEN {
+            return Err(format!(
+                "invalid tc_pedit_sel, expecting {nkeys} keys, but only got \
+                 {} bytes",
+                payload.len()
+            )
+            .into());
+        }
+        let mut keys = Vec::with_capacity(nkeys);
+        for chunk in payload.chunks_exact(TC_PEDIT_KEY_BUF_LEN).take(nkeys) {
+            keys.push(TcPeditKey::parse(&TcPeditKeyBuffer::new(chunk))?);
+        }
+        Ok(Self {
+            generic: TcActionGeneric::parse(&TcActionGenericBuffer::new(
+                buf.generic(),
+            ))?,
+            flags: buf.flags(),
+            keys,
+        })
+    }
+}
+
+const TC_PEDIT_KEY_BUF_LEN: usize = 24;
+
+/// A single edit of the pedit action.
+///
+/// The 32 bits word at `off` of the packet is replaced by
+/// `(word & mask) ^ val`. The `mask` and `val` are in network byte order as
+/// seen in the packet, e.g. `0x0a000001` for `10.0.0.1`.
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[non_exhaustive]
+pub struct TcPeditKey {
+    /// Bits of the packet word to keep.
+    pub mask: u32,
+    /// Value XORed into the packet word after masking.
+    pub val: u32,
+    /// Offset of the packet word, relative to the header selected by
+    /// [TcPeditHeaderType] when using extended keys.
+    pub off: i32,
+    /// When `offmask` is not zero, offset of the byte holding an additional
+    /// offset.
+    pub at: u32,
+    /// Mask applied to the byte at `at`.
+    pub offmask: u32,
+    /// Right shift applied to the masked byte at `at`.
+    pub shift: u32,
+}
+
+// kernel struct `tc_pedit_key`
+buffer!(TcPeditKeyBuffer(TC_PEDIT_KEY_BUF_LEN) {
+    mask: (slice, 0..4),
+    val: (slice, 4..8),
+    off: (i32, 8..12),
+    at: (u32, 12..16),
+    offmask: (u32, 16..20),
+    shift: (u32, 20..TC_PEDIT_KEY_BUF_LEN),
+});
+
+impl Emitable for TcPeditKey {
+    fn buffer_len(&self) -> usize {
+        TC_PEDIT_KEY_BUF_LEN
+    }
+
+    fn emit(&self, buffer: &mut [u8]) {
+        let mut packet = TcPeditKeyBuffer::new(buffer);
+        BigEndian::write_u32(packet.mask_mut(), self.mask);
+        BigEndian::write_u32(packet.val_mut(), self.val);
+        packet.set_off(self.off);
+        packet.set_at(self.at);
+        packet.set_offmask(self.offmask);
+        packet.set_shift(self.shift);
+    }
+}
+
+impl<T: AsRef<[u8]> + ?Sized> Parseable<TcPeditKeyBuffer<&T>> for TcPeditKey {
+    fn parse(buf: &TcPeditKeyBuffer<&T>) -> Result<Self, DecodeError> {
+        Ok(Self {
+            mask: BigEndian::read_u32(buf.mask()),
+            val: BigEndian::read_u32(buf.val()),
+            off: buf.off(),
+            at: buf.at(),
+            offmask: buf.offmask(),
+            shift: buf.shift(),
+        })
+    }
+}
+
+/// Element of [TcActionPeditOption::KeysEx].
+#[derive(Debug, PartialEq, Eq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[non_exhaustive]
+pub enum TcPeditKeyEx {
+    /// `TCA_PEDIT_KEY_EX` holding the extended information of a key.
+    KeyEx(Vec<TcPeditKeyExOption>),
+    /// Other attributes unknown at the time of writing.
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_nla"))]
+    Other(DefaultNla),
+}
+
+impl Nla for TcPeditKeyEx {
+    fn value_len(&self) -> usize {
+        match self {
+            Self::KeyEx(v) => v.as_slice().buffer_len(),
+            Self::Other(attr) => attr.value_len(),
+        }
+    }
+
+    fn emit_value(&self, buffer: &mut [u8]) {
+        match self {
+            Self::KeyEx(v) => v.as_slice().emit(buffer),
+            Self::Other(attr) => attr.emit_value(buffer),
+        }
+    }
+
+    fn kind(&self) -> u16 {
+        match self {
+            Self::KeyEx(_) => TCA_PEDIT_KEY_EX,
+            Self::Other(attr) => attr.kind(),
+        }
+    }
+}
+
+impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>> for TcPeditKeyEx {
+    fn parse(buf: &NlaBuffer<&'a T>) -> Result<Self, DecodeError> {
+        let payload = buf.value();
+        Ok(match buf.kind() {
+            TCA_PEDIT_KEY_EX => {
+                let mut opts = vec![];
+                for nla in NlasIterator::new(payload) {
+                    let nla = nla.context("invalid TCA_PEDIT_KEY_EX")?;
+                    opts.push(
+                        TcPeditKeyExOption::parse(&nla)
+                            .context("failed to parse TCA_PEDIT_KEY_EX")?,
+                    );
+                }
+                Self::KeyEx(opts)
+            }
+            _ => Self::Other(
+                DefaultNla::parse(buf)
+                    .context("failed to parse pedit extended key nla")?,
+            ),
+        })
+    }
+}
+
+/// Attributes of [TcPeditKeyEx::KeyEx].
+#[derive(Debug, PartialEq, Eq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[non_exhaustive]
+pub enum TcPeditKeyExOption {
+    /// Header the [TcPeditKey::off] is relative to.
+    HeaderType(TcPeditHeaderType),
+    /// How [TcPeditKey::val] is applied.
+    Command(TcPeditCommand),
+    /// Other attributes unknown at the time of writing.
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_nla"))]
+    Other(DefaultNla),
+}
+
+impl Nla for TcPeditKeyExOption {
+    fn value_len(&self) -> usize {
+        match self {
+            Self::HeaderType(_) | Self::Command(_) => 2,
+            Self::Other(attr) => attr.value_len(),
+        }
+    }
+
+    fn emit_value(&self, buffer: &mut [u8]) {
+        match self {
+            Self::HeaderType(v) => NativeEndian::write_u16(buffer, (*v).into()),
+            Self::Command(v) => NativeEndian::write_u16(buffer, (*v).into()),
+            Self::Other(attr) => attr.emit_value(buffer),
+        }
+    }
+
+    fn kind(&self) -> u16 {
+        match self {
+            Self::HeaderType(_) => TCA_PEDIT_KEY_EX_HTYPE,
+            Self::Command(_) => TCA_PEDIT_KEY_EX_CMD,
+            Self::Other(attr) => attr.kind(),
+        }
+    }
+}
+
+impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>>
+    for TcPeditKeyExOption
+{
+    fn parse(buf: &NlaBuffer<&'a T>) -> Result<Self, DecodeError> {
+        let payload = buf.value();
+        Ok(match buf.kind() {
+            TCA_PEDIT_KEY_EX_HTYPE => Self::HeaderType(
+                parse_u16(payload)
+                    .context("failed to parse TCA_PEDIT_KEY_EX_HTYPE")?
+                    .into(),
+            ),
+            TCA_PEDIT_KEY_EX_CMD => Self::Command(
+                parse_u16(payload)
+                    .context("failed to parse TCA_PEDIT_KEY_EX_CMD")?
+                    .into(),
+            ),
+            _ => Self::Other(
+                DefaultNla::parse(buf)
+                    .context("failed to parse pedit extended key option")?,
+            ),
+        })
+    }
+}
+
+const TCA_PEDIT_KEY_EX_HDR_TYPE_NETWORK: u16 = 0;
+const TCA_PEDIT_KEY_EX_HDR_TYPE_ETH: u16 = 1;
+const TCA_PEDIT_KEY_EX_HDR_TYPE_IP4: u16 = 2;
+const TCA_PEDIT_KEY_EX_HDR_TYPE_IP6: u16 = 3;
+const TCA_PEDIT_KEY_EX_HDR_TYPE_TCP: u16 = 4;
+const TCA_PEDIT_KEY_EX_HDR_TYPE_UDP: u16 = 5;
+
+/// Header the offset of the pedit key is relative to.
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[non_exhaustive]
+pub enum TcPeditHeaderType {
+    /// Network header, the same as legacy keys without extended information.
+    #[default]
+    Network,
+    Eth,
+    Ip4,
+    Ip6,
+    Tcp,
+    Udp,
+    /// Other header type unknown at the time of writing.
+    Other(u16),
+}
+
+impl From<u16> for TcPeditHeaderType {
+    fn from(d: u16) -> Self {
+        match d {
+            TCA_PEDIT_KEY_EX_HDR_TYPE_NETWORK => Self::Network,
+            TCA_PEDIT_KEY_EX_HDR_TYPE_ETH => Self::Eth,
+            TCA_PEDIT_KEY_EX_HDR_TYPE_IP4 => Self::Ip4,
+            TCA_PEDIT_KEY_EX_HDR_TYPE_IP6 => Self::Ip6,
+            TCA_PEDIT_KEY_EX_HDR_TYPE_TCP => Self::Tcp,
+            TCA_PEDIT_KEY_EX_HDR_TYPE_UDP => Self::Udp,
+            _ => Self::Other(d),
+        }
+    }
+}
+
+impl From<TcPeditHeaderType> for u16 {
+    fn from(v: TcPeditHeaderType) -> u16 {
+        match v {
+            TcPeditHeaderType::Network => TCA_PEDIT_KEY_EX_HDR_TYPE_NETWORK,
+            TcPeditHeaderType::Eth => TCA_PEDIT_KEY_EX_HDR_TYPE_ETH,
+            TcPeditHeaderType::Ip4 => TCA_PEDIT_KEY_EX_HDR_TYPE_IP4,
+            TcPeditHeaderType::Ip6 => TCA_PEDIT_KEY_EX_HDR_TYPE_IP6,
+            TcPeditHeaderType::Tcp => TCA_PEDIT_KEY_EX_HDR_TYPE_TCP,
+            TcPeditHeaderType::Udp => TCA_PEDIT_KEY_EX_HDR_TYPE_UDP,
+            TcPeditHeaderType::Other(d) => d,
+        }
+    }
+}
+
+const TCA_PEDIT_KEY_EX_CMD_SET: u16 = 0;
+const TCA_PEDIT_KEY_EX_CMD_ADD: u16 = 1;
+
+/// How the value of the pedit key is applied to the packet.
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[non_exhaustive]
+pub enum TcPeditCommand {
+    /// Set the masked bits to the value.
+    #[default]
+    Set,
+    /// Add the value to the packet word.
+    Add,
+    /// Other command unknown at the time of writing.
+    Other(u16),
+}
+
+impl From<u16> for TcPeditCommand {
+    fn from(d: u16) -> Self {
+        match d {
+            TCA_PEDIT_KEY_EX_CMD_SET => Self::Set,
+            TCA_PEDIT_KEY_EX_CMD_ADD => Self::Add,
+            _ => Self::Other(d),
+        }
+    }
+}
+
+impl From<TcPeditCommand> for u16 {
+    fn from(v: TcPeditCommand) -> u16 {
+        match v {
+            TcPeditCommand::Set => TCA_PEDIT_KEY_EX_CMD_SET,
+            TcPeditCommand::Add => TCA_PEDIT_KEY_EX_CMD_ADD,
+            TcPeditCommand::Other(d) => d,
+        }
+    }
+}
@@ -0,0 +1,119 @@
+// SPDX-License-Identifier: MIT
+
+/// Sample action
+///
+/// The sample action samples packets matching the classifier at the
+/// specified rate and sends the copies to a `psample` group, usually
+/// consumed by sFlow agents.
+use anyhow::Context;
+use byteorder::{ByteOrder, NativeEndian};
+use netlink_packet_utils::{
+    nla::{DefaultNla, Nla, NlaBuffer},
+    parsers::parse_u32,
+    traits::{Emitable, Parseable},
+    DecodeError,
+};
+
+use super::{TcActionGeneric, TcActionGenericBuffer, Tcf, TcfBuffer};
+
+const TCA_SAMPLE_TM: u16 = 1;
+const TCA_SAMPLE_PARMS: u16 = 2;
+const TCA_SAMPLE_RATE: u16 = 3;
+const TCA_SAMPLE_TRUNC_SIZE: u16 = 4;
+const TCA_SAMPLE_PSAMPLE_GROUP: u16 = 5;
+// const TCA_SAMPLE_PAD: u16 = 6;
+
+/// Traffic control action used to sample packets.
+#[derive(Debug, PartialEq, Eq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[non_exhaustive]
+pub struct TcActionSample {}
+
+impl TcActionSample {
+    /// The `TcActionAttribute::Kind` of this action.
+    pub const KIND: &'static str = "sample";
+}
+
+/// Options for the [`TcActionSample`] action.
+#[derive(Debug, PartialEq, Eq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[non_exhaustive]
+pub enum TcActionSampleOption {
+    /// Timestamps of the action.
+    Tm(Tcf),
+    /// Parameters for the sample action. Kernel struct `tc_sample` holds
+    /// nothing but the generic action parameters.
+    Parms(TcActionGeneric),
+    /// Sample one of every `rate` packets.
+    Rate(u32),
+    /// Maximum number of bytes of each sampled packet to copy.
+    TruncSize(u32),
+    /// The `psample` group receiving the sampled packets.
+    PsampleGroup(u32),
+    /// Other attributes unknown at the time of writing.
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_nla"))]
+    Other(DefaultNla),
+}
+
+impl Nla for TcActionSampleOption {
+    fn value_len(&self) -> usize {
+        match self {
+            Self::Tm(v) => v.buffer_len(),
+            Self::Parms(v) => v.buffer_len(),
+            Self::Rate(_) | Self::TruncSize(_) | Self::PsampleGroup(_) => 4,
+            Self::Other(attr) => attr.value_len(),
+        }
+    }
+
+    fn emit_value(&self, buffer: &mut [u8]) {
+        match self {
+            Self::Tm(v) => v.emit(buffer),
+            Self::Parms(p) => p.emit(buffer),
+            Self::Rate(v) | Self::TruncSize(v) | Self::PsampleGroup(v) => {
+                NativeEndian::write_u32(buffer, *v)
+            }
+            Self::Other(attr) => attr.emit_value(buffer),
+        }
+    }
+
+    fn kind(&self) -> u16 {
+        match self {
+            Self::Tm(_) => TCA_SAMPLE_TM,
+            Self::Parms(_) => TCA_SAMPLE_PARMS,
+            Self::Rate(_) => TCA_SAMPLE_RATE,
+            Self::TruncSize(_) => TCA_SAMPLE_TRUNC_SIZE,
+            Self::PsampleGroup(_) => TCA_SAMPLE_PSAMPLE_GROUP,
+            Self::Other(nla) => nla.kind(),
+        }
+    }
+}
+
+impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>>
+    for TcActionSampleOption
+{
+    fn parse(buf: &NlaBuffer<&'a T>) -> Result<Self, DecodeError> {
+        let payload = buf.value();
+        Ok(match buf.kind() {
+            TCA_SAMPLE_TM => Self::Tm(
+                Tcf::parse(&TcfBuffer::new_checked(payload)?)
+                    .context("failed to parse TCA_SAMPLE_TM")?,
+            ),
+            TCA_SAMPLE_PARMS => Self::Parms(TcActionGeneric::parse(
+                &TcActionGenericBuffer::new_checked(payload)?,
+            )?),
+            TCA_SAMPLE_RATE => Self::Rate(
+                parse_u32(payload)
+                    .context("failed to parse TCA_SAMPLE_RATE")?,
+            ),
+            TCA_SAMPLE_TRUNC_SIZE => Self::TruncSize(
+                parse_u32(payload)
+                    .context("failed to parse TCA_SAMPLE_TRUNC_SIZE")?,
+            ),
+            TCA_SAMPLE_PSAMPLE_GROUP => Self::PsampleGroup(
+                parse_u32(payload)
+                    .context("failed to parse TCA_SAMPLE_PSAMPLE_GROUP")?,
+            ),
+            _ => Self::Other(DefaultNla::parse(buf)?),
+        })
+    }
+}
@@ -0,0 +1,107 @@
+// SPDX-License-Identifier: MIT
+
+use netlink_packet_utils::nla::NlaBuffer;
+use netlink_packet_utils::{Emitable, Parseable};
+
+use crate::tc::actions::message::TcActionMessage;
+use crate::tc::actions::message::TcActionMessageAttribute::Actions;
+use crate::tc::actions::{TcActionMessageBuffer, TcActionMessageHeader};
+use crate::tc::TcActionAttribute::{Kind, Options};
+use crate::tc::TcActionCtOption::{Action, Parms, Zone};
+use crate::tc::TcActionOption::Ct;
+use crate::tc::{
+    TcAction, TcActionCtOption, TcActionGeneric, TcActionType, TcCt,
+    TcCtActionFlags, Tcf,
+};
+use crate::AddressFamily;
+
+/// Hand-crafted request equivalent to
+///
+/// ```bash
+/// tc actions add action ct commit zone 1 index 1
+/// ```
+const TC_ACTION_CT_COMMIT_EXAMPLE: &[u8] = &[
+    0x00, 0x00, 0x00, 0x00, // AF_UNSPEC with 3 bytes padding
+    0x3c, 0x00, 0x01, 0x00, // length 60, TCA_ACT_TAB
+    0x38, 0x00, 0x01, 0x00, // length 56, action 1
+    0x07, 0x00, 0x01, 0x00, // length 7, TCA_ACT_KIND
+    0x63, 0x74, 0x00, 0x00, // "ct\0" and padding
+    0x2c, 0x00, 0x02, 0x80, // length 44, TCA_ACT_OPTIONS | NLA_F_NESTED
+    0x06, 0x00, 0x04, 0x00, // length 6, TCA_CT_ZONE
+    0x01, 0x00, 0x00, 0x00, // zone 1 and padding
+    0x06, 0x00, 0x03, 0x00, // length 6, TCA_CT_ACTION
+    0x01, 0x00, 0x00, 0x00, // TCA_CT_ACT_COMMIT and padding
+    0x18, 0x00, 0x01, 0x00, // length 24, TCA_CT_PARMS
+    0x01, 0x00, 0x00, 0x00, // index 1
+    0x00, 0x00, 0x00, 0x00, // capab 0
+    0x03, 0x00, 0x00, 0x00, // action TC_ACT_PIPE
+    0x00, 0x00, 0x00, 0x00, // refcnt 0
+    0x00, 0x00, 0x00, 0x00, // bindcnt 0
+];
+
+fn tc_action_message_ct_commit_example() -> TcActionMessage {
+    TcActionMessage {
+        header: TcActionMessageHeader {
+            family: AddressFamily::Unspec,
+        },
+        attributes: vec![Actions(vec![TcAction {
+            tab: 1,
+            attributes: vec![
+                Kind("ct".into()),
+                Options(vec![
+                    Ct(Zone(1)),
+                    Ct(Action(TcCtActionFlags::Commit)),
+                    Ct(Parms(TcCt {
+                        generic: TcActionGeneric {
+                            index: 1,
+                            capab: 0,
+                            action: TcActionType::Pipe,
+                            refcnt: 0,
+                            bindcnt: 0,
+                        },
+                    })),
+                ]),
+            ],
+        }])],
+    }
+}
+
+#[test]
+fn parse_tc_action_ct_commit_example() {
+    let buf = TC_ACTION_CT_COMMIT_EXAMPLE;
+    let parsed = TcActionMessage::parse(
+        &TcActionMessageBuffer::new_checked(&buf).unwrap(),
+    )
+    .unwrap();
+    assert_eq!(parsed, tc_action_message_ct_commit_example());
+}
+
+#[test]
+fn emit_tc_action_ct_commit_example() {
+    let example = tc_action_message_ct_commit_example();
+    let mut buf = vec![0; example.buffer_len()];
+    example.emit(&mut buf);
+    assert_eq!(buf.as_slice(), TC_ACTION_CT_COMMIT_EXAMPLE);
+}
+
+#[test]
+fn tc_action_ct_option_tm_round_trip() {
+    let example = TcActionCtOption::Tm(Tcf {
+        install: 1000,
+        lastuse: 200,
+        expires: 0,
+        firstuse: 300,
+    });
+    let mut buf = vec![0; example.buffer_len()];
+    example.emit(&mut buf);
+    let mut expected = vec![0x24, 0x00, 0x02, 0x00]; // TCA_CT_TM, length 36
+    expected.extend_from_slice(&1000u64.to_ne_bytes());
+    expected.extend_from_slice(&200u64.to_ne_bytes());
+    expected.extend_from_slice(&0u64.to_ne_bytes());
+    expected.extend_from_slice(&300u64.to_ne_bytes());
+    assert_eq!(buf, expected);
+    let parsed =
+        TcActionCtOption::parse(&NlaBuffer::new_checked(&buf).unwrap())
+            .unwrap();
+    assert_eq!(parsed, example);
+}
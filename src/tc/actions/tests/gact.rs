@@ -0,0 +1,125 @@
+// SPDX-License-Identifier: MIT
+
+use netlink_packet_utils::nla::NlaBuffer;
+use netlink_packet_utils::{Emitable, Parseable};
+
+use crate::tc::actions::message::TcActionMessage;
+use crate::tc::actions::message::TcActionMessageAttribute::Actions;
+use crate::tc::actions::{TcActionMessageBuffer, TcActionMessageHeader};
+use crate::tc::TcActionAttribute::{Kind, Options};
+use crate::tc::TcActionGactOption::{Parms, Prob, Tm};
+use crate::tc::TcActionOption::Gact;
+use crate::tc::{
+    TcAction, TcActionGactOption, TcActionGeneric, TcActionType, TcGact,
+    TcGactProb, TcGactProbType, Tcf,
+};
+use crate::AddressFamily;
+
+/// Hand-crafted request equivalent to
+///
+/// ```bash
+/// tc actions add action drop index 1
+/// ```
+const TC_ACTION_GACT_DROP: &[u8] = &[
+    0x00, 0x00, 0x00, 0x00, // family AF_UNSPEC and padding
+    0x30, 0x00, 0x01, 0x00, // TCA_ACT_TAB, length 48
+    0x2c, 0x00, 0x01, 0x00, // action 1, length 44
+    0x09, 0x00, 0x01, 0x00, // TCA_ACT_KIND, length 9
+    0x67, 0x61, 0x63, 0x74, 0x00, 0x00, 0x00,
+    0x00, // "gact\0" and padding
+    0x1c, 0x00, 0x02, 0x80, // TCA_ACT_OPTIONS | NLA_F_NESTED, length 28
+    0x18, 0x00, 0x02, 0x00, // TCA_GACT_PARMS, length 24
+    0x01, 0x00, 0x00, 0x00, // index 1
+    0x00, 0x00, 0x00, 0x00, // capab
+    0x02, 0x00, 0x00, 0x00, // action TC_ACT_SHOT
+    0x00, 0x00, 0x00, 0x00, // refcnt
+    0x00, 0x00, 0x00, 0x00, // bindcnt
+];
+
+fn tc_action_message_gact_drop() -> TcActionMessage {
+    TcActionMessage {
+        header: TcActionMessageHeader {
+            family: AddressFamily::Unspec,
+        },
+        attributes: vec![Actions(vec![TcAction {
+            tab: 1,
+            attributes: vec![
+                Kind("gact".into()),
+                Options(vec![Gact(Parms(TcGact {
+                    generic: TcActionGeneric {
+                        index: 1,
+                        capab: 0,
+                        action: TcActionType::Shot,
+                        refcnt: 0,
+                        bindcnt: 0,
+                    },
+                }))]),
+            ],
+        }])],
+    }
+}
+
+#[test]
+fn parse_tc_action_gact_drop() {
+    let buf = TC_ACTION_GACT_DROP;
+    let parsed = TcActionMessage::parse(
+        &TcActionMessageBuffer::new_checked(&buf).unwrap(),
+    )
+    .unwrap();
+    assert_eq!(parsed, tc_action_message_gact_drop());
+}
+
+#[test]
+fn emit_tc_action_gact_drop() {
+    let example = tc_action_message_gact_drop();
+    let mut buf = vec![0; example.buffer_len()];
+    example.emit(&mut buf);
+    assert_eq!(buf.as_slice(), TC_ACTION_GACT_DROP);
+}
+
+#[test]
+fn tc_action_gact_option_prob_round_trip() {
+    // Equivalent to `random netrand pass 50`
+    let example = Prob(TcGactProb {
+        ptype: TcGactProbType::NetRand,
+        pval: 5000,
+        paction: TcActionType::Ok,
+    });
+    let mut buf = vec![0; example.buffer_len()];
+    example.emit(&mut buf);
+    assert_eq!(
+        buf,
+        vec![
+            0x0c, 0x00, 0x03, 0x00, // TCA_GACT_PROB, length 12
+            0x01, 0x00, // PGACT_NETRAND
+            0x88, 0x13, // 5000
+            0x00, 0x00, 0x00, 0x00, // TC_ACT_OK
+        ]
+    );
+    let parsed =
+        TcActionGactOption::parse(&NlaBuffer::new_checked(&buf).unwrap())
+            .unwrap();
+    assert_eq!(parsed, example);
+}
+
+#[test]
+fn tc_action_gact_option_tm_round_trip() {
+    let example = Tm(Tcf {
+        install: 1000,
+        lastuse: 200,
+        expires: 0,
+        firstuse: 300,
+    });
+    let mut buf = vec![0; example.buffer_len()];
+    example.emit(&mut buf);
+    let mut expected = vec![0x24, 0x00, 0x01, 0x00]; // TCA_GACT_TM, length 36
+    expected.extend_from_slice(&1000u64.to_ne_bytes());
+    expected.extend_from_slice(&200u64.to_ne_bytes());
+    expected.extend_from_slice(&0u64.to_ne_bytes());
+    expected.extend_from_slice(&300u64.to_ne_bytes());
+    assert_eq!(buf, expected);
+    let parsed =
+        TcActionGactOption::parse(&NlaBuffer::new_checked(&buf).unwrap())
+            .unwrap();
+    assert_eq!(parsed, example);
+}
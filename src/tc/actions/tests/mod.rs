@@ -1,7 +1,12 @@
 // SPDX-License-Identifier: MIT
 
 pub mod action;
+pub mod ct;
+pub mod gact;
 pub mod header;
 pub mod message;
 pub mod mirror;
+pub mod mpls;
 pub mod nat;
+pub mod pedit;
+pub mod sample;
@@ -0,0 +1,111 @@
+// SPDX-License-Identifier: MIT
+
+use netlink_packet_utils::nla::NlaBuffer;
+use netlink_packet_utils::{Emitable, Parseable};
+
+use crate::route::MplsLabel;
+use crate::tc::actions::message::TcActionMessage;
+use crate::tc::actions::message::TcActionMessageAttribute::Actions;
+use crate::tc::actions::{TcActionMessageBuffer, TcActionMessageHeader};
+use crate::tc::TcActionAttribute::{Kind, Options};
+use crate::tc::TcActionMplsOption::{Label, Parms, Protocol};
+use crate::tc::TcActionOption::Mpls;
+use crate::tc::{
+    TcAction, TcActionGeneric, TcActionMplsOption, TcActionType, TcMpls,
+    TcMplsActionType, Tcf,
+};
+use crate::AddressFamily;
+
+/// Hand-crafted request equivalent to
+///
+/// ```bash
+/// tc actions add action mpls push label 100 index 1
+/// ```
+const TC_ACTION_MPLS_PUSH_EXAMPLE: &[u8] = &[
+    0x00, 0x00, 0x00, 0x00, // AF_UNSPEC with 3 bytes padding
+    0x44, 0x00, 0x01, 0x00, // length 68, TCA_ACT_TAB
+    0x40, 0x00, 0x01, 0x00, // length 64, action 1
+    0x09, 0x00, 0x01, 0x00, // length 9, TCA_ACT_KIND
+    0x6d, 0x70, 0x6c, 0x73, 0x00, // "mpls\0"
+    0x00, 0x00, 0x00, // padding
+    0x30, 0x00, 0x02, 0x80, // length 48, TCA_ACT_OPTIONS | NLA_F_NESTED
+    0x1c, 0x00, 0x02, 0x00, // length 28, TCA_MPLS_PARMS
+    0x01, 0x00, 0x00, 0x00, // index 1
+    0x00, 0x00, 0x00, 0x00, // capab 0
+    0x03, 0x00, 0x00, 0x00, // action TC_ACT_PIPE
+    0x00, 0x00, 0x00, 0x00, // refcnt 0
+    0x00, 0x00, 0x00, 0x00, // bindcnt 0
+    0x02, 0x00, 0x00, 0x00, // m_action TCA_MPLS_ACT_PUSH
+    0x06, 0x00, 0x04, 0x00, // length 6, TCA_MPLS_PROTO
+    0x88, 0x47, 0x00, 0x00, // ETH_P_MPLS_UC in big endian and padding
+    0x08, 0x00, 0x05, 0x00, // length 8, TCA_MPLS_LABEL
+    0x64, 0x00, 0x00, 0x00, // label 100 in native endian
+];
+
+fn tc_action_message_mpls_push_example() -> TcActionMessage {
+    TcActionMessage {
+        header: TcActionMessageHeader {
+            family: AddressFamily::Unspec,
+        },
+        attributes: vec![Actions(vec![TcAction {
+            tab: 1,
+            attributes: vec![
+                Kind("mpls".into()),
+                Options(vec![
+                    Mpls(Parms(TcMpls {
+                        generic: TcActionGeneric {
+                            index: 1,
+                            capab: 0,
+                            action: TcActionType::Pipe,
+                            refcnt: 0,
+                            bindcnt: 0,
+                        },
+                        m_action: TcMplsActionType::Push,
+                    })),
+                    Mpls(Protocol(0x8847)),
+                    Mpls(Label(MplsLabel::new(100).unwrap().label)),
+                ]),
+            ],
+        }])],
+    }
+}
+
+#[test]
+fn parse_tc_action_mpls_push_example() {
+    let buf = TC_ACTION_MPLS_PUSH_EXAMPLE;
+    let parsed = TcActionMessage::parse(
+        &TcActionMessageBuffer::new_checked(&buf).unwrap(),
+    )
+    .unwrap();
+    assert_eq!(parsed, tc_action_message_mpls_push_example());
+}
+
+#[test]
+fn emit_tc_action_mpls_push_example() {
+    let example = tc_action_message_mpls_push_example();
+    let mut buf = vec![0; example.buffer_len()];
+    example.emit(&mut buf);
+    assert_eq!(buf.as_slice(), TC_ACTION_MPLS_PUSH_EXAMPLE);
+}
+
+#[test]
+fn tc_action_mpls_option_tm_round_trip() {
+    let example = TcActionMplsOption::Tm(Tcf {
+        install: 1000,
+        lastuse: 200,
+        expires: 0,
+        firstuse: 300,
+    });
+    let mut buf = vec![0; example.buffer_len()];
+    example.emit(&mut buf);
+    let mut expected = vec![0x24, 0x00, 0x01, 0x00]; // TCA_MPLS_TM, length 36
+    expected.extend_from_slice(&1000u64.to_ne_bytes());
+    expected.extend_from_slice(&200u64.to_ne_bytes());
+    expected.extend_from_slice(&0u64.to_ne_bytes());
+    expected.extend_from_slice(&300u64.to_ne_bytes());
+    assert_eq!(buf, expected);
+    let parsed =
+        TcActionMplsOption::parse(&NlaBuffer::new_checked(&buf).unwrap())
+            .unwrap();
+    assert_eq!(parsed, example);
+}
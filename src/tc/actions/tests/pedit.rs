@@ -0,0 +1,151 @@
+// SPDX-License-Identifier: MIT
+
+use netlink_packet_utils::nla::NlaBuffer;
+use netlink_packet_utils::{Emitable, Parseable};
+
+use crate::tc::actions::message::TcActionMessage;
+use crate::tc::actions::message::TcActionMessageAttribute::Actions;
+use crate::tc::actions::{TcActionMessageBuffer, TcActionMessageHeader};
+use crate::tc::TcActionAttribute::{Kind, Options};
+use crate::tc::TcActionOption::Pedit;
+use crate::tc::TcActionPeditOption::{KeysEx, ParmsEx, Tm};
+use crate::tc::{
+    TcAction, TcActionGeneric, TcActionPeditOption, TcActionType,
+    TcPeditCommand, TcPeditHeaderType, TcPeditKey, TcPeditKeyEx,
+    TcPeditKeyExOption, TcPeditSel, TcPeditSelBuffer, Tcf,
+};
+use crate::AddressFamily;
+
+/// Hand-crafted request equivalent to
+///
+/// ```bash
+/// tc actions add action pedit ex munge ip dst set 10.0.0.1 index 1
+/// ```
+const TC_ACTION_PEDIT_EX_EXAMPLE: &[u8] = &[
+    0x00, 0x00, 0x00, 0x00, // AF_UNSPEC with 3 bytes padding
+    0x64, 0x00, 0x01, 0x00, // length 100, TCA_ACT_TAB
+    0x60, 0x00, 0x01, 0x00, // length 96, action 1
+    0x0a, 0x00, 0x01, 0x00, // length 10, TCA_ACT_KIND
+    0x70, 0x65, 0x64, 0x69, 0x74, 0x00, // "pedit\0"
+    0x00, 0x00, // padding
+    0x50, 0x00, 0x02, 0x80, // length 80, TCA_ACT_OPTIONS | NLA_F_NESTED
+    0x34, 0x00, 0x04, 0x00, // length 52, TCA_PEDIT_PARMS_EX
+    0x01, 0x00, 0x00, 0x00, // index 1
+    0x00, 0x00, 0x00, 0x00, // capab 0
+    0x00, 0x00, 0x00, 0x00, // action TC_ACT_OK
+    0x00, 0x00, 0x00, 0x00, // refcnt 0
+    0x00, 0x00, 0x00, 0x00, // bindcnt 0
+    0x01, 0x00, // nkeys 1, flags 0
+    0x00, 0x00, // padding
+    0x00, 0x00, 0x00, 0x00, // mask 0
+    0x0a, 0x00, 0x00, 0x01, // val 10.0.0.1
+    0x10, 0x00, 0x00, 0x00, // off 16
+    0x00, 0x00, 0x00, 0x00, // at 0
+    0x00, 0x00, 0x00, 0x00, // offmask 0
+    0x00, 0x00, 0x00, 0x00, // shift 0
+    0x18, 0x00, 0x05, 0x00, // length 24, TCA_PEDIT_KEYS_EX
+    0x14, 0x00, 0x06, 0x00, // length 20, TCA_PEDIT_KEY_EX
+    0x06, 0x00, 0x01, 0x00, // length 6, TCA_PEDIT_KEY_EX_HTYPE
+    0x02, 0x00, 0x00, 0x00, // TCA_PEDIT_KEY_EX_HDR_TYPE_IP4 and padding
+    0x06, 0x00, 0x02, 0x00, // length 6, TCA_PEDIT_KEY_EX_CMD
+    0x00, 0x00, 0x00, 0x00, // TCA_PEDIT_KEY_EX_CMD_SET and padding
+];
+
+fn tc_action_message_pedit_ex_example() -> TcActionMessage {
+    let mut sel = TcPeditSel::default();
+    sel.generic = TcActionGeneric {
+        index: 1,
+        capab: 0,
+        action: TcActionType::Ok,
+        refcnt: 0,
+        bindcnt: 0,
+    };
+    sel.push_key(TcPeditKey {
+        mask: 0,
+        val: 0x0a000001,
+        off: 16,
+        ..Default::default()
+    })
+    .unwrap();
+    TcActionMessage {
+        header: TcActionMessageHeader {
+            family: AddressFamily::Unspec,
+        },
+        attributes: vec![Actions(vec![TcAction {
+            tab: 1,
+            attributes: vec![
+                Kind("pedit".into()),
+                Options(vec![
+                    Pedit(ParmsEx(sel)),
+                    Pedit(KeysEx(vec![TcPeditKeyEx::KeyEx(vec![
+                        TcPeditKeyExOption::HeaderType(TcPeditHeaderType::Ip4),
+                        TcPeditKeyExOption::Command(TcPeditCommand::Set),
+                    ])])),
+                ]),
+            ],
+        }])],
+    }
+}
+
+#[test]
+fn parse_tc_action_pedit_ex_example() {
+    let buf = TC_ACTION_PEDIT_EX_EXAMPLE;
+    let parsed = TcActionMessage::parse(
+        &TcActionMessageBuffer::new_checked(&buf).unwrap(),
+    )
+    .unwrap();
+    assert_eq!(parsed, tc_action_message_pedit_ex_example());
+}
+
+#[test]
+fn emit_tc_action_pedit_ex_example() {
+    let example = tc_action_message_pedit_ex_example();
+    let mut buf = vec![0; example.buffer_len()];
+    example.emit(&mut buf);
+    assert_eq!(buf.as_slice(), TC_ACTION_PEDIT_EX_EXAMPLE);
+}
+
+#[test]
+fn parse_tc_pedit_sel_truncated_keys() {
+    let mut buf = TC_ACTION_PEDIT_EX_EXAMPLE[32..80].to_vec();
+    // Claim 2 keys with only 1 present
+    buf[20] = 2;
+    assert!(
+        TcPeditSel::parse(&TcPeditSelBuffer::new_checked(&buf).unwrap())
+            .is_err()
+    );
+}
+
+#[test]
+fn tc_pedit_sel_push_too_many_keys() {
+    let mut sel = TcPeditSel::default();
+    for _ in 0..TcPeditSel::MAX_KEYS {
+        sel.push_key(TcPeditKey::default()).unwrap();
+    }
+    assert!(sel.push_key(TcPeditKey::default()).is_err());
+    assert_eq!(sel.keys().len(), TcPeditSel::MAX_KEYS);
+
+    let mut buf = vec![0; sel.buffer_len()];
+    sel.emit(&mut buf);
+    let parsed =
+        TcPeditSel::parse(&TcPeditSelBuffer::new_checked(&buf).unwrap())
+            .unwrap();
+    assert_eq!(parsed, sel);
+}
+
+#[test]
+fn tc_action_pedit_option_tm_round_trip() {
+    let example = Tm(Tcf {
+        install: 1000,
+        lastuse: 200,
+        expires: 0,
+        firstuse: 300,
+    });
+    let mut buf = vec![0; example.buffer_len()];
+    example.emit(&mut buf);
+    assert_eq!(buf.len(), 36);
+    let parsed =
+        TcActionPeditOption::parse(&NlaBuffer::new_checked(&buf).unwrap())
+            .unwrap();
+    assert_eq!(parsed, example);
+}
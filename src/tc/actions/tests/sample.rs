@@ -0,0 +1,106 @@
+// SPDX-License-Identifier: MIT
+
+use netlink_packet_utils::nla::NlaBuffer;
+use netlink_packet_utils::{Emitable, Parseable};
+
+use crate::tc::actions::message::TcActionMessage;
+use crate::tc::actions::message::TcActionMessageAttribute::Actions;
+use crate::tc::actions::{TcActionMessageBuffer, TcActionMessageHeader};
+use crate::tc::TcActionAttribute::{Kind, Options};
+use crate::tc::TcActionOption::Sample;
+use crate::tc::TcActionSampleOption::{Parms, PsampleGroup, Rate};
+use crate::tc::{
+    TcAction, TcActionGeneric, TcActionSample, TcActionSampleOption,
+    TcActionType, Tcf,
+};
+use crate::AddressFamily;
+
+/// Hand-crafted request equivalent to
+///
+/// ```bash
+/// tc actions add action sample rate 100 group 1 index 1
+/// ```
+const TC_ACTION_SAMPLE_EXAMPLE: &[u8] = &[
+    0x00, 0x00, 0x00, 0x00, // AF_UNSPEC with 3 bytes padding
+    0x40, 0x00, 0x01, 0x00, // length 64, TCA_ACT_TAB
+    0x3c, 0x00, 0x01, 0x00, // length 60, action 1
+    0x0b, 0x00, 0x01, 0x00, // length 11, TCA_ACT_KIND
+    0x73, 0x61, 0x6d, 0x70, 0x6c, 0x65, 0x00, // "sample\0"
+    0x00, // padding
+    0x2c, 0x00, 0x02, 0x80, // length 44, TCA_ACT_OPTIONS | NLA_F_NESTED
+    0x18, 0x00, 0x02, 0x00, // length 24, TCA_SAMPLE_PARMS
+    0x01, 0x00, 0x00, 0x00, // index 1
+    0x00, 0x00, 0x00, 0x00, // capab 0
+    0x03, 0x00, 0x00, 0x00, // action TC_ACT_PIPE
+    0x00, 0x00, 0x00, 0x00, // refcnt 0
+    0x00, 0x00, 0x00, 0x00, // bindcnt 0
+    0x08, 0x00, 0x03, 0x00, // length 8, TCA_SAMPLE_RATE
+    0x64, 0x00, 0x00, 0x00, // rate 100
+    0x08, 0x00, 0x05, 0x00, // length 8, TCA_SAMPLE_PSAMPLE_GROUP
+    0x01, 0x00, 0x00, 0x00, // group 1
+];
+
+fn tc_action_message_sample_example() -> TcActionMessage {
+    TcActionMessage {
+        header: TcActionMessageHeader {
+            family: AddressFamily::Unspec,
+        },
+        attributes: vec![Actions(vec![TcAction {
+            tab: 1,
+            attributes: vec![
+                Kind(TcActionSample::KIND.into()),
+                Options(vec![
+                    Sample(Parms(TcActionGeneric {
+                        index: 1,
+                        capab: 0,
+                        action: TcActionType::Pipe,
+                        refcnt: 0,
+                        bindcnt: 0,
+                    })),
+                    Sample(Rate(100)),
+                    Sample(PsampleGroup(1)),
+                ]),
+            ],
+        }])],
+    }
+}
+
+#[test]
+fn parse_tc_action_sample_example() {
+    let buf = TC_ACTION_SAMPLE_EXAMPLE;
+    let parsed = TcActionMessage::parse(
+        &TcActionMessageBuffer::new_checked(&buf).unwrap(),
+    )
+    .unwrap();
+    assert_eq!(parsed, tc_action_message_sample_example());
+}
+
+#[test]
+fn emit_tc_action_sample_example() {
+    let example = tc_action_message_sample_example();
+    let mut buf = vec![0; example.buffer_len()];
+    example.emit(&mut buf);
+    assert_eq!(buf.as_slice(), TC_ACTION_SAMPLE_EXAMPLE);
+}
+
+#[test]
+fn tc_action_sample_option_tm_round_trip() {
+    let example = TcActionSampleOption::Tm(Tcf {
+        install: 1000,
+        lastuse: 200,
+        expires: 0,
+        firstuse: 300,
+    });
+    let mut buf = vec![0; example.buffer_len()];
+    example.emit(&mut buf);
+    let mut expected = vec![0x24, 0x00, 0x01, 0x00]; // TCA_SAMPLE_TM, length 36
+    expected.extend_from_slice(&1000u64.to_ne_bytes());
+    expected.extend_from_slice(&200u64.to_ne_bytes());
+    expected.extend_from_slice(&0u64.to_ne_bytes());
+    expected.extend_from_slice(&300u64.to_ne_bytes());
+    assert_eq!(buf, expected);
+    let parsed =
+        TcActionSampleOption::parse(&NlaBuffer::new_checked(&buf).unwrap())
+            .unwrap();
+    assert_eq!(parsed, example);
+}
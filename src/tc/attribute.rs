@@ -9,7 +9,8 @@ use netlink_packet_utils::{
 };
 
 use super::{
-    TcOption, TcStats, TcStats2, TcStatsBuffer, TcXstats, VecTcOption,
+    options::parse_tc_struct_option, TcOption, TcStats, TcStats2,
+    TcStatsBuffer, TcStructOption, TcXstats, VecTcOption,
 };
 
 const TCA_KIND: u16 = 1;
@@ -28,24 +29,33 @@ const TCA_HW_OFFLOAD: u16 = 12;
 // const TCA_EGRESS_BLOCK: u16 = 14; // TODO
 
 #[derive(Debug, PartialEq, Eq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[non_exhaustive]
 pub enum TcAttribute {
     /// Name of queueing discipline
     Kind(String),
     /// Options follow
     Options(Vec<TcOption>),
+    /// Options of qdiscs using C struct in `TCA_OPTIONS` instead of nested
+    /// attributes, followed by the nested attributes appended by `tc`
+    /// command for compatibility.
+    StructOptions(TcStructOption, Vec<TcOption>),
     /// Statistics
     Stats(TcStats),
     /// Module-specific statistics
     Xstats(TcXstats),
     /// Rate limit
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_hex"))]
     Rate(Vec<u8>),
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_hex"))]
     Fcnt(Vec<u8>),
     Stats2(Vec<TcStats2>),
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_hex"))]
     Stab(Vec<u8>),
     Chain(u32),
     HwOffload(u8),
     DumpInvisible(bool),
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_nla"))]
     Other(DefaultNla),
 }
 
@@ -62,6 +72,9 @@ impl Nla for TcAttribute {
             Self::Stats(ref v) => v.buffer_len(),
             Self::Kind(ref string) => string.as_bytes().len() + 1,
             Self::Options(ref opt) => opt.as_slice().buffer_len(),
+            Self::StructOptions(ref opt, ref nlas) => {
+                opt.buffer_len() + nlas.as_slice().buffer_len()
+            }
             Self::DumpInvisible(_) => 0, // The existence of NLA means true
             Self::Other(ref attr) => attr.value_len(),
         }
@@ -83,6 +96,10 @@ impl Nla for TcAttribute {
                 buffer[string.as_bytes().len()] = 0;
             }
             Self::Options(ref opt) => opt.as_slice().emit(buffer),
+            Self::StructOptions(ref opt, ref nlas) => {
+                opt.emit(buffer);
+                nlas.as_slice().emit(&mut buffer[opt.buffer_len()..]);
+            }
             Self::DumpInvisible(_) => (),
             Self::Other(ref attr) => attr.emit_value(buffer),
         }
@@ -91,7 +108,7 @@ impl Nla for TcAttribute {
     fn kind(&self) -> u16 {
         match *self {
             Self::Kind(_) => TCA_KIND,
-            Self::Options(_) => TCA_OPTIONS,
+            Self::Options(_) | Self::StructOptions(_, _) => TCA_OPTIONS,
             Self::Stats(_) => TCA_STATS,
             Self::Xstats(_) => TCA_XSTATS,
             Self::Rate(_) => TCA_RATE,
@@ -118,11 +135,16 @@ impl<'a, T: AsRef<[u8]> + ?Sized> ParseableParametrized<NlaBuffer<&'a T>, &str>
             TCA_KIND => TcAttribute::Kind(
                 parse_string(payload).context("invalid TCA_KIND")?,
             ),
-            TCA_OPTIONS => TcAttribute::Options(
-                VecTcOption::parse_with_param(buf, kind)
-                    .context(format!("Invalid TCA_OPTIONS for kind: {kind}"))?
-                    .0,
-            ),
+            TCA_OPTIONS => match parse_tc_struct_option(buf, kind)? {
+                Some((opt, nlas)) => TcAttribute::StructOptions(opt, nlas),
+                None => TcAttribute::Options(
+                    VecTcOption::parse_with_param(buf, kind)
+                        .context(format!(
+                            "Invalid TCA_OPTIONS for kind: {kind}"
+                        ))?
+                        .0,
+                ),
+            },
             TCA_STATS => TcAttribute::Stats(
                 TcStats::parse(
                     &TcStatsBuffer::new_checked(payload)
@@ -0,0 +1,146 @@
+// SPDX-License-Identifier: MIT
+
+/// BPF filter
+///
+/// Classifies packets by an eBPF program and optionally performs an action
+/// on them.
+use anyhow::Context;
+use byteorder::{ByteOrder, NativeEndian};
+use netlink_packet_utils::{
+    nla::{DefaultNla, Nla, NlaBuffer, NlasIterator},
+    parsers::{parse_string, parse_u32},
+    traits::{Emitable, Parseable},
+    DecodeError,
+};
+
+use crate::tc::{TcAction, TcHandle};
+
+const TCA_BPF_ACT: u16 = 1;
+const TCA_BPF_CLASSID: u16 = 3;
+const TCA_BPF_FD: u16 = 6;
+const TCA_BPF_NAME: u16 = 7;
+const TCA_BPF_FLAGS: u16 = 8;
+const TCA_BPF_FLAGS_GEN: u16 = 9;
+const TCA_BPF_TAG: u16 = 10;
+const TCA_BPF_ID: u16 = 11;
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[non_exhaustive]
+pub struct TcFilterBpf {}
+impl TcFilterBpf {
+    pub const KIND: &'static str = "bpf";
+}
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[non_exhaustive]
+pub enum TcFilterBpfOption {
+    Action(Vec<TcAction>),
+    ClassId(TcHandle),
+    /// File descriptor of the eBPF program, only used in request
+    Fd(u32),
+    Name(String),
+    /// `TCA_BPF_FLAG_ACT_DIRECT` is the only flag defined by kernel
+    Flags(u32),
+    /// `TCA_CLS_FLAGS_*` flags
+    FlagsGen(u32),
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_hex"))]
+    Tag(Vec<u8>),
+    Id(u32),
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_nla"))]
+    Other(DefaultNla),
+}
+
+impl Nla for TcFilterBpfOption {
+    fn value_len(&self) -> usize {
+        match self {
+            Self::Action(acts) => acts.as_slice().buffer_len(),
+            Self::Name(s) => s.len() + 1,
+            Self::Tag(v) => v.len(),
+            Self::ClassId(_)
+            | Self::Fd(_)
+            | Self::Flags(_)
+            | Self::FlagsGen(_)
+            | Self::Id(_) => 4,
+            Self::Other(attr) => attr.value_len(),
+        }
+    }
+
+    fn emit_value(&self, buffer: &mut [u8]) {
+        match self {
+            Self::Action(acts) => acts.as_slice().emit(buffer),
+            Self::ClassId(i) => NativeEndian::write_u32(buffer, (*i).into()),
+            Self::Name(s) => {
+                buffer[..s.len()].copy_from_slice(s.as_bytes());
+                buffer[s.len()] = 0;
+            }
+            Self::Tag(v) => buffer.copy_from_slice(v.as_slice()),
+            Self::Fd(i) | Self::Flags(i) | Self::FlagsGen(i) | Self::Id(i) => {
+                NativeEndian::write_u32(buffer, *i)
+            }
+            Self::Other(attr) => attr.emit_value(buffer),
+        }
+    }
+
+    fn kind(&self) -> u16 {
+        match self {
+            Self::Action(_) => TCA_BPF_ACT,
+            Self::ClassId(_) => TCA_BPF_CLASSID,
+            Self::Fd(_) => TCA_BPF_FD,
+            Self::Name(_) => TCA_BPF_NAME,
+            Self::Flags(_) => TCA_BPF_FLAGS,
+            Self::FlagsGen(_) => TCA_BPF_FLAGS_GEN,
+            Self::Tag(_) => TCA_BPF_TAG,
+            Self::Id(_) => TCA_BPF_ID,
+            Self::Other(attr) => attr.kind(),
+        }
+    }
+}
+
+impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>>
+    for TcFilterBpfOption
+{
+    fn parse(buf: &NlaBuffer<&'a T>) -> Result<Self, DecodeError> {
+        let payload = buf.value();
+        Ok(match buf.kind() {
+            TCA_BPF_ACT => {
+                let mut acts = vec![];
+                for act in NlasIterator::new(payload) {
+                    let act = act.context("invalid TCA_BPF_ACT")?;
+                    acts.push(
+                        TcAction::parse(&act)
+                            .context("failed to parse TCA_BPF_ACT")?,
+                    );
+                }
+                Self::Action(acts)
+            }
+            TCA_BPF_CLASSID => Self::ClassId(
+                parse_u32(payload)
+                    .context("failed to parse TCA_BPF_CLASSID")?
+                    .into(),
+            ),
+            TCA_BPF_FD => Self::Fd(
+                parse_u32(payload).context("failed to parse TCA_BPF_FD")?,
+            ),
+            TCA_BPF_NAME => Self::Name(
+                parse_string(payload)
+                    .context("failed to parse TCA_BPF_NAME")?,
+            ),
+            TCA_BPF_FLAGS => Self::Flags(
+                parse_u32(payload).context("failed to parse TCA_BPF_FLAGS")?,
+            ),
+            TCA_BPF_FLAGS_GEN => Self::FlagsGen(
+                parse_u32(payload)
+                    .context("failed to parse TCA_BPF_FLAGS_GEN")?,
+            ),
+            TCA_BPF_TAG => Self::Tag(payload.to_vec()),
+            TCA_BPF_ID => Self::Id(
+                parse_u32(payload).context("failed to parse TCA_BPF_ID")?,
+            ),
+            _ => Self::Other(
+                DefaultNla::parse(buf).context("failed to parse bpf nla")?,
+            ),
+        })
+    }
+}
@@ -35,6 +35,7 @@ const TCA_U32_MARK: u16 = 10;
 const TCA_U32_FLAGS: u16 = 11;
 
 #[derive(Debug, PartialEq, Eq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[non_exhaustive]
 pub struct TcFilterU32 {}
 
@@ -43,6 +44,7 @@ impl TcFilterU32 {
 }
 
 #[derive(Debug, PartialEq, Eq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[non_exhaustive]
 pub enum TcFilterU32Option {
     ClassId(TcHandle),
@@ -50,12 +52,17 @@ pub enum TcFilterU32Option {
     Link(u32),
     Divisor(u32),
     Selector(TcU32Selector),
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_hex"))]
     Police(Vec<u8>),
     Action(Vec<TcAction>),
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_hex"))]
     Indev(Vec<u8>),
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_hex"))]
     Pnct(Vec<u8>),
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_hex"))]
     Mark(Vec<u8>),
     Flags(TcU32OptionFlags),
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_nla"))]
     Other(DefaultNla),
 }
 
@@ -164,6 +171,7 @@ impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>>
 }
 
 #[derive(Debug, PartialEq, Eq, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[non_exhaustive]
 pub struct TcU32Selector {
     pub flags: TcU32SelectorFlags,
@@ -279,6 +287,7 @@ impl<T: AsRef<[u8]> + ?Sized> Parseable<TcU32SelectorBuffer<&T>>
 }
 
 #[derive(Debug, PartialEq, Eq, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[non_exhaustive]
 pub struct TcU32Key {
     pub mask: u32,
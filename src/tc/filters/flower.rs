@@ -0,0 +1,567 @@
+// SPDX-License-Identifier: MIT
+
+/// Flower filter
+///
+/// Matches packets against a flow key(e.g. L2/L3/L4 headers) and performs
+/// an action on them.
+use std::{net::Ipv4Addr, ops::RangeInclusive};
+
+use anyhow::Context;
+use byteorder::{BigEndian, ByteOrder, NativeEndian};
+use netlink_packet_utils::{
+    nla::{DefaultNla, Nla, NlaBuffer, NlasIterator, NLA_F_NESTED},
+    parsers::{
+        parse_mac, parse_string, parse_u16_be, parse_u32, parse_u32_be,
+        parse_u8,
+    },
+    traits::{Emitable, Parseable},
+    DecodeError,
+};
+
+use super::{TcFlowerEncOpt, TcFlowerKeyFlags};
+use crate::{
+    ip::{parse_ipv4_addr, IpProtocol},
+    net::ethernet::Ethertype,
+    tc::{TcAction, TcHandle},
+};
+
+const TCA_FLOWER_CLASSID: u16 = 1;
+const TCA_FLOWER_INDEV: u16 = 2;
+const TCA_FLOWER_ACT: u16 = 3;
+const TCA_FLOWER_KEY_ETH_DST: u16 = 4;
+const TCA_FLOWER_KEY_ETH_DST_MASK: u16 = 5;
+const TCA_FLOWER_KEY_ETH_SRC: u16 = 6;
+const TCA_FLOWER_KEY_ETH_SRC_MASK: u16 = 7;
+const TCA_FLOWER_KEY_ETH_TYPE: u16 = 8;
+const TCA_FLOWER_KEY_IP_PROTO: u16 = 9;
+const TCA_FLOWER_KEY_IPV4_SRC: u16 = 10;
+const TCA_FLOWER_KEY_IPV4_SRC_MASK: u16 = 11;
+const TCA_FLOWER_KEY_IPV4_DST: u16 = 12;
+const TCA_FLOWER_KEY_IPV4_DST_MASK: u16 = 13;
+const TCA_FLOWER_KEY_TCP_SRC: u16 = 18;
+const TCA_FLOWER_KEY_TCP_DST: u16 = 19;
+const TCA_FLOWER_KEY_UDP_SRC: u16 = 20;
+const TCA_FLOWER_KEY_UDP_DST: u16 = 21;
+const TCA_FLOWER_FLAGS: u16 = 22;
+const TCA_FLOWER_KEY_FLAGS: u16 = 47;
+const TCA_FLOWER_KEY_FLAGS_MASK: u16 = 48;
+const TCA_FLOWER_KEY_ARP_OP: u16 = 61;
+const TCA_FLOWER_KEY_ARP_OP_MASK: u16 = 62;
+const TCA_FLOWER_KEY_MPLS_TTL: u16 = 67;
+const TCA_FLOWER_KEY_MPLS_BOS: u16 = 68;
+const TCA_FLOWER_KEY_MPLS_TC: u16 = 69;
+const TCA_FLOWER_KEY_MPLS_LABEL: u16 = 70;
+const TCA_FLOWER_KEY_ENC_OPTS: u16 = 84;
+const TCA_FLOWER_KEY_ENC_OPTS_MASK: u16 = 85;
+const TCA_FLOWER_KEY_PORT_SRC_MIN: u16 = 87;
+const TCA_FLOWER_KEY_PORT_SRC_MAX: u16 = 88;
+const TCA_FLOWER_KEY_PORT_DST_MIN: u16 = 89;
+const TCA_FLOWER_KEY_PORT_DST_MAX: u16 = 90;
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[non_exhaustive]
+pub struct TcFilterFlower {}
+impl TcFilterFlower {
+    pub const KIND: &'static str = "flower";
+
+    /// Flower options matching the IPv4 5-tuple, equivalent to:
+    ///
+    /// ```bash
+    /// tc filter add ... protocol ip flower ip_proto <proto> \
+    ///     src_ip <src_ip> dst_ip <dst_ip> src_port <sport> dst_port <dport>
+    /// ```
+    ///
+    /// The addresses are matched with /32 masks. Kernel only matches ports
+    /// of TCP and UDP, so `sport` and `dport` are ignored for other
+    /// protocols.
+    pub fn from_five_tuple(
+        proto: IpProtocol,
+        src_ip: Ipv4Addr,
+        dst_ip: Ipv4Addr,
+        sport: u16,
+        dport: u16,
+    ) -> Vec<TcFilterFlowerOption> {
+        let mut opts = vec![
+            TcFilterFlowerOption::KeyEthType(Ethertype::IPV4),
+            TcFilterFlowerOption::KeyIpProto(proto),
+            TcFilterFlowerOption::KeyIpv4Src(src_ip),
+            TcFilterFlowerOption::KeyIpv4SrcMask(Ipv4Addr::BROADCAST),
+            TcFilterFlowerOption::KeyIpv4Dst(dst_ip),
+            TcFilterFlowerOption::KeyIpv4DstMask(Ipv4Addr::BROADCAST),
+        ];
+        match proto {
+            IpProtocol::Tcp => {
+                opts.push(TcFilterFlowerOption::KeyTcpSrc(sport));
+                opts.push(TcFilterFlowerOption::KeyTcpDst(dport));
+            }
+            IpProtocol::Udp => {
+                opts.push(TcFilterFlowerOption::KeyUdpSrc(sport));
+                opts.push(TcFilterFlowerOption::KeyUdpDst(dport));
+            }
+            _ => (),
+        }
+        opts
+    }
+
+    /// Extract the IPv4 5-tuple `(proto, src_ip, dst_ip, sport, dport)`
+    /// from flower options, the reverse of [Self::from_five_tuple()].
+    ///
+    /// Return `None` when protocol or addresses are not matched or the
+    /// addresses are matched with masks other than /32. For TCP and UDP,
+    /// both ports must be matched, for other protocols they are set to 0.
+    pub fn to_five_tuple(
+        opts: &[TcFilterFlowerOption],
+    ) -> Option<(IpProtocol, Ipv4Addr, Ipv4Addr, u16, u16)> {
+        let mut proto = None;
+        let mut src_ip = None;
+        let mut dst_ip = None;
+        let mut tcp = (None, None);
+        let mut udp = (None, None);
+        for opt in opts {
+            match opt {
+                TcFilterFlowerOption::KeyIpProto(p) => proto = Some(*p),
+                TcFilterFlowerOption::KeyIpv4Src(a) => src_ip = Some(*a),
+                TcFilterFlowerOption::KeyIpv4Dst(a) => dst_ip = Some(*a),
+                TcFilterFlowerOption::KeyIpv4SrcMask(m)
+                | TcFilterFlowerOption::KeyIpv4DstMask(m)
+                    if *m != Ipv4Addr::BROADCAST =>
+                {
+                    return None;
+                }
+                TcFilterFlowerOption::KeyTcpSrc(p) => tcp.0 = Some(*p),
+                TcFilterFlowerOption::KeyTcpDst(p) => tcp.1 = Some(*p),
+                TcFilterFlowerOption::KeyUdpSrc(p) => udp.0 = Some(*p),
+                TcFilterFlowerOption::KeyUdpDst(p) => udp.1 = Some(*p),
+                _ => (),
+            }
+        }
+        let proto = proto?;
+        let (sport, dport) = match proto {
+            IpProtocol::Tcp => (tcp.0?, tcp.1?),
+            IpProtocol::Udp => (udp.0?, udp.1?),
+            _ => (0, 0),
+        };
+        Some((proto, src_ip?, dst_ip?, sport, dport))
+    }
+
+    /// Flower options matching the TCP, UDP or SCTP source port range,
+    /// equivalent to `src_port <min>-<max>` of `tc filter ... flower`.
+    ///
+    /// Kernel requires [TcFilterFlowerOption::KeyIpProto] to be set and
+    /// returns error unless the range holds at least two ports, use
+    /// [TcFilterFlowerOption::KeyTcpSrc] or
+    /// [TcFilterFlowerOption::KeyUdpSrc] for a single port.
+    pub fn from_src_port_range(
+        range: RangeInclusive<u16>,
+    ) -> Result<Vec<TcFilterFlowerOption>, DecodeError> {
+        let (min, max) = check_port_range(range)?;
+        Ok(vec![
+            TcFilterFlowerOption::KeyPortSrcMin(min),
+            TcFilterFlowerOption::KeyPortSrcMax(max),
+        ])
+    }
+
+    /// Flower options matching the TCP, UDP or SCTP destination port range,
+    /// equivalent to `dst_port <min>-<max>` of `tc filter ... flower`.
+    ///
+    /// Same restrictions as [Self::from_src_port_range()] apply.
+    pub fn from_dst_port_range(
+        range: RangeInclusive<u16>,
+    ) -> Result<Vec<TcFilterFlowerOption>, DecodeError> {
+        let (min, max) = check_port_range(range)?;
+        Ok(vec![
+            TcFilterFlowerOption::KeyPortDstMin(min),
+            TcFilterFlowerOption::KeyPortDstMax(max),
+        ])
+    }
+
+    /// Source port range stored in [TcFilterFlowerOption::KeyPortSrcMin]
+    /// and [TcFilterFlowerOption::KeyPortSrcMax], `None` if either is
+    /// missing or the minimum is larger than the maximum.
+    pub fn src_port_range(
+        opts: &[TcFilterFlowerOption],
+    ) -> Option<RangeInclusive<u16>> {
+        let min = opts.iter().find_map(|opt| match opt {
+            TcFilterFlowerOption::KeyPortSrcMin(v) => Some(*v),
+            _ => None,
+        })?;
+        let max = opts.iter().find_map(|opt| match opt {
+            TcFilterFlowerOption::KeyPortSrcMax(v) => Some(*v),
+            _ => None,
+        })?;
+        (min <= max).then_some(min..=max)
+    }
+
+    /// Destination port range stored in
+    /// [TcFilterFlowerOption::KeyPortDstMin] and
+    /// [TcFilterFlowerOption::KeyPortDstMax], `None` if either is missing or
+    /// the minimum is larger than the maximum.
+    pub fn dst_port_range(
+        opts: &[TcFilterFlowerOption],
+    ) -> Option<RangeInclusive<u16>> {
+        let min = opts.iter().find_map(|opt| match opt {
+            TcFilterFlowerOption::KeyPortDstMin(v) => Some(*v),
+            _ => None,
+        })?;
+        let max = opts.iter().find_map(|opt| match opt {
+            TcFilterFlowerOption::KeyPortDstMax(v) => Some(*v),
+            _ => None,
+        })?;
+        (min <= max).then_some(min..=max)
+    }
+}
+
+// Kernel `fl_set_key_port_range()` requires minimum to be strictly smaller
+// than maximum.
+fn check_port_range(
+    range: RangeInclusive<u16>,
+) -> Result<(u16, u16), DecodeError> {
+    let (min, max) = range.into_inner();
+    if min < max {
+        Ok((min, max))
+    } else {
+        Err(format!(
+            "invalid flower port range {min}-{max}, minimum should be \
+            smaller than maximum"
+        )
+        .into())
+    }
+}
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[non_exhaustive]
+pub enum TcFilterFlowerOption {
+    ClassId(TcHandle),
+    Indev(String),
+    Action(Vec<TcAction>),
+    Flags(u32),
+    KeyEthDst([u8; 6]),
+    KeyEthDstMask([u8; 6]),
+    KeyEthSrc([u8; 6]),
+    KeyEthSrcMask([u8; 6]),
+    KeyEthType(Ethertype),
+    KeyIpProto(IpProtocol),
+    KeyIpv4Src(Ipv4Addr),
+    KeyIpv4SrcMask(Ipv4Addr),
+    KeyIpv4Dst(Ipv4Addr),
+    KeyIpv4DstMask(Ipv4Addr),
+    KeyTcpSrc(u16),
+    KeyTcpDst(u16),
+    KeyUdpSrc(u16),
+    KeyUdpDst(u16),
+    /// ARP operation code, kernel only matches the low 8 bits of it.
+    /// Use [crate::net::arp::Operation] to convert from and to the IANA names.
+    KeyArpOp(u8),
+    KeyArpOpMask(u8),
+    KeyMplsTtl(u8),
+    KeyMplsBos(u8),
+    KeyMplsTc(u8),
+    /// The 20 bits MPLS label.
+    KeyMplsLabel(u32),
+    KeyFlags(TcFlowerKeyFlags),
+    KeyFlagsMask(TcFlowerKeyFlags),
+    KeyEncOpts(Vec<TcFlowerEncOpt>),
+    KeyEncOptsMask(Vec<TcFlowerEncOpt>),
+    KeyPortSrcMin(u16),
+    KeyPortSrcMax(u16),
+    KeyPortDstMin(u16),
+    KeyPortDstMax(u16),
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_nla"))]
+    Other(DefaultNla),
+}
+
+impl Nla for TcFilterFlowerOption {
+    fn value_len(&self) -> usize {
+        match self {
+            Self::ClassId(_) => 4,
+            Self::Indev(s) => s.len() + 1,
+            Self::Action(acts) => acts.as_slice().buffer_len(),
+            Self::Flags(_) | Self::KeyFlags(_) | Self::KeyFlagsMask(_) => 4,
+            Self::KeyEthDst(_)
+            | Self::KeyEthDstMask(_)
+            | Self::KeyEthSrc(_)
+            | Self::KeyEthSrcMask(_) => 6,
+            Self::KeyIpProto(_)
+            | Self::KeyArpOp(_)
+            | Self::KeyArpOpMask(_)
+            | Self::KeyMplsTtl(_)
+            | Self::KeyMplsBos(_)
+            | Self::KeyMplsTc(_) => 1,
+            Self::KeyMplsLabel(_) => 4,
+            Self::KeyEthType(_)
+            | Self::KeyTcpSrc(_)
+            | Self::KeyTcpDst(_)
+            | Self::KeyUdpSrc(_)
+            | Self::KeyUdpDst(_)
+            | Self::KeyPortSrcMin(_)
+            | Self::KeyPortSrcMax(_)
+            | Self::KeyPortDstMin(_)
+            | Self::KeyPortDstMax(_) => 2,
+            Self::KeyIpv4Src(_)
+            | Self::KeyIpv4SrcMask(_)
+            | Self::KeyIpv4Dst(_)
+            | Self::KeyIpv4DstMask(_) => 4,
+            Self::KeyEncOpts(opts) | Self::KeyEncOptsMask(opts) => {
+                opts.as_slice().buffer_len()
+            }
+            Self::Other(attr) => attr.value_len(),
+        }
+    }
+
+    fn emit_value(&self, buffer: &mut [u8]) {
+        match self {
+            Self::ClassId(i) => NativeEndian::write_u32(buffer, (*i).into()),
+            Self::Indev(s) => {
+                buffer[..s.len()].copy_from_slice(s.as_bytes());
+                buffer[s.len()] = 0;
+            }
+            Self::Action(acts) => acts.as_slice().emit(buffer),
+            Self::Flags(i) => NativeEndian::write_u32(buffer, *i),
+            Self::KeyEthDst(mac)
+            | Self::KeyEthDstMask(mac)
+            | Self::KeyEthSrc(mac)
+            | Self::KeyEthSrcMask(mac) => buffer.copy_from_slice(mac),
+            Self::KeyIpProto(p) => buffer[0] = i32::from(*p) as u8,
+            Self::KeyArpOp(v)
+            | Self::KeyArpOpMask(v)
+            | Self::KeyMplsTtl(v)
+            | Self::KeyMplsBos(v)
+            | Self::KeyMplsTc(v) => buffer[0] = *v,
+            // Unlike the big endian ports and flags, kernel
+            // `fl_set_key_mpls()` reads the label by `nla_get_u32()` and
+            // the `TCA_FLOWER_KEY_MPLS_LABEL` policy is `NLA_U32`, hence
+            // native endian on both little and big endian hosts.
+            Self::KeyMplsLabel(v) => NativeEndian::write_u32(buffer, *v),
+            // Kernel is using big endian for ethernet protocol and ports
+            Self::KeyEthType(v) => BigEndian::write_u16(buffer, v.0),
+            Self::KeyTcpSrc(v)
+            | Self::KeyTcpDst(v)
+            | Self::KeyUdpSrc(v)
+            | Self::KeyUdpDst(v)
+            | Self::KeyPortSrcMin(v)
+            | Self::KeyPortSrcMax(v)
+            | Self::KeyPortDstMin(v)
+            | Self::KeyPortDstMax(v) => BigEndian::write_u16(buffer, *v),
+            Self::KeyIpv4Src(a)
+            | Self::KeyIpv4SrcMask(a)
+            | Self::KeyIpv4Dst(a)
+            | Self::KeyIpv4DstMask(a) => buffer.copy_from_slice(&a.octets()),
+            // Kernel is using big endian for key flags
+            Self::KeyFlags(f) | Self::KeyFlagsMask(f) => {
+                BigEndian::write_u32(buffer, f.bits())
+            }
+            Self::KeyEncOpts(opts) | Self::KeyEncOptsMask(opts) => {
+                opts.as_slice().emit(buffer)
+            }
+            Self::Other(attr) => attr.emit_value(buffer),
+        }
+    }
+
+    fn kind(&self) -> u16 {
+        match self {
+            Self::ClassId(_) => TCA_FLOWER_CLASSID,
+            Self::Indev(_) => TCA_FLOWER_INDEV,
+            Self::Action(_) => TCA_FLOWER_ACT,
+            Self::Flags(_) => TCA_FLOWER_FLAGS,
+            Self::KeyEthDst(_) => TCA_FLOWER_KEY_ETH_DST,
+            Self::KeyEthDstMask(_) => TCA_FLOWER_KEY_ETH_DST_MASK,
+            Self::KeyEthSrc(_) => TCA_FLOWER_KEY_ETH_SRC,
+            Self::KeyEthSrcMask(_) => TCA_FLOWER_KEY_ETH_SRC_MASK,
+            Self::KeyEthType(_) => TCA_FLOWER_KEY_ETH_TYPE,
+            Self::KeyIpProto(_) => TCA_FLOWER_KEY_IP_PROTO,
+            Self::KeyIpv4Src(_) => TCA_FLOWER_KEY_IPV4_SRC,
+            Self::KeyIpv4SrcMask(_) => TCA_FLOWER_KEY_IPV4_SRC_MASK,
+            Self::KeyIpv4Dst(_) => TCA_FLOWER_KEY_IPV4_DST,
+            Self::KeyIpv4DstMask(_) => TCA_FLOWER_KEY_IPV4_DST_MASK,
+            Self::KeyTcpSrc(_) => TCA_FLOWER_KEY_TCP_SRC,
+            Self::KeyTcpDst(_) => TCA_FLOWER_KEY_TCP_DST,
+            Self::KeyUdpSrc(_) => TCA_FLOWER_KEY_UDP_SRC,
+            Self::KeyUdpDst(_) => TCA_FLOWER_KEY_UDP_DST,
+            Self::KeyArpOp(_) => TCA_FLOWER_KEY_ARP_OP,
+            Self::KeyArpOpMask(_) => TCA_FLOWER_KEY_ARP_OP_MASK,
+            Self::KeyMplsTtl(_) => TCA_FLOWER_KEY_MPLS_TTL,
+            Self::KeyMplsBos(_) => TCA_FLOWER_KEY_MPLS_BOS,
+            Self::KeyMplsTc(_) => TCA_FLOWER_KEY_MPLS_TC,
+            Self::KeyMplsLabel(_) => TCA_FLOWER_KEY_MPLS_LABEL,
+            Self::KeyFlags(_) => TCA_FLOWER_KEY_FLAGS,
+            Self::KeyFlagsMask(_) => TCA_FLOWER_KEY_FLAGS_MASK,
+            Self::KeyEncOpts(_) => TCA_FLOWER_KEY_ENC_OPTS | NLA_F_NESTED,
+            Self::KeyEncOptsMask(_) => {
+                TCA_FLOWER_KEY_ENC_OPTS_MASK | NLA_F_NESTED
+            }
+            Self::KeyPortSrcMin(_) => TCA_FLOWER_KEY_PORT_SRC_MIN,
+            Self::KeyPortSrcMax(_) => TCA_FLOWER_KEY_PORT_SRC_MAX,
+            Self::KeyPortDstMin(_) => TCA_FLOWER_KEY_PORT_DST_MIN,
+            Self::KeyPortDstMax(_) => TCA_FLOWER_KEY_PORT_DST_MAX,
+            Self::Other(attr) => attr.kind(),
+        }
+    }
+}
+
+impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>>
+    for TcFilterFlowerOption
+{
+    fn parse(buf: &NlaBuffer<&'a T>) -> Result<Self, DecodeError> {
+        let payload = buf.value();
+        Ok(match buf.kind() {
+            TCA_FLOWER_CLASSID => Self::ClassId(
+                parse_u32(payload)
+                    .context("failed to parse TCA_FLOWER_CLASSID")?
+                    .into(),
+            ),
+            TCA_FLOWER_INDEV => Self::Indev(
+                parse_string(payload)
+                    .context("failed to parse TCA_FLOWER_INDEV")?,
+            ),
+            TCA_FLOWER_ACT => {
+                let mut acts = vec![];
+                for act in NlasIterator::new(payload) {
+                    let act = act.context("invalid TCA_FLOWER_ACT")?;
+                    acts.push(
+                        TcAction::parse(&act)
+                            .context("failed to parse TCA_FLOWER_ACT")?,
+                    );
+                }
+                Self::Action(acts)
+            }
+            TCA_FLOWER_FLAGS => Self::Flags(
+                parse_u32(payload)
+                    .context("failed to parse TCA_FLOWER_FLAGS")?,
+            ),
+            // `parse_mac()` copies into `[u8; 6]` without heap allocation and
+            // rejects payload not holding exactly 6 bytes.
+            TCA_FLOWER_KEY_ETH_DST => Self::KeyEthDst(
+                parse_mac(payload)
+                    .context("failed to parse TCA_FLOWER_KEY_ETH_DST")?,
+            ),
+            TCA_FLOWER_KEY_ETH_DST_MASK => Self::KeyEthDstMask(
+                parse_mac(payload)
+                    .context("failed to parse TCA_FLOWER_KEY_ETH_DST_MASK")?,
+            ),
+            TCA_FLOWER_KEY_ETH_SRC => Self::KeyEthSrc(
+                parse_mac(payload)
+                    .context("failed to parse TCA_FLOWER_KEY_ETH_SRC")?,
+            ),
+            TCA_FLOWER_KEY_ETH_SRC_MASK => Self::KeyEthSrcMask(
+                parse_mac(payload)
+                    .context("failed to parse TCA_FLOWER_KEY_ETH_SRC_MASK")?,
+            ),
+            TCA_FLOWER_KEY_ETH_TYPE => Self::KeyEthType(
+                parse_u16_be(payload)
+                    .context("failed to parse TCA_FLOWER_KEY_ETH_TYPE")?
+                    .into(),
+            ),
+            TCA_FLOWER_KEY_IP_PROTO => Self::KeyIpProto(IpProtocol::from(
+                parse_u8(payload)
+                    .context("failed to parse TCA_FLOWER_KEY_IP_PROTO")?
+                    as i32,
+            )),
+            TCA_FLOWER_KEY_IPV4_SRC => Self::KeyIpv4Src(
+                parse_ipv4_addr(payload)
+                    .context("failed to parse TCA_FLOWER_KEY_IPV4_SRC")?,
+            ),
+            TCA_FLOWER_KEY_IPV4_SRC_MASK => Self::KeyIpv4SrcMask(
+                parse_ipv4_addr(payload)
+                    .context("failed to parse TCA_FLOWER_KEY_IPV4_SRC_MASK")?,
+            ),
+            TCA_FLOWER_KEY_IPV4_DST => Self::KeyIpv4Dst(
+                parse_ipv4_addr(payload)
+                    .context("failed to parse TCA_FLOWER_KEY_IPV4_DST")?,
+            ),
+            TCA_FLOWER_KEY_IPV4_DST_MASK => Self::KeyIpv4DstMask(
+                parse_ipv4_addr(payload)
+                    .context("failed to parse TCA_FLOWER_KEY_IPV4_DST_MASK")?,
+            ),
+            TCA_FLOWER_KEY_TCP_SRC => Self::KeyTcpSrc(
+                parse_u16_be(payload)
+                    .context("failed to parse TCA_FLOWER_KEY_TCP_SRC")?,
+            ),
+            TCA_FLOWER_KEY_TCP_DST => Self::KeyTcpDst(
+                parse_u16_be(payload)
+                    .context("failed to parse TCA_FLOWER_KEY_TCP_DST")?,
+            ),
+            TCA_FLOWER_KEY_UDP_SRC => Self::KeyUdpSrc(
+                parse_u16_be(payload)
+                    .context("failed to parse TCA_FLOWER_KEY_UDP_SRC")?,
+            ),
+            TCA_FLOWER_KEY_UDP_DST => Self::KeyUdpDst(
+                parse_u16_be(payload)
+                    .context("failed to parse TCA_FLOWER_KEY_UDP_DST")?,
+            ),
+            TCA_FLOWER_KEY_ARP_OP => Self::KeyArpOp(
+                parse_u8(payload)
+                    .context("failed to parse TCA_FLOWER_KEY_ARP_OP")?,
+            ),
+            TCA_FLOWER_KEY_ARP_OP_MASK => Self::KeyArpOpMask(
+                parse_u8(payload)
+                    .context("failed to parse TCA_FLOWER_KEY_ARP_OP_MASK")?,
+            ),
+            TCA_FLOWER_KEY_MPLS_TTL => Self::KeyMplsTtl(
+                parse_u8(payload)
+                    .context("failed to parse TCA_FLOWER_KEY_MPLS_TTL")?,
+            ),
+            TCA_FLOWER_KEY_MPLS_BOS => Self::KeyMplsBos(
+                parse_u8(payload)
+                    .context("failed to parse TCA_FLOWER_KEY_MPLS_BOS")?,
+            ),
+            TCA_FLOWER_KEY_MPLS_TC => Self::KeyMplsTc(
+                parse_u8(payload)
+                    .context("failed to parse TCA_FLOWER_KEY_MPLS_TC")?,
+            ),
+            TCA_FLOWER_KEY_MPLS_LABEL => Self::KeyMplsLabel(
+                parse_u32(payload)
+                    .context("failed to parse TCA_FLOWER_KEY_MPLS_LABEL")?,
+            ),
+            TCA_FLOWER_KEY_FLAGS => {
+                Self::KeyFlags(TcFlowerKeyFlags::from_bits_retain(
+                    parse_u32_be(payload)
+                        .context("failed to parse TCA_FLOWER_KEY_FLAGS")?,
+                ))
+            }
+            TCA_FLOWER_KEY_FLAGS_MASK => {
+                Self::KeyFlagsMask(TcFlowerKeyFlags::from_bits_retain(
+                    parse_u32_be(payload)
+                        .context("failed to parse TCA_FLOWER_KEY_FLAGS_MASK")?,
+                ))
+            }
+            TCA_FLOWER_KEY_ENC_OPTS => Self::KeyEncOpts(
+                parse_enc_opts(payload)
+                    .context("failed to parse TCA_FLOWER_KEY_ENC_OPTS")?,
+            ),
+            TCA_FLOWER_KEY_ENC_OPTS_MASK => Self::KeyEncOptsMask(
+                parse_enc_opts(payload)
+                    .context("failed to parse TCA_FLOWER_KEY_ENC_OPTS_MASK")?,
+            ),
+            TCA_FLOWER_KEY_PORT_SRC_MIN => Self::KeyPortSrcMin(
+                parse_u16_be(payload)
+                    .context("failed to parse TCA_FLOWER_KEY_PORT_SRC_MIN")?,
+            ),
+            TCA_FLOWER_KEY_PORT_SRC_MAX => Self::KeyPortSrcMax(
+                parse_u16_be(payload)
+                    .context("failed to parse TCA_FLOWER_KEY_PORT_SRC_MAX")?,
+            ),
+            TCA_FLOWER_KEY_PORT_DST_MIN => Self::KeyPortDstMin(
+                parse_u16_be(payload)
+                    .context("failed to parse TCA_FLOWER_KEY_PORT_DST_MIN")?,
+            ),
+            TCA_FLOWER_KEY_PORT_DST_MAX => Self::KeyPortDstMax(
+                parse_u16_be(payload)
+                    .context("failed to parse TCA_FLOWER_KEY_PORT_DST_MAX")?,
+            ),
+            _ => Self::Other(
+                DefaultNla::parse(buf).context("failed to parse flower nla")?,
+            ),
+        })
+    }
+}
+
+fn parse_enc_opts(payload: &[u8]) -> Result<Vec<TcFlowerEncOpt>, DecodeError> {
+    let mut opts = vec![];
+    for nla in NlasIterator::new(payload) {
+        let nla = nla.context("invalid flower encap option")?;
+        opts.push(TcFlowerEncOpt::parse(&nla)?);
+    }
+    Ok(opts)
+}
@@ -0,0 +1,249 @@
+// SPDX-License-Identifier: MIT
+
+use std::fmt;
+
+use anyhow::Context;
+use byteorder::{BigEndian, ByteOrder};
+use netlink_packet_utils::{
+    nla::{DefaultNla, Nla, NlaBuffer, NlasIterator, NLA_F_NESTED},
+    parsers::{parse_u32_be, parse_u8},
+    traits::{Emitable, Parseable},
+    DecodeError,
+};
+
+const TCA_FLOWER_KEY_ENC_OPTS_ERSPAN: u16 = 3;
+
+const TCA_FLOWER_KEY_ENC_OPT_ERSPAN_VER: u16 = 1;
+const TCA_FLOWER_KEY_ENC_OPT_ERSPAN_INDEX: u16 = 2;
+const TCA_FLOWER_KEY_ENC_OPT_ERSPAN_DIR: u16 = 3;
+const TCA_FLOWER_KEY_ENC_OPT_ERSPAN_HWID: u16 = 4;
+
+/// Tunnel options of `TCA_FLOWER_KEY_ENC_OPTS` and
+/// `TCA_FLOWER_KEY_ENC_OPTS_MASK`.
+#[derive(Debug, PartialEq, Eq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[non_exhaustive]
+pub enum TcFlowerEncOpt {
+    Erspan(Vec<TcFlowerEncOptErspan>),
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_nla"))]
+    Other(DefaultNla),
+}
+
+impl Nla for TcFlowerEncOpt {
+    fn value_len(&self) -> usize {
+        match self {
+            Self::Erspan(opts) => opts.as_slice().buffer_len(),
+            Self::Other(attr) => attr.value_len(),
+        }
+    }
+
+    fn emit_value(&self, buffer: &mut [u8]) {
+        match self {
+            Self::Erspan(opts) => opts.as_slice().emit(buffer),
+            Self::Other(attr) => attr.emit_value(buffer),
+        }
+    }
+
+    fn kind(&self) -> u16 {
+        match self {
+            Self::Erspan(_) => TCA_FLOWER_KEY_ENC_OPTS_ERSPAN | NLA_F_NESTED,
+            Self::Other(attr) => attr.kind(),
+        }
+    }
+}
+
+impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>>
+    for TcFlowerEncOpt
+{
+    fn parse(buf: &NlaBuffer<&'a T>) -> Result<Self, DecodeError> {
+        let payload = buf.value();
+        Ok(match buf.kind() {
+            TCA_FLOWER_KEY_ENC_OPTS_ERSPAN => {
+                let mut opts = vec![];
+                for nla in NlasIterator::new(payload) {
+                    let nla =
+                        nla.context("invalid TCA_FLOWER_KEY_ENC_OPTS_ERSPAN")?;
+                    opts.push(TcFlowerEncOptErspan::parse(&nla).context(
+                        "failed to parse TCA_FLOWER_KEY_ENC_OPTS_ERSPAN",
+                    )?);
+                }
+                Self::Erspan(opts)
+            }
+            _ => Self::Other(
+                DefaultNla::parse(buf)
+                    .context("failed to parse flower encap option nla")?,
+            ),
+        })
+    }
+}
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[non_exhaustive]
+pub enum TcFlowerEncOptErspan {
+    Version(u8),
+    /// Only valid for ERSPAN version 1
+    Index(u32),
+    /// Only valid for ERSPAN version 2
+    Direction(ErspanDirection),
+    /// Only valid for ERSPAN version 2
+    HwId(ErspanHwId),
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_nla"))]
+    Other(DefaultNla),
+}
+
+impl Nla for TcFlowerEncOptErspan {
+    fn value_len(&self) -> usize {
+        match self {
+            Self::Version(_) | Self::Direction(_) | Self::HwId(_) => 1,
+            Self::Index(_) => 4,
+            Self::Other(attr) => attr.value_len(),
+        }
+    }
+
+    fn emit_value(&self, buffer: &mut [u8]) {
+        match self {
+            Self::Version(v) => buffer[0] = *v,
+            Self::Index(v) => BigEndian::write_u32(buffer, *v),
+            Self::Direction(v) => buffer[0] = (*v).into(),
+            Self::HwId(v) => buffer[0] = v.value(),
+            Self::Other(attr) => attr.emit_value(buffer),
+        }
+    }
+
+    fn kind(&self) -> u16 {
+        match self {
+            Self::Version(_) => TCA_FLOWER_KEY_ENC_OPT_ERSPAN_VER,
+            Self::Index(_) => TCA_FLOWER_KEY_ENC_OPT_ERSPAN_INDEX,
+            Self::Direction(_) => TCA_FLOWER_KEY_ENC_OPT_ERSPAN_DIR,
+            Self::HwId(_) => TCA_FLOWER_KEY_ENC_OPT_ERSPAN_HWID,
+            Self::Other(attr) => attr.kind(),
+        }
+    }
+}
+
+impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>>
+    for TcFlowerEncOptErspan
+{
+    fn parse(buf: &NlaBuffer<&'a T>) -> Result<Self, DecodeError> {
+        let payload = buf.value();
+        Ok(match buf.kind() {
+            TCA_FLOWER_KEY_ENC_OPT_ERSPAN_VER => {
+                Self::Version(parse_u8(payload).context(
+                    "failed to parse TCA_FLOWER_KEY_ENC_OPT_ERSPAN_VER",
+                )?)
+            }
+            TCA_FLOWER_KEY_ENC_OPT_ERSPAN_INDEX => {
+                Self::Index(parse_u32_be(payload).context(
+                    "failed to parse TCA_FLOWER_KEY_ENC_OPT_ERSPAN_INDEX",
+                )?)
+            }
+            TCA_FLOWER_KEY_ENC_OPT_ERSPAN_DIR => Self::Direction(
+                parse_u8(payload)
+                    .context(
+                        "failed to parse TCA_FLOWER_KEY_ENC_OPT_ERSPAN_DIR",
+                    )?
+                    .into(),
+            ),
+            TCA_FLOWER_KEY_ENC_OPT_ERSPAN_HWID => Self::HwId(
+                ErspanHwId::new(parse_u8(payload).context(
+                    "failed to parse TCA_FLOWER_KEY_ENC_OPT_ERSPAN_HWID",
+                )?)
+                .context("invalid TCA_FLOWER_KEY_ENC_OPT_ERSPAN_HWID")?,
+            ),
+            _ => Self::Other(
+                DefaultNla::parse(buf)
+                    .context("failed to parse flower erspan option nla")?,
+            ),
+        })
+    }
+}
+
+impl fmt::Display for TcFlowerEncOptErspan {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Self::Version(v) => write!(f, "ver {v}"),
+            Self::Index(v) => write!(f, "index {v}"),
+            Self::Direction(v) => write!(f, "dir {v}"),
+            Self::HwId(v) => write!(f, "hwid {v}"),
+            Self::Other(attr) => write!(f, "other({})", attr.kind()),
+        }
+    }
+}
+
+const ERSPAN_DIR_INGRESS: u8 = 0;
+const ERSPAN_DIR_EGRESS: u8 = 1;
+
+/// Direction of the mirrored traffic in ERSPAN version 2 header.
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[non_exhaustive]
+pub enum ErspanDirection {
+    #[default]
+    Ingress,
+    Egress,
+    Other(u8),
+}
+
+impl From<u8> for ErspanDirection {
+    fn from(d: u8) -> Self {
+        match d {
+            ERSPAN_DIR_INGRESS => Self::Ingress,
+            ERSPAN_DIR_EGRESS => Self::Egress,
+            _ => Self::Other(d),
+        }
+    }
+}
+
+impl From<ErspanDirection> for u8 {
+    fn from(v: ErspanDirection) -> u8 {
+        match v {
+            ErspanDirection::Ingress => ERSPAN_DIR_INGRESS,
+            ErspanDirection::Egress => ERSPAN_DIR_EGRESS,
+            ErspanDirection::Other(d) => d,
+        }
+    }
+}
+
+impl fmt::Display for ErspanDirection {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Self::Ingress => write!(f, "ingress"),
+            Self::Egress => write!(f, "egress"),
+            Self::Other(d) => write!(f, "other({d})"),
+        }
+    }
+}
+
+/// 6 bits hardware ID of ERSPAN version 2 header.
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ErspanHwId(u8);
+
+impl ErspanHwId {
+    pub const MAX: u8 = 0x3f;
+
+    /// Returns error if `hwid` does not fit into 6 bits.
+    pub fn new(hwid: u8) -> Result<Self, DecodeError> {
+        if hwid > Self::MAX {
+            Err(format!(
+                "invalid ERSPAN hardware ID {hwid}, should be less or equal \
+                 to {}",
+                Self::MAX
+            )
+            .into())
+        } else {
+            Ok(Self(hwid))
+        }
+    }
+
+    pub fn value(&self) -> u8 {
+        self.0
+    }
+}
+
+impl fmt::Display for ErspanHwId {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
@@ -0,0 +1,29 @@
+// SPDX-License-Identifier: MIT
+
+use std::fmt;
+
+const TCA_FLOWER_KEY_FLAGS_IS_FRAGMENT: u32 = 1 << 0;
+const TCA_FLOWER_KEY_FLAGS_FRAG_IS_FIRST: u32 = 1 << 1;
+
+bitflags! {
+    /// Flags of `TCA_FLOWER_KEY_FLAGS` and `TCA_FLOWER_KEY_FLAGS_MASK`.
+    ///
+    /// To match first fragments(`ip_flags frag/firstfrag` in `tc`), set both
+    /// flags in key and mask. To match non-first fragments
+    /// (`ip_flags frag/nofirstfrag`), set only [Self::IsFragment] in key
+    /// with both flags in mask.
+    #[derive(Debug, PartialEq, Eq, Clone, Copy, Default)]
+    #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+    #[non_exhaustive]
+    pub struct TcFlowerKeyFlags: u32 {
+        const IsFragment = TCA_FLOWER_KEY_FLAGS_IS_FRAGMENT;
+        const FragIsFirst = TCA_FLOWER_KEY_FLAGS_FRAG_IS_FIRST;
+        const _ = !0;
+    }
+}
+
+impl fmt::Display for TcFlowerKeyFlags {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        bitflags::parser::to_writer(self, f)
+    }
+}
@@ -20,19 +20,26 @@ const TCA_MATCHALL_FLAGS: u16 = 3;
 const TCA_MATCHALL_PCNT: u16 = 4;
 
 #[derive(Debug, PartialEq, Eq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[non_exhaustive]
 pub struct TcFilterMatchAll {}
 impl TcFilterMatchAll {
     pub const KIND: &'static str = "matchall";
 }
 
+/// Options of matchall filter, emitted in the order they are stored.
+///
+/// Kernel dumps them in the order of `ClassId`, `Flags`, `Pnct` and `Action`.
 #[derive(Debug, PartialEq, Eq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[non_exhaustive]
 pub enum TcFilterMatchAllOption {
     ClassId(TcHandle),
     Action(Vec<TcAction>),
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_hex"))]
     Pnct(Vec<u8>),
     Flags(u32),
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_nla"))]
     Other(DefaultNla),
 }
 
@@ -76,7 +83,7 @@ impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>>
         Ok(match buf.kind() {
             TCA_MATCHALL_CLASSID => Self::ClassId(
                 parse_u32(payload)
-                    .context("failed to parse TCA_MATCHALL_UNSPEC")?
+                    .context("failed to parse TCA_MATCHALL_CLASSID")?
                     .into(),
             ),
             TCA_MATCHALL_ACT => {
@@ -96,7 +103,8 @@ impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>>
                     .context("failed to parse TCA_MATCHALL_FLAGS")?,
             ),
             _ => Self::Other(
-                DefaultNla::parse(buf).context("failed to parse u32 nla")?,
+                DefaultNla::parse(buf)
+                    .context("failed to parse matchall nla")?,
             ),
         })
     }
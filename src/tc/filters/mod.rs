@@ -1,12 +1,24 @@
 // SPDX-License-Identifier: MIT
 
+mod cls_bpf;
+mod cls_fw;
 mod cls_u32;
+mod flower;
+mod flower_enc_opts;
+mod flower_flags;
 mod matchall;
 mod u32_flags;
 
+pub use self::cls_bpf::{TcFilterBpf, TcFilterBpfOption};
+pub use self::cls_fw::{TcFilterFw, TcFilterFwOption};
 pub use self::cls_u32::{
     TcFilterU32, TcFilterU32Option, TcU32Key, TcU32Selector,
     TcU32SelectorBuffer,
 };
+pub use self::flower::{TcFilterFlower, TcFilterFlowerOption};
+pub use self::flower_enc_opts::{
+    ErspanDirection, ErspanHwId, TcFlowerEncOpt, TcFlowerEncOptErspan,
+};
+pub use self::flower_flags::TcFlowerKeyFlags;
 pub use self::matchall::{TcFilterMatchAll, TcFilterMatchAllOption};
 pub use u32_flags::{TcU32OptionFlags, TcU32SelectorFlags};
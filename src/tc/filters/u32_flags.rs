@@ -7,6 +7,7 @@ const TC_U32_EAT: u8 = 8;
 
 bitflags! {
     #[derive(Debug, Default, PartialEq, Eq, Clone, Copy)]
+    #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
     #[non_exhaustive]
     pub struct TcU32SelectorFlags: u8 {
         const Terminal = TC_U32_TERMINAL;
@@ -25,6 +26,7 @@ const TCA_CLS_FLAGS_VERBOSE: u32 = 1 << 4;
 
 bitflags! {
     #[derive(Debug, PartialEq, Eq, Clone, Copy)]
+    #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
     #[non_exhaustive]
     pub struct TcU32OptionFlags: u32 {
         const SkipHw = TCA_CLS_FLAGS_SKIP_HW;
@@ -21,6 +21,8 @@ buffer!(TcMessageBuffer(TC_HEADER_LEN) {
     payload: (slice, TC_HEADER_LEN..),
 });
 
+impl_try_from_slice!(TcMessageBuffer);
+
 impl<'a, T: AsRef<[u8]> + ?Sized> TcMessageBuffer<&'a T> {
     pub fn attributes(
         &self,
@@ -30,6 +32,7 @@ impl<'a, T: AsRef<[u8]> + ?Sized> TcMessageBuffer<&'a T> {
 }
 
 #[derive(Debug, PartialEq, Eq, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct TcHeader {
     pub family: AddressFamily,
     // Interface index
@@ -72,7 +75,10 @@ impl<T: AsRef<[u8]>> Parseable<TcMessageBuffer<T>> for TcHeader {
     }
 }
 
-#[derive(Debug, PartialEq, Eq, Clone, Copy, Default)]
+// The derived `Ord` compares `major` first, which matches the order of the
+// `u32` handle.
+#[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Clone, Copy, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct TcHandle {
     pub major: u16,
     pub minor: u16,
@@ -90,6 +96,16 @@ impl TcHandle {
     };
 
     pub const CLSACT: Self = Self::INGRESS;
+    /// Parent of filters handling ingress traffic of clsact qdisc.
+    pub const CLSACT_INGRESS: Self = Self {
+        major: u16::MAX,
+        minor: Self::MIN_INGRESS,
+    };
+    /// Parent of filters handling egress traffic of clsact qdisc.
+    pub const CLSACT_EGRESS: Self = Self {
+        major: u16::MAX,
+        minor: Self::MIN_EGRESS,
+    };
 
     pub const MIN_PRIORITY: u16 = 0xFFE0;
     pub const MIN_INGRESS: u16 = 0xFFF2;
@@ -119,8 +135,51 @@ impl From<TcHandle> for u32 {
     }
 }
 
+/// Format as `major:minor` in hex, the same as `tc` command does.
 impl std::fmt::Display for TcHandle {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        write!(f, "{}:{}", self.major, self.minor)
+        write!(f, "{:x}:{:x}", self.major, self.minor)
+    }
+}
+
+/// Parse the `major:minor` syntax of `tc` command, both numbers are in hex
+/// and could be omitted to indicate `0`, e.g. `ffff:` or `:1`. Besides that,
+/// `root` and `none` are accepted for [TcHandle::ROOT] and
+/// [TcHandle::UNSPEC], and a single hex number without `:` is treated as the
+/// packed 32 bits handle.
+impl std::str::FromStr for TcHandle {
+    type Err = DecodeError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "root" => return Ok(Self::ROOT),
+            "none" => return Ok(Self::UNSPEC),
+            _ => (),
+        }
+        let parse_hex = |v: &str| -> Result<u16, DecodeError> {
+            if v.is_empty() {
+                Ok(0)
+            } else {
+                u16::from_str_radix(v, 16).map_err(|e| {
+                    DecodeError::from(format!(
+                        "invalid TC handle {s}, {v} is not a 16 bits hex \
+                         number: {e}"
+                    ))
+                })
+            }
+        };
+        if let Some((major, minor)) = s.split_once(':') {
+            Ok(Self {
+                major: parse_hex(major)?,
+                minor: parse_hex(minor)?,
+            })
+        } else {
+            u32::from_str_radix(s, 16).map(Self::from).map_err(|e| {
+                format!(
+                    "invalid TC handle {s}, expecting major:minor in hex: {e}"
+                )
+                .into()
+            })
+        }
     }
 }
@@ -6,9 +6,10 @@ use netlink_packet_utils::{
     DecodeError,
 };
 
-use super::{TcAttribute, TcHeader, TcMessageBuffer};
+use super::{TcAttribute, TcHandle, TcHeader, TcMessageBuffer};
 
 #[derive(Debug, PartialEq, Eq, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[non_exhaustive]
 pub struct TcMessage {
     pub header: TcHeader,
@@ -78,3 +79,78 @@ impl Emitable for TcMessage {
             .emit(&mut buffer[self.header.buffer_len()..]);
     }
 }
+
+/// Builder of [TcMessage] for traffic control requests.
+///
+/// The netlink message type is decided by the [crate::RouteNetlinkMessage]
+/// variant holding the built message:
+///
+/// ```
+/// use netlink_packet_route::{tc::TcMessageBuilder, RouteNetlinkMessage};
+///
+/// // tc class show dev eth0
+/// let message = RouteNetlinkMessage::GetTrafficClass(
+///     TcMessageBuilder::for_class_dump(2).build(),
+/// );
+/// ```
+#[derive(Debug, PartialEq, Eq, Clone, Default)]
+pub struct TcMessageBuilder {
+    header: TcHeader,
+    attributes: Vec<TcAttribute>,
+}
+
+impl TcMessageBuilder {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Create builder for `RTM_GETQDISC` dump request of the interface with
+    /// index `ifindex`, or of all interfaces when `None`.
+    pub fn for_qdisc_dump(ifindex: Option<u32>) -> Self {
+        match ifindex {
+            Some(index) => Self::new().index(index),
+            None => Self::new(),
+        }
+    }
+
+    /// Create builder for `RTM_GETTCLASS` dump request of the interface with
+    /// index `ifindex`. Unlike qdisc, kernel replies nothing for class dump
+    /// without interface index.
+    pub fn for_class_dump(ifindex: u32) -> Self {
+        Self::new().index(ifindex)
+    }
+
+    /// Set the index of the interface the qdisc, class or filter is attached
+    /// to (`tcm_ifindex`).
+    pub fn index(mut self, index: u32) -> Self {
+        self.header.index = index as i32;
+        self
+    }
+
+    /// Set the handle of the qdisc, class or filter in the header.
+    pub fn handle(mut self, handle: TcHandle) -> Self {
+        self.header.handle = handle;
+        self
+    }
+
+    /// Set the parent handle in the header, for example [TcHandle::ROOT].
+    /// For dump requests, only children of the specified parent are
+    /// included in reply.
+    pub fn parent(mut self, parent: TcHandle) -> Self {
+        self.header.parent = parent;
+        self
+    }
+
+    /// Set the qdisc, class or filter kind (`TCA_KIND`).
+    pub fn kind(mut self, kind: String) -> Self {
+        self.attributes.push(TcAttribute::Kind(kind));
+        self
+    }
+
+    pub fn build(self) -> TcMessage {
+        TcMessage {
+            header: self.header,
+            attributes: self.attributes,
+        }
+    }
+}
@@ -10,26 +10,44 @@ mod qdiscs;
 mod stats;
 
 pub use self::actions::{
-    TcAction, TcActionAttribute, TcActionGeneric, TcActionGenericBuffer,
+    TcAction, TcActionAttribute, TcActionCt, TcActionCtOption, TcActionGact,
+    TcActionGactOption, TcActionGeneric, TcActionGenericBuffer,
     TcActionMessage, TcActionMessageAttribute, TcActionMessageBuffer,
     TcActionMessageFlags, TcActionMessageFlagsWithSelector, TcActionMirror,
-    TcActionMirrorOption, TcActionNat, TcActionNatOption, TcActionOption,
-    TcActionType, TcMirror, TcMirrorActionType, TcMirrorBuffer, TcNat,
-    TcNatBuffer, TcNatFlags,
+    TcActionMirrorOption, TcActionMpls, TcActionMplsOption, TcActionNat,
+    TcActionNatOption, TcActionOption, TcActionPedit, TcActionPeditOption,
+    TcActionSample, TcActionSampleOption, TcActionType, TcCt, TcCtActionFlags,
+    TcCtBuffer, TcGact, TcGactBuffer, TcGactProb, TcGactProbBuffer,
+    TcGactProbType, TcMirror, TcMirrorActionType, TcMirrorBuffer, TcMpls,
+    TcMplsActionType, TcMplsBuffer, TcNat, TcNatBuffer, TcNatFlags,
+    TcPeditCommand, TcPeditHeaderType, TcPeditKey, TcPeditKeyBuffer,
+    TcPeditKeyEx, TcPeditKeyExOption, TcPeditSel, TcPeditSelBuffer, Tcf,
+    TcfBuffer,
 };
 pub use self::attribute::TcAttribute;
 pub use self::filters::{
+    ErspanDirection, ErspanHwId, TcFilterBpf, TcFilterBpfOption,
+    TcFilterFlower, TcFilterFlowerOption, TcFilterFw, TcFilterFwOption,
     TcFilterMatchAll, TcFilterMatchAllOption, TcFilterU32, TcFilterU32Option,
-    TcU32Key, TcU32OptionFlags, TcU32Selector, TcU32SelectorBuffer,
-    TcU32SelectorFlags,
+    TcFlowerEncOpt, TcFlowerEncOptErspan, TcFlowerKeyFlags, TcU32Key,
+    TcU32OptionFlags, TcU32Selector, TcU32SelectorBuffer, TcU32SelectorFlags,
 };
 pub use self::header::{TcHandle, TcHeader, TcMessageBuffer};
-pub use self::message::TcMessage;
-pub use self::options::TcOption;
+pub use self::message::{TcMessage, TcMessageBuilder};
+pub use self::options::{TcOption, TcStructOption};
 pub use self::qdiscs::{
-    TcFqCodelClStats, TcFqCodelClStatsBuffer, TcFqCodelQdStats,
-    TcFqCodelQdStatsBuffer, TcFqCodelXstats, TcQdiscFqCodel,
-    TcQdiscFqCodelOption, TcQdiscIngress, TcQdiscIngressOption,
+    TcCakeStats, TcClassDrrOption, TcFifoQoptBuffer, TcFqCodelClStats,
+    TcFqCodelClStatsBuffer, TcFqCodelQdStats, TcFqCodelQdStatsBuffer,
+    TcFqCodelXstats, TcFqPieXstats, TcFqPieXstatsBuffer, TcFqQdStats,
+    TcFqQdStatsBuffer, TcMqprioQopt, TcMqprioQoptBuffer, TcMultiqQoptBuffer,
+    TcPrioQoptBuffer, TcQdiscCake, TcQdiscCakeOption, TcQdiscClsact,
+    TcQdiscClsactOption, TcQdiscDrr, TcQdiscFifo, TcQdiscFifoOption, TcQdiscFq,
+    TcQdiscFqCodel, TcQdiscFqCodelOption, TcQdiscFqOption, TcQdiscFqPie,
+    TcQdiscFqPieOption, TcQdiscIngress, TcQdiscIngressOption, TcQdiscMultiq,
+    TcQdiscMultiqOption, TcQdiscPfifoFast, TcQdiscPrio, TcQdiscPrioOption,
+    TcQdiscSfq, TcQdiscSfqOption, TcQdiscTaprio, TcQdiscTaprioOption,
+    TcSfqQoptV1Buffer, TcSfqRedStats, TcTaprioFlags, TcTaprioSchedCmd,
+    TcTaprioSchedEntry, TcTaprioSchedEntryOption,
 };
 pub use self::stats::{
     TcStats, TcStats2, TcStatsBasic, TcStatsBasicBuffer, TcStatsBuffer,
@@ -37,6 +55,9 @@ pub use self::stats::{
 };
 
 pub(crate) use self::options::VecTcOption;
+pub(crate) use self::qdiscs::{
+    TC_FQ_PIE_XSTATS_LEN, TC_FQ_QD_STATS_LEN, TC_SFQ_QOPT_V1_LEN,
+};
 
 #[cfg(test)]
 mod tests;
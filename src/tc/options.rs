@@ -3,26 +3,56 @@
 use anyhow::Context;
 use netlink_packet_utils::{
     nla::{DefaultNla, Nla, NlaBuffer, NlasIterator},
-    traits::{Parseable, ParseableParametrized},
+    traits::{Emitable, Parseable, ParseableParametrized},
     DecodeError,
 };
 
+use crate::emit::emit_value_checked;
+
 use super::{
+    TcClassDrrOption, TcFifoQoptBuffer, TcFilterBpf, TcFilterBpfOption,
+    TcFilterFlower, TcFilterFlowerOption, TcFilterFw, TcFilterFwOption,
     TcFilterMatchAll, TcFilterMatchAllOption, TcFilterU32, TcFilterU32Option,
-    TcQdiscFqCodel, TcQdiscFqCodelOption, TcQdiscIngress, TcQdiscIngressOption,
+    TcMultiqQoptBuffer, TcPrioQoptBuffer, TcQdiscCake, TcQdiscCakeOption,
+    TcQdiscClsact, TcQdiscClsactOption, TcQdiscDrr, TcQdiscFifo,
+    TcQdiscFifoOption, TcQdiscFq, TcQdiscFqCodel, TcQdiscFqCodelOption,
+    TcQdiscFqOption, TcQdiscFqPie, TcQdiscFqPieOption, TcQdiscIngress,
+    TcQdiscIngressOption, TcQdiscMultiq, TcQdiscMultiqOption, TcQdiscPfifoFast,
+    TcQdiscPrio, TcQdiscPrioOption, TcQdiscSfq, TcQdiscSfqOption,
+    TcQdiscTaprio, TcQdiscTaprioOption, TcSfqQoptV1Buffer, TC_SFQ_QOPT_V1_LEN,
 };
 
 #[derive(Debug, PartialEq, Eq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[non_exhaustive]
 pub enum TcOption {
     FqCodel(TcQdiscFqCodelOption),
+    // cake options
+    Cake(TcQdiscCakeOption),
+    // drr class options
+    Drr(TcClassDrrOption),
+    // fq options
+    Fq(TcQdiscFqOption),
+    // fq_pie options
+    FqPie(TcQdiscFqPieOption),
     // Qdisc specific options
     Ingress(TcQdiscIngressOption),
+    // clsact options
+    Clsact(TcQdiscClsactOption),
+    // taprio options
+    Taprio(TcQdiscTaprioOption),
     // Filter specific options
     U32(TcFilterU32Option),
     // matchall options
     MatchAll(TcFilterMatchAllOption),
+    // flower options
+    Flower(TcFilterFlowerOption),
+    // bpf options
+    Bpf(TcFilterBpfOption),
+    // fw options
+    Fw(TcFilterFwOption),
     // Other options
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_nla"))]
     Other(DefaultNla),
 }
 
@@ -30,9 +60,18 @@ impl Nla for TcOption {
     fn value_len(&self) -> usize {
         match self {
             Self::FqCodel(u) => u.value_len(),
+            Self::Cake(u) => u.value_len(),
+            Self::Drr(u) => u.value_len(),
+            Self::Fq(u) => u.value_len(),
+            Self::FqPie(u) => u.value_len(),
             Self::Ingress(u) => u.value_len(),
+            Self::Clsact(u) => u.value_len(),
+            Self::Taprio(u) => u.value_len(),
             Self::U32(u) => u.value_len(),
             Self::MatchAll(m) => m.value_len(),
+            Self::Flower(f) => f.value_len(),
+            Self::Bpf(f) => f.value_len(),
+            Self::Fw(f) => f.value_len(),
             Self::Other(o) => o.value_len(),
         }
     }
@@ -40,9 +79,18 @@ impl Nla for TcOption {
     fn emit_value(&self, buffer: &mut [u8]) {
         match self {
             Self::FqCodel(u) => u.emit_value(buffer),
+            Self::Cake(u) => u.emit_value(buffer),
+            Self::Drr(u) => u.emit_value(buffer),
+            Self::Fq(u) => u.emit_value(buffer),
+            Self::FqPie(u) => u.emit_value(buffer),
             Self::Ingress(u) => u.emit_value(buffer),
+            Self::Clsact(u) => u.emit_value(buffer),
+            Self::Taprio(u) => u.emit_value(buffer),
             Self::U32(u) => u.emit_value(buffer),
             Self::MatchAll(m) => m.emit_value(buffer),
+            Self::Flower(f) => emit_value_checked(f, buffer),
+            Self::Bpf(f) => f.emit_value(buffer),
+            Self::Fw(f) => f.emit_value(buffer),
             Self::Other(o) => o.emit_value(buffer),
         }
     }
@@ -50,9 +98,18 @@ impl Nla for TcOption {
     fn kind(&self) -> u16 {
         match self {
             Self::FqCodel(u) => u.kind(),
+            Self::Cake(u) => u.kind(),
+            Self::Drr(u) => u.kind(),
+            Self::Fq(u) => u.kind(),
+            Self::FqPie(u) => u.kind(),
             Self::Ingress(u) => u.kind(),
+            Self::Clsact(u) => u.kind(),
+            Self::Taprio(u) => u.kind(),
             Self::U32(u) => u.kind(),
             Self::MatchAll(m) => m.kind(),
+            Self::Flower(f) => f.kind(),
+            Self::Bpf(f) => f.kind(),
+            Self::Fw(f) => f.kind(),
             Self::Other(o) => o.kind(),
         }
     }
@@ -72,11 +129,35 @@ where
                     "failed to parse ingress TCA_OPTIONS attributes",
                 )?)
             }
+            TcQdiscClsact::KIND => Self::Clsact(
+                TcQdiscClsactOption::parse(buf)
+                    .context("failed to parse clsact TCA_OPTIONS attributes")?,
+            ),
             TcQdiscFqCodel::KIND => {
                 Self::FqCodel(TcQdiscFqCodelOption::parse(buf).context(
                     "failed to parse fq_codel TCA_OPTIONS attributes",
                 )?)
             }
+            TcQdiscCake::KIND => Self::Cake(
+                TcQdiscCakeOption::parse(buf)
+                    .context("failed to parse cake TCA_OPTIONS attributes")?,
+            ),
+            TcQdiscDrr::KIND => Self::Drr(
+                TcClassDrrOption::parse(buf)
+                    .context("failed to parse drr TCA_OPTIONS attributes")?,
+            ),
+            TcQdiscFq::KIND => Self::Fq(
+                TcQdiscFqOption::parse(buf)
+                    .context("failed to parse fq TCA_OPTIONS attributes")?,
+            ),
+            TcQdiscFqPie::KIND => Self::FqPie(
+                TcQdiscFqPieOption::parse(buf)
+                    .context("failed to parse fq_pie TCA_OPTIONS attributes")?,
+            ),
+            TcQdiscTaprio::KIND => Self::Taprio(
+                TcQdiscTaprioOption::parse(buf)
+                    .context("failed to parse taprio TCA_OPTIONS attributes")?,
+            ),
             TcFilterU32::KIND => Self::U32(
                 TcFilterU32Option::parse(buf)
                     .context("failed to parse u32 TCA_OPTIONS attributes")?,
@@ -86,11 +167,104 @@ where
                     "failed to parse matchall TCA_OPTIONS attributes",
                 )?)
             }
+            TcFilterFlower::KIND => Self::Flower(
+                TcFilterFlowerOption::parse(buf)
+                    .context("failed to parse flower TCA_OPTIONS attributes")?,
+            ),
+            TcFilterBpf::KIND => Self::Bpf(
+                TcFilterBpfOption::parse(buf)
+                    .context("failed to parse bpf TCA_OPTIONS attributes")?,
+            ),
+            TcFilterFw::KIND => Self::Fw(
+                TcFilterFwOption::parse(buf)
+                    .context("failed to parse fw TCA_OPTIONS attributes")?,
+            ),
             _ => Self::Other(DefaultNla::parse(buf)?),
         })
     }
 }
 
+/// Options of qdiscs placing a C struct in `TCA_OPTIONS` instead of nested
+/// attributes, stored in [crate::tc::TcAttribute::StructOptions].
+#[derive(Debug, PartialEq, Eq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[non_exhaustive]
+pub enum TcStructOption {
+    /// prio and pfifo_fast options
+    Prio(TcQdiscPrioOption),
+    /// multiq options
+    Multiq(TcQdiscMultiqOption),
+    /// pfifo, bfifo and pfifo_head_drop options
+    Fifo(TcQdiscFifoOption),
+    /// sfq options
+    Sfq(TcQdiscSfqOption),
+}
+
+impl Emitable for TcStructOption {
+    fn buffer_len(&self) -> usize {
+        match self {
+            Self::Prio(v) => v.buffer_len(),
+            Self::Multiq(v) => v.buffer_len(),
+            Self::Fifo(v) => v.buffer_len(),
+            Self::Sfq(v) => v.buffer_len(),
+        }
+    }
+
+    fn emit(&self, buffer: &mut [u8]) {
+        match self {
+            Self::Prio(v) => v.emit(buffer),
+            Self::Multiq(v) => v.emit(buffer),
+            Self::Fifo(v) => v.emit(buffer),
+            Self::Sfq(v) => v.emit(buffer),
+        }
+    }
+}
+
+// Parse the `TCA_OPTIONS` of qdisc using C struct, returning `None` for other
+// kinds. The `tc` command appends nested attributes after the C struct for
+// compatibility, they are returned as the `Vec<TcOption>`.
+pub(crate) fn parse_tc_struct_option<T: AsRef<[u8]> + ?Sized>(
+    buf: &NlaBuffer<&T>,
+    kind: &str,
+) -> Result<Option<(TcStructOption, Vec<TcOption>)>, DecodeError> {
+    let payload = buf.value();
+    let err = || format!("Invalid TCA_OPTIONS for kind: {kind}");
+    let opt = match kind {
+        TcQdiscPrio::KIND | TcQdiscPfifoFast::KIND => {
+            TcStructOption::Prio(TcQdiscPrioOption::parse(
+                &TcPrioQoptBuffer::new_checked(payload).context(err())?,
+            )?)
+        }
+        TcQdiscMultiq::KIND => {
+            TcStructOption::Multiq(TcQdiscMultiqOption::parse(
+                &TcMultiqQoptBuffer::new_checked(payload).context(err())?,
+            )?)
+        }
+        TcQdiscFifo::PFIFO_KIND
+        | TcQdiscFifo::BFIFO_KIND
+        | TcQdiscFifo::PFIFO_HEAD_DROP_KIND => {
+            TcStructOption::Fifo(TcQdiscFifoOption::parse(
+                &TcFifoQoptBuffer::new_checked(payload).context(err())?,
+            )?)
+        }
+        // The legacy `struct tc_sfq_qopt` without v1 extension, or any
+        // other size than `struct tc_sfq_qopt_v1`, is stored as unknown Nla
+        // in [TcAttribute::Options].
+        TcQdiscSfq::KIND if payload.len() == TC_SFQ_QOPT_V1_LEN => {
+            TcStructOption::Sfq(TcQdiscSfqOption::parse(
+                &TcSfqQoptV1Buffer::new(payload),
+            )?)
+        }
+        _ => return Ok(None),
+    };
+    let mut nlas = vec![];
+    for nla in NlasIterator::new(&payload[opt.buffer_len()..]) {
+        let nla = nla.context(err())?;
+        nlas.push(TcOption::Other(DefaultNla::parse(&nla)?));
+    }
+    Ok(Some((opt, nlas)))
+}
+
 pub(crate) struct VecTcOption(pub(crate) Vec<TcOption>);
 
 impl<'a, T> ParseableParametrized<NlaBuffer<&'a T>, &str> for VecTcOption
@@ -104,8 +278,17 @@ where
         Ok(match kind {
             TcFilterU32::KIND
             | TcFilterMatchAll::KIND
+            | TcFilterFlower::KIND
+            | TcFilterBpf::KIND
+            | TcFilterFw::KIND
             | TcQdiscIngress::KIND
-            | TcQdiscFqCodel::KIND => {
+            | TcQdiscClsact::KIND
+            | TcQdiscFqCodel::KIND
+            | TcQdiscCake::KIND
+            | TcQdiscDrr::KIND
+            | TcQdiscFq::KIND
+            | TcQdiscFqPie::KIND
+            | TcQdiscTaprio::KIND => {
                 let mut nlas = vec![];
                 for nla in NlasIterator::new(buf.value()) {
                     let nla = nla.context(format!(
@@ -122,7 +305,7 @@ where
                 Self(nlas)
             }
             // Kernel has no guide line or code indicate the scheduler
-            // should place a nla_nest here. The `sfq` qdisc kernel code is
+            // should place a nla_nest here. Some qdisc kernel code is
             // using single NLA instead nested ones. Hence we are storing
             // unknown Nla as Vec with single item.
             _ => Self(vec![TcOption::Other(DefaultNla::parse(buf)?)]),
@@ -0,0 +1,333 @@
+// SPDX-License-Identifier: MIT
+
+use anyhow::Context;
+use byteorder::{ByteOrder, NativeEndian};
+use netlink_packet_utils::{
+    nla::{DefaultNla, Nla, NlaBuffer},
+    parsers::{parse_i32, parse_u32, parse_u64},
+    traits::Parseable,
+    DecodeError,
+};
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[non_exhaustive]
+pub struct TcQdiscCake {}
+
+impl TcQdiscCake {
+    pub(crate) const KIND: &'static str = "cake";
+}
+
+// const TCA_CAKE_PAD: u16 = 1;
+const TCA_CAKE_BASE_RATE64: u16 = 2;
+const TCA_CAKE_DIFFSERV_MODE: u16 = 3;
+const TCA_CAKE_ATM: u16 = 4;
+const TCA_CAKE_FLOW_MODE: u16 = 5;
+const TCA_CAKE_OVERHEAD: u16 = 6;
+const TCA_CAKE_RTT: u16 = 7;
+// const TCA_CAKE_TARGET: u16 = 8;
+// const TCA_CAKE_AUTORATE: u16 = 9;
+const TCA_CAKE_MEMORY: u16 = 10;
+const TCA_CAKE_NAT: u16 = 11;
+// const TCA_CAKE_RAW: u16 = 12;
+const TCA_CAKE_WASH: u16 = 13;
+const TCA_CAKE_MPU: u16 = 14;
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[non_exhaustive]
+pub enum TcQdiscCakeOption {
+    /// Shaper rate in bytes per second, 0 means unlimited.
+    BaseRate64(u64),
+    /// `CAKE_DIFFSERV_*` tin configuration.
+    DiffservMode(u32),
+    /// `CAKE_ATM_*` link layer compensation.
+    Atm(u32),
+    /// `CAKE_FLOW_*` flow isolation mode.
+    FlowMode(u32),
+    /// Per packet overhead in bytes, could be negative.
+    Overhead(i32),
+    /// Microseconds
+    Rtt(u32),
+    Nat(u32),
+    Wash(u32),
+    /// Minimum packet size in bytes after overhead compensation.
+    Mpu(u32),
+    /// Memory limit in bytes, 0 means derived from the shaper rate.
+    Memory(u32),
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_nla"))]
+    Other(DefaultNla),
+}
+
+impl Nla for TcQdiscCakeOption {
+    fn value_len(&self) -> usize {
+        match self {
+            Self::BaseRate64(_) => 8,
+            Self::DiffservMode(_)
+            | Self::Atm(_)
+            | Self::FlowMode(_)
+            | Self::Overhead(_)
+            | Self::Rtt(_)
+            | Self::Nat(_)
+            | Self::Wash(_)
+            | Self::Mpu(_)
+            | Self::Memory(_) => 4,
+            Self::Other(attr) => attr.value_len(),
+        }
+    }
+
+    fn emit_value(&self, buffer: &mut [u8]) {
+        match self {
+            Self::BaseRate64(d) => NativeEndian::write_u64(buffer, *d),
+            Self::Overhead(d) => NativeEndian::write_i32(buffer, *d),
+            Self::DiffservMode(d)
+            | Self::Atm(d)
+            | Self::FlowMode(d)
+            | Self::Rtt(d)
+            | Self::Nat(d)
+            | Self::Wash(d)
+            | Self::Mpu(d)
+            | Self::Memory(d) => NativeEndian::write_u32(buffer, *d),
+            Self::Other(attr) => attr.emit_value(buffer),
+        }
+    }
+
+    fn kind(&self) -> u16 {
+        match self {
+            Self::BaseRate64(_) => TCA_CAKE_BASE_RATE64,
+            Self::DiffservMode(_) => TCA_CAKE_DIFFSERV_MODE,
+            Self::Atm(_) => TCA_CAKE_ATM,
+            Self::FlowMode(_) => TCA_CAKE_FLOW_MODE,
+            Self::Overhead(_) => TCA_CAKE_OVERHEAD,
+            Self::Rtt(_) => TCA_CAKE_RTT,
+            Self::Nat(_) => TCA_CAKE_NAT,
+            Self::Wash(_) => TCA_CAKE_WASH,
+            Self::Mpu(_) => TCA_CAKE_MPU,
+            Self::Memory(_) => TCA_CAKE_MEMORY,
+            Self::Other(attr) => attr.kind(),
+        }
+    }
+}
+
+impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>>
+    for TcQdiscCakeOption
+{
+    fn parse(buf: &NlaBuffer<&'a T>) -> Result<Self, DecodeError> {
+        let payload = buf.value();
+        Ok(match buf.kind() {
+            TCA_CAKE_BASE_RATE64 => Self::BaseRate64(
+                parse_u64(payload)
+                    .context("failed to parse TCA_CAKE_BASE_RATE64")?,
+            ),
+            TCA_CAKE_DIFFSERV_MODE => Self::DiffservMode(
+                parse_u32(payload)
+                    .context("failed to parse TCA_CAKE_DIFFSERV_MODE")?,
+            ),
+            TCA_CAKE_ATM => Self::Atm(
+                parse_u32(payload).context("failed to parse TCA_CAKE_ATM")?,
+            ),
+            TCA_CAKE_FLOW_MODE => Self::FlowMode(
+                parse_u32(payload)
+                    .context("failed to parse TCA_CAKE_FLOW_MODE")?,
+            ),
+            TCA_CAKE_OVERHEAD => Self::Overhead(
+                parse_i32(payload)
+                    .context("failed to parse TCA_CAKE_OVERHEAD")?,
+            ),
+            TCA_CAKE_RTT => Self::Rtt(
+                parse_u32(payload).context("failed to parse TCA_CAKE_RTT")?,
+            ),
+            TCA_CAKE_NAT => Self::Nat(
+                parse_u32(payload).context("failed to parse TCA_CAKE_NAT")?,
+            ),
+            TCA_CAKE_WASH => Self::Wash(
+                parse_u32(payload).context("failed to parse TCA_CAKE_WASH")?,
+            ),
+            TCA_CAKE_MPU => Self::Mpu(
+                parse_u32(payload).context("failed to parse TCA_CAKE_MPU")?,
+            ),
+            TCA_CAKE_MEMORY => Self::Memory(
+                parse_u32(payload)
+                    .context("failed to parse TCA_CAKE_MEMORY")?,
+            ),
+            _ => Self::Other(
+                DefaultNla::parse(buf).context("failed to parse cake nla")?,
+            ),
+        })
+    }
+}
+
+// const TCA_CAKE_STATS_PAD: u16 = 1;
+const TCA_CAKE_STATS_CAPACITY_ESTIMATE64: u16 = 2;
+const TCA_CAKE_STATS_MEMORY_LIMIT: u16 = 3;
+const TCA_CAKE_STATS_MEMORY_USED: u16 = 4;
+const TCA_CAKE_STATS_AVG_NETOFF: u16 = 5;
+const TCA_CAKE_STATS_MIN_NETLEN: u16 = 6;
+const TCA_CAKE_STATS_MAX_NETLEN: u16 = 7;
+const TCA_CAKE_STATS_MIN_ADJLEN: u16 = 8;
+const TCA_CAKE_STATS_MAX_ADJLEN: u16 = 9;
+// const TCA_CAKE_STATS_TIN_STATS: u16 = 10;
+const TCA_CAKE_STATS_DEFICIT: u16 = 11;
+const TCA_CAKE_STATS_COBALT_COUNT: u16 = 12;
+const TCA_CAKE_STATS_DROPPING: u16 = 13;
+const TCA_CAKE_STATS_DROP_NEXT_US: u16 = 14;
+const TCA_CAKE_STATS_P_DROP: u16 = 15;
+const TCA_CAKE_STATS_BLUE_TIMER_US: u16 = 16;
+
+/// Nested attributes of cake xstats. The qdisc dump holds the
+/// `TCA_CAKE_STATS_CAPACITY_ESTIMATE64` to `TCA_CAKE_STATS_MAX_ADJLEN`
+/// attributes, the per flow class dump holds the rest. The per tin
+/// statistics are stored as [TcCakeStats::Other].
+#[derive(Debug, PartialEq, Eq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[non_exhaustive]
+pub enum TcCakeStats {
+    /// Bytes per second
+    CapacityEstimate64(u64),
+    MemoryLimit(u32),
+    MemoryUsed(u32),
+    AvgNetoff(u32),
+    MinNetlen(u32),
+    MaxNetlen(u32),
+    MinAdjlen(u32),
+    MaxAdjlen(u32),
+    Deficit(i32),
+    CobaltCount(u32),
+    Dropping(u32),
+    DropNextUs(i32),
+    PDrop(u32),
+    BlueTimerUs(i32),
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_nla"))]
+    Other(DefaultNla),
+}
+
+impl Nla for TcCakeStats {
+    fn value_len(&self) -> usize {
+        match self {
+            Self::CapacityEstimate64(_) => 8,
+            Self::MemoryLimit(_)
+            | Self::MemoryUsed(_)
+            | Self::AvgNetoff(_)
+            | Self::MinNetlen(_)
+            | Self::MaxNetlen(_)
+            | Self::MinAdjlen(_)
+            | Self::MaxAdjlen(_)
+            | Self::Deficit(_)
+            | Self::CobaltCount(_)
+            | Self::Dropping(_)
+            | Self::DropNextUs(_)
+            | Self::PDrop(_)
+            | Self::BlueTimerUs(_) => 4,
+            Self::Other(attr) => attr.value_len(),
+        }
+    }
+
+    fn emit_value(&self, buffer: &mut [u8]) {
+        match self {
+            Self::CapacityEstimate64(d) => NativeEndian::write_u64(buffer, *d),
+            Self::MemoryLimit(d)
+            | Self::MemoryUsed(d)
+            | Self::AvgNetoff(d)
+            | Self::MinNetlen(d)
+            | Self::MaxNetlen(d)
+            | Self::MinAdjlen(d)
+            | Self::MaxAdjlen(d)
+            | Self::CobaltCount(d)
+            | Self::Dropping(d)
+            | Self::PDrop(d) => NativeEndian::write_u32(buffer, *d),
+            Self::Deficit(d) | Self::DropNextUs(d) | Self::BlueTimerUs(d) => {
+                NativeEndian::write_i32(buffer, *d)
+            }
+            Self::Other(attr) => attr.emit_value(buffer),
+        }
+    }
+
+    fn kind(&self) -> u16 {
+        match self {
+            Self::CapacityEstimate64(_) => TCA_CAKE_STATS_CAPACITY_ESTIMATE64,
+            Self::MemoryLimit(_) => TCA_CAKE_STATS_MEMORY_LIMIT,
+            Self::MemoryUsed(_) => TCA_CAKE_STATS_MEMORY_USED,
+            Self::AvgNetoff(_) => TCA_CAKE_STATS_AVG_NETOFF,
+            Self::MinNetlen(_) => TCA_CAKE_STATS_MIN_NETLEN,
+            Self::MaxNetlen(_) => TCA_CAKE_STATS_MAX_NETLEN,
+            Self::MinAdjlen(_) => TCA_CAKE_STATS_MIN_ADJLEN,
+            Self::MaxAdjlen(_) => TCA_CAKE_STATS_MAX_ADJLEN,
+            Self::Deficit(_) => TCA_CAKE_STATS_DEFICIT,
+            Self::CobaltCount(_) => TCA_CAKE_STATS_COBALT_COUNT,
+            Self::Dropping(_) => TCA_CAKE_STATS_DROPPING,
+            Self::DropNextUs(_) => TCA_CAKE_STATS_DROP_NEXT_US,
+            Self::PDrop(_) => TCA_CAKE_STATS_P_DROP,
+            Self::BlueTimerUs(_) => TCA_CAKE_STATS_BLUE_TIMER_US,
+            Self::Other(attr) => attr.kind(),
+        }
+    }
+}
+
+impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>> for TcCakeStats {
+    fn parse(buf: &NlaBuffer<&'a T>) -> Result<Self, DecodeError> {
+        let payload = buf.value();
+        Ok(match buf.kind() {
+            TCA_CAKE_STATS_CAPACITY_ESTIMATE64 => {
+                Self::CapacityEstimate64(parse_u64(payload).context(
+                    "failed to parse TCA_CAKE_STATS_CAPACITY_ESTIMATE64",
+                )?)
+            }
+            TCA_CAKE_STATS_MEMORY_LIMIT => Self::MemoryLimit(
+                parse_u32(payload)
+                    .context("failed to parse TCA_CAKE_STATS_MEMORY_LIMIT")?,
+            ),
+            TCA_CAKE_STATS_MEMORY_USED => Self::MemoryUsed(
+                parse_u32(payload)
+                    .context("failed to parse TCA_CAKE_STATS_MEMORY_USED")?,
+            ),
+            TCA_CAKE_STATS_AVG_NETOFF => Self::AvgNetoff(
+                parse_u32(payload)
+                    .context("failed to parse TCA_CAKE_STATS_AVG_NETOFF")?,
+            ),
+            TCA_CAKE_STATS_MIN_NETLEN => Self::MinNetlen(
+                parse_u32(payload)
+                    .context("failed to parse TCA_CAKE_STATS_MIN_NETLEN")?,
+            ),
+            TCA_CAKE_STATS_MAX_NETLEN => Self::MaxNetlen(
+                parse_u32(payload)
+                    .context("failed to parse TCA_CAKE_STATS_MAX_NETLEN")?,
+            ),
+            TCA_CAKE_STATS_MIN_ADJLEN => Self::MinAdjlen(
+                parse_u32(payload)
+                    .context("failed to parse TCA_CAKE_STATS_MIN_ADJLEN")?,
+            ),
+            TCA_CAKE_STATS_MAX_ADJLEN => Self::MaxAdjlen(
+                parse_u32(payload)
+                    .context("failed to parse TCA_CAKE_STATS_MAX_ADJLEN")?,
+            ),
+            TCA_CAKE_STATS_DEFICIT => Self::Deficit(
+                parse_i32(payload)
+                    .context("failed to parse TCA_CAKE_STATS_DEFICIT")?,
+            ),
+            TCA_CAKE_STATS_COBALT_COUNT => Self::CobaltCount(
+                parse_u32(payload)
+                    .context("failed to parse TCA_CAKE_STATS_COBALT_COUNT")?,
+            ),
+            TCA_CAKE_STATS_DROPPING => Self::Dropping(
+                parse_u32(payload)
+                    .context("failed to parse TCA_CAKE_STATS_DROPPING")?,
+            ),
+            TCA_CAKE_STATS_DROP_NEXT_US => Self::DropNextUs(
+                parse_i32(payload)
+                    .context("failed to parse TCA_CAKE_STATS_DROP_NEXT_US")?,
+            ),
+            TCA_CAKE_STATS_P_DROP => Self::PDrop(
+                parse_u32(payload)
+                    .context("failed to parse TCA_CAKE_STATS_P_DROP")?,
+            ),
+            TCA_CAKE_STATS_BLUE_TIMER_US => Self::BlueTimerUs(
+                parse_i32(payload)
+                    .context("failed to parse TCA_CAKE_STATS_BLUE_TIMER_US")?,
+            ),
+            _ => Self::Other(
+                DefaultNla::parse(buf)
+                    .context("failed to parse cake stats nla")?,
+            ),
+        })
+    }
+}
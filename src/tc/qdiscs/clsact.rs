@@ -0,0 +1,61 @@
+// SPDX-License-Identifier: MIT
+
+// Like ingress, the qdisc clsact does not have any attribute, kernel
+// just start a empty nla_nest. This is just a place holder
+
+use anyhow::Context;
+use netlink_packet_utils::{
+    nla::{DefaultNla, Nla, NlaBuffer},
+    DecodeError, Parseable,
+};
+
+/// The clsact qdisc, created with handle `ffff:0` under parent
+/// [crate::tc::TcHandle::CLSACT]. Filters are attached to
+/// [crate::tc::TcHandle::CLSACT_INGRESS] for ingress traffic and
+/// [crate::tc::TcHandle::CLSACT_EGRESS] for egress traffic.
+#[derive(Debug, PartialEq, Eq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[non_exhaustive]
+pub struct TcQdiscClsact {}
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[non_exhaustive]
+pub enum TcQdiscClsactOption {
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_nla"))]
+    Other(DefaultNla),
+}
+
+impl TcQdiscClsact {
+    pub const KIND: &'static str = "clsact";
+}
+
+impl Nla for TcQdiscClsactOption {
+    fn value_len(&self) -> usize {
+        match self {
+            Self::Other(attr) => attr.value_len(),
+        }
+    }
+
+    fn emit_value(&self, buffer: &mut [u8]) {
+        match self {
+            Self::Other(attr) => attr.emit_value(buffer),
+        }
+    }
+
+    fn kind(&self) -> u16 {
+        match self {
+            Self::Other(attr) => attr.kind(),
+        }
+    }
+}
+
+impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>>
+    for TcQdiscClsactOption
+{
+    fn parse(buf: &NlaBuffer<&'a T>) -> Result<Self, DecodeError> {
+        Ok(Self::Other(
+            DefaultNla::parse(buf).context("failed to parse clsact nla")?,
+        ))
+    }
+}
@@ -0,0 +1,73 @@
+// SPDX-License-Identifier: MIT
+
+use anyhow::Context;
+use byteorder::{ByteOrder, NativeEndian};
+use netlink_packet_utils::{
+    nla::{DefaultNla, Nla, NlaBuffer},
+    parsers::parse_u32,
+    traits::Parseable,
+    DecodeError,
+};
+
+/// The `drr` qdisc itself does not have any option, the quantum is
+/// configured per class via [TcClassDrrOption].
+#[derive(Debug, PartialEq, Eq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[non_exhaustive]
+pub struct TcQdiscDrr {}
+
+impl TcQdiscDrr {
+    pub(crate) const KIND: &'static str = "drr";
+}
+
+const TCA_DRR_QUANTUM: u16 = 1;
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[non_exhaustive]
+pub enum TcClassDrrOption {
+    /// Bytes could be dequeued from the class per round
+    Quantum(u32),
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_nla"))]
+    Other(DefaultNla),
+}
+
+impl Nla for TcClassDrrOption {
+    fn value_len(&self) -> usize {
+        match self {
+            Self::Quantum(_) => 4,
+            Self::Other(attr) => attr.value_len(),
+        }
+    }
+
+    fn emit_value(&self, buffer: &mut [u8]) {
+        match self {
+            Self::Quantum(d) => NativeEndian::write_u32(buffer, *d),
+            Self::Other(attr) => attr.emit_value(buffer),
+        }
+    }
+
+    fn kind(&self) -> u16 {
+        match self {
+            Self::Quantum(_) => TCA_DRR_QUANTUM,
+            Self::Other(attr) => attr.kind(),
+        }
+    }
+}
+
+impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>>
+    for TcClassDrrOption
+{
+    fn parse(buf: &NlaBuffer<&'a T>) -> Result<Self, DecodeError> {
+        let payload = buf.value();
+        Ok(match buf.kind() {
+            TCA_DRR_QUANTUM => Self::Quantum(
+                parse_u32(payload)
+                    .context("failed to parse TCA_DRR_QUANTUM")?,
+            ),
+            _ => Self::Other(
+                DefaultNla::parse(buf).context("failed to parse drr nla")?,
+            ),
+        })
+    }
+}
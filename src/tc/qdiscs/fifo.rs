@@ -0,0 +1,52 @@
+// SPDX-License-Identifier: MIT
+
+use netlink_packet_utils::{
+    traits::{Emitable, Parseable},
+    DecodeError,
+};
+
+/// The `pfifo`, `bfifo` and `pfifo_head_drop` qdiscs sharing the same
+/// [TcQdiscFifoOption].
+#[derive(Debug, PartialEq, Eq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[non_exhaustive]
+pub struct TcQdiscFifo {}
+
+impl TcQdiscFifo {
+    pub(crate) const PFIFO_KIND: &'static str = "pfifo";
+    pub(crate) const BFIFO_KIND: &'static str = "bfifo";
+    pub(crate) const PFIFO_HEAD_DROP_KIND: &'static str = "pfifo_head_drop";
+}
+
+const TC_FIFO_QOPT_LEN: usize = 4;
+
+/// The `struct tc_fifo_qopt` placed as payload of `TCA_OPTIONS` instead of
+/// nested attributes.
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[non_exhaustive]
+pub struct TcQdiscFifoOption {
+    /// Queue length in bytes for `bfifo`, in packets for others
+    pub limit: u32,
+}
+
+buffer!(TcFifoQoptBuffer(TC_FIFO_QOPT_LEN) {
+    limit: (u32, 0..4),
+});
+
+impl<T: AsRef<[u8]>> Parseable<TcFifoQoptBuffer<T>> for TcQdiscFifoOption {
+    fn parse(buf: &TcFifoQoptBuffer<T>) -> Result<Self, DecodeError> {
+        Ok(Self { limit: buf.limit() })
+    }
+}
+
+impl Emitable for TcQdiscFifoOption {
+    fn buffer_len(&self) -> usize {
+        TC_FIFO_QOPT_LEN
+    }
+
+    fn emit(&self, buffer: &mut [u8]) {
+        let mut buffer = TcFifoQoptBuffer::new(buffer);
+        buffer.set_limit(self.limit);
+    }
+}
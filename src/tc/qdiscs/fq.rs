@@ -0,0 +1,278 @@
+// SPDX-License-Identifier: MIT
+
+use anyhow::Context;
+use byteorder::{ByteOrder, NativeEndian};
+use netlink_packet_utils::{
+    nla::{DefaultNla, Nla, NlaBuffer},
+    parsers::parse_u32,
+    traits::{Emitable, Parseable},
+    DecodeError,
+};
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[non_exhaustive]
+pub struct TcQdiscFq {}
+
+impl TcQdiscFq {
+    pub(crate) const KIND: &'static str = "fq";
+}
+
+const TCA_FQ_PLIMIT: u16 = 1;
+const TCA_FQ_FLOW_PLIMIT: u16 = 2;
+const TCA_FQ_QUANTUM: u16 = 3;
+const TCA_FQ_INITIAL_QUANTUM: u16 = 4;
+const TCA_FQ_RATE_ENABLE: u16 = 5;
+const TCA_FQ_FLOW_DEFAULT_RATE: u16 = 6;
+const TCA_FQ_FLOW_MAX_RATE: u16 = 7;
+const TCA_FQ_BUCKETS_LOG: u16 = 8;
+// const TCA_FQ_FLOW_REFILL_DELAY: u16 = 9;
+// const TCA_FQ_ORPHAN_MASK: u16 = 10;
+// const TCA_FQ_LOW_RATE_THRESHOLD: u16 = 11;
+// const TCA_FQ_CE_THRESHOLD: u16 = 12;
+// const TCA_FQ_TIMER_SLACK: u16 = 13;
+const TCA_FQ_HORIZON: u16 = 14;
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[non_exhaustive]
+pub enum TcQdiscFqOption {
+    /// Limit of total number of packets in queue
+    Plimit(u32),
+    /// Limit of packets per flow
+    FlowPlimit(u32),
+    Quantum(u32),
+    /// Quantum for new flow
+    InitialQuantum(u32),
+    RateEnable(u32),
+    /// Obsolete, ignored by kernel
+    FlowDefaultRate(u32),
+    /// Per flow max rate in bytes per second, `u32::MAX` means unlimited.
+    FlowMaxRate(u32),
+    /// log2 of number of buckets
+    BucketsLog(u32),
+    /// Microseconds
+    Horizon(u32),
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_nla"))]
+    Other(DefaultNla),
+}
+
+impl Nla for TcQdiscFqOption {
+    fn value_len(&self) -> usize {
+        match self {
+            Self::Plimit(_)
+            | Self::FlowPlimit(_)
+            | Self::Quantum(_)
+            | Self::InitialQuantum(_)
+            | Self::RateEnable(_)
+            | Self::FlowDefaultRate(_)
+            | Self::FlowMaxRate(_)
+            | Self::BucketsLog(_)
+            | Self::Horizon(_) => 4,
+            Self::Other(attr) => attr.value_len(),
+        }
+    }
+
+    fn emit_value(&self, buffer: &mut [u8]) {
+        match self {
+            Self::Plimit(d)
+            | Self::FlowPlimit(d)
+            | Self::Quantum(d)
+            | Self::InitialQuantum(d)
+            | Self::RateEnable(d)
+            | Self::FlowDefaultRate(d)
+            | Self::FlowMaxRate(d)
+            | Self::BucketsLog(d)
+            | Self::Horizon(d) => NativeEndian::write_u32(buffer, *d),
+            Self::Other(attr) => attr.emit_value(buffer),
+        }
+    }
+
+    fn kind(&self) -> u16 {
+        match self {
+            Self::Plimit(_) => TCA_FQ_PLIMIT,
+            Self::FlowPlimit(_) => TCA_FQ_FLOW_PLIMIT,
+            Self::Quantum(_) => TCA_FQ_QUANTUM,
+            Self::InitialQuantum(_) => TCA_FQ_INITIAL_QUANTUM,
+            Self::RateEnable(_) => TCA_FQ_RATE_ENABLE,
+            Self::FlowDefaultRate(_) => TCA_FQ_FLOW_DEFAULT_RATE,
+            Self::FlowMaxRate(_) => TCA_FQ_FLOW_MAX_RATE,
+            Self::BucketsLog(_) => TCA_FQ_BUCKETS_LOG,
+            Self::Horizon(_) => TCA_FQ_HORIZON,
+            Self::Other(attr) => attr.kind(),
+        }
+    }
+}
+
+impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>>
+    for TcQdiscFqOption
+{
+    fn parse(buf: &NlaBuffer<&'a T>) -> Result<Self, DecodeError> {
+        let payload = buf.value();
+        Ok(match buf.kind() {
+            TCA_FQ_PLIMIT => Self::Plimit(
+                parse_u32(payload).context("failed to parse TCA_FQ_PLIMIT")?,
+            ),
+            TCA_FQ_FLOW_PLIMIT => Self::FlowPlimit(
+                parse_u32(payload)
+                    .context("failed to parse TCA_FQ_FLOW_PLIMIT")?,
+            ),
+            TCA_FQ_QUANTUM => Self::Quantum(
+                parse_u32(payload).context("failed to parse TCA_FQ_QUANTUM")?,
+            ),
+            TCA_FQ_INITIAL_QUANTUM => Self::InitialQuantum(
+                parse_u32(payload)
+                    .context("failed to parse TCA_FQ_INITIAL_QUANTUM")?,
+            ),
+            TCA_FQ_RATE_ENABLE => Self::RateEnable(
+                parse_u32(payload)
+                    .context("failed to parse TCA_FQ_RATE_ENABLE")?,
+            ),
+            TCA_FQ_FLOW_DEFAULT_RATE => Self::FlowDefaultRate(
+                parse_u32(payload)
+                    .context("failed to parse TCA_FQ_FLOW_DEFAULT_RATE")?,
+            ),
+            TCA_FQ_FLOW_MAX_RATE => Self::FlowMaxRate(
+                parse_u32(payload)
+                    .context("failed to parse TCA_FQ_FLOW_MAX_RATE")?,
+            ),
+            TCA_FQ_BUCKETS_LOG => Self::BucketsLog(
+                parse_u32(payload)
+                    .context("failed to parse TCA_FQ_BUCKETS_LOG")?,
+            ),
+            TCA_FQ_HORIZON => Self::Horizon(
+                parse_u32(payload).context("failed to parse TCA_FQ_HORIZON")?,
+            ),
+            _ => Self::Other(
+                DefaultNla::parse(buf).context("failed to parse fq nla")?,
+            ),
+        })
+    }
+}
+
+const FQ_BANDS: usize = 3;
+
+pub(crate) const TC_FQ_QD_STATS_LEN: usize = 152;
+
+/// Statistics of `fq` qdisc, the band statistics are only provided by
+/// Linux kernel 6.7 and later. The xstats of older kernel are stored as
+/// [crate::tc::TcXstats::Other].
+#[derive(Default, Debug, PartialEq, Eq, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[non_exhaustive]
+pub struct TcFqQdStats {
+    pub gc_flows: u64,
+    /// Obsolete
+    pub highprio_packets: u64,
+    /// Obsolete
+    pub tcp_retrans: u64,
+    pub throttled: u64,
+    pub flows_plimit: u64,
+    pub pkts_too_long: u64,
+    pub allocation_errors: u64,
+    pub time_next_delayed_flow: i64,
+    pub flows: u32,
+    pub inactive_flows: u32,
+    pub throttled_flows: u32,
+    pub unthrottle_latency_ns: u32,
+    /// Packets above CE threshold
+    pub ce_mark: u64,
+    pub horizon_drops: u64,
+    pub horizon_caps: u64,
+    pub fastpath_packets: u64,
+    pub band_drops: [u64; FQ_BANDS],
+    pub band_pkt_count: [u32; FQ_BANDS],
+}
+
+buffer!(TcFqQdStatsBuffer(TC_FQ_QD_STATS_LEN) {
+    gc_flows: (u64, 0..8),
+    highprio_packets: (u64, 8..16),
+    tcp_retrans: (u64, 16..24),
+    throttled: (u64, 24..32),
+    flows_plimit: (u64, 32..40),
+    pkts_too_long: (u64, 40..48),
+    allocation_errors: (u64, 48..56),
+    time_next_delayed_flow: (i64, 56..64),
+    flows: (u32, 64..68),
+    inactive_flows: (u32, 68..72),
+    throttled_flows: (u32, 72..76),
+    unthrottle_latency_ns: (u32, 76..80),
+    ce_mark: (u64, 80..88),
+    horizon_drops: (u64, 88..96),
+    horizon_caps: (u64, 96..104),
+    fastpath_packets: (u64, 104..112),
+    band_drops: (slice, 112..136),
+    band_pkt_count: (slice, 136..148),
+    pad: (u32, 148..152),
+});
+
+impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<TcFqQdStatsBuffer<&'a T>>
+    for TcFqQdStats
+{
+    fn parse(buf: &TcFqQdStatsBuffer<&'a T>) -> Result<Self, DecodeError> {
+        let mut band_drops = [0u64; FQ_BANDS];
+        for (i, v) in band_drops.iter_mut().enumerate() {
+            *v = NativeEndian::read_u64(&buf.band_drops()[i * 8..]);
+        }
+        let mut band_pkt_count = [0u32; FQ_BANDS];
+        for (i, v) in band_pkt_count.iter_mut().enumerate() {
+            *v = NativeEndian::read_u32(&buf.band_pkt_count()[i * 4..]);
+        }
+        Ok(Self {
+            gc_flows: buf.gc_flows(),
+            highprio_packets: buf.highprio_packets(),
+            tcp_retrans: buf.tcp_retrans(),
+            throttled: buf.throttled(),
+            flows_plimit: buf.flows_plimit(),
+            pkts_too_long: buf.pkts_too_long(),
+            allocation_errors: buf.allocation_errors(),
+            time_next_delayed_flow: buf.time_next_delayed_flow(),
+            flows: buf.flows(),
+            inactive_flows: buf.inactive_flows(),
+            throttled_flows: buf.throttled_flows(),
+            unthrottle_latency_ns: buf.unthrottle_latency_ns(),
+            ce_mark: buf.ce_mark(),
+            horizon_drops: buf.horizon_drops(),
+            horizon_caps: buf.horizon_caps(),
+            fastpath_packets: buf.fastpath_packets(),
+            band_drops,
+            band_pkt_count,
+        })
+    }
+}
+
+impl Emitable for TcFqQdStats {
+    fn buffer_len(&self) -> usize {
+        TC_FQ_QD_STATS_LEN
+    }
+
+    fn emit(&self, buffer: &mut [u8]) {
+        let mut buffer = TcFqQdStatsBuffer::new(buffer);
+        buffer.set_gc_flows(self.gc_flows);
+        buffer.set_highprio_packets(self.highprio_packets);
+        buffer.set_tcp_retrans(self.tcp_retrans);
+        buffer.set_throttled(self.throttled);
+        buffer.set_flows_plimit(self.flows_plimit);
+        buffer.set_pkts_too_long(self.pkts_too_long);
+        buffer.set_allocation_errors(self.allocation_errors);
+        buffer.set_time_next_delayed_flow(self.time_next_delayed_flow);
+        buffer.set_flows(self.flows);
+        buffer.set_inactive_flows(self.inactive_flows);
+        buffer.set_throttled_flows(self.throttled_flows);
+        buffer.set_unthrottle_latency_ns(self.unthrottle_latency_ns);
+        buffer.set_ce_mark(self.ce_mark);
+        buffer.set_horizon_drops(self.horizon_drops);
+        buffer.set_horizon_caps(self.horizon_caps);
+        buffer.set_fastpath_packets(self.fastpath_packets);
+        for (i, v) in self.band_drops.iter().enumerate() {
+            NativeEndian::write_u64(&mut buffer.band_drops_mut()[i * 8..], *v);
+        }
+        for (i, v) in self.band_pkt_count.iter().enumerate() {
+            NativeEndian::write_u32(
+                &mut buffer.band_pkt_count_mut()[i * 4..],
+                *v,
+            );
+        }
+        buffer.set_pad(0);
+    }
+}
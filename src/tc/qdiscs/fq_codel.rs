@@ -10,6 +10,7 @@ use netlink_packet_utils::{
 };
 
 #[derive(Debug, PartialEq, Eq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[non_exhaustive]
 pub struct TcQdiscFqCodel {}
 
@@ -24,10 +25,12 @@ const TCA_FQ_CODEL_XSTATS_QDISC: u32 = 0;
 const TCA_FQ_CODEL_XSTATS_CLASS: u32 = 1;
 
 #[derive(Debug, PartialEq, Eq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[non_exhaustive]
 pub enum TcFqCodelXstats {
     Qdisc(TcFqCodelQdStats),
     Class(TcFqCodelClStats),
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_hex"))]
     Other(Vec<u8>),
 }
 
@@ -91,6 +94,7 @@ impl Emitable for TcFqCodelXstats {
 }
 
 #[derive(Default, Debug, PartialEq, Eq, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[non_exhaustive]
 pub struct TcFqCodelQdStats {
     pub maxpacket: u32,
@@ -152,6 +156,7 @@ impl Emitable for TcFqCodelQdStats {
 }
 
 #[derive(Default, Debug, PartialEq, Eq, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[non_exhaustive]
 pub struct TcFqCodelClStats {
     deficit: i32,
@@ -213,6 +218,7 @@ const TCA_FQ_CODEL_CE_THRESHOLD_SELECTOR: u16 = 10;
 const TCA_FQ_CODEL_CE_THRESHOLD_MASK: u16 = 11;
 
 #[derive(Debug, PartialEq, Eq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[non_exhaustive]
 pub enum TcQdiscFqCodelOption {
     Target(u32),
@@ -226,6 +232,7 @@ pub enum TcQdiscFqCodelOption {
     MemoryLimit(u32),
     CeThresholdSelector(u8),
     CeThresholdMask(u8),
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_nla"))]
     Other(DefaultNla),
 }
 
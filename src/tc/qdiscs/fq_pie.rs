@@ -0,0 +1,240 @@
+// SPDX-License-Identifier: MIT
+
+use anyhow::Context;
+use byteorder::{ByteOrder, NativeEndian};
+use netlink_packet_utils::{
+    nla::{DefaultNla, Nla, NlaBuffer},
+    parsers::parse_u32,
+    traits::{Emitable, Parseable},
+    DecodeError,
+};
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[non_exhaustive]
+pub struct TcQdiscFqPie {}
+
+impl TcQdiscFqPie {
+    pub(crate) const KIND: &'static str = "fq_pie";
+}
+
+const TCA_FQ_PIE_LIMIT: u16 = 1;
+const TCA_FQ_PIE_FLOWS: u16 = 2;
+const TCA_FQ_PIE_TARGET: u16 = 3;
+const TCA_FQ_PIE_TUPDATE: u16 = 4;
+const TCA_FQ_PIE_ALPHA: u16 = 5;
+const TCA_FQ_PIE_BETA: u16 = 6;
+const TCA_FQ_PIE_QUANTUM: u16 = 7;
+const TCA_FQ_PIE_MEMORY_LIMIT: u16 = 8;
+const TCA_FQ_PIE_ECN_PROB: u16 = 9;
+const TCA_FQ_PIE_ECN: u16 = 10;
+const TCA_FQ_PIE_BYTEMODE: u16 = 11;
+const TCA_FQ_PIE_DQ_RATE_ESTIMATOR: u16 = 12;
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[non_exhaustive]
+pub enum TcQdiscFqPieOption {
+    Limit(u32),
+    Flows(u32),
+    /// Microseconds
+    Target(u32),
+    /// Microseconds
+    Tupdate(u32),
+    Alpha(u32),
+    Beta(u32),
+    Quantum(u32),
+    MemoryLimit(u32),
+    /// Percentage
+    EcnProb(u32),
+    Ecn(u32),
+    Bytemode(u32),
+    DqRateEstimator(u32),
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_nla"))]
+    Other(DefaultNla),
+}
+
+impl Nla for TcQdiscFqPieOption {
+    fn value_len(&self) -> usize {
+        match self {
+            Self::Limit(_)
+            | Self::Flows(_)
+            | Self::Target(_)
+            | Self::Tupdate(_)
+            | Self::Alpha(_)
+            | Self::Beta(_)
+            | Self::Quantum(_)
+            | Self::MemoryLimit(_)
+            | Self::EcnProb(_)
+            | Self::Ecn(_)
+            | Self::Bytemode(_)
+            | Self::DqRateEstimator(_) => 4,
+            Self::Other(attr) => attr.value_len(),
+        }
+    }
+
+    fn emit_value(&self, buffer: &mut [u8]) {
+        match self {
+            Self::Limit(d)
+            | Self::Flows(d)
+            | Self::Target(d)
+            | Self::Tupdate(d)
+            | Self::Alpha(d)
+            | Self::Beta(d)
+            | Self::Quantum(d)
+            | Self::MemoryLimit(d)
+            | Self::EcnProb(d)
+            | Self::Ecn(d)
+            | Self::Bytemode(d)
+            | Self::DqRateEstimator(d) => NativeEndian::write_u32(buffer, *d),
+            Self::Other(attr) => attr.emit_value(buffer),
+        }
+    }
+
+    fn kind(&self) -> u16 {
+        match self {
+            Self::Limit(_) => TCA_FQ_PIE_LIMIT,
+            Self::Flows(_) => TCA_FQ_PIE_FLOWS,
+            Self::Target(_) => TCA_FQ_PIE_TARGET,
+            Self::Tupdate(_) => TCA_FQ_PIE_TUPDATE,
+            Self::Alpha(_) => TCA_FQ_PIE_ALPHA,
+            Self::Beta(_) => TCA_FQ_PIE_BETA,
+            Self::Quantum(_) => TCA_FQ_PIE_QUANTUM,
+            Self::MemoryLimit(_) => TCA_FQ_PIE_MEMORY_LIMIT,
+            Self::EcnProb(_) => TCA_FQ_PIE_ECN_PROB,
+            Self::Ecn(_) => TCA_FQ_PIE_ECN,
+            Self::Bytemode(_) => TCA_FQ_PIE_BYTEMODE,
+            Self::DqRateEstimator(_) => TCA_FQ_PIE_DQ_RATE_ESTIMATOR,
+            Self::Other(attr) => attr.kind(),
+        }
+    }
+}
+
+impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>>
+    for TcQdiscFqPieOption
+{
+    fn parse(buf: &NlaBuffer<&'a T>) -> Result<Self, DecodeError> {
+        let payload = buf.value();
+        Ok(match buf.kind() {
+            TCA_FQ_PIE_LIMIT => Self::Limit(
+                parse_u32(payload)
+                    .context("failed to parse TCA_FQ_PIE_LIMIT")?,
+            ),
+            TCA_FQ_PIE_FLOWS => Self::Flows(
+                parse_u32(payload)
+                    .context("failed to parse TCA_FQ_PIE_FLOWS")?,
+            ),
+            TCA_FQ_PIE_TARGET => Self::Target(
+                parse_u32(payload)
+                    .context("failed to parse TCA_FQ_PIE_TARGET")?,
+            ),
+            TCA_FQ_PIE_TUPDATE => Self::Tupdate(
+                parse_u32(payload)
+                    .context("failed to parse TCA_FQ_PIE_TUPDATE")?,
+            ),
+            TCA_FQ_PIE_ALPHA => Self::Alpha(
+                parse_u32(payload)
+                    .context("failed to parse TCA_FQ_PIE_ALPHA")?,
+            ),
+            TCA_FQ_PIE_BETA => Self::Beta(
+                parse_u32(payload)
+                    .context("failed to parse TCA_FQ_PIE_BETA")?,
+            ),
+            TCA_FQ_PIE_QUANTUM => Self::Quantum(
+                parse_u32(payload)
+                    .context("failed to parse TCA_FQ_PIE_QUANTUM")?,
+            ),
+            TCA_FQ_PIE_MEMORY_LIMIT => Self::MemoryLimit(
+                parse_u32(payload)
+                    .context("failed to parse TCA_FQ_PIE_MEMORY_LIMIT")?,
+            ),
+            TCA_FQ_PIE_ECN_PROB => Self::EcnProb(
+                parse_u32(payload)
+                    .context("failed to parse TCA_FQ_PIE_ECN_PROB")?,
+            ),
+            TCA_FQ_PIE_ECN => Self::Ecn(
+                parse_u32(payload).context("failed to parse TCA_FQ_PIE_ECN")?,
+            ),
+            TCA_FQ_PIE_BYTEMODE => Self::Bytemode(
+                parse_u32(payload)
+                    .context("failed to parse TCA_FQ_PIE_BYTEMODE")?,
+            ),
+            TCA_FQ_PIE_DQ_RATE_ESTIMATOR => Self::DqRateEstimator(
+                parse_u32(payload)
+                    .context("failed to parse TCA_FQ_PIE_DQ_RATE_ESTIMATOR")?,
+            ),
+            _ => Self::Other(
+                DefaultNla::parse(buf).context("failed to parse fq_pie nla")?,
+            ),
+        })
+    }
+}
+
+pub(crate) const TC_FQ_PIE_XSTATS_LEN: usize = 36;
+
+#[derive(Default, Debug, PartialEq, Eq, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[non_exhaustive]
+pub struct TcFqPieXstats {
+    /// Total number of packets enqueued
+    pub packets_in: u32,
+    /// Packets dropped due to fq_pie action
+    pub dropped: u32,
+    /// Dropped due to lack of space in queue
+    pub overlimit: u32,
+    /// Dropped due to lack of memory in queue
+    pub overmemory: u32,
+    /// Packets marked with ECN
+    pub ecn_mark: u32,
+    pub new_flow_count: u32,
+    pub new_flows_len: u32,
+    pub old_flows_len: u32,
+    pub memory_usage: u32,
+}
+
+buffer!(TcFqPieXstatsBuffer(TC_FQ_PIE_XSTATS_LEN) {
+    packets_in: (u32, 0..4),
+    dropped: (u32, 4..8),
+    overlimit: (u32, 8..12),
+    overmemory: (u32, 12..16),
+    ecn_mark: (u32, 16..20),
+    new_flow_count: (u32, 20..24),
+    new_flows_len: (u32, 24..28),
+    old_flows_len: (u32, 28..32),
+    memory_usage: (u32, 32..36),
+});
+
+impl<T: AsRef<[u8]>> Parseable<TcFqPieXstatsBuffer<T>> for TcFqPieXstats {
+    fn parse(buf: &TcFqPieXstatsBuffer<T>) -> Result<Self, DecodeError> {
+        Ok(Self {
+            packets_in: buf.packets_in(),
+            dropped: buf.dropped(),
+            overlimit: buf.overlimit(),
+            overmemory: buf.overmemory(),
+            ecn_mark: buf.ecn_mark(),
+            new_flow_count: buf.new_flow_count(),
+            new_flows_len: buf.new_flows_len(),
+            old_flows_len: buf.old_flows_len(),
+            memory_usage: buf.memory_usage(),
+        })
+    }
+}
+
+impl Emitable for TcFqPieXstats {
+    fn buffer_len(&self) -> usize {
+        TC_FQ_PIE_XSTATS_LEN
+    }
+
+    fn emit(&self, buffer: &mut [u8]) {
+        let mut buffer = TcFqPieXstatsBuffer::new(buffer);
+        buffer.set_packets_in(self.packets_in);
+        buffer.set_dropped(self.dropped);
+        buffer.set_overlimit(self.overlimit);
+        buffer.set_overmemory(self.overmemory);
+        buffer.set_ecn_mark(self.ecn_mark);
+        buffer.set_new_flow_count(self.new_flow_count);
+        buffer.set_new_flows_len(self.new_flows_len);
+        buffer.set_old_flows_len(self.old_flows_len);
+        buffer.set_memory_usage(self.memory_usage);
+    }
+}
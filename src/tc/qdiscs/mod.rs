@@ -1,11 +1,49 @@
 // SPDX-License-Identifier: MIT
 
+mod cake;
+mod clsact;
+mod drr;
+mod fifo;
+mod fq;
 mod fq_codel;
+mod fq_pie;
 mod ingress;
+mod multiq;
+mod prio;
+mod sfq;
+mod taprio;
 
+pub(crate) use self::fq::TC_FQ_QD_STATS_LEN;
+pub(crate) use self::fq_pie::TC_FQ_PIE_XSTATS_LEN;
+pub(crate) use self::sfq::TC_SFQ_QOPT_V1_LEN;
+
+pub use self::cake::{TcCakeStats, TcQdiscCake, TcQdiscCakeOption};
+pub use self::clsact::{TcQdiscClsact, TcQdiscClsactOption};
+pub use self::drr::{TcClassDrrOption, TcQdiscDrr};
+pub use self::fifo::{TcFifoQoptBuffer, TcQdiscFifo, TcQdiscFifoOption};
+pub use self::fq::{
+    TcFqQdStats, TcFqQdStatsBuffer, TcQdiscFq, TcQdiscFqOption,
+};
 pub use self::fq_codel::{
     TcFqCodelClStats, TcFqCodelClStatsBuffer, TcFqCodelQdStats,
     TcFqCodelQdStatsBuffer, TcFqCodelXstats, TcQdiscFqCodel,
     TcQdiscFqCodelOption,
 };
+pub use self::fq_pie::{
+    TcFqPieXstats, TcFqPieXstatsBuffer, TcQdiscFqPie, TcQdiscFqPieOption,
+};
 pub use self::ingress::{TcQdiscIngress, TcQdiscIngressOption};
+pub use self::multiq::{
+    TcMultiqQoptBuffer, TcQdiscMultiq, TcQdiscMultiqOption,
+};
+pub use self::prio::{
+    TcPrioQoptBuffer, TcQdiscPfifoFast, TcQdiscPrio, TcQdiscPrioOption,
+};
+pub use self::sfq::{
+    TcQdiscSfq, TcQdiscSfqOption, TcSfqQoptV1Buffer, TcSfqRedStats,
+};
+pub use self::taprio::{
+    TcMqprioQopt, TcMqprioQoptBuffer, TcQdiscTaprio, TcQdiscTaprioOption,
+    TcTaprioFlags, TcTaprioSchedCmd, TcTaprioSchedEntry,
+    TcTaprioSchedEntryOption,
+};
@@ -0,0 +1,56 @@
+// SPDX-License-Identifier: MIT
+
+use netlink_packet_utils::{
+    traits::{Emitable, Parseable},
+    DecodeError,
+};
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[non_exhaustive]
+pub struct TcQdiscMultiq {}
+
+impl TcQdiscMultiq {
+    pub(crate) const KIND: &'static str = "multiq";
+}
+
+const TC_MULTIQ_QOPT_LEN: usize = 4;
+
+/// The `struct tc_multiq_qopt` placed as payload of `TCA_OPTIONS` instead
+/// of nested attributes.
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[non_exhaustive]
+pub struct TcQdiscMultiqOption {
+    /// Number of bands, ignored on request as kernel always uses the
+    /// number of TX queues of the interface.
+    pub bands: u16,
+    /// Maximum number of queues
+    pub max_bands: u16,
+}
+
+buffer!(TcMultiqQoptBuffer(TC_MULTIQ_QOPT_LEN) {
+    bands: (u16, 0..2),
+    max_bands: (u16, 2..4),
+});
+
+impl<T: AsRef<[u8]>> Parseable<TcMultiqQoptBuffer<T>> for TcQdiscMultiqOption {
+    fn parse(buf: &TcMultiqQoptBuffer<T>) -> Result<Self, DecodeError> {
+        Ok(Self {
+            bands: buf.bands(),
+            max_bands: buf.max_bands(),
+        })
+    }
+}
+
+impl Emitable for TcQdiscMultiqOption {
+    fn buffer_len(&self) -> usize {
+        TC_MULTIQ_QOPT_LEN
+    }
+
+    fn emit(&self, buffer: &mut [u8]) {
+        let mut buffer = TcMultiqQoptBuffer::new(buffer);
+        buffer.set_bands(self.bands);
+        buffer.set_max_bands(self.max_bands);
+    }
+}
@@ -0,0 +1,71 @@
+// SPDX-License-Identifier: MIT
+
+use netlink_packet_utils::{
+    traits::{Emitable, Parseable},
+    DecodeError,
+};
+
+const TC_PRIO_MAX: usize = 15;
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[non_exhaustive]
+pub struct TcQdiscPrio {}
+
+impl TcQdiscPrio {
+    pub(crate) const KIND: &'static str = "prio";
+}
+
+/// The `pfifo_fast` qdisc shares the [TcQdiscPrioOption] of `prio` qdisc.
+#[derive(Debug, PartialEq, Eq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[non_exhaustive]
+pub struct TcQdiscPfifoFast {}
+
+impl TcQdiscPfifoFast {
+    pub(crate) const KIND: &'static str = "pfifo_fast";
+}
+
+const TC_PRIO_QOPT_LEN: usize = 4 + TC_PRIO_MAX + 1;
+
+/// The `struct tc_prio_qopt` placed as payload of `TCA_OPTIONS` instead of
+/// nested attributes.
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[non_exhaustive]
+pub struct TcQdiscPrioOption {
+    /// Number of bands
+    pub bands: i32,
+    /// Map of packet priority(skb->priority) to band index
+    pub priomap: [u8; TC_PRIO_MAX + 1],
+}
+
+buffer!(TcPrioQoptBuffer(TC_PRIO_QOPT_LEN) {
+    bands: (i32, 0..4),
+    priomap: (slice, 4..TC_PRIO_QOPT_LEN),
+});
+
+impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<TcPrioQoptBuffer<&'a T>>
+    for TcQdiscPrioOption
+{
+    fn parse(buf: &TcPrioQoptBuffer<&'a T>) -> Result<Self, DecodeError> {
+        let mut priomap = [0u8; TC_PRIO_MAX + 1];
+        priomap.copy_from_slice(buf.priomap());
+        Ok(Self {
+            bands: buf.bands(),
+            priomap,
+        })
+    }
+}
+
+impl Emitable for TcQdiscPrioOption {
+    fn buffer_len(&self) -> usize {
+        TC_PRIO_QOPT_LEN
+    }
+
+    fn emit(&self, buffer: &mut [u8]) {
+        let mut buffer = TcPrioQoptBuffer::new(buffer);
+        buffer.set_bands(self.bands);
+        buffer.priomap_mut().copy_from_slice(&self.priomap);
+    }
+}
@@ -0,0 +1,161 @@
+// SPDX-License-Identifier: MIT
+
+use netlink_packet_utils::{
+    traits::{Emitable, Parseable},
+    DecodeError,
+};
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[non_exhaustive]
+pub struct TcQdiscSfq {}
+
+impl TcQdiscSfq {
+    pub(crate) const KIND: &'static str = "sfq";
+}
+
+pub(crate) const TC_SFQ_QOPT_V1_LEN: usize = 72;
+
+/// The `struct tc_sfq_qopt_v1` placed as payload of `TCA_OPTIONS` instead of
+/// nested attributes. The RED parameters are only used when
+/// [TcQdiscSfqOption::max_p] is not zero.
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[non_exhaustive]
+pub struct TcQdiscSfqOption {
+    /// Bytes per round allocated to flow
+    pub quantum: u32,
+    /// Seconds of hash perturbation period
+    pub perturb_period: i32,
+    /// Maximal packets in queue
+    pub limit: u32,
+    /// Hash divisor
+    pub divisor: u32,
+    /// Maximal number of flows
+    pub flows: u32,
+    /// Maximal number of packets per flow
+    pub depth: u32,
+    pub headdrop: u32,
+    /// RED hard maximal flow queue length in bytes
+    pub red_limit: u32,
+    /// RED min average length threshold in bytes
+    pub qth_min: u32,
+    /// RED max average length threshold in bytes
+    pub qth_max: u32,
+    /// RED log(W)
+    pub wlog: u8,
+    /// RED log(P_max/(qth_max-qth_min))
+    pub plog: u8,
+    /// RED cell size for idle damping
+    pub scell_log: u8,
+    /// `TC_RED_*` flags
+    pub flags: u8,
+    /// RED probability in high resolution
+    pub max_p: u32,
+    /// RED statistics, ignored by kernel on request
+    pub stats: TcSfqRedStats,
+}
+
+buffer!(TcSfqQoptV1Buffer(TC_SFQ_QOPT_V1_LEN) {
+    quantum: (u32, 0..4),
+    perturb_period: (i32, 4..8),
+    limit: (u32, 8..12),
+    divisor: (u32, 12..16),
+    flows: (u32, 16..20),
+    depth: (u32, 20..24),
+    headdrop: (u32, 24..28),
+    red_limit: (u32, 28..32),
+    qth_min: (u32, 32..36),
+    qth_max: (u32, 36..40),
+    wlog: (u8, 40),
+    plog: (u8, 41),
+    scell_log: (u8, 42),
+    flags: (u8, 43),
+    max_p: (u32, 44..48),
+    prob_drop: (u32, 48..52),
+    forced_drop: (u32, 52..56),
+    prob_mark: (u32, 56..60),
+    forced_mark: (u32, 60..64),
+    prob_mark_head: (u32, 64..68),
+    forced_mark_head: (u32, 68..72),
+});
+
+impl<T: AsRef<[u8]>> Parseable<TcSfqQoptV1Buffer<T>> for TcQdiscSfqOption {
+    fn parse(buf: &TcSfqQoptV1Buffer<T>) -> Result<Self, DecodeError> {
+        Ok(Self {
+            quantum: buf.quantum(),
+            perturb_period: buf.perturb_period(),
+            limit: buf.limit(),
+            divisor: buf.divisor(),
+            flows: buf.flows(),
+            depth: buf.depth(),
+            headdrop: buf.headdrop(),
+            red_limit: buf.red_limit(),
+            qth_min: buf.qth_min(),
+            qth_max: buf.qth_max(),
+            wlog: buf.wlog(),
+            plog: buf.plog(),
+            scell_log: buf.scell_log(),
+            flags: buf.flags(),
+            max_p: buf.max_p(),
+            stats: TcSfqRedStats {
+                prob_drop: buf.prob_drop(),
+                forced_drop: buf.forced_drop(),
+                prob_mark: buf.prob_mark(),
+                forced_mark: buf.forced_mark(),
+                prob_mark_head: buf.prob_mark_head(),
+                forced_mark_head: buf.forced_mark_head(),
+            },
+        })
+    }
+}
+
+impl Emitable for TcQdiscSfqOption {
+    fn buffer_len(&self) -> usize {
+        TC_SFQ_QOPT_V1_LEN
+    }
+
+    fn emit(&self, buffer: &mut [u8]) {
+        let mut buffer = TcSfqQoptV1Buffer::new(buffer);
+        buffer.set_quantum(self.quantum);
+        buffer.set_perturb_period(self.perturb_period);
+        buffer.set_limit(self.limit);
+        buffer.set_divisor(self.divisor);
+        buffer.set_flows(self.flows);
+        buffer.set_depth(self.depth);
+        buffer.set_headdrop(self.headdrop);
+        buffer.set_red_limit(self.red_limit);
+        buffer.set_qth_min(self.qth_min);
+        buffer.set_qth_max(self.qth_max);
+        buffer.set_wlog(self.wlog);
+        buffer.set_plog(self.plog);
+        buffer.set_scell_log(self.scell_log);
+        buffer.set_flags(self.flags);
+        buffer.set_max_p(self.max_p);
+        buffer.set_prob_drop(self.stats.prob_drop);
+        buffer.set_forced_drop(self.stats.forced_drop);
+        buffer.set_prob_mark(self.stats.prob_mark);
+        buffer.set_forced_mark(self.stats.forced_mark);
+        buffer.set_prob_mark_head(self.stats.prob_mark_head);
+        buffer.set_forced_mark_head(self.stats.forced_mark_head);
+    }
+}
+
+/// The `struct tc_sfqred_stats` embedded in [TcQdiscSfqOption].
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[non_exhaustive]
+pub struct TcSfqRedStats {
+    /// Early drops, below max threshold
+    pub prob_drop: u32,
+    /// Early drops, after max threshold
+    pub forced_drop: u32,
+    /// Marked packets, below max threshold
+    pub prob_mark: u32,
+    /// Marked packets, after max threshold
+    pub forced_mark: u32,
+    /// Marked packets, below max threshold
+    pub prob_mark_head: u32,
+    /// Marked packets, after max threshold
+    pub forced_mark_head: u32,
+}
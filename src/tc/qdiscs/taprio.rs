@@ -0,0 +1,424 @@
+// SPDX-License-Identifier: MIT
+
+use anyhow::Context;
+use byteorder::{ByteOrder, NativeEndian};
+use netlink_packet_utils::{
+    nla::{DefaultNla, Nla, NlaBuffer, NlasIterator},
+    parsers::{parse_i32, parse_u32, parse_u64, parse_u8},
+    traits::{Emitable, Parseable},
+    DecodeError,
+};
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[non_exhaustive]
+pub struct TcQdiscTaprio {}
+
+impl TcQdiscTaprio {
+    pub(crate) const KIND: &'static str = "taprio";
+}
+
+const TCA_TAPRIO_ATTR_PRIOMAP: u16 = 1;
+const TCA_TAPRIO_ATTR_SCHED_ENTRY_LIST: u16 = 2;
+const TCA_TAPRIO_ATTR_SCHED_BASE_TIME: u16 = 3;
+// const TCA_TAPRIO_ATTR_SCHED_SINGLE_ENTRY: u16 = 4; // not supported by kernel
+const TCA_TAPRIO_ATTR_SCHED_CLOCKID: u16 = 5;
+// const TCA_TAPRIO_PAD: u16 = 6;
+const TCA_TAPRIO_ATTR_ADMIN_SCHED: u16 = 7;
+const TCA_TAPRIO_ATTR_SCHED_CYCLE_TIME: u16 = 8;
+const TCA_TAPRIO_ATTR_SCHED_CYCLE_TIME_EXTENSION: u16 = 9;
+const TCA_TAPRIO_ATTR_FLAGS: u16 = 10;
+const TCA_TAPRIO_ATTR_TXTIME_DELAY: u16 = 11;
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[non_exhaustive]
+pub enum TcQdiscTaprioOption {
+    Priomap(TcMqprioQopt),
+    SchedEntryList(Vec<TcTaprioSchedEntry>),
+    /// Nanoseconds since epoch of [TcQdiscTaprioOption::SchedClockId]
+    SchedBaseTime(i64),
+    /// Not included when [TcTaprioFlags::FullOffload] is set as the
+    /// hardware is using its own clock.
+    SchedClockId(i32),
+    /// Schedule pending to replace the operational one at its base time,
+    /// holding the same schedule attributes
+    AdminSched(Vec<TcQdiscTaprioOption>),
+    /// Nanoseconds
+    SchedCycleTime(i64),
+    /// Nanoseconds
+    SchedCycleTimeExtension(i64),
+    Flags(TcTaprioFlags),
+    /// Nanoseconds before the transmission time the packet should be
+    /// handed to the network card, only valid for
+    /// [TcTaprioFlags::TxtimeAssist].
+    TxtimeDelay(u32),
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_nla"))]
+    Other(DefaultNla),
+}
+
+impl Nla for TcQdiscTaprioOption {
+    fn value_len(&self) -> usize {
+        match self {
+            Self::Priomap(v) => v.buffer_len(),
+            Self::SchedEntryList(v) => v.as_slice().buffer_len(),
+            Self::AdminSched(v) => v.as_slice().buffer_len(),
+            Self::SchedBaseTime(_)
+            | Self::SchedCycleTime(_)
+            | Self::SchedCycleTimeExtension(_) => 8,
+            Self::SchedClockId(_) | Self::Flags(_) | Self::TxtimeDelay(_) => 4,
+            Self::Other(attr) => attr.value_len(),
+        }
+    }
+
+    fn emit_value(&self, buffer: &mut [u8]) {
+        match self {
+            Self::Priomap(v) => v.emit(buffer),
+            Self::SchedEntryList(v) => v.as_slice().emit(buffer),
+            Self::AdminSched(v) => v.as_slice().emit(buffer),
+            Self::SchedBaseTime(v)
+            | Self::SchedCycleTime(v)
+            | Self::SchedCycleTimeExtension(v) => {
+                NativeEndian::write_i64(buffer, *v)
+            }
+            Self::SchedClockId(v) => NativeEndian::write_i32(buffer, *v),
+            Self::Flags(v) => NativeEndian::write_u32(buffer, v.bits()),
+            Self::TxtimeDelay(v) => NativeEndian::write_u32(buffer, *v),
+            Self::Other(attr) => attr.emit_value(buffer),
+        }
+    }
+
+    fn kind(&self) -> u16 {
+        match self {
+            Self::Priomap(_) => TCA_TAPRIO_ATTR_PRIOMAP,
+            Self::SchedEntryList(_) => TCA_TAPRIO_ATTR_SCHED_ENTRY_LIST,
+            Self::SchedBaseTime(_) => TCA_TAPRIO_ATTR_SCHED_BASE_TIME,
+            Self::SchedClockId(_) => TCA_TAPRIO_ATTR_SCHED_CLOCKID,
+            Self::AdminSched(_) => TCA_TAPRIO_ATTR_ADMIN_SCHED,
+            Self::SchedCycleTime(_) => TCA_TAPRIO_ATTR_SCHED_CYCLE_TIME,
+            Self::SchedCycleTimeExtension(_) => {
+                TCA_TAPRIO_ATTR_SCHED_CYCLE_TIME_EXTENSION
+            }
+            Self::Flags(_) => TCA_TAPRIO_ATTR_FLAGS,
+            Self::TxtimeDelay(_) => TCA_TAPRIO_ATTR_TXTIME_DELAY,
+            Self::Other(attr) => attr.kind(),
+        }
+    }
+}
+
+impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>>
+    for TcQdiscTaprioOption
+{
+    fn parse(buf: &NlaBuffer<&'a T>) -> Result<Self, DecodeError> {
+        let payload = buf.value();
+        Ok(match buf.kind() {
+            TCA_TAPRIO_ATTR_PRIOMAP => Self::Priomap(
+                TcMqprioQopt::parse(
+                    &TcMqprioQoptBuffer::new_checked(payload)
+                        .context("invalid TCA_TAPRIO_ATTR_PRIOMAP")?,
+                )
+                .context("invalid TCA_TAPRIO_ATTR_PRIOMAP")?,
+            ),
+            TCA_TAPRIO_ATTR_SCHED_ENTRY_LIST => {
+                let mut entries = vec![];
+                for nla in NlasIterator::new(payload) {
+                    let nla = nla
+                        .context("invalid TCA_TAPRIO_ATTR_SCHED_ENTRY_LIST")?;
+                    entries.push(TcTaprioSchedEntry::parse(&nla)?);
+                }
+                Self::SchedEntryList(entries)
+            }
+            TCA_TAPRIO_ATTR_SCHED_BASE_TIME => Self::SchedBaseTime(
+                parse_u64(payload)
+                    .context("invalid TCA_TAPRIO_ATTR_SCHED_BASE_TIME")?
+                    as i64,
+            ),
+            TCA_TAPRIO_ATTR_SCHED_CLOCKID => Self::SchedClockId(
+                parse_i32(payload)
+                    .context("invalid TCA_TAPRIO_ATTR_SCHED_CLOCKID")?,
+            ),
+            TCA_TAPRIO_ATTR_ADMIN_SCHED => {
+                let mut nlas = vec![];
+                for nla in NlasIterator::new(payload) {
+                    let nla =
+                        nla.context("invalid TCA_TAPRIO_ATTR_ADMIN_SCHED")?;
+                    nlas.push(Self::parse(&nla)?);
+                }
+                Self::AdminSched(nlas)
+            }
+            TCA_TAPRIO_ATTR_SCHED_CYCLE_TIME => Self::SchedCycleTime(
+                parse_u64(payload)
+                    .context("invalid TCA_TAPRIO_ATTR_SCHED_CYCLE_TIME")?
+                    as i64,
+            ),
+            TCA_TAPRIO_ATTR_SCHED_CYCLE_TIME_EXTENSION => {
+                Self::SchedCycleTimeExtension(parse_u64(payload).context(
+                    "invalid TCA_TAPRIO_ATTR_SCHED_CYCLE_TIME_EXTENSION",
+                )? as i64)
+            }
+            TCA_TAPRIO_ATTR_FLAGS => {
+                Self::Flags(TcTaprioFlags::from_bits_retain(
+                    parse_u32(payload)
+                        .context("invalid TCA_TAPRIO_ATTR_FLAGS")?,
+                ))
+            }
+            TCA_TAPRIO_ATTR_TXTIME_DELAY => Self::TxtimeDelay(
+                parse_u32(payload)
+                    .context("invalid TCA_TAPRIO_ATTR_TXTIME_DELAY")?,
+            ),
+            _ => Self::Other(
+                DefaultNla::parse(buf).context("failed to parse taprio nla")?,
+            ),
+        })
+    }
+}
+
+const TCA_TAPRIO_ATTR_FLAG_TXTIME_ASSIST: u32 = 1 << 0;
+const TCA_TAPRIO_ATTR_FLAG_FULL_OFFLOAD: u32 = 1 << 1;
+
+bitflags! {
+    #[derive(Clone, Eq, PartialEq, Debug, Copy, Default)]
+    #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+    #[non_exhaustive]
+    pub struct TcTaprioFlags: u32 {
+        /// Software schedule with the transmission time set by taprio and
+        /// enforced by the `etf` qdisc or network card
+        const TxtimeAssist = TCA_TAPRIO_ATTR_FLAG_TXTIME_ASSIST;
+        /// Schedule fully offloaded to network card
+        const FullOffload = TCA_TAPRIO_ATTR_FLAG_FULL_OFFLOAD;
+        const _ = !0;
+    }
+}
+
+const TC_QOPT_MAX_QUEUE: usize = 16;
+const TC_QOPT_BITMASK: usize = 15;
+const TC_PRIO_MAP_LEN: usize = TC_QOPT_BITMASK + 1;
+const TC_MQPRIO_QOPT_LEN: usize = 82;
+
+buffer!(TcMqprioQoptBuffer(TC_MQPRIO_QOPT_LEN) {
+    num_tc: (u8, 0),
+    prio_tc_map: (slice, 1..17),
+    hw: (u8, 17),
+    count: (slice, 18..50),
+    offset: (slice, 50..TC_MQPRIO_QOPT_LEN),
+});
+
+/// Traffic class mapping shared by `mqprio` and `taprio` qdisc.
+// Linux kernel code `struct tc_mqprio_qopt`
+#[derive(Debug, PartialEq, Eq, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[non_exhaustive]
+pub struct TcMqprioQopt {
+    /// Number of traffic classes
+    pub num_tc: u8,
+    /// Traffic class of each priority
+    pub prio_tc_map: [u8; TC_PRIO_MAP_LEN],
+    pub hw: u8,
+    /// Number of TX queues of each traffic class
+    pub count: [u16; TC_QOPT_MAX_QUEUE],
+    /// First TX queue of each traffic class
+    pub offset: [u16; TC_QOPT_MAX_QUEUE],
+}
+
+impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<TcMqprioQoptBuffer<&'a T>>
+    for TcMqprioQopt
+{
+    fn parse(buf: &TcMqprioQoptBuffer<&'a T>) -> Result<Self, DecodeError> {
+        let mut ret = Self {
+            num_tc: buf.num_tc(),
+            hw: buf.hw(),
+            ..Default::default()
+        };
+        ret.prio_tc_map.copy_from_slice(buf.prio_tc_map());
+        NativeEndian::read_u16_into(buf.count(), &mut ret.count);
+        NativeEndian::read_u16_into(buf.offset(), &mut ret.offset);
+        Ok(ret)
+    }
+}
+
+impl Emitable for TcMqprioQopt {
+    fn buffer_len(&self) -> usize {
+        TC_MQPRIO_QOPT_LEN
+    }
+
+    fn emit(&self, buffer: &mut [u8]) {
+        let mut buffer = TcMqprioQoptBuffer::new(buffer);
+        buffer.set_num_tc(self.num_tc);
+        buffer.prio_tc_map_mut().copy_from_slice(&self.prio_tc_map);
+        buffer.set_hw(self.hw);
+        NativeEndian::write_u16_into(&self.count, buffer.count_mut());
+        NativeEndian::write_u16_into(&self.offset, buffer.offset_mut());
+    }
+}
+
+const TCA_TAPRIO_SCHED_ENTRY: u16 = 1;
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[non_exhaustive]
+pub enum TcTaprioSchedEntry {
+    Entry(Vec<TcTaprioSchedEntryOption>),
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_nla"))]
+    Other(DefaultNla),
+}
+
+impl Nla for TcTaprioSchedEntry {
+    fn value_len(&self) -> usize {
+        match self {
+            Self::Entry(v) => v.as_slice().buffer_len(),
+            Self::Other(attr) => attr.value_len(),
+        }
+    }
+
+    fn emit_value(&self, buffer: &mut [u8]) {
+        match self {
+            Self::Entry(v) => v.as_slice().emit(buffer),
+            Self::Other(attr) => attr.emit_value(buffer),
+        }
+    }
+
+    fn kind(&self) -> u16 {
+        match self {
+            Self::Entry(_) => TCA_TAPRIO_SCHED_ENTRY,
+            Self::Other(attr) => attr.kind(),
+        }
+    }
+}
+
+impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>>
+    for TcTaprioSchedEntry
+{
+    fn parse(buf: &NlaBuffer<&'a T>) -> Result<Self, DecodeError> {
+        Ok(match buf.kind() {
+            TCA_TAPRIO_SCHED_ENTRY => {
+                let mut nlas = vec![];
+                for nla in NlasIterator::new(buf.value()) {
+                    let nla = nla.context("invalid TCA_TAPRIO_SCHED_ENTRY")?;
+                    nlas.push(TcTaprioSchedEntryOption::parse(&nla)?);
+                }
+                Self::Entry(nlas)
+            }
+            _ => Self::Other(
+                DefaultNla::parse(buf)
+                    .context("failed to parse taprio schedule entry")?,
+            ),
+        })
+    }
+}
+
+const TCA_TAPRIO_SCHED_ENTRY_INDEX: u16 = 1;
+const TCA_TAPRIO_SCHED_ENTRY_CMD: u16 = 2;
+const TCA_TAPRIO_SCHED_ENTRY_GATE_MASK: u16 = 3;
+const TCA_TAPRIO_SCHED_ENTRY_INTERVAL: u16 = 4;
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[non_exhaustive]
+pub enum TcTaprioSchedEntryOption {
+    Index(u32),
+    Cmd(TcTaprioSchedCmd),
+    /// Bitmap of the traffic classes with open gate
+    GateMask(u32),
+    /// Nanoseconds
+    Interval(u32),
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_nla"))]
+    Other(DefaultNla),
+}
+
+impl Nla for TcTaprioSchedEntryOption {
+    fn value_len(&self) -> usize {
+        match self {
+            Self::Cmd(_) => 1,
+            Self::Index(_) | Self::GateMask(_) | Self::Interval(_) => 4,
+            Self::Other(attr) => attr.value_len(),
+        }
+    }
+
+    fn emit_value(&self, buffer: &mut [u8]) {
+        match self {
+            Self::Cmd(v) => buffer[0] = (*v).into(),
+            Self::Index(v) | Self::GateMask(v) | Self::Interval(v) => {
+                NativeEndian::write_u32(buffer, *v)
+            }
+            Self::Other(attr) => attr.emit_value(buffer),
+        }
+    }
+
+    fn kind(&self) -> u16 {
+        match self {
+            Self::Index(_) => TCA_TAPRIO_SCHED_ENTRY_INDEX,
+            Self::Cmd(_) => TCA_TAPRIO_SCHED_ENTRY_CMD,
+            Self::GateMask(_) => TCA_TAPRIO_SCHED_ENTRY_GATE_MASK,
+            Self::Interval(_) => TCA_TAPRIO_SCHED_ENTRY_INTERVAL,
+            Self::Other(attr) => attr.kind(),
+        }
+    }
+}
+
+impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>>
+    for TcTaprioSchedEntryOption
+{
+    fn parse(buf: &NlaBuffer<&'a T>) -> Result<Self, DecodeError> {
+        let payload = buf.value();
+        Ok(match buf.kind() {
+            TCA_TAPRIO_SCHED_ENTRY_INDEX => Self::Index(
+                parse_u32(payload)
+                    .context("invalid TCA_TAPRIO_SCHED_ENTRY_INDEX")?,
+            ),
+            TCA_TAPRIO_SCHED_ENTRY_CMD => Self::Cmd(
+                parse_u8(payload)
+                    .context("invalid TCA_TAPRIO_SCHED_ENTRY_CMD")?
+                    .into(),
+            ),
+            TCA_TAPRIO_SCHED_ENTRY_GATE_MASK => Self::GateMask(
+                parse_u32(payload)
+                    .context("invalid TCA_TAPRIO_SCHED_ENTRY_GATE_MASK")?,
+            ),
+            TCA_TAPRIO_SCHED_ENTRY_INTERVAL => Self::Interval(
+                parse_u32(payload)
+                    .context("invalid TCA_TAPRIO_SCHED_ENTRY_INTERVAL")?,
+            ),
+            _ => Self::Other(
+                DefaultNla::parse(buf)
+                    .context("failed to parse taprio schedule entry nla")?,
+            ),
+        })
+    }
+}
+
+const TC_TAPRIO_CMD_SET_GATES: u8 = 0;
+const TC_TAPRIO_CMD_SET_AND_HOLD: u8 = 1;
+const TC_TAPRIO_CMD_SET_AND_RELEASE: u8 = 2;
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[non_exhaustive]
+pub enum TcTaprioSchedCmd {
+    #[default]
+    SetGates,
+    SetAndHold,
+    SetAndRelease,
+    Other(u8),
+}
+
+impl From<u8> for TcTaprioSchedCmd {
+    fn from(d: u8) -> Self {
+        match d {
+            TC_TAPRIO_CMD_SET_GATES => Self::SetGates,
+            TC_TAPRIO_CMD_SET_AND_HOLD => Self::SetAndHold,
+            TC_TAPRIO_CMD_SET_AND_RELEASE => Self::SetAndRelease,
+            _ => Self::Other(d),
+        }
+    }
+}
+
+impl From<TcTaprioSchedCmd> for u8 {
+    fn from(v: TcTaprioSchedCmd) -> u8 {
+        match v {
+            TcTaprioSchedCmd::SetGates => TC_TAPRIO_CMD_SET_GATES,
+            TcTaprioSchedCmd::SetAndHold => TC_TAPRIO_CMD_SET_AND_HOLD,
+            TcTaprioSchedCmd::SetAndRelease => TC_TAPRIO_CMD_SET_AND_RELEASE,
+            TcTaprioSchedCmd::Other(d) => d,
+        }
+    }
+}
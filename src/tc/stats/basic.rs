@@ -6,7 +6,11 @@ use netlink_packet_utils::{
 };
 
 /// Byte/Packet throughput statistics
+///
+/// When parsed from `TCA_STATS_BASIC`, data longer than expected is ignored,
+/// unless [crate::set_strict_parsing()] is enabled.
 #[derive(Default, Debug, PartialEq, Eq, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[non_exhaustive]
 pub struct TcStatsBasic {
     /// number of seen bytes
@@ -23,6 +27,8 @@ buffer!(TcStatsBasicBuffer(STATS_BASIC_LEN) {
     packets: (u32, 8..12),
 });
 
+impl_new_strict!(TcStatsBasicBuffer, STATS_BASIC_LEN);
+
 impl<T: AsRef<[u8]>> Parseable<TcStatsBasicBuffer<T>> for TcStatsBasic {
     fn parse(buf: &TcStatsBasicBuffer<T>) -> Result<Self, DecodeError> {
         Ok(TcStatsBasic {
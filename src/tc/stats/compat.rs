@@ -7,6 +7,7 @@ use netlink_packet_utils::{
 
 /// Generic queue statistics
 #[derive(Default, Debug, PartialEq, Eq, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[non_exhaustive]
 pub struct TcStats {
     /// Number of enqueued bytes
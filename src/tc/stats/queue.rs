@@ -7,6 +7,7 @@ use netlink_packet_utils::{
 
 /// Queuing statistics
 #[derive(Default, Debug, PartialEq, Eq, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[non_exhaustive]
 pub struct TcStatsQueue {
     /// queue length
@@ -6,6 +6,8 @@ use netlink_packet_utils::{
     DecodeError,
 };
 
+use crate::is_strict_parsing;
+
 use super::{
     TcStatsBasic, TcStatsBasicBuffer, TcStatsQueue, TcStatsQueueBuffer,
     TcXstats,
@@ -20,12 +22,14 @@ const TCA_STATS_APP: u16 = 4;
 const TCA_STATS_BASIC_HW: u16 = 7;
 
 #[derive(Debug, PartialEq, Eq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[non_exhaustive]
 pub enum TcStats2 {
     App(TcXstats),
     Basic(TcStatsBasic),
     Queue(TcStatsQueue),
     BasicHw(TcStatsBasic),
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_nla"))]
     Other(DefaultNla),
 }
 
@@ -72,12 +76,18 @@ where
         let payload = buf.value();
         Ok(match buf.kind() {
             TCA_STATS_APP => Self::App(TcXstats::parse_with_param(buf, kind)?),
+            TCA_STATS_BASIC if is_strict_parsing() => Self::Basic(
+                TcStatsBasic::parse(&TcStatsBasicBuffer::new_strict(payload)?)?,
+            ),
             TCA_STATS_BASIC => Self::Basic(TcStatsBasic::parse(
                 &TcStatsBasicBuffer::new(payload),
             )?),
             TCA_STATS_QUEUE => Self::Queue(TcStatsQueue::parse(
                 &TcStatsQueueBuffer::new(payload),
             )?),
+            TCA_STATS_BASIC_HW if is_strict_parsing() => Self::BasicHw(
+                TcStatsBasic::parse(&TcStatsBasicBuffer::new_strict(payload)?)?,
+            ),
             TCA_STATS_BASIC_HW => Self::BasicHw(TcStatsBasic::parse(
                 &TcStatsBasicBuffer::new(payload),
             )?),
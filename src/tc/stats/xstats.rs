@@ -1,17 +1,27 @@
 // SPDX-License-Identifier: MIT
 
+use anyhow::Context;
 use netlink_packet_utils::{
-    nla::NlaBuffer,
+    nla::{NlaBuffer, NlasIterator},
     traits::{Emitable, Parseable, ParseableParametrized},
     DecodeError,
 };
 
-use crate::tc::{TcFqCodelXstats, TcQdiscFqCodel};
+use crate::tc::{
+    TcCakeStats, TcFqCodelXstats, TcFqPieXstats, TcFqPieXstatsBuffer,
+    TcFqQdStats, TcFqQdStatsBuffer, TcQdiscCake, TcQdiscFq, TcQdiscFqCodel,
+    TcQdiscFqPie, TC_FQ_PIE_XSTATS_LEN, TC_FQ_QD_STATS_LEN,
+};
 
 #[derive(Debug, PartialEq, Eq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[non_exhaustive]
 pub enum TcXstats {
     FqCodel(TcFqCodelXstats),
+    Cake(Vec<TcCakeStats>),
+    Fq(TcFqQdStats),
+    FqPie(TcFqPieXstats),
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_hex"))]
     Other(Vec<u8>),
 }
 
@@ -19,6 +29,9 @@ impl Emitable for TcXstats {
     fn buffer_len(&self) -> usize {
         match self {
             Self::FqCodel(v) => v.buffer_len(),
+            Self::Cake(v) => v.as_slice().buffer_len(),
+            Self::Fq(v) => v.buffer_len(),
+            Self::FqPie(v) => v.buffer_len(),
             Self::Other(v) => v.len(),
         }
     }
@@ -26,6 +39,9 @@ impl Emitable for TcXstats {
     fn emit(&self, buffer: &mut [u8]) {
         match self {
             Self::FqCodel(v) => v.emit(buffer),
+            Self::Cake(v) => v.as_slice().emit(buffer),
+            Self::Fq(v) => v.emit(buffer),
+            Self::FqPie(v) => v.emit(buffer),
             Self::Other(v) => buffer.copy_from_slice(v.as_slice()),
         }
     }
@@ -43,6 +59,27 @@ where
             TcQdiscFqCodel::KIND => {
                 TcXstats::FqCodel(TcFqCodelXstats::parse(buf.value())?)
             }
+            TcQdiscCake::KIND => {
+                let mut nlas = vec![];
+                for nla in NlasIterator::new(buf.value()) {
+                    let nla = nla.context("invalid cake xstats")?;
+                    nlas.push(
+                        TcCakeStats::parse(&nla)
+                            .context("failed to parse cake xstats")?,
+                    );
+                }
+                TcXstats::Cake(nlas)
+            }
+            TcQdiscFq::KIND if buf.value().len() == TC_FQ_QD_STATS_LEN => {
+                TcXstats::Fq(TcFqQdStats::parse(&TcFqQdStatsBuffer::new(
+                    buf.value(),
+                ))?)
+            }
+            TcQdiscFqPie::KIND if buf.value().len() == TC_FQ_PIE_XSTATS_LEN => {
+                TcXstats::FqPie(TcFqPieXstats::parse(
+                    &TcFqPieXstatsBuffer::new(buf.value()),
+                )?)
+            }
             _ => TcXstats::Other(buf.value().to_vec()),
         })
     }
@@ -0,0 +1,77 @@
+// SPDX-License-Identifier: MIT
+
+use netlink_packet_utils::{Emitable, Parseable};
+
+use crate::tc::{
+    TcHandle, TcHeader, TcMessage, TcMessageBuffer, TcMessageBuilder,
+};
+
+// Captured RTM_GETQDISC dump request sent by iproute2 6.1 for
+//      tc qdisc show
+// with netlink header removed.
+#[test]
+fn test_tc_message_builder_qdisc_dump() {
+    let raw = vec![
+        0x00, // AF_UNSPEC
+        0x00, 0x00, 0x00, // padding
+        0x00, 0x00, 0x00, 0x00, // ifindex 0
+        0x00, 0x00, 0x00, 0x00, // handle 0:0
+        0x00, 0x00, 0x00, 0x00, // parent 0:0
+        0x00, 0x00, 0x00, 0x00, // info 0
+    ];
+
+    let message = TcMessageBuilder::for_qdisc_dump(None).build();
+    assert_eq!(message, TcMessage::default());
+
+    let mut buf = vec![0; message.buffer_len()];
+    message.emit(&mut buf);
+    assert_eq!(buf, raw);
+}
+
+// Captured RTM_GETTCLASS dump request sent by iproute2 6.1 for
+//      tc class show dev veth9a parent 1:
+// with veth9a holding interface index 11, netlink header removed.
+#[test]
+fn test_tc_message_builder_class_dump() {
+    let raw = vec![
+        0x00, // AF_UNSPEC
+        0x00, 0x00, 0x00, // padding
+        0x0b, 0x00, 0x00, 0x00, // ifindex 11
+        0x00, 0x00, 0x00, 0x00, // handle 0:0
+        0x00, 0x00, 0x01, 0x00, // parent 1:0
+        0x00, 0x00, 0x00, 0x00, // info 0
+    ];
+
+    let message = TcMessageBuilder::for_class_dump(11)
+        .parent(TcHandle { major: 1, minor: 0 })
+        .build();
+
+    assert_eq!(
+        message,
+        TcMessage {
+            header: TcHeader {
+                index: 11,
+                parent: TcHandle { major: 1, minor: 0 },
+                ..Default::default()
+            },
+            attributes: vec![],
+        }
+    );
+
+    let mut buf = vec![0; message.buffer_len()];
+    message.emit(&mut buf);
+    assert_eq!(buf, raw);
+
+    assert_eq!(
+        message,
+        TcMessage::parse(&TcMessageBuffer::new(&raw)).unwrap()
+    );
+
+    assert_eq!(
+        TcMessageBuilder::for_qdisc_dump(Some(2))
+            .build()
+            .header
+            .index,
+        2
+    );
+}
@@ -0,0 +1,92 @@
+// SPDX-License-Identifier: MIT
+
+use netlink_packet_utils::{nla::NLA_F_NESTED, Emitable, Parseable};
+
+use crate::{
+    tc::{
+        TcAttribute, TcClassDrrOption, TcHandle, TcHeader, TcMessage,
+        TcMessageBuffer, TcOption,
+    },
+    AddressFamily,
+};
+
+// Captured RTM_NEWTCLASS request sent by iproute2 6.1 for:
+//
+//      tc class add dev ifb1 parent 1: classid 1:1 drr quantum 1500
+//
+// with ifb1 holding interface index 3, netlink header removed. iproute2 6.1
+// does not set NLA_F_NESTED on the drr TCA_OPTIONS.
+fn raw_add_class_drr() -> Vec<u8> {
+    vec![
+        0x00, // AF_UNSPEC
+        0x00, 0x00, 0x00, // padding
+        0x03, 0x00, 0x00, 0x00, // iface index: 3
+        0x01, 0x00, 0x01, 0x00, // handle 1:1
+        0x00, 0x00, 0x01, 0x00, // parent 1:0
+        0x00, 0x00, 0x00, 0x00, // info: 0
+        0x08, 0x00, // length 8
+        0x01, 0x00, // TCA_KIND
+        0x64, 0x72, 0x72, 0x00, // "drr\0"
+        0x0c, 0x00, // length 12
+        0x02, 0x00, // TCA_OPTIONS for `drr`
+        0x08, 0x00, // length 8
+        0x01, 0x00, // TCA_DRR_QUANTUM
+        0xdc, 0x05, 0x00, 0x00, // 1500
+    ]
+}
+
+fn expected_add_class_drr() -> TcMessage {
+    TcMessage {
+        header: TcHeader {
+            family: AddressFamily::Unspec,
+            index: 3,
+            handle: TcHandle { major: 1, minor: 1 },
+            parent: TcHandle { major: 1, minor: 0 },
+            info: 0,
+        },
+        attributes: vec![
+            TcAttribute::Kind("drr".to_string()),
+            TcAttribute::Options(vec![TcOption::Drr(
+                TcClassDrrOption::Quantum(1500),
+            )]),
+        ],
+    }
+}
+
+#[test]
+fn test_add_class_drr() {
+    let raw = raw_add_class_drr();
+    let expected = expected_add_class_drr();
+
+    assert_eq!(
+        expected,
+        TcMessage::parse(&TcMessageBuffer::new(&raw)).unwrap()
+    );
+
+    let mut buf = vec![0; expected.buffer_len()];
+
+    expected.emit(&mut buf);
+
+    assert_eq!(buf, raw);
+}
+
+// The capture above with NLA_F_NESTED set on TCA_OPTIONS, as other senders
+// may do. The flag is ignored on parse and not emitted.
+#[test]
+fn test_add_class_drr_nested_flag() {
+    let mut raw = raw_add_class_drr();
+    // TCA_OPTIONS | NLA_F_NESTED
+    raw[30..32].copy_from_slice(&(2u16 | NLA_F_NESTED).to_ne_bytes());
+    let expected = expected_add_class_drr();
+
+    assert_eq!(
+        expected,
+        TcMessage::parse(&TcMessageBuffer::new(&raw)).unwrap()
+    );
+
+    let mut buf = vec![0; expected.buffer_len()];
+
+    expected.emit(&mut buf);
+
+    assert_eq!(buf, raw_add_class_drr());
+}
@@ -0,0 +1,85 @@
+// SPDX-License-Identifier: MIT
+
+use netlink_packet_utils::{Emitable, Parseable};
+
+use crate::{
+    tc::{
+        TcAttribute, TcFilterBpfOption, TcHandle, TcHeader, TcMessage,
+        TcMessageBuffer, TcOption,
+    },
+    AddressFamily,
+};
+
+// Hand-crafted RTM_NEWTFILTER reply(rtnetlink header removed) equivalent to
+// the one used by `tc filter show dev dummy1 ingress` after:
+//
+//      tc qdisc add dev dummy1 clsact
+//      tc filter add dev dummy1 ingress bpf da obj prog.o sec classifier
+#[test]
+fn test_bpf_filter_direct_action() {
+    let raw = vec![
+        0x00, // AF_UNSPEC
+        0x00, 0x00, 0x00, // padding
+        0x03, 0x00, 0x00, 0x00, // iface index 3
+        0x01, 0x00, 0x00, 0x00, // handle 0:1
+        0xf2, 0xff, 0xff, 0xff, // parent ffff:fff2
+        0x00, 0x03, 0x00,
+        0xc0, // info: priority 49152, protocol ETH_P_ALL
+        0x08, 0x00, // length 8
+        0x01, 0x00, // TCA_KIND
+        0x62, 0x70, 0x66, 0x00, // "bpf\0"
+        0x38, 0x00, // length 56
+        0x02, 0x00, // TCA_OPTIONS for `bpf`
+        0x18, 0x00, // length 24
+        0x07, 0x00, // TCA_BPF_NAME
+        0x70, 0x72, 0x6f, 0x67, 0x2e, 0x6f, 0x3a, 0x5b, 0x63, 0x6c, 0x61, 0x73,
+        0x73, 0x69, 0x66, 0x69, 0x65, 0x72, 0x5d,
+        0x00, // "prog.o:[classifier]\0"
+        0x08, 0x00, // length 8
+        0x0b, 0x00, // TCA_BPF_ID
+        0x2a, 0x00, 0x00, 0x00, // 42
+        0x0c, 0x00, // length 12
+        0x0a, 0x00, // TCA_BPF_TAG
+        0xa0, 0x4f, 0x5e, 0xef, 0x51, 0x85, 0x1c, 0x9b, // tag
+        0x08, 0x00, // length 8
+        0x08, 0x00, // TCA_BPF_FLAGS
+        0x01, 0x00, 0x00, 0x00, // TCA_BPF_FLAG_ACT_DIRECT
+    ];
+
+    let expected = TcMessage {
+        header: TcHeader {
+            family: AddressFamily::Unspec,
+            index: 3,
+            handle: TcHandle { major: 0, minor: 1 },
+            parent: TcHandle {
+                major: 0xffff,
+                minor: 0xfff2,
+            },
+            info: 0xc0000300,
+        },
+        attributes: vec![
+            TcAttribute::Kind("bpf".to_string()),
+            TcAttribute::Options(vec![
+                TcOption::Bpf(TcFilterBpfOption::Name(
+                    "prog.o:[classifier]".to_string(),
+                )),
+                TcOption::Bpf(TcFilterBpfOption::Id(42)),
+                TcOption::Bpf(TcFilterBpfOption::Tag(vec![
+                    0xa0, 0x4f, 0x5e, 0xef, 0x51, 0x85, 0x1c, 0x9b,
+                ])),
+                TcOption::Bpf(TcFilterBpfOption::Flags(1)),
+            ]),
+        ],
+    };
+
+    assert_eq!(
+        expected,
+        TcMessage::parse(&TcMessageBuffer::new(&raw)).unwrap()
+    );
+
+    let mut buf = vec![0; expected.buffer_len()];
+
+    expected.emit(&mut buf);
+
+    assert_eq!(buf, raw);
+}
@@ -0,0 +1,636 @@
+// SPDX-License-Identifier: MIT
+
+use std::{net::Ipv4Addr, ops::RangeInclusive};
+
+use netlink_packet_utils::{
+    nla::{DefaultNla, NlaBuffer, NlasIterator},
+    Emitable, Parseable,
+};
+
+use crate::{
+    net::{arp, ethernet::Ethertype},
+    tc::{
+        ErspanDirection, ErspanHwId, TcAction, TcActionAttribute, TcAttribute,
+        TcFilterFlower, TcFilterFlowerOption, TcFlowerEncOpt,
+        TcFlowerEncOptErspan, TcFlowerKeyFlags, TcHandle, TcHeader, TcMessage,
+        TcMessageBuffer, TcOption,
+    },
+    AddressFamily, IpProtocol,
+};
+
+// RTM_NEWTFILTER request(rtnetlink header removed) for matching the first
+// IP fragments, equivalent to the flower options of:
+//
+//      tc filter add dev dummy1 ingress flower ip_flags frag/firstfrag
+#[test]
+fn test_flower_first_fragment() {
+    let raw = vec![
+        0x00, // AF_UNSPEC
+        0x00, 0x00, 0x00, // padding
+        0x03, 0x00, 0x00, 0x00, // iface index 3
+        0x00, 0x00, 0x00, 0x00, // handle 0:0
+        0xf1, 0xff, 0xff, 0xff, // parent ffff:fff1
+        0x00, 0x00, 0x00, 0x00, // info 0
+        0x0b, 0x00, // length 11
+        0x01, 0x00, // TCA_KIND
+        0x66, 0x6c, 0x6f, 0x77, 0x65, 0x72, 0x00, 0x00,
+        // "flower\0" and 1 padding byte
+        0x14, 0x00, // length 20
+        0x02, 0x00, // TCA_OPTIONS for `flower`
+        0x08, 0x00, // length 8
+        0x2f, 0x00, // TCA_FLOWER_KEY_FLAGS
+        0x00, 0x00, 0x00,
+        0x03, // IS_FRAGMENT | FRAG_IS_FIRST in big endian
+        0x08, 0x00, // length 8
+        0x30, 0x00, // TCA_FLOWER_KEY_FLAGS_MASK
+        0x00, 0x00, 0x00,
+        0x03, // IS_FRAGMENT | FRAG_IS_FIRST in big endian
+    ];
+
+    let flags = TcFlowerKeyFlags::IsFragment | TcFlowerKeyFlags::FragIsFirst;
+
+    let expected = TcMessage {
+        header: TcHeader {
+            family: AddressFamily::Unspec,
+            index: 3,
+            handle: TcHandle { major: 0, minor: 0 },
+            parent: TcHandle {
+                major: 0xffff,
+                minor: 0xfff1,
+            },
+            info: 0,
+        },
+        attributes: vec![
+            TcAttribute::Kind("flower".to_string()),
+            TcAttribute::Options(vec![
+                TcOption::Flower(TcFilterFlowerOption::KeyFlags(flags)),
+                TcOption::Flower(TcFilterFlowerOption::KeyFlagsMask(flags)),
+            ]),
+        ],
+    };
+
+    assert_eq!(
+        expected,
+        TcMessage::parse(&TcMessageBuffer::new(&raw)).unwrap()
+    );
+
+    let mut buf = vec![0; expected.buffer_len()];
+
+    expected.emit(&mut buf);
+
+    assert_eq!(buf, raw);
+
+    assert_eq!(flags.to_string(), "IsFragment | FragIsFirst");
+}
+
+// RTM_NEWTFILTER request(rtnetlink header removed) for matching ERSPAN
+// version 2 tunnel options, equivalent to the flower options of:
+//
+//      tc filter add dev erspan1 ingress flower \
+//          erspan_opts 2:0:1:7/255:0:1:63
+#[test]
+fn test_flower_erspan_v2_opts() {
+    let raw = vec![
+        0x00, // AF_UNSPEC
+        0x00, 0x00, 0x00, // padding
+        0x03, 0x00, 0x00, 0x00, // iface index 3
+        0x00, 0x00, 0x00, 0x00, // handle 0:0
+        0xf1, 0xff, 0xff, 0xff, // parent ffff:fff1
+        0x00, 0x00, 0x00, 0x00, // info 0
+        0x0b, 0x00, // length 11
+        0x01, 0x00, // TCA_KIND
+        0x66, 0x6c, 0x6f, 0x77, 0x65, 0x72, 0x00, 0x00,
+        // "flower\0" and 1 padding byte
+        0x44, 0x00, // length 68
+        0x02, 0x00, // TCA_OPTIONS for `flower`
+        0x20, 0x00, // length 32
+        0x54, 0x80, // TCA_FLOWER_KEY_ENC_OPTS | NLA_F_NESTED
+        0x1c, 0x00, // length 28
+        0x03, 0x80, // TCA_FLOWER_KEY_ENC_OPTS_ERSPAN | NLA_F_NESTED
+        0x05, 0x00, // length 5
+        0x01, 0x00, // TCA_FLOWER_KEY_ENC_OPT_ERSPAN_VER
+        0x02, 0x00, 0x00, 0x00, // version 2 and padding
+        0x05, 0x00, // length 5
+        0x03, 0x00, // TCA_FLOWER_KEY_ENC_OPT_ERSPAN_DIR
+        0x01, 0x00, 0x00, 0x00, // egress and padding
+        0x05, 0x00, // length 5
+        0x04, 0x00, // TCA_FLOWER_KEY_ENC_OPT_ERSPAN_HWID
+        0x07, 0x00, 0x00, 0x00, // 7 and padding
+        0x20, 0x00, // length 32
+        0x55, 0x80, // TCA_FLOWER_KEY_ENC_OPTS_MASK | NLA_F_NESTED
+        0x1c, 0x00, // length 28
+        0x03, 0x80, // TCA_FLOWER_KEY_ENC_OPTS_ERSPAN | NLA_F_NESTED
+        0x05, 0x00, // length 5
+        0x01, 0x00, // TCA_FLOWER_KEY_ENC_OPT_ERSPAN_VER
+        0xff, 0x00, 0x00, 0x00, // 255 and padding
+        0x05, 0x00, // length 5
+        0x03, 0x00, // TCA_FLOWER_KEY_ENC_OPT_ERSPAN_DIR
+        0x01, 0x00, 0x00, 0x00, // 1 and padding
+        0x05, 0x00, // length 5
+        0x04, 0x00, // TCA_FLOWER_KEY_ENC_OPT_ERSPAN_HWID
+        0x3f, 0x00, 0x00, 0x00, // 63 and padding
+    ];
+
+    let key = vec![
+        TcFlowerEncOptErspan::Version(2),
+        TcFlowerEncOptErspan::Direction(ErspanDirection::Egress),
+        TcFlowerEncOptErspan::HwId(ErspanHwId::new(7).unwrap()),
+    ];
+    let mask = vec![
+        TcFlowerEncOptErspan::Version(0xff),
+        TcFlowerEncOptErspan::Direction(ErspanDirection::Egress),
+        TcFlowerEncOptErspan::HwId(ErspanHwId::new(ErspanHwId::MAX).unwrap()),
+    ];
+
+    let expected = TcMessage {
+        header: TcHeader {
+            family: AddressFamily::Unspec,
+            index: 3,
+            handle: TcHandle { major: 0, minor: 0 },
+            parent: TcHandle {
+                major: 0xffff,
+                minor: 0xfff1,
+            },
+            info: 0,
+        },
+        attributes: vec![
+            TcAttribute::Kind("flower".to_string()),
+            TcAttribute::Options(vec![
+                TcOption::Flower(TcFilterFlowerOption::KeyEncOpts(vec![
+                    TcFlowerEncOpt::Erspan(key.clone()),
+                ])),
+                TcOption::Flower(TcFilterFlowerOption::KeyEncOptsMask(vec![
+                    TcFlowerEncOpt::Erspan(mask),
+                ])),
+            ]),
+        ],
+    };
+
+    assert_eq!(
+        expected,
+        TcMessage::parse(&TcMessageBuffer::new(&raw)).unwrap()
+    );
+
+    let mut buf = vec![0; expected.buffer_len()];
+
+    expected.emit(&mut buf);
+
+    assert_eq!(buf, raw);
+
+    assert_eq!(
+        key.iter()
+            .map(|opt| opt.to_string())
+            .collect::<Vec<String>>()
+            .join(" "),
+        "ver 2 dir egress hwid 7"
+    );
+    assert!(ErspanHwId::new(ErspanHwId::MAX + 1).is_err());
+}
+
+// Emit every flower option through `TcOption`, which checks in debug builds
+// that each of them fills exactly its `value_len()`, then make sure they
+// survive a round trip.
+#[test]
+fn test_flower_all_options_value_len() {
+    let erspan = vec![TcFlowerEncOpt::Erspan(vec![
+        TcFlowerEncOptErspan::Version(2),
+        TcFlowerEncOptErspan::Index(0x12345678),
+        TcFlowerEncOptErspan::Direction(ErspanDirection::Egress),
+        TcFlowerEncOptErspan::HwId(ErspanHwId::new(7).unwrap()),
+    ])];
+    let options = vec![
+        TcFilterFlowerOption::ClassId(TcHandle { major: 1, minor: 2 }),
+        TcFilterFlowerOption::Indev("eth0".to_string()),
+        TcFilterFlowerOption::Action(vec![TcAction {
+            tab: 1,
+            attributes: vec![TcActionAttribute::Kind("gact".to_string())],
+        }]),
+        TcFilterFlowerOption::Flags(0x8),
+        TcFilterFlowerOption::KeyEthDst([0x00, 0x23, 0x45, 0x67, 0x89, 0x1c]),
+        TcFilterFlowerOption::KeyEthDstMask([0xff; 6]),
+        TcFilterFlowerOption::KeyEthSrc([0x02, 0x00, 0x00, 0x00, 0x00, 0x01]),
+        TcFilterFlowerOption::KeyEthSrcMask([0xff, 0xff, 0xff, 0, 0, 0]),
+        TcFilterFlowerOption::KeyEthType(Ethertype::IPV4),
+        TcFilterFlowerOption::KeyIpProto(IpProtocol::Tcp),
+        TcFilterFlowerOption::KeyIpv4Src(Ipv4Addr::new(192, 0, 2, 1)),
+        TcFilterFlowerOption::KeyIpv4SrcMask(Ipv4Addr::new(255, 255, 255, 0)),
+        TcFilterFlowerOption::KeyIpv4Dst(Ipv4Addr::new(198, 51, 100, 2)),
+        TcFilterFlowerOption::KeyIpv4DstMask(Ipv4Addr::BROADCAST),
+        TcFilterFlowerOption::KeyTcpSrc(54321),
+        TcFilterFlowerOption::KeyTcpDst(80),
+        TcFilterFlowerOption::KeyUdpSrc(5353),
+        TcFilterFlowerOption::KeyUdpDst(53),
+        TcFilterFlowerOption::KeyArpOp(
+            u8::try_from(arp::Operation::Reply).unwrap(),
+        ),
+        TcFilterFlowerOption::KeyArpOpMask(0xff),
+        TcFilterFlowerOption::KeyMplsTtl(64),
+        TcFilterFlowerOption::KeyMplsBos(1),
+        TcFilterFlowerOption::KeyMplsTc(3),
+        TcFilterFlowerOption::KeyMplsLabel(0xfffff),
+        TcFilterFlowerOption::KeyFlags(TcFlowerKeyFlags::FragIsFirst),
+        TcFilterFlowerOption::KeyFlagsMask(
+            TcFlowerKeyFlags::FragIsFirst | TcFlowerKeyFlags::IsFragment,
+        ),
+        TcFilterFlowerOption::KeyEncOpts(erspan.clone()),
+        TcFilterFlowerOption::KeyEncOptsMask(erspan),
+        TcFilterFlowerOption::KeyPortSrcMin(1024),
+        TcFilterFlowerOption::KeyPortSrcMax(65535),
+        TcFilterFlowerOption::KeyPortDstMin(1000),
+        TcFilterFlowerOption::KeyPortDstMax(2000),
+        TcFilterFlowerOption::Other(DefaultNla::new(0xfff, vec![0xff; 3])),
+    ];
+
+    let expected = TcMessage {
+        header: TcHeader {
+            family: AddressFamily::Unspec,
+            index: 3,
+            parent: TcHandle::INGRESS,
+            ..Default::default()
+        },
+        attributes: vec![
+            TcAttribute::Kind("flower".to_string()),
+            TcAttribute::Options(
+                options.into_iter().map(TcOption::Flower).collect(),
+            ),
+        ],
+    };
+
+    let mut buf = vec![0; expected.buffer_len()];
+    expected.emit(&mut buf);
+
+    assert_eq!(
+        expected,
+        TcMessage::parse(&TcMessageBuffer::new(&buf)).unwrap()
+    );
+}
+
+// Hand-crafted RTM_NEWTFILTER request(rtnetlink header removed) equivalent to
+// the flower options of:
+//
+//      tc filter add dev dummy1 ingress protocol ip flower ip_proto tcp \
+//          src_ip 192.0.2.1 dst_ip 198.51.100.2 src_port 54321 dst_port 80
+#[test]
+fn test_flower_tcp_five_tuple() {
+    let raw = vec![
+        0x00, // AF_UNSPEC
+        0x00, 0x00, 0x00, // padding
+        0x03, 0x00, 0x00, 0x00, // iface index 3
+        0x00, 0x00, 0x00, 0x00, // handle 0:0
+        0xf1, 0xff, 0xff, 0xff, // parent ffff:fff1
+        0x00, 0x00, 0x00, 0x00, // info 0
+        0x0b, 0x00, // length 11
+        0x01, 0x00, // TCA_KIND
+        0x66, 0x6c, 0x6f, 0x77, 0x65, 0x72, 0x00, 0x00,
+        // "flower\0" and 1 padding byte
+        0x44, 0x00, // length 68
+        0x02, 0x00, // TCA_OPTIONS for `flower`
+        0x06, 0x00, // length 6
+        0x08, 0x00, // TCA_FLOWER_KEY_ETH_TYPE
+        0x08, 0x00, 0x00, 0x00, // ETH_P_IP in big endian and padding
+        0x05, 0x00, // length 5
+        0x09, 0x00, // TCA_FLOWER_KEY_IP_PROTO
+        0x06, 0x00, 0x00, 0x00, // IPPROTO_TCP and padding
+        0x08, 0x00, // length 8
+        0x0a, 0x00, // TCA_FLOWER_KEY_IPV4_SRC
+        0xc0, 0x00, 0x02, 0x01, // 192.0.2.1
+        0x08, 0x00, // length 8
+        0x0b, 0x00, // TCA_FLOWER_KEY_IPV4_SRC_MASK
+        0xff, 0xff, 0xff, 0xff, // 255.255.255.255
+        0x08, 0x00, // length 8
+        0x0c, 0x00, // TCA_FLOWER_KEY_IPV4_DST
+        0xc6, 0x33, 0x64, 0x02, // 198.51.100.2
+        0x08, 0x00, // length 8
+        0x0d, 0x00, // TCA_FLOWER_KEY_IPV4_DST_MASK
+        0xff, 0xff, 0xff, 0xff, // 255.255.255.255
+        0x06, 0x00, // length 6
+        0x12, 0x00, // TCA_FLOWER_KEY_TCP_SRC
+        0xd4, 0x31, 0x00, 0x00, // 54321 in big endian and padding
+        0x06, 0x00, // length 6
+        0x13, 0x00, // TCA_FLOWER_KEY_TCP_DST
+        0x00, 0x50, 0x00, 0x00, // 80 in big endian and padding
+    ];
+
+    let src_ip = Ipv4Addr::new(192, 0, 2, 1);
+    let dst_ip = Ipv4Addr::new(198, 51, 100, 2);
+    let options = TcFilterFlower::from_five_tuple(
+        IpProtocol::Tcp,
+        src_ip,
+        dst_ip,
+        54321,
+        80,
+    );
+
+    let expected = TcMessage {
+        header: TcHeader {
+            family: AddressFamily::Unspec,
+            index: 3,
+            parent: TcHandle::INGRESS,
+            ..Default::default()
+        },
+        attributes: vec![
+            TcAttribute::Kind("flower".to_string()),
+            TcAttribute::Options(
+                options.iter().cloned().map(TcOption::Flower).collect(),
+            ),
+        ],
+    };
+
+    assert_eq!(
+        expected,
+        TcMessage::parse(&TcMessageBuffer::new(&raw)).unwrap()
+    );
+
+    let mut buf = vec![0; expected.buffer_len()];
+    expected.emit(&mut buf);
+    assert_eq!(buf, raw);
+
+    assert_eq!(
+        TcFilterFlower::to_five_tuple(&options),
+        Some((IpProtocol::Tcp, src_ip, dst_ip, 54321, 80))
+    );
+}
+
+#[test]
+fn test_flower_udp_five_tuple() {
+    let src_ip = Ipv4Addr::new(192, 0, 2, 1);
+    let dst_ip = Ipv4Addr::new(198, 51, 100, 53);
+    let mut options = TcFilterFlower::from_five_tuple(
+        IpProtocol::Udp,
+        src_ip,
+        dst_ip,
+        5353,
+        53,
+    );
+
+    assert_eq!(
+        options,
+        vec![
+            TcFilterFlowerOption::KeyEthType(Ethertype::IPV4),
+            TcFilterFlowerOption::KeyIpProto(IpProtocol::Udp),
+            TcFilterFlowerOption::KeyIpv4Src(src_ip),
+            TcFilterFlowerOption::KeyIpv4SrcMask(Ipv4Addr::BROADCAST),
+            TcFilterFlowerOption::KeyIpv4Dst(dst_ip),
+            TcFilterFlowerOption::KeyIpv4DstMask(Ipv4Addr::BROADCAST),
+            TcFilterFlowerOption::KeyUdpSrc(5353),
+            TcFilterFlowerOption::KeyUdpDst(53),
+        ]
+    );
+    assert_eq!(
+        TcFilterFlower::to_five_tuple(&options),
+        Some((IpProtocol::Udp, src_ip, dst_ip, 5353, 53))
+    );
+
+    // Subnet match is not a 5-tuple
+    options[3] =
+        TcFilterFlowerOption::KeyIpv4SrcMask(Ipv4Addr::new(255, 255, 255, 0));
+    assert_eq!(TcFilterFlower::to_five_tuple(&options), None);
+
+    // Missing destination port
+    options.truncate(7);
+    options[3] = TcFilterFlowerOption::KeyIpv4SrcMask(Ipv4Addr::BROADCAST);
+    assert_eq!(TcFilterFlower::to_five_tuple(&options), None);
+}
+
+// Hand-crafted RTM_NEWTFILTER request(rtnetlink header removed) equivalent to
+// the flower options of:
+//
+//      tc filter add dev dummy1 ingress protocol mpls_uc flower \
+//          mpls_label 12345 mpls_bos 1
+//
+// The MPLS label is in native endian unlike other flower keys, the fixture
+// is built by `to_ne_bytes()` so it holds on both little and big endian
+// hosts.
+#[test]
+fn test_flower_mpls_label() {
+    let label: u32 = 12345;
+    let mut raw = vec![
+        0x00, // AF_UNSPEC
+        0x00, 0x00, 0x00, // padding
+        0x03, 0x00, 0x00, 0x00, // iface index 3
+        0x00, 0x00, 0x00, 0x00, // handle 0:0
+        0xf1, 0xff, 0xff, 0xff, // parent ffff:fff1
+        0x00, 0x00, 0x00, 0x00, // info 0
+        0x0b, 0x00, // length 11
+        0x01, 0x00, // TCA_KIND
+        0x66, 0x6c, 0x6f, 0x77, 0x65, 0x72, 0x00, 0x00,
+        // "flower\0" and 1 padding byte
+        0x14, 0x00, // length 20
+        0x02, 0x00, // TCA_OPTIONS for `flower`
+        0x05, 0x00, // length 5
+        0x44, 0x00, // TCA_FLOWER_KEY_MPLS_BOS
+        0x01, 0x00, 0x00, 0x00, // 1 and padding
+        0x08, 0x00, // length 8
+        0x46, 0x00, // TCA_FLOWER_KEY_MPLS_LABEL
+    ];
+    raw.extend_from_slice(&label.to_ne_bytes());
+
+    let expected = TcMessage {
+        header: TcHeader {
+            family: AddressFamily::Unspec,
+            index: 3,
+            parent: TcHandle::INGRESS,
+            ..Default::default()
+        },
+        attributes: vec![
+            TcAttribute::Kind("flower".to_string()),
+            TcAttribute::Options(vec![
+                TcOption::Flower(TcFilterFlowerOption::KeyMplsBos(1)),
+                TcOption::Flower(TcFilterFlowerOption::KeyMplsLabel(label)),
+            ]),
+        ],
+    };
+
+    let mut buf = vec![0; expected.buffer_len()];
+    expected.emit(&mut buf);
+    assert_eq!(buf, raw);
+
+    assert_eq!(
+        expected,
+        TcMessage::parse(&TcMessageBuffer::new(&raw)).unwrap()
+    );
+}
+
+// Hand-crafted flower options equivalent to
+//
+//      tc filter add dev dummy1 ingress flower \
+//          dst_mac 00:23:45:67:89:1c src_mac 02:00:00:00:00:00/24
+#[test]
+fn test_flower_mac_keys() {
+    let raw = vec![
+        0x0a, 0x00, // length 10
+        0x04, 0x00, // TCA_FLOWER_KEY_ETH_DST
+        0x00, 0x23, 0x45, 0x67, 0x89, 0x1c, // 00:23:45:67:89:1c
+        0x00, 0x00, // padding
+        0x0a, 0x00, // length 10
+        0x05, 0x00, // TCA_FLOWER_KEY_ETH_DST_MASK
+        0xff, 0xff, 0xff, 0xff, 0xff, 0xff, // ff:ff:ff:ff:ff:ff
+        0x00, 0x00, // padding
+        0x0a, 0x00, // length 10
+        0x06, 0x00, // TCA_FLOWER_KEY_ETH_SRC
+        0x02, 0x00, 0x00, 0x00, 0x00, 0x00, // 02:00:00:00:00:00
+        0x00, 0x00, // padding
+        0x0a, 0x00, // length 10
+        0x07, 0x00, // TCA_FLOWER_KEY_ETH_SRC_MASK
+        0xff, 0xff, 0xff, 0x00, 0x00, 0x00, // ff:ff:ff:00:00:00
+        0x00, 0x00, // padding
+    ];
+    let expected = vec![
+        TcFilterFlowerOption::KeyEthDst([0x00, 0x23, 0x45, 0x67, 0x89, 0x1c]),
+        TcFilterFlowerOption::KeyEthDstMask([0xff; 6]),
+        TcFilterFlowerOption::KeyEthSrc([0x02, 0, 0, 0, 0, 0]),
+        TcFilterFlowerOption::KeyEthSrcMask([0xff, 0xff, 0xff, 0, 0, 0]),
+    ];
+
+    let parsed = NlasIterator::new(&raw)
+        .map(|nla| TcFilterFlowerOption::parse(&nla.unwrap()).unwrap())
+        .collect::<Vec<_>>();
+    assert_eq!(parsed, expected);
+
+    let mut buf = vec![0; expected.as_slice().buffer_len()];
+    expected.as_slice().emit(&mut buf);
+    assert_eq!(buf, raw);
+
+    // MAC address should hold exactly 6 bytes
+    let mut short = raw[..12].to_vec();
+    short[0] = 0x09;
+    assert!(TcFilterFlowerOption::parse(&NlaBuffer::new(&short)).is_err());
+    let mut long = raw[..12].to_vec();
+    long[0] = 0x0b;
+    assert!(TcFilterFlowerOption::parse(&NlaBuffer::new(&long)).is_err());
+}
+
+// Hand-crafted flower options equivalent to
+//
+//      tc filter add dev dummy1 ingress protocol arp flower arp_op reply
+#[test]
+fn test_flower_arp_op() {
+    let raw = vec![
+        0x06, 0x00, // length 6
+        0x08, 0x00, // TCA_FLOWER_KEY_ETH_TYPE
+        0x08, 0x06, 0x00, 0x00, // ETH_P_ARP in big endian and padding
+        0x05, 0x00, // length 5
+        0x3d, 0x00, // TCA_FLOWER_KEY_ARP_OP
+        0x02, 0x00, 0x00, 0x00, // ARPOP_REPLY and padding
+        0x05, 0x00, // length 5
+        0x3e, 0x00, // TCA_FLOWER_KEY_ARP_OP_MASK
+        0xff, 0x00, 0x00, 0x00, // 0xff and padding
+    ];
+    let expected = vec![
+        TcFilterFlowerOption::KeyEthType(Ethertype::ARP),
+        TcFilterFlowerOption::KeyArpOp(2),
+        TcFilterFlowerOption::KeyArpOpMask(0xff),
+    ];
+
+    let parsed = NlasIterator::new(&raw)
+        .map(|nla| TcFilterFlowerOption::parse(&nla.unwrap()).unwrap())
+        .collect::<Vec<_>>();
+    assert_eq!(parsed, expected);
+    assert_eq!(arp::Operation::from(2u8), arp::Operation::Reply);
+
+    let mut buf = vec![0; expected.as_slice().buffer_len()];
+    expected.as_slice().emit(&mut buf);
+    assert_eq!(buf, raw);
+}
+
+// Hand-crafted flower options equivalent to
+//
+//      tc filter add dev dummy1 ingress protocol ip flower ip_proto sctp \
+//          src_port 5000 dst_port 6000
+//
+// The SCTP ports are not modeled yet and should be kept as
+// `TcFilterFlowerOption::Other`.
+#[test]
+fn test_flower_sctp_ports_other() {
+    let raw = vec![
+        0x06, 0x00, // length 6
+        0x29, 0x00, // TCA_FLOWER_KEY_SCTP_SRC
+        0x13, 0x88, 0x00, 0x00, // 5000 in big endian and padding
+        0x06, 0x00, // length 6
+        0x2a, 0x00, // TCA_FLOWER_KEY_SCTP_DST
+        0x17, 0x70, 0x00, 0x00, // 6000 in big endian and padding
+    ];
+    let expected = vec![
+        TcFilterFlowerOption::Other(DefaultNla::new(41, vec![0x13, 0x88])),
+        TcFilterFlowerOption::Other(DefaultNla::new(42, vec![0x17, 0x70])),
+    ];
+
+    let parsed = NlasIterator::new(&raw)
+        .map(|nla| TcFilterFlowerOption::parse(&nla.unwrap()).unwrap())
+        .collect::<Vec<_>>();
+    assert_eq!(parsed, expected);
+
+    let mut buf = vec![0; expected.as_slice().buffer_len()];
+    expected.as_slice().emit(&mut buf);
+    assert_eq!(buf, raw);
+}
+
+#[test]
+fn test_flower_many_mac_keys() {
+    let expected = (0..4096u16)
+        .map(|i| {
+            let [hi, lo] = i.to_be_bytes();
+            if i % 2 == 0 {
+                TcFilterFlowerOption::KeyEthDst([0x02, 0, 0, 0, hi, lo])
+            } else {
+                TcFilterFlowerOption::KeyEthSrc([0x02, 0, 0, 0, hi, lo])
+            }
+        })
+        .collect::<Vec<_>>();
+    let mut buf = vec![0; expected.as_slice().buffer_len()];
+    expected.as_slice().emit(&mut buf);
+    // Each key is 4 bytes header, 6 bytes MAC and 2 bytes padding
+    assert_eq!(buf.len(), expected.len() * 12);
+
+    let parsed = NlasIterator::new(&buf)
+        .map(|nla| TcFilterFlowerOption::parse(&nla.unwrap()).unwrap())
+        .collect::<Vec<_>>();
+    assert_eq!(parsed, expected);
+}
+
+// Hand-crafted flower options equivalent to
+//
+//      tc filter add dev dummy1 ingress protocol ip flower ip_proto tcp \
+//          dst_port 1000-2000
+#[test]
+fn test_flower_dst_port_range() {
+    let raw = vec![
+        0x06, 0x00, // length 6
+        0x08, 0x00, // TCA_FLOWER_KEY_ETH_TYPE
+        0x08, 0x00, 0x00, 0x00, // ETH_P_IP in big endian and padding
+        0x05, 0x00, // length 5
+        0x09, 0x00, // TCA_FLOWER_KEY_IP_PROTO
+        0x06, 0x00, 0x00, 0x00, // IPPROTO_TCP and padding
+        0x06, 0x00, // length 6
+        0x59, 0x00, // TCA_FLOWER_KEY_PORT_DST_MIN
+        0x03, 0xe8, 0x00, 0x00, // 1000 in big endian and padding
+        0x06, 0x00, // length 6
+        0x5a, 0x00, // TCA_FLOWER_KEY_PORT_DST_MAX
+        0x07, 0xd0, 0x00, 0x00, // 2000 in big endian and padding
+    ];
+
+    let mut expected = vec![
+        TcFilterFlowerOption::KeyEthType(Ethertype::IPV4),
+        TcFilterFlowerOption::KeyIpProto(IpProtocol::Tcp),
+    ];
+    expected.extend(TcFilterFlower::from_dst_port_range(1000..=2000).unwrap());
+
+    let parsed = NlasIterator::new(&raw)
+        .map(|nla| TcFilterFlowerOption::parse(&nla.unwrap()).unwrap())
+        .collect::<Vec<_>>();
+    assert_eq!(parsed, expected);
+
+    let mut buf = vec![0; expected.as_slice().buffer_len()];
+    expected.as_slice().emit(&mut buf);
+    assert_eq!(buf, raw);
+
+    assert_eq!(TcFilterFlower::dst_port_range(&parsed), Some(1000..=2000));
+    assert_eq!(TcFilterFlower::src_port_range(&parsed), None);
+
+    // Kernel rejects range holding less than two ports
+    assert!(TcFilterFlower::from_dst_port_range(RangeInclusive::new(
+        2000, 1000,
+    ))
+    .is_err());
+    assert!(TcFilterFlower::from_src_port_range(80..=80).is_err());
+}
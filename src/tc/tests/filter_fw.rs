@@ -0,0 +1,69 @@
+// SPDX-License-Identifier: MIT
+
+use netlink_packet_utils::{Emitable, Parseable};
+
+use crate::{
+    tc::{
+        TcAttribute, TcFilterFwOption, TcHandle, TcHeader, TcMessage,
+        TcMessageBuffer, TcOption,
+    },
+    AddressFamily,
+};
+
+// RTM_NEWTFILTER request(rtnetlink header removed) equivalent to:
+//
+//      tc filter add dev dummy1 parent 1: protocol ip prio 49152 \
+//          handle 6/0xff fw classid 1:10
+#[test]
+fn test_fw_filter_classid_mask() {
+    let raw = vec![
+        0x00, // AF_UNSPEC
+        0x00, 0x00, 0x00, // padding
+        0x03, 0x00, 0x00, 0x00, // iface index 3
+        0x06, 0x00, 0x00, 0x00, // handle 0:6, the firewall mark
+        0x00, 0x00, 0x01, 0x00, // parent 1:0
+        0x08, 0x00, 0x00, 0xc0, // info: priority 49152, protocol ETH_P_IP
+        0x07, 0x00, // length 7
+        0x01, 0x00, // TCA_KIND
+        0x66, 0x77, 0x00, 0x00, // "fw\0" and 1 padding byte
+        0x14, 0x00, // length 20
+        0x02, 0x00, // TCA_OPTIONS for `fw`
+        0x08, 0x00, // length 8
+        0x01, 0x00, // TCA_FW_CLASSID
+        0x10, 0x00, 0x01, 0x00, // 1:10
+        0x08, 0x00, // length 8
+        0x05, 0x00, // TCA_FW_MASK
+        0xff, 0x00, 0x00, 0x00, // 0xff
+    ];
+
+    let expected = TcMessage {
+        header: TcHeader {
+            family: AddressFamily::Unspec,
+            index: 3,
+            handle: TcHandle { major: 0, minor: 6 },
+            parent: TcHandle { major: 1, minor: 0 },
+            info: 0xc0000008,
+        },
+        attributes: vec![
+            TcAttribute::Kind("fw".to_string()),
+            TcAttribute::Options(vec![
+                TcOption::Fw(TcFilterFwOption::ClassId(TcHandle {
+                    major: 1,
+                    minor: 0x10,
+                })),
+                TcOption::Fw(TcFilterFwOption::Mask(0xff)),
+            ]),
+        ],
+    };
+
+    assert_eq!(
+        expected,
+        TcMessage::parse(&TcMessageBuffer::new(&raw)).unwrap()
+    );
+
+    let mut buf = vec![0; expected.buffer_len()];
+
+    expected.emit(&mut buf);
+
+    assert_eq!(buf, raw);
+}
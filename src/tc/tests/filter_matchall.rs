@@ -181,3 +181,116 @@ fn test_get_filter_matchall() {
 
     assert_eq!(buf, raw);
 }
+
+// Hand-crafted RTM_NEWTFILTER payload equivalent to the one dumped by kernel
+// for the filter created by
+//      tc filter add dev dummy1 parent ffff: matchall classid 1:1 skip_hw \
+//          action mirred egress redirect dev dummy2
+// with dummy1 holding interface index 50 and dummy2 holding 51.
+// Action statistics and timestamps are omitted.
+#[test]
+fn test_filter_matchall_classid_flags_action() {
+    let raw = vec![
+        0x00, // AF_UNSPEC
+        0x00, 0x00, 0x00, // padding
+        0x32, 0x00, 0x00, 0x00, // iface index 50
+        0x01, 0x00, 0x00, 0x00, // handle 0:1
+        0x00, 0x00, 0xff, 0xff, // parent ffff:0
+        0x00, 0x03, 0x00, 0xc0, // info: protocol all, prio 49152
+        0x0d, 0x00, // length 13
+        0x01, 0x00, // TCA_KIND
+        0x6d, 0x61, 0x74, 0x63, 0x68, 0x61, 0x6c, 0x6c, 0x00, 0x00, 0x00, 0x00,
+        // "matchall\0" and 3 bytes pad
+        0x08, 0x00, // length 8
+        0x0b, 0x00, // TCA_CHAIN
+        0x00, 0x00, 0x00, 0x00, // chain: 0
+        0x58, 0x00, // length 88
+        0x02, 0x00, // TCA_OPTIONS for `matchall`
+        0x08, 0x00, // length 8
+        0x01, 0x00, // TCA_MATCHALL_CLASSID
+        0x01, 0x00, 0x01, 0x00, // 1:1
+        0x08, 0x00, // length 8
+        0x03, 0x00, // TCA_MATCHALL_FLAGS
+        0x09, 0x00, 0x00, 0x00, // TCA_CLS_FLAGS_SKIP_HW | NOT_IN_HW
+        0x0c, 0x00, // length 12
+        0x04, 0x00, // TCA_MATCHALL_PCNT
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, // rhit 0
+        0x38, 0x00, // length 56
+        0x02, 0x00, // TCA_MATCHALL_ACT
+        0x34, 0x00, // length 52
+        0x01, 0x00, // TCA_ACT_TAB
+        0x0b, 0x00, // length 11
+        0x01, 0x00, // TCA_ACT_KIND
+        0x6d, 0x69, 0x72, 0x72, 0x65, 0x64, 0x00, 0x00,
+        // "mirred\0" and 1 padding byte
+        0x24, 0x00, // length 36
+        0x02, 0x80, // TCA_ACT_OPTIONS
+        0x20, 0x00, // length 32
+        0x02, 0x00, // TCA_MIRRED_PARMS
+        0x01, 0x00, 0x00, 0x00, // index 1
+        0x00, 0x00, 0x00, 0x00, // capab 0
+        0x04, 0x00, 0x00, 0x00, // action 4 TC_ACT_STOLEN
+        0x01, 0x00, 0x00, 0x00, // refcount 1
+        0x01, 0x00, 0x00, 0x00, // bindcnt 1
+        0x01, 0x00, 0x00, 0x00, // eaction 1 TCA_EGRESS_REDIR
+        0x33, 0x00, 0x00, 0x00, // ifindex 51
+    ];
+
+    let expected = TcMessage {
+        header: TcHeader {
+            family: AddressFamily::Unspec,
+            index: 50,
+            handle: TcHandle { major: 0, minor: 1 },
+            parent: TcHandle {
+                major: 0xffff,
+                minor: 0,
+            },
+            info: 3221226240,
+        },
+        attributes: vec![
+            TcAttribute::Kind("matchall".to_string()),
+            TcAttribute::Chain(0),
+            TcAttribute::Options(vec![
+                TcOption::MatchAll(TcFilterMatchAllOption::ClassId(TcHandle {
+                    major: 1,
+                    minor: 1,
+                })),
+                TcOption::MatchAll(TcFilterMatchAllOption::Flags(9)),
+                TcOption::MatchAll(TcFilterMatchAllOption::Pnct(vec![0; 8])),
+                TcOption::MatchAll(TcFilterMatchAllOption::Action(vec![
+                    TcAction {
+                        tab: 1,
+                        attributes: vec![
+                            TcActionAttribute::Kind("mirred".to_string()),
+                            TcActionAttribute::Options(vec![
+                                TcActionOption::Mirror(
+                                    TcActionMirrorOption::Parms(TcMirror {
+                                        generic: TcActionGeneric {
+                                            index: 1,
+                                            capab: 0,
+                                            action: TcActionType::Stolen,
+                                            refcnt: 1,
+                                            bindcnt: 1,
+                                        },
+                                        eaction:
+                                            TcMirrorActionType::EgressRedir,
+                                        ifindex: 51,
+                                    }),
+                                ),
+                            ]),
+                        ],
+                    },
+                ])),
+            ]),
+        ],
+    };
+
+    assert_eq!(
+        expected,
+        TcMessage::parse(&TcMessageBuffer::new(&raw)).unwrap()
+    );
+
+    let mut buf = vec![0; expected.buffer_len()];
+    expected.emit(&mut buf);
+    assert_eq!(buf, raw);
+}
@@ -0,0 +1,74 @@
+// SPDX-License-Identifier: MIT
+
+use std::str::FromStr;
+
+use crate::tc::TcHandle;
+
+#[test]
+fn test_tc_handle_from_str() {
+    for (s, handle) in [
+        (
+            "1:10",
+            TcHandle {
+                major: 1,
+                minor: 0x10,
+            },
+        ),
+        (
+            "ffff:",
+            TcHandle {
+                major: 0xffff,
+                minor: 0,
+            },
+        ),
+        (":1", TcHandle { major: 0, minor: 1 }),
+        ("0:0", TcHandle::UNSPEC),
+        ("ffff:fff1", TcHandle::INGRESS),
+        ("root", TcHandle::ROOT),
+        ("none", TcHandle::UNSPEC),
+        ("10001", TcHandle { major: 1, minor: 1 }),
+    ] {
+        assert_eq!(TcHandle::from_str(s).unwrap(), handle);
+    }
+}
+
+#[test]
+fn test_tc_handle_display_round_trip() {
+    for (handle, s) in [
+        (TcHandle::UNSPEC, "0:0"),
+        (TcHandle::INGRESS, "ffff:fff1"),
+        (TcHandle::ROOT, "ffff:ffff"),
+        (
+            TcHandle {
+                major: 1,
+                minor: 0x10,
+            },
+            "1:10",
+        ),
+        (TcHandle::CLSACT_EGRESS, "ffff:fff3"),
+    ] {
+        assert_eq!(handle.to_string(), s);
+        assert_eq!(TcHandle::from_str(s).unwrap(), handle);
+    }
+}
+
+#[test]
+fn test_tc_handle_from_str_invalid() {
+    for s in ["", "1:2:3", "10000:", "g:1", "1:fffff", "100000000"] {
+        assert!(TcHandle::from_str(s).is_err(), "{s} should be invalid");
+    }
+}
+
+#[test]
+fn test_tc_handle_sort() {
+    let mut handles: Vec<TcHandle> = [0xffff0000u32, 0x10001, 0x20000, 0x10000]
+        .into_iter()
+        .map(TcHandle::from)
+        .collect();
+    handles.sort();
+    assert_eq!(
+        handles.into_iter().map(u32::from).collect::<Vec<u32>>(),
+        vec![0x10000, 0x10001, 0x20000, 0xffff0000]
+    );
+    assert!(TcHandle::INGRESS < TcHandle::ROOT);
+}
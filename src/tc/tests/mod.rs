@@ -1,10 +1,42 @@
 // SPDX-License-Identifier: MIT
 
+#[cfg(test)]
+mod builder;
+#[cfg(test)]
+mod class_drr;
+#[cfg(test)]
+mod filter_bpf;
+#[cfg(test)]
+mod filter_flower;
+#[cfg(test)]
+mod filter_fw;
 #[cfg(test)]
 mod filter_matchall;
 #[cfg(test)]
 mod filter_u32;
 #[cfg(test)]
+mod handle;
+#[cfg(test)]
+mod qdisc_cake;
+#[cfg(test)]
+mod qdisc_clsact;
+#[cfg(test)]
+mod qdisc_fifo;
+#[cfg(test)]
+mod qdisc_fq;
+#[cfg(test)]
 mod qdisc_fq_codel;
 #[cfg(test)]
+mod qdisc_fq_pie;
+#[cfg(test)]
 mod qdisc_ingress;
+#[cfg(test)]
+mod qdisc_multiq;
+#[cfg(test)]
+mod qdisc_prio;
+#[cfg(test)]
+mod qdisc_sfq;
+#[cfg(test)]
+mod qdisc_taprio;
+#[cfg(test)]
+mod stats;
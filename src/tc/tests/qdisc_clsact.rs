@@ -0,0 +1,101 @@
+// SPDX-License-Identifier: MIT
+
+use netlink_packet_utils::{Emitable, Parseable};
+
+use crate::{
+    tc::{
+        TcAttribute, TcHandle, TcHeader, TcMessage, TcMessageBuffer,
+        TcQdiscClsact,
+    },
+    AddressFamily,
+};
+
+// Hand-crafted RTM_NEWQDISC payload equivalent to the one generated by
+//      tc qdisc add dev eth0 clsact
+// with eth0 holding interface index 2.
+#[test]
+fn test_add_qdisc_clsact() {
+    let raw = vec![
+        0x00, // AF_UNSPEC
+        0x00, 0x00, 0x00, // padding
+        0x02, 0x00, 0x00, 0x00, // iface index 2
+        0x00, 0x00, 0xff, 0xff, // handle ffff:0
+        0xf1, 0xff, 0xff, 0xff, // parent ffff:fff1
+        0x00, 0x00, 0x00, 0x00, // info
+        0x0b, 0x00, // length 11
+        0x01, 0x00, // TCA_KIND
+        0x63, 0x6c, 0x73, 0x61, 0x63, 0x74, 0x00,
+        0x00,
+        // "clsact\0" and 1 padding byte
+    ];
+
+    let mut message = TcMessage::with_index(2);
+    message.header.handle = TcHandle {
+        major: 0xffff,
+        minor: 0,
+    };
+    message.header.parent = TcHandle::CLSACT;
+    message
+        .attributes
+        .push(TcAttribute::Kind(TcQdiscClsact::KIND.to_string()));
+
+    let mut buf = vec![0; message.buffer_len()];
+    message.emit(&mut buf);
+    assert_eq!(buf, raw);
+
+    assert_eq!(
+        message,
+        TcMessage::parse(&TcMessageBuffer::new(&raw)).unwrap()
+    );
+}
+
+// Hand-crafted RTM_NEWQDISC payload equivalent to the one replied by kernel
+// for
+//      tc qdisc show dev eth0
+// after the clsact qdisc is created. Statistics attributes are omitted.
+#[test]
+fn test_get_qdisc_clsact() {
+    let raw = vec![
+        0x00, // AF_UNSPEC
+        0x00, 0x00, 0x00, // padding
+        0x02, 0x00, 0x00, 0x00, // iface index 2
+        0x00, 0x00, 0xff, 0xff, // handle ffff:0
+        0xf1, 0xff, 0xff, 0xff, // parent ffff:fff1
+        0x01, 0x00, 0x00, 0x00, // info: refcnt 1
+        0x0b, 0x00, // length 11
+        0x01, 0x00, // TCA_KIND
+        0x63, 0x6c, 0x73, 0x61, 0x63, 0x74, 0x00, 0x00,
+        // "clsact\0" and 1 padding byte
+        0x04, 0x00, // length 4
+        0x02, 0x00, // TCA_OPTIONS with no attribute
+    ];
+
+    let expected = TcMessage {
+        header: TcHeader {
+            family: AddressFamily::Unspec,
+            index: 2,
+            handle: TcHandle {
+                major: 0xffff,
+                minor: 0,
+            },
+            parent: TcHandle::CLSACT,
+            info: 1,
+        },
+        attributes: vec![
+            TcAttribute::Kind("clsact".to_string()),
+            TcAttribute::Options(vec![]),
+        ],
+    };
+
+    assert_eq!(
+        expected,
+        TcMessage::parse(&TcMessageBuffer::new(&raw)).unwrap()
+    );
+
+    let mut buf = vec![0; expected.buffer_len()];
+    expected.emit(&mut buf);
+    assert_eq!(buf, raw);
+
+    assert_eq!(u32::from(TcHandle::CLSACT_INGRESS), 0xfffffff2);
+    assert_eq!(u32::from(TcHandle::CLSACT_EGRESS), 0xfffffff3);
+}
@@ -0,0 +1,167 @@
+// SPDX-License-Identifier: MIT
+
+use netlink_packet_utils::{Emitable, Parseable};
+
+use crate::{
+    tc::{
+        TcAttribute, TcHandle, TcHeader, TcMessage, TcMessageBuffer,
+        TcQdiscFifoOption, TcStructOption,
+    },
+    AddressFamily,
+};
+
+// Captured RTM_NEWQDISC request sent by iproute2 6.1 for:
+//
+//      tc qdisc add dev ifb1 root pfifo limit 100
+//
+// with ifb1 holding interface index 3, netlink header removed.
+#[test]
+fn test_add_qdisc_pfifo() {
+    let raw = vec![
+        0x00, // AF_UNSPEC
+        0x00, 0x00, 0x00, // padding
+        0x03, 0x00, 0x00, 0x00, // iface index: 3
+        0x00, 0x00, 0x00, 0x00, // handle 0:0 (TC_H_UNSPEC)
+        0xff, 0xff, 0xff, 0xff, // parent u32::MAX (TC_H_ROOT)
+        0x00, 0x00, 0x00, 0x00, // info: 0
+        0x0a, 0x00, // length 10
+        0x01, 0x00, // TCA_KIND
+        0x70, 0x66, 0x69, 0x66, 0x6f, 0x00, 0x00, 0x00,
+        // "pfifo\0" and 2 bytes pad
+        0x08, 0x00, // length 8
+        0x02, 0x00, // TCA_OPTIONS for `pfifo`
+        0x64, 0x00, 0x00, 0x00, // limit 100
+    ];
+
+    let expected = TcMessage {
+        header: TcHeader {
+            family: AddressFamily::Unspec,
+            index: 3,
+            handle: TcHandle::UNSPEC,
+            parent: TcHandle::ROOT,
+            info: 0,
+        },
+        attributes: vec![
+            TcAttribute::Kind("pfifo".to_string()),
+            TcAttribute::StructOptions(
+                TcStructOption::Fifo(TcQdiscFifoOption { limit: 100 }),
+                vec![],
+            ),
+        ],
+    };
+
+    assert_eq!(
+        expected,
+        TcMessage::parse(&TcMessageBuffer::new(&raw)).unwrap()
+    );
+
+    let mut buf = vec![0; expected.buffer_len()];
+
+    expected.emit(&mut buf);
+
+    assert_eq!(buf, raw);
+}
+
+// Captured RTM_NEWQDISC request sent by iproute2 6.1 for:
+//
+//      tc qdisc add dev ifb1 root bfifo limit 10240
+//
+// with ifb1 holding interface index 3, netlink header removed.
+#[test]
+fn test_add_qdisc_bfifo() {
+    let raw = vec![
+        0x00, // AF_UNSPEC
+        0x00, 0x00, 0x00, // padding
+        0x03, 0x00, 0x00, 0x00, // iface index: 3
+        0x00, 0x00, 0x00, 0x00, // handle 0:0 (TC_H_UNSPEC)
+        0xff, 0xff, 0xff, 0xff, // parent u32::MAX (TC_H_ROOT)
+        0x00, 0x00, 0x00, 0x00, // info: 0
+        0x0a, 0x00, // length 10
+        0x01, 0x00, // TCA_KIND
+        0x62, 0x66, 0x69, 0x66, 0x6f, 0x00, 0x00, 0x00,
+        // "bfifo\0" and 2 bytes pad
+        0x08, 0x00, // length 8
+        0x02, 0x00, // TCA_OPTIONS for `bfifo`
+        0x00, 0x28, 0x00, 0x00, // limit 10240
+    ];
+
+    let expected = TcMessage {
+        header: TcHeader {
+            family: AddressFamily::Unspec,
+            index: 3,
+            handle: TcHandle::UNSPEC,
+            parent: TcHandle::ROOT,
+            info: 0,
+        },
+        attributes: vec![
+            TcAttribute::Kind("bfifo".to_string()),
+            TcAttribute::StructOptions(
+                TcStructOption::Fifo(TcQdiscFifoOption { limit: 10240 }),
+                vec![],
+            ),
+        ],
+    };
+
+    assert_eq!(
+        expected,
+        TcMessage::parse(&TcMessageBuffer::new(&raw)).unwrap()
+    );
+
+    let mut buf = vec![0; expected.buffer_len()];
+
+    expected.emit(&mut buf);
+
+    assert_eq!(buf, raw);
+}
+
+// Captured RTM_NEWQDISC request sent by iproute2 6.1 for:
+//
+//      tc qdisc add dev ifb1 root pfifo_head_drop limit 50
+//
+// with ifb1 holding interface index 3, netlink header removed.
+#[test]
+fn test_add_qdisc_pfifo_head_drop() {
+    let raw = vec![
+        0x00, // AF_UNSPEC
+        0x00, 0x00, 0x00, // padding
+        0x03, 0x00, 0x00, 0x00, // iface index: 3
+        0x00, 0x00, 0x00, 0x00, // handle 0:0 (TC_H_UNSPEC)
+        0xff, 0xff, 0xff, 0xff, // parent u32::MAX (TC_H_ROOT)
+        0x00, 0x00, 0x00, 0x00, // info: 0
+        0x14, 0x00, // length 20
+        0x01, 0x00, // TCA_KIND
+        0x70, 0x66, 0x69, 0x66, 0x6f, 0x5f, 0x68, 0x65, 0x61, 0x64, 0x5f, 0x64,
+        0x72, 0x6f, 0x70, 0x00, // "pfifo_head_drop\0"
+        0x08, 0x00, // length 8
+        0x02, 0x00, // TCA_OPTIONS for `pfifo_head_drop`
+        0x32, 0x00, 0x00, 0x00, // limit 50
+    ];
+
+    let expected = TcMessage {
+        header: TcHeader {
+            family: AddressFamily::Unspec,
+            index: 3,
+            handle: TcHandle::UNSPEC,
+            parent: TcHandle::ROOT,
+            info: 0,
+        },
+        attributes: vec![
+            TcAttribute::Kind("pfifo_head_drop".to_string()),
+            TcAttribute::StructOptions(
+                TcStructOption::Fifo(TcQdiscFifoOption { limit: 50 }),
+                vec![],
+            ),
+        ],
+    };
+
+    assert_eq!(
+        expected,
+        TcMessage::parse(&TcMessageBuffer::new(&raw)).unwrap()
+    );
+
+    let mut buf = vec![0; expected.buffer_len()];
+
+    expected.emit(&mut buf);
+
+    assert_eq!(buf, raw);
+}
@@ -0,0 +1,237 @@
+// SPDX-License-Identifier: MIT
+
+use netlink_packet_utils::{Emitable, Parseable};
+
+use crate::{
+    tc::{
+        TcAttribute, TcFqQdStats, TcHandle, TcHeader, TcMessage,
+        TcMessageBuffer, TcOption, TcQdiscFqOption, TcXstats,
+    },
+    AddressFamily,
+};
+
+// Captured RTM_NEWQDISC request sent by iproute2 6.1 for:
+//
+//      tc qdisc add dev ifb1 root fq limit 10000 flow_limit 100 \
+//          quantum 3028 initial_quantum 15140 maxrate 1gbit buckets 1024 \
+//          horizon 2s
+//
+// with ifb1 holding interface index 3, netlink header removed.
+#[test]
+fn test_add_qdisc_fq() {
+    let raw = vec![
+        0x00, // AF_UNSPEC
+        0x00, 0x00, 0x00, // padding
+        0x03, 0x00, 0x00, 0x00, // iface index: 3
+        0x00, 0x00, 0x00, 0x00, // handle 0:0 (TC_H_UNSPEC)
+        0xff, 0xff, 0xff, 0xff, // parent u32::MAX (TC_H_ROOT)
+        0x00, 0x00, 0x00, 0x00, // info: 0
+        0x07, 0x00, // length 7
+        0x01, 0x00, // TCA_KIND
+        0x66, 0x71, 0x00, 0x00, // "fq\0" and 1 byte pad
+        0x3c, 0x00, // length 60
+        0x02, 0x00, // TCA_OPTIONS for `fq`
+        0x08, 0x00, // length 8
+        0x08, 0x00, // TCA_FQ_BUCKETS_LOG
+        0x0a, 0x00, 0x00, 0x00, // 10
+        0x08, 0x00, // length 8
+        0x01, 0x00, // TCA_FQ_PLIMIT
+        0x10, 0x27, 0x00, 0x00, // 10000
+        0x08, 0x00, // length 8
+        0x02, 0x00, // TCA_FQ_FLOW_PLIMIT
+        0x64, 0x00, 0x00, 0x00, // 100
+        0x08, 0x00, // length 8
+        0x03, 0x00, // TCA_FQ_QUANTUM
+        0xd4, 0x0b, 0x00, 0x00, // 3028
+        0x08, 0x00, // length 8
+        0x04, 0x00, // TCA_FQ_INITIAL_QUANTUM
+        0x24, 0x3b, 0x00, 0x00, // 15140
+        0x08, 0x00, // length 8
+        0x07, 0x00, // TCA_FQ_FLOW_MAX_RATE
+        0x40, 0x59, 0x73, 0x07, // 125000000
+        0x08, 0x00, // length 8
+        0x0e, 0x00, // TCA_FQ_HORIZON
+        0x80, 0x84, 0x1e, 0x00, // 2000000
+    ];
+
+    let expected = TcMessage {
+        header: TcHeader {
+            family: AddressFamily::Unspec,
+            index: 3,
+            handle: TcHandle::UNSPEC,
+            parent: TcHandle::ROOT,
+            info: 0,
+        },
+        attributes: vec![
+            TcAttribute::Kind("fq".to_string()),
+            TcAttribute::Options(vec![
+                TcOption::Fq(TcQdiscFqOption::BucketsLog(10)),
+                TcOption::Fq(TcQdiscFqOption::Plimit(10000)),
+                TcOption::Fq(TcQdiscFqOption::FlowPlimit(100)),
+                TcOption::Fq(TcQdiscFqOption::Quantum(3028)),
+                TcOption::Fq(TcQdiscFqOption::InitialQuantum(15140)),
+                TcOption::Fq(TcQdiscFqOption::FlowMaxRate(125000000)),
+                TcOption::Fq(TcQdiscFqOption::Horizon(2000000)),
+            ]),
+        ],
+    };
+
+    assert_eq!(
+        expected,
+        TcMessage::parse(&TcMessageBuffer::new(&raw)).unwrap()
+    );
+
+    let mut buf = vec![0; expected.buffer_len()];
+
+    expected.emit(&mut buf);
+
+    assert_eq!(buf, raw);
+}
+
+// Hand-crafted RTM_NEWQDISC reply payload holding the fq qdisc xstats of
+// eth0 with the unrelated attributes removed. Not a capture: the kernel of
+// the capture host has no sch_fq, so the layout follows `struct
+// tc_fq_qd_stats` of kernel 6.7 instead.
+#[test]
+fn test_get_qdisc_fq_xstats() {
+    let raw = vec![
+        0x00, // AF_UNSPEC
+        0x00, 0x00, 0x00, // padding
+        0x02, 0x00, 0x00, 0x00, // iface index: 2
+        0x00, 0x00, 0x01, 0x80, // handle 8001:0
+        0xff, 0xff, 0xff, 0xff, // parent u32::MAX (TC_H_ROOT)
+        0x02, 0x00, 0x00, 0x00, // info(refcount): 2
+        0x07, 0x00, // length 7
+        0x01, 0x00, // TCA_KIND
+        0x66, 0x71, 0x00, 0x00, // "fq\0" and 1 byte pad
+        0x9c, 0x00, // length 156
+        0x04, 0x00, // TCA_XSTATS
+        0x03, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, // gc_flows: 3
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, // highprio_packets
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, // tcp_retrans
+        0x05, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, // throttled: 5
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, // flows_plimit
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, // pkts_too_long
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, // allocation_errors
+        0x40, 0x42, 0x0f, 0x00, 0x00, 0x00, 0x00, 0x00,
+        // time_next_delayed_flow: 1000000
+        0x0c, 0x00, 0x00, 0x00, // flows: 12
+        0x0a, 0x00, 0x00, 0x00, // inactive_flows: 10
+        0x01, 0x00, 0x00, 0x00, // throttled_flows: 1
+        0x29, 0x09, 0x00, 0x00, // unthrottle_latency_ns: 2345
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, // ce_mark
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, // horizon_drops
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, // horizon_caps
+        0xe8, 0x03, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        // fastpath_packets: 1000
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, // band_drops[0]
+        0x02, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, // band_drops[1]: 2
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, // band_drops[2]
+        0x00, 0x00, 0x00, 0x00, // band_pkt_count[0]
+        0x04, 0x00, 0x00, 0x00, // band_pkt_count[1]: 4
+        0x00, 0x00, 0x00, 0x00, // band_pkt_count[2]
+        0x00, 0x00, 0x00, 0x00, // pad
+    ];
+
+    let expected = TcMessage {
+        header: TcHeader {
+            family: AddressFamily::Unspec,
+            index: 2,
+            handle: TcHandle {
+                major: 0x8001,
+                minor: 0,
+            },
+            parent: TcHandle::ROOT,
+            info: 2,
+        },
+        attributes: vec![
+            TcAttribute::Kind("fq".to_string()),
+            TcAttribute::Xstats(TcXstats::Fq(TcFqQdStats {
+                gc_flows: 3,
+                throttled: 5,
+                time_next_delayed_flow: 1000000,
+                flows: 12,
+                inactive_flows: 10,
+                throttled_flows: 1,
+                unthrottle_latency_ns: 2345,
+                fastpath_packets: 1000,
+                band_drops: [0, 2, 0],
+                band_pkt_count: [0, 4, 0],
+                ..Default::default()
+            })),
+        ],
+    };
+
+    assert_eq!(
+        expected,
+        TcMessage::parse(&TcMessageBuffer::new(&raw)).unwrap()
+    );
+
+    let mut buf = vec![0; expected.buffer_len()];
+
+    expected.emit(&mut buf);
+
+    assert_eq!(buf, raw);
+}
+
+// The xstats of kernel older than 6.7 does not include the band statistics
+#[test]
+fn test_get_qdisc_fq_xstats_legacy() {
+    let mut raw = vec![
+        0x00, // AF_UNSPEC
+        0x00, 0x00, 0x00, // padding
+        0x02, 0x00, 0x00, 0x00, // iface index: 2
+        0x00, 0x00, 0x01, 0x80, // handle 8001:0
+        0xff, 0xff, 0xff, 0xff, // parent u32::MAX (TC_H_ROOT)
+        0x02, 0x00, 0x00, 0x00, // info(refcount): 2
+        0x07, 0x00, // length 7
+        0x01, 0x00, // TCA_KIND
+        0x66, 0x71, 0x00, 0x00, // "fq\0" and 1 byte pad
+        0x74, 0x00, // length 116
+        0x04, 0x00, // TCA_XSTATS
+    ];
+    raw.extend_from_slice(&[0u8; 112]);
+
+    let message = TcMessage::parse(&TcMessageBuffer::new(&raw)).unwrap();
+    assert_eq!(
+        message.attributes[1],
+        TcAttribute::Xstats(TcXstats::Other(vec![0u8; 112]))
+    );
+
+    let mut buf = vec![0; message.buffer_len()];
+    message.emit(&mut buf);
+    assert_eq!(buf, raw);
+}
+
+// Newer kernels may append fields to tc_fq_qd_stats, the whole payload is
+// kept as raw bytes instead of silently dropping the unknown tail
+#[test]
+fn test_get_qdisc_fq_xstats_longer() {
+    let mut raw = vec![
+        0x00, // AF_UNSPEC
+        0x00, 0x00, 0x00, // padding
+        0x02, 0x00, 0x00, 0x00, // iface index: 2
+        0x00, 0x00, 0x01, 0x80, // handle 8001:0
+        0xff, 0xff, 0xff, 0xff, // parent u32::MAX (TC_H_ROOT)
+        0x02, 0x00, 0x00, 0x00, // info(refcount): 2
+        0x07, 0x00, // length 7
+        0x01, 0x00, // TCA_KIND
+        0x66, 0x71, 0x00, 0x00, // "fq\0" and 1 byte pad
+        0xa4, 0x00, // length 164
+        0x04, 0x00, // TCA_XSTATS
+    ];
+    raw.extend_from_slice(&[0u8; 160]);
+
+    let message = TcMessage::parse(&TcMessageBuffer::new(&raw)).unwrap();
+    assert_eq!(
+        message.attributes[1],
+        TcAttribute::Xstats(TcXstats::Other(vec![0u8; 160]))
+    );
+
+    let mut buf = vec![0; message.buffer_len()];
+    message.emit(&mut buf);
+    assert_eq!(buf, raw);
+}
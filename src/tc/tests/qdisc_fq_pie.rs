@@ -0,0 +1,165 @@
+// SPDX-License-Identifier: MIT
+
+use netlink_packet_utils::{Emitable, Parseable};
+
+use crate::{
+    tc::{
+        TcAttribute, TcFqPieXstats, TcHandle, TcHeader, TcMessage,
+        TcMessageBuffer, TcOption, TcQdiscFqPieOption, TcStats2, TcXstats,
+    },
+    AddressFamily,
+};
+
+// Hand-crafted RTM_NEWQDISC reply payload of fq_pie qdisc of eth0 holding
+// interface index 2 with limit 10240, flows 1024 and target 15ms, the
+// unrelated attributes are removed. Not a capture: the kernel of the capture
+// host has no sch_fq_pie, see `test_add_qdisc_fq_pie()` for the captured
+// request instead.
+#[test]
+fn test_get_qdisc_fq_pie() {
+    let raw = vec![
+        0x00, // AF_UNSPEC
+        0x00, 0x00, 0x00, // padding
+        0x02, 0x00, 0x00, 0x00, // iface index: 2
+        0x00, 0x00, 0x01, 0x80, // handle 8001:0
+        0xff, 0xff, 0xff, 0xff, // parent u32::MAX (TC_H_ROOT)
+        0x02, 0x00, 0x00, 0x00, // info(refcount): 2
+        0x0b, 0x00, // length 11
+        0x01, 0x00, // TCA_KIND
+        0x66, 0x71, 0x5f, 0x70, 0x69, 0x65, 0x00, 0x00,
+        // "fq_pie\0" and 1 byte pad
+        0x1c, 0x00, // length 28
+        0x02, 0x00, // TCA_OPTIONS for `fq_pie`
+        0x08, 0x00, // length 8
+        0x01, 0x00, // TCA_FQ_PIE_LIMIT
+        0x00, 0x28, 0x00, 0x00, // 10240
+        0x08, 0x00, // length 8
+        0x02, 0x00, // TCA_FQ_PIE_FLOWS
+        0x00, 0x04, 0x00, 0x00, // 1024
+        0x08, 0x00, // length 8
+        0x03, 0x00, // TCA_FQ_PIE_TARGET
+        0x98, 0x3a, 0x00, 0x00, // 15000
+        0x2c, 0x00, // length 44
+        0x07, 0x00, // TCA_STATS2
+        0x28, 0x00, // length 40
+        0x04, 0x00, // TCA_STATS_APP
+        0xe8, 0x03, 0x00, 0x00, // packets_in: 1000
+        0x02, 0x00, 0x00, 0x00, // dropped: 2
+        0x00, 0x00, 0x00, 0x00, // overlimit: 0
+        0x00, 0x00, 0x00, 0x00, // overmemory: 0
+        0x01, 0x00, 0x00, 0x00, // ecn_mark: 1
+        0x07, 0x00, 0x00, 0x00, // new_flow_count: 7
+        0x00, 0x00, 0x00, 0x00, // new_flows_len: 0
+        0x01, 0x00, 0x00, 0x00, // old_flows_len: 1
+        0x00, 0x06, 0x00, 0x00, // memory_usage: 1536
+    ];
+
+    let expected = TcMessage {
+        header: TcHeader {
+            family: AddressFamily::Unspec,
+            index: 2,
+            handle: TcHandle {
+                major: 0x8001,
+                minor: 0,
+            },
+            parent: TcHandle::ROOT,
+            info: 2,
+        },
+        attributes: vec![
+            TcAttribute::Kind("fq_pie".to_string()),
+            TcAttribute::Options(vec![
+                TcOption::FqPie(TcQdiscFqPieOption::Limit(10240)),
+                TcOption::FqPie(TcQdiscFqPieOption::Flows(1024)),
+                TcOption::FqPie(TcQdiscFqPieOption::Target(15000)),
+            ]),
+            TcAttribute::Stats2(vec![TcStats2::App(TcXstats::FqPie(
+                TcFqPieXstats {
+                    packets_in: 1000,
+                    dropped: 2,
+                    overlimit: 0,
+                    overmemory: 0,
+                    ecn_mark: 1,
+                    new_flow_count: 7,
+                    new_flows_len: 0,
+                    old_flows_len: 1,
+                    memory_usage: 1536,
+                },
+            ))]),
+        ],
+    };
+
+    assert_eq!(
+        expected,
+        TcMessage::parse(&TcMessageBuffer::new(&raw)).unwrap()
+    );
+
+    let mut buf = vec![0; expected.buffer_len()];
+
+    expected.emit(&mut buf);
+
+    assert_eq!(buf, raw);
+}
+
+// Captured RTM_NEWQDISC request sent by iproute2 6.1 for:
+//
+//      tc qdisc add dev ifb1 root fq_pie limit 10240 flows 1024 target 15ms
+//
+// with ifb1 holding interface index 3, netlink header removed. iproute2 sets
+// NLA_F_NESTED on TCA_OPTIONS, which is ignored on parse and not emitted.
+#[test]
+fn test_add_qdisc_fq_pie() {
+    let raw = vec![
+        0x00, // AF_UNSPEC
+        0x00, 0x00, 0x00, // padding
+        0x03, 0x00, 0x00, 0x00, // iface index: 3
+        0x00, 0x00, 0x00, 0x00, // handle 0:0 (TC_H_UNSPEC)
+        0xff, 0xff, 0xff, 0xff, // parent u32::MAX (TC_H_ROOT)
+        0x00, 0x00, 0x00, 0x00, // info: 0
+        0x0b, 0x00, // length 11
+        0x01, 0x00, // TCA_KIND
+        0x66, 0x71, 0x5f, 0x70, 0x69, 0x65, 0x00, 0x00,
+        // "fq_pie\0" and 1 byte pad
+        0x1c, 0x00, // length 28
+        0x02, 0x80, // TCA_OPTIONS | NLA_F_NESTED for `fq_pie`
+        0x08, 0x00, // length 8
+        0x01, 0x00, // TCA_FQ_PIE_LIMIT
+        0x00, 0x28, 0x00, 0x00, // 10240
+        0x08, 0x00, // length 8
+        0x02, 0x00, // TCA_FQ_PIE_FLOWS
+        0x00, 0x04, 0x00, 0x00, // 1024
+        0x08, 0x00, // length 8
+        0x03, 0x00, // TCA_FQ_PIE_TARGET
+        0x98, 0x3a, 0x00, 0x00, // 15000
+    ];
+
+    let expected = TcMessage {
+        header: TcHeader {
+            family: AddressFamily::Unspec,
+            index: 3,
+            handle: TcHandle::UNSPEC,
+            parent: TcHandle::ROOT,
+            info: 0,
+        },
+        attributes: vec![
+            TcAttribute::Kind("fq_pie".to_string()),
+            TcAttribute::Options(vec![
+                TcOption::FqPie(TcQdiscFqPieOption::Limit(10240)),
+                TcOption::FqPie(TcQdiscFqPieOption::Flows(1024)),
+                TcOption::FqPie(TcQdiscFqPieOption::Target(15000)),
+            ]),
+        ],
+    };
+
+    assert_eq!(
+        expected,
+        TcMessage::parse(&TcMessageBuffer::new(&raw)).unwrap()
+    );
+
+    let mut buf = vec![0; expected.buffer_len()];
+
+    expected.emit(&mut buf);
+
+    let mut raw_without_flag = raw;
+    raw_without_flag[35] = 0x00; // TCA_OPTIONS without NLA_F_NESTED
+    assert_eq!(buf, raw_without_flag);
+}
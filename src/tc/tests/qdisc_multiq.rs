@@ -0,0 +1,65 @@
+// SPDX-License-Identifier: MIT
+
+use netlink_packet_utils::{Emitable, Parseable};
+
+use crate::{
+    tc::{
+        TcAttribute, TcHandle, TcHeader, TcMessage, TcMessageBuffer,
+        TcQdiscMultiqOption, TcStructOption,
+    },
+    AddressFamily,
+};
+
+// Hand-crafted RTM_NEWQDISC reply payload equivalent to multiq qdisc of eth0
+// holding interface index 2 with 4 TX queues, the unrelated attributes are
+// removed.
+#[test]
+fn test_get_qdisc_multiq() {
+    let raw = vec![
+        0x00, // AF_UNSPEC
+        0x00, 0x00, 0x00, // padding
+        0x02, 0x00, 0x00, 0x00, // iface index: 2
+        0x00, 0x00, 0x01, 0x00, // handle 1:0
+        0xff, 0xff, 0xff, 0xff, // parent u32::MAX (TC_H_ROOT)
+        0x02, 0x00, 0x00, 0x00, // info(refcount): 2
+        0x0b, 0x00, // length 11
+        0x01, 0x00, // TCA_KIND
+        0x6d, 0x75, 0x6c, 0x74, 0x69, 0x71, 0x00, 0x00,
+        // "multiq\0" and 1 byte pad
+        0x08, 0x00, // length 8
+        0x02, 0x00, // TCA_OPTIONS for `multiq`
+        0x04, 0x00, // bands 4
+        0x04, 0x00, // max_bands 4
+    ];
+
+    let expected = TcMessage {
+        header: TcHeader {
+            family: AddressFamily::Unspec,
+            index: 2,
+            handle: TcHandle { major: 1, minor: 0 },
+            parent: TcHandle::ROOT,
+            info: 2,
+        },
+        attributes: vec![
+            TcAttribute::Kind("multiq".to_string()),
+            TcAttribute::StructOptions(
+                TcStructOption::Multiq(TcQdiscMultiqOption {
+                    bands: 4,
+                    max_bands: 4,
+                }),
+                vec![],
+            ),
+        ],
+    };
+
+    assert_eq!(
+        expected,
+        TcMessage::parse(&TcMessageBuffer::new(&raw)).unwrap()
+    );
+
+    let mut buf = vec![0; expected.buffer_len()];
+
+    expected.emit(&mut buf);
+
+    assert_eq!(buf, raw);
+}
@@ -0,0 +1,126 @@
+// SPDX-License-Identifier: MIT
+
+use netlink_packet_utils::{nla::DefaultNla, Emitable, Parseable};
+
+use crate::{
+    tc::{
+        TcAttribute, TcHandle, TcHeader, TcMessage, TcMessageBuffer, TcOption,
+        TcQdiscPrioOption, TcStructOption,
+    },
+    AddressFamily,
+};
+
+// Hand-crafted RTM_NEWQDISC request payload equivalent to:
+//
+//      tc qdisc add dev eth0 root handle 1: prio bands 3
+//
+// with eth0 holding interface index 2. The `tc` command appends an empty
+// nested TCA_OPTIONS after the `struct tc_prio_qopt`.
+#[test]
+fn test_add_qdisc_prio() {
+    let raw = vec![
+        0x00, // AF_UNSPEC
+        0x00, 0x00, 0x00, // padding
+        0x02, 0x00, 0x00, 0x00, // iface index: 2
+        0x00, 0x00, 0x01, 0x00, // handle 1:0
+        0xff, 0xff, 0xff, 0xff, // parent u32::MAX (TC_H_ROOT)
+        0x00, 0x00, 0x00, 0x00, // info: 0
+        0x09, 0x00, // length 9
+        0x01, 0x00, // TCA_KIND
+        0x70, 0x72, 0x69, 0x6f, 0x00, 0x00, 0x00, 0x00,
+        // "prio\0" and 3 bytes pad
+        0x1c, 0x00, // length 28
+        0x02, 0x00, // TCA_OPTIONS for `prio`
+        0x03, 0x00, 0x00, 0x00, // bands 3
+        0x01, 0x02, 0x02, 0x02, 0x01, 0x02, 0x00, 0x00, // priomap
+        0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, // priomap
+        0x04, 0x00, // length 4
+        0x02, 0x00, // TCA_OPTIONS
+    ];
+
+    let expected = TcMessage {
+        header: TcHeader {
+            family: AddressFamily::Unspec,
+            index: 2,
+            handle: TcHandle { major: 1, minor: 0 },
+            parent: TcHandle::ROOT,
+            info: 0,
+        },
+        attributes: vec![
+            TcAttribute::Kind("prio".to_string()),
+            TcAttribute::StructOptions(
+                TcStructOption::Prio(TcQdiscPrioOption {
+                    bands: 3,
+                    priomap: [1, 2, 2, 2, 1, 2, 0, 0, 1, 1, 1, 1, 1, 1, 1, 1],
+                }),
+                vec![TcOption::Other(DefaultNla::new(2, vec![]))],
+            ),
+        ],
+    };
+
+    assert_eq!(
+        expected,
+        TcMessage::parse(&TcMessageBuffer::new(&raw)).unwrap()
+    );
+
+    let mut buf = vec![0; expected.buffer_len()];
+
+    expected.emit(&mut buf);
+
+    assert_eq!(buf, raw);
+}
+
+// Hand-crafted RTM_NEWQDISC reply payload equivalent to the default
+// pfifo_fast qdisc of eth0 holding interface index 2 with the unrelated
+// attributes removed.
+#[test]
+fn test_get_qdisc_pfifo_fast() {
+    let raw = vec![
+        0x00, // AF_UNSPEC
+        0x00, 0x00, 0x00, // padding
+        0x02, 0x00, 0x00, 0x00, // iface index: 2
+        0x00, 0x00, 0x00, 0x00, // handle 0:0 (TC_H_UNSPEC)
+        0xff, 0xff, 0xff, 0xff, // parent u32::MAX (TC_H_ROOT)
+        0x02, 0x00, 0x00, 0x00, // info(refcount): 2
+        0x0f, 0x00, // length 15
+        0x01, 0x00, // TCA_KIND
+        0x70, 0x66, 0x69, 0x66, 0x6f, 0x5f, 0x66, 0x61, 0x73, 0x74, 0x00,
+        0x00, // "pfifo_fast\0" and 1 byte pad
+        0x18, 0x00, // length 24
+        0x02, 0x00, // TCA_OPTIONS for `pfifo_fast`
+        0x03, 0x00, 0x00, 0x00, // bands 3
+        0x01, 0x02, 0x02, 0x02, 0x01, 0x02, 0x00, 0x00, // priomap
+        0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, // priomap
+    ];
+
+    let expected = TcMessage {
+        header: TcHeader {
+            family: AddressFamily::Unspec,
+            index: 2,
+            handle: TcHandle::UNSPEC,
+            parent: TcHandle::ROOT,
+            info: 2,
+        },
+        attributes: vec![
+            TcAttribute::Kind("pfifo_fast".to_string()),
+            TcAttribute::StructOptions(
+                TcStructOption::Prio(TcQdiscPrioOption {
+                    bands: 3,
+                    priomap: [1, 2, 2, 2, 1, 2, 0, 0, 1, 1, 1, 1, 1, 1, 1, 1],
+                }),
+                vec![],
+            ),
+        ],
+    };
+
+    assert_eq!(
+        expected,
+        TcMessage::parse(&TcMessageBuffer::new(&raw)).unwrap()
+    );
+
+    let mut buf = vec![0; expected.buffer_len()];
+
+    expected.emit(&mut buf);
+
+    assert_eq!(buf, raw);
+}
@@ -0,0 +1,182 @@
+// SPDX-License-Identifier: MIT
+
+use netlink_packet_utils::{nla::DefaultNla, Emitable, Parseable};
+
+use crate::{
+    tc::{
+        TcAttribute, TcHandle, TcHeader, TcMessage, TcMessageBuffer, TcOption,
+        TcQdiscSfqOption, TcStructOption,
+    },
+    AddressFamily,
+};
+
+// Hand-crafted RTM_NEWQDISC reply payload of sfq qdisc of eth0 holding
+// interface index 2 created by:
+//
+//      tc qdisc add dev eth0 root handle 1: sfq perturb 10
+//
+// with the unrelated attributes removed. Not a capture: the kernel of the
+// capture host has no sch_sfq, so the defaults filled by sfq_dump() of
+// kernel are used instead. See `test_add_qdisc_sfq()` for the captured
+// request.
+#[test]
+fn test_get_qdisc_sfq() {
+    let raw = vec![
+        0x00, // AF_UNSPEC
+        0x00, 0x00, 0x00, // padding
+        0x02, 0x00, 0x00, 0x00, // iface index: 2
+        0x00, 0x00, 0x01, 0x00, // handle 1:0
+        0xff, 0xff, 0xff, 0xff, // parent u32::MAX (TC_H_ROOT)
+        0x02, 0x00, 0x00, 0x00, // info(refcount): 2
+        0x08, 0x00, // length 8
+        0x01, 0x00, // TCA_KIND
+        0x73, 0x66, 0x71, 0x00, // "sfq\0"
+        0x4c, 0x00, // length 76
+        0x02, 0x00, // TCA_OPTIONS for `sfq`
+        0xea, 0x05, 0x00, 0x00, // quantum 1514
+        0x0a, 0x00, 0x00, 0x00, // perturb_period 10
+        0x7f, 0x00, 0x00, 0x00, // limit 127
+        0x00, 0x04, 0x00, 0x00, // divisor 1024
+        0x80, 0x00, 0x00, 0x00, // flows 128
+        0x7f, 0x00, 0x00, 0x00, // depth 127
+        0x00, 0x00, 0x00, 0x00, // headdrop 0
+        0x00, 0x00, 0x00, 0x00, // red_limit 0
+        0x00, 0x00, 0x00, 0x00, // qth_min 0
+        0x00, 0x00, 0x00, 0x00, // qth_max 0
+        0x00, // wlog 0
+        0x00, // plog 0
+        0x00, // scell_log 0
+        0x00, // flags 0
+        0x00, 0x00, 0x00, 0x00, // max_p 0
+        0x00, 0x00, 0x00, 0x00, // prob_drop 0
+        0x00, 0x00, 0x00, 0x00, // forced_drop 0
+        0x00, 0x00, 0x00, 0x00, // prob_mark 0
+        0x00, 0x00, 0x00, 0x00, // forced_mark 0
+        0x00, 0x00, 0x00, 0x00, // prob_mark_head 0
+        0x00, 0x00, 0x00, 0x00, // forced_mark_head 0
+    ];
+
+    let expected = TcMessage {
+        header: TcHeader {
+            family: AddressFamily::Unspec,
+            index: 2,
+            handle: TcHandle { major: 1, minor: 0 },
+            parent: TcHandle::ROOT,
+            info: 2,
+        },
+        attributes: vec![
+            TcAttribute::Kind("sfq".to_string()),
+            TcAttribute::StructOptions(
+                TcStructOption::Sfq(TcQdiscSfqOption {
+                    quantum: 1514,
+                    perturb_period: 10,
+                    limit: 127,
+                    divisor: 1024,
+                    flows: 128,
+                    depth: 127,
+                    ..Default::default()
+                }),
+                vec![],
+            ),
+        ],
+    };
+
+    assert_eq!(
+        expected,
+        TcMessage::parse(&TcMessageBuffer::new(&raw)).unwrap()
+    );
+
+    let mut buf = vec![0; expected.buffer_len()];
+
+    expected.emit(&mut buf);
+
+    assert_eq!(buf, raw);
+}
+
+// Captured RTM_NEWQDISC request sent by iproute2 6.1 for:
+//
+//      tc qdisc add dev ifb1 root handle 1: sfq perturb 10
+//
+// with ifb1 holding interface index 3, netlink header removed. iproute2
+// leaves all the unspecified fields of `struct tc_sfq_qopt_v1` as 0 for
+// kernel to use its defaults.
+#[test]
+fn test_add_qdisc_sfq() {
+    let mut raw = vec![
+        0x00, // AF_UNSPEC
+        0x00, 0x00, 0x00, // padding
+        0x03, 0x00, 0x00, 0x00, // iface index: 3
+        0x00, 0x00, 0x01, 0x00, // handle 1:0
+        0xff, 0xff, 0xff, 0xff, // parent u32::MAX (TC_H_ROOT)
+        0x00, 0x00, 0x00, 0x00, // info: 0
+        0x08, 0x00, // length 8
+        0x01, 0x00, // TCA_KIND
+        0x73, 0x66, 0x71, 0x00, // "sfq\0"
+        0x4c, 0x00, // length 76
+        0x02, 0x00, // TCA_OPTIONS for `sfq`
+        0x00, 0x00, 0x00, 0x00, // quantum 0
+        0x0a, 0x00, 0x00, 0x00, // perturb_period 10
+    ];
+    // All the remaining fields are 0
+    raw.extend_from_slice(&[0u8; 64]);
+
+    let expected = TcMessage {
+        header: TcHeader {
+            family: AddressFamily::Unspec,
+            index: 3,
+            handle: TcHandle { major: 1, minor: 0 },
+            parent: TcHandle::ROOT,
+            info: 0,
+        },
+        attributes: vec![
+            TcAttribute::Kind("sfq".to_string()),
+            TcAttribute::StructOptions(
+                TcStructOption::Sfq(TcQdiscSfqOption {
+                    perturb_period: 10,
+                    ..Default::default()
+                }),
+                vec![],
+            ),
+        ],
+    };
+
+    assert_eq!(
+        expected,
+        TcMessage::parse(&TcMessageBuffer::new(&raw)).unwrap()
+    );
+
+    let mut buf = vec![0; expected.buffer_len()];
+
+    expected.emit(&mut buf);
+
+    assert_eq!(buf, raw);
+}
+
+// A TCA_OPTIONS longer than `struct tc_sfq_qopt_v1` is kept as a whole as
+// unknown Nla instead of dropping the unexpected tail
+#[test]
+fn test_get_qdisc_sfq_longer_options() {
+    let mut raw = vec![
+        0x00, // AF_UNSPEC
+        0x00, 0x00, 0x00, // padding
+        0x02, 0x00, 0x00, 0x00, // iface index: 2
+        0x00, 0x00, 0x01, 0x00, // handle 1:0
+        0xff, 0xff, 0xff, 0xff, // parent u32::MAX (TC_H_ROOT)
+        0x02, 0x00, 0x00, 0x00, // info(refcount): 2
+        0x08, 0x00, // length 8
+        0x01, 0x00, // TCA_KIND
+        0x73, 0x66, 0x71, 0x00, // "sfq\0"
+        0x50, 0x00, // length 80
+        0x02, 0x00, // TCA_OPTIONS for `sfq`
+    ];
+    raw.extend_from_slice(&[0u8; 76]);
+
+    let message = TcMessage::parse(&TcMessageBuffer::new(&raw)).unwrap();
+    assert_eq!(
+        message.attributes[1],
+        TcAttribute::Options(vec![TcOption::Other(DefaultNla::new(
+            2,
+            vec![0u8; 76]
+        ))])
+    );
+}
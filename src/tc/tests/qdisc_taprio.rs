@@ -0,0 +1,155 @@
+// SPDX-License-Identifier: MIT
+
+use netlink_packet_utils::{Emitable, Parseable};
+
+use crate::{
+    tc::{
+        TcAttribute, TcHandle, TcHeader, TcMessage, TcMessageBuffer,
+        TcMqprioQopt, TcOption, TcQdiscTaprioOption, TcTaprioFlags,
+        TcTaprioSchedCmd, TcTaprioSchedEntry, TcTaprioSchedEntryOption,
+    },
+    AddressFamily,
+};
+
+// Hand-crafted RTM_NEWQDISC payload equivalent to the `tc qdisc show dev eth0`
+// reply after command:
+//      tc qdisc replace dev eth0 parent root handle 100 taprio num_tc 3 \
+//          map 2 2 1 0 2 2 2 2 2 2 2 2 2 2 2 2 queues 1@0 1@1 2@2 \
+//          base-time 1528743495910289987 \
+//          sched-entry S 01 300000 sched-entry S 02 300000 \
+//          sched-entry S 04 400000 flags 0x2
+// with eth0 holding interface index 2. With full offload, kernel omits the
+// TCA_TAPRIO_ATTR_SCHED_CLOCKID and TCA_TAPRIO_ATTR_TXTIME_DELAY.
+#[test]
+fn test_get_qdisc_taprio_full_offload() {
+    let raw = vec![
+        0x00, 0x00, 0x00, 0x00, // AF_UNSPEC and padding
+        0x02, 0x00, 0x00, 0x00, // ifindex 2
+        0x00, 0x00, 0x00, 0x01, // handle 100:0
+        0xff, 0xff, 0xff, 0xff, // parent root
+        0x01, 0x00, 0x00, 0x00, // info 1
+        0x0b, 0x00, 0x01, 0x00, // length 11, TCA_KIND
+        0x74, 0x61, 0x70, 0x72, 0x69, 0x6f, 0x00,
+        0x00, // "taprio\0" and padding
+        0xf8, 0x00, 0x02, 0x00, // length 248, TCA_OPTIONS
+        0x56, 0x00, 0x01, 0x00, // length 86, TCA_TAPRIO_ATTR_PRIOMAP
+        // num_tc 3, prio_tc_map, hw 0
+        0x03, 0x02, 0x02, 0x01, 0x00, 0x02, 0x02, 0x02, 0x02, 0x02, 0x02, 0x02,
+        0x02, 0x02, 0x02, 0x02, 0x02, 0x00, // count
+        0x01, 0x00, 0x01, 0x00, 0x02, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        // offset and padding
+        0x00, 0x00, 0x01, 0x00, 0x02, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x08, 0x00,
+        0x0a, 0x00, 0x02, 0x00, 0x00,
+        0x00, // TCA_TAPRIO_ATTR_FLAGS, full offload
+        0x0c, 0x00, 0x03,
+        0x00, // length 12, TCA_TAPRIO_ATTR_SCHED_BASE_TIME
+        0x43, 0xf2, 0x6b, 0x22, 0x1c, 0x30, 0x37,
+        0x15, // 1528743495910289987
+        0x0c, 0x00, 0x08,
+        0x00, // length 12, TCA_TAPRIO_ATTR_SCHED_CYCLE_TIME
+        0x40, 0x42, 0x0f, 0x00, 0x00, 0x00, 0x00, 0x00, // 1000000
+        0x0c, 0x00, 0x09,
+        0x00, // length 12, TCA_TAPRIO_ATTR_SCHED_CYCLE_TIME_EXTENSION
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, // 0
+        0x70, 0x00, 0x02,
+        0x00, // length 112, TCA_TAPRIO_ATTR_SCHED_ENTRY_LIST
+        0x24, 0x00, 0x01, 0x00, // length 36, TCA_TAPRIO_SCHED_ENTRY
+        0x08, 0x00, 0x01, 0x00, 0x00, 0x00, 0x00,
+        0x00, // TCA_TAPRIO_SCHED_ENTRY_INDEX 0
+        0x05, 0x00, 0x02, 0x00, 0x00, 0x00, 0x00,
+        0x00, // TCA_TAPRIO_SCHED_ENTRY_CMD set gates
+        0x08, 0x00, 0x03, 0x00, 0x01, 0x00, 0x00,
+        0x00, // TCA_TAPRIO_SCHED_ENTRY_GATE_MASK 0x01
+        0x08, 0x00, 0x04, 0x00, 0xe0, 0x93, 0x04,
+        0x00, // TCA_TAPRIO_SCHED_ENTRY_INTERVAL 300000
+        0x24, 0x00, 0x01, 0x00, // length 36, TCA_TAPRIO_SCHED_ENTRY
+        0x08, 0x00, 0x01, 0x00, 0x01, 0x00, 0x00,
+        0x00, // TCA_TAPRIO_SCHED_ENTRY_INDEX 1
+        0x05, 0x00, 0x02, 0x00, 0x00, 0x00, 0x00,
+        0x00, // TCA_TAPRIO_SCHED_ENTRY_CMD set gates
+        0x08, 0x00, 0x03, 0x00, 0x02, 0x00, 0x00,
+        0x00, // TCA_TAPRIO_SCHED_ENTRY_GATE_MASK 0x02
+        0x08, 0x00, 0x04, 0x00, 0xe0, 0x93, 0x04,
+        0x00, // TCA_TAPRIO_SCHED_ENTRY_INTERVAL 300000
+        0x24, 0x00, 0x01, 0x00, // length 36, TCA_TAPRIO_SCHED_ENTRY
+        0x08, 0x00, 0x01, 0x00, 0x02, 0x00, 0x00,
+        0x00, // TCA_TAPRIO_SCHED_ENTRY_INDEX 2
+        0x05, 0x00, 0x02, 0x00, 0x00, 0x00, 0x00,
+        0x00, // TCA_TAPRIO_SCHED_ENTRY_CMD set gates
+        0x08, 0x00, 0x03, 0x00, 0x04, 0x00, 0x00,
+        0x00, // TCA_TAPRIO_SCHED_ENTRY_GATE_MASK 0x04
+        0x08, 0x00, 0x04, 0x00, 0x80, 0x1a, 0x06,
+        0x00, // TCA_TAPRIO_SCHED_ENTRY_INTERVAL 400000
+        0x05, 0x00, 0x0c, 0x00, 0x01, 0x00, 0x00,
+        0x00, // TCA_HW_OFFLOAD 1
+    ];
+
+    let entry = |index, gate_mask, interval| {
+        TcTaprioSchedEntry::Entry(vec![
+            TcTaprioSchedEntryOption::Index(index),
+            TcTaprioSchedEntryOption::Cmd(TcTaprioSchedCmd::SetGates),
+            TcTaprioSchedEntryOption::GateMask(gate_mask),
+            TcTaprioSchedEntryOption::Interval(interval),
+        ])
+    };
+
+    let mut priomap = TcMqprioQopt {
+        num_tc: 3,
+        prio_tc_map: [2; 16],
+        ..Default::default()
+    };
+    priomap.prio_tc_map[2] = 1;
+    priomap.prio_tc_map[3] = 0;
+    priomap.count[..3].copy_from_slice(&[1, 1, 2]);
+    priomap.offset[..3].copy_from_slice(&[0, 1, 2]);
+
+    let expected = TcMessage {
+        header: TcHeader {
+            family: AddressFamily::Unspec,
+            index: 2,
+            handle: TcHandle {
+                major: 0x100,
+                minor: 0,
+            },
+            parent: TcHandle::ROOT,
+            info: 1,
+        },
+        attributes: vec![
+            TcAttribute::Kind("taprio".to_string()),
+            TcAttribute::Options(vec![
+                TcOption::Taprio(TcQdiscTaprioOption::Priomap(priomap)),
+                TcOption::Taprio(TcQdiscTaprioOption::Flags(
+                    TcTaprioFlags::FullOffload,
+                )),
+                TcOption::Taprio(TcQdiscTaprioOption::SchedBaseTime(
+                    1528743495910289987,
+                )),
+                TcOption::Taprio(TcQdiscTaprioOption::SchedCycleTime(1000000)),
+                TcOption::Taprio(TcQdiscTaprioOption::SchedCycleTimeExtension(
+                    0,
+                )),
+                TcOption::Taprio(TcQdiscTaprioOption::SchedEntryList(vec![
+                    entry(0, 0x01, 300000),
+                    entry(1, 0x02, 300000),
+                    entry(2, 0x04, 400000),
+                ])),
+            ]),
+            TcAttribute::HwOffload(1),
+        ],
+    };
+
+    assert_eq!(
+        expected,
+        TcMessage::parse(&TcMessageBuffer::new(&raw)).unwrap()
+    );
+
+    let mut buf = vec![0; expected.buffer_len()];
+
+    expected.emit(&mut buf);
+
+    assert_eq!(buf, raw);
+}
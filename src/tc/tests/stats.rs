@@ -0,0 +1,37 @@
+// SPDX-License-Identifier: MIT
+
+use netlink_packet_utils::{nla::NlaBuffer, ParseableParametrized};
+
+use crate::{
+    set_strict_parsing,
+    tc::{TcStats2, TcStatsBasic, TcStatsBasicBuffer},
+};
+
+#[test]
+fn test_stats_basic_strict_parse() {
+    let raw = vec![
+        0x1c, 0x00, // length 28
+        0x01, 0x00, // TCA_STATS_BASIC
+        0x64, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, // bytes 100
+        0x02, 0x00, 0x00, 0x00, // packets 2
+        0x00, 0x00, 0x00, 0x00, // padding
+        0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, // unknown data
+    ];
+
+    let nla = NlaBuffer::new_checked(&raw).unwrap();
+    assert_eq!(
+        TcStats2::parse_with_param(&nla, "").unwrap(),
+        TcStats2::Basic(TcStatsBasic {
+            bytes: 100,
+            packets: 2,
+        })
+    );
+
+    assert!(TcStatsBasicBuffer::new_strict(nla.value()).is_err());
+    assert!(TcStatsBasicBuffer::new_strict(&nla.value()[..16]).is_ok());
+
+    set_strict_parsing(true);
+    let result = TcStats2::parse_with_param(&nla, "");
+    set_strict_parsing(false);
+    assert!(result.is_err());
+}
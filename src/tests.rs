@@ -1,16 +1,39 @@
 // SPDX-License-Identifier: MIT
 
-// This file only contains testing parsing RouteNetlinkMessage, not focusing on
-// detailed sub-component parsing. Each component has their own tests moduel.
+// This file only contains testing parsing RouteNetlinkMessage and the
+// top-level types like AddressFamily, not focusing on detailed sub-component
+// parsing. Each component has their own tests moduel.
 
 use netlink_packet_core::{NetlinkHeader, NetlinkMessage, NetlinkPayload};
-use netlink_packet_utils::Emitable;
+use netlink_packet_utils::{nla::Nla, Emitable};
 
 use crate::{
-    link::{LinkAttribute, LinkExtentMask, LinkMessage},
-    RouteNetlinkMessage,
+    address::AddressMessageBuffer,
+    emit::emit_value_checked,
+    emit_to_vec,
+    link::{LinkAttribute, LinkExtentMask, LinkMessage, LinkMessageBuffer},
+    neighbour::NeighbourMessageBuffer,
+    route::RouteMessageBuffer,
+    rule::{RuleAttribute, RuleMessage, RuleMessageBuffer},
+    tc::TcMessageBuffer,
+    AddressFamily, RouteNetlinkMessage,
 };
 
+/// Load the binary netlink payload stored in `tests/fixtures/<name>`.
+///
+/// Large captures should be stored as fixture other than inline arrays, for
+/// example, the payload of an nlmon capture with netlink message header
+/// removed can be saved by `tshark -T fields -e data` piped to `xxd -r -p`.
+pub(crate) fn load_fixture(name: &str) -> Vec<u8> {
+    let path = std::path::Path::new(env!("CARGO_MANIFEST_DIR"))
+        .join("tests")
+        .join("fixtures")
+        .join(name);
+    std::fs::read(&path).unwrap_or_else(|e| {
+        panic!("failed to read fixture {}: {e}", path.display())
+    })
+}
+
 // wireshark capture of nlmon against command:
 //   ip link show dev lo
 #[test]
@@ -47,3 +70,300 @@ fn test_get_link() {
     expected.emit(&mut buffer);
     assert_eq!(buffer.as_slice(), raw);
 }
+
+#[test]
+fn test_address_family_is_ip() {
+    assert!(AddressFamily::Inet.is_ip());
+    assert!(AddressFamily::Inet6.is_ip());
+    assert!(!AddressFamily::Unspec.is_ip());
+    assert!(!AddressFamily::Unix.is_ip());
+    assert!(!AddressFamily::Other(255).is_ip());
+}
+
+#[test]
+fn test_address_family_address_len() {
+    assert_eq!(AddressFamily::Inet.address_len(), Some(4));
+    assert_eq!(AddressFamily::Inet6.address_len(), Some(16));
+    assert_eq!(AddressFamily::Unspec.address_len(), None);
+    assert_eq!(AddressFamily::Other(255).address_len(), None);
+}
+
+#[test]
+fn test_address_family_display() {
+    assert_eq!(AddressFamily::Unspec.to_string(), "unspec");
+    assert_eq!(AddressFamily::Inet.to_string(), "inet");
+    assert_eq!(AddressFamily::Inet6.to_string(), "inet6");
+    assert_eq!(AddressFamily::Other(255).to_string(), "other(255)");
+    assert_eq!(AddressFamily::Inet6.as_str(), "inet6");
+}
+
+#[cfg(any(target_os = "linux", target_os = "fuchsia"))]
+#[test]
+fn test_address_family_display_linux() {
+    assert_eq!(AddressFamily::Packet.to_string(), "packet");
+    assert_eq!(AddressFamily::Bridge.to_string(), "bridge");
+    assert_eq!(AddressFamily::Mpls.to_string(), "mpls");
+    assert_eq!(AddressFamily::Decnet.to_string(), "dnet");
+}
+
+#[test]
+fn test_emit_to_vec_reuse_buffer() {
+    let link_msg = LinkMessage {
+        attributes: vec![
+            LinkAttribute::IfName("a-long-interface-name".to_string()),
+            LinkAttribute::Mtu(9000),
+        ],
+        ..Default::default()
+    };
+    let mut rule_msg = RuleMessage::default();
+    rule_msg.attributes.push(RuleAttribute::Priority(100));
+
+    let mut buf = vec![0xff; 1024];
+
+    let len = emit_to_vec(&link_msg, &mut buf);
+    assert_eq!(len, link_msg.buffer_len());
+    let mut expected = vec![0; link_msg.buffer_len()];
+    link_msg.emit(&mut expected);
+    assert_eq!(buf, expected);
+
+    // Smaller message should not carry stale bytes of previous one
+    let capacity = buf.capacity();
+    let len = emit_to_vec(&rule_msg, &mut buf);
+    assert_eq!(len, rule_msg.buffer_len());
+    assert!(len < link_msg.buffer_len());
+    let mut expected = vec![0; rule_msg.buffer_len()];
+    rule_msg.emit(&mut expected);
+    assert_eq!(buf, expected);
+    assert_eq!(buf.capacity(), capacity);
+}
+
+// Hand-crafted RTM_NEWROUTE request equivalent to:
+//   ip route add 192.0.2.0/24 via 198.51.100.1 dev eth1
+// with eth1 holding interface index 3. When rejecting the gateway, kernel
+// points NLMSGERR_ATTR_OFFS of the extended ACK into the RTA_GATEWAY.
+#[test]
+fn test_attribute_at_offset() {
+    let raw: Vec<u8> = vec![
+        0x34, 0x00, 0x00, 0x00, // length 52
+        0x18, 0x00, // RTM_NEWROUTE
+        0x05,
+        0x06, // NLM_F_REQUEST | NLM_F_ACK | NLM_F_EXCL | NLM_F_CREATE
+        0x01, 0x00, 0x00, 0x00, // sequence number 1
+        0x00, 0x00, 0x00, 0x00, // port id 0
+        0x02, // AF_INET
+        0x18, // destination prefix length 24
+        0x00, // source prefix length 0
+        0x00, // tos 0
+        0xfe, // RT_TABLE_MAIN
+        0x03, // RTPROT_BOOT
+        0x00, // RT_SCOPE_UNIVERSE
+        0x01, // RTN_UNICAST
+        0x00, 0x00, 0x00, 0x00, // flags 0
+        0x08, 0x00, // length 8, offset 28
+        0x01, 0x00, // RTA_DST
+        0xc0, 0x00, 0x02, 0x00, // 192.0.2.0
+        0x08, 0x00, // length 8, offset 36
+        0x05, 0x00, // RTA_GATEWAY
+        0xc6, 0x33, 0x64, 0x01, // 198.51.100.1
+        0x08, 0x00, // length 8, offset 44
+        0x04, 0x00, // RTA_OIF
+        0x03, 0x00, 0x00, 0x00, // 3
+    ];
+
+    let attr = RouteNetlinkMessage::attribute_at_offset(&raw, 40).unwrap();
+    assert_eq!(attr.to_string(), "route/RTA_GATEWAY");
+    assert_eq!(attr.offset, 36);
+    assert_eq!(attr.length, 8);
+
+    let attr = RouteNetlinkMessage::attribute_at_offset(&raw, 36).unwrap();
+    assert_eq!(attr.to_string(), "route/RTA_GATEWAY");
+    let attr = RouteNetlinkMessage::attribute_at_offset(&raw, 44).unwrap();
+    assert_eq!(attr.to_string(), "route/RTA_OIF");
+
+    // Offsets in netlink header, route header or beyond the message
+    assert_eq!(RouteNetlinkMessage::attribute_at_offset(&raw, 4), None);
+    assert_eq!(RouteNetlinkMessage::attribute_at_offset(&raw, 20), None);
+    assert_eq!(RouteNetlinkMessage::attribute_at_offset(&raw, 52), None);
+
+    // Attribute names are not known outside of route module
+    let mut link = LinkMessage::default();
+    link.attributes
+        .push(LinkAttribute::IfName("lo".to_string()));
+    let mut msg = NetlinkMessage::new(
+        NetlinkHeader::default(),
+        NetlinkPayload::from(RouteNetlinkMessage::DelLink(link)),
+    );
+    msg.finalize();
+    let mut buf = vec![0; msg.buffer_len()];
+    msg.serialize(&mut buf);
+    // netlink header(16) + link header(16)
+    let attr = RouteNetlinkMessage::attribute_at_offset(&buf, 32).unwrap();
+    assert_eq!(attr.to_string(), "link/3");
+}
+
+#[test]
+fn test_parse_strict_trailing_bytes() {
+    let mut link = LinkMessage::default();
+    link.attributes
+        .push(LinkAttribute::IfName("lo".to_string()));
+    let mut msg = NetlinkMessage::new(
+        NetlinkHeader::default(),
+        NetlinkPayload::from(RouteNetlinkMessage::GetLink(link)),
+    );
+    msg.finalize();
+    let mut buf = vec![0; msg.buffer_len()];
+    msg.serialize(&mut buf);
+
+    assert_eq!(RouteNetlinkMessage::parse_strict(&buf).unwrap(), msg);
+
+    buf.extend_from_slice(&[0xde, 0xad, 0xbe, 0xef]);
+    assert_eq!(NetlinkMessage::deserialize(&buf).unwrap(), msg);
+    let err = RouteNetlinkMessage::parse_strict(&buf).unwrap_err();
+    assert!(err.to_string().contains("4 bytes trailing"));
+}
+
+fn serialize_link_messages(indexes: &[u32]) -> Vec<u8> {
+    let mut buf = vec![];
+    for index in indexes {
+        let mut link = LinkMessage::default();
+        link.header.index = *index;
+        link.attributes
+            .push(LinkAttribute::IfName(format!("eth{index}")));
+        let mut msg = NetlinkMessage::new(
+            NetlinkHeader::default(),
+            NetlinkPayload::from(RouteNetlinkMessage::NewLink(link)),
+        );
+        msg.finalize();
+        let start = buf.len();
+        buf.resize(start + msg.buffer_len(), 0);
+        msg.serialize(&mut buf[start..]);
+    }
+    buf
+}
+
+#[test]
+fn test_iter_messages() {
+    let buf = serialize_link_messages(&[1, 2]);
+
+    let msgs = RouteNetlinkMessage::iter_messages(&buf)
+        .map(|msg| {
+            NetlinkMessage::<RouteNetlinkMessage>::deserialize(
+                msg.unwrap().into_inner(),
+            )
+            .unwrap()
+        })
+        .collect::<Vec<_>>();
+    assert_eq!(msgs.len(), 2);
+    for (msg, index) in msgs.iter().zip([1, 2]) {
+        if let NetlinkPayload::InnerMessage(RouteNetlinkMessage::NewLink(
+            link,
+        )) = &msg.payload
+        {
+            assert_eq!(link.header.index, index);
+            assert_eq!(link.name(), Some(format!("eth{index}").as_str()));
+        } else {
+            panic!("unexpected payload {:?}", msg.payload);
+        }
+    }
+
+    assert_eq!(RouteNetlinkMessage::iter_messages(&[]).count(), 0);
+}
+
+#[test]
+fn test_iter_messages_truncated() {
+    let buf = serialize_link_messages(&[1, 2]);
+
+    for len in [buf.len() - 1, buf.len() / 2 + 4, buf.len() / 2 + 20] {
+        let mut iter = RouteNetlinkMessage::iter_messages(&buf[..len]);
+        assert!(iter.next().unwrap().is_ok());
+        assert!(iter.next().unwrap().is_err());
+        assert!(iter.next().is_none());
+    }
+}
+
+#[test]
+fn test_message_buffer_try_from_short() {
+    // The 4 bytes `rtgen_family` header iproute2 sends for dump requests is
+    // shorter than any of the headers below.
+    let raw: &[u8] = &[0x02, 0x00, 0x00, 0x00];
+
+    fn err_msg<T, E: std::fmt::Display>(result: Result<T, E>) -> String {
+        match result {
+            Ok(_) => panic!("short buffer should be rejected"),
+            Err(e) => e.to_string(),
+        }
+    }
+
+    assert_eq!(
+        err_msg(LinkMessageBuffer::try_from(raw)),
+        "Decode error occurred: invalid LinkMessageBuffer: length 4 < 16"
+    );
+    assert_eq!(
+        err_msg(AddressMessageBuffer::try_from(raw)),
+        "Decode error occurred: invalid AddressMessageBuffer: length 4 < 8"
+    );
+    assert_eq!(
+        err_msg(RouteMessageBuffer::try_from(raw)),
+        "Decode error occurred: invalid RouteMessageBuffer: length 4 < 12"
+    );
+    assert_eq!(
+        err_msg(RuleMessageBuffer::try_from(raw)),
+        "Decode error occurred: invalid RuleMessageBuffer: length 4 < 12"
+    );
+    assert_eq!(
+        err_msg(NeighbourMessageBuffer::try_from(raw)),
+        "Decode error occurred: invalid NeighbourMessageBuffer: length 4 < 12"
+    );
+    assert_eq!(
+        err_msg(TcMessageBuffer::try_from(raw)),
+        "Decode error occurred: invalid TcMessageBuffer: length 4 < 20"
+    );
+
+    let raw = [0u8; 16];
+    assert!(LinkMessageBuffer::try_from(&raw[..]).is_ok());
+}
+
+// Writes only the first `written` bytes of its value.
+struct PartialNla {
+    value: Vec<u8>,
+    written: usize,
+}
+
+impl Nla for PartialNla {
+    fn value_len(&self) -> usize {
+        self.value.len()
+    }
+
+    fn kind(&self) -> u16 {
+        1
+    }
+
+    fn emit_value(&self, buffer: &mut [u8]) {
+        buffer[..self.written].copy_from_slice(&self.value[..self.written]);
+    }
+}
+
+#[test]
+fn test_emit_value_checked_value_ending_in_zero() {
+    let nla = PartialNla {
+        value: vec![0x01, 0x02, 0x00, 0x00],
+        written: 4,
+    };
+    let mut buf = vec![0xaa; 4];
+    emit_value_checked(&nla, &mut buf);
+    assert_eq!(buf, nla.value);
+}
+
+#[cfg(debug_assertions)]
+#[test]
+#[should_panic(expected = "left byte 2 of its value_len() 4 unwritten")]
+fn test_emit_value_checked_short_emitter() {
+    // The unwritten tail would be zero in the output, so a check looking at
+    // the emitted bytes alone cannot tell that it was never written.
+    let nla = PartialNla {
+        value: vec![0x01, 0x02, 0x00, 0x00],
+        written: 2,
+    };
+    let mut buf = vec![0; 4];
+    emit_value_checked(&nla, &mut buf);
+}
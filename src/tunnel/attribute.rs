@@ -0,0 +1,70 @@
+// SPDX-License-Identifier: MIT
+
+use anyhow::Context;
+use netlink_packet_utils::{
+    nla::{DefaultNla, Nla, NlaBuffer, NlasIterator, NLA_F_NESTED},
+    traits::{Emitable, Parseable},
+    DecodeError,
+};
+
+use super::VniFilterEntry;
+
+const VXLAN_VNIFILTER_ENTRY: u16 = 1;
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[non_exhaustive]
+pub enum TunnelAttribute {
+    /// A VNI or VNI range of VXLAN VNI filter.
+    VniFilterEntry(Vec<VniFilterEntry>),
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_nla"))]
+    Other(DefaultNla),
+}
+
+impl Nla for TunnelAttribute {
+    fn value_len(&self) -> usize {
+        match self {
+            Self::VniFilterEntry(v) => v.as_slice().buffer_len(),
+            Self::Other(attr) => attr.value_len(),
+        }
+    }
+
+    fn emit_value(&self, buffer: &mut [u8]) {
+        match self {
+            Self::VniFilterEntry(v) => v.as_slice().emit(buffer),
+            Self::Other(attr) => attr.emit_value(buffer),
+        }
+    }
+
+    fn kind(&self) -> u16 {
+        match self {
+            Self::VniFilterEntry(_) => VXLAN_VNIFILTER_ENTRY | NLA_F_NESTED,
+            Self::Other(attr) => attr.kind(),
+        }
+    }
+}
+
+impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>>
+    for TunnelAttribute
+{
+    fn parse(buf: &NlaBuffer<&'a T>) -> Result<Self, DecodeError> {
+        let payload = buf.value();
+        Ok(match buf.kind() {
+            VXLAN_VNIFILTER_ENTRY => {
+                let mut entries = vec![];
+                for nla in NlasIterator::new(payload) {
+                    let nla = nla.context("invalid VXLAN_VNIFILTER_ENTRY")?;
+                    entries
+                        .push(VniFilterEntry::parse(&nla).context(
+                            "failed to parse VXLAN_VNIFILTER_ENTRY",
+                        )?);
+                }
+                Self::VniFilterEntry(entries)
+            }
+            kind => Self::Other(
+                DefaultNla::parse(buf)
+                    .context(format!("unknown NLA type {kind}"))?,
+            ),
+        })
+    }
+}
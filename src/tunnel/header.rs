@@ -0,0 +1,62 @@
+// SPDX-License-Identifier: MIT
+
+use netlink_packet_utils::{
+    nla::{NlaBuffer, NlasIterator},
+    DecodeError, Emitable, Parseable,
+};
+
+use crate::AddressFamily;
+
+const TUNNEL_HEADER_LEN: usize = 8;
+
+// kernel struct `tunnel_msg`
+buffer!(TunnelMessageBuffer(TUNNEL_HEADER_LEN) {
+    family: (u8, 0),
+    flags: (u8, 1),
+    reserved: (u16, 2..4),
+    ifindex: (u32, 4..TUNNEL_HEADER_LEN),
+    payload: (slice, TUNNEL_HEADER_LEN..),
+});
+
+impl<'a, T: AsRef<[u8]> + ?Sized> TunnelMessageBuffer<&'a T> {
+    pub fn attributes(
+        &self,
+    ) -> impl Iterator<Item = Result<NlaBuffer<&'a [u8]>, DecodeError>> {
+        NlasIterator::new(self.payload())
+    }
+}
+
+#[derive(Debug, PartialEq, Eq, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[non_exhaustive]
+pub struct TunnelHeader {
+    /// Kernel only supports `AF_BRIDGE` for VXLAN VNI filter.
+    pub family: AddressFamily,
+    pub flags: u8,
+    /// Interface index of the tunnel device.
+    pub ifindex: u32,
+}
+
+impl Emitable for TunnelHeader {
+    fn buffer_len(&self) -> usize {
+        TUNNEL_HEADER_LEN
+    }
+
+    fn emit(&self, buffer: &mut [u8]) {
+        let mut packet = TunnelMessageBuffer::new(buffer);
+        packet.set_family(self.family.into());
+        packet.set_flags(self.flags);
+        packet.set_reserved(0);
+        packet.set_ifindex(self.ifindex);
+    }
+}
+
+impl<T: AsRef<[u8]>> Parseable<TunnelMessageBuffer<T>> for TunnelHeader {
+    fn parse(buf: &TunnelMessageBuffer<T>) -> Result<Self, DecodeError> {
+        Ok(Self {
+            family: buf.family().into(),
+            flags: buf.flags(),
+            ifindex: buf.ifindex(),
+        })
+    }
+}
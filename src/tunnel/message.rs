@@ -0,0 +1,58 @@
+// SPDX-License-Identifier: MIT
+
+use anyhow::Context;
+use netlink_packet_utils::{
+    traits::{Emitable, Parseable},
+    DecodeError,
+};
+
+use crate::tunnel::{TunnelAttribute, TunnelHeader, TunnelMessageBuffer};
+
+/// Message of `RTM_NEWTUNNEL`, `RTM_DELTUNNEL` and `RTM_GETTUNNEL`, used by
+/// `bridge vni` command to manage the VNI filter of VXLAN device in
+/// `external vnifilter` mode.
+#[derive(Debug, PartialEq, Eq, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[non_exhaustive]
+pub struct TunnelMessage {
+    pub header: TunnelHeader,
+    pub attributes: Vec<TunnelAttribute>,
+}
+
+impl<'a, T: AsRef<[u8]> + 'a> Parseable<TunnelMessageBuffer<&'a T>>
+    for TunnelMessage
+{
+    fn parse(buf: &TunnelMessageBuffer<&'a T>) -> Result<Self, DecodeError> {
+        Ok(Self {
+            header: TunnelHeader::parse(buf)
+                .context("failed to parse tunnel message header")?,
+            attributes: Vec::<TunnelAttribute>::parse(buf)
+                .context("failed to parse tunnel message NLAs")?,
+        })
+    }
+}
+
+impl<'a, T: AsRef<[u8]> + 'a> Parseable<TunnelMessageBuffer<&'a T>>
+    for Vec<TunnelAttribute>
+{
+    fn parse(buf: &TunnelMessageBuffer<&'a T>) -> Result<Self, DecodeError> {
+        let mut attributes = vec![];
+        for nla_buf in buf.attributes() {
+            attributes.push(TunnelAttribute::parse(&nla_buf?)?);
+        }
+        Ok(attributes)
+    }
+}
+
+impl Emitable for TunnelMessage {
+    fn buffer_len(&self) -> usize {
+        self.header.buffer_len() + self.attributes.as_slice().buffer_len()
+    }
+
+    fn emit(&self, buffer: &mut [u8]) {
+        self.header.emit(buffer);
+        self.attributes
+            .as_slice()
+            .emit(&mut buffer[self.header.buffer_len()..]);
+    }
+}
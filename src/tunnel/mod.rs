@@ -0,0 +1,13 @@
+// SPDX-License-Identifier: MIT
+
+mod attribute;
+mod header;
+mod message;
+#[cfg(test)]
+mod tests;
+mod vni_filter;
+
+pub use self::attribute::TunnelAttribute;
+pub use self::header::{TunnelHeader, TunnelMessageBuffer};
+pub use self::message::TunnelMessage;
+pub use self::vni_filter::VniFilterEntry;
@@ -0,0 +1,105 @@
+// SPDX-License-Identifier: MIT
+
+use std::net::Ipv4Addr;
+
+use netlink_packet_core::{
+    NetlinkHeader, NetlinkMessage, NetlinkPayload, NLM_F_DUMP, NLM_F_REQUEST,
+};
+use netlink_packet_utils::{Emitable, Parseable};
+
+use crate::{
+    tunnel::{
+        TunnelAttribute, TunnelHeader, TunnelMessage, TunnelMessageBuffer,
+        VniFilterEntry,
+    },
+    AddressFamily, RouteNetlinkMessage,
+};
+
+// Hand-crafted RTM_NEWTUNNEL payload equivalent to the one generated by
+//      bridge vni add dev vxlan0 vni 100-200 group 239.1.1.1
+// with vxlan0 holding interface index 5.
+#[test]
+fn test_vni_filter_range_group() {
+    let raw = vec![
+        0x07, // AF_BRIDGE
+        0x00, // flags
+        0x00, 0x00, // reserved
+        0x05, 0x00, 0x00, 0x00, // ifindex 5
+        0x1c, 0x00, // length 28
+        0x01, 0x80, // VXLAN_VNIFILTER_ENTRY | NLA_F_NESTED
+        0x08, 0x00, 0x01, 0x00, // length 8, VXLAN_VNIFILTER_ENTRY_START
+        0x64, 0x00, 0x00, 0x00, // 100
+        0x08, 0x00, 0x02, 0x00, // length 8, VXLAN_VNIFILTER_ENTRY_END
+        0xc8, 0x00, 0x00, 0x00, // 200
+        0x08, 0x00, 0x03, 0x00, // length 8, VXLAN_VNIFILTER_ENTRY_GROUP
+        0xef, 0x01, 0x01, 0x01, // 239.1.1.1
+    ];
+
+    let expected = TunnelMessage {
+        header: TunnelHeader {
+            family: AddressFamily::Bridge,
+            flags: 0,
+            ifindex: 5,
+        },
+        attributes: vec![TunnelAttribute::VniFilterEntry(vec![
+            VniFilterEntry::Start(100),
+            VniFilterEntry::End(200),
+            VniFilterEntry::Group(Ipv4Addr::new(239, 1, 1, 1)),
+        ])],
+    };
+
+    assert_eq!(
+        expected,
+        TunnelMessage::parse(&TunnelMessageBuffer::new(&raw)).unwrap()
+    );
+
+    let mut buf = vec![0; expected.buffer_len()];
+    expected.emit(&mut buf);
+    assert_eq!(buf, raw);
+}
+
+// Hand-crafted RTM_GETTUNNEL dump request equivalent to the one sent by
+//      bridge vni show dev vxlan0
+// with vxlan0 holding interface index 5.
+#[test]
+fn test_get_tunnel_netlink_message() {
+    let raw = vec![
+        0x18, 0x00, 0x00, 0x00, // length 24
+        0x7a, 0x00, // RTM_GETTUNNEL 122
+        0x01, 0x03, // NLM_F_REQUEST | NLM_F_DUMP
+        0x00, 0x00, 0x00, 0x00, // sequence number
+        0x00, 0x00, 0x00, 0x00, // port id
+        0x07, // AF_BRIDGE
+        0x00, // flags
+        0x00, 0x00, // reserved
+        0x05, 0x00, 0x00, 0x00, // ifindex 5
+    ];
+
+    let mut header = NetlinkHeader::default();
+    header.flags = NLM_F_REQUEST | NLM_F_DUMP;
+    let mut expected = NetlinkMessage::new(
+        header,
+        NetlinkPayload::from(RouteNetlinkMessage::GetTunnel(TunnelMessage {
+            header: TunnelHeader {
+                family: AddressFamily::Bridge,
+                flags: 0,
+                ifindex: 5,
+            },
+            attributes: vec![],
+        })),
+    );
+    expected.finalize();
+
+    let mut buf = vec![0; expected.buffer_len()];
+    expected.emit(&mut buf);
+    assert_eq!(buf, raw);
+
+    let parsed =
+        NetlinkMessage::<RouteNetlinkMessage>::deserialize(&raw).unwrap();
+    assert_eq!(parsed, expected);
+    if let NetlinkPayload::InnerMessage(msg) = parsed.payload {
+        assert!(msg.is_get_tunnel());
+    } else {
+        panic!("expecting RTM_GETTUNNEL message");
+    }
+}
@@ -0,0 +1,97 @@
+// SPDX-License-Identifier: MIT
+
+use std::net::{Ipv4Addr, Ipv6Addr};
+
+use anyhow::Context;
+use byteorder::{ByteOrder, NativeEndian};
+use netlink_packet_utils::{
+    nla::{DefaultNla, Nla, NlaBuffer},
+    parsers::parse_u32,
+    traits::Parseable,
+    DecodeError,
+};
+
+use crate::ip::{parse_ipv4_addr, parse_ipv6_addr};
+
+const VXLAN_VNIFILTER_ENTRY_START: u16 = 1;
+const VXLAN_VNIFILTER_ENTRY_END: u16 = 2;
+const VXLAN_VNIFILTER_ENTRY_GROUP: u16 = 3;
+const VXLAN_VNIFILTER_ENTRY_GROUP6: u16 = 4;
+// const VXLAN_VNIFILTER_ENTRY_STATS: u16 = 5;
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[non_exhaustive]
+pub enum VniFilterEntry {
+    /// First VNI of the range.
+    Start(u32),
+    /// Last VNI of the range, omitted for single VNI.
+    End(u32),
+    /// Multicast group or remote IPv4 address of the VNI range.
+    Group(Ipv4Addr),
+    /// Multicast group or remote IPv6 address of the VNI range.
+    Group6(Ipv6Addr),
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_nla"))]
+    Other(DefaultNla),
+}
+
+impl Nla for VniFilterEntry {
+    fn value_len(&self) -> usize {
+        match self {
+            Self::Start(_) | Self::End(_) | Self::Group(_) => 4,
+            Self::Group6(_) => 16,
+            Self::Other(attr) => attr.value_len(),
+        }
+    }
+
+    fn emit_value(&self, buffer: &mut [u8]) {
+        match self {
+            Self::Start(v) | Self::End(v) => {
+                NativeEndian::write_u32(buffer, *v)
+            }
+            Self::Group(v) => buffer.copy_from_slice(&v.octets()),
+            Self::Group6(v) => buffer.copy_from_slice(&v.octets()),
+            Self::Other(attr) => attr.emit_value(buffer),
+        }
+    }
+
+    fn kind(&self) -> u16 {
+        match self {
+            Self::Start(_) => VXLAN_VNIFILTER_ENTRY_START,
+            Self::End(_) => VXLAN_VNIFILTER_ENTRY_END,
+            Self::Group(_) => VXLAN_VNIFILTER_ENTRY_GROUP,
+            Self::Group6(_) => VXLAN_VNIFILTER_ENTRY_GROUP6,
+            Self::Other(attr) => attr.kind(),
+        }
+    }
+}
+
+impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>>
+    for VniFilterEntry
+{
+    fn parse(buf: &NlaBuffer<&'a T>) -> Result<Self, DecodeError> {
+        let payload = buf.value();
+        Ok(match buf.kind() {
+            VXLAN_VNIFILTER_ENTRY_START => Self::Start(
+                parse_u32(payload)
+                    .context("invalid VXLAN_VNIFILTER_ENTRY_START")?,
+            ),
+            VXLAN_VNIFILTER_ENTRY_END => Self::End(
+                parse_u32(payload)
+                    .context("invalid VXLAN_VNIFILTER_ENTRY_END")?,
+            ),
+            VXLAN_VNIFILTER_ENTRY_GROUP => Self::Group(
+                parse_ipv4_addr(payload)
+                    .context("invalid VXLAN_VNIFILTER_ENTRY_GROUP")?,
+            ),
+            VXLAN_VNIFILTER_ENTRY_GROUP6 => Self::Group6(
+                parse_ipv6_addr(payload)
+                    .context("invalid VXLAN_VNIFILTER_ENTRY_GROUP6")?,
+            ),
+            kind => Self::Other(
+                DefaultNla::parse(buf)
+                    .context(format!("unknown NLA type {kind}"))?,
+            ),
+        })
+    }
+}
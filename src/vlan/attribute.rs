@@ -0,0 +1,74 @@
+// SPDX-License-Identifier: MIT
+
+use anyhow::Context;
+use netlink_packet_utils::{
+    nla::{DefaultNla, Nla, NlaBuffer, NlasIterator, NLA_F_NESTED},
+    traits::{Emitable, Parseable},
+    DecodeError,
+};
+
+use super::VlanEntry;
+
+const BRIDGE_VLANDB_ENTRY: u16 = 1;
+// const BRIDGE_VLANDB_GLOBAL_OPTIONS: u16 = 2;
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[non_exhaustive]
+pub enum VlanAttribute {
+    /// A VLAN or VLAN range of bridge or bridge port.
+    Entry(Vec<VlanEntry>),
+    /// The `BRIDGE_VLANDB_DUMP_FLAGS` of `RTM_GETVLAN` request shares the
+    /// same attribute type as `BRIDGE_VLANDB_ENTRY` without `NLA_F_NESTED`,
+    /// hence it is stored here.
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_nla"))]
+    Other(DefaultNla),
+}
+
+impl Nla for VlanAttribute {
+    fn value_len(&self) -> usize {
+        match self {
+            Self::Entry(v) => v.as_slice().buffer_len(),
+            Self::Other(attr) => attr.value_len(),
+        }
+    }
+
+    fn emit_value(&self, buffer: &mut [u8]) {
+        match self {
+            Self::Entry(v) => v.as_slice().emit(buffer),
+            Self::Other(attr) => attr.emit_value(buffer),
+        }
+    }
+
+    fn kind(&self) -> u16 {
+        match self {
+            Self::Entry(_) => BRIDGE_VLANDB_ENTRY | NLA_F_NESTED,
+            Self::Other(attr) => attr.kind(),
+        }
+    }
+}
+
+impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>>
+    for VlanAttribute
+{
+    fn parse(buf: &NlaBuffer<&'a T>) -> Result<Self, DecodeError> {
+        let payload = buf.value();
+        Ok(match buf.kind() {
+            BRIDGE_VLANDB_ENTRY if buf.nested_flag() => {
+                let mut entries = vec![];
+                for nla in NlasIterator::new(payload) {
+                    let nla = nla.context("invalid BRIDGE_VLANDB_ENTRY")?;
+                    entries.push(
+                        VlanEntry::parse(&nla)
+                            .context("failed to parse BRIDGE_VLANDB_ENTRY")?,
+                    );
+                }
+                Self::Entry(entries)
+            }
+            kind => Self::Other(
+                DefaultNla::parse(buf)
+                    .context(format!("unknown NLA type {kind}"))?,
+            ),
+        })
+    }
+}
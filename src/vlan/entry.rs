@@ -0,0 +1,127 @@
+// SPDX-License-Identifier: MIT
+
+use anyhow::Context;
+use byteorder::{ByteOrder, NativeEndian};
+use netlink_packet_utils::{
+    nla::{DefaultNla, Nla, NlaBuffer},
+    parsers::{parse_u16, parse_u32, parse_u8},
+    traits::Parseable,
+    DecodeError,
+};
+
+use crate::link::{BridgePortMulticastRouter, BridgePortState, BridgeVlanInfo};
+
+const BRIDGE_VLANDB_ENTRY_INFO: u16 = 1;
+const BRIDGE_VLANDB_ENTRY_RANGE: u16 = 2;
+const BRIDGE_VLANDB_ENTRY_STATE: u16 = 3;
+// const BRIDGE_VLANDB_ENTRY_TUNNEL_INFO: u16 = 4;
+// const BRIDGE_VLANDB_ENTRY_STATS: u16 = 5;
+const BRIDGE_VLANDB_ENTRY_MCAST_ROUTER: u16 = 6;
+const BRIDGE_VLANDB_ENTRY_MCAST_N_GROUPS: u16 = 7;
+const BRIDGE_VLANDB_ENTRY_MCAST_MAX_GROUPS: u16 = 8;
+const BRIDGE_VLANDB_ENTRY_NEIGH_SUPPRESS: u16 = 9;
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[non_exhaustive]
+pub enum VlanEntry {
+    /// VLAN ID and flags, the first VLAN ID when
+    /// [VlanEntry::Range] is present.
+    Info(BridgeVlanInfo),
+    /// Last VLAN ID of the range.
+    Range(u16),
+    /// Per-VLAN STP state.
+    State(BridgePortState),
+    McastRouter(BridgePortMulticastRouter),
+    /// Number of multicast groups joined on this VLAN of the port.
+    McastNGroups(u32),
+    /// Maximum number of multicast groups on this VLAN of the port.
+    McastMaxGroups(u32),
+    NeighSuppress(bool),
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_nla"))]
+    Other(DefaultNla),
+}
+
+impl Nla for VlanEntry {
+    fn value_len(&self) -> usize {
+        match self {
+            Self::Info(_) => BridgeVlanInfo::LENGTH,
+            Self::Range(_) => 2,
+            Self::State(_) | Self::McastRouter(_) | Self::NeighSuppress(_) => 1,
+            Self::McastNGroups(_) | Self::McastMaxGroups(_) => 4,
+            Self::Other(attr) => attr.value_len(),
+        }
+    }
+
+    fn emit_value(&self, buffer: &mut [u8]) {
+        match self {
+            Self::Info(v) => buffer.copy_from_slice(&<[u8; 4]>::from(v)),
+            Self::Range(v) => NativeEndian::write_u16(buffer, *v),
+            Self::State(v) => buffer[0] = (*v).into(),
+            Self::McastRouter(v) => buffer[0] = (*v).into(),
+            Self::NeighSuppress(v) => buffer[0] = *v as u8,
+            Self::McastNGroups(v) | Self::McastMaxGroups(v) => {
+                NativeEndian::write_u32(buffer, *v)
+            }
+            Self::Other(attr) => attr.emit_value(buffer),
+        }
+    }
+
+    fn kind(&self) -> u16 {
+        match self {
+            Self::Info(_) => BRIDGE_VLANDB_ENTRY_INFO,
+            Self::Range(_) => BRIDGE_VLANDB_ENTRY_RANGE,
+            Self::State(_) => BRIDGE_VLANDB_ENTRY_STATE,
+            Self::McastRouter(_) => BRIDGE_VLANDB_ENTRY_MCAST_ROUTER,
+            Self::McastNGroups(_) => BRIDGE_VLANDB_ENTRY_MCAST_N_GROUPS,
+            Self::McastMaxGroups(_) => BRIDGE_VLANDB_ENTRY_MCAST_MAX_GROUPS,
+            Self::NeighSuppress(_) => BRIDGE_VLANDB_ENTRY_NEIGH_SUPPRESS,
+            Self::Other(attr) => attr.kind(),
+        }
+    }
+}
+
+impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>> for VlanEntry {
+    fn parse(buf: &NlaBuffer<&'a T>) -> Result<Self, DecodeError> {
+        let payload = buf.value();
+        Ok(match buf.kind() {
+            BRIDGE_VLANDB_ENTRY_INFO => Self::Info(
+                BridgeVlanInfo::try_from(payload)
+                    .context("invalid BRIDGE_VLANDB_ENTRY_INFO value")?,
+            ),
+            BRIDGE_VLANDB_ENTRY_RANGE => Self::Range(
+                parse_u16(payload)
+                    .context("invalid BRIDGE_VLANDB_ENTRY_RANGE value")?,
+            ),
+            BRIDGE_VLANDB_ENTRY_STATE => Self::State(
+                parse_u8(payload)
+                    .context("invalid BRIDGE_VLANDB_ENTRY_STATE value")?
+                    .into(),
+            ),
+            BRIDGE_VLANDB_ENTRY_MCAST_ROUTER => Self::McastRouter(
+                parse_u8(payload)
+                    .context("invalid BRIDGE_VLANDB_ENTRY_MCAST_ROUTER value")?
+                    .into(),
+            ),
+            BRIDGE_VLANDB_ENTRY_MCAST_N_GROUPS => {
+                Self::McastNGroups(parse_u32(payload).context(
+                    "invalid BRIDGE_VLANDB_ENTRY_MCAST_N_GROUPS value",
+                )?)
+            }
+            BRIDGE_VLANDB_ENTRY_MCAST_MAX_GROUPS => {
+                Self::McastMaxGroups(parse_u32(payload).context(
+                    "invalid BRIDGE_VLANDB_ENTRY_MCAST_MAX_GROUPS value",
+                )?)
+            }
+            BRIDGE_VLANDB_ENTRY_NEIGH_SUPPRESS => Self::NeighSuppress(
+                parse_u8(payload).context(
+                    "invalid BRIDGE_VLANDB_ENTRY_NEIGH_SUPPRESS value",
+                )? > 0,
+            ),
+            kind => Self::Other(
+                DefaultNla::parse(buf)
+                    .context(format!("unknown NLA type {kind}"))?,
+            ),
+        })
+    }
+}
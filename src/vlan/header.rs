@@ -0,0 +1,60 @@
+// SPDX-License-Identifier: MIT
+
+use netlink_packet_utils::{
+    nla::{NlaBuffer, NlasIterator},
+    DecodeError, Emitable, Parseable,
+};
+
+use crate::AddressFamily;
+
+const VLAN_HEADER_LEN: usize = 8;
+
+// kernel struct `br_vlan_msg`
+buffer!(VlanMessageBuffer(VLAN_HEADER_LEN) {
+    family: (u8, 0),
+    reserved1: (u8, 1),
+    reserved2: (u16, 2..4),
+    ifindex: (u32, 4..VLAN_HEADER_LEN),
+    payload: (slice, VLAN_HEADER_LEN..),
+});
+
+impl<'a, T: AsRef<[u8]> + ?Sized> VlanMessageBuffer<&'a T> {
+    pub fn attributes(
+        &self,
+    ) -> impl Iterator<Item = Result<NlaBuffer<&'a [u8]>, DecodeError>> {
+        NlasIterator::new(self.payload())
+    }
+}
+
+#[derive(Debug, PartialEq, Eq, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[non_exhaustive]
+pub struct VlanHeader {
+    /// Kernel only supports `AF_BRIDGE`.
+    pub family: AddressFamily,
+    /// Interface index of the bridge or bridge port.
+    pub ifindex: u32,
+}
+
+impl Emitable for VlanHeader {
+    fn buffer_len(&self) -> usize {
+        VLAN_HEADER_LEN
+    }
+
+    fn emit(&self, buffer: &mut [u8]) {
+        let mut packet = VlanMessageBuffer::new(buffer);
+        packet.set_family(self.family.into());
+        packet.set_reserved1(0);
+        packet.set_reserved2(0);
+        packet.set_ifindex(self.ifindex);
+    }
+}
+
+impl<T: AsRef<[u8]>> Parseable<VlanMessageBuffer<T>> for VlanHeader {
+    fn parse(buf: &VlanMessageBuffer<T>) -> Result<Self, DecodeError> {
+        Ok(Self {
+            family: buf.family().into(),
+            ifindex: buf.ifindex(),
+        })
+    }
+}
@@ -0,0 +1,58 @@
+// SPDX-License-Identifier: MIT
+
+use anyhow::Context;
+use netlink_packet_utils::{
+    traits::{Emitable, Parseable},
+    DecodeError,
+};
+
+use crate::vlan::{VlanAttribute, VlanHeader, VlanMessageBuffer};
+
+/// Message of `RTM_NEWVLAN`, `RTM_DELVLAN` and `RTM_GETVLAN`, used by
+/// `bridge vlan` command to manage the per-VLAN options of bridge and bridge
+/// port.
+#[derive(Debug, PartialEq, Eq, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[non_exhaustive]
+pub struct VlanMessage {
+    pub header: VlanHeader,
+    pub attributes: Vec<VlanAttribute>,
+}
+
+impl<'a, T: AsRef<[u8]> + 'a> Parseable<VlanMessageBuffer<&'a T>>
+    for VlanMessage
+{
+    fn parse(buf: &VlanMessageBuffer<&'a T>) -> Result<Self, DecodeError> {
+        Ok(Self {
+            header: VlanHeader::parse(buf)
+                .context("failed to parse vlan message header")?,
+            attributes: Vec::<VlanAttribute>::parse(buf)
+                .context("failed to parse vlan message NLAs")?,
+        })
+    }
+}
+
+impl<'a, T: AsRef<[u8]> + 'a> Parseable<VlanMessageBuffer<&'a T>>
+    for Vec<VlanAttribute>
+{
+    fn parse(buf: &VlanMessageBuffer<&'a T>) -> Result<Self, DecodeError> {
+        let mut attributes = vec![];
+        for nla_buf in buf.attributes() {
+            attributes.push(VlanAttribute::parse(&nla_buf?)?);
+        }
+        Ok(attributes)
+    }
+}
+
+impl Emitable for VlanMessage {
+    fn buffer_len(&self) -> usize {
+        self.header.buffer_len() + self.attributes.as_slice().buffer_len()
+    }
+
+    fn emit(&self, buffer: &mut [u8]) {
+        self.header.emit(buffer);
+        self.attributes
+            .as_slice()
+            .emit(&mut buffer[self.header.buffer_len()..]);
+    }
+}
@@ -0,0 +1,13 @@
+// SPDX-License-Identifier: MIT
+
+mod attribute;
+mod entry;
+mod header;
+mod message;
+#[cfg(test)]
+mod tests;
+
+pub use self::attribute::VlanAttribute;
+pub use self::entry::VlanEntry;
+pub use self::header::{VlanHeader, VlanMessageBuffer};
+pub use self::message::VlanMessage;
@@ -0,0 +1,111 @@
+// SPDX-License-Identifier: MIT
+
+use netlink_packet_core::{
+    NetlinkHeader, NetlinkMessage, NetlinkPayload, NLM_F_DUMP, NLM_F_REQUEST,
+};
+use netlink_packet_utils::{nla::DefaultNla, Emitable, Parseable};
+
+use crate::{
+    link::{BridgePortState, BridgeVlanInfo, BridgeVlanInfoFlags},
+    vlan::{
+        VlanAttribute, VlanEntry, VlanHeader, VlanMessage, VlanMessageBuffer,
+    },
+    AddressFamily, RouteNetlinkMessage,
+};
+
+// Hand-crafted RTM_NEWVLAN dump reply of bridge port veth9a(ifindex 11)
+// holding VLAN 1 as PVID and egress untagged. The test kernel has no
+// bridge VLAN filtering support, hence no captured data.
+#[test]
+fn test_vlan_entry() {
+    let raw = vec![
+        0x07, // AF_BRIDGE
+        0x00, // reserved1
+        0x00, 0x00, // reserved2
+        0x0b, 0x00, 0x00, 0x00, // ifindex 11
+        0x1c, 0x00, // length 28
+        0x01, 0x80, // BRIDGE_VLANDB_ENTRY | NLA_F_NESTED
+        0x08, 0x00, 0x01, 0x00, // length 8, BRIDGE_VLANDB_ENTRY_INFO
+        0x06, 0x00, // BRIDGE_VLAN_INFO_PVID | BRIDGE_VLAN_INFO_UNTAGGED
+        0x01, 0x00, // vid 1
+        0x05, 0x00, 0x03, 0x00, // length 5, BRIDGE_VLANDB_ENTRY_STATE
+        0x03, 0x00, 0x00, 0x00, // BR_STATE_FORWARDING
+        // length 5, BRIDGE_VLANDB_ENTRY_NEIGH_SUPPRESS
+        0x05, 0x00, 0x09, 0x00, 0x00, 0x00, 0x00, 0x00, // false
+    ];
+
+    let expected = VlanMessage {
+        header: VlanHeader {
+            family: AddressFamily::Bridge,
+            ifindex: 11,
+        },
+        attributes: vec![VlanAttribute::Entry(vec![
+            VlanEntry::Info(BridgeVlanInfo {
+                flags: BridgeVlanInfoFlags::Pvid
+                    | BridgeVlanInfoFlags::Untagged,
+                vid: 1,
+            }),
+            VlanEntry::State(BridgePortState::Forwarding),
+            VlanEntry::NeighSuppress(false),
+        ])],
+    };
+
+    assert_eq!(
+        expected,
+        VlanMessage::parse(&VlanMessageBuffer::new(&raw)).unwrap()
+    );
+
+    let mut buf = vec![0; expected.buffer_len()];
+    expected.emit(&mut buf);
+    assert_eq!(buf, raw);
+}
+
+// Hand-crafted RTM_GETVLAN dump request of all bridge ports asking for
+// VLAN statistics. The `BRIDGE_VLANDB_DUMP_FLAGS` shares the attribute
+// type with `BRIDGE_VLANDB_ENTRY` but without NLA_F_NESTED.
+#[test]
+fn test_get_vlan_netlink_message() {
+    let raw = vec![
+        0x20, 0x00, 0x00, 0x00, // length 32
+        0x72, 0x00, // RTM_GETVLAN 114
+        0x01, 0x03, // NLM_F_REQUEST | NLM_F_DUMP
+        0x00, 0x00, 0x00, 0x00, // sequence number
+        0x00, 0x00, 0x00, 0x00, // port id
+        0x07, // AF_BRIDGE
+        0x00, // reserved1
+        0x00, 0x00, // reserved2
+        0x00, 0x00, 0x00, 0x00, // ifindex 0
+        0x08, 0x00, 0x01, 0x00, // length 8, BRIDGE_VLANDB_DUMP_FLAGS
+        0x01, 0x00, 0x00, 0x00, // BRIDGE_VLANDB_DUMPF_STATS
+    ];
+
+    let mut header = NetlinkHeader::default();
+    header.flags = NLM_F_REQUEST | NLM_F_DUMP;
+    let mut expected = NetlinkMessage::new(
+        header,
+        NetlinkPayload::from(RouteNetlinkMessage::GetVlan(VlanMessage {
+            header: VlanHeader {
+                family: AddressFamily::Bridge,
+                ifindex: 0,
+            },
+            attributes: vec![VlanAttribute::Other(DefaultNla::new(
+                1,
+                vec![0x01, 0x00, 0x00, 0x00],
+            ))],
+        })),
+    );
+    expected.finalize();
+
+    let mut buf = vec![0; expected.buffer_len()];
+    expected.emit(&mut buf);
+    assert_eq!(buf, raw);
+
+    let parsed =
+        NetlinkMessage::<RouteNetlinkMessage>::deserialize(&raw).unwrap();
+    assert_eq!(parsed, expected);
+    if let NetlinkPayload::InnerMessage(msg) = parsed.payload {
+        assert!(msg.is_get_vlan());
+    } else {
+        panic!("expecting RTM_GETVLAN message");
+    }
+}